@@ -5,7 +5,9 @@
 #include <wasmi/config.h>
 #include <wasmi/engine.h>
 #include <wasmi/error.h>
+#include <wasmi/memory.h>
 #include <wasmi/store.h>
+#include <wasmi/val.h>
 
 /**
  * \brief Wasmi version string.
@@ -85,6 +85,43 @@ WASMI_CONFIG_PROP(void, wasm_reference_types, bool)
  */
 WASMI_CONFIG_PROP(void, wasm_tail_call, bool)
 
+/**
+ * \brief Whether or not to Wasm function-references proposal is enabled.
+ *
+ * Default value: `false`
+ */
+WASMI_CONFIG_PROP(void, wasm_function_references, bool)
+
+/**
+ * \brief Whether or not to Wasm custom-page-sizes proposal is enabled.
+ *
+ * Default value: `false`
+ */
+WASMI_CONFIG_PROP(void, wasm_custom_page_sizes, bool)
+
+/**
+ * \brief Whether or not to Wasm wide-arithmetic proposal is enabled.
+ *
+ * Default value: `false`
+ */
+WASMI_CONFIG_PROP(void, wasm_wide_arithmetic, bool)
+
+/**
+ * \brief Whether or not to Wasm exception-handling proposal is enabled.
+ *
+ * Only tags are supported as required by the stack-switching proposal.
+ *
+ * Default value: `false`
+ */
+WASMI_CONFIG_PROP(void, wasm_exceptions, bool)
+
+/**
+ * \brief Whether or not to Wasm stack-switching proposal is enabled.
+ *
+ * Default value: `false`
+ */
+WASMI_CONFIG_PROP(void, wasm_stack_switching, bool)
+
 /**
  * \brief Whether or not to Wasm extended-const proposal is enabled.
  *
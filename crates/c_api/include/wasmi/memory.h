@@ -0,0 +1,39 @@
+/**
+ * \file wasmi/memory.h
+ *
+ * \brief Wasmi-specific extensions to #wasm_memorytype_t
+ */
+
+#ifndef WASMI_MEMORY_H
+#define WASMI_MEMORY_H
+
+#include <wasm.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \brief Creates a new #wasm_memorytype_t with `limits` in pages of `2^page_size_log2` bytes.
+ *
+ * Returns `NULL` if `page_size_log2` is neither `0` nor `16` or if `limits` are out of bounds.
+ *
+ * The returned #wasm_memorytype_t must be freed using #wasm_memorytype_delete.
+ */
+WASM_API_EXTERN own wasm_memorytype_t *wasmi_memorytype_new_with_page_size(
+    const wasm_limits_t *limits,
+    uint8_t page_size_log2
+);
+
+/**
+ * \brief Returns the size of a page of `mt` as power of two.
+ *
+ * This is `16` for the default page size of 64 KiB.
+ */
+WASM_API_EXTERN uint8_t wasmi_memorytype_page_size_log2(const wasm_memorytype_t *mt);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMI_MEMORY_H
@@ -0,0 +1,58 @@
+/**
+ * \file wasmi/val.h
+ *
+ * \brief Wasmi-specific extensions to #wasm_valtype_t
+ */
+
+#ifndef WASMI_VAL_H
+#define WASMI_VAL_H
+
+#include <wasm.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \brief Creates a new reference #wasm_valtype_t with the given nullability.
+ *
+ * Returns `NULL` if `kind` is neither #WASM_FUNCREF nor #WASM_EXTERNREF.
+ *
+ * The returned #wasm_valtype_t must be freed using #wasm_valtype_delete.
+ */
+WASM_API_EXTERN own wasm_valtype_t *wasmi_valtype_new_ref(bool nullable, wasm_valkind_t kind);
+
+/**
+ * \brief Creates a new #wasm_valtype_t referencing functions of type `functype`.
+ *
+ * The returned #wasm_valtype_t is only valid for use with `engine`
+ * and must be freed using #wasm_valtype_delete.
+ */
+WASM_API_EXTERN own wasm_valtype_t *wasmi_valtype_new_typed_funcref(
+    const wasm_engine_t *engine,
+    bool nullable,
+    const wasm_functype_t *functype
+);
+
+/**
+ * \brief Returns `true` if `vt` is a reference type that admits `null` values.
+ */
+WASM_API_EXTERN bool wasmi_valtype_is_nullable(const wasm_valtype_t *vt);
+
+/**
+ * \brief Returns the function type referenced by `vt`.
+ *
+ * Returns `NULL` if `vt` is not a typed function reference or if it was not created for `engine`.
+ *
+ * The returned #wasm_functype_t must be freed using #wasm_functype_delete.
+ */
+WASM_API_EXTERN own wasm_functype_t *wasmi_valtype_functype(
+    const wasm_engine_t *engine,
+    const wasm_valtype_t *vt
+);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WASMI_VAL_H
@@ -101,6 +101,56 @@ pub extern "C" fn wasmi_config_wasm_tail_call_set(c: &mut wasm_config_t, enable:
     c.inner.wasm_tail_call(enable);
 }
 
+/// Enables or disables support for the Wasm [`function-references`] proposal.
+///
+/// Wraps [`wasmi::Config::wasm_function_references`]
+///
+/// [`function-references`]: <https://github.com/WebAssembly/function-references>
+#[no_mangle]
+pub extern "C" fn wasmi_config_wasm_function_references_set(c: &mut wasm_config_t, enable: bool) {
+    c.inner.wasm_function_references(enable);
+}
+
+/// Enables or disables support for the Wasm [`custom-page-sizes`] proposal.
+///
+/// Wraps [`wasmi::Config::wasm_custom_page_sizes`]
+///
+/// [`custom-page-sizes`]: <https://github.com/WebAssembly/custom-page-sizes>
+#[no_mangle]
+pub extern "C" fn wasmi_config_wasm_custom_page_sizes_set(c: &mut wasm_config_t, enable: bool) {
+    c.inner.wasm_custom_page_sizes(enable);
+}
+
+/// Enables or disables support for the Wasm [`wide-arithmetic`] proposal.
+///
+/// Wraps [`wasmi::Config::wasm_wide_arithmetic`]
+///
+/// [`wide-arithmetic`]: <https://github.com/WebAssembly/wide-arithmetic>
+#[no_mangle]
+pub extern "C" fn wasmi_config_wasm_wide_arithmetic_set(c: &mut wasm_config_t, enable: bool) {
+    c.inner.wasm_wide_arithmetic(enable);
+}
+
+/// Enables or disables support for the Wasm [`exception-handling`] proposal.
+///
+/// Wraps [`wasmi::Config::wasm_exceptions`]
+///
+/// [`exception-handling`]: <https://github.com/WebAssembly/exception-handling>
+#[no_mangle]
+pub extern "C" fn wasmi_config_wasm_exceptions_set(c: &mut wasm_config_t, enable: bool) {
+    c.inner.wasm_exceptions(enable);
+}
+
+/// Enables or disables support for the Wasm [`stack-switching`] proposal.
+///
+/// Wraps [`wasmi::Config::wasm_stack_switching`]
+///
+/// [`stack-switching`]: <https://github.com/WebAssembly/stack-switching>
+#[no_mangle]
+pub extern "C" fn wasmi_config_wasm_stack_switching_set(c: &mut wasm_config_t, enable: bool) {
+    c.inner.wasm_stack_switching(enable);
+}
+
 /// Enables or disables support for the Wasm [`extended-const`] proposal.
 ///
 /// Wraps [`wasmi::Config::wasm_extended_const`]
@@ -69,6 +69,31 @@ pub extern "C" fn wasm_memorytype_new(limits: &wasm_limits_t) -> Box<wasm_memory
     Box::new(wasm_memorytype_t::new(memory_type))
 }
 
+/// Creates a new [`wasm_memorytype_t`] with the given `limits` in pages of `2^page_size_log2` bytes.
+///
+/// Returns `None` if `page_size_log2` is neither `0` nor `16` or if `limits` are out of bounds.
+///
+/// Wraps [`MemoryType::new_with_page_size`].
+#[no_mangle]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasmi_memorytype_new_with_page_size(
+    limits: &wasm_limits_t,
+    page_size_log2: u8,
+) -> Option<Box<wasm_memorytype_t>> {
+    let memory_type =
+        MemoryType::new_with_page_size(limits.min, limits.max(), page_size_log2).ok()?;
+    Some(Box::new(wasm_memorytype_t::new(memory_type)))
+}
+
+/// Returns the size of a page of the [`wasm_memorytype_t`] as power of two.
+///
+/// Wraps [`MemoryType::page_size_log2`].
+#[no_mangle]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasmi_memorytype_page_size_log2(mt: &wasm_memorytype_t) -> u8 {
+    mt.ty().ty.page_size_log2()
+}
+
 /// Returns a shared reference to the table limits of the [`wasm_memorytype_t`].
 #[no_mangle]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
@@ -1,5 +1,6 @@
+use crate::{wasm_engine_t, wasm_functype_t};
 use alloc::boxed::Box;
-use wasmi::core::ValType;
+use wasmi::core::{HeapType, RefType, ValType};
 
 /// A WebAssembly value type.
 ///
@@ -60,6 +61,69 @@ pub extern "C" fn wasm_valtype_kind(vt: &wasm_valtype_t) -> wasm_valkind_t {
     from_valtype(&vt.ty)
 }
 
+/// Creates a new owned reference [`wasm_valtype_t`] with the given nullability.
+///
+/// Returns `None` if `kind` is not a reference kind.
+///
+/// Wraps [`RefType::new`] with [`HeapType::Func`] or [`HeapType::Extern`].
+#[no_mangle]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasmi_valtype_new_ref(
+    nullable: bool,
+    kind: wasm_valkind_t,
+) -> Option<Box<wasm_valtype_t>> {
+    let heap_type = match kind {
+        wasm_valkind_t::WASM_FUNCREF => HeapType::Func,
+        wasm_valkind_t::WASM_EXTERNREF => HeapType::Extern,
+        _ => return None,
+    };
+    let ty = ValType::from(RefType::new(nullable, heap_type));
+    Some(Box::new(wasm_valtype_t { ty }))
+}
+
+/// Creates a new owned [`wasm_valtype_t`] referencing functions of type `functype`.
+///
+/// The returned [`wasm_valtype_t`] is only valid for use with `engine`.
+///
+/// Wraps [`RefType::new`] with [`HeapType::Concrete`].
+#[no_mangle]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasmi_valtype_new_typed_funcref(
+    engine: &wasm_engine_t,
+    nullable: bool,
+    functype: &wasm_functype_t,
+) -> Box<wasm_valtype_t> {
+    let id = engine.inner.register_func_type(functype.ty().ty.clone());
+    let ty = ValType::from(RefType::new(nullable, HeapType::Concrete(id)));
+    Box::new(wasm_valtype_t { ty })
+}
+
+/// Returns `true` if the [`wasm_valtype_t`] is a reference type that admits `null` values.
+#[no_mangle]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasmi_valtype_is_nullable(vt: &wasm_valtype_t) -> bool {
+    vt.ty
+        .ref_type()
+        .is_some_and(|ref_type| ref_type.is_nullable())
+}
+
+/// Returns the function type referenced by the [`wasm_valtype_t`] if any.
+///
+/// Returns `None` if the [`wasm_valtype_t`] is not a typed function reference
+/// or if it was not created for `engine`.
+#[no_mangle]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasmi_valtype_functype(
+    engine: &wasm_engine_t,
+    vt: &wasm_valtype_t,
+) -> Option<Box<wasm_functype_t>> {
+    let HeapType::Concrete(id) = vt.ty.ref_type()?.heap_type() else {
+        return None;
+    };
+    let func_type = engine.inner.func_type_by_id(id)?;
+    Some(Box::new(wasm_functype_t::new(func_type)))
+}
+
 /// Converts the [`wasm_valkind_t`] into the respective [`ValType`].
 pub(crate) fn into_valtype(kind: wasm_valkind_t) -> ValType {
     match kind {
@@ -81,5 +145,7 @@ pub(crate) fn from_valtype(ty: &ValType) -> wasm_valkind_t {
         ValType::F64 => wasm_valkind_t::WASM_F64,
         ValType::ExternRef => wasm_valkind_t::WASM_EXTERNREF,
         ValType::FuncRef => wasm_valkind_t::WASM_FUNCREF,
+        ty if ty.is_func_ref() => wasm_valkind_t::WASM_FUNCREF,
+        _ => wasm_valkind_t::WASM_EXTERNREF,
     }
 }
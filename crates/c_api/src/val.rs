@@ -113,6 +113,12 @@ impl From<Val> for wasm_val_t {
             Val::ExternRef(_) => {
                 core::panic!("`wasm_val_t`: creating a `wasm_val_t` from an `externref`")
             }
+            Val::AnyRef(_) => {
+                core::panic!("`wasm_val_t`: creating a `wasm_val_t` from an `anyref`")
+            }
+            Val::ContRef(_) => {
+                core::panic!("`wasm_val_t`: creating a `wasm_val_t` from a `contref`")
+            }
         }
     }
 }
@@ -124,16 +130,16 @@ impl wasm_val_t {
     ///
     /// This effectively clones the [`wasm_val_t`] if necessary.
     pub fn to_val(&self) -> Val {
-        match into_valtype(self.kind) {
-            ValType::I32 => Val::from(unsafe { self.of.i32 }),
-            ValType::I64 => Val::from(unsafe { self.of.i64 }),
-            ValType::F32 => Val::from(F32::from(unsafe { self.of.f32 })),
-            ValType::F64 => Val::from(F64::from(unsafe { self.of.f64 })),
-            ValType::FuncRef => match unsafe { self.of.ref_ }.is_null() {
+        match self.kind {
+            wasm_valkind_t::WASM_I32 => Val::from(unsafe { self.of.i32 }),
+            wasm_valkind_t::WASM_I64 => Val::from(unsafe { self.of.i64 }),
+            wasm_valkind_t::WASM_F32 => Val::from(F32::from(unsafe { self.of.f32 })),
+            wasm_valkind_t::WASM_F64 => Val::from(F64::from(unsafe { self.of.f64 })),
+            wasm_valkind_t::WASM_FUNCREF => match unsafe { self.of.ref_ }.is_null() {
                 true => Val::FuncRef(FuncRef::null()),
                 false => ref_to_val(unsafe { &*self.of.ref_ }),
             },
-            ValType::ExternRef => {
+            wasm_valkind_t::WASM_EXTERNREF => {
                 core::unreachable!("`wasm_val_t`: cannot contain non-function reference values")
             }
         }
@@ -1,5 +1,5 @@
 use anyhow::{Context, Error, Result};
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::{
     ffi::OsStr,
     net::SocketAddr,
@@ -28,7 +28,7 @@ impl FromStr for KeyValue {
             .find('=')
             .ok_or_else(|| anyhow::anyhow!("invalid KEY=value: no `=` found in `{}`", s))?;
         let (key, eq_value) = s.split_at(eq_pos);
-        assert!(s.starts_with('='));
+        assert!(eq_value.starts_with('='));
         let value = &eq_value[1..];
         let key = key.to_string();
         let value = value.to_string();
@@ -38,14 +38,27 @@ impl FromStr for KeyValue {
 
 /// The Wasmi CLI application arguments.
 #[derive(Parser, Debug)]
-#[clap(author, version, about, long_about = None, trailing_var_arg = true)]
+#[clap(
+    author,
+    version,
+    about,
+    long_about = None,
+    trailing_var_arg = true,
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true,
+)]
 pub struct Args {
+    /// The optional subcommand to run instead of executing the Wasm module.
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// The host directory to pre-open for the `guest` to use.
     #[clap(
         long = "dir",
         value_name = "DIRECTORY",
         action = clap::ArgAction::Append,
         value_hint = clap::ValueHint::DirPath,
+        global = true,
     )]
     dirs: Vec<PathBuf>,
 
@@ -54,6 +67,7 @@ pub struct Args {
         long = "tcplisten",
         value_name = "SOCKET ADDRESS",
         action = clap::ArgAction::Append,
+        global = true,
     )]
     tcplisten: Vec<SocketAddr>,
 
@@ -63,15 +77,30 @@ pub struct Args {
         value_name = "NAME=VAL",
         value_parser(KeyValue::from_str),
         action = clap::ArgAction::Append,
+        global = true,
     )]
     envs: Vec<KeyValue>,
 
+    /// The Wasm module to instantiate and register under NAME before the main Wasm module.
+    ///
+    /// The main Wasm module and all subsequent preloaded Wasm modules may import
+    /// the exports of the preloaded Wasm module using NAME as their module name.
+    #[clap(
+        long = "preload",
+        value_name = "NAME=MODULE",
+        value_parser(KeyValue::from_str),
+        action = clap::ArgAction::Append,
+        global = true,
+    )]
+    preloads: Vec<KeyValue>,
+
     /// The file containing the WebAssembly module to execute.
     #[clap(
         value_name = "MODULE",
         value_hint = clap::ValueHint::FilePath,
+        required = true,
     )]
-    wasm_file: PathBuf,
+    wasm_file: Option<PathBuf>,
 
     /// The function to invoke.
     ///
@@ -83,24 +112,70 @@ pub struct Args {
     invoke: Option<String>,
 
     /// Enable lazy Wasm compilation.
-    #[clap(long = "compilation-mode", value_enum, default_value_t=CompilationMode::Eager)]
+    #[clap(
+        long = "compilation-mode",
+        value_enum,
+        default_value_t = CompilationMode::Eager,
+        global = true,
+    )]
     compilation_mode: CompilationMode,
 
     /// Enable execution fiel metering with N units of fuel.
     ///
     /// The execution will trap after running out of the N units of fuel.
-    #[clap(long = "fuel", value_name = "N")]
+    #[clap(long = "fuel", value_name = "N", global = true)]
     fuel: Option<u64>,
 
     /// Enable informational messages beyond warnings or errors.
     #[clap(long = "verbose")]
     verbose: bool,
 
+    /// Write a Wasm coredump to PATH if the execution traps.
+    ///
+    /// The coredump uses the tool-conventions Wasm coredump format.
+    #[clap(
+        long = "coredump-on-trap",
+        value_name = "PATH",
+        value_hint = clap::ValueHint::FilePath,
+    )]
+    coredump_on_trap: Option<PathBuf>,
+
     /// Arguments given to the Wasm module or the invoked function.
     #[clap(value_name = "ARGS")]
     func_args: Vec<String>,
 }
 
+/// The subcommands of the Wasmi CLI application.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Start an interactive session to repeatedly call exports and inspect the Wasm module instance.
+    Repl {
+        /// The file containing the WebAssembly module to instantiate.
+        #[clap(
+            value_name = "MODULE",
+            value_hint = clap::ValueHint::FilePath,
+        )]
+        wasm_file: PathBuf,
+    },
+    /// Print information about the Wasm module without executing it.
+    Inspect {
+        /// The file containing the WebAssembly module to inspect.
+        #[clap(
+            value_name = "MODULE",
+            value_hint = clap::ValueHint::FilePath,
+        )]
+        wasm_file: PathBuf,
+
+        /// Print the information as JSON for use in scripts.
+        #[clap(long = "json")]
+        json: bool,
+
+        /// Print the disassembled Wasmi bytecode of all non-imported functions.
+        #[clap(long = "ir")]
+        ir: bool,
+    },
+}
+
 /// The chosen Wasmi compilation mode.
 #[derive(Debug, Default, Copy, Clone, ValueEnum)]
 enum CompilationMode {
@@ -121,9 +196,27 @@ impl From<CompilationMode> for wasmi::CompilationMode {
 }
 
 impl Args {
+    /// Returns the subcommand given to the CLI app if any.
+    pub fn command(&self) -> Option<&Command> {
+        self.command.as_ref()
+    }
+
     /// Returns the Wasm file path given to the CLI app.
     pub fn wasm_file(&self) -> &Path {
-        &self.wasm_file
+        match &self.command {
+            Some(Command::Repl { wasm_file } | Command::Inspect { wasm_file, .. }) => wasm_file,
+            None => self
+                .wasm_file
+                .as_deref()
+                .expect("the Wasm file is required without subcommand"),
+        }
+    }
+
+    /// Returns the names and file paths of the Wasm modules to preload in order.
+    pub fn preloads(&self) -> impl Iterator<Item = (&str, &Path)> {
+        self.preloads
+            .iter()
+            .map(|KeyValue { key, value }| (key.as_str(), Path::new(value)))
     }
 
     /// Returns the name of the invoked function if any.
@@ -151,6 +244,11 @@ impl Args {
         self.verbose
     }
 
+    /// Returns the path to write a Wasm coredump to upon traps if any.
+    pub fn coredump_on_trap(&self) -> Option<&Path> {
+        self.coredump_on_trap.as_deref()
+    }
+
     /// Pre-opens all directories given in `--dir` and returns them for use by the [`WasiCtx`].
     ///
     /// # Errors
@@ -196,7 +294,7 @@ impl Args {
         // The WebAssembly filename is expected to be the first argument to WASI.
         // Note that the module name still has it's `.wasm` file extension.
         let module_name = self
-            .wasm_file
+            .wasm_file()
             .file_name()
             .and_then(OsStr::to_str)
             .unwrap_or("")
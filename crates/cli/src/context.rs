@@ -1,7 +1,20 @@
-use crate::utils;
-use anyhow::{anyhow, Error};
+use crate::{args::Args, display::DisplayExternType, utils};
+use anyhow::{anyhow, bail, Error};
 use std::path::Path;
-use wasmi::{CompilationMode, Config, ExternType, Func, FuncType, Instance, Module, Store};
+use wasmi::{
+    CompilationMode,
+    Config,
+    ExternType,
+    Func,
+    FuncType,
+    Global,
+    GlobalType,
+    Instance,
+    Linker,
+    Memory,
+    Module,
+    Store,
+};
 use wasmi_wasi::WasiCtx;
 
 /// The [`Context`] for the Wasmi CLI application.
@@ -19,26 +32,30 @@ pub struct Context {
 impl Context {
     /// Creates a new [`Context`].
     ///
+    /// Each of the `preloads` is instantiated in order and registered under its name
+    /// so that the main Wasm module and all subsequent `preloads` may import its exports.
+    ///
     /// # Errors
     ///
-    /// - If parsing, validating, compiling or instantiating the Wasm module failed.
+    /// - If parsing, validating, compiling or instantiating any Wasm module failed.
+    /// - If any Wasm module has imports that cannot be resolved.
     /// - If adding WASI defintions to the linker failed.
     pub fn new(
         wasm_file: &Path,
+        preloads: &[(&str, &Path)],
         wasi_ctx: WasiCtx,
         fuel: Option<u64>,
         compilation_mode: CompilationMode,
+        coredump_on_trap: bool,
     ) -> Result<Self, Error> {
         let mut config = Config::default();
         if fuel.is_some() {
             config.consume_fuel(true);
         }
         config.compilation_mode(compilation_mode);
+        config.wasm_backtrace(true);
+        config.coredump_on_trap(coredump_on_trap);
         let engine = wasmi::Engine::new(&config);
-        let wasm_bytes = utils::read_wasm_or_wat(wasm_file)?;
-        let module = wasmi::Module::new(&engine, &wasm_bytes[..]).map_err(|error| {
-            anyhow!("failed to parse and validate Wasm module {wasm_file:?}: {error}")
-        })?;
         let mut store = wasmi::Store::new(&engine, wasi_ctx);
         if let Some(fuel) = fuel {
             store.set_fuel(fuel).unwrap_or_else(|error| {
@@ -48,10 +65,17 @@ impl Context {
         let mut linker = <wasmi::Linker<WasiCtx>>::new(&engine);
         wasmi_wasi::add_to_linker(&mut linker, |ctx| ctx)
             .map_err(|error| anyhow!("failed to add WASI definitions to the linker: {error}"))?;
-        let instance = linker
-            .instantiate(&mut store, &module)
-            .and_then(|pre| pre.start(&mut store))
-            .map_err(|error| anyhow!("failed to instantiate and start the Wasm module: {error}"))?;
+        for &(name, preload_file) in preloads {
+            let preload = Self::compile(&engine, preload_file)?;
+            let instance = Self::instantiate(&linker, &mut store, preload_file, &preload)?;
+            linker
+                .instance(&mut store, name, instance)
+                .map_err(|error| {
+                    anyhow!("failed to register Wasm module {preload_file:?} as {name:?}: {error}")
+                })?;
+        }
+        let module = Self::compile(&engine, wasm_file)?;
+        let instance = Self::instantiate(&linker, &mut store, wasm_file, &module)?;
         Ok(Self {
             module,
             store,
@@ -59,6 +83,70 @@ impl Context {
         })
     }
 
+    /// Reads, parses, validates and compiles the Wasm module at `wasm_file`.
+    ///
+    /// # Errors
+    ///
+    /// If reading, parsing, validating or compiling the Wasm module failed.
+    fn compile(engine: &wasmi::Engine, wasm_file: &Path) -> Result<Module, Error> {
+        let wasm_bytes = utils::read_wasm_or_wat(wasm_file)?;
+        wasmi::Module::new(engine, &wasm_bytes[..]).map_err(|error| {
+            anyhow!("failed to parse and validate Wasm module {wasm_file:?}: {error}")
+        })
+    }
+
+    /// Instantiates and starts the Wasm `module` loaded from `wasm_file` using `linker`.
+    ///
+    /// # Errors
+    ///
+    /// - If any import of the Wasm `module` cannot be resolved by the `linker`.
+    /// - If instantiating or starting the Wasm `module` failed.
+    fn instantiate(
+        linker: &Linker<WasiCtx>,
+        store: &mut Store<WasiCtx>,
+        wasm_file: &Path,
+        module: &Module,
+    ) -> Result<Instance, Error> {
+        let unresolved = module
+            .imports()
+            .filter(|import| !linker.has_definition(import.module(), import.name()))
+            .map(|import| {
+                let name = format!("{}::{}", import.module(), import.name());
+                format!("\n - {}", DisplayExternType::new(&name, import.ty()))
+            })
+            .collect::<String>();
+        if !unresolved.is_empty() {
+            bail!(
+                "failed to instantiate Wasm module {wasm_file:?} due to unresolved imports:{unresolved}\n\
+                note: use `--preload NAME=MODULE` to provide the imports of module NAME"
+            )
+        }
+        linker
+            .instantiate(&mut *store, module)
+            .and_then(|pre| pre.start(&mut *store))
+            .map_err(|error| {
+                anyhow!("failed to instantiate and start the Wasm module {wasm_file:?}: {error}")
+            })
+    }
+
+    /// Creates a new [`Context`] as configured by the CLI `args`.
+    ///
+    /// # Errors
+    ///
+    /// - If setting up the WASI context failed.
+    /// - If creating the [`Context`] failed. See [`Context::new`].
+    pub fn from_args(args: &Args) -> Result<Self, Error> {
+        let preloads = args.preloads().collect::<Vec<_>>();
+        Self::new(
+            args.wasm_file(),
+            &preloads,
+            args.wasi_context()?,
+            args.fuel(),
+            args.compilation_mode(),
+            args.coredump_on_trap().is_some(),
+        )
+    }
+
     /// Returns the exported named functions of the Wasm [`Module`].
     ///
     /// [`Module`]: wasmi::Module
@@ -72,6 +160,31 @@ impl Context {
         })
     }
 
+    /// Returns the exported named global variables of the Wasm [`Module`].
+    ///
+    /// [`Module`]: wasmi::Module
+    pub fn exported_globals(&self) -> impl Iterator<Item = (&str, GlobalType)> {
+        self.module.exports().filter_map(|export| {
+            let name = export.name();
+            match export.ty() {
+                ExternType::Global(global_type) => Some((name, *global_type)),
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the names of the exported linear memories of the Wasm [`Module`].
+    ///
+    /// [`Module`]: wasmi::Module
+    pub fn exported_memories(&self) -> impl Iterator<Item = &str> {
+        self.module
+            .exports()
+            .filter_map(|export| match export.ty() {
+                ExternType::Memory(_) => Some(export.name()),
+                _ => None,
+            })
+    }
+
     /// Returns a shared reference to the [`Store`] of the [`Context`].
     pub fn store(&self) -> &Store<WasiCtx> {
         &self.store
@@ -88,4 +201,18 @@ impl Context {
             .get_func(&self.store, name)
             .ok_or_else(|| anyhow!("failed to find function named {name:?} in the Wasm module"))
     }
+
+    /// Returns the exported global variable named `name` if any.
+    pub fn get_global(&self, name: &str) -> Result<Global, Error> {
+        self.instance
+            .get_global(&self.store, name)
+            .ok_or_else(|| anyhow!("failed to find global named {name:?} in the Wasm module"))
+    }
+
+    /// Returns the exported linear memory named `name` if any.
+    pub fn get_memory(&self, name: &str) -> Result<Memory, Error> {
+        self.instance
+            .get_memory(&self.store, name)
+            .ok_or_else(|| anyhow!("failed to find memory named {name:?} in the Wasm module"))
+    }
 }
@@ -1,6 +1,15 @@
 use crate::context::Context;
 use std::fmt::{self, Display};
-use wasmi::{core::ValType, FuncType, Val};
+use wasmi::{
+    core::{HeapType, Pages, ValType},
+    ExternType,
+    FuncType,
+    GlobalType,
+    MemoryType,
+    Mutability,
+    TableType,
+    Val,
+};
 
 /// [`Display`]-wrapper type for [`ValType`].
 pub struct DisplayValueType<'a>(&'a ValType);
@@ -20,6 +29,24 @@ impl Display for DisplayValueType<'_> {
             ValType::F64 => write!(f, "f64"),
             ValType::FuncRef => write!(f, "funcref"),
             ValType::ExternRef => write!(f, "externref"),
+            ValType::Ref(ref_type) => {
+                let null = if ref_type.is_nullable() { "null " } else { "" };
+                match ref_type.heap_type() {
+                    HeapType::Func => write!(f, "(ref {null}func)"),
+                    HeapType::Extern => write!(f, "(ref {null}extern)"),
+                    HeapType::Any => write!(f, "(ref {null}any)"),
+                    HeapType::Eq => write!(f, "(ref {null}eq)"),
+                    HeapType::I31 => write!(f, "(ref {null}i31)"),
+                    HeapType::Struct => write!(f, "(ref {null}struct)"),
+                    HeapType::Array => write!(f, "(ref {null}array)"),
+                    HeapType::None => write!(f, "(ref {null}none)"),
+                    HeapType::NoFunc => write!(f, "(ref {null}nofunc)"),
+                    HeapType::NoExtern => write!(f, "(ref {null}noextern)"),
+                    HeapType::Cont => write!(f, "(ref {null}cont)"),
+                    HeapType::NoCont => write!(f, "(ref {null}nocont)"),
+                    HeapType::Concrete(id) => write!(f, "(ref {null}{})", id.into_u32()),
+                }
+            }
         }
     }
 }
@@ -44,6 +71,8 @@ impl fmt::Display for DisplayValue<'_> {
             Val::ExternRef(value) => {
                 panic!("cannot display externref values but found {value:?}")
             }
+            Val::AnyRef(value) => panic!("cannot display anyref values but found {value:?}"),
+            Val::ContRef(value) => panic!("cannot display contref values but found {value:?}"),
         }
     }
 }
@@ -104,6 +133,115 @@ impl fmt::Display for DisplayFuncType<'_> {
     }
 }
 
+/// [`Display`]-wrapper type for the minimum and optional maximum limits of a type.
+struct DisplayLimits {
+    minimum: u32,
+    maximum: Option<u32>,
+}
+
+impl Display for DisplayLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.maximum {
+            Some(maximum) => write!(f, "{}..={maximum}", self.minimum),
+            None => write!(f, "{}..", self.minimum),
+        }
+    }
+}
+
+/// [`Display`]-wrapper type for [`TableType`].
+pub struct DisplayTableType<'a>(&'a TableType);
+
+impl<'a> From<&'a TableType> for DisplayTableType<'a> {
+    fn from(table_type: &'a TableType) -> Self {
+        Self(table_type)
+    }
+}
+
+impl Display for DisplayTableType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limits = DisplayLimits {
+            minimum: self.0.minimum(),
+            maximum: self.0.maximum(),
+        };
+        write!(
+            f,
+            "{} {limits} elements",
+            DisplayValueType::from(&self.0.element())
+        )
+    }
+}
+
+/// [`Display`]-wrapper type for [`MemoryType`].
+pub struct DisplayMemoryType<'a>(&'a MemoryType);
+
+impl<'a> From<&'a MemoryType> for DisplayMemoryType<'a> {
+    fn from(memory_type: &'a MemoryType) -> Self {
+        Self(memory_type)
+    }
+}
+
+impl Display for DisplayMemoryType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limits = DisplayLimits {
+            minimum: u32::from(self.0.initial_pages()),
+            maximum: self.0.maximum_pages().map(u32::from),
+        };
+        write!(f, "{limits} pages")?;
+        if self.0.page_size_log2() != Pages::DEFAULT_PAGE_SIZE_LOG2 {
+            write!(f, " of size {}", self.0.page_size())?;
+        }
+        Ok(())
+    }
+}
+
+/// [`Display`]-wrapper type for [`GlobalType`].
+pub struct DisplayGlobalType<'a>(&'a GlobalType);
+
+impl<'a> From<&'a GlobalType> for DisplayGlobalType<'a> {
+    fn from(global_type: &'a GlobalType) -> Self {
+        Self(global_type)
+    }
+}
+
+impl Display for DisplayGlobalType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mutability = match self.0.mutability() {
+            Mutability::Const => "const",
+            Mutability::Var => "mut",
+        };
+        write!(
+            f,
+            "{mutability} {}",
+            DisplayValueType::from(&self.0.content())
+        )
+    }
+}
+
+/// [`Display`]-wrapper type around a named [`ExternType`].
+pub struct DisplayExternType<'a> {
+    name: &'a str,
+    extern_type: &'a ExternType,
+}
+
+impl<'a> DisplayExternType<'a> {
+    /// Creates a named [`DisplayExternType`] for the given [`ExternType`].
+    pub fn new(name: &'a str, extern_type: &'a ExternType) -> Self {
+        Self { name, extern_type }
+    }
+}
+
+impl Display for DisplayExternType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name;
+        match self.extern_type {
+            ExternType::Func(ty) => write!(f, "{}", DisplayFuncType::new(name, ty)),
+            ExternType::Table(ty) => write!(f, "table {name}: {}", DisplayTableType::from(ty)),
+            ExternType::Memory(ty) => write!(f, "memory {name}: {}", DisplayMemoryType::from(ty)),
+            ExternType::Global(ty) => write!(f, "global {name}: {}", DisplayGlobalType::from(ty)),
+        }
+    }
+}
+
 /// [`Display`]-wrapper for generic sequences with separators.
 #[derive(Debug)]
 pub struct DisplaySequence<'a, T> {
@@ -168,3 +306,45 @@ impl Display for DisplayExportedFuncs<'_> {
         Ok(())
     }
 }
+
+/// [`Display`]-wrapper for a hex dump of linear memory contents.
+///
+/// Each line displays up to 16 bytes prefixed by their address
+/// and followed by their printable ASCII representation.
+pub struct DisplayHexDump<'a> {
+    /// The address of the first displayed byte.
+    address: usize,
+    /// The displayed bytes.
+    bytes: &'a [u8],
+}
+
+impl<'a> DisplayHexDump<'a> {
+    /// Creates a new [`DisplayHexDump`] for `bytes` starting at `address`.
+    pub fn new(address: usize, bytes: &'a [u8]) -> Self {
+        Self { address, bytes }
+    }
+}
+
+impl Display for DisplayHexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// The number of bytes displayed per line.
+        const BYTES_PER_LINE: usize = 16;
+        for (n, line) in self.bytes.chunks(BYTES_PER_LINE).enumerate() {
+            write!(f, "{:08x}:", self.address + n * BYTES_PER_LINE)?;
+            for byte in line {
+                write!(f, " {byte:02x}")?;
+            }
+            let padding = 3 * (BYTES_PER_LINE - line.len());
+            write!(f, "{:padding$}  |", "")?;
+            for &byte in line {
+                let ch = match byte.is_ascii_graphic() || byte == b' ' {
+                    true => char::from(byte),
+                    false => '.',
+                };
+                write!(f, "{ch}")?;
+            }
+            writeln!(f, "|")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{
+    args::Args,
+    display::{
+        DisplayExternType,
+        DisplayFuncType,
+        DisplayGlobalType,
+        DisplayMemoryType,
+        DisplayTableType,
+        DisplayValueType,
+    },
+    utils,
+};
+use anyhow::{anyhow, Error, Result};
+use serde_json::{json, Value};
+use std::path::Path;
+use wasmi::{
+    core::ValType,
+    CompilationMode,
+    Config,
+    Engine,
+    ExternType,
+    FuncType,
+    GlobalType,
+    MemoryType,
+    Module,
+    TableType,
+};
+
+/// Prints information about the Wasm module given by `args` without executing it.
+///
+/// - Prints the information as JSON if `json` is `true`.
+/// - Includes the disassembled Wasmi bytecode of all functions if `ir` is `true`.
+///
+/// # Errors
+///
+/// If parsing, validating or translating the Wasm module failed.
+pub fn run(args: &Args, json: bool, ir: bool) -> Result<()> {
+    let wasm_file = args.wasm_file();
+    let module = compile(wasm_file, args.compilation_mode())?;
+    let inspection = Inspection::new(&module, ir)?;
+    if json {
+        println!("{:#}", inspection.to_json(wasm_file));
+    } else {
+        inspection.print(wasm_file);
+    }
+    Ok(())
+}
+
+/// Reads, parses, validates and compiles the Wasm module at `wasm_file`.
+///
+/// # Note
+///
+/// Wasm backtraces are enabled so that function names are available.
+///
+/// # Errors
+///
+/// If reading, parsing, validating or compiling the Wasm module failed.
+fn compile(wasm_file: &Path, compilation_mode: CompilationMode) -> Result<Module, Error> {
+    let mut config = Config::default();
+    config.compilation_mode(compilation_mode);
+    config.wasm_backtrace(true);
+    let engine = Engine::new(&config);
+    let wasm_bytes = utils::read_wasm_or_wat(wasm_file)?;
+    Module::new(&engine, &wasm_bytes[..])
+        .map_err(|error| anyhow!("failed to parse and validate Wasm module {wasm_file:?}: {error}"))
+}
+
+/// The number of imported items per kind of a Wasm module.
+#[derive(Debug, Default)]
+struct LenImports {
+    funcs: usize,
+    tables: usize,
+    memories: usize,
+    globals: usize,
+}
+
+/// Information about a single function of a Wasm module.
+struct FuncInfo<'a> {
+    /// The name of the function if any.
+    name: Option<&'a str>,
+    /// The type of the function.
+    ty: FuncType,
+    /// The size of the translated Wasmi bytecode or `None` if the function is imported.
+    bytecode_size: Option<usize>,
+    /// The disassembled Wasmi bytecode if requested and the function is not imported.
+    ir: Option<String>,
+}
+
+/// The gathered information about a Wasm module.
+struct Inspection<'a> {
+    /// The inspected Wasm module.
+    module: &'a Module,
+    /// The number of imported items per kind.
+    len_imports: LenImports,
+    /// Information about all functions in index order.
+    funcs: Vec<FuncInfo<'a>>,
+}
+
+impl<'a> Inspection<'a> {
+    /// Gathers information about the Wasm `module`.
+    ///
+    /// Disassembles the Wasmi bytecode of all functions if `ir` is `true`.
+    ///
+    /// # Note
+    ///
+    /// This translates all functions of the Wasm `module` that have not yet been translated.
+    ///
+    /// # Errors
+    ///
+    /// If translating any function of the Wasm `module` failed.
+    fn new(module: &'a Module, ir: bool) -> Result<Self, Error> {
+        let mut len_imports = LenImports::default();
+        for import in module.imports() {
+            match import.ty() {
+                ExternType::Func(_) => len_imports.funcs += 1,
+                ExternType::Table(_) => len_imports.tables += 1,
+                ExternType::Memory(_) => len_imports.memories += 1,
+                ExternType::Global(_) => len_imports.globals += 1,
+            }
+        }
+        let funcs = module
+            .funcs()
+            .zip(0_u32..)
+            .map(|(ty, index)| {
+                let translation_error =
+                    |error| anyhow!("failed to translate function at index {index}: {error}");
+                let bytecode_size = module
+                    .compiled_func_size(index)
+                    .map_err(translation_error)?;
+                let ir = match ir {
+                    true => module.disassemble(index).map_err(translation_error)?,
+                    false => None,
+                };
+                Ok(FuncInfo {
+                    name: module.get_func_name(index),
+                    ty,
+                    bytecode_size,
+                    ir,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self {
+            module,
+            len_imports,
+            funcs,
+        })
+    }
+
+    /// Prints the gathered information in a human readable form.
+    fn print(&self, wasm_file: &Path) {
+        let module = self.module;
+        println!("Module {wasm_file:?}");
+        println!("\nImports ({}):", module.imports().len());
+        for import in module.imports() {
+            let name = format!("{}::{}", import.module(), import.name());
+            println!(" - {}", DisplayExternType::new(&name, import.ty()));
+        }
+        println!("\nExports ({}):", module.exports().count());
+        for export in module.exports() {
+            println!(" - {}", DisplayExternType::new(export.name(), export.ty()));
+        }
+        println!("\nFunctions ({}):", self.funcs.len());
+        for (index, func) in self.funcs.iter().enumerate() {
+            let ty = match func.name {
+                Some(name) => DisplayFuncType::new(name, &func.ty),
+                None => DisplayFuncType::from(&func.ty),
+            };
+            match func.bytecode_size {
+                Some(size) => println!(" - func[{index}]: {ty} ({size} bytes)"),
+                None => println!(" - func[{index}]: {ty} (imported)"),
+            }
+        }
+        for (index, func) in self.funcs.iter().enumerate() {
+            let Some(ir) = &func.ir else { continue };
+            match func.name {
+                Some(name) => println!("\nDisassembly of func[{index}] {name}:"),
+                None => println!("\nDisassembly of func[{index}]:"),
+            }
+            for line in ir.lines() {
+                println!("  {line}");
+            }
+        }
+        println!("\nTables ({}):", module.tables().len());
+        for (index, ty) in module.tables().enumerate() {
+            let imported = imported_note(index, self.len_imports.tables);
+            println!(
+                " - table[{index}]: {}{imported}",
+                DisplayTableType::from(ty)
+            );
+        }
+        println!("\nMemories ({}):", module.memories().len());
+        for (index, ty) in module.memories().enumerate() {
+            let imported = imported_note(index, self.len_imports.memories);
+            println!(
+                " - memory[{index}]: {}{imported}",
+                DisplayMemoryType::from(ty)
+            );
+        }
+        println!("\nGlobals ({}):", module.globals().len());
+        for (index, ty) in module.globals().enumerate() {
+            let imported = imported_note(index, self.len_imports.globals);
+            println!(
+                " - global[{index}]: {}{imported}",
+                DisplayGlobalType::from(ty)
+            );
+        }
+        match module.start_func() {
+            Some(index) => println!("\nStart function: func[{index}]"),
+            None => println!("\nStart function: none"),
+        }
+        println!("\nCustom sections ({}):", module.custom_sections().count());
+        for section in module.custom_sections() {
+            println!(" - {:?} ({} bytes)", section.name(), section.data().len());
+        }
+    }
+
+    /// Returns the gathered information as JSON [`Value`].
+    fn to_json(&self, wasm_file: &Path) -> Value {
+        let module = self.module;
+        let imports = module
+            .imports()
+            .map(|import| {
+                let mut value = extern_type_to_json(import.ty());
+                value["module"] = json!(import.module());
+                value["name"] = json!(import.name());
+                value
+            })
+            .collect::<Vec<_>>();
+        let exports = module
+            .exports()
+            .map(|export| {
+                let mut value = extern_type_to_json(export.ty());
+                value["name"] = json!(export.name());
+                value
+            })
+            .collect::<Vec<_>>();
+        let funcs = self
+            .funcs
+            .iter()
+            .enumerate()
+            .map(|(index, func)| {
+                let mut value = func_type_to_json(&func.ty);
+                value["index"] = json!(index);
+                value["name"] = json!(func.name);
+                value["imported"] = json!(index < self.len_imports.funcs);
+                value["bytecode_size"] = json!(func.bytecode_size);
+                if let Some(ir) = &func.ir {
+                    value["ir"] = json!(ir);
+                }
+                value
+            })
+            .collect::<Vec<_>>();
+        let tables = module
+            .tables()
+            .enumerate()
+            .map(|(index, ty)| {
+                let mut value = table_type_to_json(ty);
+                value["index"] = json!(index);
+                value["imported"] = json!(index < self.len_imports.tables);
+                value
+            })
+            .collect::<Vec<_>>();
+        let memories = module
+            .memories()
+            .enumerate()
+            .map(|(index, ty)| {
+                let mut value = memory_type_to_json(ty);
+                value["index"] = json!(index);
+                value["imported"] = json!(index < self.len_imports.memories);
+                value
+            })
+            .collect::<Vec<_>>();
+        let globals = module
+            .globals()
+            .enumerate()
+            .map(|(index, ty)| {
+                let mut value = global_type_to_json(ty);
+                value["index"] = json!(index);
+                value["imported"] = json!(index < self.len_imports.globals);
+                value
+            })
+            .collect::<Vec<_>>();
+        let custom_sections = module
+            .custom_sections()
+            .map(|section| json!({ "name": section.name(), "size": section.data().len() }))
+            .collect::<Vec<_>>();
+        json!({
+            "file": wasm_file,
+            "imports": imports,
+            "exports": exports,
+            "funcs": funcs,
+            "tables": tables,
+            "memories": memories,
+            "globals": globals,
+            "start_func": module.start_func(),
+            "custom_sections": custom_sections,
+        })
+    }
+}
+
+/// Returns the note appended to the item at `index` if it is imported.
+fn imported_note(index: usize, len_imported: usize) -> &'static str {
+    if index < len_imported {
+        " (imported)"
+    } else {
+        ""
+    }
+}
+
+/// Returns the name of the [`ValType`] as JSON [`Value`].
+fn value_type_to_json(ty: &ValType) -> Value {
+    json!(DisplayValueType::from(ty).to_string())
+}
+
+/// Returns the [`ExternType`] as JSON [`Value`].
+fn extern_type_to_json(ty: &ExternType) -> Value {
+    let (kind, mut value) = match ty {
+        ExternType::Func(ty) => ("func", func_type_to_json(ty)),
+        ExternType::Table(ty) => ("table", table_type_to_json(ty)),
+        ExternType::Memory(ty) => ("memory", memory_type_to_json(ty)),
+        ExternType::Global(ty) => ("global", global_type_to_json(ty)),
+    };
+    value["kind"] = json!(kind);
+    value
+}
+
+/// Returns the [`FuncType`] as JSON [`Value`].
+fn func_type_to_json(ty: &FuncType) -> Value {
+    let params = ty.params().iter().map(value_type_to_json);
+    let results = ty.results().iter().map(value_type_to_json);
+    json!({
+        "params": params.collect::<Vec<_>>(),
+        "results": results.collect::<Vec<_>>(),
+    })
+}
+
+/// Returns the [`TableType`] as JSON [`Value`].
+fn table_type_to_json(ty: &TableType) -> Value {
+    json!({
+        "element": value_type_to_json(&ty.element()),
+        "minimum": ty.minimum(),
+        "maximum": ty.maximum(),
+    })
+}
+
+/// Returns the [`MemoryType`] as JSON [`Value`].
+fn memory_type_to_json(ty: &MemoryType) -> Value {
+    json!({
+        "minimum": u32::from(ty.initial_pages()),
+        "maximum": ty.maximum_pages().map(u32::from),
+        "page_size": ty.page_size(),
+    })
+}
+
+/// Returns the [`GlobalType`] as JSON [`Value`].
+fn global_type_to_json(ty: &GlobalType) -> Value {
+    json!({
+        "content": value_type_to_json(&ty.content()),
+        "mutable": ty.mutability().is_mut(),
+    })
+}
@@ -1,16 +1,18 @@
 use crate::{
-    args::Args,
+    args::{Args, Command},
     display::{DisplayExportedFuncs, DisplayFuncType, DisplaySequence, DisplayValue},
 };
 use anyhow::{anyhow, bail, Error, Result};
 use clap::Parser;
 use context::Context;
-use std::{path::Path, process};
+use std::{fs, path::Path, process};
 use wasmi::{Func, FuncType, Val};
 
 mod args;
 mod context;
 mod display;
+mod inspect;
+mod repl;
 mod utils;
 
 #[cfg(test)]
@@ -18,9 +20,13 @@ mod tests;
 
 fn main() -> Result<()> {
     let args = Args::parse();
+    match args.command() {
+        Some(Command::Repl { .. }) => return repl::run(&args),
+        Some(Command::Inspect { json, ir, .. }) => return inspect::run(&args, *json, *ir),
+        None => {}
+    }
     let wasm_file = args.wasm_file();
-    let wasi_ctx = args.wasi_context()?;
-    let mut ctx = Context::new(wasm_file, wasi_ctx, args.fuel(), args.compilation_mode())?;
+    let mut ctx = Context::from_args(&args)?;
     let (func_name, func) = get_invoked_func(&args, &ctx)?;
     let ty = func.ty(ctx.store());
     let func_args = utils::decode_func_args(&ty, args.func_args())?;
@@ -54,6 +60,9 @@ fn main() -> Result<()> {
                 print_pretty_results(&func_results);
                 process::exit(exit_code)
             }
+            if let Some(path) = args.coredump_on_trap() {
+                write_coredump(path, wasm_file, &error);
+            }
             bail!("failed during execution of {func_name}: {error}")
         }
     }
@@ -71,6 +80,24 @@ fn print_remaining_fuel(args: &Args, ctx: &Context) {
     }
 }
 
+/// Writes the Wasm coredump captured by `error` to `path` if any.
+///
+/// # Note
+///
+/// Failing to write the coredump is reported but does not
+/// shadow the original `error` of the Wasm execution.
+fn write_coredump(path: &Path, wasm_file: &Path, error: &wasmi::Error) {
+    let Some(coredump) = error.coredump() else {
+        return;
+    };
+    let name = wasm_file.file_name().unwrap_or(wasm_file.as_os_str());
+    let bytes = coredump.serialize(&name.to_string_lossy());
+    match fs::write(path, bytes) {
+        Ok(()) => eprintln!("wrote Wasm coredump to {path:?}"),
+        Err(write_error) => eprintln!("failed to write Wasm coredump to {path:?}: {write_error}"),
+    }
+}
+
 /// Performs minor typecheck on the function signature.
 ///
 /// # Note
@@ -0,0 +1,250 @@
+use crate::{
+    args::Args,
+    context::Context,
+    display::{
+        DisplayExportedFuncs,
+        DisplayFuncType,
+        DisplayGlobalType,
+        DisplayHexDump,
+        DisplaySequence,
+        DisplayValue,
+    },
+    utils,
+};
+use anyhow::{anyhow, bail, Error, Result};
+use std::{
+    io::{self, BufRead, Write},
+    ops::ControlFlow,
+};
+
+/// The help message listing all commands of the REPL.
+const HELP: &str = "\
+Commands:
+  call <func> [args...]           call the exported function <func> with typed [args...]
+  funcs                           list all exported functions
+  globals                         list all exported global variables and their values
+  global <name> [value]           show or set the value of the exported global variable <name>
+  memories                        list all exported linear memories and their sizes
+  memory <name> <offset> <len>    hex dump <len> bytes of the exported memory <name> at <offset>
+  grow <name> <pages>             grow the exported memory <name> by <pages>
+  fuel                            show the remaining fuel
+  reload                          reload and re-instantiate the Wasm module
+  help                            show this help message
+  exit                            exit the REPL";
+
+/// Runs an interactive REPL session for the Wasm module given by `args`.
+///
+/// Commands are read line by line from `stdin` until `exit` or end of input.
+///
+/// # Errors
+///
+/// - If the Wasm module cannot be instantiated.
+/// - If reading from `stdin` or writing to `stdout` failed.
+pub fn run(args: &Args) -> Result<()> {
+    let mut repl = Repl {
+        args,
+        ctx: Context::from_args(args)?,
+    };
+    println!(
+        "Wasmi REPL for {:?}. Type `help` for a list of commands.",
+        args.wasm_file()
+    );
+    let mut stdin = io::stdin().lock();
+    let mut line = String::new();
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+        line.clear();
+        if stdin.read_line(&mut line)? == 0 {
+            // Note: end of input was reached.
+            println!();
+            return Ok(());
+        }
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+        let params = words.collect::<Vec<_>>();
+        match repl.execute(command, &params) {
+            Ok(ControlFlow::Continue(())) => {}
+            Ok(ControlFlow::Break(())) => return Ok(()),
+            Err(error) => println!("error: {error}"),
+        }
+    }
+}
+
+/// The state of an interactive REPL session.
+struct Repl<'a> {
+    /// The CLI arguments used to (re-)instantiate the Wasm module.
+    args: &'a Args,
+    /// The [`Context`] holding the instantiated Wasm module.
+    ctx: Context,
+}
+
+impl Repl<'_> {
+    /// Executes the REPL `command` with its `params`.
+    ///
+    /// Returns [`ControlFlow::Break`] if the REPL session shall end.
+    ///
+    /// # Errors
+    ///
+    /// If the `command` is unknown or failed to execute.
+    fn execute(&mut self, command: &str, params: &[&str]) -> Result<ControlFlow<()>> {
+        match (command, params) {
+            ("exit" | "quit", []) => return Ok(ControlFlow::Break(())),
+            ("help", []) => println!("{HELP}"),
+            ("call", [func, args @ ..]) => self.call(func, args)?,
+            ("funcs", []) => println!("{}", DisplayExportedFuncs::from(&self.ctx)),
+            ("globals", []) => self.globals()?,
+            ("global", [name]) => self.global(name)?,
+            ("global", [name, value]) => self.set_global(name, value)?,
+            ("memories", []) => self.memories()?,
+            ("memory", [name, offset, len]) => self.memory(name, offset, len)?,
+            ("grow", [name, pages]) => self.grow(name, pages)?,
+            ("fuel", []) => self.fuel()?,
+            ("reload", []) => self.reload()?,
+            (
+                "exit" | "quit" | "help" | "call" | "funcs" | "globals" | "global" | "memories"
+                | "memory" | "grow" | "fuel" | "reload",
+                _,
+            ) => {
+                bail!("invalid parameters for `{command}`. Type `help` for a list of commands.")
+            }
+            _ => bail!("unknown command `{command}`. Type `help` for a list of commands."),
+        }
+        Ok(ControlFlow::Continue(()))
+    }
+
+    /// Calls the exported function `name` with the typed `args` and prints its results.
+    fn call(&mut self, name: &str, args: &[&str]) -> Result<()> {
+        let func = self.ctx.get_func(name)?;
+        let ty = func.ty(self.ctx.store());
+        if ty.params().len() != args.len() {
+            bail!(
+                "invalid amount of arguments given to function {}. expected {} but received {}",
+                DisplayFuncType::new(name, &ty),
+                ty.params().len(),
+                args.len()
+            )
+        }
+        let func_args = utils::decode_func_args(&ty, args)?;
+        let mut func_results = utils::prepare_func_results(&ty);
+        func.call(self.ctx.store_mut(), &func_args, &mut func_results)
+            .map_err(|error| anyhow!("failed during execution of {name}: {error}"))?;
+        println!(
+            "[{}]",
+            DisplaySequence::new(", ", func_results.iter().map(DisplayValue::from))
+        );
+        Ok(())
+    }
+
+    /// Prints all exported global variables and their values.
+    fn globals(&self) -> Result<()> {
+        for (name, ty) in self.ctx.exported_globals() {
+            let value = self.ctx.get_global(name)?.get(self.ctx.store());
+            println!(
+                " - {name}: {} = {}",
+                DisplayGlobalType::from(&ty),
+                DisplayValue::from(&value)
+            );
+        }
+        Ok(())
+    }
+
+    /// Prints the value of the exported global variable `name`.
+    fn global(&self, name: &str) -> Result<()> {
+        let value = self.ctx.get_global(name)?.get(self.ctx.store());
+        println!("{}", DisplayValue::from(&value));
+        Ok(())
+    }
+
+    /// Sets the value of the exported global variable `name` to `value`.
+    fn set_global(&mut self, name: &str, value: &str) -> Result<()> {
+        let global = self.ctx.get_global(name)?;
+        let ty = global.ty(self.ctx.store());
+        let value = utils::decode_value(ty.content(), value)?;
+        global
+            .set(self.ctx.store_mut(), value)
+            .map_err(|error| anyhow!("failed to set global {name:?}: {error}"))?;
+        Ok(())
+    }
+
+    /// Prints all exported linear memories and their sizes.
+    fn memories(&self) -> Result<()> {
+        for name in self.ctx.exported_memories() {
+            let memory = self.ctx.get_memory(name)?;
+            let pages = memory.size(self.ctx.store());
+            let bytes = memory.data_size(self.ctx.store());
+            println!(" - {name}: {pages} pages ({bytes} bytes)");
+        }
+        Ok(())
+    }
+
+    /// Prints a hex dump of `len` bytes at `offset` of the exported linear memory `name`.
+    fn memory(&self, name: &str, offset: &str, len: &str) -> Result<()> {
+        let memory = self.ctx.get_memory(name)?;
+        let offset = parse_usize(offset)?;
+        let len = parse_usize(len)?;
+        let data = memory.data(self.ctx.store());
+        let Some(bytes) = offset
+            .checked_add(len)
+            .and_then(|end| data.get(offset..end))
+        else {
+            bail!(
+                "memory range {offset}..{offset}+{len} is out of bounds for memory of {} bytes",
+                data.len()
+            )
+        };
+        print!("{}", DisplayHexDump::new(offset, bytes));
+        Ok(())
+    }
+
+    /// Grows the exported linear memory `name` by `pages` and prints its previous size.
+    fn grow(&mut self, name: &str, pages: &str) -> Result<()> {
+        let memory = self.ctx.get_memory(name)?;
+        let pages = parse_usize(pages)
+            .ok()
+            .and_then(|pages| u32::try_from(pages).ok())
+            .ok_or_else(|| anyhow!("invalid amount of pages: {pages}"))?;
+        let previous = memory
+            .grow(self.ctx.store_mut(), pages)
+            .map_err(|error| anyhow!("failed to grow memory {name:?}: {error}"))?;
+        println!(
+            "grew memory {name:?} from {previous} to {} pages",
+            previous + pages
+        );
+        Ok(())
+    }
+
+    /// Prints the remaining fuel if fuel metering is enabled.
+    fn fuel(&self) -> Result<()> {
+        match self.args.fuel() {
+            Some(_) => {
+                let remaining = self.ctx.store().get_fuel()?;
+                println!("fuel remaining: {remaining}");
+            }
+            None => println!("fuel metering is disabled. Use `--fuel <N>` to enable it."),
+        }
+        Ok(())
+    }
+
+    /// Reloads the Wasm module from disk and re-instantiates it.
+    ///
+    /// # Note
+    ///
+    /// This resets the state of the Wasm module instance as well as the remaining fuel.
+    fn reload(&mut self) -> Result<()> {
+        self.ctx = Context::from_args(self.args)?;
+        println!("reloaded {:?}", self.args.wasm_file());
+        Ok(())
+    }
+}
+
+/// Parses `input` as decimal or `0x`-prefixed hexadecimal `usize`.
+fn parse_usize(input: &str) -> Result<usize, Error> {
+    let parsed = match input.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => input.parse::<usize>(),
+    };
+    parsed.map_err(|error| anyhow!("failed to parse {input:?} as integer: {error}"))
+}
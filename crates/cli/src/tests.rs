@@ -1,6 +1,7 @@
 use super::*;
+use crate::display::{DisplayExternType, DisplayHexDump};
 use core::borrow::Borrow;
-use wasmi::core::ValType;
+use wasmi::{core::ValType, ExternType, GlobalType, MemoryType, Mutability, TableType};
 
 fn assert_display(func_type: impl Borrow<FuncType>, expected: &str) {
     assert_eq!(
@@ -58,3 +59,62 @@ fn display_4in_4out() {
         "fn(i32, i64, f32, f64) -> (i32, i64, f32, f64)",
     );
 }
+
+#[test]
+fn display_hex_dump() {
+    assert_eq!(
+        format!(
+            "{}",
+            DisplayHexDump::new(0x10, b"Hello, Wasmi!\0\x01\x02\xFFxyz")
+        ),
+        "00000010: 48 65 6c 6c 6f 2c 20 57 61 73 6d 69 21 00 01 02  |Hello, Wasmi!...|\n\
+         00000020: ff 78 79 7a                                      |.xyz|\n",
+    );
+}
+
+#[test]
+fn display_extern_type() {
+    fn assert_display(extern_type: impl Into<ExternType>, expected: &str) {
+        let extern_type = extern_type.into();
+        assert_eq!(
+            format!("{}", DisplayExternType::new("x", &extern_type)),
+            expected,
+        );
+    }
+    assert_display(FuncType::new([ValType::I32], []), "fn x(i32)");
+    assert_display(
+        TableType::new(ValType::FuncRef, 2, Some(10)),
+        "table x: funcref 2..=10 elements",
+    );
+    assert_display(
+        TableType::new(ValType::ExternRef, 0, None),
+        "table x: externref 0.. elements",
+    );
+    assert_display(
+        MemoryType::new(1, Some(2)).unwrap(),
+        "memory x: 1..=2 pages",
+    );
+    assert_display(MemoryType::new(3, None).unwrap(), "memory x: 3.. pages");
+    assert_display(
+        MemoryType::new_with_page_size(1, Some(2), 0).unwrap(),
+        "memory x: 1..=2 pages of size 1",
+    );
+    assert_display(
+        GlobalType::new(ValType::I64, Mutability::Const),
+        "global x: const i64",
+    );
+    assert_display(
+        GlobalType::new(ValType::F32, Mutability::Var),
+        "global x: mut f32",
+    );
+}
+
+#[test]
+fn decode_func_args_error() {
+    let ty = FuncType::new([ValType::I32, ValType::F64], []);
+    let error = utils::decode_func_args(&ty, &["1", "x"]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "failed to parse function argument x at index 1 as f64",
+    );
+}
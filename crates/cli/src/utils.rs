@@ -50,43 +50,90 @@ pub fn prepare_func_results(ty: &FuncType) -> Box<[Val]> {
 ///
 /// [`FuncRef`]: wasmi::FuncRef
 /// [`ExternRef`]: wasmi::ExternRef
-pub fn decode_func_args(ty: &FuncType, args: &[String]) -> Result<Box<[Val]>, Error> {
+pub fn decode_func_args<T>(ty: &FuncType, args: &[T]) -> Result<Box<[Val]>, Error>
+where
+    T: AsRef<str>,
+{
     ty.params()
         .iter()
         .zip(args)
         .enumerate()
         .map(|(n, (param_type, arg))| {
-            macro_rules! make_err {
-                () => {
-                    |_| {
-                        anyhow!(
-                            "failed to parse function argument \
-                            {arg} at index {n} as {}",
-                            DisplayValueType::from(param_type)
-                        )
-                    }
-                };
-            }
-            match param_type {
-                ValType::I32 => arg.parse::<i32>().map(Val::from).map_err(make_err!()),
-                ValType::I64 => arg.parse::<i64>().map(Val::from).map_err(make_err!()),
-                ValType::F32 => arg
-                    .parse::<f32>()
-                    .map(F32::from)
-                    .map(Val::from)
-                    .map_err(make_err!()),
-                ValType::F64 => arg
-                    .parse::<f64>()
-                    .map(F64::from)
-                    .map(Val::from)
-                    .map_err(make_err!()),
-                ValType::FuncRef => {
-                    bail!("the wasmi CLI cannot take arguments of type funcref")
-                }
-                ValType::ExternRef => {
-                    bail!("the wasmi CLI cannot take arguments of type externref")
-                }
-            }
+            let arg = arg.as_ref();
+            decode_value_or_else(*param_type, arg, || {
+                anyhow!(
+                    "failed to parse function argument \
+                    {arg} at index {n} as {}",
+                    DisplayValueType::from(param_type)
+                )
+            })
         })
         .collect::<Result<Box<[_]>, _>>()
 }
+
+/// Decodes the given `arg` as [`Val`] of type `ty`.
+///
+/// # Errors
+///
+/// - If `arg` cannot be parsed as a value of type `ty`.
+/// - If unsupported [`ExternRef`] or [`FuncRef`] types are encountered.
+///
+/// [`FuncRef`]: wasmi::FuncRef
+/// [`ExternRef`]: wasmi::ExternRef
+pub fn decode_value(ty: ValType, arg: &str) -> Result<Val, Error> {
+    decode_value_or_else(ty, arg, || {
+        anyhow!(
+            "failed to parse argument {arg} as {}",
+            DisplayValueType::from(&ty)
+        )
+    })
+}
+
+/// Decodes the given `arg` as [`Val`] of type `ty`.
+///
+/// Returns the error of `parse_error` if `arg` cannot be parsed as a value of type `ty`.
+///
+/// # Errors
+///
+/// - If `arg` cannot be parsed as a value of type `ty`.
+/// - If unsupported [`ExternRef`] or [`FuncRef`] types are encountered.
+///
+/// [`FuncRef`]: wasmi::FuncRef
+/// [`ExternRef`]: wasmi::ExternRef
+fn decode_value_or_else(
+    ty: ValType,
+    arg: &str,
+    parse_error: impl FnOnce() -> Error,
+) -> Result<Val, Error> {
+    macro_rules! make_err {
+        () => {
+            |_| parse_error()
+        };
+    }
+    match ty {
+        ValType::I32 => arg.parse::<i32>().map(Val::from).map_err(make_err!()),
+        ValType::I64 => arg.parse::<i64>().map(Val::from).map_err(make_err!()),
+        ValType::F32 => arg
+            .parse::<f32>()
+            .map(F32::from)
+            .map(Val::from)
+            .map_err(make_err!()),
+        ValType::F64 => arg
+            .parse::<f64>()
+            .map(F64::from)
+            .map(Val::from)
+            .map_err(make_err!()),
+        ValType::FuncRef => {
+            bail!("the wasmi CLI cannot take arguments of type funcref")
+        }
+        ValType::ExternRef => {
+            bail!("the wasmi CLI cannot take arguments of type externref")
+        }
+        ValType::Ref(_) => {
+            bail!(
+                "the wasmi CLI cannot take arguments of type {}",
+                DisplayValueType::from(&ty)
+            )
+        }
+    }
+}
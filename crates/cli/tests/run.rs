@@ -39,6 +39,127 @@ fn test_verbose() {
     assert!(contains_slice(stdout, b"proc_exit.wat\")::()"));
 }
 
+#[test]
+fn test_repl() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("repl")
+        .arg("--fuel")
+        .arg("1000")
+        .arg(get_bin_path("repl"))
+        .write_stdin(
+            "call add 1 2\n\
+            call inc\n\
+            global counter 41\n\
+            call inc\n\
+            memory mem 0x10 4\n\
+            grow mem 1\n\
+            memories\n\
+            fuel\n\
+            call trap\n\
+            reload\n\
+            global counter\n\
+            exit\n",
+        )
+        .assert();
+    let stdout = &assert.get_output().stdout;
+    assert!(contains_slice(stdout, b"> [3]\n"));
+    assert!(contains_slice(stdout, b"> [1]\n"));
+    assert!(contains_slice(stdout, b"> [42]\n"));
+    assert!(contains_slice(stdout, b"00000010: 2a 00 00 00"));
+    assert!(contains_slice(
+        stdout,
+        b"grew memory \"mem\" from 1 to 2 pages"
+    ));
+    assert!(contains_slice(stdout, b" - mem: 2 pages (131072 bytes)"));
+    assert!(contains_slice(stdout, b"fuel remaining: "));
+    assert!(contains_slice(
+        stdout,
+        b"error: failed during execution of trap"
+    ));
+    assert!(contains_slice(stdout, b"> 0\n"));
+    assert.success();
+}
+
+#[test]
+fn test_inspect() {
+    let mut cmd = get_cmd();
+    let assert = cmd.arg("inspect").arg(get_bin_path("inspect")).assert();
+    let stdout = &assert.get_output().stdout;
+    assert!(contains_slice(
+        stdout,
+        b"Imports (2):\n - fn env::log(i32)\n"
+    ));
+    assert!(contains_slice(
+        stdout,
+        b" - global env::global: const i64\n"
+    ));
+    assert!(contains_slice(stdout, b" - fn add(i32, i32) -> i32\n"));
+    assert!(contains_slice(stdout, b" - memory mem: 1..=2 pages\n"));
+    assert!(contains_slice(
+        stdout,
+        b" - func[0]: fn log(i32) (imported)\n"
+    ));
+    assert!(contains_slice(stdout, b" - func[1]: fn init() ("));
+    assert!(contains_slice(
+        stdout,
+        b" - table[0]: funcref 2..=10 elements\n"
+    ));
+    assert!(contains_slice(
+        stdout,
+        b" - global[0]: const i64 (imported)\n"
+    ));
+    assert!(contains_slice(stdout, b" - global[1]: mut i32\n"));
+    assert!(contains_slice(stdout, b"Start function: func[1]\n"));
+    assert!(contains_slice(stdout, b" - \"name\" ("));
+    assert.success();
+}
+
+#[test]
+fn test_inspect_json() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("inspect")
+        .arg("--json")
+        .arg(get_bin_path("inspect"))
+        .assert();
+    let json: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+    assert_eq!(json["imports"].as_array().unwrap().len(), 2);
+    assert_eq!(json["imports"][0]["kind"], "func");
+    assert_eq!(json["imports"][0]["module"], "env");
+    assert_eq!(json["exports"][0]["name"], "add");
+    assert_eq!(
+        json["exports"][0]["params"],
+        serde_json::json!(["i32", "i32"])
+    );
+    assert_eq!(json["funcs"][0]["imported"], true);
+    assert!(json["funcs"][0]["bytecode_size"].is_null());
+    assert_eq!(json["funcs"][2]["name"], "add");
+    assert!(json["funcs"][2]["bytecode_size"].as_u64().unwrap() > 0);
+    assert!(json["funcs"][2].get("ir").is_none());
+    assert_eq!(json["tables"][0]["maximum"], 10);
+    assert_eq!(json["memories"][0]["minimum"], 1);
+    assert_eq!(json["globals"][1]["mutable"], true);
+    assert_eq!(json["start_func"], 1);
+    assert_eq!(json["custom_sections"][0]["name"], "name");
+    assert.success();
+}
+
+#[test]
+fn test_inspect_ir() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("inspect")
+        .arg("--ir")
+        .arg(get_bin_path("inspect"))
+        .assert();
+    let stdout = &assert.get_output().stdout;
+    assert!(contains_slice(stdout, b"Disassembly of func[2] add:\n"));
+    assert!(contains_slice(stdout, b": r2 = i32_add lhs=r0, rhs=r1\n"));
+    assert!(!contains_slice(stdout, b"Disassembly of func[0]"));
+    assert.success();
+}
+
 /// gets the path to a wasm binary given it's name
 fn get_bin_path(name: &str) -> PathBuf {
     let mut path = PathBuf::new();
@@ -51,3 +172,36 @@ fn get_bin_path(name: &str) -> PathBuf {
 fn get_cmd() -> assert_cmd::Command {
     Command::cargo_bin("wasmi_cli").expect("could not create wasmi_cli command")
 }
+
+#[test]
+fn test_preload() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("--preload")
+        .arg(format!("lib={}", get_bin_path("preload_lib").display()))
+        .arg("--invoke")
+        .arg("add_twice")
+        .arg(get_bin_path("preload_main"))
+        .arg("1")
+        .arg("2")
+        .assert();
+    assert!(contains_slice(&assert.get_output().stdout, b"5\n"));
+    assert.success();
+}
+
+#[test]
+fn test_preload_unresolved_imports() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("--invoke")
+        .arg("add_twice")
+        .arg(get_bin_path("preload_main"))
+        .arg("1")
+        .arg("2")
+        .assert();
+    let stderr = &assert.get_output().stderr;
+    assert!(contains_slice(stderr, b"due to unresolved imports:"));
+    assert!(contains_slice(stderr, b" - fn lib::add(i32, i32) -> i32\n"));
+    assert!(contains_slice(stderr, b" - fn lib::count() -> i32\n"));
+    assert.failure();
+}
@@ -0,0 +1,15 @@
+(module
+    (import "env" "log" (func $log (param i32)))
+    (import "env" "global" (global i64))
+    (table 2 10 funcref)
+    (memory (export "mem") 1 2)
+    (global $counter (mut i32) (i32.const 0))
+    (func $init
+        (global.set $counter (i32.const 1))
+    )
+    (func $add (export "add") (param i32 i32) (result i32)
+        (call $log (local.get 0))
+        (i32.add (local.get 0) (local.get 1))
+    )
+    (start $init)
+)
@@ -0,0 +1,10 @@
+(module
+    (global $counter (mut i32) (i32.const 0))
+    (func (export "add") (param i32 i32) (result i32)
+        (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+        (i32.add (local.get 0) (local.get 1))
+    )
+    (func (export "count") (result i32)
+        (global.get $counter)
+    )
+)
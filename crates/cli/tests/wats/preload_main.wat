@@ -0,0 +1,10 @@
+(module
+    (import "lib" "add" (func $add (param i32 i32) (result i32)))
+    (import "lib" "count" (func $count (result i32)))
+    (func (export "add_twice") (param i32 i32) (result i32)
+        (call $add (call $add (local.get 0) (local.get 1)) (local.get 1))
+    )
+    (func (export "count") (result i32)
+        (call $count)
+    )
+)
@@ -0,0 +1,15 @@
+(module
+    (memory (export "mem") 1 3)
+    (global $counter (export "counter") (mut i32) (i32.const 0))
+    (func (export "add") (param i32 i32) (result i32)
+        (i32.add (local.get 0) (local.get 1))
+    )
+    (func (export "inc") (result i32)
+        (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+        (i32.store (i32.const 0x10) (global.get $counter))
+        (global.get $counter)
+    )
+    (func (export "trap")
+        (unreachable)
+    )
+)
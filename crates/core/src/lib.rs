@@ -42,5 +42,5 @@ pub use self::{
     typed::{Typed, TypedVal},
     units::Pages,
     untyped::{DecodeUntypedSlice, EncodeUntypedSlice, UntypedError, UntypedVal},
-    value::ValType,
+    value::{HeapType, RefType, TypeId, TypeKind, ValType},
 };
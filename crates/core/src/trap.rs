@@ -117,7 +117,7 @@ impl Trap {
     {
         self.reason
             .as_host()
-            .and_then(<(dyn HostError + 'static)>::downcast_ref)
+            .and_then(<dyn HostError>::downcast_ref)
     }
 
     /// Downcasts the [`Trap`] into the `T: HostError` if possible.
@@ -130,7 +130,7 @@ impl Trap {
     {
         self.reason
             .as_host_mut()
-            .and_then(<(dyn HostError + 'static)>::downcast_mut)
+            .and_then(<dyn HostError>::downcast_mut)
     }
 
     /// Consumes `self` to downcast the [`Trap`] into the `T: HostError` if possible.
@@ -245,6 +245,11 @@ pub enum TrapCode {
     /// an uninitialized (i.e. `null`) table index.
     IndirectCallToNull,
 
+    /// Indicates that a `null` reference was used where a non-`null` reference was expected.
+    ///
+    /// This can happen with `call_ref` or `ref.as_non_null` instructions.
+    NullReference,
+
     /// Attempt to divide by zero.
     ///
     /// This trap typically can happen if `div` or `rem` is executed with
@@ -291,6 +296,25 @@ pub enum TrapCode {
     /// desire on the part of the embedder to trap the interpreter rather than
     /// merely fail the growth operation.
     GrowthOperationLimited,
+
+    /// Indicates that a `ref.cast` instruction or a cast of a host value failed.
+    CastFailure,
+
+    /// Indicates that an array object was accessed out of bounds.
+    ///
+    /// This can happen with array instructions of the Wasm `gc` proposal.
+    ArrayOutOfBounds,
+
+    /// Indicates that the allocation of an array object failed because it was too large.
+    AllocationTooLarge,
+
+    /// Indicates that a continuation was resumed, bound or switched to after it was consumed.
+    ///
+    /// Continuations of the Wasm `stack-switching` proposal are one-shot.
+    ContinuationAlreadyConsumed,
+
+    /// Indicates that a `suspend` or `switch` instruction found no enclosing handler for its tag.
+    UnhandledTag,
 }
 
 impl TrapCode {
@@ -306,6 +330,7 @@ impl TrapCode {
             Self::MemoryOutOfBounds => "out of bounds memory access",
             Self::TableOutOfBounds => "undefined element: out of bounds table access",
             Self::IndirectCallToNull => "uninitialized element 2", // TODO: fixme, remove the trailing " 2" again
+            Self::NullReference => "null reference",
             Self::IntegerDivisionByZero => "integer divide by zero",
             Self::IntegerOverflow => "integer overflow",
             Self::BadConversionToInteger => "invalid conversion to integer",
@@ -313,6 +338,11 @@ impl TrapCode {
             Self::BadSignature => "indirect call type mismatch",
             Self::OutOfFuel => "all fuel consumed by WebAssembly",
             Self::GrowthOperationLimited => "growth operation limited",
+            Self::CastFailure => "cast failure",
+            Self::ArrayOutOfBounds => "out of bounds array access",
+            Self::AllocationTooLarge => "allocation too large",
+            Self::ContinuationAlreadyConsumed => "continuation already consumed",
+            Self::UnhandledTag => "unhandled tag",
         }
     }
 }
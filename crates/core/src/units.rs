@@ -4,6 +4,13 @@
 pub struct Pages(u32);
 
 impl Pages {
+    /// The default size of a linear memory page as power of two.
+    ///
+    /// # Note
+    ///
+    /// Without the Wasm `custom-page-sizes` proposal every page is 2^16 bytes in size.
+    pub const DEFAULT_PAGE_SIZE_LOG2: u8 = 16;
+
     /// The maximum amount of pages on the `wasm32` target.
     ///
     /// # Note
@@ -15,6 +22,24 @@ impl Pages {
     pub const fn max() -> Self {
         Self(65536) // 2^16
     }
+
+    /// The maximum amount of pages on the `wasm32` target for pages of `2^page_size_log2` bytes.
+    ///
+    /// # Note
+    ///
+    /// For 1 byte sized pages this is `u32::MAX` since amounts of pages are 32-bit.
+    ///
+    /// # Panics
+    ///
+    /// If `page_size_log2` is greater than [`Pages::DEFAULT_PAGE_SIZE_LOG2`].
+    pub const fn max_with_page_size(page_size_log2: u8) -> Self {
+        assert!(page_size_log2 <= Self::DEFAULT_PAGE_SIZE_LOG2);
+        let max = 1_u64 << (32 - page_size_log2 as u32);
+        if max > u32::MAX as u64 {
+            return Self(u32::MAX);
+        }
+        Self(max as u32)
+    }
 }
 
 impl From<u16> for Pages {
@@ -38,7 +63,14 @@ impl Pages {
     ///
     /// [`Pages::max()`]: struct.Pages.html#method.max
     pub fn new(amount: u32) -> Option<Self> {
-        if amount > u32::from(Self::max()) {
+        Self::new_with_page_size(amount, Self::DEFAULT_PAGE_SIZE_LOG2)
+    }
+
+    /// Creates a new amount of [`Pages`] of `2^page_size_log2` bytes if the amount is within bounds.
+    ///
+    /// Returns `None` if the given `amount` of [`Pages`] exceeds [`Pages::max_with_page_size`].
+    pub fn new_with_page_size(amount: u32, page_size_log2: u8) -> Option<Self> {
+        if amount > u32::from(Self::max_with_page_size(page_size_log2)) {
             return None;
         }
         Some(Self(amount))
@@ -73,7 +105,15 @@ impl Pages {
     /// Returns `None` if the amount of pages represented by `self` cannot
     /// be represented as bytes on the executing platform.
     pub fn to_bytes(self) -> Option<usize> {
-        Bytes::new(self).map(Into::into)
+        self.to_bytes_with_page_size(Self::DEFAULT_PAGE_SIZE_LOG2)
+    }
+
+    /// Returns the amount of bytes required for the amount of [`Pages`] of `2^page_size_log2` bytes.
+    ///
+    /// Returns `None` if the amount of pages represented by `self` cannot
+    /// be represented as bytes on the executing platform.
+    pub fn to_bytes_with_page_size(self, page_size_log2: u8) -> Option<usize> {
+        Bytes::new(self, page_size_log2).map(Into::into)
     }
 }
 
@@ -104,29 +144,30 @@ impl Bytes {
         u32::MAX as u64 + 1
     }
 
-    /// The bytes per WebAssembly linear memory page.
+    /// The bytes per WebAssembly linear memory page of the default page size.
     ///
     /// # Note
     ///
-    /// As mandated by the WebAssembly specification every linear memory page
+    /// Without the Wasm `custom-page-sizes` proposal every linear memory page
     /// has exactly 2^16 (65536) bytes.
+    #[cfg(test)]
     const fn per_page() -> Self {
         Self(65536) // 2^16
     }
 
-    /// Creates [`Bytes`] from the given amount of [`Pages`] if possible.
+    /// Creates [`Bytes`] from the given amount of [`Pages`] of `2^page_size_log2` bytes if possible.
     ///
     /// Returns `None` if the amount of bytes is out of bounds. This may
     /// happen for example when trying to allocate bytes for more than
     /// `i16::MAX + 1` pages on a 32-bit platform since that amount would
     /// not be representable by a pointer sized `usize`.
-    fn new(pages: Pages) -> Option<Bytes> {
+    fn new(pages: Pages, page_size_log2: u8) -> Option<Bytes> {
         if cfg!(target_pointer_width = "16") {
-            Self::new16(pages)
+            Self::new16(pages, page_size_log2)
         } else if cfg!(target_pointer_width = "32") {
-            Self::new32(pages)
+            Self::new32(pages, page_size_log2)
         } else if cfg!(target_pointer_width = "64") {
-            Self::new64(pages)
+            Self::new64(pages, page_size_log2)
         } else {
             None
         }
@@ -140,8 +181,8 @@ impl Bytes {
     /// # Note
     ///
     /// This API exists in isolation for cross-platform testing purposes.
-    fn new16(pages: Pages) -> Option<Bytes> {
-        Self::new_impl(pages, Bytes::max16())
+    fn new16(pages: Pages, page_size_log2: u8) -> Option<Bytes> {
+        Self::new_impl(pages, page_size_log2, Bytes::max16())
     }
 
     /// Creates [`Bytes`] from the given amount of [`Pages`] as if
@@ -152,8 +193,8 @@ impl Bytes {
     /// # Note
     ///
     /// This API exists in isolation for cross-platform testing purposes.
-    fn new32(pages: Pages) -> Option<Bytes> {
-        Self::new_impl(pages, Bytes::max32())
+    fn new32(pages: Pages, page_size_log2: u8) -> Option<Bytes> {
+        Self::new_impl(pages, page_size_log2, Bytes::max32())
     }
 
     /// Creates [`Bytes`] from the given amount of [`Pages`] as if
@@ -164,14 +205,14 @@ impl Bytes {
     /// # Note
     ///
     /// This API exists in isolation for cross-platform testing purposes.
-    fn new64(pages: Pages) -> Option<Bytes> {
-        Self::new_impl(pages, Bytes::max64())
+    fn new64(pages: Pages, page_size_log2: u8) -> Option<Bytes> {
+        Self::new_impl(pages, page_size_log2, Bytes::max64())
     }
 
     /// Actual underlying implementation of [`Bytes::new`].
-    fn new_impl(pages: Pages, max: u64) -> Option<Bytes> {
+    fn new_impl(pages: Pages, page_size_log2: u8, max: u64) -> Option<Bytes> {
         let pages = u64::from(u32::from(pages));
-        let bytes_per_page = usize::from(Self::per_page()) as u64;
+        let bytes_per_page = 1_u64.checked_shl(u32::from(page_size_log2))?;
         let bytes = pages
             .checked_mul(bytes_per_page)
             .filter(|&amount| amount <= max)?;
@@ -275,42 +316,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pages_with_page_size() {
+        assert_eq!(Pages::max_with_page_size(16), Pages::max());
+        assert_eq!(Pages::max_with_page_size(0), Pages(u32::MAX));
+        assert_eq!(
+            Pages::new_with_page_size(u32::MAX, 0),
+            Some(Pages(u32::MAX))
+        );
+        assert_eq!(Pages::new_with_page_size(u32::from(u16::MAX) + 2, 16), None);
+        assert_eq!(pages(0).to_bytes_with_page_size(0), Some(0));
+        for n in 1..10 {
+            assert_eq!(pages(n).to_bytes_with_page_size(0), Some(n as usize));
+            assert_eq!(pages(n).to_bytes_with_page_size(16), pages(n).to_bytes());
+        }
+        assert_eq!(
+            Bytes::new64(Pages(u32::MAX), 0),
+            Some(bytes(u32::MAX as usize))
+        );
+        assert_eq!(Bytes::new32(Pages(u32::MAX), 0), None);
+    }
+
     #[test]
     fn bytes_new16() {
-        assert_eq!(Bytes::new16(pages(0)), Some(bytes(0)));
-        assert_eq!(Bytes::new16(pages(1)), None);
-        assert!(Bytes::new16(Pages::max()).is_none());
+        assert_eq!(Bytes::new16(pages(0), 16), Some(bytes(0)));
+        assert_eq!(Bytes::new16(pages(1), 16), None);
+        assert!(Bytes::new16(Pages::max(), 16).is_none());
     }
 
     #[test]
     fn bytes_new32() {
-        assert_eq!(Bytes::new32(pages(0)), Some(bytes(0)));
-        assert_eq!(Bytes::new32(pages(1)), Some(Bytes::per_page()));
+        assert_eq!(Bytes::new32(pages(0), 16), Some(bytes(0)));
+        assert_eq!(Bytes::new32(pages(1), 16), Some(Bytes::per_page()));
         let bytes_per_page = usize::from(Bytes::per_page());
         for n in 2..10 {
             assert_eq!(
-                Bytes::new32(pages(n as u32)),
+                Bytes::new32(pages(n as u32), 16),
                 Some(bytes(n * bytes_per_page))
             );
         }
-        assert!(Bytes::new32(pages(i16::MAX as u32 + 1)).is_some());
-        assert!(Bytes::new32(pages(i16::MAX as u32 + 2)).is_none());
-        assert!(Bytes::new32(Pages::max()).is_none());
+        assert!(Bytes::new32(pages(i16::MAX as u32 + 1), 16).is_some());
+        assert!(Bytes::new32(pages(i16::MAX as u32 + 2), 16).is_none());
+        assert!(Bytes::new32(Pages::max(), 16).is_none());
     }
 
     #[test]
     fn bytes_new64() {
-        assert_eq!(Bytes::new64(pages(0)), Some(bytes(0)));
-        assert_eq!(Bytes::new64(pages(1)), Some(Bytes::per_page()));
+        assert_eq!(Bytes::new64(pages(0), 16), Some(bytes(0)));
+        assert_eq!(Bytes::new64(pages(1), 16), Some(Bytes::per_page()));
         let bytes_per_page = usize::from(Bytes::per_page());
         for n in 2..10 {
             assert_eq!(
-                Bytes::new64(pages(n as u32)),
+                Bytes::new64(pages(n as u32), 16),
                 Some(bytes(n * bytes_per_page))
             );
         }
-        assert!(Bytes::new64(Pages(u32::from(u16::MAX) + 1)).is_some());
-        assert!(Bytes::new64(Pages(u32::from(u16::MAX) + 2)).is_none());
-        assert!(Bytes::new64(Pages::max()).is_some());
+        assert!(Bytes::new64(Pages(u32::from(u16::MAX) + 1), 16).is_some());
+        assert!(Bytes::new64(Pages(u32::from(u16::MAX) + 2), 16).is_none());
+        assert!(Bytes::new64(Pages::max(), 16).is_some());
     }
 }
@@ -1262,6 +1262,49 @@ impl UntypedVal {
     pub fn i64_trunc_sat_f64_u(self) -> Self {
         self.execute_unary(<f64 as TruncateSaturateInto<u64>>::truncate_saturate_into)
     }
+
+    /// Combines the low and high 64-bit halves `[lo, hi]` into a 128-bit integer.
+    fn to_u128(halves: [Self; 2]) -> u128 {
+        let [lo, hi] = halves.map(|half| u128::from(u64::from(half)));
+        (hi << 64) | lo
+    }
+
+    /// Splits the 128-bit integer `value` into its low and high 64-bit halves `[lo, hi]`.
+    fn from_u128(value: u128) -> [Self; 2] {
+        [value as u64, (value >> 64) as u64].map(Self::from)
+    }
+
+    /// Execute `i64.add128` Wasm operation.
+    ///
+    /// Both operands and the result are 128-bit integers encoded as `[lo, hi]`.
+    pub fn i64_add128(lhs: [Self; 2], rhs: [Self; 2]) -> [Self; 2] {
+        Self::from_u128(Self::to_u128(lhs).wrapping_add(Self::to_u128(rhs)))
+    }
+
+    /// Execute `i64.sub128` Wasm operation.
+    ///
+    /// Both operands and the result are 128-bit integers encoded as `[lo, hi]`.
+    pub fn i64_sub128(lhs: [Self; 2], rhs: [Self; 2]) -> [Self; 2] {
+        Self::from_u128(Self::to_u128(lhs).wrapping_sub(Self::to_u128(rhs)))
+    }
+
+    /// Execute `i64.mul_wide_s` Wasm operation.
+    ///
+    /// The 128-bit result is encoded as `[lo, hi]`.
+    pub fn i64_mul_wide_s(self, rhs: Self) -> [Self; 2] {
+        let lhs = i128::from(i64::from(self));
+        let rhs = i128::from(i64::from(rhs));
+        Self::from_u128(lhs.wrapping_mul(rhs) as u128)
+    }
+
+    /// Execute `i64.mul_wide_u` Wasm operation.
+    ///
+    /// The 128-bit result is encoded as `[lo, hi]`.
+    pub fn i64_mul_wide_u(self, rhs: Self) -> [Self; 2] {
+        let lhs = u128::from(u64::from(self));
+        let rhs = u128::from(u64::from(rhs));
+        Self::from_u128(lhs.wrapping_mul(rhs))
+    }
 }
 
 /// Macro to help implement generic trait implementations for tuple types.
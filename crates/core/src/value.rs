@@ -3,6 +3,7 @@ use crate::{
     nan_preserving_float::{F32, F64},
     TrapCode,
 };
+use core::fmt;
 
 /// Type of a value.
 ///
@@ -23,6 +24,14 @@ pub enum ValType {
     FuncRef,
     /// A nullable external reference.
     ExternRef,
+    /// A typed reference as introduced by the Wasm `function-references` proposal.
+    ///
+    /// # Note
+    ///
+    /// Nullable references to abstract `func` and `extern` heap types are always
+    /// represented by [`ValType::FuncRef`] and [`ValType::ExternRef`] respectively.
+    /// Use [`ValType::from`] with a [`RefType`] to construct canonical reference types.
+    Ref(RefType),
 }
 
 impl ValType {
@@ -36,9 +45,440 @@ impl ValType {
 
     /// Returns `true` if [`ValType`] is a Wasm reference type.
     ///
-    /// This is `true` for [`ValType::FuncRef`] and [`ValType::ExternRef`].
+    /// This is `true` for [`ValType::FuncRef`], [`ValType::ExternRef`] and [`ValType::Ref`].
     pub fn is_ref(&self) -> bool {
-        matches!(self, Self::ExternRef | Self::FuncRef)
+        matches!(self, Self::ExternRef | Self::FuncRef | Self::Ref(_))
+    }
+
+    /// Returns the [`RefType`] of `self` if it is a Wasm reference type.
+    pub fn ref_type(&self) -> Option<RefType> {
+        match self {
+            Self::FuncRef => Some(RefType::FUNCREF),
+            Self::ExternRef => Some(RefType::EXTERNREF),
+            Self::Ref(ref_type) => Some(*ref_type),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if [`ValType`] is a reference to functions.
+    ///
+    /// This is `true` for [`ValType::FuncRef`] and typed function references.
+    pub fn is_func_ref(&self) -> bool {
+        self.ref_type()
+            .is_some_and(|ref_type| ref_type.heap_type().is_func())
+    }
+
+    /// Returns `true` if [`ValType`] is a reference to external objects.
+    ///
+    /// This is `true` for [`ValType::ExternRef`] and typed external references.
+    pub fn is_extern_ref(&self) -> bool {
+        self.ref_type()
+            .is_some_and(|ref_type| ref_type.heap_type().is_extern())
+    }
+
+    /// Returns `true` if [`ValType`] is a reference to internal objects.
+    ///
+    /// This is `true` for all reference types of the `any` hierarchy
+    /// as introduced by the Wasm `gc` proposal.
+    pub fn is_any_ref(&self) -> bool {
+        self.ref_type()
+            .is_some_and(|ref_type| ref_type.heap_type().is_any())
+    }
+
+    /// Returns `true` if [`ValType`] is a reference to continuations.
+    ///
+    /// This is `true` for all reference types of the `cont` hierarchy
+    /// as introduced by the Wasm `stack-switching` proposal.
+    pub fn is_cont_ref(&self) -> bool {
+        self.ref_type()
+            .is_some_and(|ref_type| ref_type.heap_type().is_cont())
+    }
+
+    /// Returns `true` if [`ValType`] has a default value.
+    ///
+    /// This is `true` for all numeric types and nullable reference types.
+    pub fn is_defaultable(&self) -> bool {
+        match self.ref_type() {
+            Some(ref_type) => ref_type.is_nullable(),
+            None => true,
+        }
+    }
+
+    /// Returns `true` if `self` is a subtype of `other`.
+    ///
+    /// # Note
+    ///
+    /// - Every [`ValType`] is a subtype of itself.
+    /// - Non-nullable references are subtypes of their nullable counterparts.
+    /// - Reference subtyping follows [`HeapType::is_subtype_of`].
+    pub fn is_subtype_of(&self, other: &Self) -> bool {
+        if self == other {
+            return true;
+        }
+        match (self.ref_type(), other.ref_type()) {
+            (Some(lhs), Some(rhs)) => lhs.is_subtype_of(&rhs),
+            _ => false,
+        }
+    }
+}
+
+impl From<RefType> for ValType {
+    fn from(ref_type: RefType) -> Self {
+        match ref_type {
+            RefType::FUNCREF => Self::FuncRef,
+            RefType::EXTERNREF => Self::ExternRef,
+            ref_type => Self::Ref(ref_type),
+        }
+    }
+}
+
+/// A Wasm reference type.
+///
+/// Consists of a [`HeapType`] and whether the reference is nullable.
+///
+/// # Note
+///
+/// Both parts are packed into a single `u32` so that [`ValType`] stays small.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RefType {
+    /// The packed nullability flag and [`HeapType`].
+    ///
+    /// - The most significant bit is set if the reference type is nullable.
+    /// - The remaining bits encode the [`HeapType`]:
+    ///     - `0..=11`: abstract heap types such as [`HeapType::Func`]
+    ///     - `n + 16`: [`HeapType::Concrete`] with raw [`TypeId`] `n`
+    bits: u32,
+}
+
+impl fmt::Debug for RefType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RefType")
+            .field("heap_type", &self.heap_type())
+            .field("nullable", &self.is_nullable())
+            .finish()
+    }
+}
+
+impl RefType {
+    /// Bit mask for the nullability flag of the packed representation.
+    const NULLABLE: u32 = 1 << 31;
+
+    /// Offset of raw [`TypeId`] values in the packed representation.
+    const CONCRETE_OFFSET: u32 = 16;
+
+    /// The nullable `func` reference type.
+    pub const FUNCREF: Self = Self::new(true, HeapType::Func);
+
+    /// The nullable `extern` reference type.
+    pub const EXTERNREF: Self = Self::new(true, HeapType::Extern);
+
+    /// The nullable `any` reference type.
+    pub const ANYREF: Self = Self::new(true, HeapType::Any);
+
+    /// The nullable `eq` reference type.
+    pub const EQREF: Self = Self::new(true, HeapType::Eq);
+
+    /// The nullable `i31` reference type.
+    pub const I31REF: Self = Self::new(true, HeapType::I31);
+
+    /// The nullable `struct` reference type.
+    pub const STRUCTREF: Self = Self::new(true, HeapType::Struct);
+
+    /// The nullable `array` reference type.
+    pub const ARRAYREF: Self = Self::new(true, HeapType::Array);
+
+    /// The nullable `none` reference type.
+    pub const NULLREF: Self = Self::new(true, HeapType::None);
+
+    /// The nullable `cont` reference type.
+    pub const CONTREF: Self = Self::new(true, HeapType::Cont);
+
+    /// Creates a new [`RefType`] referencing `heap_type`.
+    pub const fn new(nullable: bool, heap_type: HeapType) -> Self {
+        let heap_bits = match heap_type {
+            HeapType::Func => 0,
+            HeapType::Extern => 1,
+            HeapType::Any => 2,
+            HeapType::Eq => 3,
+            HeapType::I31 => 4,
+            HeapType::Struct => 5,
+            HeapType::Array => 6,
+            HeapType::None => 7,
+            HeapType::NoFunc => 8,
+            HeapType::NoExtern => 9,
+            HeapType::Cont => 10,
+            HeapType::NoCont => 11,
+            HeapType::Concrete(type_id) => type_id.0 + Self::CONCRETE_OFFSET,
+        };
+        let nullable_bits = if nullable { Self::NULLABLE } else { 0 };
+        Self {
+            bits: nullable_bits | heap_bits,
+        }
+    }
+
+    /// Returns the packed `u32` representation of the [`RefType`].
+    pub fn to_bits(self) -> u32 {
+        self.bits
+    }
+
+    /// Creates a [`RefType`] from its packed `u32` representation.
+    ///
+    /// Returns `None` if `bits` is not a valid packed [`RefType`] representation.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        let heap_bits = bits & !Self::NULLABLE;
+        let is_valid = match heap_bits.checked_sub(Self::CONCRETE_OFFSET) {
+            Some(raw) => TypeId::from_u32(raw).is_some(),
+            None => heap_bits <= 11,
+        };
+        is_valid.then_some(Self { bits })
+    }
+
+    /// Returns `true` if the [`RefType`] admits `null` values.
+    pub fn is_nullable(&self) -> bool {
+        self.bits & Self::NULLABLE != 0
+    }
+
+    /// Returns the referenced [`HeapType`].
+    pub fn heap_type(&self) -> HeapType {
+        match self.bits & !Self::NULLABLE {
+            0 => HeapType::Func,
+            1 => HeapType::Extern,
+            2 => HeapType::Any,
+            3 => HeapType::Eq,
+            4 => HeapType::I31,
+            5 => HeapType::Struct,
+            6 => HeapType::Array,
+            7 => HeapType::None,
+            8 => HeapType::NoFunc,
+            9 => HeapType::NoExtern,
+            10 => HeapType::Cont,
+            11 => HeapType::NoCont,
+            n => HeapType::Concrete(TypeId(n - Self::CONCRETE_OFFSET)),
+        }
+    }
+
+    /// Returns the nullable counterpart of `self`.
+    pub fn as_nullable(&self) -> Self {
+        Self::new(true, self.heap_type())
+    }
+
+    /// Returns the non-nullable counterpart of `self`.
+    pub fn as_non_null(&self) -> Self {
+        Self::new(false, self.heap_type())
+    }
+
+    /// Returns `true` if `self` is a subtype of `other`.
+    pub fn is_subtype_of(&self, other: &Self) -> bool {
+        if self.is_nullable() && !other.is_nullable() {
+            return false;
+        }
+        self.heap_type().is_subtype_of(&other.heap_type())
+    }
+}
+
+/// The type of objects a [`RefType`] refers to.
+///
+/// # Note
+///
+/// Heap types form four disjoint hierarchies:
+///
+/// - `func` with bottom type `nofunc` and concrete function types.
+/// - `extern` with bottom type `noextern`.
+/// - `any` with `eq`, `i31`, `struct`, `array`, bottom type `none`
+///   as well as concrete struct and array types.
+/// - `cont` with bottom type `nocont` and concrete continuation types.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HeapType {
+    /// Functions of any function type.
+    Func,
+    /// External objects provided by the host.
+    Extern,
+    /// Any internal object, the top type of the `any` hierarchy.
+    Any,
+    /// Internal objects that can be compared for equality via `ref.eq`.
+    Eq,
+    /// Unboxed 31-bit scalars.
+    I31,
+    /// Struct objects of any struct type.
+    Struct,
+    /// Array objects of any array type.
+    Array,
+    /// The bottom type of the `any` hierarchy.
+    None,
+    /// The bottom type of the `func` hierarchy.
+    NoFunc,
+    /// The bottom type of the `extern` hierarchy.
+    NoExtern,
+    /// Continuations of any continuation type.
+    Cont,
+    /// The bottom type of the `cont` hierarchy.
+    NoCont,
+    /// Objects of a concrete function, struct, array or continuation type.
+    Concrete(TypeId),
+}
+
+impl HeapType {
+    /// Returns `true` if the [`HeapType`] belongs to the `func` hierarchy.
+    ///
+    /// This is `true` for [`HeapType::Func`], [`HeapType::NoFunc`]
+    /// and concrete function types.
+    pub fn is_func(&self) -> bool {
+        matches!(self.top(), Self::Func)
+    }
+
+    /// Returns `true` if the [`HeapType`] belongs to the `extern` hierarchy.
+    ///
+    /// This is `true` for [`HeapType::Extern`] and [`HeapType::NoExtern`].
+    pub fn is_extern(&self) -> bool {
+        matches!(self.top(), Self::Extern)
+    }
+
+    /// Returns `true` if the [`HeapType`] belongs to the `any` hierarchy.
+    pub fn is_any(&self) -> bool {
+        matches!(self.top(), Self::Any)
+    }
+
+    /// Returns `true` if the [`HeapType`] belongs to the `cont` hierarchy.
+    ///
+    /// This is `true` for [`HeapType::Cont`], [`HeapType::NoCont`]
+    /// and concrete continuation types.
+    pub fn is_cont(&self) -> bool {
+        matches!(self.top(), Self::Cont)
+    }
+
+    /// Returns the top type of the hierarchy that `self` belongs to.
+    pub fn top(&self) -> Self {
+        match self {
+            Self::Func | Self::NoFunc => Self::Func,
+            Self::Extern | Self::NoExtern => Self::Extern,
+            Self::Cont | Self::NoCont => Self::Cont,
+            Self::Any | Self::Eq | Self::I31 | Self::Struct | Self::Array | Self::None => Self::Any,
+            Self::Concrete(type_id) => match type_id.kind() {
+                TypeKind::Func => Self::Func,
+                TypeKind::Struct | TypeKind::Array => Self::Any,
+                TypeKind::Cont => Self::Cont,
+            },
+        }
+    }
+
+    /// Returns the bottom type of the hierarchy that `self` belongs to.
+    pub fn bottom(&self) -> Self {
+        match self.top() {
+            Self::Func => Self::NoFunc,
+            Self::Extern => Self::NoExtern,
+            Self::Cont => Self::NoCont,
+            _ => Self::None,
+        }
+    }
+
+    /// Returns `true` if `self` is a subtype of `other`.
+    ///
+    /// # Note
+    ///
+    /// Concrete heap types are only subtypes of themselves and their abstract
+    /// supertypes here. Declared subtyping between concrete heap types requires
+    /// the type information of the `Engine` that registered them.
+    pub fn is_subtype_of(&self, other: &Self) -> bool {
+        if self == other {
+            return true;
+        }
+        match (*self, *other) {
+            (Self::Concrete(type_id), other) => {
+                let abstract_type = match type_id.kind() {
+                    TypeKind::Func => Self::Func,
+                    TypeKind::Struct => Self::Struct,
+                    TypeKind::Array => Self::Array,
+                    TypeKind::Cont => Self::Cont,
+                };
+                abstract_type.is_subtype_of(&other)
+            }
+            (Self::None | Self::NoFunc | Self::NoExtern | Self::NoCont, other) => {
+                self.top() == other.top()
+            }
+            (Self::I31 | Self::Struct | Self::Array, Self::Eq | Self::Any) => true,
+            (Self::Eq, Self::Any) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The kind of a type identified by a [`TypeId`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TypeKind {
+    /// A function type.
+    Func,
+    /// A struct type as introduced by the Wasm `gc` proposal.
+    Struct,
+    /// An array type as introduced by the Wasm `gc` proposal.
+    Array,
+    /// A continuation type as introduced by the Wasm `stack-switching` proposal.
+    Cont,
+}
+
+/// Identifies a concrete type registered within a Wasmi `Engine`.
+///
+/// # Note
+///
+/// - A [`TypeId`] is only meaningful for the `Engine` that created it.
+/// - The [`TypeKind`] of the identified type is encoded into the [`TypeId`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TypeId(u32);
+
+impl TypeId {
+    /// The number of bits used to encode the [`TypeKind`].
+    const KIND_BITS: u32 = 2;
+
+    /// The maximum index of a [`TypeId`].
+    const MAX_INDEX: u32 = (1 << 27) - 1;
+
+    /// Creates a new [`TypeId`] for the `index`-th type with the given [`TypeKind`].
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds for a [`TypeId`].
+    pub fn new(kind: TypeKind, index: u32) -> Self {
+        assert!(
+            index <= Self::MAX_INDEX,
+            "type index out of bounds: {index}"
+        );
+        let kind = match kind {
+            TypeKind::Func => 0,
+            TypeKind::Struct => 1,
+            TypeKind::Array => 2,
+            TypeKind::Cont => 3,
+        };
+        Self((index << Self::KIND_BITS) | kind)
+    }
+
+    /// Creates a new [`TypeId`] from its raw `u32` representation.
+    ///
+    /// Returns `None` if `raw` is not a valid [`TypeId`] representation.
+    pub fn from_u32(raw: u32) -> Option<Self> {
+        if (raw >> Self::KIND_BITS) > Self::MAX_INDEX {
+            return None;
+        }
+        Some(Self(raw))
+    }
+
+    /// Returns the raw `u32` representation of the [`TypeId`].
+    pub fn into_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the index of the [`TypeId`].
+    pub fn index(self) -> u32 {
+        self.0 >> Self::KIND_BITS
+    }
+
+    /// Returns the [`TypeKind`] of the [`TypeId`].
+    pub fn kind(self) -> TypeKind {
+        match self.0 & 0b11 {
+            0 => TypeKind::Func,
+            1 => TypeKind::Struct,
+            2 => TypeKind::Array,
+            _ => TypeKind::Cont,
+        }
     }
 }
 
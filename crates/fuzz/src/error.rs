@@ -17,9 +17,13 @@ pub enum TrapCode {
     MemoryOutOfBounds,
     TableOutOfBounds,
     IndirectCallToNull,
+    NullReference,
     IntegerDivisionByZero,
     IntegerOverflow,
     BadConversionToInteger,
     StackOverflow,
     BadSignature,
+    CastFailure,
+    ArrayOutOfBounds,
+    AllocationTooLarge,
 }
@@ -175,12 +175,19 @@ impl From<wasmi::Error> for FuzzError {
             TrapCode::MemoryOutOfBounds => crate::TrapCode::MemoryOutOfBounds,
             TrapCode::TableOutOfBounds => crate::TrapCode::TableOutOfBounds,
             TrapCode::IndirectCallToNull => crate::TrapCode::IndirectCallToNull,
+            TrapCode::NullReference => crate::TrapCode::NullReference,
             TrapCode::IntegerDivisionByZero => crate::TrapCode::IntegerDivisionByZero,
             TrapCode::IntegerOverflow => crate::TrapCode::IntegerOverflow,
             TrapCode::BadConversionToInteger => crate::TrapCode::BadConversionToInteger,
             TrapCode::StackOverflow => crate::TrapCode::StackOverflow,
             TrapCode::BadSignature => crate::TrapCode::BadSignature,
-            TrapCode::OutOfFuel | TrapCode::GrowthOperationLimited => return FuzzError::Other,
+            TrapCode::CastFailure => crate::TrapCode::CastFailure,
+            TrapCode::ArrayOutOfBounds => crate::TrapCode::ArrayOutOfBounds,
+            TrapCode::AllocationTooLarge => crate::TrapCode::AllocationTooLarge,
+            TrapCode::OutOfFuel
+            | TrapCode::GrowthOperationLimited
+            | TrapCode::ContinuationAlreadyConsumed
+            | TrapCode::UnhandledTag => return FuzzError::Other,
         };
         FuzzError::Trap(trap_code)
     }
@@ -153,6 +153,7 @@ impl From<Error> for FuzzError {
             TrapCode::MemoryOutOfBounds => crate::TrapCode::MemoryOutOfBounds,
             TrapCode::TableOutOfBounds => crate::TrapCode::TableOutOfBounds,
             TrapCode::IndirectCallToNull => crate::TrapCode::IndirectCallToNull,
+            TrapCode::NullReference => crate::TrapCode::NullReference,
             TrapCode::IntegerDivisionByZero => crate::TrapCode::IntegerDivisionByZero,
             TrapCode::IntegerOverflow => crate::TrapCode::IntegerOverflow,
             TrapCode::BadConversionToInteger => crate::TrapCode::BadConversionToInteger,
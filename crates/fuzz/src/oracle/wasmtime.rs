@@ -137,11 +137,15 @@ impl From<wasmtime::Error> for FuzzError {
             Trap::MemoryOutOfBounds => crate::TrapCode::MemoryOutOfBounds,
             Trap::TableOutOfBounds => crate::TrapCode::TableOutOfBounds,
             Trap::IndirectCallToNull => crate::TrapCode::IndirectCallToNull,
+            Trap::NullReference => crate::TrapCode::NullReference,
             Trap::IntegerDivisionByZero => crate::TrapCode::IntegerDivisionByZero,
             Trap::IntegerOverflow => crate::TrapCode::IntegerOverflow,
             Trap::BadConversionToInteger => crate::TrapCode::BadConversionToInteger,
             Trap::StackOverflow => crate::TrapCode::StackOverflow,
             Trap::BadSignature => crate::TrapCode::BadSignature,
+            Trap::CastFailure => crate::TrapCode::CastFailure,
+            Trap::ArrayOutOfBounds => crate::TrapCode::ArrayOutOfBounds,
+            Trap::AllocationTooLarge => crate::TrapCode::AllocationTooLarge,
             _ => return FuzzError::Other,
         };
         FuzzError::Trap(trap_code)
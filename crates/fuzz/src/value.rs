@@ -27,6 +27,8 @@ impl From<ValType> for FuzzValType {
             ValType::F64 => Self::F64,
             ValType::FuncRef => Self::FuncRef,
             ValType::ExternRef => Self::ExternRef,
+            ty if ty.is_func_ref() => Self::FuncRef,
+            _ => Self::ExternRef,
         }
     }
 }
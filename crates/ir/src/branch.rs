@@ -0,0 +1,136 @@
+use crate::{core::TrapCode, for_each_op, index::*, *};
+
+/// Helper trait for [`Instruction::branch_offset`] and [`Instruction::set_branch_offset`].
+trait AsBranchOffset {
+    /// Returns the branch offset of `self` if `self` is a branch offset.
+    fn as_branch_offset(&self) -> Option<i32> {
+        None
+    }
+
+    /// Sets `self` to the branch `offset` if `self` is a branch offset.
+    ///
+    /// Returns `None` if `self` is not a branch offset.
+    fn set_branch_offset(&mut self, _offset: i32) -> Option<Result<(), Error>> {
+        None
+    }
+}
+
+impl AsBranchOffset for BranchOffset {
+    fn as_branch_offset(&self) -> Option<i32> {
+        Some(self.to_i32())
+    }
+
+    fn set_branch_offset(&mut self, offset: i32) -> Option<Result<(), Error>> {
+        *self = BranchOffset::from(offset);
+        Some(Ok(()))
+    }
+}
+
+impl AsBranchOffset for BranchOffset16 {
+    fn as_branch_offset(&self) -> Option<i32> {
+        Some(i32::from(self.to_i16()))
+    }
+
+    fn set_branch_offset(&mut self, offset: i32) -> Option<Result<(), Error>> {
+        let result = i16::try_from(offset)
+            .map(|offset| *self = BranchOffset16::from(offset))
+            .map_err(|_| Error::BranchOffsetOutOfBounds);
+        Some(result)
+    }
+}
+
+impl<const N: usize> AsBranchOffset for [Reg; N] {}
+impl<const N: u16> AsBranchOffset for FixedRegSpan<N> {}
+
+macro_rules! impl_as_branch_offset_for {
+    ( $( $ty:ident $(<$t:ident>)? ),* $(,)? ) => {
+        $(
+            impl $(<$t>)? AsBranchOffset for $ty $(<$t>)? {}
+        )*
+    };
+}
+impl_as_branch_offset_for!(
+    Reg,
+    RegSpan,
+    BoundedRegSpan,
+    u8,
+    i8,
+    i16,
+    u16,
+    u32,
+    TrapCode,
+    BlockFuel,
+    AnyConst16,
+    AnyConst32,
+    InternalFunc,
+    Func,
+    FuncType,
+    Global,
+    Memory,
+    Table,
+    Elem,
+    Data,
+    Type,
+    Tag,
+    Const16<T>,
+    Const32<T>,
+    Sign<T>,
+    ShiftAmount<T>,
+);
+
+macro_rules! define_branch_offset {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $( @ $result_name:ident: $result_ty:ty, )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        impl Instruction {
+            /// Returns the branch offset of `self` relative to itself if any.
+            pub fn branch_offset(&self) -> Option<i32> {
+                match self {
+                    $(
+                        Self::$name { $( $( $field_name, )* )? .. } => {
+                            None $( $( .or_else(|| $field_name.as_branch_offset()) )* )?
+                        }
+                    )*
+                }
+            }
+
+            /// Sets the branch offset of `self` relative to itself.
+            ///
+            /// # Errors
+            ///
+            /// If `offset` is out of bounds for the branch offset of `self`.
+            ///
+            /// # Panics
+            ///
+            /// If `self` has no branch offset.
+            pub fn set_branch_offset(&mut self, offset: i32) -> Result<(), Error> {
+                let result = match self {
+                    $(
+                        Self::$name { $( $( $field_name, )* )? .. } => {
+                            None $( $( .or_else(|| $field_name.set_branch_offset(offset)) )* )?
+                        }
+                    )*
+                };
+                match result {
+                    Some(result) => result,
+                    None => panic!("expected a branch instruction but found: {self:?}"),
+                }
+            }
+        }
+    };
+}
+for_each_op!(define_branch_offset);
@@ -0,0 +1,421 @@
+//! A compact variable-length byte encoding of Wasmi [`Instruction`]s.
+//!
+//! Every [`Instruction`] is encoded as its opcode followed by its packed operands.
+//! Opcodes and integer operands are encoded as LEB128 variable-length integers
+//! where signed integers are zigzag encoded first. Since most register indices,
+//! immediates and branch offsets are small most instructions take 2 to 4 bytes
+//! instead of the 8 bytes of an [`Instruction`].
+
+use crate::{core::TrapCode, for_each_op, index::*, *};
+use ::core::num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64};
+use alloc::vec::Vec;
+
+macro_rules! impl_compact {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $( @ $result_name:ident: $result_ty:ty, )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        /// The opcodes of all [`Instruction`]s in the compact encoding.
+        #[repr(u16)]
+        enum Opcode {
+            $( $name ),*
+        }
+
+        /// The opcodes of all [`Instruction`]s usable as `match` patterns.
+        #[allow(non_upper_case_globals)]
+        mod op {
+            $(
+                pub const $name: u16 = super::Opcode::$name as u16;
+            )*
+        }
+
+        impl Instruction {
+            /// Appends the compact encoding of `self` to `out`.
+            ///
+            /// Use [`Instruction::decode_compact`] to decode the encoded [`Instruction`].
+            pub fn encode_compact(&self, out: &mut Vec<u8>) {
+                match *self {
+                    $(
+                        Self::$name { $( $( $result_name, )? $( $field_name, )* )? } => {
+                            encode_uleb(out, u64::from(Opcode::$name as u16));
+                            $(
+                                $( $result_name.encode(out); )?
+                                $( $field_name.encode(out); )*
+                            )?
+                        }
+                    )*
+                }
+            }
+
+            /// Decodes the compact encoding of the next [`Instruction`] in `bytes`.
+            ///
+            /// Advances `bytes` past the decoded [`Instruction`].
+            ///
+            /// Returns `None` if `bytes` does not start with a valid compact encoded [`Instruction`].
+            pub fn decode_compact(bytes: &mut &[u8]) -> Option<Self> {
+                let opcode = u16::try_from(decode_uleb(bytes)?).ok()?;
+                let instr = match opcode {
+                    $(
+                        op::$name => Self::$name {
+                            $(
+                                $( $result_name: CompactOperand::decode(bytes)?, )?
+                                $( $field_name: CompactOperand::decode(bytes)?, )*
+                            )?
+                        },
+                    )*
+                    _ => return None,
+                };
+                Some(instr)
+            }
+        }
+    };
+}
+for_each_op!(impl_compact);
+
+/// Appends the LEB128 encoding of `value` to `out`.
+fn encode_uleb(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Decodes the next LEB128 encoded value in `bytes`.
+///
+/// Returns `None` if `bytes` ends prematurely or the value overflows.
+fn decode_uleb(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0_u64;
+    let mut shift = 0_u32;
+    loop {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7F).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+        if shift >= 64 {
+            return None;
+        }
+    }
+}
+
+/// Appends the zigzag and LEB128 encoding of `value` to `out`.
+fn encode_sleb(out: &mut Vec<u8>, value: i64) {
+    encode_uleb(out, ((value << 1) ^ (value >> 63)) as u64)
+}
+
+/// Decodes the next zigzag and LEB128 encoded value in `bytes`.
+fn decode_sleb(bytes: &mut &[u8]) -> Option<i64> {
+    let value = decode_uleb(bytes)?;
+    Some(((value >> 1) as i64) ^ -((value & 1) as i64))
+}
+
+/// Implemented by types that can be compactly encoded as [`Instruction`] operands.
+trait CompactOperand: Sized {
+    /// Appends the compact encoding of `self` to `out`.
+    fn encode(&self, out: &mut Vec<u8>);
+    /// Decodes the next compact encoded operand in `bytes`.
+    fn decode(bytes: &mut &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_compact_operand_for_unsigned {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl CompactOperand for $ty {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    encode_uleb(out, u64::from(*self))
+                }
+
+                fn decode(bytes: &mut &[u8]) -> Option<Self> {
+                    Self::try_from(decode_uleb(bytes)?).ok()
+                }
+            }
+        )*
+    };
+}
+impl_compact_operand_for_unsigned!(u8, u16, u32);
+
+macro_rules! impl_compact_operand_for_signed {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl CompactOperand for $ty {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    encode_sleb(out, i64::from(*self))
+                }
+
+                fn decode(bytes: &mut &[u8]) -> Option<Self> {
+                    Self::try_from(decode_sleb(bytes)?).ok()
+                }
+            }
+        )*
+    };
+}
+impl_compact_operand_for_signed!(i8, i16);
+
+macro_rules! impl_compact_operand_for_index {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl CompactOperand for $ty {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    self.0.encode(out)
+                }
+
+                fn decode(bytes: &mut &[u8]) -> Option<Self> {
+                    CompactOperand::decode(bytes).map(Self)
+                }
+            }
+        )*
+    };
+}
+impl_compact_operand_for_index!(
+    Reg,
+    InternalFunc,
+    Func,
+    FuncType,
+    Global,
+    Memory,
+    Table,
+    Elem,
+    Data,
+    Type,
+    Tag
+);
+
+impl<const N: usize> CompactOperand for [Reg; N] {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for reg in self {
+            reg.encode(out);
+        }
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        let mut regs = [Reg::from(0); N];
+        for reg in &mut regs {
+            *reg = Reg::decode(bytes)?;
+        }
+        Some(regs)
+    }
+}
+
+impl CompactOperand for RegSpan {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.head().encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        Reg::decode(bytes).map(RegSpan::new)
+    }
+}
+
+impl CompactOperand for BoundedRegSpan {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.span().encode(out);
+        self.len().encode(out);
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        let span = RegSpan::decode(bytes)?;
+        let len = u16::decode(bytes)?;
+        Some(BoundedRegSpan::new(span, len))
+    }
+}
+
+impl<const N: u16> CompactOperand for FixedRegSpan<N> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.span().encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        FixedRegSpan::new(RegSpan::decode(bytes)?).ok()
+    }
+}
+
+impl CompactOperand for BranchOffset {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_sleb(out, i64::from(self.to_i32()))
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        i32::try_from(decode_sleb(bytes)?).ok().map(Self::from)
+    }
+}
+
+impl CompactOperand for BranchOffset16 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_i16().encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        i16::decode(bytes).map(Self::from)
+    }
+}
+
+impl CompactOperand for TrapCode {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let code: u8 = match self {
+            Self::UnreachableCodeReached => 0,
+            Self::MemoryOutOfBounds => 1,
+            Self::TableOutOfBounds => 2,
+            Self::IndirectCallToNull => 3,
+            Self::IntegerDivisionByZero => 4,
+            Self::IntegerOverflow => 5,
+            Self::BadConversionToInteger => 6,
+            Self::StackOverflow => 7,
+            Self::BadSignature => 8,
+            Self::OutOfFuel => 9,
+            Self::GrowthOperationLimited => 10,
+            Self::NullReference => 11,
+            Self::CastFailure => 12,
+            Self::ArrayOutOfBounds => 13,
+            Self::AllocationTooLarge => 14,
+            Self::ContinuationAlreadyConsumed => 15,
+            Self::UnhandledTag => 16,
+        };
+        code.encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        let trap_code = match u8::decode(bytes)? {
+            0 => Self::UnreachableCodeReached,
+            1 => Self::MemoryOutOfBounds,
+            2 => Self::TableOutOfBounds,
+            3 => Self::IndirectCallToNull,
+            4 => Self::IntegerDivisionByZero,
+            5 => Self::IntegerOverflow,
+            6 => Self::BadConversionToInteger,
+            7 => Self::StackOverflow,
+            8 => Self::BadSignature,
+            9 => Self::OutOfFuel,
+            10 => Self::GrowthOperationLimited,
+            11 => Self::NullReference,
+            12 => Self::CastFailure,
+            13 => Self::ArrayOutOfBounds,
+            14 => Self::AllocationTooLarge,
+            15 => Self::ContinuationAlreadyConsumed,
+            16 => Self::UnhandledTag,
+            _ => return None,
+        };
+        Some(trap_code)
+    }
+}
+
+impl CompactOperand for BlockFuel {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_uleb(out, self.to_u64())
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        u32::decode(bytes).map(Self::from)
+    }
+}
+
+impl CompactOperand for AnyConst16 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        i16::from(*self).encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        i16::decode(bytes).map(Self::from)
+    }
+}
+
+impl CompactOperand for AnyConst32 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        u32::from(*self).encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        u32::decode(bytes).map(Self::from)
+    }
+}
+
+macro_rules! impl_compact_operand_for_const16 {
+    ( $( $ty:ty => $may_be_zero:literal ),* $(,)? ) => {
+        $(
+            impl CompactOperand for Const16<$ty> {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    self.inner.encode(out)
+                }
+
+                fn decode(bytes: &mut &[u8]) -> Option<Self> {
+                    let inner = AnyConst16::decode(bytes)?;
+                    // Note: zero values would violate the invariant of non-zero types.
+                    if !$may_be_zero && i16::from(inner) == 0 {
+                        return None;
+                    }
+                    Some(Self::new(inner))
+                }
+            }
+        )*
+    };
+}
+impl_compact_operand_for_const16! {
+    i32 => true,
+    u32 => true,
+    i64 => true,
+    u64 => true,
+    NonZeroI32 => false,
+    NonZeroU32 => false,
+    NonZeroI64 => false,
+    NonZeroU64 => false,
+}
+
+impl<T> CompactOperand for Const32<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.inner.encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        AnyConst32::decode(bytes).map(Self::new)
+    }
+}
+
+impl<T> CompactOperand for Sign<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        u8::from(self.is_positive).encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        match u8::decode(bytes)? {
+            0 => Some(Self::new(false)),
+            1 => Some(Self::new(true)),
+            _ => None,
+        }
+    }
+}
+
+impl<T> CompactOperand for ShiftAmount<T>
+where
+    Const16<T>: CompactOperand,
+{
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.value.encode(out)
+    }
+
+    fn decode(bytes: &mut &[u8]) -> Option<Self> {
+        let value = Const16::decode(bytes)?;
+        if value.is_zero() {
+            return None;
+        }
+        Some(Self { value })
+    }
+}
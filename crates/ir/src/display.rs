@@ -0,0 +1,286 @@
+use crate::{core::TrapCode, for_each_op, index::*, *};
+use ::core::fmt::{self, Display};
+
+/// [`Display`]-wrapper type for an [`Instruction`].
+///
+/// # Note
+///
+/// - Function local constant [`Reg`]s are displayed as `cN` where `N` is the
+///   number of the constant value in allocation order, other [`Reg`]s as `rN`.
+/// - If the index of the [`Instruction`] is known its branch offsets are resolved
+///   and displayed as absolute branch targets `@N`, otherwise they are displayed as
+///   relative signed offsets.
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayInstruction<'a> {
+    /// The displayed [`Instruction`].
+    instr: &'a Instruction,
+    /// The index of the displayed [`Instruction`] within its function if known.
+    index: Option<usize>,
+}
+
+impl Instruction {
+    /// Returns a [`Display`]-wrapper for `self` located at `index` within its function.
+    ///
+    /// This resolves the branch offsets of `self` to absolute branch targets.
+    pub fn display_at(&self, index: usize) -> DisplayInstruction<'_> {
+        DisplayInstruction {
+            instr: self,
+            index: Some(index),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DisplayInstruction {
+            instr: self,
+            index: None,
+        }
+        .fmt(f)
+    }
+}
+
+/// Implemented by types that can be displayed as [`Instruction`] operands.
+trait DisplayOperand {
+    /// Displays `self` as operand of the [`Instruction`] at `index` if known.
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result;
+}
+
+/// Writes the named operands of an [`Instruction`] separated by commas.
+struct OperandWriter<'a, 'b> {
+    /// The underlying formatter.
+    f: &'a mut fmt::Formatter<'b>,
+    /// The index of the displayed [`Instruction`] within its function if known.
+    index: Option<usize>,
+    /// `true` if no operand has been written, yet.
+    first: bool,
+}
+
+impl OperandWriter<'_, '_> {
+    /// Writes the operand `value` named `name`.
+    fn write(&mut self, name: &str, value: &dyn DisplayOperand) -> fmt::Result {
+        let separator = if self.first { " " } else { ", " };
+        self.first = false;
+        write!(self.f, "{separator}{name}=")?;
+        value.fmt_operand(self.f, self.index)
+    }
+}
+
+/// Returns the `snake_name` of an [`Instruction`] without its raw identifier prefix.
+fn strip_raw(snake_name: &'static str) -> &'static str {
+    snake_name.strip_prefix("r#").unwrap_or(snake_name)
+}
+
+macro_rules! impl_display {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $( @ $result_name:ident: $result_ty:ty, )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        impl Display for DisplayInstruction<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let index = self.index;
+                match self.instr {
+                    $(
+                        Instruction::$name { $( $( $result_name, )? $( $field_name, )* )? } => {
+                            $( $(
+                                $result_name.fmt_operand(f, index)?;
+                                f.write_str(" = ")?;
+                            )? )?
+                            f.write_str(strip_raw(stringify!($snake_name)))?;
+                            #[allow(unused_mut, unused_variables)]
+                            let mut writer = OperandWriter { f, index, first: true };
+                            $( $(
+                                writer.write(stringify!($field_name), $field_name)?;
+                            )* )?
+                            Ok(())
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+for_each_op!(impl_display);
+
+impl DisplayOperand for Reg {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        match self.is_const() {
+            true => write!(f, "c{}", -(i32::from(self.0) + 1)),
+            false => write!(f, "r{}", self.0),
+        }
+    }
+}
+
+impl<const N: usize> DisplayOperand for [Reg; N] {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result {
+        f.write_str("[")?;
+        for (n, reg) in self.iter().enumerate() {
+            if n != 0 {
+                f.write_str(", ")?;
+            }
+            reg.fmt_operand(f, index)?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl DisplayOperand for RegSpan {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result {
+        self.head().fmt_operand(f, index)?;
+        f.write_str("..")
+    }
+}
+
+impl DisplayOperand for BoundedRegSpan {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result {
+        let head = self.span().head();
+        head.fmt_operand(f, index)?;
+        f.write_str("..")?;
+        head.next_n(self.len()).fmt_operand(f, index)
+    }
+}
+
+impl<const N: u16> DisplayOperand for FixedRegSpan<N> {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result {
+        BoundedRegSpan::new(self.span(), N).fmt_operand(f, index)
+    }
+}
+
+/// Displays the branch `offset` of the [`Instruction`] at `index` if known.
+fn fmt_branch_offset(f: &mut fmt::Formatter<'_>, offset: i32, index: Option<usize>) -> fmt::Result {
+    let target = index.and_then(|index| index.checked_add_signed(offset as isize));
+    match target {
+        Some(target) => write!(f, "@{target}"),
+        None => write!(f, "{offset:+}"),
+    }
+}
+
+impl DisplayOperand for BranchOffset {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result {
+        fmt_branch_offset(f, self.to_i32(), index)
+    }
+}
+
+impl DisplayOperand for BranchOffset16 {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, index: Option<usize>) -> fmt::Result {
+        fmt_branch_offset(f, i32::from(self.to_i16()), index)
+    }
+}
+
+macro_rules! impl_display_operand_for_primitive {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl DisplayOperand for $ty {
+                fn fmt_operand(
+                    &self,
+                    f: &mut fmt::Formatter<'_>,
+                    _index: Option<usize>,
+                ) -> fmt::Result {
+                    write!(f, "{self}")
+                }
+            }
+        )*
+    };
+}
+impl_display_operand_for_primitive!(u8, i8, i16, u16, u32);
+
+macro_rules! impl_display_operand_for_index {
+    ( $( $ty:ty => $prefix:literal ),* $(,)? ) => {
+        $(
+            impl DisplayOperand for $ty {
+                fn fmt_operand(
+                    &self,
+                    f: &mut fmt::Formatter<'_>,
+                    _index: Option<usize>,
+                ) -> fmt::Result {
+                    write!(f, concat!($prefix, "[{}]"), self.0)
+                }
+            }
+        )*
+    };
+}
+impl_display_operand_for_index! {
+    InternalFunc => "internal_func",
+    Func => "func",
+    FuncType => "func_type",
+    Global => "global",
+    Memory => "memory",
+    Table => "table",
+    Elem => "elem",
+    Data => "data",
+    Type => "type",
+    Tag => "tag",
+}
+
+impl DisplayOperand for TrapCode {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl DisplayOperand for BlockFuel {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        write!(f, "{}", self.to_u64())
+    }
+}
+
+impl DisplayOperand for AnyConst16 {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        write!(f, "{}", i16::from(*self))
+    }
+}
+
+impl DisplayOperand for AnyConst32 {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        write!(f, "0x{:08x}", u32::from(*self))
+    }
+}
+
+impl<T> DisplayOperand for Const16<T>
+where
+    T: From<Const16<T>> + Display,
+{
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        write!(f, "{}", T::from(*self))
+    }
+}
+
+impl<T> DisplayOperand for Const32<T>
+where
+    T: From<Const32<T>> + Display,
+{
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        write!(f, "{}", T::from(*self))
+    }
+}
+
+impl<T> DisplayOperand for ShiftAmount<T>
+where
+    T: From<ShiftAmount<T>> + Display + Copy,
+{
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        write!(f, "{}", T::from(*self))
+    }
+}
+
+impl<T> DisplayOperand for Sign<T> {
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, _index: Option<usize>) -> fmt::Result {
+        match self.is_positive {
+            true => f.write_str("+"),
+            false => f.write_str("-"),
+        }
+    }
+}
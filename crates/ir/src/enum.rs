@@ -93,6 +93,7 @@ impl IntoReg for Reg {
     }
 }
 impl IntoReg for RegSpan {}
+impl IntoReg for BoundedRegSpan {}
 impl<const N: u16> IntoReg for FixedRegSpan<N> {}
 impl IntoReg for () {}
 
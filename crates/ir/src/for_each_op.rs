@@ -1164,6 +1164,41 @@ macro_rules! for_each_op {
                 /// The called internal function.
                 func_type: FuncType,
             },
+            /// Wasm `return_call_ref` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// Used for tail calling function references without parameters.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register`] encoding the called function reference.
+            #[snake_name(return_call_ref_0)]
+            ReturnCallRef0 {
+                /// The function type of the called function reference.
+                func_type: FuncType,
+            },
+            /// Wasm `return_call_ref` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// Used for tail calling function references with parameters.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. [`Instruction::Register`]: encoding the called function reference
+            /// 2. Zero or more [`Instruction::RegisterList`]
+            /// 3. Followed by one of
+            ///     - [`Instruction::Register`]
+            ///     - [`Instruction::Register2`]
+            ///     - [`Instruction::Register3`]
+            #[snake_name(return_call_ref)]
+            ReturnCallRef {
+                /// The function type of the called function reference.
+                func_type: FuncType,
+            },
 
             /// Wasm `call` equivalent Wasmi instruction.
             ///
@@ -1305,6 +1340,43 @@ macro_rules! for_each_op {
                 /// The called internal function.
                 func_type: FuncType,
             },
+            /// Wasm `call_ref` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// Used for calling function references without parameters.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register`] encoding the called function reference.
+            #[snake_name(call_ref_0)]
+            CallRef0 {
+                @results: RegSpan,
+                /// The function type of the called function reference.
+                func_type: FuncType,
+            },
+            /// Wasm `call_ref` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// Used for calling function references with parameters.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. [`Instruction::Register`]: encoding the called function reference
+            /// 2. Zero or more [`Instruction::RegisterList`]
+            /// 3. Followed by one of
+            ///     - [`Instruction::Register`]
+            ///     - [`Instruction::Register2`]
+            ///     - [`Instruction::Register3`]
+            #[snake_name(call_ref)]
+            CallRef {
+                @results: RegSpan,
+                /// The function type of the called function reference.
+                func_type: FuncType,
+            },
 
             /// A Wasm `select` equivalent Wasmi instruction.
             ///
@@ -1501,6 +1573,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::Load32At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(load32_at_unchecked)]
+            Load32AtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Load instruction for 32-bit values.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::Load32`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(load32_offset16)]
@@ -1511,6 +1596,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Load instruction for 32-bit values.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::Load32Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(load32_offset16_unchecked)]
+            Load32Offset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Load instruction for 64-bit values.
             ///
@@ -1548,6 +1648,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::Load64At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(load64_at_unchecked)]
+            Load64AtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Load instruction for 64-bit values.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::Load64`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(load64_offset16)]
@@ -1558,6 +1671,90 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Load instruction for 64-bit values.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::Load64Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(load64_offset16_unchecked)]
+            Load64Offset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
+
+            /// Fused `i32.load` and `i32.add` instruction: `r0 = r1 + load32(ptr+offset)`
+            ///
+            /// # Note
+            ///
+            /// - Formed by the optimizer from an [`Instruction::Load32Offset16`] whose result
+            ///   is solely read by the directly following [`Instruction::I32Add`].
+            /// - Operates on the default Wasm memory instance.
+            ///
+            /// # Encoding
+            ///
+            /// Followed by an [`Instruction::RegisterAndImm32`] encoding `ptr` and `offset`.
+            #[snake_name(i32_add_load32)]
+            I32AddLoad32 {
+                @result: Reg,
+                /// The register holding the left-hand side value.
+                lhs: Reg,
+            },
+            /// Fused `i32.load` and `i32.add` instruction: `r0 = r1 + load32(ptr+offset)`
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32AddLoad32`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            ///
+            /// # Encoding
+            ///
+            /// Followed by an [`Instruction::RegisterAndImm32`] encoding `ptr` and `offset`.
+            #[snake_name(i32_add_load32_unchecked)]
+            I32AddLoad32Unchecked {
+                @result: Reg,
+                /// The register holding the left-hand side value.
+                lhs: Reg,
+            },
+            /// Fused `i64.load` and `i64.add` instruction: `r0 = r1 + load64(ptr+offset)`
+            ///
+            /// # Note
+            ///
+            /// - Formed by the optimizer from an [`Instruction::Load64Offset16`] whose result
+            ///   is solely read by the directly following [`Instruction::I64Add`].
+            /// - Operates on the default Wasm memory instance.
+            ///
+            /// # Encoding
+            ///
+            /// Followed by an [`Instruction::RegisterAndImm32`] encoding `ptr` and `offset`.
+            #[snake_name(i64_add_load64)]
+            I64AddLoad64 {
+                @result: Reg,
+                /// The register holding the left-hand side value.
+                lhs: Reg,
+            },
+            /// Fused `i64.load` and `i64.add` instruction: `r0 = r1 + load64(ptr+offset)`
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64AddLoad64`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            ///
+            /// # Encoding
+            ///
+            /// Followed by an [`Instruction::RegisterAndImm32`] encoding `ptr` and `offset`.
+            #[snake_name(i64_add_load64_unchecked)]
+            I64AddLoad64Unchecked {
+                @result: Reg,
+                /// The register holding the left-hand side value.
+                lhs: Reg,
+            },
 
             /// Wasm `i32.load8_s` equivalent Wasmi instruction.
             ///
@@ -1591,6 +1788,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Load8sAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load8_s_at_unchecked)]
+            I32Load8sAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i32.load8_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I32Load8s`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i32_load8_s_offset16)]
@@ -1601,6 +1811,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i32.load8_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32Load8sOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load8_s_offset16_unchecked)]
+            I32Load8sOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i32.load8_u` equivalent Wasmi instruction.
             ///
@@ -1634,6 +1859,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Load8uAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load8_u_at_unchecked)]
+            I32Load8uAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i32.load8_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I32Load8u`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i32_load8_u_offset16)]
@@ -1644,6 +1882,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i32.load8_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32Load8uOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load8_u_offset16_unchecked)]
+            I32Load8uOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i32.load16_s` equivalent Wasmi instruction.
             ///
@@ -1677,6 +1930,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Load16sAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load16_s_at_unchecked)]
+            I32Load16sAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i32.load16_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I32Load16s`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i32_load16_s_offset16)]
@@ -1687,6 +1953,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i32.load16_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32Load16sOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load16_s_offset16_unchecked)]
+            I32Load16sOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i32.load16_u` equivalent Wasmi instruction.
             ///
@@ -1720,6 +2001,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Load16uAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load16_u_at_unchecked)]
+            I32Load16uAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i32.load16_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I32Load16u`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i32_load16_u_offset16)]
@@ -1730,6 +2024,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i32.load16_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32Load16uOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_load16_u_offset16_unchecked)]
+            I32Load16uOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i64.load8_s` equivalent Wasmi instruction.
             ///
@@ -1763,6 +2072,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Load8sAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load8_s_at_unchecked)]
+            I64Load8sAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i64.load8_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Load8s`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_load8_s_offset16)]
@@ -1773,6 +2095,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i64.load8_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Load8sOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load8_s_offset16_unchecked)]
+            I64Load8sOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i64.load8_u` equivalent Wasmi instruction.
             ///
@@ -1806,6 +2143,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Load8uAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load8_u_at_unchecked)]
+            I64Load8uAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i64.load8_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Load8u`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_load8_u_offset16)]
@@ -1816,16 +2166,31 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
-
-            /// Wasm `i64.load16_s` equivalent Wasmi instruction.
+            /// Wasm `i64.load8_u` equivalent Wasmi instruction.
             ///
-            /// # Encoding
+            /// # Note
             ///
-            /// Followed by an [`Instruction::RegisterAndImm32`] encoding `ptr` and `offset`.
-            #[snake_name(i64_load16_s)]
-            I64Load16s {
+            /// - Variant of [`Instruction::I64Load8uOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load8_u_offset16_unchecked)]
+            I64Load8uOffset16Unchecked {
                 @result: Reg,
-                /// The linear memory index for which the load instruction is executed.
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
+
+            /// Wasm `i64.load16_s` equivalent Wasmi instruction.
+            ///
+            /// # Encoding
+            ///
+            /// Followed by an [`Instruction::RegisterAndImm32`] encoding `ptr` and `offset`.
+            #[snake_name(i64_load16_s)]
+            I64Load16s {
+                @result: Reg,
+                /// The linear memory index for which the load instruction is executed.
                 memory: Memory,
             },
             /// Wasm `i64.load16_s` equivalent Wasmi instruction.
@@ -1849,6 +2214,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Load16sAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load16_s_at_unchecked)]
+            I64Load16sAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i64.load16_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Load16s`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_load16_s_offset16)]
@@ -1859,6 +2237,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i64.load16_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Load16sOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load16_s_offset16_unchecked)]
+            I64Load16sOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i64.load16_u` equivalent Wasmi instruction.
             ///
@@ -1892,6 +2285,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Load16uAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load16_u_at_unchecked)]
+            I64Load16uAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i64.load16_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Load16u`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_load16_u_offset16)]
@@ -1902,6 +2308,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i64.load16_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Load16uOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load16_u_offset16_unchecked)]
+            I64Load16uOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i64.load32_s` equivalent Wasmi instruction.
             ///
@@ -1935,6 +2356,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Load32sAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load32_s_at_unchecked)]
+            I64Load32sAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i64.load32_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Load32s`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_load32_s_offset16)]
@@ -1945,6 +2379,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i64.load32_s` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Load32sOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load32_s_offset16_unchecked)]
+            I64Load32sOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Wasm `i64.load32_u` equivalent Wasmi instruction.
             ///
@@ -1978,6 +2427,19 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Load32uAt`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load32_u_at_unchecked)]
+            I64Load32uAtUnchecked {
+                @result: Reg,
+                /// The `ptr+offset` address of the `load` instruction.
+                address: u32,
+            },
+            /// Wasm `i64.load32_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Load32u`] with a 16-bit `offset`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_load32_u_offset16)]
@@ -1988,6 +2450,21 @@ macro_rules! for_each_op {
                 /// The 16-bit encoded offset of the `load` instruction.
                 offset: Const16<u32>,
             },
+            /// Wasm `i64.load32_u` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Load32uOffset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_load32_u_offset16_unchecked)]
+            I64Load32uOffset16Unchecked {
+                @result: Reg,
+                /// The register storing the pointer of the `load` instruction.
+                ptr: Reg,
+                /// The 16-bit encoded offset of the `load` instruction.
+                offset: Const16<u32>,
+            },
 
             /// Store instruction for 32-bit values.
             ///
@@ -2024,6 +2501,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::Store32Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(store32_offset16_unchecked)]
+            Store32Offset16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Reg,
+            },
+            /// Store instruction for 32-bit values.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::Store32`] with an immediate `ptr+offset` address.
             ///
             /// # Encoding
@@ -2038,6 +2531,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Store instruction for 32-bit values.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::Store32At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(store32_at_unchecked)]
+            Store32AtUnchecked {
+                /// The value to be stored.
+                value: Reg,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Store instruction for 64-bit values.
             ///
@@ -2074,6 +2581,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::Store64Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(store64_offset16_unchecked)]
+            Store64Offset16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Reg,
+            },
+            /// Store instruction for 64-bit values.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::Store64`] with an immediate `ptr+offset` address.
             ///
             /// # Encoding
@@ -2088,6 +2611,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Store instruction for 64-bit values.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::Store64At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(store64_at_unchecked)]
+            Store64AtUnchecked {
+                /// The value to be stored.
+                value: Reg,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i32.store` equivalent Wasmi instruction.
             ///
@@ -2124,6 +2661,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32StoreOffset16Imm16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store_offset16_imm16_unchecked)]
+            I32StoreOffset16Imm16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Const16<i32>,
+            },
+            /// Wasm `i32.store` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::Store32At`] with 16-bit immediate `value`.
             ///
             /// # Encoding
@@ -2138,6 +2691,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Wasm `i32.store` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32StoreAtImm16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store_at_imm16_unchecked)]
+            I32StoreAtImm16Unchecked {
+                /// The value to be stored.
+                value: Const16<i32>,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i32.store` equivalent Wasmi instruction.
             ///
@@ -2186,6 +2753,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Store8Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store8_offset16_unchecked)]
+            I32Store8Offset16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Reg,
+            },
+            /// Wasm `i32.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I32Store8Offset16`] with immediate `value`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i32_store8_offset16_imm)]
@@ -2201,6 +2784,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Store8Offset16Imm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store8_offset16_imm_unchecked)]
+            I32Store8Offset16ImmUnchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: i8,
+            },
+            /// Wasm `i32.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I32Store8`] with an immediate `ptr+offset` address.
             ///
             /// # Encoding
@@ -2219,6 +2818,20 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Store8At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store8_at_unchecked)]
+            I32Store8AtUnchecked {
+                /// The value to be stored.
+                value: Reg,
+                /// The constant address to store the value.
+                address: u32,
+            },
+            /// Wasm `i32.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I32Store8At`] with immediate `value`.
             ///
             /// # Encoding
@@ -2233,6 +2846,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Wasm `i32.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32Store8AtImm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store8_at_imm_unchecked)]
+            I32Store8AtImmUnchecked {
+                /// The value to be stored.
+                value: i8,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i32.store16` equivalent Wasmi instruction.
             ///
@@ -2281,6 +2908,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Store16Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store16_offset16_unchecked)]
+            I32Store16Offset16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Reg,
+            },
+            /// Wasm `i32.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I32Store16Offset16`] with immediate `value`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i32_store16_offset16_imm)]
@@ -2296,6 +2939,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Store16Offset16Imm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store16_offset16_imm_unchecked)]
+            I32Store16Offset16ImmUnchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: i16,
+            },
+            /// Wasm `i32.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I32Store16`] with an immediate `ptr+offset` address.
             ///
             /// # Encoding
@@ -2314,6 +2973,20 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I32Store16At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store16_at_unchecked)]
+            I32Store16AtUnchecked {
+                /// The value to be stored.
+                value: Reg,
+                /// The constant address to store the value.
+                address: u32,
+            },
+            /// Wasm `i32.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I32Store16At`] with immediate `value`.
             ///
             /// # Encoding
@@ -2328,6 +3001,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Wasm `i32.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I32Store16AtImm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i32_store16_at_imm_unchecked)]
+            I32Store16AtImmUnchecked {
+                /// The value to be stored.
+                value: i16,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i64.store` equivalent Wasmi instruction.
             ///
@@ -2364,6 +3051,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64StoreOffset16Imm16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store_offset16_imm16_unchecked)]
+            I64StoreOffset16Imm16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Const16<i64>,
+            },
+            /// Wasm `i64.store` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::Store64At`] with 16-bit immediate `value`.
             ///
             /// # Encoding
@@ -2378,6 +3081,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Wasm `i64.store` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64StoreAtImm16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store_at_imm16_unchecked)]
+            I64StoreAtImm16Unchecked {
+                /// The value to be stored.
+                value: Const16<i64>,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i64.store8` equivalent Wasmi instruction.
             ///
@@ -2426,6 +3143,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store8Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store8_offset16_unchecked)]
+            I64Store8Offset16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Reg,
+            },
+            /// Wasm `i64.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Store8Offset16`] with immediate `value`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_store8_offset16_imm)]
@@ -2441,6 +3174,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store8Offset16Imm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store8_offset16_imm_unchecked)]
+            I64Store8Offset16ImmUnchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: i8,
+            },
+            /// Wasm `i64.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I64Store8`] with an immediate `ptr+offset` address.
             ///
             /// # Encoding
@@ -2459,6 +3208,20 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store8At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store8_at_unchecked)]
+            I64Store8AtUnchecked {
+                /// The value to be stored.
+                value: Reg,
+                /// The constant address to store the value.
+                address: u32,
+            },
+            /// Wasm `i64.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I64Store8At`] with immediate `value`.
             ///
             /// # Encoding
@@ -2473,6 +3236,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Wasm `i64.store8` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Store8AtImm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store8_at_imm_unchecked)]
+            I64Store8AtImmUnchecked {
+                /// The value to be stored.
+                value: i8,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i64.store16` equivalent Wasmi instruction.
             ///
@@ -2521,6 +3298,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store16Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store16_offset16_unchecked)]
+            I64Store16Offset16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Reg,
+            },
+            /// Wasm `i64.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Store16Offset16`] with immediate `value`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_store16_offset16_imm)]
@@ -2536,6 +3329,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store16Offset16Imm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store16_offset16_imm_unchecked)]
+            I64Store16Offset16ImmUnchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: i16,
+            },
+            /// Wasm `i64.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I64Store16`] with an immediate `ptr+offset` address.
             ///
             /// # Encoding
@@ -2554,6 +3363,20 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store16At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store16_at_unchecked)]
+            I64Store16AtUnchecked {
+                /// The value to be stored.
+                value: Reg,
+                /// The constant address to store the value.
+                address: u32,
+            },
+            /// Wasm `i64.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I64Store16At`] with immediate `value`.
             ///
             /// # Encoding
@@ -2568,6 +3391,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Wasm `i64.store16` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Store16AtImm`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store16_at_imm_unchecked)]
+            I64Store16AtImmUnchecked {
+                /// The value to be stored.
+                value: i16,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i64.store32` equivalent Wasmi instruction.
             ///
@@ -2616,6 +3453,22 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store32Offset16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store32_offset16_unchecked)]
+            I64Store32Offset16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Reg,
+            },
+            /// Wasm `i64.store32` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// - Variant of [`Instruction::I64Store32Offset16`] with 16-bit immediate `value`.
             /// - Operates on the default Wasm memory instance.
             #[snake_name(i64_store32_offset16_imm16)]
@@ -2631,15 +3484,45 @@ macro_rules! for_each_op {
             ///
             /// # Note
             ///
+            /// - Variant of [`Instruction::I64Store32Offset16Imm16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store32_offset16_imm16_unchecked)]
+            I64Store32Offset16Imm16Unchecked {
+                /// The register storing the pointer of the `store` instruction.
+                ptr: Reg,
+                /// The register storing the pointer offset of the `store` instruction.
+                offset: Const16<u32>,
+                /// The value to be stored.
+                value: Const16<i32>,
+            },
+            /// Wasm `i64.store32` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
             /// Variant of [`Instruction::I64Store32`] with an immediate `ptr+offset` address.
             ///
             /// # Encoding
             ///
-            /// Optionally followed by an [`Instruction::MemoryIndex`] encoding `memory`.
+            /// Optionally followed by an [`Instruction::MemoryIndex`] encoding `memory`.
+            ///
+            /// - Operates on the default Wasm memory instance if missing.
+            #[snake_name(i64_store32_at)]
+            I64Store32At {
+                /// The value to be stored.
+                value: Reg,
+                /// The constant address to store the value.
+                address: u32,
+            },
+            /// Wasm `i64.store32` equivalent Wasmi instruction.
+            ///
+            /// # Note
             ///
-            /// - Operates on the default Wasm memory instance if missing.
-            #[snake_name(i64_store32_at)]
-            I64Store32At {
+            /// - Variant of [`Instruction::I64Store32At`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store32_at_unchecked)]
+            I64Store32AtUnchecked {
                 /// The value to be stored.
                 value: Reg,
                 /// The constant address to store the value.
@@ -2663,6 +3546,20 @@ macro_rules! for_each_op {
                 /// The constant address to store the value.
                 address: u32,
             },
+            /// Wasm `i64.store32` equivalent Wasmi instruction.
+            ///
+            /// # Note
+            ///
+            /// - Variant of [`Instruction::I64Store32AtImm16`] without bounds checks.
+            /// - Only emitted if the accessed bytes are known to be in bounds.
+            /// - Operates on the default Wasm memory instance.
+            #[snake_name(i64_store32_at_imm16_unchecked)]
+            I64Store32AtImm16Unchecked {
+                /// The value to be stored.
+                value: Const16<i32>,
+                /// The constant address to store the value.
+                address: u32,
+            },
 
             /// Wasm `i32.eq` equivalent Wasmi instruction.
             #[snake_name(i32_eq)]
@@ -3642,6 +4539,57 @@ macro_rules! for_each_op {
                 rhs: Const16<i64>,
             },
 
+            /// Wasm `i64.add128` instruction from the Wasm `wide-arithmetic` proposal.
+            ///
+            /// Adds two 128-bit integers each encoded as `[lo, hi]` pair of `i64` values.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register2`] encoding the `[lo, hi]`
+            /// registers of the right-hand side value.
+            #[snake_name(i64_add128)]
+            I64Add128 {
+                @results: FixedRegSpan<2>,
+                /// The registers holding the `[lo, hi]` halves of the left-hand side value.
+                lhs: [Reg; 2],
+            },
+            /// Wasm `i64.sub128` instruction from the Wasm `wide-arithmetic` proposal.
+            ///
+            /// Subtracts two 128-bit integers each encoded as `[lo, hi]` pair of `i64` values.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register2`] encoding the `[lo, hi]`
+            /// registers of the right-hand side value.
+            #[snake_name(i64_sub128)]
+            I64Sub128 {
+                @results: FixedRegSpan<2>,
+                /// The registers holding the `[lo, hi]` halves of the left-hand side value.
+                lhs: [Reg; 2],
+            },
+            /// Wasm `i64.mul_wide_s` instruction from the Wasm `wide-arithmetic` proposal.
+            ///
+            /// Stores the signed 128-bit product of `lhs` and `rhs` as `[lo, hi]` into `results`.
+            #[snake_name(i64_mul_wide_s)]
+            I64MulWideS {
+                @results: FixedRegSpan<2>,
+                /// The register holding the left-hand side value.
+                lhs: Reg,
+                /// The register holding the right-hand side value.
+                rhs: Reg,
+            },
+            /// Wasm `i64.mul_wide_u` instruction from the Wasm `wide-arithmetic` proposal.
+            ///
+            /// Stores the unsigned 128-bit product of `lhs` and `rhs` as `[lo, hi]` into `results`.
+            #[snake_name(i64_mul_wide_u)]
+            I64MulWideU {
+                @results: FixedRegSpan<2>,
+                /// The register holding the left-hand side value.
+                lhs: Reg,
+                /// The register holding the right-hand side value.
+                rhs: Reg,
+            },
+
             /// `i64` signed-division instruction: `r0 = r1 / r2`
             #[snake_name(i64_div_s)]
             I64DivS {
@@ -5437,6 +6385,438 @@ macro_rules! for_each_op {
                 len: Const16<u32>,
             },
 
+            /// Wasm `struct.new` instruction from the Wasm `gc` proposal.
+            ///
+            /// Allocates a new struct object of type `ty` with the given field values.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. Zero or more [`Instruction::RegisterList`]
+            /// 2. Followed by one of
+            ///     - [`Instruction::Register`]
+            ///     - [`Instruction::Register2`]
+            ///     - [`Instruction::Register3`]
+            #[snake_name(struct_new)]
+            StructNew {
+                @result: Reg,
+                /// The type of the allocated struct.
+                ty: Type,
+            },
+            /// Wasm `struct.new_default` instruction from the Wasm `gc` proposal.
+            ///
+            /// Allocates a new struct object of type `ty` with default field values.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Const32`] holding the number of struct fields.
+            #[snake_name(struct_new_default)]
+            StructNewDefault {
+                @result: Reg,
+                /// The type of the allocated struct.
+                ty: Type,
+            },
+            /// Wasm `struct.get` instruction from the Wasm `gc` proposal.
+            #[snake_name(struct_get)]
+            StructGet {
+                @result: Reg,
+                /// The register holding the struct reference.
+                struct_ref: Reg,
+                /// The index of the accessed struct field.
+                field: Const16<u32>,
+            },
+            /// Wasm `struct.get_s` instruction for packed `i8` fields.
+            #[snake_name(struct_get_i8_s)]
+            StructGetI8S {
+                @result: Reg,
+                /// The register holding the struct reference.
+                struct_ref: Reg,
+                /// The index of the accessed struct field.
+                field: Const16<u32>,
+            },
+            /// Wasm `struct.get_u` instruction for packed `i8` fields.
+            #[snake_name(struct_get_i8_u)]
+            StructGetI8U {
+                @result: Reg,
+                /// The register holding the struct reference.
+                struct_ref: Reg,
+                /// The index of the accessed struct field.
+                field: Const16<u32>,
+            },
+            /// Wasm `struct.get_s` instruction for packed `i16` fields.
+            #[snake_name(struct_get_i16_s)]
+            StructGetI16S {
+                @result: Reg,
+                /// The register holding the struct reference.
+                struct_ref: Reg,
+                /// The index of the accessed struct field.
+                field: Const16<u32>,
+            },
+            /// Wasm `struct.get_u` instruction for packed `i16` fields.
+            #[snake_name(struct_get_i16_u)]
+            StructGetI16U {
+                @result: Reg,
+                /// The register holding the struct reference.
+                struct_ref: Reg,
+                /// The index of the accessed struct field.
+                field: Const16<u32>,
+            },
+            /// Wasm `struct.set` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Note
+            ///
+            /// Values of packed fields are truncated upon access.
+            #[snake_name(struct_set)]
+            StructSet {
+                /// The register holding the struct reference.
+                struct_ref: Reg,
+                /// The index of the accessed struct field.
+                field: Const16<u32>,
+                /// The register holding the stored value.
+                value: Reg,
+            },
+
+            /// Wasm `array.new` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register2`] encoding
+            ///
+            /// 1. `len`: the number of elements of the allocated array
+            /// 2. `value`: the initial value of all elements
+            #[snake_name(array_new)]
+            ArrayNew {
+                @result: Reg,
+                /// The type of the allocated array.
+                ty: Type,
+            },
+            /// Wasm `array.new_default` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register`] holding the number
+            /// of elements of the allocated array.
+            #[snake_name(array_new_default)]
+            ArrayNewDefault {
+                @result: Reg,
+                /// The type of the allocated array.
+                ty: Type,
+            },
+            /// Wasm `array.new_fixed` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. Zero or more [`Instruction::RegisterList`]
+            /// 2. Followed by one of
+            ///     - [`Instruction::Register`]
+            ///     - [`Instruction::Register2`]
+            ///     - [`Instruction::Register3`]
+            #[snake_name(array_new_fixed)]
+            ArrayNewFixed {
+                @result: Reg,
+                /// The type of the allocated array.
+                ty: Type,
+            },
+            /// Wasm `array.new_data` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. [`Instruction::DataIndex`]: the data segment holding the elements
+            /// 2. [`Instruction::Register2`]: the `offset` into the data segment and `len`
+            #[snake_name(array_new_data)]
+            ArrayNewData {
+                @result: Reg,
+                /// The type of the allocated array.
+                ty: Type,
+            },
+            /// Wasm `array.new_elem` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. [`Instruction::ElemIndex`]: the element segment holding the elements
+            /// 2. [`Instruction::Register2`]: the `offset` into the element segment and `len`
+            #[snake_name(array_new_elem)]
+            ArrayNewElem {
+                @result: Reg,
+                /// The type of the allocated array.
+                ty: Type,
+            },
+            /// Wasm `array.get` instruction from the Wasm `gc` proposal.
+            #[snake_name(array_get)]
+            ArrayGet {
+                @result: Reg,
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the accessed array element.
+                index: Reg,
+            },
+            /// Wasm `array.get_s` instruction for packed `i8` elements.
+            #[snake_name(array_get_i8_s)]
+            ArrayGetI8S {
+                @result: Reg,
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the accessed array element.
+                index: Reg,
+            },
+            /// Wasm `array.get_u` instruction for packed `i8` elements.
+            #[snake_name(array_get_i8_u)]
+            ArrayGetI8U {
+                @result: Reg,
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the accessed array element.
+                index: Reg,
+            },
+            /// Wasm `array.get_s` instruction for packed `i16` elements.
+            #[snake_name(array_get_i16_s)]
+            ArrayGetI16S {
+                @result: Reg,
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the accessed array element.
+                index: Reg,
+            },
+            /// Wasm `array.get_u` instruction for packed `i16` elements.
+            #[snake_name(array_get_i16_u)]
+            ArrayGetI16U {
+                @result: Reg,
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the accessed array element.
+                index: Reg,
+            },
+            /// Wasm `array.set` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Note
+            ///
+            /// Values of packed elements are truncated upon access.
+            #[snake_name(array_set)]
+            ArraySet {
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the accessed array element.
+                index: Reg,
+                /// The register holding the stored value.
+                value: Reg,
+            },
+            /// Wasm `array.len` instruction from the Wasm `gc` proposal.
+            #[snake_name(array_len)]
+            ArrayLen {
+                @result: Reg,
+                /// The register holding the array reference.
+                array: Reg,
+            },
+            /// Wasm `array.fill` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register`] holding the number of filled elements.
+            #[snake_name(array_fill)]
+            ArrayFill {
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the first filled element.
+                offset: Reg,
+                /// The register holding the fill value.
+                value: Reg,
+            },
+            /// Wasm `array.copy` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Register2`] encoding
+            ///
+            /// 1. `src_offset`: the index of the first copied element of the `src` array
+            /// 2. `len`: the number of copied elements
+            #[snake_name(array_copy)]
+            ArrayCopy {
+                /// The register holding the destination array reference.
+                dst: Reg,
+                /// The register holding the index of the first copied element of the `dst` array.
+                dst_offset: Reg,
+                /// The register holding the source array reference.
+                src: Reg,
+            },
+            /// Wasm `array.init_data` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. [`Instruction::DataIndex`]: the data segment holding the elements
+            /// 2. [`Instruction::Register`]: the number of initialized elements
+            #[snake_name(array_init_data)]
+            ArrayInitData {
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the first initialized array element.
+                dst: Reg,
+                /// The register holding the offset into the data segment.
+                src: Reg,
+            },
+            /// Wasm `array.init_elem` instruction from the Wasm `gc` proposal.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. [`Instruction::ElemIndex`]: the element segment holding the elements
+            /// 2. [`Instruction::Register`]: the number of initialized elements
+            #[snake_name(array_init_elem)]
+            ArrayInitElem {
+                /// The register holding the array reference.
+                array: Reg,
+                /// The register holding the index of the first initialized array element.
+                dst: Reg,
+                /// The register holding the offset into the element segment.
+                src: Reg,
+            },
+
+            /// Wasm `ref.i31` instruction from the Wasm `gc` proposal.
+            #[snake_name(ref_i31)]
+            RefI31 {
+                @result: Reg,
+                /// The register holding the `i32` input value.
+                input: Reg,
+            },
+            /// Wasm `i31.get_s` instruction from the Wasm `gc` proposal.
+            #[snake_name(i31_get_s)]
+            I31GetS {
+                @result: Reg,
+                /// The register holding the `i31ref` input value.
+                input: Reg,
+            },
+            /// Wasm `i31.get_u` instruction from the Wasm `gc` proposal.
+            #[snake_name(i31_get_u)]
+            I31GetU {
+                @result: Reg,
+                /// The register holding the `i31ref` input value.
+                input: Reg,
+            },
+            /// Wasm `ref.test` instruction from the Wasm `gc` proposal.
+            ///
+            /// Stores `1` to `result` if `input` matches the reference type and `0` otherwise.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Const32`] holding the packed
+            /// representation of the reference type tested against.
+            #[snake_name(ref_test)]
+            RefTest {
+                @result: Reg,
+                /// The register holding the tested reference.
+                input: Reg,
+            },
+            /// Wasm `ref.cast` instruction from the Wasm `gc` proposal.
+            ///
+            /// Copies `input` to `result` if it matches the reference type and traps otherwise.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::Const32`] holding the packed
+            /// representation of the reference type casted to.
+            #[snake_name(ref_cast)]
+            RefCast {
+                @result: Reg,
+                /// The register holding the casted reference.
+                input: Reg,
+            },
+            /// Wasm `any.convert_extern` instruction from the Wasm `gc` proposal.
+            #[snake_name(any_convert_extern)]
+            AnyConvertExtern {
+                @result: Reg,
+                /// The register holding the `externref` input value.
+                input: Reg,
+            },
+            /// Wasm `extern.convert_any` instruction from the Wasm `gc` proposal.
+            #[snake_name(extern_convert_any)]
+            ExternConvertAny {
+                @result: Reg,
+                /// The register holding the `anyref` input value.
+                input: Reg,
+            },
+
+            /// Wasm `cont.new` instruction from the Wasm `stack-switching` proposal.
+            ///
+            /// Creates a new continuation that executes the referenced function once resumed.
+            #[snake_name(cont_new)]
+            ContNew {
+                @result: Reg,
+                /// The register holding the function reference.
+                func: Reg,
+            },
+            /// Wasm `cont.bind` instruction from the Wasm `stack-switching` proposal.
+            ///
+            /// Consumes the continuation and returns a new continuation with the given
+            /// leading parameters bound.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::RegisterSpan`] encoding the bound values.
+            #[snake_name(cont_bind)]
+            ContBind {
+                @result: Reg,
+                /// The register holding the bound continuation.
+                cont: Reg,
+            },
+            /// Wasm `resume` instruction from the Wasm `stack-switching` proposal.
+            ///
+            /// Consumes the continuation and executes it until it returns or suspends
+            /// to one of the handlers of the [`Instruction::Resume`].
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by
+            ///
+            /// 1. [`Instruction::ResumeParams`]
+            /// 2. `len_handlers` handlers each encoded by either
+            ///     - [`Instruction::ResumeHandler`] followed by one of
+            ///         - [`Instruction::BranchTableTarget`]: branch to the handler label
+            ///         - [`Instruction::Return`]: return from the function
+            ///     - [`Instruction::SwitchHandler`]
+            #[snake_name(resume)]
+            Resume {
+                @results: BoundedRegSpan,
+                /// The register holding the resumed continuation.
+                cont: Reg,
+            },
+            /// Wasm `suspend` instruction from the Wasm `stack-switching` proposal.
+            ///
+            /// Suspends the current continuation to the innermost handler of `tag`.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::RegisterSpan`] encoding the tag parameters.
+            #[snake_name(suspend)]
+            Suspend {
+                @results: RegSpan,
+                /// The suspending tag.
+                tag: Tag,
+            },
+            /// Wasm `switch` instruction from the Wasm `stack-switching` proposal.
+            ///
+            /// Suspends the current continuation and resumes the target continuation
+            /// in its place under the innermost switch handler of `tag`.
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by [`Instruction::SwitchParams`].
+            #[snake_name(switch)]
+            Switch {
+                /// The register holding the target continuation.
+                cont: Reg,
+                /// The tag of the switch handler.
+                tag: Tag,
+            },
+
             /// A [`Table`] instruction parameter.
             ///
             /// # Note
@@ -5507,6 +6887,61 @@ macro_rules! for_each_op {
             F64Const32 {
                 value: Const32<f64>
             },
+            /// The parameters of an [`Instruction::Resume`].
+            ///
+            /// # Note
+            ///
+            /// This [`Instruction`] only acts as a parameter to another
+            /// one and will never be executed itself directly.
+            #[snake_name(resume_params)]
+            ResumeParams {
+                /// The registers holding the values passed to the resumed continuation.
+                args: BoundedRegSpan,
+                /// The number of handlers following the [`Instruction::ResumeParams`].
+                len_handlers: u16,
+            },
+            /// A suspend handler of an [`Instruction::Resume`].
+            ///
+            /// # Encoding
+            ///
+            /// Must be followed by one of
+            ///
+            /// - [`Instruction::BranchTableTarget`]: branch to the handler label
+            /// - [`Instruction::Return`]: return from the function
+            ///
+            /// # Note
+            ///
+            /// This [`Instruction`] only acts as a parameter to another
+            /// one and will never be executed itself directly.
+            #[snake_name(resume_handler)]
+            ResumeHandler {
+                /// The handled tag.
+                tag: Tag,
+            },
+            /// A switch handler of an [`Instruction::Resume`].
+            ///
+            /// # Note
+            ///
+            /// This [`Instruction`] only acts as a parameter to another
+            /// one and will never be executed itself directly.
+            #[snake_name(switch_handler)]
+            SwitchHandler {
+                /// The handled tag.
+                tag: Tag,
+            },
+            /// The parameters of an [`Instruction::Switch`].
+            ///
+            /// # Note
+            ///
+            /// This [`Instruction`] only acts as a parameter to another
+            /// one and will never be executed itself directly.
+            #[snake_name(switch_params)]
+            SwitchParams {
+                /// The registers holding the values passed to the target continuation.
+                args: BoundedRegSpan,
+                /// The registers receiving the values once the current continuation is resumed.
+                results: RegSpan,
+            },
             /// A Wasm `br_table` branching target which copies values before branching.
             ///
             /// # Encoding
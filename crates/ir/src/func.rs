@@ -0,0 +1,87 @@
+use crate::{core::UntypedVal, Instruction};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// A function body of Wasmi IR [`Instruction`]s and its function local constant values.
+///
+/// # Text Format
+///
+/// An [`IrFunc`] is displayed in and parsed from the following text format:
+///
+/// ```text
+/// registers: 3
+/// consts: 1
+///   c0 = 0x00000000000186a0
+/// instrs: 4
+///   0: branch_i32_eq_imm16 lhs=r0, rhs=0, offset=@3
+///   1: r1 = i32_add lhs=r0, rhs=c0
+///   2: return_reg value=r1
+///   3: return_imm32 value=0x00000000
+/// ```
+///
+/// When parsing, empty lines and lines starting with `;;` are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IrFunc {
+    /// The number of registers used by the function including its function local constant values.
+    len_registers: u16,
+    /// The function local constant values in allocation order.
+    consts: Vec<UntypedVal>,
+    /// The Wasmi IR instructions of the function.
+    instrs: Vec<Instruction>,
+}
+
+impl IrFunc {
+    /// Creates a new [`IrFunc`].
+    ///
+    /// # Note
+    ///
+    /// - The `consts` are expected in allocation order, so the first value is referred to by `c0`.
+    /// - Use [`IrFunc::validate`] to check that `self` is well-formed.
+    pub fn new<C, I>(len_registers: u16, consts: C, instrs: I) -> Self
+    where
+        C: IntoIterator<Item = UntypedVal>,
+        I: IntoIterator<Item = Instruction>,
+    {
+        Self {
+            len_registers,
+            consts: consts.into_iter().collect(),
+            instrs: instrs.into_iter().collect(),
+        }
+    }
+
+    /// Returns the number of registers used by `self` including its function local constant values.
+    pub fn len_registers(&self) -> u16 {
+        self.len_registers
+    }
+
+    /// Returns the function local constant values of `self` in allocation order.
+    pub fn consts(&self) -> &[UntypedVal] {
+        &self.consts
+    }
+
+    /// Returns the Wasmi IR [`Instruction`]s of `self`.
+    pub fn instrs(&self) -> &[Instruction] {
+        &self.instrs
+    }
+}
+
+impl Display for IrFunc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "registers: {}", self.len_registers)?;
+        writeln!(f, "consts: {}", self.consts.len())?;
+        for (n, value) in self.consts.iter().enumerate() {
+            writeln!(f, "  c{n} = 0x{:016x}", u64::from(*value))?;
+        }
+        writeln!(f, "instrs: {}", self.instrs.len())?;
+        let width = decimal_digits(self.instrs.len().saturating_sub(1));
+        for (index, instr) in self.instrs.iter().enumerate() {
+            writeln!(f, "  {index:>width$}: {}", instr.display_at(index))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of decimal digits required to display `value`.
+fn decimal_digits(value: usize) -> usize {
+    value.checked_ilog10().map_or(1, |log| log as usize + 1)
+}
@@ -13,7 +13,7 @@ pub struct OutOfBoundsConst;
 #[derive(Debug)]
 pub struct Const16<T> {
     /// The underlying untyped value.
-    inner: AnyConst16,
+    pub(crate) inner: AnyConst16,
     /// The type marker to satisfy the Rust type system.
     marker: PhantomData<fn() -> T>,
 }
@@ -27,7 +27,7 @@ impl<T> Const16<T> {
 
 impl<T> Const16<T> {
     /// Crete a new typed [`Const16`] value.
-    fn new(inner: AnyConst16) -> Self {
+    pub(crate) fn new(inner: AnyConst16) -> Self {
         Self {
             inner,
             marker: PhantomData,
@@ -222,7 +222,7 @@ impl TryFrom<NonZeroU64> for Const16<NonZeroU64> {
 /// A typed 32-bit encoded constant value.
 pub struct Const32<T> {
     /// The underlying untyped value.
-    inner: AnyConst32,
+    pub(crate) inner: AnyConst32,
     /// The type marker to satisfy the Rust type system.
     marker: PhantomData<fn() -> T>,
 }
@@ -240,7 +240,7 @@ where
 
 impl<T> Const32<T> {
     /// Crete a new typed [`Const32`] value.
-    fn new(inner: AnyConst32) -> Self {
+    pub(crate) fn new(inner: AnyConst32) -> Self {
         Self {
             inner,
             marker: PhantomData,
@@ -23,6 +23,10 @@ macro_rules! for_each_index {
             Data(pub(crate) u32);
             /// A Wasm element segment index.
             Elem(pub(crate) u32);
+            /// An engine-wide identifier of a struct or array type.
+            Type(pub(crate) u32);
+            /// A Wasm tag index.
+            Tag(pub(crate) u32);
         }
     };
 }
@@ -4,13 +4,20 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod branch;
+#[cfg(feature = "compact")]
+mod compact;
+mod display;
 mod r#enum;
 mod error;
 mod for_each_op;
+mod func;
 mod immeditate;
 pub mod index;
+mod parse;
 mod primitive;
 mod span;
+mod validate;
 mod visit_regs;
 
 #[cfg(test)]
@@ -20,9 +27,12 @@ use wasmi_core as core;
 
 #[doc(inline)]
 pub use self::{
+    display::DisplayInstruction,
     error::Error,
+    func::IrFunc,
     immeditate::{AnyConst16, AnyConst32, Const16, Const32},
     index::Reg,
+    parse::ParseError,
     primitive::{
         BlockFuel,
         BranchOffset,
@@ -35,5 +45,6 @@ pub use self::{
     },
     r#enum::Instruction,
     span::{BoundedRegSpan, FixedRegSpan, RegSpan, RegSpanIter},
+    validate::ValidationError,
     visit_regs::VisitRegs,
 };
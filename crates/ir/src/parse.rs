@@ -0,0 +1,552 @@
+use crate::{
+    core::{TrapCode, UntypedVal},
+    for_each_op,
+    index::*,
+    *,
+};
+use ::core::{
+    fmt::{self, Display},
+    num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64},
+    str::FromStr,
+};
+use alloc::{format, string::String, vec::Vec};
+
+/// An error that may occur when parsing Wasmi IR from its text format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line number at which the error occurred if known.
+    line: Option<usize>,
+    /// The description of the error.
+    message: String,
+}
+
+impl ParseError {
+    /// Creates a new [`ParseError`] with the given `message`.
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    /// Attaches the 1-based `line` number to `self` unless it already has one.
+    fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Returns the 1-based line number at which the error occurred if known.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Returns the description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl Instruction {
+    /// Parses an [`Instruction`] located at `index` within its function from its text format.
+    ///
+    /// This is the inverse of [`Instruction::display_at`] and resolves absolute branch
+    /// targets `@N` to branch offsets relative to `index`.
+    ///
+    /// # Errors
+    ///
+    /// If `s` is not a valid [`Instruction`] in the text format.
+    pub fn parse_at(s: &str, index: usize) -> Result<Self, ParseError> {
+        parse_instr(s, Some(index))
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    /// Parses an [`Instruction`] from its text format.
+    ///
+    /// # Note
+    ///
+    /// Since the index of the [`Instruction`] is unknown its branch offsets must
+    /// be given as relative signed offsets such as `+3` or `-2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_instr(s, None)
+    }
+}
+
+impl FromStr for IrFunc {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(n, line)| (n + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with(";;"));
+        let mut next_line = |expected: &str| {
+            lines.next().ok_or_else(|| {
+                ParseError::new(format!("unexpected end of input: expected {expected}"))
+            })
+        };
+        let (line, text) = next_line("`registers: N`")?;
+        let len_registers = parse_header::<u16>(text, "registers").map_err(|e| e.at_line(line))?;
+        let (line, text) = next_line("`consts: N`")?;
+        let len_consts = parse_header::<usize>(text, "consts").map_err(|e| e.at_line(line))?;
+        let mut consts = Vec::with_capacity(len_consts);
+        for n in 0..len_consts {
+            let (line, text) = next_line("function local constant value")?;
+            let value = parse_const(text, n).map_err(|e| e.at_line(line))?;
+            consts.push(value);
+        }
+        let (line, text) = next_line("`instrs: N`")?;
+        let len_instrs = parse_header::<usize>(text, "instrs").map_err(|e| e.at_line(line))?;
+        let mut instrs = Vec::with_capacity(len_instrs);
+        for index in 0..len_instrs {
+            let (line, text) = next_line("instruction")?;
+            let instr = parse_indexed_instr(text, index).map_err(|e| e.at_line(line))?;
+            instrs.push(instr);
+        }
+        if let Some((line, text)) = lines.next() {
+            return Err(
+                ParseError::new(format!("unexpected trailing input: {text}")).at_line(line),
+            );
+        }
+        Ok(IrFunc::new(len_registers, consts, instrs))
+    }
+}
+
+/// Parses a header line of the form `{name}: N`.
+fn parse_header<T: FromStr>(text: &str, name: &str) -> Result<T, ParseError> {
+    text.strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .and_then(|value| value.trim().parse().ok())
+        .ok_or_else(|| ParseError::new(format!("expected `{name}: N` but found: {text}")))
+}
+
+/// Parses the `n`-th function local constant value of the form `cN = 0x...`.
+fn parse_const(text: &str, n: usize) -> Result<UntypedVal, ParseError> {
+    let error = || ParseError::new(format!("expected `c{n} = 0x...` but found: {text}"));
+    let (name, value) = text.split_once('=').ok_or_else(error)?;
+    if name.trim() != format!("c{n}") {
+        return Err(error());
+    }
+    let bits = value
+        .trim()
+        .strip_prefix("0x")
+        .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+        .ok_or_else(error)?;
+    Ok(UntypedVal::from(bits))
+}
+
+/// Parses the [`Instruction`] at `index` of the form `{index}: {instr}`.
+fn parse_indexed_instr(text: &str, index: usize) -> Result<Instruction, ParseError> {
+    let (prefix, instr) = text
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(format!("expected `{index}: ...` but found: {text}")))?;
+    if prefix.trim().parse::<usize>().ok() != Some(index) {
+        return Err(ParseError::new(format!(
+            "expected instruction at index {index} but found: {text}"
+        )));
+    }
+    Instruction::parse_at(instr.trim(), index)
+}
+
+/// Parses an [`Instruction`] at `index` if known.
+fn parse_instr(s: &str, index: Option<usize>) -> Result<Instruction, ParseError> {
+    let s = s.trim();
+    let (result, rest) = match s.split_once(" = ") {
+        Some((result, rest)) => (Some(result.trim()), rest.trim_start()),
+        None => (None, s),
+    };
+    let (name, operands) = match rest.split_once(' ') {
+        Some((name, operands)) => (name, operands.trim()),
+        None => (rest, ""),
+    };
+    let mut parser = OperandParser {
+        instr: name,
+        operands,
+        index,
+    };
+    let instr = parser.parse_instr(result)?;
+    parser.finish()?;
+    Ok(instr)
+}
+
+/// Parses the named operands of an [`Instruction`] separated by commas.
+struct OperandParser<'a> {
+    /// The name of the parsed [`Instruction`].
+    instr: &'a str,
+    /// The remaining unparsed operands.
+    operands: &'a str,
+    /// The index of the parsed [`Instruction`] within its function if known.
+    index: Option<usize>,
+}
+
+impl<'a> OperandParser<'a> {
+    /// Returns the next unparsed operand if any.
+    ///
+    /// Commas within brackets do not separate operands.
+    fn next_operand(&mut self) -> Option<&'a str> {
+        if self.operands.is_empty() {
+            return None;
+        }
+        let mut depth = 0_usize;
+        let end = self
+            .operands
+            .char_indices()
+            .find(|&(_, c)| match c {
+                '[' => {
+                    depth += 1;
+                    false
+                }
+                ']' => {
+                    depth = depth.saturating_sub(1);
+                    false
+                }
+                ',' => depth == 0,
+                _ => false,
+            })
+            .map(|(pos, _)| pos);
+        let operand = match end {
+            Some(end) => {
+                let (operand, rest) = self.operands.split_at(end);
+                self.operands = rest[1..].trim_start();
+                operand
+            }
+            None => ::core::mem::take(&mut self.operands),
+        };
+        Some(operand.trim())
+    }
+
+    /// Parses the next operand named `name` as `T`.
+    fn parse<T: ParseOperand>(&mut self, name: &str) -> Result<T, ParseError> {
+        let instr = self.instr;
+        let index = self.index;
+        let Some(operand) = self.next_operand() else {
+            return Err(ParseError::new(format!(
+                "missing operand `{name}` for `{instr}`"
+            )));
+        };
+        let value = match operand.split_once('=') {
+            Some((found, value)) if found.trim() == name => value.trim(),
+            _ => {
+                return Err(ParseError::new(format!(
+                    "expected operand `{name}` for `{instr}` but found: {operand}"
+                )))
+            }
+        };
+        T::parse_operand(value, index).ok_or_else(|| {
+            ParseError::new(format!("invalid operand `{name}` for `{instr}`: {value}"))
+        })
+    }
+
+    /// Parses the `result` of the [`Instruction`] as `T`.
+    fn parse_result<T: ParseOperand>(&self, result: Option<&str>) -> Result<T, ParseError> {
+        let instr = self.instr;
+        let Some(result) = result else {
+            return Err(ParseError::new(format!("missing result for `{instr}`")));
+        };
+        T::parse_operand(result, self.index)
+            .ok_or_else(|| ParseError::new(format!("invalid result for `{instr}`: {result}")))
+    }
+
+    /// Returns an error if there are operands left that have not been parsed.
+    fn finish(mut self) -> Result<(), ParseError> {
+        let instr = self.instr;
+        match self.next_operand() {
+            Some(operand) => Err(ParseError::new(format!(
+                "unexpected operand for `{instr}`: {operand}"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the `snake_name` of an [`Instruction`] without its raw identifier prefix.
+fn strip_raw(snake_name: &'static str) -> &'static str {
+    snake_name.strip_prefix("r#").unwrap_or(snake_name)
+}
+
+macro_rules! impl_parse {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $( @ $result_name:ident: $result_ty:ty, )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        impl OperandParser<'_> {
+            /// Parses the [`Instruction`] with its optional `result`.
+            fn parse_instr(&mut self, result: Option<&str>) -> Result<Instruction, ParseError> {
+                let instr = self.instr;
+                $(
+                    if instr == strip_raw(stringify!($snake_name)) {
+                        let results: &[&str] = &[$( $( stringify!($result_name) )? )?];
+                        if results.is_empty() && result.is_some() {
+                            return Err(ParseError::new(format!(
+                                "unexpected result for `{instr}`"
+                            )));
+                        }
+                        $( $(
+                            let $result_name = self.parse_result::<$result_ty>(result)?;
+                        )? )?
+                        $( $(
+                            let $field_name = self.parse::<$field_ty>(stringify!($field_name))?;
+                        )* )?
+                        return Ok(Instruction::$name {
+                            $(
+                                $( $result_name, )?
+                                $( $field_name, )*
+                            )?
+                        });
+                    }
+                )*
+                Err(ParseError::new(format!("unknown instruction: {instr}")))
+            }
+        }
+    };
+}
+for_each_op!(impl_parse);
+
+/// Implemented by types that can be parsed as [`Instruction`] operands.
+///
+/// This is the inverse of displaying an [`Instruction`] operand.
+trait ParseOperand: Sized {
+    /// Parses `s` as operand of the [`Instruction`] at `index` if known.
+    fn parse_operand(s: &str, index: Option<usize>) -> Option<Self>;
+}
+
+impl ParseOperand for Reg {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        if let Some(n) = s.strip_prefix('r') {
+            let n = n.parse::<i16>().ok()?;
+            return (n >= 0).then(|| Reg::from(n));
+        }
+        let n = s.strip_prefix('c')?.parse::<u16>().ok()?;
+        let reg = i16::try_from(-(i32::from(n) + 1)).ok()?;
+        Some(Reg::from(reg))
+    }
+}
+
+impl<const N: usize> ParseOperand for [Reg; N] {
+    fn parse_operand(s: &str, index: Option<usize>) -> Option<Self> {
+        let s = s.strip_prefix('[')?.strip_suffix(']')?;
+        let mut regs = [Reg::from(0); N];
+        let mut elems = s.split(',').map(str::trim);
+        for reg in &mut regs {
+            *reg = Reg::parse_operand(elems.next()?, index)?;
+        }
+        elems.next().is_none().then_some(regs)
+    }
+}
+
+impl ParseOperand for RegSpan {
+    fn parse_operand(s: &str, index: Option<usize>) -> Option<Self> {
+        let head = Reg::parse_operand(s.strip_suffix("..")?, index)?;
+        Some(RegSpan::new(head))
+    }
+}
+
+impl ParseOperand for BoundedRegSpan {
+    fn parse_operand(s: &str, index: Option<usize>) -> Option<Self> {
+        let (head, end) = s.split_once("..")?;
+        let head = Reg::parse_operand(head, index)?;
+        let end = Reg::parse_operand(end, index)?;
+        let len = u16::try_from(i32::from(i16::from(end)) - i32::from(i16::from(head))).ok()?;
+        Some(BoundedRegSpan::new(RegSpan::new(head), len))
+    }
+}
+
+impl<const N: u16> ParseOperand for FixedRegSpan<N> {
+    fn parse_operand(s: &str, index: Option<usize>) -> Option<Self> {
+        let span = BoundedRegSpan::parse_operand(s, index)?;
+        if span.len() != N {
+            return None;
+        }
+        FixedRegSpan::new(span.span()).ok()
+    }
+}
+
+/// Parses the branch offset of the [`Instruction`] at `index` if known.
+///
+/// Absolute branch targets `@N` require the `index` to be known.
+fn parse_branch_offset(s: &str, index: Option<usize>) -> Option<i32> {
+    match s.strip_prefix('@') {
+        Some(target) => {
+            let target = i64::try_from(target.parse::<usize>().ok()?).ok()?;
+            let index = i64::try_from(index?).ok()?;
+            i32::try_from(target - index).ok()
+        }
+        None => s.parse::<i32>().ok(),
+    }
+}
+
+impl ParseOperand for BranchOffset {
+    fn parse_operand(s: &str, index: Option<usize>) -> Option<Self> {
+        parse_branch_offset(s, index).map(BranchOffset::from)
+    }
+}
+
+impl ParseOperand for BranchOffset16 {
+    fn parse_operand(s: &str, index: Option<usize>) -> Option<Self> {
+        let offset = parse_branch_offset(s, index)?;
+        i16::try_from(offset).ok().map(BranchOffset16::from)
+    }
+}
+
+macro_rules! impl_parse_operand_for_primitive {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl ParseOperand for $ty {
+                fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+                    s.parse().ok()
+                }
+            }
+        )*
+    };
+}
+impl_parse_operand_for_primitive!(u8, i8, i16, u16, u32);
+
+macro_rules! impl_parse_operand_for_index {
+    ( $( $ty:ty => $prefix:literal ),* $(,)? ) => {
+        $(
+            impl ParseOperand for $ty {
+                fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+                    let index = s.strip_prefix(concat!($prefix, "["))?.strip_suffix(']')?;
+                    index.parse::<u32>().ok().map(Self::from)
+                }
+            }
+        )*
+    };
+}
+impl_parse_operand_for_index! {
+    InternalFunc => "internal_func",
+    Func => "func",
+    FuncType => "func_type",
+    Global => "global",
+    Memory => "memory",
+    Table => "table",
+    Elem => "elem",
+    Data => "data",
+    Type => "type",
+    Tag => "tag",
+}
+
+impl ParseOperand for TrapCode {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        let trap_code = match s {
+            "UnreachableCodeReached" => Self::UnreachableCodeReached,
+            "MemoryOutOfBounds" => Self::MemoryOutOfBounds,
+            "TableOutOfBounds" => Self::TableOutOfBounds,
+            "IndirectCallToNull" => Self::IndirectCallToNull,
+            "NullReference" => Self::NullReference,
+            "IntegerDivisionByZero" => Self::IntegerDivisionByZero,
+            "IntegerOverflow" => Self::IntegerOverflow,
+            "BadConversionToInteger" => Self::BadConversionToInteger,
+            "StackOverflow" => Self::StackOverflow,
+            "BadSignature" => Self::BadSignature,
+            "OutOfFuel" => Self::OutOfFuel,
+            "GrowthOperationLimited" => Self::GrowthOperationLimited,
+            "CastFailure" => Self::CastFailure,
+            "ArrayOutOfBounds" => Self::ArrayOutOfBounds,
+            "AllocationTooLarge" => Self::AllocationTooLarge,
+            "ContinuationAlreadyConsumed" => Self::ContinuationAlreadyConsumed,
+            "UnhandledTag" => Self::UnhandledTag,
+            _ => return None,
+        };
+        Some(trap_code)
+    }
+}
+
+impl ParseOperand for BlockFuel {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        BlockFuel::try_from(s.parse::<u64>().ok()?).ok()
+    }
+}
+
+impl ParseOperand for AnyConst16 {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        s.parse::<i16>().ok().map(AnyConst16::from)
+    }
+}
+
+impl ParseOperand for AnyConst32 {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        let hex = s.strip_prefix("0x")?;
+        u32::from_str_radix(hex, 16).ok().map(AnyConst32::from)
+    }
+}
+
+macro_rules! impl_parse_operand_for_const16 {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl ParseOperand for Const16<$ty> {
+                fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+                    Self::try_from(s.parse::<$ty>().ok()?).ok()
+                }
+            }
+        )*
+    };
+}
+impl_parse_operand_for_const16!(
+    i32, u32, i64, u64, NonZeroI32, NonZeroU32, NonZeroI64, NonZeroU64,
+);
+
+impl ParseOperand for Const32<i64> {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        let value = i32::try_from(s.parse::<i64>().ok()?).ok()?;
+        Some(Self::from(value))
+    }
+}
+
+impl ParseOperand for Const32<f64> {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        let value = s.parse::<f64>().ok()?;
+        let narrowed = value as f32;
+        if f64::from(narrowed) != value && !value.is_nan() {
+            return None;
+        }
+        Some(Self::from(narrowed))
+    }
+}
+
+impl<T> ParseOperand for ShiftAmount<T>
+where
+    T: FromStr + IntoShiftAmount,
+{
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        s.parse::<T>().ok()?.into_shift_amount()
+    }
+}
+
+impl<T> ParseOperand for Sign<T> {
+    fn parse_operand(s: &str, _index: Option<usize>) -> Option<Self> {
+        match s {
+            "+" => Some(Self::pos()),
+            "-" => Some(Self::neg()),
+            _ => None,
+        }
+    }
+}
@@ -5,7 +5,7 @@ use core::marker::PhantomData;
 #[derive(Debug)]
 pub struct Sign<T> {
     /// Whether the sign value is positive.
-    is_positive: bool,
+    pub(crate) is_positive: bool,
     /// Required for the Rust compiler.
     marker: PhantomData<fn() -> T>,
 }
@@ -28,7 +28,7 @@ impl<T> Eq for Sign<T> {}
 
 impl<T> Sign<T> {
     /// Create a new typed [`Sign`] with the given value.
-    fn new(is_positive: bool) -> Self {
+    pub(crate) fn new(is_positive: bool) -> Self {
         Self {
             is_positive,
             marker: PhantomData,
@@ -345,7 +345,8 @@ impl ComparatorAndOffset {
     /// Converts the [`ComparatorAndOffset`] into an `u64` value.
     pub fn as_u64(&self) -> u64 {
         let hi = self.cmp as u64;
-        let lo = self.offset.to_i32() as u64;
+        // Note: the offset must not be sign extended into the bits of the comparator.
+        let lo = u64::from(self.offset.to_i32() as u32);
         hi << 32 | lo
     }
 }
@@ -360,7 +361,7 @@ impl From<ComparatorAndOffset> for UntypedVal {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ShiftAmount<T> {
     /// The underlying wrapped shift amount.
-    value: Const16<T>,
+    pub(crate) value: Const16<T>,
 }
 
 /// Integer ypes that can be used as shift amount in shift or rotate instructions.
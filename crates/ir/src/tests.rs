@@ -1,4 +1,23 @@
-use crate::{Reg, RegSpan, RegSpanIter};
+use crate::{
+    core::{TrapCode, UntypedVal},
+    BoundedRegSpan,
+    BranchOffset,
+    BranchOffset16,
+    Comparator,
+    ComparatorAndOffset,
+    Const16,
+    Const32,
+    Error,
+    Instruction,
+    IrFunc,
+    Reg,
+    RegSpan,
+    RegSpanIter,
+    ShiftAmount,
+    Sign,
+    ValidationError,
+};
+use alloc::string::ToString;
 
 #[test]
 fn has_overlapping_copy_spans_works() {
@@ -38,3 +57,359 @@ fn has_overlapping_copy_spans_works() {
     assert!(has_overlapping_copy_spans(span(4), span(1), 4));
     assert!(has_overlapping_copy_spans(span(4), span(0), 5));
 }
+
+#[test]
+fn display_instruction_works() {
+    fn reg(index: i16) -> Reg {
+        Reg::from(index)
+    }
+    fn assert_display(instr: Instruction, expected: &str) {
+        assert_eq!(instr.to_string(), expected);
+    }
+    assert_display(Instruction::r#return(), "return");
+    assert_display(
+        Instruction::trap(TrapCode::UnreachableCodeReached),
+        "trap trap_code=UnreachableCodeReached",
+    );
+    assert_display(
+        Instruction::i32_add(reg(2), reg(0), reg(-1)),
+        "r2 = i32_add lhs=r0, rhs=c0",
+    );
+    assert_display(
+        Instruction::i32_add_imm16(reg(1), reg(0), <Const16<i32>>::from(-5)),
+        "r1 = i32_add_imm16 lhs=r0, rhs=-5",
+    );
+    assert_display(
+        Instruction::return_reg2_ext(reg(0), reg(-3)),
+        "return_reg2 values=[r0, c2]",
+    );
+    assert_display(
+        Instruction::return_span(BoundedRegSpan::new(RegSpan::new(reg(1)), 3)),
+        "return_span values=r1..r4",
+    );
+    assert_display(
+        Instruction::copy_span(RegSpan::new(reg(4)), RegSpan::new(reg(1)), 2_u16),
+        "r4.. = copy_span values=r1.., len=2",
+    );
+    assert_display(
+        Instruction::branch(BranchOffset::from(-2)),
+        "branch offset=-2",
+    );
+    assert_display(
+        Instruction::branch_i32_eq_imm16(reg(0), <Const16<i32>>::from(7), BranchOffset16::from(3)),
+        "branch_i32_eq_imm16 lhs=r0, rhs=7, offset=+3",
+    );
+}
+
+#[test]
+fn display_instruction_at_works() {
+    assert_eq!(
+        Instruction::branch(BranchOffset::from(-2))
+            .display_at(5)
+            .to_string(),
+        "branch offset=@3",
+    );
+    assert_eq!(
+        Instruction::branch_i32_eq_imm16(
+            Reg::from(0),
+            <Const16<i32>>::from(7),
+            BranchOffset16::from(3)
+        )
+        .display_at(5)
+        .to_string(),
+        "branch_i32_eq_imm16 lhs=r0, rhs=7, offset=@8",
+    );
+}
+
+#[test]
+fn parse_instruction_works() {
+    fn reg(index: i16) -> Reg {
+        Reg::from(index)
+    }
+    fn assert_round_trip(instr: Instruction) {
+        let text = instr.to_string();
+        assert_eq!(text.parse::<Instruction>(), Ok(instr), "{text}");
+        let text = instr.display_at(5).to_string();
+        assert_eq!(Instruction::parse_at(&text, 5), Ok(instr), "{text}");
+    }
+    assert_round_trip(Instruction::r#return());
+    assert_round_trip(Instruction::trap(TrapCode::IntegerOverflow));
+    assert_round_trip(Instruction::i32_add(reg(2), reg(0), reg(-1)));
+    assert_round_trip(Instruction::i32_add_imm16(
+        reg(1),
+        reg(0),
+        <Const16<i32>>::from(-5),
+    ));
+    assert_round_trip(Instruction::i64_shl_by(
+        reg(1),
+        reg(0),
+        <ShiftAmount<i64>>::new(3).unwrap(),
+    ));
+    assert_round_trip(Instruction::f64_copysign_imm(
+        reg(1),
+        reg(0),
+        <Sign<f64>>::neg(),
+    ));
+    assert_round_trip(Instruction::return_reg2_ext(reg(0), reg(-3)));
+    assert_round_trip(Instruction::return_span(BoundedRegSpan::new(
+        RegSpan::new(reg(1)),
+        3,
+    )));
+    assert_round_trip(Instruction::copy_span(
+        RegSpan::new(reg(4)),
+        RegSpan::new(reg(1)),
+        2_u16,
+    ));
+    assert_round_trip(Instruction::copy_f64imm32(
+        reg(0),
+        <Const32<f64>>::from(1.5_f32),
+    ));
+    assert_round_trip(Instruction::branch(BranchOffset::from(-2)));
+    assert_round_trip(Instruction::branch_i32_eq_imm16(
+        reg(0),
+        <Const16<i32>>::from(7),
+        BranchOffset16::from(3),
+    ));
+}
+
+#[test]
+fn parse_instruction_errors() {
+    fn assert_error(text: &str, expected: &str) {
+        let error = text.parse::<Instruction>().unwrap_err();
+        assert_eq!(error.line(), None);
+        assert_eq!(error.message(), expected);
+    }
+    assert_error("foo", "unknown instruction: foo");
+    assert_error("i32_add lhs=r0, rhs=r1", "missing result for `i32_add`");
+    assert_error("r0 = return", "unexpected result for `return`");
+    assert_error("r2 = i32_add lhs=r0", "missing operand `rhs` for `i32_add`");
+    assert_error(
+        "r2 = i32_add rhs=r0, lhs=r1",
+        "expected operand `lhs` for `i32_add` but found: rhs=r0",
+    );
+    assert_error(
+        "r2 = i32_add lhs=r0, rhs=x1",
+        "invalid operand `rhs` for `i32_add`: x1",
+    );
+    assert_error(
+        "r2 = i32_add lhs=r0, rhs=r1, foo=r3",
+        "unexpected operand for `i32_add`: foo=r3",
+    );
+    assert_error(
+        "branch offset=@3",
+        "invalid operand `offset` for `branch`: @3",
+    );
+}
+
+#[test]
+fn parse_ir_func_works() {
+    let text = "\
+        ;; returns `x + 100_000` unless `x` is zero\n\
+        registers: 3\n\
+        consts: 1\n\
+        \x20 c0 = 0x00000000000186a0\n\
+        instrs: 4\n\
+        \x20 0: branch_i32_eq_imm16 lhs=r0, rhs=0, offset=@3\n\
+        \x20 1: r1 = i32_add lhs=r0, rhs=c0\n\
+        \x20 2: return_reg value=r1\n\
+        \x20 3: return_imm32 value=0x00000000\n\
+    ";
+    let func = text.parse::<IrFunc>().unwrap();
+    assert_eq!(func.len_registers(), 3);
+    assert_eq!(func.consts(), [UntypedVal::from(100_000_i32)]);
+    assert_eq!(
+        func.instrs(),
+        [
+            Instruction::branch_i32_eq_imm16(
+                Reg::from(0),
+                <Const16<i32>>::from(0),
+                BranchOffset16::from(3),
+            ),
+            Instruction::i32_add(Reg::from(1), Reg::from(0), Reg::from(-1)),
+            Instruction::return_reg(Reg::from(1)),
+            Instruction::return_imm32(0_i32),
+        ]
+    );
+    assert_eq!(func.validate(), Ok(()));
+    assert_eq!(func.to_string().parse::<IrFunc>(), Ok(func));
+}
+
+#[test]
+fn parse_ir_func_errors() {
+    fn assert_error(text: &str, line: usize, expected: &str) {
+        let error = text.parse::<IrFunc>().unwrap_err();
+        assert_eq!(error.line(), Some(line));
+        assert_eq!(error.message(), expected);
+    }
+    assert_error("regs: 1", 1, "expected `registers: N` but found: regs: 1");
+    assert_error(
+        "registers: 1\nconsts: 1\nc1 = 0x0",
+        3,
+        "expected `c0 = 0x...` but found: c1 = 0x0",
+    );
+    assert_error(
+        "registers: 1\nconsts: 0\ninstrs: 2\n0: return\n2: return",
+        5,
+        "expected instruction at index 1 but found: 2: return",
+    );
+    assert_error(
+        "registers: 1\nconsts: 0\ninstrs: 1\n\n0: r0 = return",
+        5,
+        "unexpected result for `return`",
+    );
+    assert_error(
+        "registers: 1\nconsts: 0\ninstrs: 1\n0: return\n1: return",
+        5,
+        "unexpected trailing input: 1: return",
+    );
+    let error = "registers: 1\nconsts: 0\ninstrs: 2\n0: return"
+        .parse::<IrFunc>()
+        .unwrap_err();
+    assert_eq!(error.line(), None);
+    assert_eq!(
+        error.message(),
+        "unexpected end of input: expected instruction"
+    );
+}
+
+#[test]
+fn validate_ir_func_works() {
+    fn validate(
+        len_registers: u16,
+        consts: usize,
+        instrs: &[Instruction],
+    ) -> Result<(), ValidationError> {
+        let consts = (0..consts).map(|n| UntypedVal::from(n as u64));
+        IrFunc::new(len_registers, consts, instrs.iter().copied()).validate()
+    }
+    let reg = Reg::from;
+    let add = |result, lhs, rhs| Instruction::i32_add(reg(result), reg(lhs), reg(rhs));
+    assert_eq!(validate(3, 1, &[add(1, 0, -1)]), Ok(()));
+    assert_eq!(
+        validate(1, 2, &[]),
+        Err(ValidationError::TooManyConsts {
+            len_consts: 2,
+            len_registers: 1
+        }),
+    );
+    assert_eq!(
+        validate(3, 1, &[add(2, 0, -1)]),
+        Err(ValidationError::RegisterOutOfBounds {
+            instr: 0,
+            reg: reg(2)
+        }),
+    );
+    assert_eq!(
+        validate(3, 1, &[Instruction::Return, add(1, 0, -2)]),
+        Err(ValidationError::ConstOutOfBounds {
+            instr: 1,
+            reg: reg(-2)
+        }),
+    );
+    assert_eq!(
+        validate(3, 1, &[add(-1, 0, 1)]),
+        Err(ValidationError::ConstResult {
+            instr: 0,
+            reg: reg(-1)
+        }),
+    );
+    assert_eq!(
+        validate(3, 1, &[Instruction::branch(BranchOffset::from(1))]),
+        Err(ValidationError::BranchOutOfBounds {
+            instr: 0,
+            offset: 1
+        }),
+    );
+    assert_eq!(
+        validate(
+            2,
+            0,
+            &[
+                Instruction::return_span(BoundedRegSpan::new(RegSpan::new(reg(0)), 2)),
+                Instruction::return_span(BoundedRegSpan::new(RegSpan::new(reg(1)), 2)),
+            ]
+        ),
+        Err(ValidationError::RegisterOutOfBounds {
+            instr: 1,
+            reg: reg(2)
+        }),
+    );
+}
+
+#[test]
+fn comparator_and_offset_roundtrip_works() {
+    for offset in [0, 1, -1, i32::MAX, i32::MIN] {
+        let params = ComparatorAndOffset::new(Comparator::I64LtU, BranchOffset::from(offset));
+        assert_eq!(
+            ComparatorAndOffset::from_untyped(UntypedVal::from(params)),
+            Some(params)
+        );
+    }
+}
+
+#[test]
+fn branch_offset_works() {
+    let offset16 = |offset: i16| BranchOffset16::from(offset);
+    let mut branch = Instruction::branch(BranchOffset::from(-5));
+    assert_eq!(branch.branch_offset(), Some(-5));
+    assert!(branch.set_branch_offset(100_000).is_ok());
+    assert_eq!(branch, Instruction::branch(BranchOffset::from(100_000)));
+    let mut cmp_branch = Instruction::branch_i32_eq(Reg::from(0), Reg::from(1), offset16(3));
+    assert_eq!(cmp_branch.branch_offset(), Some(3));
+    assert!(cmp_branch.set_branch_offset(-7).is_ok());
+    assert_eq!(cmp_branch.branch_offset(), Some(-7));
+    assert!(matches!(
+        cmp_branch.set_branch_offset(100_000),
+        Err(Error::BranchOffsetOutOfBounds)
+    ));
+    assert_eq!(cmp_branch.branch_offset(), Some(-7));
+    assert_eq!(Instruction::Return.branch_offset(), None);
+}
+
+#[test]
+#[cfg(feature = "compact")]
+fn compact_encoding_works() {
+    use alloc::vec::Vec;
+    fn reg(index: i16) -> Reg {
+        Reg::from(index)
+    }
+    let instrs = [
+        Instruction::r#return(),
+        Instruction::trap(TrapCode::GrowthOperationLimited),
+        Instruction::i32_add(reg(2), reg(0), reg(-1)),
+        Instruction::i32_add(reg(i16::MAX), reg(i16::MIN), reg(-64)),
+        Instruction::i32_add_imm16(reg(1), reg(0), <Const16<i32>>::from(-5)),
+        Instruction::i64_shl_by(reg(1), reg(0), <ShiftAmount<i64>>::new(63).unwrap()),
+        Instruction::f64_copysign_imm(reg(1), reg(0), <Sign<f64>>::neg()),
+        Instruction::return_reg2_ext(reg(0), reg(-3)),
+        Instruction::return_span(BoundedRegSpan::new(RegSpan::new(reg(1)), 3)),
+        Instruction::copy_span(RegSpan::new(reg(4)), RegSpan::new(reg(1)), 2_u16),
+        Instruction::copy_f64imm32(reg(0), <Const32<f64>>::from(1.5_f32)),
+        Instruction::copy_imm32(reg(0), u32::MAX),
+        Instruction::branch(BranchOffset::from(i32::MIN)),
+        Instruction::branch_i32_eq_imm16(reg(0), <Const16<i32>>::from(7), BranchOffset16::from(3)),
+        Instruction::register_list_ext(reg(5), reg(6), reg(7)),
+    ];
+    let mut bytes = Vec::new();
+    for instr in &instrs {
+        instr.encode_compact(&mut bytes);
+    }
+    assert!(bytes.len() < instrs.len() * ::core::mem::size_of::<Instruction>());
+    let mut remaining = &bytes[..];
+    for instr in instrs {
+        assert_eq!(Instruction::decode_compact(&mut remaining), Some(instr));
+    }
+    assert!(remaining.is_empty());
+    // Truncated and unknown encodings are rejected.
+    assert_eq!(Instruction::decode_compact(&mut &[][..]), None);
+    let mut add = Vec::new();
+    Instruction::i32_add(reg(2), reg(0), reg(-1)).encode_compact(&mut add);
+    assert_eq!(
+        Instruction::decode_compact(&mut &add[..add.len() - 1]),
+        None
+    );
+    assert_eq!(
+        Instruction::decode_compact(&mut &[0xFF, 0xFF, 0x03][..]),
+        None
+    );
+}
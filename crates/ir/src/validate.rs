@@ -0,0 +1,226 @@
+use crate::*;
+use ::core::fmt::{self, Display};
+
+/// An error that may occur when validating an [`IrFunc`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The function local constant values do not fit into the registers of the function.
+    TooManyConsts {
+        /// The number of function local constant values.
+        len_consts: usize,
+        /// The number of registers of the function.
+        len_registers: u16,
+    },
+    /// An [`Instruction`] refers to a [`Reg`] that is out of bounds for the function.
+    RegisterOutOfBounds {
+        /// The index of the invalid [`Instruction`].
+        instr: usize,
+        /// The out of bounds [`Reg`].
+        reg: Reg,
+    },
+    /// An [`Instruction`] refers to a function local constant that does not exist.
+    ConstOutOfBounds {
+        /// The index of the invalid [`Instruction`].
+        instr: usize,
+        /// The out of bounds function local constant [`Reg`].
+        reg: Reg,
+    },
+    /// An [`Instruction`] stores its result into a function local constant.
+    ConstResult {
+        /// The index of the invalid [`Instruction`].
+        instr: usize,
+        /// The function local constant [`Reg`] used as result.
+        reg: Reg,
+    },
+    /// An [`Instruction`] branches to a target outside of the function.
+    BranchOutOfBounds {
+        /// The index of the invalid [`Instruction`].
+        instr: usize,
+        /// The out of bounds branch offset relative to the [`Instruction`].
+        offset: i32,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::TooManyConsts {
+                len_consts,
+                len_registers,
+            } => write!(
+                f,
+                "{len_consts} function local constant values exceed {len_registers} registers"
+            ),
+            Self::RegisterOutOfBounds { instr, reg } => {
+                write!(
+                    f,
+                    "instruction {instr}: register r{} is out of bounds",
+                    i16::from(reg)
+                )
+            }
+            Self::ConstOutOfBounds { instr, reg } => {
+                write!(
+                    f,
+                    "instruction {instr}: constant c{} is out of bounds",
+                    const_index(reg)
+                )
+            }
+            Self::ConstResult { instr, reg } => {
+                write!(
+                    f,
+                    "instruction {instr}: result is constant c{}",
+                    const_index(reg)
+                )
+            }
+            Self::BranchOutOfBounds { instr, offset } => {
+                write!(
+                    f,
+                    "instruction {instr}: branch offset {offset:+} is out of bounds"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// Returns the allocation index of the function local constant [`Reg`].
+fn const_index(reg: Reg) -> i32 {
+    -(i32::from(i16::from(reg)) + 1)
+}
+
+impl IrFunc {
+    /// Validates that `self` is well-formed.
+    ///
+    /// This checks that
+    ///
+    /// - all function local constant values fit into the registers of `self`,
+    /// - all [`Reg`]s used by the [`Instruction`]s are in bounds of the registers
+    ///   and function local constant values of `self`,
+    /// - no [`Instruction`] stores its result into a function local constant and
+    /// - all branch offsets target [`Instruction`]s of `self`.
+    ///
+    /// # Note
+    ///
+    /// Unbounded [`RegSpan`]s can only be checked for their first [`Reg`] which
+    /// is allowed to be one past the last [`Reg`] for empty spans.
+    ///
+    /// # Errors
+    ///
+    /// If `self` is not well-formed.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let len_consts = self.consts().len();
+        let len_registers = self.len_registers();
+        let Some(len_locals) = usize::from(len_registers).checked_sub(len_consts) else {
+            return Err(ValidationError::TooManyConsts {
+                len_consts,
+                len_registers,
+            });
+        };
+        let len_instrs = self.instrs().len();
+        for (index, instr) in self.instrs().iter().enumerate() {
+            let mut checker = RegChecker {
+                instr: index,
+                len_locals,
+                len_consts,
+                error: None,
+            };
+            let mut instr = *instr;
+            instr.visit_regs(&mut checker);
+            if let Some(error) = checker.error {
+                return Err(error);
+            }
+            if let Some(offset) = instr.branch_offset() {
+                let in_bounds = index
+                    .checked_add_signed(offset as isize)
+                    .is_some_and(|target| target < len_instrs);
+                if !in_bounds {
+                    return Err(ValidationError::BranchOutOfBounds {
+                        instr: index,
+                        offset,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks the bounds of all [`Reg`]s of an [`Instruction`] via [`VisitRegs`].
+struct RegChecker {
+    /// The index of the checked [`Instruction`].
+    instr: usize,
+    /// The number of registers that are not function local constants.
+    len_locals: usize,
+    /// The number of function local constant values.
+    len_consts: usize,
+    /// The first error encountered if any.
+    error: Option<ValidationError>,
+}
+
+impl RegChecker {
+    /// Records `error` unless another error has already been recorded.
+    fn report(&mut self, error: ValidationError) {
+        self.error.get_or_insert(error);
+    }
+
+    /// Checks that the input `reg` is in bounds.
+    ///
+    /// If `allow_end` is `true` non-constant `reg` may be one past the last register.
+    fn check_input(&mut self, reg: Reg, allow_end: bool) {
+        let instr = self.instr;
+        if reg.is_const() {
+            if const_index(reg) as usize >= self.len_consts {
+                self.report(ValidationError::ConstOutOfBounds { instr, reg });
+            }
+            return;
+        }
+        let index = i16::from(reg) as usize;
+        if index > self.len_locals || (index == self.len_locals && !allow_end) {
+            self.report(ValidationError::RegisterOutOfBounds { instr, reg });
+        }
+    }
+
+    /// Checks that the result `reg` is in bounds and not a function local constant.
+    fn check_result(&mut self, reg: Reg, allow_end: bool) {
+        if reg.is_const() {
+            let instr = self.instr;
+            self.report(ValidationError::ConstResult { instr, reg });
+            return;
+        }
+        self.check_input(reg, allow_end)
+    }
+}
+
+impl VisitRegs for RegChecker {
+    fn visit_result_reg(&mut self, reg: &mut Reg) {
+        self.check_result(*reg, false)
+    }
+
+    fn visit_result_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        match len {
+            Some(len) => {
+                for reg in regs.iter(len) {
+                    self.check_result(reg, false)
+                }
+            }
+            None => self.check_result(regs.head(), true),
+        }
+    }
+
+    fn visit_input_reg(&mut self, reg: &mut Reg) {
+        self.check_input(*reg, false)
+    }
+
+    fn visit_input_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        match len {
+            Some(len) => {
+                for reg in regs.iter(len) {
+                    self.check_input(reg, false)
+                }
+            }
+            None => self.check_input(regs.head(), true),
+        }
+    }
+}
@@ -88,6 +88,8 @@ impl_host_visitor_for!(
     Table,
     Elem,
     Data,
+    Type,
+    Tag,
     Const16<T>,
     Const32<T>,
     Sign<T>,
@@ -0,0 +1,360 @@
+//! Procedural macros for the Wasmi WebAssembly interpreter.
+//!
+//! These macros are re-exported by the `wasmi` crate if its `macros` crate feature is enabled:
+//!
+//! - [`macro@host_module`]: registers all methods of an `impl` block as host functions.
+//! - [`macro@WasmTyList`]: allows structs to be used as multi-value parameters and results.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input,
+    spanned::Spanned as _,
+    Data,
+    DeriveInput,
+    Error,
+    FnArg,
+    ImplItem,
+    ImplItemFn,
+    ItemImpl,
+    LitStr,
+    Member,
+    Type,
+};
+
+/// Registers all methods of an `impl` block as host functions of a Wasm module.
+///
+/// The attribute argument is the name of the Wasm module under which all
+/// host functions are defined, e.g. `#[wasmi::host_module("env")]`.
+///
+/// Generates `add_to_linker` and `add_to_linker_builder` associated functions that define
+/// all methods as host functions with a `Linker<Self>` or `LinkerBuilder<Constructing, Self>`.
+///
+/// # Methods
+///
+/// - Methods taking `&self` or `&mut self` are called with the host state of the `Store`.
+/// - Methods whose first parameter is a `Caller<'_, Self>` are called with the `Caller`.
+/// - All other parameters and results must be Wasm types or derive `WasmTyList`.
+///
+/// # Method Attributes
+///
+/// - `#[wasmi(name = "...")]`: defines the host function under the given name instead of the method name.
+/// - `#[wasmi(skip)]`: does not define the method as host function.
+#[proc_macro_attribute]
+pub fn host_module(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let module = parse_macro_input!(attr as LitStr);
+    let item = parse_macro_input!(item as ItemImpl);
+    expand_host_module(module, item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `WasmTyList` for a struct so that it can be used as multi-value parameters or results.
+///
+/// All fields of the struct must be Wasm types.
+/// The fields are mapped to Wasm values in the order of their declaration.
+#[proc_macro_derive(WasmTyList)]
+pub fn derive_wasm_ty_list(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_wasm_ty_list(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The way a host function method accesses its environment.
+enum HostFuncKind {
+    /// The method takes `&self`.
+    Ref,
+    /// The method takes `&mut self`.
+    RefMut,
+    /// The method takes a `Caller` as first parameter.
+    Caller,
+    /// The method takes neither `self` nor a `Caller`.
+    Plain,
+}
+
+/// A method of a `host_module` that is defined as host function.
+struct HostFunc {
+    /// The name under which the host function is defined.
+    name: LitStr,
+    /// The closure that calls the method.
+    closure: TokenStream,
+}
+
+fn expand_host_module(module: LitStr, mut item: ItemImpl) -> Result<TokenStream, Error> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(Error::new(
+            path.span(),
+            "`host_module` is not supported on trait implementations",
+        ));
+    }
+    let mut funcs = Vec::new();
+    for item in &mut item.items {
+        let ImplItem::Fn(func) = item else {
+            continue;
+        };
+        if let Some(func) = HostFunc::from_method(func)? {
+            funcs.push(func);
+        }
+    }
+    let names = funcs.iter().map(|func| &func.name).collect::<Vec<_>>();
+    let closures = funcs.iter().map(|func| &func.closure).collect::<Vec<_>>();
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let self_ty = &item.self_ty;
+    let linker_docs = format!(
+        "Defines all host functions of `Self` as imports of the `{}` module in `linker`.",
+        module.value()
+    );
+    let builder_docs = format!(
+        "Defines all host functions of `Self` as imports of the `{}` module in `builder`.",
+        module.value()
+    );
+    Ok(quote! {
+        #item
+
+        impl #impl_generics #self_ty #where_clause {
+            #[doc = #linker_docs]
+            ///
+            /// # Errors
+            ///
+            /// If any of the host functions is already defined in `linker`.
+            pub fn add_to_linker(
+                linker: &mut ::wasmi::Linker<Self>,
+            ) -> ::core::result::Result<(), ::wasmi::errors::LinkerError> {
+                #( linker.func_wrap(#module, #names, #closures)?; )*
+                ::core::result::Result::Ok(())
+            }
+
+            #[doc = #builder_docs]
+            ///
+            /// # Errors
+            ///
+            /// If any of the host functions is already defined in `builder`.
+            pub fn add_to_linker_builder(
+                builder: &mut ::wasmi::LinkerBuilder<::wasmi::state::Constructing, Self>,
+            ) -> ::core::result::Result<(), ::wasmi::errors::LinkerError> {
+                #( builder.func_wrap(#module, #names, #closures)?; )*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+impl HostFunc {
+    /// Creates a [`HostFunc`] from the `func` method of a `host_module`.
+    ///
+    /// Removes all `#[wasmi(..)]` attributes from `func`.
+    ///
+    /// Returns `None` if `func` is marked with `#[wasmi(skip)]`.
+    fn from_method(func: &mut ImplItemFn) -> Result<Option<Self>, Error> {
+        let mut name = None;
+        let mut skip = false;
+        let mut error = None;
+        func.attrs.retain(|attr| {
+            if !attr.path().is_ident("wasmi") {
+                return true;
+            }
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    name = Some(meta.value()?.parse::<LitStr>()?);
+                    return Ok(());
+                }
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    return Ok(());
+                }
+                Err(meta.error("expected `name = \"...\"` or `skip`"))
+            });
+            if let Err(e) = result {
+                error.get_or_insert(e);
+            }
+            false
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+        if skip {
+            return Ok(None);
+        }
+        let sig = &func.sig;
+        if let Some(asyncness) = sig.asyncness {
+            return Err(Error::new(
+                asyncness.span(),
+                "host functions must not be `async`",
+            ));
+        }
+        if !sig.generics.params.is_empty() {
+            return Err(Error::new(
+                sig.generics.span(),
+                "host functions must not have generic parameters",
+            ));
+        }
+        let mut inputs = sig.inputs.iter().peekable();
+        let kind = match inputs.peek() {
+            Some(FnArg::Receiver(receiver)) => {
+                let kind = match (&receiver.reference, &receiver.mutability) {
+                    (Some(_), None) => HostFuncKind::Ref,
+                    (Some(_), Some(_)) => HostFuncKind::RefMut,
+                    (None, _) => {
+                        return Err(Error::new(
+                            receiver.span(),
+                            "host functions must take `self` by reference",
+                        ))
+                    }
+                };
+                inputs.next();
+                kind
+            }
+            Some(FnArg::Typed(param)) if is_caller(&param.ty) => {
+                inputs.next();
+                HostFuncKind::Caller
+            }
+            _ => HostFuncKind::Plain,
+        };
+        let mut params = Vec::new();
+        for input in inputs {
+            match input {
+                FnArg::Typed(param) => params.push(&*param.ty),
+                FnArg::Receiver(receiver) => {
+                    return Err(Error::new(receiver.span(), "unexpected `self` parameter"))
+                }
+            }
+        }
+        let ident = &sig.ident;
+        let args = (0..params.len())
+            .map(|n| format_ident!("arg{n}"))
+            .collect::<Vec<_>>();
+        let closure = match kind {
+            HostFuncKind::Ref => quote! {
+                |caller: ::wasmi::Caller<'_, Self>, #( #args: #params ),*| {
+                    Self::#ident(caller.data(), #( #args ),*)
+                }
+            },
+            HostFuncKind::RefMut => quote! {
+                |mut caller: ::wasmi::Caller<'_, Self>, #( #args: #params ),*| {
+                    Self::#ident(caller.data_mut(), #( #args ),*)
+                }
+            },
+            HostFuncKind::Caller => quote! {
+                |caller: ::wasmi::Caller<'_, Self>, #( #args: #params ),*| {
+                    Self::#ident(caller, #( #args ),*)
+                }
+            },
+            HostFuncKind::Plain => quote! {
+                |_: ::wasmi::Caller<'_, Self>, #( #args: #params ),*| {
+                    Self::#ident(#( #args ),*)
+                }
+            },
+        };
+        let name = name.unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+        Ok(Some(Self { name, closure }))
+    }
+}
+
+/// Returns `true` if `ty` is a path to a type named `Caller`.
+fn is_caller(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Caller")
+}
+
+fn expand_wasm_ty_list(input: DeriveInput) -> Result<TokenStream, Error> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "`WasmTyList` can only be derived for structs",
+        ));
+    };
+    let members = data.fields.members().collect::<Vec<Member>>();
+    let tys = data
+        .fields
+        .iter()
+        .map(|field| &field.ty)
+        .collect::<Vec<_>>();
+    let vars = (0..members.len())
+        .map(|n| format_ident!("value{n}"))
+        .collect::<Vec<_>>();
+    let len = members.len();
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in &tys {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ty: ::wasmi::WasmTy));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::wasmi::WasmTyList for #ident #ty_generics #where_clause {
+            const LEN: usize = #len;
+
+            type Types = [::wasmi::core::ValType; #len];
+            type TypesIter = ::core::array::IntoIter<::wasmi::core::ValType, #len>;
+            type Values = [::wasmi::core::UntypedVal; #len];
+            type ValuesIter = ::core::array::IntoIter<::wasmi::core::UntypedVal, #len>;
+
+            #[inline]
+            fn types() -> Self::Types {
+                [#( <#tys as ::wasmi::WasmTy>::ty() ),*]
+            }
+
+            #[inline]
+            fn values(self) -> Self::Values {
+                [#( <#tys as ::core::convert::Into<::wasmi::core::UntypedVal>>::into(self.#members) ),*]
+            }
+
+            #[inline]
+            fn from_values(
+                values: &[::wasmi::core::UntypedVal],
+            ) -> ::core::option::Option<Self> {
+                if let [#( #vars ),*] = *values {
+                    return ::core::option::Option::Some(Self {
+                        #( #members: <#tys as ::core::convert::From<::wasmi::core::UntypedVal>>::from(#vars) ),*
+                    });
+                }
+                ::core::option::Option::None
+            }
+        }
+
+        impl #impl_generics ::wasmi::core::DecodeUntypedSlice for #ident #ty_generics #where_clause {
+            #[inline]
+            fn decode_untyped_slice(
+                values: &[::wasmi::core::UntypedVal],
+            ) -> ::core::result::Result<Self, ::wasmi::core::UntypedError> {
+                <Self as ::wasmi::WasmTyList>::from_values(values)
+                    .ok_or_else(::wasmi::core::UntypedError::invalid_len)
+            }
+        }
+
+        impl #impl_generics ::wasmi::core::EncodeUntypedSlice for #ident #ty_generics #where_clause {
+            #[inline]
+            fn encode_untyped_slice(
+                self,
+                results: &mut [::wasmi::core::UntypedVal],
+            ) -> ::core::result::Result<(), ::wasmi::core::UntypedError> {
+                let values = <Self as ::wasmi::WasmTyList>::values(self);
+                if results.len() != values.len() {
+                    return ::core::result::Result::Err(::wasmi::core::UntypedError::invalid_len());
+                }
+                results.copy_from_slice(&values);
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl #impl_generics ::wasmi::WasmRet for #ident #ty_generics #where_clause {
+            type Ok = Self;
+
+            #[inline]
+            fn into_fallible(self) -> ::core::result::Result<Self, ::wasmi::Error> {
+                ::core::result::Result::Ok(self)
+            }
+        }
+    })
+}
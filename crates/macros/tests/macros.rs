@@ -0,0 +1,161 @@
+use wasmi::{
+    errors::LinkerError,
+    host_module,
+    Caller,
+    Engine,
+    Error,
+    Linker,
+    Module,
+    Store,
+    WasmTyList,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, WasmTyList)]
+struct Pair {
+    first: i32,
+    second: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, WasmTyList)]
+struct Wrapped<T>(T, u64);
+
+#[derive(Debug, Default)]
+struct Host {
+    counter: i64,
+    log: Vec<i32>,
+}
+
+#[host_module("env")]
+impl Host {
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    fn get(&self) -> i64 {
+        self.counter
+    }
+
+    #[wasmi(name = "bump")]
+    fn increment(&mut self, delta: i64) {
+        self.counter += delta;
+    }
+
+    fn log(mut caller: Caller<'_, Host>, value: i32) {
+        caller.data_mut().log.push(value);
+    }
+
+    fn swap(pair: Pair) -> Pair {
+        Pair {
+            first: pair.first * 2,
+            second: -pair.second,
+        }
+    }
+
+    fn checked(value: i32) -> Result<Wrapped<i32>, Error> {
+        if value < 0 {
+            return Err(Error::new("negative value"));
+        }
+        Ok(Wrapped(value, value as u64 + 1))
+    }
+
+    #[wasmi(skip)]
+    #[allow(dead_code)]
+    fn helper(&self) -> String {
+        String::new()
+    }
+}
+
+const WAT: &str = r#"
+    (module
+        (import "env" "add" (func $add (param i32 i32) (result i32)))
+        (import "env" "get" (func $get (result i64)))
+        (import "env" "bump" (func $bump (param i64)))
+        (import "env" "log" (func $log (param i32)))
+        (import "env" "swap" (func $swap (param i32 f64) (result i32 f64)))
+        (import "env" "checked" (func $checked (param i32) (result i32 i64)))
+        (func (export "run") (param i32 i32) (result i64)
+            (call $log (call $add (local.get 0) (local.get 1)))
+            (call $bump (i64.const 40))
+            (call $bump (i64.const 2))
+            (call $get)
+        )
+        (func (export "swap") (param i32 f64) (result i32 f64)
+            (call $swap (local.get 0) (local.get 1))
+        )
+        (func (export "checked") (param i32) (result i32 i64)
+            (call $checked (local.get 0))
+        )
+    )
+"#;
+
+fn module(engine: &Engine) -> Module {
+    let wasm = wat::parse_str(WAT).unwrap();
+    Module::new(engine, &wasm[..]).unwrap()
+}
+
+#[test]
+fn host_module_works() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, Host::default());
+    let mut linker = <Linker<Host>>::new(&engine);
+    Host::add_to_linker(&mut linker).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module(&engine))
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance
+        .get_typed_func::<(i32, i32), i64>(&store, "run")
+        .unwrap();
+    assert_eq!(run.call(&mut store, (1, 2)).unwrap(), 42);
+    assert_eq!(store.data().log, [3]);
+    let swap = instance
+        .get_typed_func::<Pair, Pair>(&store, "swap")
+        .unwrap();
+    let pair = Pair {
+        first: 21,
+        second: 1.5,
+    };
+    assert_eq!(
+        swap.call(&mut store, pair).unwrap(),
+        Pair {
+            first: 42,
+            second: -1.5
+        }
+    );
+    let checked = instance
+        .get_typed_func::<i32, Wrapped<i32>>(&store, "checked")
+        .unwrap();
+    assert_eq!(checked.call(&mut store, 5).unwrap(), Wrapped(5, 6));
+    assert!(checked.call(&mut store, -1).is_err());
+}
+
+#[test]
+fn host_module_works_with_linker_builder() {
+    let engine = Engine::default();
+    let mut builder = <Linker<Host>>::build();
+    Host::add_to_linker_builder(&mut builder).unwrap();
+    let linker = builder.finish().create(&engine);
+    let mut store = Store::new(&engine, Host::default());
+    let instance = linker
+        .instantiate(&mut store, &module(&engine))
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance
+        .get_typed_func::<(i32, i32), i64>(&store, "run")
+        .unwrap();
+    assert_eq!(run.call(&mut store, (5, 6)).unwrap(), 42);
+    assert_eq!(store.data().log, [11]);
+}
+
+#[test]
+fn host_module_duplicate_definitions_fail() {
+    let engine = Engine::default();
+    let mut linker = <Linker<Host>>::new(&engine);
+    Host::add_to_linker(&mut linker).unwrap();
+    assert!(matches!(
+        Host::add_to_linker(&mut linker),
+        Err(LinkerError::DuplicateDefinition { .. })
+    ));
+}
@@ -85,8 +85,24 @@ pub fn wat2wasm(bytes: &[u8]) -> Vec<u8> {
 ///
 /// If the benchmark Wasm file could not be opened, read or parsed.
 pub fn load_instance_from_wat(wat_bytes: &[u8]) -> (wasmi::Store<()>, wasmi::Instance) {
+    load_instance_from_wat_with_config(wat_bytes, &bench_config())
+}
+
+/// Parses the Wasm source from the given `.wat` bytes into a Wasmi module using `config`.
+///
+/// # Note
+///
+/// This includes validation and compilation to Wasmi bytecode.
+///
+/// # Panics
+///
+/// If the benchmark Wasm file could not be opened, read or parsed.
+pub fn load_instance_from_wat_with_config(
+    wat_bytes: &[u8],
+    config: &Config,
+) -> (wasmi::Store<()>, wasmi::Instance) {
     let wasm = wat2wasm(wat_bytes);
-    let engine = wasmi::Engine::new(&bench_config());
+    let engine = wasmi::Engine::new(config);
     let module = wasmi::Module::new(&engine, &wasm[..]).unwrap();
     let linker = <wasmi::Linker<()>>::new(&engine);
     let mut store = wasmi::Store::new(&engine, ());
@@ -3,6 +3,7 @@ mod bench;
 use self::bench::{
     load_instance_from_file,
     load_instance_from_wat,
+    load_instance_from_wat_with_config,
     load_module_from_file,
     load_wasm_from_file,
     wat2wasm,
@@ -32,6 +33,7 @@ use wasmi::{
     Linker,
     Memory,
     Module,
+    OptimizationLevel,
     Store,
     TypedFunc,
     Val,
@@ -118,14 +120,18 @@ criterion_group! {
         bench_execute_flat_calls,
         bench_execute_nested_calls,
         bench_execute_host_calls,
+        bench_execute_host_calls_raw,
         bench_execute_fuse,
         bench_execute_divrem,
         bench_execute_fibonacci,
         bench_execute_recursive_is_even,
+        bench_execute_call_indirect,
         bench_execute_memory_sum,
         bench_execute_memory_fill,
         bench_execute_vec_add,
         bench_execute_bulk_ops,
+        bench_execute_optimized,
+        bench_execute_tiered,
 }
 
 criterion_main!(
@@ -159,6 +165,7 @@ fn bench_translate_for(
     mode: CompilationMode,
     validation: Validation,
     fuel_metering: FuelMetering,
+    optimization_level: OptimizationLevel,
 ) {
     let mode_id = match mode {
         CompilationMode::Eager => "eager",
@@ -173,13 +180,19 @@ fn bench_translate_for(
         FuelMetering::Enabled => "+metered",
         FuelMetering::Disabled => "",
     };
-    let bench_id = format!("translate/{name}/{mode_id}/{validation_id}{fuel_id}");
+    let optimization_id = match optimization_level {
+        OptimizationLevel::None => "",
+        OptimizationLevel::Basic => "+opt-basic",
+        OptimizationLevel::Full => "+opt-full",
+    };
+    let bench_id = format!("translate/{name}/{mode_id}/{validation_id}{fuel_id}{optimization_id}");
     c.bench_function(&bench_id, |b| {
         let mut config = bench_config();
         if matches!(fuel_metering, FuelMetering::Enabled) {
             config.consume_fuel(true);
         }
         config.compilation_mode(mode);
+        config.optimization_level(optimization_level);
         let create_module = match validation {
             Validation::Checked => {
                 |engine: &Engine, bytes: &[u8]| -> Module { Module::new(engine, bytes).unwrap() }
@@ -205,6 +218,7 @@ fn bench_translate_for_all(c: &mut Criterion, name: &str, path: &str) {
         CompilationMode::Eager,
         Validation::Checked,
         FuelMetering::Disabled,
+        OptimizationLevel::None,
     );
     bench_translate_for(
         c,
@@ -213,6 +227,7 @@ fn bench_translate_for_all(c: &mut Criterion, name: &str, path: &str) {
         CompilationMode::Eager,
         Validation::Checked,
         FuelMetering::Enabled,
+        OptimizationLevel::None,
     );
     bench_translate_for(
         c,
@@ -221,6 +236,7 @@ fn bench_translate_for_all(c: &mut Criterion, name: &str, path: &str) {
         CompilationMode::Eager,
         Validation::Unchecked,
         FuelMetering::Disabled,
+        OptimizationLevel::None,
     );
     bench_translate_for(
         c,
@@ -229,6 +245,7 @@ fn bench_translate_for_all(c: &mut Criterion, name: &str, path: &str) {
         CompilationMode::LazyTranslation,
         Validation::Checked,
         FuelMetering::Disabled,
+        OptimizationLevel::None,
     );
     bench_translate_for(
         c,
@@ -237,6 +254,7 @@ fn bench_translate_for_all(c: &mut Criterion, name: &str, path: &str) {
         CompilationMode::Lazy,
         Validation::Checked,
         FuelMetering::Disabled,
+        OptimizationLevel::None,
     );
     bench_translate_for(
         c,
@@ -245,9 +263,44 @@ fn bench_translate_for_all(c: &mut Criterion, name: &str, path: &str) {
         CompilationMode::Lazy,
         Validation::Unchecked,
         FuelMetering::Disabled,
+        OptimizationLevel::None,
+    );
+    bench_translate_for(
+        c,
+        name,
+        path,
+        CompilationMode::Eager,
+        Validation::Checked,
+        FuelMetering::Disabled,
+        OptimizationLevel::Basic,
+    );
+    bench_translate_for(
+        c,
+        name,
+        path,
+        CompilationMode::Eager,
+        Validation::Checked,
+        FuelMetering::Disabled,
+        OptimizationLevel::Full,
     );
 }
 
+/// Benchmarks eager translation with validation using multiple translation threads.
+fn bench_translate_parallel_for(c: &mut Criterion, name: &str, path: &str) {
+    for threads in [2, 4, 8] {
+        let bench_id = format!("translate/{name}/eager/checked+threads-{threads}");
+        c.bench_function(&bench_id, |b| {
+            let mut config = bench_config();
+            config.translation_threads(threads);
+            let wasm_bytes = load_wasm_from_file(path);
+            b.iter(|| {
+                let engine = Engine::new(&config);
+                _ = Module::new(&engine, &wasm_bytes[..]).unwrap();
+            })
+        });
+    }
+}
+
 fn bench_translate_tiny_keccak(c: &mut Criterion) {
     bench_translate_for_all(c, "tiny_keccak", "benches/rust/cases/tiny_keccak/out.wasm");
 }
@@ -266,10 +319,12 @@ fn bench_translate_regex_redux(c: &mut Criterion) {
 
 fn bench_translate_spidermonkey(c: &mut Criterion) {
     bench_translate_for_all(c, "spidermonkey", "benches/wasm/spidermonkey.wasm");
+    bench_translate_parallel_for(c, "spidermonkey", "benches/wasm/spidermonkey.wasm");
 }
 
 fn bench_translate_bz2(c: &mut Criterion) {
     bench_translate_for_all(c, "bz2", "benches/wasm/bz2.wasm");
+    bench_translate_parallel_for(c, "bz2", "benches/wasm/bz2.wasm");
 }
 
 fn bench_translate_pulldown_cmark(c: &mut Criterion) {
@@ -1096,6 +1151,21 @@ fn bench_execute_recursive_is_even(c: &mut Criterion) {
     });
 }
 
+fn bench_execute_call_indirect(c: &mut Criterion) {
+    const ITERATIONS: i32 = 100_000;
+    let mut g = c.benchmark_group("execute/call_indirect");
+    let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/call_indirect.wat"));
+    for (name, expected) in [("monomorphic", ITERATIONS), ("polymorphic", 0)] {
+        let run = instance.get_typed_func::<i32, i32>(&store, name).unwrap();
+        g.bench_function(name, |b| {
+            b.iter(|| {
+                let result = run.call(&mut store, ITERATIONS).unwrap();
+                assert_eq!(result, expected);
+            })
+        });
+    }
+}
+
 fn bench_execute_flat_calls(c: &mut Criterion) {
     fn bench_with(g: &mut BenchmarkGroup<WallTime>, wasm: &[u8], n: usize) {
         /// How often the host functions are called per benchmark run.
@@ -1244,6 +1314,44 @@ fn bench_execute_host_calls(c: &mut Criterion) {
     }
 }
 
+fn bench_execute_host_calls_raw(c: &mut Criterion) {
+    /// How often the host functions are called per benchmark run.
+    const ITERATIONS: i64 = 5_000;
+
+    let mut g = c.benchmark_group("execute/call/host/raw");
+    let wasm = wat2wasm(include_bytes!("wat/host_calls.wat"));
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    for n in [0, 1, 8, 16] {
+        let ty = FuncType::new(vec![ValType::I64; n], vec![ValType::I64; n]);
+        // Parameters and results share the same buffer so identity functions are no-ops.
+        linker
+            .func_new_raw("benchmark", &format!("host/{n}"), ty, |_caller, _inout| {
+                Ok(())
+            })
+            .unwrap();
+    }
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .ensure_no_start(&mut store)
+        .unwrap();
+    for n in [0, 1, 8, 16] {
+        let id = format!("{n}");
+        g.bench_function(&id, |b| {
+            let func_name = format!("run/{n}");
+            let run = instance
+                .get_typed_func::<i64, i64>(&store, &func_name)
+                .unwrap();
+            b.iter(|| {
+                run.call(&mut store, ITERATIONS).unwrap();
+            })
+        });
+    }
+}
+
 fn bench_execute_fuse(c: &mut Criterion) {
     let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/fuse.wat"));
     let mut bench_fuse = |bench_id: &str, func_name: &str, input: i32| {
@@ -1466,3 +1574,107 @@ fn bench_execute_bulk_ops(c: &mut Criterion) {
         })
     });
 }
+
+fn bench_execute_optimized(c: &mut Criterion) {
+    const COUNTER_ITERATIONS: i32 = 1_000_000;
+    const FUSE_INPUT: i32 = 1_000_000;
+    const FIBONACCI_ITER_N: i64 = 100_000;
+    const SUM_WORDS_LEN: usize = 12_500;
+    let fibonacci_iter_result = {
+        let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/fibonacci.wat"));
+        instance
+            .get_typed_func::<i64, i64>(&store, "fibonacci_iter")
+            .unwrap()
+            .call(&mut store, FIBONACCI_ITER_N)
+            .unwrap()
+    };
+    for (level, level_id) in [
+        (OptimizationLevel::Basic, "basic"),
+        (OptimizationLevel::Full, "full"),
+    ] {
+        let mut config = bench_config();
+        config.optimization_level(level);
+        c.bench_function(&format!("execute/optimized/{level_id}/counter"), |b| {
+            let (mut store, instance) =
+                load_instance_from_wat_with_config(include_bytes!("wat/counter.wat"), &config);
+            let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+            b.iter(|| {
+                let result = run.call(&mut store, COUNTER_ITERATIONS).unwrap();
+                assert_eq!(result, COUNTER_ITERATIONS);
+            })
+        });
+        c.bench_function(&format!("execute/optimized/{level_id}/fuse"), |b| {
+            let (mut store, instance) =
+                load_instance_from_wat_with_config(include_bytes!("wat/fuse.wat"), &config);
+            let run = instance.get_typed_func::<i32, i32>(&store, "test").unwrap();
+            b.iter(|| {
+                assert_eq!(run.call(&mut store, FUSE_INPUT).unwrap(), FUSE_INPUT);
+            })
+        });
+        c.bench_function(
+            &format!("execute/optimized/{level_id}/memory/sum_words"),
+            |b| {
+                let (mut store, instance) = load_instance_from_wat_with_config(
+                    include_bytes!("wat/memory-sum.wat"),
+                    &config,
+                );
+                let run = instance
+                    .get_typed_func::<i32, i64>(&store, "sum_words")
+                    .unwrap();
+                let mem = instance.get_memory(&store, "mem").unwrap();
+                let len = 8 * SUM_WORDS_LEN;
+                mem.grow(&mut store, 1).unwrap();
+                let expected_sum: i64 = mem.data_mut(&mut store)[..len]
+                    .chunks_exact_mut(8)
+                    .enumerate()
+                    .map(|(n, word)| {
+                        let new_word = n as i64;
+                        word.copy_from_slice(&new_word.to_le_bytes());
+                        new_word
+                    })
+                    .sum();
+                b.iter(|| {
+                    let result = run.call(&mut store, len as i32).unwrap();
+                    assert_eq!(result, expected_sum);
+                })
+            },
+        );
+        c.bench_function(
+            &format!("execute/optimized/{level_id}/fibonacci/iter"),
+            |b| {
+                let (mut store, instance) = load_instance_from_wat_with_config(
+                    include_bytes!("wat/fibonacci.wat"),
+                    &config,
+                );
+                let run = instance
+                    .get_typed_func::<i64, i64>(&store, "fibonacci_iter")
+                    .unwrap();
+                b.iter(|| {
+                    let result = run.call(&mut store, FIBONACCI_ITER_N).unwrap();
+                    assert_eq!(result, fibonacci_iter_result);
+                })
+            },
+        );
+    }
+}
+
+fn bench_execute_tiered(c: &mut Criterion) {
+    const COUNTER_ITERATIONS: i32 = 1_000_000;
+    // Note: a threshold of `u32::MAX` counts every backwards branch without ever
+    //       re-translating and thus measures the overhead of the hotness counting.
+    for (threshold, threshold_id) in [(0, "disabled"), (u32::MAX, "counting")] {
+        let mut config = bench_config();
+        config
+            .optimization_level(OptimizationLevel::Basic)
+            .tier_up_threshold(threshold);
+        c.bench_function(&format!("execute/tiered/{threshold_id}/counter"), |b| {
+            let (mut store, instance) =
+                load_instance_from_wat_with_config(include_bytes!("wat/counter.wat"), &config);
+            let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+            b.iter(|| {
+                let result = run.call(&mut store, COUNTER_ITERATIONS).unwrap();
+                assert_eq!(result, COUNTER_ITERATIONS);
+            })
+        });
+    }
+}
@@ -0,0 +1,143 @@
+//! Benchmarks the memory footprint of translated Wasm modules.
+//!
+//! Compare the Wasmi bytecode encodings by running this benchmark with and
+//! without the `compact-bytecode` crate feature:
+//!
+//! ```text
+//! cargo bench --bench footprint
+//! cargo bench --bench footprint --features compact-bytecode
+//! ```
+//!
+//! The footprint is measured after translation and again after all functions
+//! have been executed at least once. With the `compact-bytecode` crate feature
+//! functions are decoded upon execution and their decoded forms are cached up
+//! to `Config::decoded_cache_size` bytes in addition to their compact forms.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use wasmi::{CompilationMode, Config, Engine, ExternType, Module};
+
+/// The Wasm modules for which the memory footprint is measured.
+const MODULES: &[(&str, &str)] = &[
+    ("spidermonkey", "benches/wasm/spidermonkey.wasm"),
+    ("erc20", "benches/wasm/erc20.wasm"),
+    ("pulldown-cmark", "benches/wasm/pulldown-cmark.wasm"),
+];
+
+/// A global allocator that keeps track of the number of live heap allocated bytes.
+struct CountingAllocator;
+
+/// The number of live heap allocated bytes.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The number of bytes retained by an eagerly translated [`Module`].
+struct Footprint {
+    /// The number of bytes retained after translation.
+    translated: usize,
+    /// The number of bytes retained after all functions have been executed.
+    executed: usize,
+}
+
+/// The decoded bytecode cache size in bytes used to measure a bounded footprint.
+#[cfg(feature = "compact-bytecode")]
+const SMALL_DECODED_CACHE_SIZE: usize = 64 * 1024;
+
+/// Returns the [`Footprint`] of the eagerly translated [`Module`] of `wasm` using `config`.
+fn module_footprint(wasm: &[u8], mut config: Config) -> Footprint {
+    config.compilation_mode(CompilationMode::Eager);
+    let engine = Engine::new(&config);
+    // Note: the first translation warms up the reusable translation buffers of the `engine`
+    //       which would otherwise be accounted for the `module`.
+    drop(Module::new(&engine, wasm).unwrap());
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let module = Module::new(&engine, wasm).unwrap();
+    let translated = ALLOCATED.load(Ordering::Relaxed).saturating_sub(before);
+    execute_all(&module);
+    let executed = ALLOCATED.load(Ordering::Relaxed).saturating_sub(before);
+    drop(module);
+    Footprint {
+        translated,
+        executed,
+    }
+}
+
+/// Brings all functions of `module` into the state they are in after their first execution.
+///
+/// # Note
+///
+/// Executing arbitrary functions requires suitable host functions and inputs.
+/// Instead this queries the Wasmi bytecode of all functions which decodes
+/// them just like their first execution would.
+fn execute_all(module: &Module) {
+    let len_imported = module
+        .imports()
+        .filter(|import| matches!(import.ty(), ExternType::Func(_)))
+        .count();
+    for func_index in len_imported.. {
+        let func_index = u32::try_from(func_index).unwrap();
+        if module.compiled_func_size(func_index).unwrap().is_none() {
+            break;
+        }
+    }
+}
+
+/// Prints the `footprint` of the Wasm module `name` with `len_wasm` bytes using `encoding`.
+fn print_footprint(name: &str, encoding: &str, len_wasm: usize, footprint: &Footprint) {
+    for (state, bytes) in [
+        ("translated", footprint.translated),
+        ("executed", footprint.executed),
+    ] {
+        println!(
+            "footprint/{name}/{encoding}/{state}: wasm = {len_wasm} bytes, module = {bytes} bytes ({:.2}x)",
+            bytes as f64 / len_wasm as f64,
+        );
+    }
+}
+
+fn main() {
+    let encoding = match cfg!(feature = "compact-bytecode") {
+        true => "compact",
+        false => "default",
+    };
+    for (name, path) in MODULES {
+        let wasm = std::fs::read(path)
+            .unwrap_or_else(|error| panic!("could not read benchmark file {path}: {error}"));
+        let footprint = module_footprint(&wasm, Config::default());
+        print_footprint(name, encoding, wasm.len(), &footprint);
+        #[cfg(feature = "compact-bytecode")]
+        {
+            let mut config = Config::default();
+            config.decoded_cache_size(SMALL_DECODED_CACHE_SIZE);
+            let footprint = module_footprint(&wasm, config);
+            print_footprint(name, "compact-small-cache", wasm.len(), &footprint);
+        }
+    }
+}
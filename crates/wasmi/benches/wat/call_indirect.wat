@@ -0,0 +1,45 @@
+(module
+    (type $binop (func (param i32 i32) (result i32)))
+    (table 2 funcref)
+    (elem (i32.const 0) $add $sub)
+    (func $add (type $binop)
+        (i32.add (local.get 0) (local.get 1))
+    )
+    (func $sub (type $binop)
+        (i32.sub (local.get 0) (local.get 1))
+    )
+    ;; Calls the function at the same table index in every iteration.
+    (func (export "monomorphic") (param $n i32) (result i32)
+        (local $acc i32)
+        (block $break
+            (loop $continue
+                (br_if $break (i32.eqz (local.get $n)))
+                (local.set $acc
+                    (call_indirect (type $binop)
+                        (local.get $acc) (i32.const 1) (i32.const 0)
+                    )
+                )
+                (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                (br $continue)
+            )
+        )
+        (local.get $acc)
+    )
+    ;; Alternates between the functions at both table indices in every iteration.
+    (func (export "polymorphic") (param $n i32) (result i32)
+        (local $acc i32)
+        (block $break
+            (loop $continue
+                (br_if $break (i32.eqz (local.get $n)))
+                (local.set $acc
+                    (call_indirect (type $binop)
+                        (local.get $acc) (i32.const 1) (i32.and (local.get $n) (i32.const 1))
+                    )
+                )
+                (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                (br $continue)
+            )
+        )
+        (local.get $acc)
+    )
+)
@@ -1,5 +1,5 @@
-;; Exports a function `sum` that returns the sum of the linear memory
-;; contents until the given `limit`.
+;; Exports functions `sum_bytes` and `sum_words` that return the sum of the
+;; linear memory contents until the given `limit` as bytes or 64-bit words.
 (module
     (memory (export "mem") 1)
     (func (export "sum_bytes") (param $limit i32) (result i64)
@@ -28,4 +28,30 @@
         )
         (return (local.get $sum))
     )
+    (func (export "sum_words") (param $limit i32) (result i64)
+        (local $n i32)
+        (local $sum i64)
+        (block $exit
+            (loop $loop
+                (br_if ;; exit loop if $n == $limit
+                    $exit
+                    (i32.eq
+                        (local.get $n)
+                        (local.get $limit)
+                    )
+                )
+                (local.set $sum ;; load n-th word from memory and add to sum
+                    (i64.add
+                        (local.get $sum)
+                        (i64.load offset=0 (local.get $n))
+                    )
+                )
+                (local.set $n ;; advance n to the next word
+                    (i32.add (local.get $n) (i32.const 8))
+                 )
+                (br $loop) ;; continue loop
+            )
+        )
+        (return (local.get $sum))
+    )
 )
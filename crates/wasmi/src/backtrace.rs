@@ -0,0 +1,213 @@
+use crate::Instance;
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{self, Display};
+
+#[cfg(doc)]
+use crate::{Config, Error};
+
+/// A backtrace of the Wasm call stack captured when a Wasm execution trapped.
+///
+/// # Note
+///
+/// - Wasm backtraces are only captured if [`Config::wasm_backtrace`] is enabled.
+/// - Frames are ordered from the most recently called Wasm function to the
+///   Wasm function that was called first.
+#[derive(Debug, Clone, Default)]
+pub struct WasmBacktrace {
+    /// The captured Wasm call frames from top to bottom.
+    frames: Vec<FrameInfo>,
+}
+
+impl WasmBacktrace {
+    /// Creates a new [`WasmBacktrace`] from the given `frames`.
+    pub(crate) fn new(frames: Vec<FrameInfo>) -> Self {
+        Self { frames }
+    }
+
+    /// Appends the frames of `other` to the bottom of `self`.
+    ///
+    /// # Note
+    ///
+    /// This is used to combine backtraces of nested Wasm executions
+    /// that have been interleaved by host function calls.
+    pub(crate) fn append(&mut self, other: WasmBacktrace) {
+        self.frames.extend(other.frames);
+    }
+
+    /// Returns the captured [`FrameInfo`]s from top to bottom.
+    pub fn frames(&self) -> &[FrameInfo] {
+        &self.frames
+    }
+
+    /// Returns `true` if the [`WasmBacktrace`] has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl Display for WasmBacktrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "wasm backtrace:")?;
+        for (n, frame) in self.frames.iter().enumerate() {
+            write!(f, "  {n:>3}: ")?;
+            match frame.module_offset() {
+                Some(offset) => write!(f, "{offset:#8x}")?,
+                None => write!(f, "{:>8}", "<?>")?,
+            }
+            writeln!(f, " - {frame}")?;
+            for symbol in frame.symbols() {
+                writeln!(f, "{:>18}{symbol}", "")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Information about a single Wasm call frame of a [`WasmBacktrace`].
+#[derive(Debug, Clone)]
+pub struct FrameInfo {
+    /// The [`Instance`] of the called Wasm function.
+    instance: Instance,
+    /// The index of the called Wasm function within its Wasm module.
+    func_index: u32,
+    /// The name of the called function as found in the Wasm `name` custom section.
+    func_name: Option<Box<str>>,
+    /// The offset of the executed Wasm operator within the Wasm module binary.
+    module_offset: Option<usize>,
+    /// The source-level symbols of the executed Wasm operator.
+    symbols: Box<[FrameSymbol]>,
+}
+
+impl FrameInfo {
+    /// Creates a new [`FrameInfo`].
+    pub(crate) fn new(
+        instance: Instance,
+        func_index: u32,
+        func_name: Option<&str>,
+        module_offset: Option<usize>,
+        symbols: Vec<FrameSymbol>,
+    ) -> Self {
+        Self {
+            instance,
+            func_index,
+            func_name: func_name.map(Box::from),
+            module_offset,
+            symbols: symbols.into(),
+        }
+    }
+
+    /// Returns the [`Instance`] of the Wasm function of the [`FrameInfo`].
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    /// Returns the index of the Wasm function of the [`FrameInfo`] within its Wasm module.
+    ///
+    /// # Note
+    ///
+    /// This index space includes imported functions.
+    pub fn func_index(&self) -> u32 {
+        self.func_index
+    }
+
+    /// Returns the name of the Wasm function of the [`FrameInfo`] if any.
+    ///
+    /// The name is taken from the Wasm `name` custom section.
+    pub fn func_name(&self) -> Option<&str> {
+        self.func_name.as_deref()
+    }
+
+    /// Returns the offset of the executed Wasm operator within the Wasm module binary if known.
+    ///
+    /// For the top-most frame this is the trapping Wasm operator, for all other
+    /// frames this is the Wasm call operator that is still in progress.
+    pub fn module_offset(&self) -> Option<usize> {
+        self.module_offset
+    }
+
+    /// Returns the source-level [`FrameSymbol`]s of the executed Wasm operator.
+    ///
+    /// # Note
+    ///
+    /// - Symbols are resolved from DWARF debug information which requires the
+    ///   `dwarf` crate feature and a Wasm module with `.debug_*` custom sections.
+    /// - Multiple symbols are returned for Wasm operators of inlined functions,
+    ///   ordered from the innermost to the outermost function.
+    pub fn symbols(&self) -> &[FrameSymbol] {
+        &self.symbols
+    }
+}
+
+impl Display for FrameInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.func_name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "<wasm function {}>", self.func_index),
+        }
+    }
+}
+
+/// A source-level location of a [`FrameInfo`] resolved from DWARF debug information.
+#[derive(Debug, Clone)]
+pub struct FrameSymbol {
+    /// The demangled name of the source-level function.
+    name: Option<Box<str>>,
+    /// The path of the source file.
+    file: Option<Box<str>>,
+    /// The line number within the source file.
+    line: Option<u32>,
+    /// The column number within the source line.
+    column: Option<u32>,
+}
+
+impl FrameSymbol {
+    /// Creates a new [`FrameSymbol`].
+    #[cfg_attr(not(feature = "dwarf"), allow(dead_code))]
+    pub(crate) fn new(
+        name: Option<Box<str>>,
+        file: Option<Box<str>>,
+        line: Option<u32>,
+        column: Option<u32>,
+    ) -> Self {
+        Self {
+            name,
+            file,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the demangled name of the source-level function if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the path of the source file if any.
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// Returns the line number within the source file if any.
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Returns the column number within the source line if any.
+    pub fn column(&self) -> Option<u32> {
+        self.column
+    }
+}
+
+impl Display for FrameSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at ", self.name().unwrap_or("<unknown>"))?;
+        write!(f, "{}", self.file().unwrap_or("<unknown>"))?;
+        if let Some(line) = self.line() {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column() {
+                write!(f, ":{column}")?;
+            }
+        }
+        Ok(())
+    }
+}
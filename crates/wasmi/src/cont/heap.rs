@@ -0,0 +1,135 @@
+use super::ContRef;
+use crate::{core::UntypedVal, engine::Stack, Func};
+use alloc::vec::Vec;
+
+/// A continuation of the Wasm `stack-switching` proposal.
+#[derive(Debug)]
+pub enum ContEntity {
+    /// A continuation created by `cont.new` that has not yet been resumed.
+    Fresh {
+        /// The function executed once the continuation is resumed.
+        func: Func,
+        /// The leading parameters of `func` bound via `cont.bind`.
+        args: Vec<UntypedVal>,
+    },
+    /// A continuation created by `suspend` or `switch`.
+    Suspended {
+        /// The suspended stack segments ordered from outermost to innermost.
+        ///
+        /// The innermost stack segment is the one that executed the `suspend` or `switch`.
+        segments: Vec<Stack>,
+        /// The leading resumption values bound via `cont.bind`.
+        args: Vec<UntypedVal>,
+    },
+}
+
+impl ContEntity {
+    /// Returns the leading values bound via `cont.bind`.
+    pub fn args_mut(&mut self) -> &mut Vec<UntypedVal> {
+        match self {
+            Self::Fresh { args, .. } | Self::Suspended { args, .. } => args,
+        }
+    }
+
+    /// Returns an iterator over all values held by the continuation.
+    ///
+    /// # Note
+    ///
+    /// This includes the bound values as well as the values of all suspended stack segments.
+    #[cfg(feature = "gc")]
+    pub fn values(&self) -> impl Iterator<Item = UntypedVal> + '_ {
+        let (args, segments) = match self {
+            Self::Fresh { args, .. } => (args, &[][..]),
+            Self::Suspended { segments, args } => (args, &segments[..]),
+        };
+        let stacks = segments
+            .iter()
+            .flat_map(|segment| segment.values.as_slice().iter());
+        args.iter().chain(stacks).copied()
+    }
+}
+
+/// A slot of the [`ContHeap`].
+#[derive(Debug, Default)]
+struct ContSlot {
+    /// The generation of the slot.
+    ///
+    /// Incremented whenever the continuation of the slot is consumed so that
+    /// stale [`ContRef`]s to the slot are detected.
+    generation: u32,
+    /// The continuation occupying the slot if any.
+    entity: Option<ContEntity>,
+}
+
+/// The continuations of a [`Store`] as introduced by the Wasm `stack-switching` proposal.
+///
+/// # Handles
+///
+/// Continuations are referred to via 64-bit [`ContRef`] handles:
+///
+/// - The lower 32 bits hold the slot index plus one so that handles are never `null`.
+/// - The upper 32 bits hold the generation of the slot.
+///
+/// [`Store`]: crate::Store
+#[derive(Debug, Default)]
+pub struct ContHeap {
+    /// The slots of the heap.
+    slots: Vec<ContSlot>,
+    /// The indices of unoccupied slots.
+    free: Vec<u32>,
+}
+
+// # Safety
+//
+// `ContHeap` is not `Sync` because the suspended `Stack`s of its continuations
+// hold `InstructionPtr`s which are raw pointers to `Instruction` buffers owned by the `Engine`.
+//
+// The `ContHeap` is owned by a `Store` which also owns the `Engine` and thus cannot outlive it.
+// Furthermore the suspended `Stack`s are only ever accessed via exclusive references.
+//
+// Therefore `ContHeap` can safely be assumed to be `Sync`.
+unsafe impl Sync for ContHeap {}
+
+impl ContHeap {
+    /// Allocates the continuation `entity` and returns a [`ContRef`] to it.
+    pub fn alloc(&mut self, entity: ContEntity) -> ContRef {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                let index = u32::try_from(self.slots.len())
+                    .ok()
+                    .filter(|index| *index < u32::MAX - 1)
+                    .unwrap_or_else(|| panic!("out of continuation slots"));
+                self.slots.push(ContSlot::default());
+                index
+            }
+        };
+        let slot = &mut self.slots[index as usize];
+        slot.entity = Some(entity);
+        let bits = (u64::from(slot.generation) << 32) | u64::from(index + 1);
+        ContRef::from(UntypedVal::from(bits))
+    }
+
+    /// Consumes the continuation referred to by `contref` and returns it.
+    ///
+    /// Returns `None` if `contref` is `null` or if its continuation has already been consumed.
+    pub fn take(&mut self, contref: ContRef) -> Option<ContEntity> {
+        let bits = u64::from(UntypedVal::from(contref));
+        let generation = (bits >> 32) as u32;
+        let index = (bits as u32).checked_sub(1)?;
+        let slot = self.slots.get_mut(index as usize)?;
+        if slot.generation != generation {
+            return None;
+        }
+        let entity = slot.entity.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(index);
+        Some(entity)
+    }
+
+    /// Returns an iterator over all live continuations.
+    #[cfg(feature = "gc")]
+    pub fn iter(&self) -> impl Iterator<Item = &ContEntity> {
+        self.slots.iter().filter_map(|slot| slot.entity.as_ref())
+    }
+}
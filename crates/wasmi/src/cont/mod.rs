@@ -0,0 +1,51 @@
+mod heap;
+
+pub(crate) use self::heap::{ContEntity, ContHeap};
+use crate::core::UntypedVal;
+
+/// Represents a nullable reference to a continuation as introduced by the Wasm `stack-switching` proposal.
+///
+/// # Note
+///
+/// Continuations are one-shot: resuming, binding or switching to a continuation
+/// consumes it so that any further use of the same [`ContRef`] traps.
+/// Continuations are owned by their [`Store`] and can only be created and resumed by Wasm code.
+///
+/// [`Store`]: crate::Store
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ContRef {
+    /// The raw bits of the reference.
+    ///
+    /// - `0` represents `null`.
+    /// - Non-zero bits represent handles to continuations of the [`Store`].
+    ///
+    /// [`Store`]: crate::Store
+    bits: u64,
+}
+
+impl From<UntypedVal> for ContRef {
+    fn from(untyped: UntypedVal) -> Self {
+        Self {
+            bits: u64::from(untyped),
+        }
+    }
+}
+
+impl From<ContRef> for UntypedVal {
+    fn from(contref: ContRef) -> Self {
+        UntypedVal::from(contref.bits)
+    }
+}
+
+impl ContRef {
+    /// Creates a new [`ContRef`] which is `null`.
+    pub fn null() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Returns `true` if [`ContRef`] is `null`.
+    pub fn is_null(&self) -> bool {
+        self.bits == 0
+    }
+}
@@ -0,0 +1,598 @@
+use crate::{
+    core::{Pages, ValType},
+    store::StoreInner,
+    Global,
+    GlobalType,
+    Instance,
+    Memory,
+    Mutability,
+    Val,
+};
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(doc)]
+use crate::{Config, Error};
+
+/// A Wasm coredump captured when a Wasm execution trapped.
+///
+/// # Note
+///
+/// - Wasm coredumps are only captured if [`Config::coredump_on_trap`] is enabled.
+/// - A [`WasmCoreDump`] is a snapshot of the Wasm call stack as well as all linear memories
+///   and global variables of the module instances on the Wasm call stack at the time of the trap.
+/// - Use [`WasmCoreDump::serialize`] to encode it into the [tool-conventions Wasm coredump format]
+///   which can be inspected by existing post-mortem debugging tools.
+///
+/// [tool-conventions Wasm coredump format]: https://github.com/WebAssembly/tool-conventions/blob/main/Coredump.md
+#[derive(Debug, Clone, Default)]
+pub struct WasmCoreDump {
+    /// The captured Wasm call frames from top to bottom.
+    frames: Vec<CoreDumpFrame>,
+    /// The module instances referenced by the captured frames.
+    instances: Vec<CoreDumpInstance>,
+    /// The snapshots of the linear memories referenced by the captured instances.
+    memories: Vec<CoreDumpMemory>,
+    /// The snapshots of the global variables referenced by the captured instances.
+    globals: Vec<CoreDumpGlobal>,
+}
+
+/// A single Wasm call frame of a [`WasmCoreDump`].
+#[derive(Debug, Clone)]
+pub struct CoreDumpFrame {
+    /// The [`Instance`] of the called Wasm function.
+    instance: Instance,
+    /// The index of the called Wasm function within its Wasm module.
+    func_index: u32,
+    /// The offset of the executed Wasm operator relative to the start of the Wasm function body.
+    code_offset: u32,
+    /// The values of the function parameters and locals.
+    ///
+    /// Values that could not be captured are `None`.
+    locals: Box<[Option<Val>]>,
+    /// The values of the Wasm operand stack from bottom to top.
+    ///
+    /// Values that could not be captured are `None`.
+    stack: Box<[Option<Val>]>,
+}
+
+/// A module instance captured by a [`WasmCoreDump`].
+#[derive(Debug, Clone)]
+struct CoreDumpInstance {
+    /// The captured [`Instance`].
+    instance: Instance,
+    /// The indices of the captured linear memories of the instance.
+    memories: Box<[u32]>,
+    /// The indices of the captured global variables of the instance.
+    globals: Box<[u32]>,
+}
+
+/// A snapshot of a linear memory captured by a [`WasmCoreDump`].
+#[derive(Debug, Clone)]
+struct CoreDumpMemory {
+    /// The captured [`Memory`].
+    memory: Memory,
+    /// The current number of pages of the linear memory.
+    pages: u32,
+    /// The maximum number of pages of the linear memory if any.
+    maximum_pages: Option<u32>,
+    /// The size of a page of the linear memory as power of two.
+    page_size_log2: u8,
+    /// The contents of the linear memory.
+    data: Box<[u8]>,
+}
+
+/// A snapshot of a global variable captured by a [`WasmCoreDump`].
+#[derive(Debug, Clone)]
+struct CoreDumpGlobal {
+    /// The captured [`Global`].
+    global: Global,
+    /// The type of the global variable.
+    ty: GlobalType,
+    /// The value of the global variable.
+    value: Val,
+}
+
+impl WasmCoreDump {
+    /// Pushes a Wasm call frame to the bottom of the [`WasmCoreDump`].
+    ///
+    /// Captures the `instance` of the frame if it has not yet been captured.
+    pub(crate) fn push_frame(
+        &mut self,
+        store: &StoreInner,
+        instance: Instance,
+        func_index: u32,
+        code_offset: u32,
+        locals: Box<[Option<Val>]>,
+        stack: Box<[Option<Val>]>,
+    ) {
+        self.push_instance(store, instance);
+        self.frames.push(CoreDumpFrame {
+            instance,
+            func_index,
+            code_offset,
+            locals,
+            stack,
+        });
+    }
+
+    /// Captures the linear memories and global variables of `instance` if not yet captured.
+    fn push_instance(&mut self, store: &StoreInner, instance: Instance) {
+        if self.contains_instance(&instance) {
+            return;
+        }
+        let entity = store.resolve_instance(&instance);
+        let memories = entity
+            .memories()
+            .iter()
+            .map(|memory| {
+                self.memory_index(memory, || {
+                    let entity = store.resolve_memory(memory);
+                    CoreDumpMemory {
+                        memory: *memory,
+                        pages: entity.size(),
+                        maximum_pages: entity.ty().maximum_pages().map(u32::from),
+                        page_size_log2: entity.ty().page_size_log2(),
+                        data: entity.data().into(),
+                    }
+                })
+            })
+            .collect();
+        let globals = entity
+            .globals()
+            .iter()
+            .map(|global| {
+                self.global_index(global, || {
+                    let entity = store.resolve_global(global);
+                    CoreDumpGlobal {
+                        global: *global,
+                        ty: entity.ty(),
+                        value: entity.get(),
+                    }
+                })
+            })
+            .collect();
+        self.instances.push(CoreDumpInstance {
+            instance,
+            memories,
+            globals,
+        });
+    }
+
+    /// Returns `true` if `instance` has already been captured.
+    fn contains_instance(&self, instance: &Instance) -> bool {
+        self.instances.iter().any(|i| i.instance == *instance)
+    }
+
+    /// Returns the index of the captured `memory`.
+    ///
+    /// Captures `memory` via `snapshot` if it has not yet been captured.
+    fn memory_index(&mut self, memory: &Memory, snapshot: impl FnOnce() -> CoreDumpMemory) -> u32 {
+        let index = self
+            .memories
+            .iter()
+            .position(|m| m.memory.as_inner() == memory.as_inner())
+            .unwrap_or_else(|| {
+                self.memories.push(snapshot());
+                self.memories.len() - 1
+            });
+        index as u32
+    }
+
+    /// Returns the index of the captured `global`.
+    ///
+    /// Captures `global` via `snapshot` if it has not yet been captured.
+    fn global_index(&mut self, global: &Global, snapshot: impl FnOnce() -> CoreDumpGlobal) -> u32 {
+        let index = self
+            .globals
+            .iter()
+            .position(|g| g.global.as_inner() == global.as_inner())
+            .unwrap_or_else(|| {
+                self.globals.push(snapshot());
+                self.globals.len() - 1
+            });
+        index as u32
+    }
+
+    /// Appends the frames of `other` to the bottom of `self`.
+    ///
+    /// # Note
+    ///
+    /// - This is used to combine coredumps of nested Wasm executions
+    ///   that have been interleaved by host function calls.
+    /// - Instances, linear memories and global variables that have already
+    ///   been captured by `self` keep their earlier snapshot.
+    pub(crate) fn append(&mut self, other: WasmCoreDump) {
+        for instance in &other.instances {
+            if self.contains_instance(&instance.instance) {
+                continue;
+            }
+            let memories = instance
+                .memories
+                .iter()
+                .map(|&index| {
+                    let memory = &other.memories[index as usize];
+                    self.memory_index(&memory.memory, || memory.clone())
+                })
+                .collect();
+            let globals = instance
+                .globals
+                .iter()
+                .map(|&index| {
+                    let global = &other.globals[index as usize];
+                    self.global_index(&global.global, || global.clone())
+                })
+                .collect();
+            self.instances.push(CoreDumpInstance {
+                instance: instance.instance,
+                memories,
+                globals,
+            });
+        }
+        self.frames.extend(other.frames);
+    }
+
+    /// Returns the captured [`CoreDumpFrame`]s from top to bottom.
+    pub fn frames(&self) -> &[CoreDumpFrame] {
+        &self.frames
+    }
+
+    /// Returns the captured contents of the linear memories of all captured [`Instance`]s.
+    pub fn memories(&self) -> impl ExactSizeIterator<Item = &[u8]> {
+        self.memories.iter().map(|memory| &memory.data[..])
+    }
+
+    /// Returns the captured values of the global variables of all captured [`Instance`]s.
+    pub fn globals(&self) -> impl ExactSizeIterator<Item = &Val> {
+        self.globals.iter().map(|global| &global.value)
+    }
+
+    /// Serializes the [`WasmCoreDump`] into the [tool-conventions Wasm coredump format].
+    ///
+    /// The `name` is recorded as the name of the executable that trapped.
+    ///
+    /// # Note
+    ///
+    /// - All linear memories are encoded as 32-bit memories since Wasmi
+    ///   does not support the Wasm `memory64` proposal.
+    /// - Values of function locals and operand stacks that could not be captured or that
+    ///   cannot be represented by the coredump format, such as references, are serialized as missing.
+    /// - Since Wasmi does not retain the Wasm modules of captured instances,
+    ///   all instances are associated to their own anonymous module.
+    ///
+    /// [tool-conventions Wasm coredump format]: https://github.com/WebAssembly/tool-conventions/blob/main/Coredump.md
+    pub fn serialize(&self, name: &str) -> Vec<u8> {
+        let mut encoder = Encoder::default();
+        encoder.bytes.extend_from_slice(b"\0asm");
+        encoder.bytes.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+        encoder.custom_section("core", |e| {
+            e.byte(0x00);
+            e.name(name);
+        });
+        encoder.custom_section("coremodules", |e| {
+            e.len(self.instances.len());
+            for _ in &self.instances {
+                e.byte(0x00);
+                e.name("<module>");
+            }
+        });
+        encoder.custom_section("coreinstances", |e| {
+            e.len(self.instances.len());
+            for (index, instance) in self.instances.iter().enumerate() {
+                e.byte(0x00);
+                e.u32(index as u32);
+                e.len(instance.memories.len());
+                instance.memories.iter().for_each(|&index| e.u32(index));
+                e.len(instance.globals.len());
+                instance.globals.iter().for_each(|&index| e.u32(index));
+            }
+        });
+        encoder.custom_section("corestack", |e| {
+            e.byte(0x00);
+            e.name("main");
+            e.len(self.frames.len());
+            for frame in &self.frames {
+                let instance_index = self
+                    .instances
+                    .iter()
+                    .position(|i| i.instance == frame.instance)
+                    .unwrap_or_default();
+                e.byte(0x00);
+                e.u32(instance_index as u32);
+                e.u32(frame.func_index);
+                e.u32(frame.code_offset);
+                e.len(frame.locals.len());
+                frame
+                    .locals
+                    .iter()
+                    .for_each(|local| e.value(local.as_ref()));
+                e.len(frame.stack.len());
+                frame.stack.iter().for_each(|value| e.value(value.as_ref()));
+            }
+        });
+        encoder.section(SectionId::Memory, |e| {
+            e.len(self.memories.len());
+            for memory in &self.memories {
+                // Note: custom page sizes are encoded via flag `0x08` as in the Wasm binary format.
+                let custom_page_size = memory.page_size_log2 != Pages::DEFAULT_PAGE_SIZE_LOG2;
+                let page_size_flag = if custom_page_size { 0x08 } else { 0x00 };
+                match memory.maximum_pages {
+                    Some(maximum) => {
+                        e.byte(0x01 | page_size_flag);
+                        e.u32(memory.pages);
+                        e.u32(maximum);
+                    }
+                    None => {
+                        e.byte(page_size_flag);
+                        e.u32(memory.pages);
+                    }
+                }
+                if custom_page_size {
+                    e.u32(u32::from(memory.page_size_log2));
+                }
+            }
+        });
+        encoder.section(SectionId::Global, |e| {
+            e.len(self.globals.len());
+            for global in &self.globals {
+                e.val_type(global.ty.content());
+                e.byte(match global.ty.mutability() {
+                    Mutability::Const => 0x00,
+                    Mutability::Var => 0x01,
+                });
+                e.const_expr(&global.value);
+            }
+        });
+        // Note: only non-zero chunks of the linear memories are encoded as active data segments.
+        let segments = self
+            .memories
+            .iter()
+            .enumerate()
+            .flat_map(|(index, memory)| {
+                memory
+                    .data
+                    .chunks(SEGMENT_SIZE)
+                    .enumerate()
+                    .filter(|(_, page)| page.iter().any(|&byte| byte != 0))
+                    .map(move |(n, page)| (index as u32, (n * SEGMENT_SIZE) as u64, page))
+            })
+            .collect::<Vec<_>>();
+        encoder.section(SectionId::Data, |e| {
+            e.len(segments.len());
+            for (memory_index, offset, bytes) in &segments {
+                match memory_index {
+                    0 => e.byte(0x00),
+                    index => {
+                        e.byte(0x02);
+                        e.u32(*index);
+                    }
+                }
+                e.data_offset(*offset);
+                e.len(bytes.len());
+                e.bytes.extend_from_slice(bytes);
+            }
+        });
+        encoder.bytes
+    }
+}
+
+impl CoreDumpFrame {
+    /// Returns the [`Instance`] of the Wasm function of the [`CoreDumpFrame`].
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    /// Returns the index of the Wasm function of the [`CoreDumpFrame`] within its Wasm module.
+    ///
+    /// # Note
+    ///
+    /// This index space includes imported functions.
+    pub fn func_index(&self) -> u32 {
+        self.func_index
+    }
+
+    /// Returns the offset of the executed Wasm operator relative to the start of the Wasm function body.
+    ///
+    /// For the top-most frame this is the trapping Wasm operator, for all other
+    /// frames this is the Wasm call operator that is still in progress.
+    pub fn code_offset(&self) -> u32 {
+        self.code_offset
+    }
+
+    /// Returns the values of the function parameters and locals of the [`CoreDumpFrame`].
+    ///
+    /// Values that could not be captured are `None`.
+    pub fn locals(&self) -> &[Option<Val>] {
+        &self.locals
+    }
+
+    /// Returns the values of the Wasm operand stack of the [`CoreDumpFrame`] from bottom to top.
+    ///
+    /// # Note
+    ///
+    /// - For the top-most frame these are the values before the trapping Wasm operator,
+    ///   for all other frames the values before the Wasm call operator, including its arguments.
+    /// - This is empty for functions that have not been validated by Wasmi,
+    ///   e.g. functions of Wasm modules created via [`Module::new_unchecked`].
+    /// - Values that could not be captured are `None`.
+    ///
+    /// [`Module::new_unchecked`]: crate::Module::new_unchecked
+    pub fn stack(&self) -> &[Option<Val>] {
+        &self.stack
+    }
+}
+
+/// The size of the chunks in which linear memory contents are encoded as data segments.
+const SEGMENT_SIZE: usize = 0x1_0000;
+
+/// The identifiers of the Wasm sections encoded by a [`WasmCoreDump`].
+#[derive(Debug, Copy, Clone)]
+#[repr(u8)]
+enum SectionId {
+    Custom = 0,
+    Memory = 5,
+    Global = 6,
+    Data = 11,
+}
+
+/// A minimal Wasm binary encoder for [`WasmCoreDump::serialize`].
+#[derive(Debug, Default)]
+struct Encoder {
+    /// The encoded Wasm bytes.
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    /// Encodes a single `byte`.
+    fn byte(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    /// Encodes `value` as unsigned LEB128.
+    fn u32(&mut self, mut value: u32) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.byte(byte);
+                return;
+            }
+            self.byte(byte | 0x80);
+        }
+    }
+
+    /// Encodes `value` as signed LEB128.
+    fn i64(&mut self, mut value: i64) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+            if done {
+                self.byte(byte);
+                return;
+            }
+            self.byte(byte | 0x80);
+        }
+    }
+
+    /// Encodes the length of a Wasm vector.
+    fn len(&mut self, len: usize) {
+        self.u32(len as u32);
+    }
+
+    /// Encodes a Wasm name.
+    fn name(&mut self, name: &str) {
+        self.len(name.len());
+        self.bytes.extend_from_slice(name.as_bytes());
+    }
+
+    /// Encodes a [`ValType`].
+    fn val_type(&mut self, ty: ValType) {
+        self.byte(match ty {
+            ValType::I32 => 0x7F,
+            ValType::I64 => 0x7E,
+            ValType::F32 => 0x7D,
+            ValType::F64 => 0x7C,
+            ValType::FuncRef => 0x70,
+            ValType::ExternRef => 0x6F,
+            ty if ty.is_func_ref() => 0x70,
+            ty if ty.is_any_ref() => 0x6E,
+            ty if ty.is_cont_ref() => 0x68,
+            _ => 0x6F,
+        });
+    }
+
+    /// Encodes `value` as coredump value.
+    ///
+    /// Values that could not be captured and references are encoded as missing values
+    /// since they are not supported.
+    fn value(&mut self, value: Option<&Val>) {
+        let Some(value) = value else {
+            self.byte(0x01);
+            return;
+        };
+        match value {
+            Val::I32(value) => {
+                self.byte(0x7F);
+                self.i64(i64::from(*value));
+            }
+            Val::I64(value) => {
+                self.byte(0x7E);
+                self.i64(*value);
+            }
+            Val::F32(value) => {
+                self.byte(0x7D);
+                self.bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+            Val::F64(value) => {
+                self.byte(0x7C);
+                self.bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+            Val::FuncRef(_) | Val::ExternRef(_) | Val::AnyRef(_) | Val::ContRef(_) => {
+                self.byte(0x01)
+            }
+        }
+    }
+
+    /// Encodes a Wasm constant expression evaluating to `value`.
+    ///
+    /// References are encoded as `ref.null` since their identity cannot be preserved.
+    fn const_expr(&mut self, value: &Val) {
+        match value {
+            Val::I32(value) => {
+                self.byte(0x41);
+                self.i64(i64::from(*value));
+            }
+            Val::I64(value) => {
+                self.byte(0x42);
+                self.i64(*value);
+            }
+            Val::F32(value) => {
+                self.byte(0x43);
+                self.bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+            Val::F64(value) => {
+                self.byte(0x44);
+                self.bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+            }
+            Val::FuncRef(_) => self.bytes.extend_from_slice(&[0xD0, 0x70]),
+            Val::ExternRef(_) => self.bytes.extend_from_slice(&[0xD0, 0x6F]),
+            Val::AnyRef(_) => self.bytes.extend_from_slice(&[0xD0, 0x6E]),
+            Val::ContRef(_) => self.bytes.extend_from_slice(&[0xD0, 0x68]),
+        }
+        self.byte(0x0B);
+    }
+
+    /// Encodes the constant offset expression of an active data segment at `offset`.
+    ///
+    /// # Note
+    ///
+    /// Offsets of 32-bit linear memories are encoded as `i32.const` whose
+    /// value is interpreted as unsigned integer, thus the full 4 GiB are addressable.
+    ///
+    /// # Panics
+    ///
+    /// If `offset` is out of bounds for a 32-bit linear memory.
+    fn data_offset(&mut self, offset: u64) {
+        let Ok(offset) = u32::try_from(offset) else {
+            panic!("out of bounds data segment offset for 32-bit linear memory: {offset}")
+        };
+        self.const_expr(&Val::I32(offset as i32));
+    }
+
+    /// Encodes a Wasm section with `id` whose contents are encoded by `f`.
+    fn section(&mut self, id: SectionId, f: impl FnOnce(&mut Self)) {
+        let mut contents = Self::default();
+        f(&mut contents);
+        self.byte(id as u8);
+        self.len(contents.bytes.len());
+        self.bytes.extend_from_slice(&contents.bytes);
+    }
+
+    /// Encodes a Wasm custom section with `name` whose contents are encoded by `f`.
+    fn custom_section(&mut self, name: &str, f: impl FnOnce(&mut Self)) {
+        self.section(SectionId::Custom, |e| {
+            e.name(name);
+            f(e);
+        });
+    }
+}
@@ -1,8 +1,13 @@
 use crate::{
     core::ValType,
     engine::DedupFuncType,
-    module::{utils::WasmiValueType, FuncTypeIdx, ModuleHeader},
+    module::{
+        utils::{self, WasmiValueType},
+        FuncTypeIdx,
+        ModuleHeader,
+    },
     Engine,
+    Error,
     FuncType,
 };
 
@@ -29,10 +34,11 @@ impl BlockType {
     /// # Errors
     ///
     /// If the conversion is not valid or unsupported.
-    pub fn new(block_type: wasmparser::BlockType, res: &ModuleHeader) -> Self {
-        match block_type {
+    pub fn new(block_type: wasmparser::BlockType, res: &ModuleHeader) -> Result<Self, Error> {
+        let block_type = match block_type {
             wasmparser::BlockType::Empty => Self::empty(),
             wasmparser::BlockType::Type(return_type) => {
+                utils::ensure_supported_val_type(return_type)?;
                 let return_type = WasmiValueType::from(return_type).into_inner();
                 Self::returns(return_type)
             }
@@ -40,7 +46,8 @@ impl BlockType {
                 let dedup_func_type = res.get_func_type(FuncTypeIdx::from(func_type_idx));
                 Self::func_type(dedup_func_type)
             }
-        }
+        };
+        Ok(block_type)
     }
 
     /// Creates a [`BlockType`] from the underlying type.
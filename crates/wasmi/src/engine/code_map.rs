@@ -10,11 +10,12 @@ use super::{
     FuncTranslationDriver,
     FuncTranslator,
     TranslationError,
+    UntypedProvider,
     ValidatingFuncTranslator,
 };
 use crate::{
     collections::arena::{Arena, ArenaIndex},
-    core::{TrapCode, UntypedVal},
+    core::{TrapCode, UntypedVal, ValType},
     engine::utils::unreachable_unchecked,
     ir::{index::InternalFunc, Instruction},
     module::{FuncIdx, ModuleHeader},
@@ -22,13 +23,18 @@ use crate::{
     Config,
     Error,
 };
-use alloc::boxed::Box;
+#[cfg(feature = "compact-bytecode")]
+use alloc::collections::VecDeque;
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+#[cfg(feature = "compact-bytecode")]
+use core::ptr;
 use core::{
     fmt,
     mem::{self, MaybeUninit},
     ops::{self, Range},
     pin::Pin,
     slice,
+    sync::atomic::{AtomicU32, Ordering},
 };
 use spin::Mutex;
 use wasmparser::{FuncToValidate, ValidatorResources, WasmFeatures};
@@ -79,6 +85,17 @@ impl ArenaIndex for EngineFunc {
 pub struct CodeMap {
     funcs: Mutex<Arena<EngineFunc, FuncEntity>>,
     features: WasmFeatures,
+    /// The hotness after which functions are re-translated or `0` if tiered translation is disabled.
+    tier_up_threshold: u32,
+    /// The epochs used to free replaced [`CompiledFuncEntity`] once they are no longer executed.
+    epochs: Arc<Mutex<Epochs>>,
+    /// The cache of decoded compact encoded [`Instruction`]s.
+    ///
+    /// # Note
+    ///
+    /// Must only be locked while the `funcs` lock is held.
+    #[cfg(feature = "compact-bytecode")]
+    decoded: Mutex<DecodedCache>,
 }
 
 /// A range of [`EngineFunc`]s with contiguous indices.
@@ -221,9 +238,28 @@ impl CodeMap {
         Self {
             funcs: Mutex::new(Arena::default()),
             features: config.wasm_features(),
+            tier_up_threshold: config.get_tier_up_threshold(),
+            epochs: Arc::default(),
+            #[cfg(feature = "compact-bytecode")]
+            decoded: Mutex::new(DecodedCache::new(config.get_decoded_cache_size())),
         }
     }
 
+    /// Returns `true` if tiered translation is enabled for the [`CodeMap`].
+    #[inline]
+    pub fn is_tiered(&self) -> bool {
+        self.tier_up_threshold != 0
+    }
+
+    /// Returns `true` if the [`CodeMap`] frees instructions that might still be executing.
+    ///
+    /// This is the case for replaced [`CompiledFuncEntity`] with tiered translation and
+    /// for evicted decoded [`Instruction`]s with the `compact-bytecode` crate feature.
+    #[inline]
+    fn uses_epochs(&self) -> bool {
+        self.is_tiered() || cfg!(feature = "compact-bytecode")
+    }
+
     /// Allocates `amount` new uninitialized [`EngineFunc`] to the [`CodeMap`].
     ///
     /// # Note
@@ -261,6 +297,7 @@ impl CodeMap {
         &self,
         func: EngineFunc,
         func_idx: FuncIdx,
+        offset: usize,
         bytes: &[u8],
         module: &ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
@@ -271,12 +308,98 @@ impl CodeMap {
         };
         func.init_uncompiled(UncompiledFuncEntity::new(
             func_idx,
+            offset,
             bytes,
             module.clone(),
             func_to_validate,
         ));
     }
 
+    /// Returns the [`WasmLocation`]s of the Wasm operator that got translated to `instr` of `func`.
+    ///
+    /// The [`WasmLocation`]s are ordered from the innermost inlined function to `func` itself.
+    /// Wasm binary offsets are `None` if `func` is not compiled, if `instr` does not belong
+    /// to `func` or if `func` has been compiled without [`Config::wasm_backtrace`] enabled.
+    pub fn wasm_locations(&self, func: EngineFunc, instr: &Instruction) -> Vec<WasmLocation> {
+        let funcs = self.funcs.lock();
+        let mut locations = Vec::new();
+        let location = match funcs.get(func) {
+            Some(FuncEntity::Compiled(entity)) => entity.wasm_locations(instr, &mut locations),
+            _ => None,
+        };
+        locations.push(WasmLocation {
+            func,
+            offset: location.map(|offset| offset as usize),
+            frame: 0,
+        });
+        locations
+    }
+
+    /// Calls `f` with the Wasm binary offsets and [`InlinedCall`]s of the compiled `func`
+    /// and the number of its parameters and locals.
+    ///
+    /// Returns `None` if `func` is not compiled.
+    pub fn with_inline_info<R>(
+        &self,
+        func: EngineFunc,
+        f: impl FnOnce(&[(u32, u32)], &[InlinedCall], usize) -> R,
+    ) -> Option<R> {
+        let funcs = self.funcs.lock();
+        let FuncEntity::Compiled(entity) = funcs.get(func)? else {
+            return None;
+        };
+        Some(f(
+            &entity.wasm_offsets,
+            &entity.inlined_calls,
+            entity.local_types.len(),
+        ))
+    }
+
+    /// Returns the offset within the Wasm binary of the Wasm function body of `func`
+    /// and the types of its parameters and locals.
+    ///
+    /// Returns `None` if `func` is not compiled.
+    ///
+    /// # Note
+    ///
+    /// The returned information is only meaningful if `func` has been
+    /// compiled with [`Config::coredump_on_trap`] enabled.
+    pub fn func_locals(&self, func: EngineFunc) -> Option<(usize, Box<[ValType]>)> {
+        let funcs = self.funcs.lock();
+        let FuncEntity::Compiled(entity) = funcs.get(func)? else {
+            return None;
+        };
+        Some((entity.func_offset as usize, entity.local_types.clone()))
+    }
+
+    /// Returns the types and providers of the values on the Wasm operand stack of `func`
+    /// before the Wasm operator at Wasm binary `offset` executed.
+    ///
+    /// Returns `None` if `func` is not compiled or if the operand stack has not been recorded.
+    ///
+    /// # Note
+    ///
+    /// - Operand stacks are only recorded if `func` has been compiled with
+    ///   [`Config::coredump_on_trap`] enabled and has been validated.
+    /// - Function local constant values are resolved to [`UntypedProvider::Const`].
+    pub fn operand_stack(
+        &self,
+        func: EngineFunc,
+        offset: usize,
+    ) -> Option<Box<[(ValType, UntypedProvider)]>> {
+        let funcs = self.funcs.lock();
+        let FuncEntity::Compiled(entity) = funcs.get(func)? else {
+            return None;
+        };
+        let offset = u32::try_from(offset).ok()?;
+        let operands = entity
+            .operand_stack(offset)?
+            .iter()
+            .map(|(ty, provider)| (*ty, entity.resolve_const(*provider)))
+            .collect();
+        Some(operands)
+    }
+
     /// Returns the [`FuncEntity`] of the [`EngineFunc`].
     ///
     /// # Errors
@@ -290,12 +413,244 @@ impl CodeMap {
         fuel: Option<&mut Fuel>,
         func: EngineFunc,
     ) -> Result<CompiledFuncRef<'a>, Error> {
+        if self.is_tiered() {
+            return self.get_tiered(fuel, func);
+        }
+        self.get_unprofiled(fuel, func)
+    }
+
+    /// Returns the [`FuncEntity`] of the [`EngineFunc`] without accounting for tiered translation.
+    ///
+    /// # Errors
+    ///
+    /// - If translation or Wasm validation of `func` failed.
+    /// - If `ctx` ran out of fuel in case fuel consumption is enabled.
+    #[track_caller]
+    #[inline]
+    pub fn get_unprofiled(
+        &self,
+        fuel: Option<&mut Fuel>,
+        func: EngineFunc,
+    ) -> Result<CompiledFuncRef<'_>, Error> {
         match self.get_compiled(func) {
             Some(cref) => Ok(cref),
             None => self.compile_or_wait(fuel, func),
         }
     }
 
+    /// Returns the [`FuncEntity`] of the [`EngineFunc`] and accounts for the call with tiered translation.
+    ///
+    /// # Errors
+    ///
+    /// - If translation or Wasm validation of `func` failed.
+    /// - If `ctx` ran out of fuel in case fuel consumption is enabled.
+    #[inline(never)]
+    fn get_tiered<'a>(
+        &'a self,
+        fuel: Option<&mut Fuel>,
+        func: EngineFunc,
+    ) -> Result<CompiledFuncRef<'a>, Error> {
+        let Some(cref) = self.get_compiled(func) else {
+            return self.compile_or_wait(fuel, func);
+        };
+        if !self.bump_hotness(cref.hotness, 1) {
+            return Ok(cref);
+        }
+        self.tier_up(func);
+        Ok(self.get_compiled(func).unwrap_or(cref))
+    }
+
+    /// Adds the number of backwards branches taken by `func` to its hotness.
+    ///
+    /// This may re-translate `func` if tiered translation is enabled.
+    #[inline]
+    pub fn add_back_edges(&self, func: EngineFunc, amount: u32) {
+        if !self.is_tiered() {
+            return;
+        }
+        if self.bump_hotness(self.get_hotness(func), amount) {
+            self.tier_up(func);
+        }
+    }
+
+    /// Returns the hotness of `func` if it is eligible for tiered translation.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`CodeMap::get_compiled`] this never decodes the [`Instruction`]s of `func`.
+    #[inline]
+    fn get_hotness(&self, func: EngineFunc) -> Option<&AtomicU32> {
+        let funcs = self.funcs.lock();
+        let Some(FuncEntity::Compiled(entity)) = funcs.get(func) else {
+            return None;
+        };
+        let hotness: *const AtomicU32 = entity.hotness.as_deref()?;
+        // Safety: this is safe for the same reasons as [`CodeMap::adjust_cref_lifetime`]
+        //         since the hotness is `Pin`ned.
+        Some(unsafe { &*hotness })
+    }
+
+    /// Adds `amount` to the `hotness` of a function.
+    ///
+    /// Returns `true` if the `hotness` reached the tier-up threshold with this update.
+    ///
+    /// # Note
+    ///
+    /// This does not lock the [`CodeMap`] and does nothing for functions
+    /// that are not eligible for or already went through tiered translation.
+    #[inline]
+    fn bump_hotness(&self, hotness: Option<&AtomicU32>, amount: u32) -> bool {
+        let Some(hotness) = hotness else {
+            return false;
+        };
+        let threshold = self.tier_up_threshold;
+        let before = hotness.fetch_add(amount, Ordering::Relaxed);
+        before < threshold && before.saturating_add(amount) >= threshold
+    }
+
+    /// Re-translates `func` with tiered translation since it became hot.
+    ///
+    /// Does nothing if `func` is already being or has already been re-translated.
+    #[cold]
+    #[inline(never)]
+    fn tier_up(&self, func: EngineFunc) {
+        let source = {
+            let mut funcs = self.funcs.lock();
+            let Some(FuncEntity::Compiled(entity)) = funcs.get_mut(func) else {
+                panic!("encountered invalid or uncompiled internal function: {func:?}")
+            };
+            entity.tier_up.take()
+        };
+        let Some(source) = source else {
+            return;
+        };
+        // Note: it is important that re-translation happens without locking the `CodeMap`
+        //       since translation can take a prolonged time and may query other functions.
+        //
+        // Note: re-translation of validated Wasm is not expected to fail but if it does
+        //       the function simply keeps its initial translation.
+        //
+        // Note: `source` is consumed by re-translation and thus dropped afterwards.
+        let Ok(optimized) = source.translate() else {
+            return;
+        };
+        {
+            let mut funcs = self.funcs.lock();
+            let Some(entity) = funcs.get_mut(func) else {
+                panic!("encountered invalid internal function: {func:?}")
+            };
+            entity.tier_up(optimized);
+            // Note: the decoded instructions of the replaced entity are freed together with it.
+            #[cfg(feature = "compact-bytecode")]
+            self.decoded.lock().remove(func);
+        }
+        self.epochs.lock().retire(Retired::Replaced(func));
+        self.reclaim();
+    }
+
+    /// Returns an [`EpochGuard`] that keeps all [`CompiledFuncEntity`] alive that are replaced while it exists.
+    ///
+    /// This also keeps decoded [`Instruction`]s alive that are evicted while it exists.
+    ///
+    /// Returns `None` if tiered translation is disabled and the `compact-bytecode` crate
+    /// feature is not enabled since then no [`CompiledFuncEntity`] is ever replaced.
+    ///
+    /// # Note
+    ///
+    /// A [`CompiledFuncRef`] must only be used while an [`EpochGuard`] created
+    /// before the [`CompiledFuncRef`] was queried is alive. This is why every
+    /// [`Stack`] that executes Wasm functions holds an [`EpochGuard`].
+    ///
+    /// [`Stack`]: crate::engine::Stack
+    pub fn enter_epoch(&self) -> Option<EpochGuard> {
+        if !self.uses_epochs() {
+            return None;
+        }
+        let epoch = self.epochs.lock().enter();
+        Some(EpochGuard {
+            epochs: self.epochs.clone(),
+            epoch,
+        })
+    }
+
+    /// Returns the number of replaced [`CompiledFuncEntity`] that have not yet been freed.
+    ///
+    /// # Note
+    ///
+    /// This is a test-only API and not meant for code outside of tests.
+    #[cfg(test)]
+    pub fn len_replaced(&self) -> usize {
+        self.funcs
+            .lock()
+            .iter()
+            .filter(|(_, entity)| {
+                matches!(entity, FuncEntity::Compiled(entity) if entity.replaced.is_some())
+            })
+            .count()
+    }
+
+    /// Returns the number of evicted decoded [`Instruction`]s that have not yet been freed.
+    ///
+    /// # Note
+    ///
+    /// This is a test-only API and not meant for code outside of tests.
+    #[cfg(all(test, feature = "compact-bytecode"))]
+    pub fn len_evicted(&self) -> usize {
+        self.funcs
+            .lock()
+            .iter()
+            .filter_map(|(_, entity)| match entity {
+                FuncEntity::Compiled(entity) => Some(entity.instrs.evicted.len()),
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// Returns the number of functions with cached decoded [`Instruction`]s.
+    ///
+    /// # Note
+    ///
+    /// This is a test-only API and not meant for code outside of tests.
+    #[cfg(all(test, feature = "compact-bytecode"))]
+    pub fn len_decoded(&self) -> usize {
+        self.decoded.lock().funcs.len()
+    }
+
+    /// Frees all replaced [`CompiledFuncEntity`] and evicted decoded [`Instruction`]s that can no longer be executed.
+    ///
+    /// They can no longer be executed once all [`EpochGuard`]s that existed
+    /// at the time of their replacement or eviction are dropped.
+    pub fn reclaim(&self) {
+        if !self.uses_epochs() {
+            return;
+        }
+        let reclaimable = self.epochs.lock().drain_reclaimable();
+        if reclaimable.is_empty() {
+            return;
+        }
+        let mut replaced: Vec<Box<CompiledFuncEntity>> = Vec::new();
+        #[cfg(feature = "compact-bytecode")]
+        let mut evicted: Vec<Pin<Box<[Instruction]>>> = Vec::new();
+        {
+            let mut funcs = self.funcs.lock();
+            for retired in reclaimable {
+                let func = retired.func();
+                let Some(FuncEntity::Compiled(entity)) = funcs.get_mut(func) else {
+                    continue;
+                };
+                match retired {
+                    Retired::Replaced(_) => replaced.extend(entity.replaced.take()),
+                    #[cfg(feature = "compact-bytecode")]
+                    Retired::Evicted(_) => evicted.extend(entity.reclaim_evicted()),
+                }
+            }
+        }
+        // Note: we drop the replaced entities and evicted instructions without holding the lock.
+        drop(replaced);
+        #[cfg(feature = "compact-bytecode")]
+        drop(evicted);
+    }
+
     /// Compile `func` or wait for result if another process already started compilation.
     ///
     /// # Errors
@@ -316,10 +671,14 @@ impl CodeMap {
     }
 
     /// Returns the [`CompiledFuncRef`] of `func` if possible, otherwise returns `None`.
+    ///
+    /// With the `compact-bytecode` crate feature this decodes the [`Instruction`]s
+    /// of `func` if they are not cached.
     #[inline]
-    fn get_compiled(&self, func: EngineFunc) -> Option<CompiledFuncRef> {
-        let funcs = self.funcs.lock();
-        let Some(entity) = funcs.get(func) else {
+    pub fn get_compiled(&self, func: EngineFunc) -> Option<CompiledFuncRef<'_>> {
+        #[cfg_attr(not(feature = "compact-bytecode"), allow(unused_mut))]
+        let mut funcs = self.funcs.lock();
+        let Some(entity) = funcs.get_mut(func) else {
             // Safety: this is just called internally with function indices
             //         that are known to be valid. Since this is a performance
             //         critical path we need to leave out this check.
@@ -327,10 +686,97 @@ impl CodeMap {
                 unreachable_unchecked!("encountered invalid function index for engine: {func:?}")
             }
         };
+        #[cfg(feature = "compact-bytecode")]
+        if let FuncEntity::Compiled(entity) = entity {
+            if !entity.instrs.touch() {
+                drop(funcs);
+                return Some(self.decode(func));
+            }
+        }
         let cref = entity.get_compiled()?;
         Some(self.adjust_cref_lifetime(cref))
     }
 
+    /// Decodes the compact encoded [`Instruction`]s of the compiled `func` and caches them.
+    ///
+    /// Returns the [`CompiledFuncRef`] of `func` with its decoded [`Instruction`]s.
+    ///
+    /// # Note
+    ///
+    /// - Decoding does not lock the [`CodeMap`] since it can take a prolonged time.
+    /// - Caching the decoded [`Instruction`]s may evict the decoded [`Instruction`]s of other functions.
+    ///
+    /// # Panics
+    ///
+    /// If the compact encoded [`Instruction`]s are malformed.
+    #[cfg(feature = "compact-bytecode")]
+    #[cold]
+    #[inline(never)]
+    fn decode(&self, func: EngineFunc) -> CompiledFuncRef<'_> {
+        // Note: frees instructions evicted by executions that have finished in the meantime.
+        self.reclaim();
+        loop {
+            let compact = {
+                let mut funcs = self.funcs.lock();
+                let Some(FuncEntity::Compiled(entity)) = funcs.get_mut(func) else {
+                    panic!("encountered invalid or uncompiled internal function: {func:?}")
+                };
+                if entity.instrs.touch() {
+                    let cref = CompiledFuncRef::from(&*entity);
+                    return self.adjust_cref_lifetime(cref);
+                }
+                let compact: *const [u8] = entity.instrs.compact();
+                // Safety: this is safe for the same reasons as [`CodeMap::adjust_cref_lifetime`]
+                //         since the compact encoded instructions are `Pin`ned.
+                unsafe { &*compact }
+            };
+            let decoded = CompactInstrs::decode(compact);
+            let mut funcs = self.funcs.lock();
+            let Some(FuncEntity::Compiled(entity)) = funcs.get_mut(func) else {
+                panic!("encountered invalid or uncompiled internal function: {func:?}")
+            };
+            if !ptr::eq(entity.instrs.compact(), compact) {
+                // Note: `func` has been re-translated in the meantime.
+                continue;
+            }
+            if let Some(size) = entity.instrs.set_decoded(decoded) {
+                self.cache_decoded(&mut funcs, func, size);
+            }
+            let Some(FuncEntity::Compiled(entity)) = funcs.get(func) else {
+                panic!("encountered invalid or uncompiled internal function: {func:?}")
+            };
+            let cref = CompiledFuncRef::from(entity);
+            return self.adjust_cref_lifetime(cref);
+        }
+    }
+
+    /// Registers the decoded [`Instruction`]s of `func` of `size` bytes with the [`DecodedCache`].
+    ///
+    /// Evicts the decoded [`Instruction`]s of other functions to stay within the size limit.
+    /// Evicted [`Instruction`]s are freed by [`CodeMap::reclaim`] once they can no longer be executed.
+    #[cfg(feature = "compact-bytecode")]
+    fn cache_decoded(
+        &self,
+        funcs: &mut Arena<EngineFunc, FuncEntity>,
+        func: EngineFunc,
+        size: usize,
+    ) {
+        let mut cache = self.decoded.lock();
+        while let Some(evicted) = cache.next_eviction(size) {
+            let Some(FuncEntity::Compiled(entity)) = funcs.get_mut(evicted) else {
+                panic!("encountered invalid or uncompiled internal function: {evicted:?}")
+            };
+            if mem::take(&mut entity.instrs.used) {
+                // Note: recently used functions get a second chance.
+                cache.insert(evicted, entity.instrs.decoded_size());
+                continue;
+            }
+            entity.instrs.evict();
+            self.epochs.lock().retire(Retired::Evicted(evicted));
+        }
+        cache.insert(func, size);
+    }
+
     /// Returns the [`UncompiledFuncEntity`] of `func` if possible, otherwise returns `None`.
     ///
     /// After this operation `func` will be in [`FuncEntity::Compiling`] state.
@@ -351,13 +797,18 @@ impl CodeMap {
     ///
     /// - [`CompiledFuncRef`] only references `Pin`ned data
     /// - [`CodeMap`] is an append-only data structure
+    /// - re-translated [`CompiledFuncEntity`] retain the entity they replaced
+    ///   for as long as an [`EpochGuard`] of the time of replacement is alive
     ///
-    /// Thus any shared [`CompiledFuncRef`] can safely outlive the internal `Mutex` lock.
+    /// Thus any shared [`CompiledFuncRef`] can safely outlive the internal `Mutex` lock
+    /// as long as it is used while holding an [`EpochGuard`].
     #[inline]
     fn adjust_cref_lifetime<'a>(&'a self, cref: CompiledFuncRef<'_>) -> CompiledFuncRef<'a> {
         // Safety: we cast the lifetime of `cref` to match `&self` instead of the inner
-        //         `MutexGuard` which is safe because `CodeMap` is append-only and the
-        //         returned `CompiledFuncRef` only references `Pin`ned data.
+        //         `MutexGuard` which is safe because `CodeMap` is append-only, only drops
+        //         replaced `CompiledFuncEntity` once no `EpochGuard` of the time of their
+        //         replacement exists and the returned `CompiledFuncRef` only references
+        //         `Pin`ned data.
         unsafe { mem::transmute::<CompiledFuncRef<'_>, CompiledFuncRef<'a>>(cref) }
     }
 
@@ -382,8 +833,15 @@ impl CodeMap {
             panic!("encountered invalid internal function: {func:?}")
         };
         match compiled_func {
+            #[cfg(feature = "compact-bytecode")]
+            Ok(compiled_func) => {
+                entity.set_compiled(compiled_func);
+                drop(funcs);
+                Ok(self.decode(func))
+            }
+            #[cfg(not(feature = "compact-bytecode"))]
             Ok(compiled_func) => {
-                let cref = entity.set_compiled(compiled_func);
+                let cref = CompiledFuncRef::from(entity.set_compiled(compiled_func));
                 Ok(self.adjust_cref_lifetime(cref))
             }
             Err(error) => {
@@ -403,7 +861,7 @@ impl CodeMap {
     /// - If `ctx` ran out of fuel in case fuel consumption is enabled.
     #[cold]
     #[inline(never)]
-    fn wait_for_compilation(&self, func: EngineFunc) -> Result<CompiledFuncRef, Error> {
+    fn wait_for_compilation(&self, func: EngineFunc) -> Result<CompiledFuncRef<'_>, Error> {
         'wait: loop {
             let funcs = self.funcs.lock();
             let Some(entity) = funcs.get(func) else {
@@ -411,6 +869,12 @@ impl CodeMap {
             };
             match entity {
                 FuncEntity::Compiling => continue 'wait,
+                #[cfg(feature = "compact-bytecode")]
+                FuncEntity::Compiled(_) => {
+                    drop(funcs);
+                    return Ok(self.decode(func));
+                }
+                #[cfg(not(feature = "compact-bytecode"))]
                 FuncEntity::Compiled(func) => {
                     let cref = CompiledFuncRef::from(func);
                     return Ok(self.adjust_cref_lifetime(cref));
@@ -477,7 +941,7 @@ impl FuncEntity {
     ///
     /// Returns `None` if the [`FuncEntity`] has not yet been compiled.
     #[inline]
-    pub fn get_compiled(&self) -> Option<CompiledFuncRef> {
+    pub fn get_compiled(&self) -> Option<CompiledFuncRef<'_>> {
         match self {
             FuncEntity::Compiled(func) => Some(func.into()),
             _ => None,
@@ -510,19 +974,35 @@ impl FuncEntity {
 
     /// Sets the [`FuncEntity`] as [`CompiledFuncEntity`].
     ///
-    /// Returns a [`CompiledFuncRef`] to the [`CompiledFuncEntity`].
+    /// Returns a shared reference to the [`CompiledFuncEntity`].
     ///
     /// # Panics
     ///
     /// If `func` has already been initialized.
     #[inline]
-    pub fn set_compiled(&mut self, entity: CompiledFuncEntity) -> CompiledFuncRef {
+    pub fn set_compiled(&mut self, entity: CompiledFuncEntity) -> &CompiledFuncEntity {
         assert!(matches!(self, Self::Compiling));
         *self = Self::Compiled(entity);
         let Self::Compiled(entity) = self else {
             panic!("just initialized `self` as compiled")
         };
-        CompiledFuncRef::from(&*entity)
+        entity
+    }
+
+    /// Replaces the [`CompiledFuncEntity`] of the [`FuncEntity`] with its re-translation.
+    ///
+    /// The replaced [`CompiledFuncEntity`] is retained since it might still be executing
+    /// until it is freed by [`CodeMap::reclaim`].
+    ///
+    /// # Panics
+    ///
+    /// If `func` is not compiled.
+    pub fn tier_up(&mut self, entity: CompiledFuncEntity) {
+        let Self::Compiled(func) = self else {
+            panic!("expected compiled function but found: {self:?}")
+        };
+        let replaced = mem::replace(func, entity);
+        func.replaced = Some(Box::new(replaced));
     }
 
     /// Signals a failed compilation for the [`FuncEntity`].
@@ -546,6 +1026,8 @@ pub struct TypeIndex(u32);
 pub struct UncompiledFuncEntity {
     /// The index of the function within the Wasm module.
     func_index: FuncIdx,
+    /// The offset of the Wasm function body within the Wasm module binary.
+    offset: usize,
     /// The Wasm binary bytes.
     bytes: SmallByteSlice,
     /// The Wasm module of the Wasm function.
@@ -563,6 +1045,7 @@ impl UncompiledFuncEntity {
     /// Creates a new [`UncompiledFuncEntity`].
     pub fn new(
         func_index: FuncIdx,
+        offset: usize,
         bytes: &[u8],
         module: ModuleHeader,
         func_to_validate: impl Into<Option<FuncToValidate<ValidatorResources>>>,
@@ -580,6 +1063,7 @@ impl UncompiledFuncEntity {
         let bytes = bytes.into();
         Self {
             func_index,
+            offset,
             bytes,
             module,
             validation,
@@ -623,6 +1107,7 @@ impl UncompiledFuncEntity {
             VALIDATE_FUEL_PER_BYTE + COMPILE_FUEL_PER_BYTE;
 
         let func_idx = self.func_index;
+        let offset = self.offset;
         let bytes = mem::take(&mut self.bytes);
         let needs_validation = self.validation.is_some();
         let compilation_fuel = |_costs: &FuelCosts| {
@@ -646,6 +1131,7 @@ impl UncompiledFuncEntity {
                 module.engine()
             )
         };
+        let tiered = engine.config().get_tier_up_threshold() != 0;
         let mut result = MaybeUninit::uninit();
         match self.validation.take() {
             Some((type_index, resources)) => {
@@ -659,24 +1145,175 @@ impl UncompiledFuncEntity {
                 };
                 let validator = func_to_validate.into_validator(allocs.1);
                 let translator = ValidatingFuncTranslator::new(validator, translator)?;
-                let allocs = FuncTranslationDriver::new(0, &bytes[..], translator)?.translate(
-                    |compiled_func| {
+                let allocs = FuncTranslationDriver::new(offset, &bytes[..], translator)?
+                    .translate(|compiled_func| {
                         result.write(compiled_func);
-                    },
-                )?;
+                    })?;
                 engine.recycle_allocs(allocs.translation, allocs.validation);
             }
             None => {
                 let allocs = engine.get_translation_allocs();
                 let translator = FuncTranslator::new(func_idx, module, allocs)?;
-                let allocs = FuncTranslationDriver::new(0, &bytes[..], translator)?.translate(
-                    |compiled_func| {
+                let allocs = FuncTranslationDriver::new(offset, &bytes[..], translator)?
+                    .translate(|compiled_func| {
                         result.write(compiled_func);
-                    },
-                )?;
+                    })?;
                 engine.recycle_translation_allocs(allocs);
             }
         };
+        let compiled_func: CompiledFuncEntity = unsafe { result.assume_init() };
+        let tier_up = tiered.then(|| TierUpSource {
+            func_index: func_idx,
+            offset,
+            bytes,
+            module: self.module.clone(),
+        });
+        Ok(compiled_func.with_tier_up(tier_up))
+    }
+}
+
+/// The epochs of a [`CodeMap`] used to free replaced [`CompiledFuncEntity`].
+///
+/// The epoch is advanced whenever a [`CompiledFuncEntity`] is replaced by its re-translation
+/// or its decoded [`Instruction`]s are evicted. These can be freed once all [`EpochGuard`]s
+/// of the epoch of their replacement or eviction or earlier epochs have been dropped.
+#[derive(Debug, Default)]
+struct Epochs {
+    /// The current epoch.
+    current: u64,
+    /// The number of alive [`EpochGuard`]s per epoch.
+    active: BTreeMap<u64, usize>,
+    /// The [`Retired`] resources of [`EngineFunc`]s and the epoch of their retirement.
+    ///
+    /// # Note
+    ///
+    /// This is sorted by epochs since the epoch only ever advances.
+    retired: Vec<(u64, Retired)>,
+}
+
+/// A resource of an [`EngineFunc`] that is freed once it can no longer be executed.
+#[derive(Debug, Copy, Clone)]
+enum Retired {
+    /// The replaced [`CompiledFuncEntity`] of the [`EngineFunc`].
+    Replaced(EngineFunc),
+    /// The oldest evicted decoded [`Instruction`]s of the [`EngineFunc`].
+    #[cfg(feature = "compact-bytecode")]
+    Evicted(EngineFunc),
+}
+
+impl Retired {
+    /// Returns the [`EngineFunc`] of the [`Retired`] resource.
+    fn func(self) -> EngineFunc {
+        match self {
+            Self::Replaced(func) => func,
+            #[cfg(feature = "compact-bytecode")]
+            Self::Evicted(func) => func,
+        }
+    }
+}
+
+impl Epochs {
+    /// Registers a new [`EpochGuard`] for the current epoch and returns the current epoch.
+    fn enter(&mut self) -> u64 {
+        *self.active.entry(self.current).or_default() += 1;
+        self.current
+    }
+
+    /// Unregisters an [`EpochGuard`] of `epoch`.
+    fn leave(&mut self, epoch: u64) {
+        let Some(count) = self.active.get_mut(&epoch) else {
+            panic!("missing active epoch guard for epoch: {epoch}")
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.active.remove(&epoch);
+        }
+    }
+
+    /// Registers the `retired` resource and advances the epoch.
+    fn retire(&mut self, retired: Retired) {
+        self.retired.push((self.current, retired));
+        self.current += 1;
+    }
+
+    /// Removes and returns all [`Retired`] resources that can be freed.
+    fn drain_reclaimable(&mut self) -> Vec<Retired> {
+        let oldest = self.active.keys().next().copied();
+        let len = self
+            .retired
+            .partition_point(|(epoch, _)| oldest.map_or(true, |oldest| *epoch < oldest));
+        self.retired
+            .drain(..len)
+            .map(|(_, retired)| retired)
+            .collect()
+    }
+}
+
+/// Keeps replaced [`CompiledFuncEntity`] of a [`CodeMap`] alive while it exists.
+///
+/// Created by [`CodeMap::enter_epoch`].
+#[derive(Debug)]
+pub struct EpochGuard {
+    /// The epochs of the [`CodeMap`].
+    epochs: Arc<Mutex<Epochs>>,
+    /// The epoch at which the [`EpochGuard`] was created.
+    epoch: u64,
+}
+
+impl Drop for EpochGuard {
+    fn drop(&mut self) {
+        self.epochs.lock().leave(self.epoch);
+    }
+}
+
+/// The information required to re-translate a [`CompiledFuncEntity`] with tiered translation.
+#[derive(Debug)]
+pub struct TierUpSource {
+    /// The index of the function within the Wasm module.
+    func_index: FuncIdx,
+    /// The offset of the Wasm function body within the Wasm module binary.
+    offset: usize,
+    /// The Wasm binary bytes.
+    bytes: SmallByteSlice,
+    /// The Wasm module of the Wasm function.
+    module: ModuleHeader,
+}
+
+impl TierUpSource {
+    /// Creates a new [`TierUpSource`].
+    pub fn new(func_index: FuncIdx, offset: usize, bytes: &[u8], module: ModuleHeader) -> Self {
+        Self {
+            func_index,
+            offset,
+            bytes: bytes.into(),
+            module,
+        }
+    }
+
+    /// Re-translates the already validated function with all configured optimizations.
+    ///
+    /// # Panics
+    ///
+    /// If the `engine` unexpectedly no longer exists due to weak referencing.
+    ///
+    /// # Errors
+    ///
+    /// If function translation failed.
+    fn translate(self) -> Result<CompiledFuncEntity, Error> {
+        let Some(engine) = self.module.engine().upgrade() else {
+            panic!(
+                "cannot re-translate function since engine does no longer exist: {:?}",
+                self.module.engine()
+            )
+        };
+        let mut result = MaybeUninit::uninit();
+        let allocs = engine.get_translation_allocs();
+        let translator = FuncTranslator::new(self.func_index, self.module, allocs)?.with_tier_up();
+        let allocs = FuncTranslationDriver::new(self.offset, &self.bytes[..], translator)?
+            .translate(|compiled_func| {
+                result.write(compiled_func);
+            })?;
+        engine.recycle_translation_allocs(allocs);
         Ok(unsafe { result.assume_init() })
     }
 }
@@ -685,6 +1322,7 @@ impl fmt::Debug for UncompiledFuncEntity {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("UncompiledFuncEntity")
             .field("func_idx", &self.func_index)
+            .field("offset", &self.offset)
             .field("bytes", &self.bytes)
             .field("module", &self.module)
             .field("validate", &self.validation.is_some())
@@ -762,7 +1400,11 @@ impl<'a> From<&'a [u8]> for SmallByteSlice {
 #[derive(Debug)]
 pub struct CompiledFuncEntity {
     /// The sequence of [`Instruction`] of the [`CompiledFuncEntity`].
+    #[cfg(not(feature = "compact-bytecode"))]
     instrs: Pin<Box<[Instruction]>>,
+    /// The compact encoded sequence of [`Instruction`] of the [`CompiledFuncEntity`].
+    #[cfg(feature = "compact-bytecode")]
+    instrs: CompactInstrs,
     /// The constant values local to the [`EngineFunc`].
     consts: Pin<Box<[UntypedVal]>>,
     /// The number of registers used by the [`EngineFunc`] in total.
@@ -772,6 +1414,63 @@ pub struct CompiledFuncEntity {
     /// This includes registers to store the function local constant values,
     /// function parameters, function locals and dynamically used registers.
     len_registers: u16,
+    /// Pairs of [`Instruction`] indices and the Wasm binary offsets of their origin.
+    ///
+    /// # Note
+    ///
+    /// - This is empty unless [`Config::wasm_backtrace`] or [`Config::coredump_on_trap`] is enabled.
+    /// - The pairs are sorted by their [`Instruction`] indices.
+    wasm_offsets: Box<[(u32, u32)]>,
+    /// The calls of functions that have been inlined into the [`CompiledFuncEntity`].
+    ///
+    /// # Note
+    ///
+    /// - This is empty unless [`Config::wasm_backtrace`] or [`Config::coredump_on_trap`] is enabled.
+    /// - Enclosing [`InlinedCall`]s precede the [`InlinedCall`]s they contain.
+    /// - The `wasm_offsets` of inlined [`Instruction`]s refer to the Wasm binary of the inlined function.
+    inlined_calls: Box<[InlinedCall]>,
+    /// The Wasm binary offset of the Wasm function body.
+    ///
+    /// # Note
+    ///
+    /// This is zero unless [`Config::wasm_backtrace`] or [`Config::coredump_on_trap`] is enabled.
+    func_offset: u32,
+    /// The types of the function parameters and locals in order.
+    ///
+    /// # Note
+    ///
+    /// This is empty unless [`Config::coredump_on_trap`] is enabled.
+    local_types: Box<[ValType]>,
+    /// Pairs of Wasm binary offsets and the end of the Wasm operand stack before their execution within `operands`.
+    ///
+    /// # Note
+    ///
+    /// - This is empty unless [`Config::coredump_on_trap`] is enabled.
+    /// - The pairs are sorted by their Wasm binary offsets.
+    operand_stacks: Box<[(u32, u32)]>,
+    /// The types and providers of the values of all Wasm operand stacks from bottom to top.
+    operands: Box<[(ValType, UntypedProvider)]>,
+    /// The number of calls and backwards branches of the [`CompiledFuncEntity`] so far.
+    ///
+    /// # Note
+    ///
+    /// - This is `None` unless the [`CompiledFuncEntity`] is eligible for tiered translation.
+    /// - The counter is atomic so that it can be updated without locking the [`CodeMap`].
+    hotness: Option<Pin<Box<AtomicU32>>>,
+    /// The information required to re-translate the [`CompiledFuncEntity`] once it is hot.
+    ///
+    /// # Note
+    ///
+    /// This is `None` unless [`Config::tier_up_threshold`] is enabled or if the
+    /// [`CompiledFuncEntity`] has already been re-translated.
+    tier_up: Option<Box<TierUpSource>>,
+    /// The [`CompiledFuncEntity`] that got replaced by this re-translated [`CompiledFuncEntity`].
+    ///
+    /// # Note
+    ///
+    /// This must be kept alive since calls that are still in progress execute its instructions.
+    /// It is freed by [`CodeMap::reclaim`] once all of those calls are guaranteed to have finished.
+    replaced: Option<Box<CompiledFuncEntity>>,
 }
 
 impl CompiledFuncEntity {
@@ -786,7 +1485,7 @@ impl CompiledFuncEntity {
         I: IntoIterator<Item = Instruction>,
         C: IntoIterator<Item = UntypedVal>,
     {
-        let instrs: Pin<Box<[Instruction]>> = Pin::new(instrs.into_iter().collect());
+        let instrs: Box<[Instruction]> = instrs.into_iter().collect();
         let consts: Pin<Box<[UntypedVal]>> = Pin::new(consts.into_iter().collect());
         assert!(
             !instrs.is_empty(),
@@ -801,10 +1500,407 @@ impl CompiledFuncEntity {
             "compiled function has too many instructions: {}",
             instrs.len(),
         );
+        #[cfg(feature = "compact-bytecode")]
+        let instrs = CompactInstrs::new(&instrs);
+        #[cfg(not(feature = "compact-bytecode"))]
+        let instrs = Pin::new(instrs);
         Self {
             instrs,
             consts,
             len_registers,
+            wasm_offsets: Box::default(),
+            inlined_calls: Box::default(),
+            func_offset: 0,
+            local_types: Box::default(),
+            operand_stacks: Box::default(),
+            operands: Box::default(),
+            hotness: None,
+            tier_up: None,
+            replaced: None,
+        }
+    }
+
+    /// Attaches the Wasm function body offset and the `(instruction index, wasm offset)`
+    /// pairs to the [`CompiledFuncEntity`].
+    ///
+    /// # Note
+    ///
+    /// The pairs must be sorted by their instruction indices.
+    pub fn with_wasm_offsets<T>(mut self, func_offset: u32, wasm_offsets: T) -> Self
+    where
+        T: IntoIterator<Item = (u32, u32)>,
+    {
+        self.func_offset = func_offset;
+        self.wasm_offsets = wasm_offsets.into_iter().collect();
+        self
+    }
+
+    /// Attaches the [`InlinedCall`]s to the [`CompiledFuncEntity`].
+    ///
+    /// # Note
+    ///
+    /// Enclosing [`InlinedCall`]s must precede the [`InlinedCall`]s they contain.
+    pub fn with_inlined_calls<T>(mut self, inlined_calls: T) -> Self
+    where
+        T: IntoIterator<Item = InlinedCall>,
+    {
+        self.inlined_calls = inlined_calls.into_iter().collect();
+        self
+    }
+
+    /// Attaches the types of the function parameters and locals to the [`CompiledFuncEntity`].
+    pub fn with_local_types<T>(mut self, local_types: T) -> Self
+    where
+        T: IntoIterator<Item = ValType>,
+    {
+        self.local_types = local_types.into_iter().collect();
+        self
+    }
+
+    /// Attaches the Wasm operand stacks of the Wasm operators to the [`CompiledFuncEntity`].
+    ///
+    /// # Note
+    ///
+    /// - The `(wasm offset, end)` pairs must be sorted by their Wasm binary offsets.
+    /// - The operand stack of a pair consists of the `operands` between the `end` of
+    ///   the previous pair and its own `end`.
+    pub fn with_operand_stacks<S, O>(mut self, operand_stacks: S, operands: O) -> Self
+    where
+        S: IntoIterator<Item = (u32, u32)>,
+        O: IntoIterator<Item = (ValType, UntypedProvider)>,
+    {
+        self.operand_stacks = operand_stacks.into_iter().collect();
+        self.operands = operands.into_iter().collect();
+        self
+    }
+
+    /// Returns all decoded copies of the [`Instruction`]s of the [`CompiledFuncEntity`].
+    ///
+    /// This includes evicted copies that might still be executed.
+    #[cfg(feature = "compact-bytecode")]
+    fn decoded_instrs(&self) -> impl Iterator<Item = &[Instruction]> {
+        self.instrs.decoded_copies()
+    }
+
+    /// Returns all decoded copies of the [`Instruction`]s of the [`CompiledFuncEntity`].
+    #[cfg(not(feature = "compact-bytecode"))]
+    fn decoded_instrs(&self) -> impl Iterator<Item = &[Instruction]> {
+        core::iter::once(&self.instrs[..])
+    }
+
+    /// Removes and returns the oldest evicted decoded [`Instruction`]s of the [`CompiledFuncEntity`].
+    ///
+    /// This also queries the replaced [`CompiledFuncEntity`] since its evicted [`Instruction`]s are older.
+    #[cfg(feature = "compact-bytecode")]
+    fn reclaim_evicted(&mut self) -> Option<Pin<Box<[Instruction]>>> {
+        if let Some(evicted) = self
+            .replaced
+            .as_mut()
+            .and_then(|replaced| replaced.reclaim_evicted())
+        {
+            return Some(evicted);
+        }
+        self.instrs.reclaim_evicted()
+    }
+
+    /// Attaches the [`TierUpSource`] to the [`CompiledFuncEntity`] if any.
+    pub fn with_tier_up(mut self, source: Option<TierUpSource>) -> Self {
+        self.hotness = source.is_some().then(|| Box::pin(AtomicU32::new(0)));
+        self.tier_up = source.map(Box::new);
+        self
+    }
+
+    /// Returns the Wasm binary offset associated to `instr` if any.
+    ///
+    /// Pushes a [`WasmLocation`] to `locations` for every inlined function
+    /// `instr` originates from, ordered from the innermost to the outermost.
+    /// The returned Wasm binary offset is the one of the outermost function.
+    ///
+    /// This also queries the replaced [`CompiledFuncEntity`] since `instr` might belong to it.
+    fn wasm_locations(
+        &self,
+        instr: &Instruction,
+        locations: &mut Vec<WasmLocation>,
+    ) -> Option<u32> {
+        let (entity, index) = self.locate(instr)?;
+        let mut offset = entity.wasm_offset_at(index);
+        // Note: enclosing inlined calls precede the inlined calls they contain.
+        for call in entity.inlined_calls.iter().rev() {
+            if !(call.start..call.end).contains(&index) {
+                continue;
+            }
+            locations.push(WasmLocation {
+                func: call.callee,
+                offset: offset.map(|offset| offset as usize),
+                frame: call.frame,
+            });
+            offset = Some(call.call_offset);
+        }
+        offset
+    }
+
+    /// Returns the Wasm operand stack before the Wasm operator at Wasm binary `offset` if any.
+    fn operand_stack(&self, offset: u32) -> Option<&[(ValType, UntypedProvider)]> {
+        let pos = self
+            .operand_stacks
+            .binary_search_by_key(&offset, |(offset, _)| *offset)
+            .ok()?;
+        let start = match pos.checked_sub(1) {
+            Some(prev) => self.operand_stacks[prev].1 as usize,
+            None => 0,
+        };
+        let end = self.operand_stacks[pos].1 as usize;
+        self.operands.get(start..end)
+    }
+
+    /// Resolves `provider` to [`UntypedProvider::Const`] if it refers to a function local constant value.
+    fn resolve_const(&self, provider: UntypedProvider) -> UntypedProvider {
+        let UntypedProvider::Register(reg) = provider else {
+            return provider;
+        };
+        if !reg.is_const() {
+            return provider;
+        }
+        // Note: the constant `Reg(-1-k)` refers to the `k`-th last function local constant value.
+        let k = -(i32::from(i16::from(reg)) + 1) as usize;
+        match self.consts.len().checked_sub(1 + k) {
+            Some(index) => UntypedProvider::Const(self.consts[index]),
+            None => provider,
+        }
+    }
+
+    /// Returns the [`CompiledFuncEntity`] that `instr` belongs to and the index of `instr` within it.
+    ///
+    /// This also queries the replaced [`CompiledFuncEntity`] since `instr` might belong to it.
+    fn locate(&self, instr: &Instruction) -> Option<(&Self, u32)> {
+        match self.instr_index(instr) {
+            Some(index) => Some((self, index)),
+            None => self.replaced.as_ref()?.locate(instr),
+        }
+    }
+
+    /// Returns the index of `instr` if it belongs to the [`CompiledFuncEntity`].
+    fn instr_index(&self, instr: &Instruction) -> Option<u32> {
+        self.decoded_instrs().find_map(|instrs| {
+            let base = instrs.as_ptr() as usize;
+            let index = (instr as *const Instruction as usize).checked_sub(base)?
+                / mem::size_of::<Instruction>();
+            if index >= instrs.len() {
+                return None;
+            }
+            u32::try_from(index).ok()
+        })
+    }
+
+    /// Returns the Wasm binary offset associated to the [`Instruction`] at `index`.
+    fn wasm_offset_at(&self, index: u32) -> Option<u32> {
+        let pos = match self
+            .wasm_offsets
+            .binary_search_by_key(&index, |(instr, _)| *instr)
+        {
+            Ok(pos) => pos,
+            Err(pos) => pos.checked_sub(1)?,
+        };
+        let (_, offset) = self.wasm_offsets[pos];
+        Some(offset)
+    }
+}
+
+/// A call of a function that has been inlined into a [`CompiledFuncEntity`].
+#[derive(Debug, Copy, Clone)]
+pub struct InlinedCall {
+    /// The index of the first [`Instruction`] of the inlined function.
+    pub start: u32,
+    /// The index after the last [`Instruction`] of the inlined function.
+    pub end: u32,
+    /// The inlined function.
+    pub callee: EngineFunc,
+    /// The Wasm binary offset of the Wasm call operator that got inlined.
+    pub call_offset: u32,
+    /// The first register of the caller that stores the registers of the inlined function.
+    pub frame: i16,
+}
+
+/// The location of an executed Wasm operator within a possibly inlined Wasm function.
+#[derive(Debug, Copy, Clone)]
+pub struct WasmLocation {
+    /// The function of the executed Wasm operator.
+    pub func: EngineFunc,
+    /// The Wasm binary offset of the executed Wasm operator if known.
+    pub offset: Option<usize>,
+    /// The first register of the executing frame that stores the registers of `func`.
+    ///
+    /// This is zero unless `func` has been inlined.
+    pub frame: i16,
+}
+
+/// The compact encoded [`Instruction`]s of a [`CompiledFuncEntity`] that are decoded upon use.
+///
+/// # Note
+///
+/// Wasmi executes the decoded [`Instruction`]s. The decoded [`Instruction`]s are cached
+/// by the [`DecodedCache`] of the [`CodeMap`] which bounds their total size by evicting
+/// the decoded [`Instruction`]s of functions that have not been called recently.
+#[cfg(feature = "compact-bytecode")]
+#[derive(Debug)]
+struct CompactInstrs {
+    /// The compact encoded [`Instruction`]s.
+    compact: Pin<Box<[u8]>>,
+    /// The cached decoded [`Instruction`]s if any.
+    decoded: Option<Pin<Box<[Instruction]>>>,
+    /// Is `true` if the decoded [`Instruction`]s have been used since the [`DecodedCache`] last checked.
+    used: bool,
+    /// The evicted decoded [`Instruction`]s that might still be executed, oldest first.
+    evicted: Vec<Pin<Box<[Instruction]>>>,
+}
+
+#[cfg(feature = "compact-bytecode")]
+impl CompactInstrs {
+    /// Creates new [`CompactInstrs`] by encoding `instrs`.
+    fn new(instrs: &[Instruction]) -> Self {
+        let mut compact = Vec::new();
+        for instr in instrs {
+            instr.encode_compact(&mut compact);
+        }
+        Self {
+            compact: Pin::new(compact.into_boxed_slice()),
+            decoded: None,
+            used: false,
+            evicted: Vec::new(),
+        }
+    }
+
+    /// Returns the compact encoded [`Instruction`]s.
+    fn compact(&self) -> &[u8] {
+        &self.compact[..]
+    }
+
+    /// Marks the decoded [`Instruction`]s as used.
+    ///
+    /// Returns `true` if the decoded [`Instruction`]s are cached.
+    #[inline]
+    fn touch(&mut self) -> bool {
+        self.used = true;
+        self.decoded.is_some()
+    }
+
+    /// Returns the cached decoded [`Instruction`]s if any.
+    fn decoded(&self) -> Option<Pin<&[Instruction]>> {
+        self.decoded.as_ref().map(|decoded| decoded.as_ref())
+    }
+
+    /// Returns the number of bytes of the cached decoded [`Instruction`]s.
+    fn decoded_size(&self) -> usize {
+        self.decoded
+            .as_ref()
+            .map(|decoded| mem::size_of_val(&decoded[..]))
+            .unwrap_or(0)
+    }
+
+    /// Returns all decoded copies of the [`Instruction`]s including the evicted ones.
+    fn decoded_copies(&self) -> impl Iterator<Item = &[Instruction]> {
+        self.decoded
+            .iter()
+            .chain(&self.evicted)
+            .map(|decoded| &decoded[..])
+    }
+
+    /// Caches the `decoded` [`Instruction`]s unless they are already cached.
+    ///
+    /// Returns the number of bytes of the `decoded` [`Instruction`]s if they have been cached.
+    fn set_decoded(&mut self, decoded: Pin<Box<[Instruction]>>) -> Option<usize> {
+        if self.decoded.is_some() {
+            return None;
+        }
+        let size = mem::size_of_val(&decoded[..]);
+        self.decoded = Some(decoded);
+        Some(size)
+    }
+
+    /// Evicts the cached decoded [`Instruction`]s.
+    ///
+    /// They are retained until freed by [`CompactInstrs::reclaim_evicted`] since they might still be executing.
+    fn evict(&mut self) {
+        self.evicted.extend(self.decoded.take());
+    }
+
+    /// Removes and returns the oldest evicted decoded [`Instruction`]s if any.
+    fn reclaim_evicted(&mut self) -> Option<Pin<Box<[Instruction]>>> {
+        if self.evicted.is_empty() {
+            return None;
+        }
+        Some(self.evicted.remove(0))
+    }
+
+    /// Decodes the `compact` encoded [`Instruction`]s.
+    ///
+    /// # Panics
+    ///
+    /// If the `compact` encoded [`Instruction`]s are malformed.
+    fn decode(compact: &[u8]) -> Pin<Box<[Instruction]>> {
+        let mut bytes = compact;
+        let mut instrs = Vec::new();
+        while !bytes.is_empty() {
+            let Some(instr) = Instruction::decode_compact(&mut bytes) else {
+                panic!("encountered malformed compact encoded instructions: {compact:?}")
+            };
+            instrs.push(instr);
+        }
+        Pin::new(instrs.into_boxed_slice())
+    }
+}
+
+/// The cache of the decoded [`Instruction`]s of compact encoded [`CompiledFuncEntity`]s.
+///
+/// # Note
+///
+/// Once the size limit is exceeded the decoded [`Instruction`]s of functions that have
+/// not been used recently are evicted following the second-chance (clock) algorithm.
+#[cfg(feature = "compact-bytecode")]
+#[derive(Debug)]
+struct DecodedCache {
+    /// The [`EngineFunc`]s with cached decoded [`Instruction`]s and their sizes in bytes, oldest first.
+    funcs: VecDeque<(EngineFunc, usize)>,
+    /// The total size of all cached decoded [`Instruction`]s in bytes.
+    size: usize,
+    /// The maximum total size of all cached decoded [`Instruction`]s in bytes.
+    max_size: usize,
+}
+
+#[cfg(feature = "compact-bytecode")]
+impl DecodedCache {
+    /// Creates a new [`DecodedCache`] with a size limit of `max_size` bytes.
+    fn new(max_size: usize) -> Self {
+        Self {
+            funcs: VecDeque::new(),
+            size: 0,
+            max_size,
+        }
+    }
+
+    /// Registers the decoded [`Instruction`]s of `func` with `size` bytes.
+    fn insert(&mut self, func: EngineFunc, size: usize) {
+        self.funcs.push_back((func, size));
+        self.size += size;
+    }
+
+    /// Removes and returns the oldest [`EngineFunc`] if `size` more bytes exceed the size limit.
+    fn next_eviction(&mut self, size: usize) -> Option<EngineFunc> {
+        if self.size.saturating_add(size) <= self.max_size {
+            return None;
+        }
+        let (func, evicted) = self.funcs.pop_front()?;
+        self.size -= evicted;
+        Some(func)
+    }
+
+    /// Removes `func` from the [`DecodedCache`] if it is registered.
+    fn remove(&mut self, func: EngineFunc) {
+        let Some(pos) = self.funcs.iter().position(|(cached, _)| *cached == func) else {
+            return;
+        };
+        if let Some((_, size)) = self.funcs.remove(pos) {
+            self.size -= size;
         }
     }
 }
@@ -818,15 +1914,29 @@ pub struct CompiledFuncRef<'a> {
     consts: Pin<&'a [UntypedVal]>,
     /// The number of registers used by the [`EngineFunc`] in total.
     len_registers: u16,
+    /// The hotness counter of the [`EngineFunc`] if it is eligible for tiered translation.
+    hotness: Option<&'a AtomicU32>,
 }
 
 impl<'a> From<&'a CompiledFuncEntity> for CompiledFuncRef<'a> {
+    /// Creates a [`CompiledFuncRef`] to `func`.
+    ///
+    /// # Panics
+    ///
+    /// If the compact encoded [`Instruction`]s of `func` have not been decoded.
     #[inline]
     fn from(func: &'a CompiledFuncEntity) -> Self {
+        #[cfg(feature = "compact-bytecode")]
+        let Some(instrs) = func.instrs.decoded() else {
+            panic!("the compact encoded instructions must be decoded before use")
+        };
+        #[cfg(not(feature = "compact-bytecode"))]
+        let instrs = func.instrs.as_ref();
         Self {
-            instrs: func.instrs.as_ref(),
+            instrs,
             consts: func.consts.as_ref(),
             len_registers: func.len_registers,
+            hotness: func.hotness.as_deref(),
         }
     }
 }
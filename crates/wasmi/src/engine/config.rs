@@ -6,6 +6,10 @@ use wasmparser::WasmFeatures;
 /// The default amount of stacks kept in the cache at most.
 const DEFAULT_CACHED_STACKS: usize = 2;
 
+/// The default number of bytes of decoded Wasmi bytecode kept in the cache at most.
+#[cfg(feature = "compact-bytecode")]
+const DEFAULT_DECODED_CACHE_SIZE: usize = 16 * 1024 * 1024;
+
 /// Configuration for an [`Engine`].
 ///
 /// [`Engine`]: [`crate::Engine`]
@@ -25,8 +29,30 @@ pub struct Config {
     fuel_costs: FuelCosts,
     /// The mode of Wasm to Wasmi bytecode compilation.
     compilation_mode: CompilationMode,
+    /// The number of threads used to translate Wasm function bodies with [`CompilationMode::Eager`].
+    #[cfg(feature = "std")]
+    translation_threads: usize,
+    /// The level of optimizations applied to the translated Wasmi bytecode.
+    optimization_level: OptimizationLevel,
+    /// Is `true` if statically known in-bounds linear memory accesses shall not be bounds checked.
+    bounds_check_elimination: bool,
+    /// The maximum number of instructions of internal functions inlined into their callers.
+    max_inline_len: u32,
+    /// The number of calls and loop iterations after which a function is re-translated.
+    tier_up_threshold: u32,
+    /// The number of bytes of decoded Wasmi bytecode kept in the cache at most.
+    #[cfg(feature = "compact-bytecode")]
+    decoded_cache_size: usize,
     /// Enforced limits for Wasm module parsing and compilation.
     limits: EnforcedLimits,
+    /// Is `true` if Wasmi shall capture a [`WasmBacktrace`] when a trap occurs.
+    ///
+    /// [`WasmBacktrace`]: crate::WasmBacktrace
+    wasm_backtrace: bool,
+    /// Is `true` if Wasmi shall capture a [`WasmCoreDump`] when a trap occurs.
+    ///
+    /// [`WasmCoreDump`]: crate::WasmCoreDump
+    coredump_on_trap: bool,
 }
 
 /// Type storing all kinds of fuel costs of instructions.
@@ -151,6 +177,34 @@ pub enum CompilationMode {
     Lazy,
 }
 
+/// The level of optimizations applied to the Wasmi bytecode after translation.
+///
+/// Higher levels increase translation time in exchange for faster execution.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// No optimizations are applied after translation.
+    #[default]
+    None,
+    /// Applies cheap control flow optimizations.
+    ///
+    /// - Jump threading: branches to unconditional branches are redirected to their final
+    ///   targets and branches to returns are replaced by the return.
+    /// - Merges consecutive fuel consumption instructions.
+    Basic,
+    /// Applies all optimizations of [`OptimizationLevel::Basic`] and additionally:
+    ///
+    /// - Copy propagation across blocks without incoming branches.
+    /// - Dead register write elimination of copies whose results are never read.
+    /// - Fusion of compare and conditional branch instructions that were not fused during translation.
+    /// - Fusion of default linear memory loads with a following addition into a single instruction.
+    ///
+    /// # Note
+    ///
+    /// Writes to function locals and operand stack values are preserved if
+    /// [`Config::coredump_on_trap`] is enabled.
+    Full,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -161,7 +215,17 @@ impl Default for Config {
             ignore_custom_sections: false,
             fuel_costs: FuelCosts::default(),
             compilation_mode: CompilationMode::default(),
+            #[cfg(feature = "std")]
+            translation_threads: 1,
+            optimization_level: OptimizationLevel::default(),
+            bounds_check_elimination: true,
+            max_inline_len: 0,
+            tier_up_threshold: 0,
+            #[cfg(feature = "compact-bytecode")]
+            decoded_cache_size: DEFAULT_DECODED_CACHE_SIZE,
             limits: EnforcedLimits::default(),
+            wasm_backtrace: false,
+            coredump_on_trap: false,
         }
     }
 }
@@ -177,7 +241,7 @@ impl Config {
         features.set(WasmFeatures::SIGN_EXTENSION, true);
         features.set(WasmFeatures::BULK_MEMORY, true);
         features.set(WasmFeatures::REFERENCE_TYPES, true);
-        features.set(WasmFeatures::GC_TYPES, true); // required by reference-types
+        features.set(WasmFeatures::GC_TYPES, true); // required by all reference types
         features.set(WasmFeatures::TAIL_CALL, true);
         features.set(WasmFeatures::EXTENDED_CONST, true);
         features.set(WasmFeatures::FLOATS, true);
@@ -297,6 +361,96 @@ impl Config {
         self
     }
 
+    /// Enable or disable the [`function-references`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Requires the [`reference-types`] Wasm proposal to be enabled.
+    ///
+    /// [`function-references`]: https://github.com/WebAssembly/function-references
+    /// [`reference-types`]: https://github.com/WebAssembly/reference-types
+    pub fn wasm_function_references(&mut self, enable: bool) -> &mut Self {
+        self.features.set(WasmFeatures::FUNCTION_REFERENCES, enable);
+        self
+    }
+
+    /// Enable or disable the [`gc`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Requires the [`function-references`] Wasm proposal to be enabled.
+    /// - Unreachable objects are reclaimed automatically upon allocation or via [`Store::gc`].
+    ///
+    /// [`gc`]: https://github.com/WebAssembly/gc
+    /// [`function-references`]: https://github.com/WebAssembly/function-references
+    /// [`Store::gc`]: crate::Store::gc
+    #[cfg(feature = "gc")]
+    pub fn wasm_gc(&mut self, enable: bool) -> &mut Self {
+        self.features.set(WasmFeatures::GC, enable);
+        self
+    }
+
+    /// Enable or disable the [`custom-page-sizes`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Linear memories may declare a page size of 1 byte instead of 64 KiB.
+    ///
+    /// [`custom-page-sizes`]: https://github.com/WebAssembly/custom-page-sizes
+    pub fn wasm_custom_page_sizes(&mut self, enable: bool) -> &mut Self {
+        self.features.set(WasmFeatures::CUSTOM_PAGE_SIZES, enable);
+        self
+    }
+
+    /// Enable or disable the [`wide-arithmetic`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Adds 128-bit integer addition, subtraction and widening multiplication.
+    ///
+    /// [`wide-arithmetic`]: https://github.com/WebAssembly/wide-arithmetic
+    pub fn wasm_wide_arithmetic(&mut self, enable: bool) -> &mut Self {
+        self.features.set(WasmFeatures::WIDE_ARITHMETIC, enable);
+        self
+    }
+
+    /// Enable or disable the [`exception-handling`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Only tags are supported which are required by the [`stack-switching`] proposal:
+    ///   tags cannot be imported or exported and the operators of the
+    ///   [`exception-handling`] proposal are rejected upon translation.
+    ///
+    /// [`exception-handling`]: https://github.com/WebAssembly/exception-handling
+    /// [`stack-switching`]: https://github.com/WebAssembly/stack-switching
+    pub fn wasm_exceptions(&mut self, enable: bool) -> &mut Self {
+        self.features.set(WasmFeatures::EXCEPTIONS, enable);
+        self
+    }
+
+    /// Enable or disable the [`stack-switching`] Wasm proposal for the [`Config`].
+    ///
+    /// # Note
+    ///
+    /// - Disabled by default.
+    /// - Continuation types are typed references and thus require
+    ///   [`Config::wasm_function_references`] to be enabled as well.
+    /// - Tags are defined by the [`exception-handling`] proposal and thus require
+    ///   [`Config::wasm_exceptions`] to be enabled as well.
+    ///
+    /// [`stack-switching`]: https://github.com/WebAssembly/stack-switching
+    /// [`exception-handling`]: https://github.com/WebAssembly/exception-handling
+    pub fn wasm_stack_switching(&mut self, enable: bool) -> &mut Self {
+        self.features.set(WasmFeatures::STACK_SWITCHING, enable);
+        self
+    }
+
     /// Enable or disable the [`tail-call`] Wasm proposal for the [`Config`].
     ///
     /// # Note
@@ -388,9 +542,195 @@ impl Config {
 
     /// Returns the [`CompilationMode`] used for the [`Engine`].
     ///
+    /// # Note
+    ///
+    /// [`CompilationMode::LazyTranslation`] translates eagerly if [`Config::coredump_on_trap`]
+    /// is enabled since recording operand stacks requires validation during translation.
+    ///
     /// [`Engine`]: crate::Engine
     pub(super) fn get_compilation_mode(&self) -> CompilationMode {
-        self.compilation_mode
+        match (self.compilation_mode, self.coredump_on_trap) {
+            (CompilationMode::LazyTranslation, true) => CompilationMode::Eager,
+            (mode, _) => mode,
+        }
+    }
+
+    /// Sets the number of threads used to translate Wasm function bodies with [`CompilationMode::Eager`].
+    ///
+    /// By default `1` is used which translates all function bodies on the calling thread.
+    ///
+    /// # Note
+    ///
+    /// - With more than one thread the function bodies of a Wasm module are translated
+    ///   in parallel by a pool of scoped worker threads, including the calling thread.
+    /// - Parallel translation produces the same Wasmi bytecode and errors as sequential translation.
+    /// - Only [`Module::new`] and [`Module::new_unchecked`] translate in parallel.
+    /// - Translation is sequential if [`Config::max_inline_len`] is non-zero since
+    ///   inlining depends on the order in which functions are translated, unless
+    ///   inlining is deferred to re-translation via [`Config::tier_up_threshold`].
+    /// - A value of `0` is treated as `1`.
+    ///
+    /// [`Module::new`]: crate::Module::new
+    /// [`Module::new_unchecked`]: crate::Module::new_unchecked
+    #[cfg(feature = "std")]
+    pub fn translation_threads(&mut self, threads: usize) -> &mut Self {
+        self.translation_threads = threads;
+        self
+    }
+
+    /// Returns the number of threads used to translate Wasm function bodies in parallel.
+    ///
+    /// Returns `1` if Wasm function bodies are translated sequentially.
+    #[cfg(feature = "std")]
+    pub(crate) fn get_translation_threads(&self) -> usize {
+        let parallel = matches!(self.get_compilation_mode(), CompilationMode::Eager)
+            && (self.max_inline_len == 0 || self.get_tier_up_threshold() != 0);
+        match parallel {
+            true => self.translation_threads.max(1),
+            false => 1,
+        }
+    }
+
+    /// Sets the [`OptimizationLevel`] used for the [`Engine`].
+    ///
+    /// By default [`OptimizationLevel::None`] is used.
+    ///
+    /// # Note
+    ///
+    /// - Optimizations are applied to every function after its translation
+    ///   and therefore increase translation time.
+    /// - When running out of fuel with [`OptimizationLevel::Basic`] or higher
+    ///   the execution might trap slightly earlier.
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn optimization_level(&mut self, level: OptimizationLevel) -> &mut Self {
+        self.optimization_level = level;
+        self
+    }
+
+    /// Returns the [`OptimizationLevel`] used for the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    pub(crate) fn get_optimization_level(&self) -> OptimizationLevel {
+        self.optimization_level
+    }
+
+    /// Enables or disables bounds check elimination for the [`Engine`].
+    ///
+    /// By default bounds check elimination is enabled.
+    ///
+    /// # Note
+    ///
+    /// - Accesses of the default linear memory that are statically known to be in bounds
+    ///   are not bounds checked, e.g. repeated accesses through the same pointer, accesses
+    ///   in loops dominated by a covering access or constant addresses below the minimum
+    ///   size of the linear memory. Trapping behavior is preserved.
+    /// - Bounds check elimination is applied to every function after its translation
+    ///   independently of the [`OptimizationLevel`] and of [`Config::tier_up_threshold`].
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn bounds_check_elimination(&mut self, enable: bool) -> &mut Self {
+        self.bounds_check_elimination = enable;
+        self
+    }
+
+    /// Returns `true` if bounds check elimination is enabled for the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    pub(crate) fn get_bounds_check_elimination(&self) -> bool {
+        self.bounds_check_elimination
+    }
+
+    /// Sets the maximum number of instructions of internal functions that are inlined into their callers.
+    ///
+    /// By default this is `0` which disables inlining.
+    ///
+    /// # Note
+    ///
+    /// - Only calls to internal functions without control flow and without calls
+    ///   are inlined, so recursive functions are never inlined.
+    /// - Only callees that have already been translated are inlined. With
+    ///   [`CompilationMode::Eager`] these are the functions defined before the caller.
+    /// - Fuel consumption is unaffected by inlining.
+    /// - Inlined functions still show up as separate frames in Wasm backtraces and coredumps
+    ///   if [`Config::wasm_backtrace`] or [`Config::coredump_on_trap`] is enabled.
+    pub fn max_inline_len(&mut self, max_len: u32) -> &mut Self {
+        self.max_inline_len = max_len;
+        self
+    }
+
+    /// Returns the maximum number of instructions of internal functions that are inlined into their callers.
+    ///
+    /// Returns `0` if inlining is disabled.
+    pub(crate) fn get_max_inline_len(&self) -> u32 {
+        self.max_inline_len
+    }
+
+    /// Enables tiered translation and sets the number of calls and loop iterations
+    /// after which a function is re-translated.
+    ///
+    /// By default this is `0` which disables tiered translation.
+    ///
+    /// # Note
+    ///
+    /// - With tiered translation all functions are first translated without
+    ///   optimizations and without inlining in order to minimize translation time.
+    /// - Every call to a function and every backwards branch within a function
+    ///   counts towards its hotness. Loop iterations are accounted for when the
+    ///   function calls another Wasm function or returns.
+    /// - Once the hotness of a function reaches the threshold it is re-translated
+    ///   using the configured [`Config::optimization_level`] and [`Config::max_inline_len`].
+    ///   Re-translation applies no optimizations beyond those, e.g. register copies are only
+    ///   coalesced by the copy propagation of [`OptimizationLevel::Full`].
+    /// - The re-translated function is used by all subsequent calls while calls that are
+    ///   still in progress finish executing the previous translation since there is no
+    ///   on-stack replacement. The previous translation is freed once no execution that
+    ///   started before the re-translation is in progress, including resumable calls.
+    /// - Functions keep a copy of their Wasm function body until they are re-translated.
+    /// - Re-translation does not consume fuel. However, fuel consumption of the
+    ///   re-translated function follows its [`OptimizationLevel`].
+    /// - Tiered translation has no effect if neither optimizations nor inlining are enabled.
+    /// - Tiered translation has no effect if [`Config::coredump_on_trap`] is enabled since
+    ///   re-translated functions cannot record the Wasm operand stacks for Wasm coredumps.
+    pub fn tier_up_threshold(&mut self, threshold: u32) -> &mut Self {
+        self.tier_up_threshold = threshold;
+        self
+    }
+
+    /// Returns the number of calls and loop iterations after which a function is re-translated.
+    ///
+    /// Returns `0` if tiered translation is disabled or would not change the translation.
+    pub(crate) fn get_tier_up_threshold(&self) -> u32 {
+        let optimizes = !matches!(self.optimization_level, OptimizationLevel::None);
+        match (optimizes || self.max_inline_len != 0) && !self.coredump_on_trap {
+            true => self.tier_up_threshold,
+            false => 0,
+        }
+    }
+
+    /// Sets the number of bytes of decoded Wasmi bytecode kept in the cache at most.
+    ///
+    /// By default at most 16 MiB of decoded Wasmi bytecode are kept.
+    ///
+    /// # Note
+    ///
+    /// - With the `compact-bytecode` crate feature functions are decoded upon their first
+    ///   call and their decoded form is cached for subsequent calls.
+    /// - Once the cache is full the decoded form of a function that has not been called
+    ///   recently is evicted and the function is decoded again upon its next call.
+    /// - Evicted decoded functions are freed once no execution that started before
+    ///   their eviction is in progress, including resumable calls.
+    /// - The most recently decoded function is always cached, even if it exceeds the limit.
+    #[cfg(feature = "compact-bytecode")]
+    pub fn decoded_cache_size(&mut self, bytes: usize) -> &mut Self {
+        self.decoded_cache_size = bytes;
+        self
+    }
+
+    /// Returns the number of bytes of decoded Wasmi bytecode kept in the cache at most.
+    #[cfg(feature = "compact-bytecode")]
+    pub(crate) fn get_decoded_cache_size(&self) -> usize {
+        self.decoded_cache_size
     }
 
     /// Sets the [`EnforcedLimits`] enforced by the [`Engine`] for Wasm module parsing and compilation.
@@ -410,6 +750,63 @@ impl Config {
         &self.limits
     }
 
+    /// Configures whether Wasmi captures a [`WasmBacktrace`] of the Wasm call stack upon traps.
+    ///
+    /// # Note
+    ///
+    /// - Captured backtraces are available via [`Error::backtrace`] and are part of
+    ///   the [`Display`] implementation of the returned [`Error`].
+    /// - Function names are taken from the Wasm `name` custom section if available.
+    /// - Functions inlined via [`Config::max_inline_len`] are captured as separate frames.
+    /// - This requires Wasmi to store a Wasm code offset table for every translated function
+    ///   which increases translation time and memory consumption slightly.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`WasmBacktrace`]: crate::WasmBacktrace
+    /// [`Error`]: crate::Error
+    /// [`Error::backtrace`]: crate::Error::backtrace
+    /// [`Display`]: core::fmt::Display
+    pub fn wasm_backtrace(&mut self, enable: bool) -> &mut Self {
+        self.wasm_backtrace = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables capturing of Wasm backtraces upon traps.
+    pub(crate) fn get_wasm_backtrace(&self) -> bool {
+        self.wasm_backtrace
+    }
+
+    /// Configures whether Wasmi captures a [`WasmCoreDump`] upon traps.
+    ///
+    /// # Note
+    ///
+    /// - Captured coredumps are available via [`Error::coredump`] and can be serialized
+    ///   into the [tool-conventions Wasm coredump format] for post-mortem debugging.
+    /// - Coredumps contain the Wasm call stack with the values of all function locals and
+    ///   operand stacks as well as snapshots of the linear memories and global variables
+    ///   of all module instances on the Wasm call stack.
+    /// - This requires Wasmi to store a Wasm code offset table, the local types and the operand
+    ///   stack layouts for every translated function which increases translation time and
+    ///   memory consumption.
+    /// - [`CompilationMode::LazyTranslation`] translates eagerly since recording the operand
+    ///   stack layouts requires Wasm validation during translation.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`WasmCoreDump`]: crate::WasmCoreDump
+    /// [`Error::coredump`]: crate::Error::coredump
+    /// [tool-conventions Wasm coredump format]: https://github.com/WebAssembly/tool-conventions/blob/main/Coredump.md
+    pub fn coredump_on_trap(&mut self, enable: bool) -> &mut Self {
+        self.coredump_on_trap = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables capturing of Wasm coredumps upon traps.
+    pub(crate) fn get_coredump_on_trap(&self) -> bool {
+        self.coredump_on_trap
+    }
+
     /// Returns the [`WasmFeatures`] represented by the [`Config`].
     pub(crate) fn wasm_features(&self) -> WasmFeatures {
         self.features
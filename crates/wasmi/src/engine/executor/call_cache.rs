@@ -0,0 +1,103 @@
+use crate::{engine::DedupFuncType, ir::Instruction, Func, TableIdx};
+use alloc::{boxed::Box, vec};
+use core::mem;
+
+/// The number of entries of a [`CallIndirectCache`].
+const CACHE_LEN: usize = 256;
+
+/// The inline caches of the `call_indirect` call sites executed within a [`Store`].
+///
+/// Every call site caches the [`Func`] it resolved most recently together with
+/// the table index through which it was resolved and the signature that it was
+/// successfully checked against. Subsequent calls of the same call site through the
+/// same table index can skip the table lookup and the signature check.
+///
+/// # Note
+///
+/// - Call sites are identified by the address of their [`Instruction`] and map
+///   to the entries of a direct-mapped cache. Since the cached [`Func`] is fully
+///   determined by the table, the table index and the expected signature a
+///   collision of two call sites only ever leads to a cache miss.
+/// - All entries are invalidated whenever any table of the [`Store`] is mutated.
+///
+/// [`Store`]: crate::Store
+#[derive(Debug, Default)]
+pub struct CallIndirectCache {
+    /// The cache entries, allocated upon the first cached call.
+    entries: Box<[Option<CallIndirectCacheEntry>]>,
+    /// The current epoch of the tables of the [`Store`](crate::Store).
+    ///
+    /// Entries of prior epochs are stale.
+    epoch: u64,
+}
+
+/// A monomorphic inline cache entry of a `call_indirect` call site.
+#[derive(Debug, Copy, Clone)]
+struct CallIndirectCacheEntry {
+    /// The table through which `func` was resolved.
+    table: TableIdx,
+    /// The index of `func` within `table`.
+    index: u32,
+    /// The signature that `func` matched.
+    func_type: DedupFuncType,
+    /// The resolved function.
+    func: Func,
+    /// The table epoch at which the entry was created.
+    epoch: u64,
+}
+
+impl CallIndirectCache {
+    /// Returns the entry index of the `call_site`.
+    fn slot(call_site: usize) -> usize {
+        (call_site / mem::size_of::<Instruction>()) % CACHE_LEN
+    }
+
+    /// Invalidates all entries of the [`CallIndirectCache`].
+    ///
+    /// This must be called whenever a table is mutated.
+    #[inline]
+    pub fn invalidate(&mut self) {
+        self.epoch = self.epoch.wrapping_add(1);
+    }
+
+    /// Returns the cached [`Func`] of the `call_site` if any.
+    ///
+    /// Returns `None` if the `call_site` did not resolve a [`Func`] at `index` of `table`
+    /// matching `func_type` since the last mutation of a table.
+    #[inline]
+    pub fn get(
+        &self,
+        call_site: usize,
+        table: TableIdx,
+        index: u32,
+        func_type: &DedupFuncType,
+    ) -> Option<Func> {
+        let entry = self.entries.get(Self::slot(call_site))?.as_ref()?;
+        let is_hit = entry.epoch == self.epoch
+            && entry.table == table
+            && entry.index == index
+            && entry.func_type == *func_type;
+        is_hit.then_some(entry.func)
+    }
+
+    /// Caches `func` resolved by `call_site` at `index` of `table` matching `func_type`.
+    pub fn insert(
+        &mut self,
+        call_site: usize,
+        table: TableIdx,
+        index: u32,
+        func_type: DedupFuncType,
+        func: Func,
+    ) {
+        if self.entries.is_empty() {
+            self.entries = vec![None; CACHE_LEN].into_boxed_slice();
+        }
+        self.entries[Self::slot(call_site)] = Some(CallIndirectCacheEntry {
+            table,
+            index,
+            func_type,
+            func,
+            epoch: self.epoch,
+        });
+    }
+}
@@ -47,6 +47,12 @@ impl InstructionPtr {
         self.ptr = unsafe { self.ptr.add(delta) };
     }
 
+    /// Returns the address of the currently pointed at [`Instruction`].
+    #[inline(always)]
+    pub fn addr(&self) -> usize {
+        self.ptr as usize
+    }
+
     /// Returns a shared reference to the currently pointed at [`Instruction`].
     ///
     /// # Safety
@@ -25,12 +25,15 @@ use crate::{
 #[cfg(doc)]
 use crate::Instance;
 
+mod backtrace;
 mod binary;
 mod branch;
 mod call;
 mod comparison;
+mod cont;
 mod conversion;
 mod copy;
+mod gc;
 mod global;
 mod load;
 mod memory;
@@ -61,7 +64,17 @@ pub fn execute_instrs<'engine, T>(
 ) -> Result<(), Error> {
     let instance = stack.calls.instance_expect();
     let cache = CachedInstance::new(&mut store.inner, instance);
-    Executor::new(stack, code_map, cache).execute(store)
+    let mut executor = Executor::new(stack, code_map, cache);
+    executor.execute(store).map_err(|mut error| {
+        let config = store.engine().config();
+        if config.get_wasm_backtrace() {
+            error.attach_backtrace(executor.capture_backtrace(&store.inner));
+        }
+        if config.get_coredump_on_trap() && error.i32_exit_status().is_none() {
+            error.attach_coredump(executor.capture_coredump(&store.inner));
+        }
+        error
+    })
 }
 
 /// An execution context for executing a Wasmi function frame.
@@ -79,6 +92,15 @@ struct Executor<'engine> {
     ///
     /// [`Engine`]: crate::Engine
     code_map: &'engine CodeMap,
+    /// The number of backwards branches taken by the currently executed function frame.
+    ///
+    /// This is accounted to the hotness of the function for tiered translation
+    /// upon calls and returns.
+    back_edges: u32,
+    /// Is `true` if tiered translation is enabled.
+    ///
+    /// Backwards branches are neither counted nor flushed otherwise.
+    tiered: bool,
 }
 
 impl<'engine> Executor<'engine> {
@@ -104,12 +126,14 @@ impl<'engine> Executor<'engine> {
             cache,
             stack,
             code_map,
+            back_edges: 0,
+            tiered: code_map.is_tiered(),
         }
     }
 
     /// Executes the function frame until it returns or traps.
     #[inline(always)]
-    fn execute<T>(mut self, store: &mut Store<T>) -> Result<(), Error> {
+    fn execute<T>(&mut self, store: &mut Store<T>) -> Result<(), Error> {
         use Instruction as Instr;
         loop {
             match *self.ip.get() {
@@ -413,6 +437,12 @@ impl<'engine> Executor<'engine> {
                 Instr::ReturnCallIndirectImm16 { func_type } => {
                     self.execute_return_call_indirect_imm16::<T>(store, func_type)?
                 }
+                Instr::ReturnCallRef0 { func_type } => {
+                    self.execute_return_call_ref_0::<T>(store, func_type)?
+                }
+                Instr::ReturnCallRef { func_type } => {
+                    self.execute_return_call_ref::<T>(store, func_type)?
+                }
                 Instr::CallInternal0 { results, func } => {
                     self.execute_call_internal_0(&mut store.inner, results, EngineFunc::from(func))?
                 }
@@ -437,6 +467,12 @@ impl<'engine> Executor<'engine> {
                 Instr::CallIndirectImm16 { results, func_type } => {
                     self.execute_call_indirect_imm16::<T>(store, results, func_type)?
                 }
+                Instr::CallRef0 { results, func_type } => {
+                    self.execute_call_ref_0::<T>(store, results, func_type)?
+                }
+                Instr::CallRef { results, func_type } => {
+                    self.execute_call_ref::<T>(store, results, func_type)?
+                }
                 Instr::Select { result, lhs } => self.execute_select(result, lhs),
                 Instr::SelectImm32Rhs { result, lhs } => self.execute_select_imm32_rhs(result, lhs),
                 Instr::SelectImm32Lhs { result, lhs } => self.execute_select_imm32_lhs(result, lhs),
@@ -474,159 +510,281 @@ impl<'engine> Executor<'engine> {
                 Instr::Load32At { result, address } => {
                     self.execute_load32_at(&store.inner, result, address)?
                 }
+                Instr::Load32AtUnchecked { result, address } => {
+                    self.execute_load32_at_unchecked(result, address)
+                }
                 Instr::Load32Offset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_load32_offset16(result, ptr, offset)?,
+                Instr::Load32Offset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_load32_offset16_unchecked(result, ptr, offset),
                 Instr::Load64 { result, memory } => {
                     self.execute_load64(&store.inner, result, memory)?
                 }
                 Instr::Load64At { result, address } => {
                     self.execute_load64_at(&store.inner, result, address)?
                 }
+                Instr::Load64AtUnchecked { result, address } => {
+                    self.execute_load64_at_unchecked(result, address)
+                }
                 Instr::Load64Offset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_load64_offset16(result, ptr, offset)?,
+                Instr::Load64Offset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_load64_offset16_unchecked(result, ptr, offset),
+                Instr::I32AddLoad32 { result, lhs } => self.execute_i32_add_load32(result, lhs)?,
+                Instr::I32AddLoad32Unchecked { result, lhs } => {
+                    self.execute_i32_add_load32_unchecked(result, lhs)
+                }
+                Instr::I64AddLoad64 { result, lhs } => self.execute_i64_add_load64(result, lhs)?,
+                Instr::I64AddLoad64Unchecked { result, lhs } => {
+                    self.execute_i64_add_load64_unchecked(result, lhs)
+                }
                 Instr::I32Load8s { result, memory } => {
                     self.execute_i32_load8_s(&store.inner, result, memory)?
                 }
                 Instr::I32Load8sAt { result, address } => {
                     self.execute_i32_load8_s_at(&store.inner, result, address)?
                 }
+                Instr::I32Load8sAtUnchecked { result, address } => {
+                    self.execute_i32_load8_s_at_unchecked(result, address)
+                }
                 Instr::I32Load8sOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i32_load8_s_offset16(result, ptr, offset)?,
+                Instr::I32Load8sOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i32_load8_s_offset16_unchecked(result, ptr, offset),
                 Instr::I32Load8u { result, memory } => {
                     self.execute_i32_load8_u(&store.inner, result, memory)?
                 }
                 Instr::I32Load8uAt { result, address } => {
                     self.execute_i32_load8_u_at(&store.inner, result, address)?
                 }
+                Instr::I32Load8uAtUnchecked { result, address } => {
+                    self.execute_i32_load8_u_at_unchecked(result, address)
+                }
                 Instr::I32Load8uOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i32_load8_u_offset16(result, ptr, offset)?,
+                Instr::I32Load8uOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i32_load8_u_offset16_unchecked(result, ptr, offset),
                 Instr::I32Load16s { result, memory } => {
                     self.execute_i32_load16_s(&store.inner, result, memory)?
                 }
                 Instr::I32Load16sAt { result, address } => {
                     self.execute_i32_load16_s_at(&store.inner, result, address)?
                 }
+                Instr::I32Load16sAtUnchecked { result, address } => {
+                    self.execute_i32_load16_s_at_unchecked(result, address)
+                }
                 Instr::I32Load16sOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i32_load16_s_offset16(result, ptr, offset)?,
+                Instr::I32Load16sOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i32_load16_s_offset16_unchecked(result, ptr, offset),
                 Instr::I32Load16u { result, memory } => {
                     self.execute_i32_load16_u(&store.inner, result, memory)?
                 }
                 Instr::I32Load16uAt { result, address } => {
                     self.execute_i32_load16_u_at(&store.inner, result, address)?
                 }
+                Instr::I32Load16uAtUnchecked { result, address } => {
+                    self.execute_i32_load16_u_at_unchecked(result, address)
+                }
                 Instr::I32Load16uOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i32_load16_u_offset16(result, ptr, offset)?,
+                Instr::I32Load16uOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i32_load16_u_offset16_unchecked(result, ptr, offset),
                 Instr::I64Load8s { result, memory } => {
                     self.execute_i64_load8_s(&store.inner, result, memory)?
                 }
                 Instr::I64Load8sAt { result, address } => {
                     self.execute_i64_load8_s_at(&store.inner, result, address)?
                 }
+                Instr::I64Load8sAtUnchecked { result, address } => {
+                    self.execute_i64_load8_s_at_unchecked(result, address)
+                }
                 Instr::I64Load8sOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i64_load8_s_offset16(result, ptr, offset)?,
+                Instr::I64Load8sOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i64_load8_s_offset16_unchecked(result, ptr, offset),
                 Instr::I64Load8u { result, memory } => {
                     self.execute_i64_load8_u(&store.inner, result, memory)?
                 }
                 Instr::I64Load8uAt { result, address } => {
                     self.execute_i64_load8_u_at(&store.inner, result, address)?
                 }
+                Instr::I64Load8uAtUnchecked { result, address } => {
+                    self.execute_i64_load8_u_at_unchecked(result, address)
+                }
                 Instr::I64Load8uOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i64_load8_u_offset16(result, ptr, offset)?,
+                Instr::I64Load8uOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i64_load8_u_offset16_unchecked(result, ptr, offset),
                 Instr::I64Load16s { result, memory } => {
                     self.execute_i64_load16_s(&store.inner, result, memory)?
                 }
                 Instr::I64Load16sAt { result, address } => {
                     self.execute_i64_load16_s_at(&store.inner, result, address)?
                 }
+                Instr::I64Load16sAtUnchecked { result, address } => {
+                    self.execute_i64_load16_s_at_unchecked(result, address)
+                }
                 Instr::I64Load16sOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i64_load16_s_offset16(result, ptr, offset)?,
+                Instr::I64Load16sOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i64_load16_s_offset16_unchecked(result, ptr, offset),
                 Instr::I64Load16u { result, memory } => {
                     self.execute_i64_load16_u(&store.inner, result, memory)?
                 }
                 Instr::I64Load16uAt { result, address } => {
                     self.execute_i64_load16_u_at(&store.inner, result, address)?
                 }
+                Instr::I64Load16uAtUnchecked { result, address } => {
+                    self.execute_i64_load16_u_at_unchecked(result, address)
+                }
                 Instr::I64Load16uOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i64_load16_u_offset16(result, ptr, offset)?,
+                Instr::I64Load16uOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i64_load16_u_offset16_unchecked(result, ptr, offset),
                 Instr::I64Load32s { result, memory } => {
                     self.execute_i64_load32_s(&store.inner, result, memory)?
                 }
                 Instr::I64Load32sAt { result, address } => {
                     self.execute_i64_load32_s_at(&store.inner, result, address)?
                 }
+                Instr::I64Load32sAtUnchecked { result, address } => {
+                    self.execute_i64_load32_s_at_unchecked(result, address)
+                }
                 Instr::I64Load32sOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i64_load32_s_offset16(result, ptr, offset)?,
+                Instr::I64Load32sOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i64_load32_s_offset16_unchecked(result, ptr, offset),
                 Instr::I64Load32u { result, memory } => {
                     self.execute_i64_load32_u(&store.inner, result, memory)?
                 }
                 Instr::I64Load32uAt { result, address } => {
                     self.execute_i64_load32_u_at(&store.inner, result, address)?
                 }
+                Instr::I64Load32uAtUnchecked { result, address } => {
+                    self.execute_i64_load32_u_at_unchecked(result, address)
+                }
                 Instr::I64Load32uOffset16 {
                     result,
                     ptr,
                     offset,
                 } => self.execute_i64_load32_u_offset16(result, ptr, offset)?,
+                Instr::I64Load32uOffset16Unchecked {
+                    result,
+                    ptr,
+                    offset,
+                } => self.execute_i64_load32_u_offset16_unchecked(result, ptr, offset),
                 Instr::Store32 { ptr, memory } => {
                     self.execute_store32(&mut store.inner, ptr, memory)?
                 }
                 Instr::Store32Offset16 { ptr, offset, value } => {
                     self.execute_store32_offset16(ptr, offset, value)?
                 }
+                Instr::Store32Offset16Unchecked { ptr, offset, value } => {
+                    self.execute_store32_offset16_unchecked(ptr, offset, value)
+                }
                 Instr::Store32At { address, value } => {
                     self.execute_store32_at(&mut store.inner, address, value)?
                 }
+                Instr::Store32AtUnchecked { value, address } => {
+                    self.execute_store32_at_unchecked(address, value)
+                }
                 Instr::Store64 { ptr, memory } => {
                     self.execute_store64(&mut store.inner, ptr, memory)?
                 }
                 Instr::Store64Offset16 { ptr, offset, value } => {
                     self.execute_store64_offset16(ptr, offset, value)?
                 }
+                Instr::Store64Offset16Unchecked { ptr, offset, value } => {
+                    self.execute_store64_offset16_unchecked(ptr, offset, value)
+                }
                 Instr::Store64At { address, value } => {
                     self.execute_store64_at(&mut store.inner, address, value)?
                 }
+                Instr::Store64AtUnchecked { value, address } => {
+                    self.execute_store64_at_unchecked(address, value)
+                }
                 Instr::I32StoreImm16 { ptr, memory } => {
                     self.execute_i32_store_imm16(&mut store.inner, ptr, memory)?
                 }
                 Instr::I32StoreOffset16Imm16 { ptr, offset, value } => {
                     self.execute_i32_store_offset16_imm16(ptr, offset, value)?
                 }
+                Instr::I32StoreOffset16Imm16Unchecked { ptr, offset, value } => {
+                    self.execute_i32_store_offset16_imm16_unchecked(ptr, offset, value)
+                }
                 Instr::I32StoreAtImm16 { address, value } => {
                     self.execute_i32_store_at_imm16(&mut store.inner, address, value)?
                 }
+                Instr::I32StoreAtImm16Unchecked { value, address } => {
+                    self.execute_i32_store_at_imm16_unchecked(address, value)
+                }
                 Instr::I32Store8 { ptr, memory } => {
                     self.execute_i32_store8(&mut store.inner, ptr, memory)?
                 }
@@ -636,15 +794,27 @@ impl<'engine> Executor<'engine> {
                 Instr::I32Store8Offset16 { ptr, offset, value } => {
                     self.execute_i32_store8_offset16(ptr, offset, value)?
                 }
+                Instr::I32Store8Offset16Unchecked { ptr, offset, value } => {
+                    self.execute_i32_store8_offset16_unchecked(ptr, offset, value)
+                }
                 Instr::I32Store8Offset16Imm { ptr, offset, value } => {
                     self.execute_i32_store8_offset16_imm(ptr, offset, value)?
                 }
+                Instr::I32Store8Offset16ImmUnchecked { ptr, offset, value } => {
+                    self.execute_i32_store8_offset16_imm_unchecked(ptr, offset, value)
+                }
                 Instr::I32Store8At { address, value } => {
                     self.execute_i32_store8_at(&mut store.inner, address, value)?
                 }
+                Instr::I32Store8AtUnchecked { value, address } => {
+                    self.execute_i32_store8_at_unchecked(address, value)
+                }
                 Instr::I32Store8AtImm { address, value } => {
                     self.execute_i32_store8_at_imm(&mut store.inner, address, value)?
                 }
+                Instr::I32Store8AtImmUnchecked { value, address } => {
+                    self.execute_i32_store8_at_imm_unchecked(address, value)
+                }
                 Instr::I32Store16 { ptr, memory } => {
                     self.execute_i32_store16(&mut store.inner, ptr, memory)?
                 }
@@ -654,24 +824,42 @@ impl<'engine> Executor<'engine> {
                 Instr::I32Store16Offset16 { ptr, offset, value } => {
                     self.execute_i32_store16_offset16(ptr, offset, value)?
                 }
+                Instr::I32Store16Offset16Unchecked { ptr, offset, value } => {
+                    self.execute_i32_store16_offset16_unchecked(ptr, offset, value)
+                }
                 Instr::I32Store16Offset16Imm { ptr, offset, value } => {
                     self.execute_i32_store16_offset16_imm(ptr, offset, value)?
                 }
+                Instr::I32Store16Offset16ImmUnchecked { ptr, offset, value } => {
+                    self.execute_i32_store16_offset16_imm_unchecked(ptr, offset, value)
+                }
                 Instr::I32Store16At { address, value } => {
                     self.execute_i32_store16_at(&mut store.inner, address, value)?
                 }
+                Instr::I32Store16AtUnchecked { value, address } => {
+                    self.execute_i32_store16_at_unchecked(address, value)
+                }
                 Instr::I32Store16AtImm { address, value } => {
                     self.execute_i32_store16_at_imm(&mut store.inner, address, value)?
                 }
+                Instr::I32Store16AtImmUnchecked { value, address } => {
+                    self.execute_i32_store16_at_imm_unchecked(address, value)
+                }
                 Instr::I64StoreImm16 { ptr, memory } => {
                     self.execute_i64_store_imm16(&mut store.inner, ptr, memory)?
                 }
                 Instr::I64StoreOffset16Imm16 { ptr, offset, value } => {
                     self.execute_i64_store_offset16_imm16(ptr, offset, value)?
                 }
+                Instr::I64StoreOffset16Imm16Unchecked { ptr, offset, value } => {
+                    self.execute_i64_store_offset16_imm16_unchecked(ptr, offset, value)
+                }
                 Instr::I64StoreAtImm16 { address, value } => {
                     self.execute_i64_store_at_imm16(&mut store.inner, address, value)?
                 }
+                Instr::I64StoreAtImm16Unchecked { value, address } => {
+                    self.execute_i64_store_at_imm16_unchecked(address, value)
+                }
                 Instr::I64Store8 { ptr, memory } => {
                     self.execute_i64_store8(&mut store.inner, ptr, memory)?
                 }
@@ -681,15 +869,27 @@ impl<'engine> Executor<'engine> {
                 Instr::I64Store8Offset16 { ptr, offset, value } => {
                     self.execute_i64_store8_offset16(ptr, offset, value)?
                 }
+                Instr::I64Store8Offset16Unchecked { ptr, offset, value } => {
+                    self.execute_i64_store8_offset16_unchecked(ptr, offset, value)
+                }
                 Instr::I64Store8Offset16Imm { ptr, offset, value } => {
                     self.execute_i64_store8_offset16_imm(ptr, offset, value)?
                 }
+                Instr::I64Store8Offset16ImmUnchecked { ptr, offset, value } => {
+                    self.execute_i64_store8_offset16_imm_unchecked(ptr, offset, value)
+                }
                 Instr::I64Store8At { address, value } => {
                     self.execute_i64_store8_at(&mut store.inner, address, value)?
                 }
+                Instr::I64Store8AtUnchecked { value, address } => {
+                    self.execute_i64_store8_at_unchecked(address, value)
+                }
                 Instr::I64Store8AtImm { address, value } => {
                     self.execute_i64_store8_at_imm(&mut store.inner, address, value)?
                 }
+                Instr::I64Store8AtImmUnchecked { value, address } => {
+                    self.execute_i64_store8_at_imm_unchecked(address, value)
+                }
                 Instr::I64Store16 { ptr, memory } => {
                     self.execute_i64_store16(&mut store.inner, ptr, memory)?
                 }
@@ -699,15 +899,27 @@ impl<'engine> Executor<'engine> {
                 Instr::I64Store16Offset16 { ptr, offset, value } => {
                     self.execute_i64_store16_offset16(ptr, offset, value)?
                 }
+                Instr::I64Store16Offset16Unchecked { ptr, offset, value } => {
+                    self.execute_i64_store16_offset16_unchecked(ptr, offset, value)
+                }
                 Instr::I64Store16Offset16Imm { ptr, offset, value } => {
                     self.execute_i64_store16_offset16_imm(ptr, offset, value)?
                 }
+                Instr::I64Store16Offset16ImmUnchecked { ptr, offset, value } => {
+                    self.execute_i64_store16_offset16_imm_unchecked(ptr, offset, value)
+                }
                 Instr::I64Store16At { address, value } => {
                     self.execute_i64_store16_at(&mut store.inner, address, value)?
                 }
+                Instr::I64Store16AtUnchecked { value, address } => {
+                    self.execute_i64_store16_at_unchecked(address, value)
+                }
                 Instr::I64Store16AtImm { address, value } => {
                     self.execute_i64_store16_at_imm(&mut store.inner, address, value)?
                 }
+                Instr::I64Store16AtImmUnchecked { value, address } => {
+                    self.execute_i64_store16_at_imm_unchecked(address, value)
+                }
                 Instr::I64Store32 { ptr, memory } => {
                     self.execute_i64_store32(&mut store.inner, ptr, memory)?
                 }
@@ -717,15 +929,27 @@ impl<'engine> Executor<'engine> {
                 Instr::I64Store32Offset16 { ptr, offset, value } => {
                     self.execute_i64_store32_offset16(ptr, offset, value)?
                 }
+                Instr::I64Store32Offset16Unchecked { ptr, offset, value } => {
+                    self.execute_i64_store32_offset16_unchecked(ptr, offset, value)
+                }
                 Instr::I64Store32Offset16Imm16 { ptr, offset, value } => {
                     self.execute_i64_store32_offset16_imm16(ptr, offset, value)?
                 }
+                Instr::I64Store32Offset16Imm16Unchecked { ptr, offset, value } => {
+                    self.execute_i64_store32_offset16_imm16_unchecked(ptr, offset, value)
+                }
                 Instr::I64Store32At { address, value } => {
                     self.execute_i64_store32_at(&mut store.inner, address, value)?
                 }
+                Instr::I64Store32AtUnchecked { value, address } => {
+                    self.execute_i64_store32_at_unchecked(address, value)
+                }
                 Instr::I64Store32AtImm16 { address, value } => {
                     self.execute_i64_store32_at_imm16(&mut store.inner, address, value)?
                 }
+                Instr::I64Store32AtImm16Unchecked { value, address } => {
+                    self.execute_i64_store32_at_imm16_unchecked(address, value)
+                }
                 Instr::I32Eq { result, lhs, rhs } => self.execute_i32_eq(result, lhs, rhs),
                 Instr::I32EqImm16 { result, lhs, rhs } => {
                     self.execute_i32_eq_imm16(result, lhs, rhs)
@@ -917,6 +1141,14 @@ impl<'engine> Executor<'engine> {
                 Instr::I64MulImm16 { result, lhs, rhs } => {
                     self.execute_i64_mul_imm16(result, lhs, rhs)
                 }
+                Instr::I64Add128 { results, lhs } => self.execute_i64_add128(results, lhs),
+                Instr::I64Sub128 { results, lhs } => self.execute_i64_sub128(results, lhs),
+                Instr::I64MulWideS { results, lhs, rhs } => {
+                    self.execute_i64_mul_wide_s(results, lhs, rhs)
+                }
+                Instr::I64MulWideU { results, lhs, rhs } => {
+                    self.execute_i64_mul_wide_u(results, lhs, rhs)
+                }
                 Instr::I64DivS { result, lhs, rhs } => self.execute_i64_div_s(result, lhs, rhs)?,
                 Instr::I64DivSImm16Rhs { result, lhs, rhs } => {
                     self.execute_i64_div_s_imm16_rhs(result, lhs, rhs)?
@@ -1274,6 +1506,166 @@ impl<'engine> Executor<'engine> {
                 Instr::MemoryInitFromToExact { dst, src, len } => {
                     self.execute_memory_init_from_to_exact(&mut store.inner, dst, src, len)?
                 }
+                Instr::StructNew { result, ty } => {
+                    self.execute_struct_new(&mut store.inner, result, ty)
+                }
+                Instr::StructNewDefault { result, ty } => {
+                    self.execute_struct_new_default(&mut store.inner, result, ty)
+                }
+                Instr::StructGet {
+                    result,
+                    struct_ref,
+                    field,
+                } => self.execute_struct_get(
+                    &store.inner,
+                    result,
+                    struct_ref,
+                    field,
+                    gc::unpack_none,
+                )?,
+                Instr::StructGetI8S {
+                    result,
+                    struct_ref,
+                    field,
+                } => self.execute_struct_get(
+                    &store.inner,
+                    result,
+                    struct_ref,
+                    field,
+                    gc::unpack_i8_s,
+                )?,
+                Instr::StructGetI8U {
+                    result,
+                    struct_ref,
+                    field,
+                } => self.execute_struct_get(
+                    &store.inner,
+                    result,
+                    struct_ref,
+                    field,
+                    gc::unpack_i8_u,
+                )?,
+                Instr::StructGetI16S {
+                    result,
+                    struct_ref,
+                    field,
+                } => self.execute_struct_get(
+                    &store.inner,
+                    result,
+                    struct_ref,
+                    field,
+                    gc::unpack_i16_s,
+                )?,
+                Instr::StructGetI16U {
+                    result,
+                    struct_ref,
+                    field,
+                } => self.execute_struct_get(
+                    &store.inner,
+                    result,
+                    struct_ref,
+                    field,
+                    gc::unpack_i16_u,
+                )?,
+                Instr::StructSet {
+                    struct_ref,
+                    field,
+                    value,
+                } => self.execute_struct_set(&mut store.inner, struct_ref, field, value)?,
+                Instr::ArrayNew { result, ty } => {
+                    self.execute_array_new(&mut store.inner, result, ty)?
+                }
+                Instr::ArrayNewDefault { result, ty } => {
+                    self.execute_array_new_default(&mut store.inner, result, ty)?
+                }
+                Instr::ArrayNewFixed { result, ty } => {
+                    self.execute_array_new_fixed(&mut store.inner, result, ty)
+                }
+                Instr::ArrayNewData { result, ty } => {
+                    self.execute_array_new_data(&mut store.inner, result, ty)?
+                }
+                Instr::ArrayNewElem { result, ty } => {
+                    self.execute_array_new_elem(&mut store.inner, result, ty)?
+                }
+                Instr::ArrayGet {
+                    result,
+                    array,
+                    index,
+                } => self.execute_array_get(&store.inner, result, array, index, gc::unpack_none)?,
+                Instr::ArrayGetI8S {
+                    result,
+                    array,
+                    index,
+                } => self.execute_array_get(&store.inner, result, array, index, gc::unpack_i8_s)?,
+                Instr::ArrayGetI8U {
+                    result,
+                    array,
+                    index,
+                } => self.execute_array_get(&store.inner, result, array, index, gc::unpack_i8_u)?,
+                Instr::ArrayGetI16S {
+                    result,
+                    array,
+                    index,
+                } => {
+                    self.execute_array_get(&store.inner, result, array, index, gc::unpack_i16_s)?
+                }
+                Instr::ArrayGetI16U {
+                    result,
+                    array,
+                    index,
+                } => {
+                    self.execute_array_get(&store.inner, result, array, index, gc::unpack_i16_u)?
+                }
+                Instr::ArraySet {
+                    array,
+                    index,
+                    value,
+                } => self.execute_array_set(&mut store.inner, array, index, value)?,
+                Instr::ArrayLen { result, array } => {
+                    self.execute_array_len(&store.inner, result, array)?
+                }
+                Instr::ArrayFill {
+                    array,
+                    offset,
+                    value,
+                } => self.execute_array_fill(&mut store.inner, array, offset, value)?,
+                Instr::ArrayCopy {
+                    dst,
+                    dst_offset,
+                    src,
+                } => self.execute_array_copy(&mut store.inner, dst, dst_offset, src)?,
+                Instr::ArrayInitData { array, dst, src } => {
+                    self.execute_array_init_data(&mut store.inner, array, dst, src)?
+                }
+                Instr::ArrayInitElem { array, dst, src } => {
+                    self.execute_array_init_elem(&mut store.inner, array, dst, src)?
+                }
+                Instr::RefI31 { result, input } => self.execute_ref_i31(result, input),
+                Instr::I31GetS { result, input } => self.execute_i31_get_s(result, input)?,
+                Instr::I31GetU { result, input } => self.execute_i31_get_u(result, input)?,
+                Instr::RefTest { result, input } => {
+                    self.execute_ref_test(&store.inner, result, input)
+                }
+                Instr::RefCast { result, input } => {
+                    self.execute_ref_cast(&store.inner, result, input)?
+                }
+                Instr::AnyConvertExtern { result, input } => {
+                    self.execute_any_convert_extern(&mut store.inner, result, input)
+                }
+                Instr::ExternConvertAny { result, input } => {
+                    self.execute_extern_convert_any(&mut store.inner, result, input)
+                }
+                Instr::ContNew { result, func } => {
+                    self.execute_cont_new(&mut store.inner, result, func)?
+                }
+                Instr::ContBind { result, cont } => {
+                    self.execute_cont_bind(&mut store.inner, result, cont)?
+                }
+                Instr::Resume { cont, .. } => self.execute_resume(store, cont)?,
+                Instr::Suspend { tag, .. } => {
+                    forward_return!(self.execute_suspend(&mut store.inner, tag)?)
+                }
+                Instr::Switch { cont, tag } => self.execute_switch(store, cont, tag)?,
                 Instr::TableIndex { .. }
                 | Instr::MemoryIndex { .. }
                 | Instr::DataIndex { .. }
@@ -1291,7 +1683,11 @@ impl<'engine> Executor<'engine> {
                 | Instr::RegisterSpan { .. }
                 | Instr::RegisterList { .. }
                 | Instr::CallIndirectParams { .. }
-                | Instr::CallIndirectParamsImm16 { .. } => self.invalid_instruction_word()?,
+                | Instr::CallIndirectParamsImm16 { .. }
+                | Instr::ResumeParams { .. }
+                | Instr::ResumeHandler { .. }
+                | Instr::SwitchHandler { .. }
+                | Instr::SwitchParams { .. } => self.invalid_instruction_word()?,
             }
         }
     }
@@ -0,0 +1,144 @@
+use super::Executor;
+use crate::{
+    backtrace::{FrameInfo, WasmBacktrace},
+    coredump::WasmCoreDump,
+    engine::UntypedProvider,
+    store::StoreInner,
+    value::WithType,
+};
+use alloc::vec::Vec;
+
+#[cfg(doc)]
+use crate::engine::executor::stack::CallFrame;
+
+impl Executor<'_> {
+    /// Points the instruction pointer to the call instruction of the top-most [`CallFrame`].
+    ///
+    /// # Note
+    ///
+    /// This is used to properly locate errors that occur during dispatch of a
+    /// function call in Wasm backtraces since the instruction pointer might
+    /// already have been advanced at this point.
+    #[cold]
+    pub(super) fn rewind_to_call_site(&mut self) {
+        if let Some(caller) = self.stack.calls.peek() {
+            self.ip = caller.instr_ptr();
+            self.ip.offset(-1);
+        }
+    }
+
+    /// Captures a [`WasmBacktrace`] of the current Wasm call stack.
+    ///
+    /// # Note
+    ///
+    /// - The top-most frame is located via the current instruction pointer.
+    /// - All other frames are located via the call instruction they are currently executing.
+    /// - Frames of inlined functions precede the frame of the function they are inlined into.
+    #[cold]
+    pub(super) fn capture_backtrace(&self, store: &StoreInner) -> WasmBacktrace {
+        let mut top_ip = Some(self.ip);
+        let mut frames = Vec::new();
+        for (frame, instance) in self.stack.calls.frames_with_instances() {
+            let ip = top_ip.take().unwrap_or_else(|| {
+                let mut ip = frame.instr_ptr();
+                ip.offset(-1);
+                ip
+            });
+            let Some(module) = store.resolve_instance(instance).module() else {
+                continue;
+            };
+            for location in self.code_map.wasm_locations(frame.func(), ip.get()) {
+                let Some(func_index) = module.get_func_index(location.func) else {
+                    continue;
+                };
+                let func_name = module.get_func_name(func_index);
+                let symbols = location
+                    .offset
+                    .map(|offset| module.get_frame_symbols(offset))
+                    .unwrap_or_default();
+                frames.push(FrameInfo::new(
+                    *instance,
+                    func_index,
+                    func_name,
+                    location.offset,
+                    symbols,
+                ));
+            }
+        }
+        WasmBacktrace::new(frames)
+    }
+
+    /// Captures a [`WasmCoreDump`] of the current Wasm call stack.
+    ///
+    /// # Note
+    ///
+    /// Frames are located the same way as for [`Executor::capture_backtrace`].
+    #[cold]
+    pub(super) fn capture_coredump(&self, store: &StoreInner) -> WasmCoreDump {
+        let mut coredump = WasmCoreDump::default();
+        let mut top_ip = Some(self.ip);
+        let values = self.stack.values.as_slice();
+        for (frame, instance) in self.stack.calls.frames_with_instances() {
+            let ip = top_ip.take().unwrap_or_else(|| {
+                let mut ip = frame.instr_ptr();
+                ip.offset(-1);
+                ip
+            });
+            let Some(module) = store.resolve_instance(instance).module() else {
+                continue;
+            };
+            for location in self.code_map.wasm_locations(frame.func(), ip.get()) {
+                let Some(func_index) = module.get_func_index(location.func) else {
+                    continue;
+                };
+                let Some((func_offset, local_types)) = self.code_map.func_locals(location.func)
+                else {
+                    continue;
+                };
+                let code_offset = location
+                    .offset
+                    .and_then(|offset| offset.checked_sub(func_offset))
+                    .unwrap_or_default();
+                let code_offset = u32::try_from(code_offset)
+                    .unwrap_or_else(|_| panic!("Wasm code offset is out of bounds: {code_offset}"));
+                // Note: the parameters and locals of a function are stored in its first
+                //       registers in order which for inlined functions start at their frame.
+                let Some(base) = usize::from(frame.base_offset())
+                    .checked_add_signed(isize::from(location.frame))
+                else {
+                    continue;
+                };
+                // Note: values that cannot be captured are kept as `None` so that
+                //       the indices of all other values stay intact.
+                let locals = local_types
+                    .iter()
+                    .enumerate()
+                    .map(|(index, ty)| {
+                        let value = values.get(base.checked_add(index)?)?;
+                        Some(value.with_type(*ty))
+                    })
+                    .collect();
+                // Note: operand stack values are either stored in the registers
+                //       of the function or are constant values.
+                let stack = location
+                    .offset
+                    .and_then(|offset| self.code_map.operand_stack(location.func, offset))
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|&(ty, provider)| {
+                        let value = match provider {
+                            UntypedProvider::Register(reg) => {
+                                let index = base.checked_add_signed(isize::from(i16::from(reg)))?;
+                                *values.get(index)?
+                            }
+                            UntypedProvider::Const(value) => value,
+                        };
+                        Some(value.with_type(ty))
+                    })
+                    .collect();
+                coredump.push_frame(store, *instance, func_index, code_offset, locals, stack);
+            }
+        }
+        coredump
+    }
+}
@@ -1,14 +1,12 @@
-use super::{Executor, UntypedValueExt};
+use super::{Executor, InstructionPtr, UntypedValueExt};
 use crate::{
     core::{TrapCode, UntypedVal},
-    ir::{Const16, Reg, ShiftAmount, Sign},
+    engine::utils::unreachable_unchecked,
+    ir::{Const16, FixedRegSpan, Instruction, Reg, ShiftAmount, Sign},
     Error,
 };
 use core::num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64};
 
-#[cfg(doc)]
-use crate::ir::Instruction;
-
 macro_rules! impl_binary {
     ( $( (Instruction::$var_name:ident, $fn_name:ident, $op:expr) ),* $(,)? ) => {
         $(
@@ -325,3 +323,82 @@ impl Executor<'_> {
         self.next_instr()
     }
 }
+
+impl Executor<'_> {
+    /// Fetches the [`Reg`]s of the [`Instruction::Register2`] parameter.
+    fn fetch_wide_rhs(&self) -> [Reg; 2] {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(1);
+        match *addr.get() {
+            Instruction::Register2 { regs } => regs,
+            unexpected => {
+                // Safety: Wasmi translation guarantees that [`Instruction::Register2`] exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected `Instruction::Register2` but found {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    /// Stores the `[lo, hi]` `values` into the `results` register pair.
+    ///
+    /// # Note
+    ///
+    /// All inputs must have been read before since `results` may overlap with them.
+    fn set_register_pair(&mut self, results: FixedRegSpan<2>, values: [UntypedVal; 2]) {
+        let result_lo = results.span().head();
+        let result_hi = result_lo.next();
+        self.set_register(result_lo, values[0]);
+        self.set_register(result_hi, values[1]);
+    }
+
+    /// Executes a 128-bit binary instruction of the Wasm `wide-arithmetic` proposal.
+    fn execute_binary128(
+        &mut self,
+        results: FixedRegSpan<2>,
+        lhs: [Reg; 2],
+        op: fn([UntypedVal; 2], [UntypedVal; 2]) -> [UntypedVal; 2],
+    ) {
+        let rhs = self.fetch_wide_rhs();
+        let lhs = lhs.map(|reg| self.get_register(reg));
+        let rhs = rhs.map(|reg| self.get_register(reg));
+        self.set_register_pair(results, op(lhs, rhs));
+        self.next_instr_at(2)
+    }
+
+    /// Executes a widening multiplication instruction of the Wasm `wide-arithmetic` proposal.
+    fn execute_mul_wide(
+        &mut self,
+        results: FixedRegSpan<2>,
+        lhs: Reg,
+        rhs: Reg,
+        op: fn(UntypedVal, UntypedVal) -> [UntypedVal; 2],
+    ) {
+        let lhs = self.get_register(lhs);
+        let rhs = self.get_register(rhs);
+        self.set_register_pair(results, op(lhs, rhs));
+        self.next_instr()
+    }
+
+    /// Executes an [`Instruction::I64Add128`].
+    pub fn execute_i64_add128(&mut self, results: FixedRegSpan<2>, lhs: [Reg; 2]) {
+        self.execute_binary128(results, lhs, UntypedVal::i64_add128)
+    }
+
+    /// Executes an [`Instruction::I64Sub128`].
+    pub fn execute_i64_sub128(&mut self, results: FixedRegSpan<2>, lhs: [Reg; 2]) {
+        self.execute_binary128(results, lhs, UntypedVal::i64_sub128)
+    }
+
+    /// Executes an [`Instruction::I64MulWideS`].
+    pub fn execute_i64_mul_wide_s(&mut self, results: FixedRegSpan<2>, lhs: Reg, rhs: Reg) {
+        self.execute_mul_wide(results, lhs, rhs, UntypedVal::i64_mul_wide_s)
+    }
+
+    /// Executes an [`Instruction::I64MulWideU`].
+    pub fn execute_i64_mul_wide_u(&mut self, results: FixedRegSpan<2>, lhs: Reg, rhs: Reg) {
+        self.execute_mul_wide(results, lhs, rhs, UntypedVal::i64_mul_wide_u)
+    }
+}
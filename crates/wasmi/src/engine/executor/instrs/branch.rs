@@ -12,7 +12,7 @@ use crate::{
         Reg,
     },
 };
-use core::cmp;
+use core::{cmp, mem};
 
 impl Executor<'_> {
     /// Branches and adjusts the value stack.
@@ -21,7 +21,9 @@ impl Executor<'_> {
     ///
     /// Offsets the instruction pointer using the given [`BranchOffset`].
     fn branch_to(&mut self, offset: BranchOffset) {
-        self.ip.offset(offset.to_i32() as isize)
+        let offset = offset.to_i32();
+        self.count_back_edge(offset < 0);
+        self.ip.offset(offset as isize)
     }
 
     /// Branches and adjusts the value stack.
@@ -30,7 +32,42 @@ impl Executor<'_> {
     ///
     /// Offsets the instruction pointer using the given [`BranchOffset`].
     fn branch_to16(&mut self, offset: BranchOffset16) {
-        self.ip.offset(offset.to_i16() as isize)
+        let offset = offset.to_i16();
+        self.count_back_edge(offset < 0);
+        self.ip.offset(offset as isize)
+    }
+
+    /// Counts a backwards branch of the currently executed function frame if `is_back_edge`.
+    ///
+    /// # Note
+    ///
+    /// This is a no-op unless tiered translation is enabled.
+    #[inline(always)]
+    fn count_back_edge(&mut self, is_back_edge: bool) {
+        if !self.tiered {
+            return;
+        }
+        self.back_edges = self.back_edges.wrapping_add(u32::from(is_back_edge));
+    }
+
+    /// Accounts the backwards branches taken so far to the currently executed function.
+    ///
+    /// # Note
+    ///
+    /// - This must be called before the currently executed function frame calls or returns.
+    /// - This is a no-op unless tiered translation is enabled.
+    #[inline]
+    pub(super) fn flush_back_edges(&mut self) {
+        if !self.tiered || self.back_edges == 0 {
+            return;
+        }
+        let back_edges = mem::take(&mut self.back_edges);
+        let frame = self
+            .stack
+            .calls
+            .peek()
+            .expect("the executing call frame is always on the stack");
+        self.code_map.add_back_edges(frame.func(), back_edges);
     }
 
     pub fn execute_branch(&mut self, offset: BranchOffset) {
@@ -1,9 +1,8 @@
 use super::{Executor, InstructionPtr};
 use crate::{
-    core::TrapCode,
     engine::{
         code_map::CompiledFuncRef,
-        executor::stack::{CallFrame, FrameParams, ValueStack},
+        executor::stack::{CallFrame, FrameParams, Stack},
         utils::unreachable_unchecked,
         EngineFunc,
         FuncParams,
@@ -24,18 +23,26 @@ use core::{array, fmt};
 ///
 /// Returns the number of parameters and results of the called host function.
 ///
+/// # Note
+///
+/// The objects referenced by the values of the `stack` are roots of the garbage
+/// collector during the host function call since they cannot be traced by nested
+/// Wasm executions of the host function.
+///
 /// # Errors
 ///
 /// Returns the error of the host function if an error occurred.
 pub fn dispatch_host_func<T>(
     store: &mut Store<T>,
-    value_stack: &mut ValueStack,
+    stack: &mut Stack,
     host_func: HostFuncEntity,
     instance: Option<&Instance>,
 ) -> Result<(u16, u16), Error> {
     let len_params = host_func.len_params();
     let len_results = host_func.len_results();
     let max_inout = len_params.max(len_results);
+    let scope = store.inner.enter_host_scope(stack.values());
+    let value_stack = &mut stack.values;
     let values = value_stack.as_slice_mut();
     let params_results = FuncParams::new(
         values.split_at_mut(values.len() - usize::from(max_inout)).1,
@@ -43,16 +50,16 @@ pub fn dispatch_host_func<T>(
         usize::from(len_results),
     );
     let trampoline = store.resolve_trampoline(host_func.trampoline()).clone();
-    trampoline
-        .call(store, instance, params_results)
-        .inspect_err(|_error| {
-            // Note: We drop the values that have been temporarily added to
-            //       the stack to act as parameter and result buffer for the
-            //       called host function. Since the host function failed we
-            //       need to clean up the temporary buffer values here.
-            //       This is required for resumable calls to work properly.
-            value_stack.drop(usize::from(max_inout));
-        })?;
+    let result = trampoline.call(&mut *store, instance, params_results);
+    store.inner.exit_host_scope(scope);
+    result.inspect_err(|_error| {
+        // Note: We drop the values that have been temporarily added to
+        //       the stack to act as parameter and result buffer for the
+        //       called host function. Since the host function failed we
+        //       need to clean up the temporary buffer values here.
+        //       This is required for resumable calls to work properly.
+        value_stack.drop(usize::from(max_inout));
+    })?;
     Ok((len_params, len_results))
 }
 
@@ -101,6 +108,11 @@ impl ResumableHostError {
         self.host_error
     }
 
+    /// Returns an exclusive reference to the underlying [`Error`].
+    pub(crate) fn host_error_mut(&mut self) -> &mut Error {
+        &mut self.host_error
+    }
+
     /// Returns the [`Func`] of the [`ResumableHostError`].
     pub(crate) fn host_func(&self) -> &Func {
         &self.host_func
@@ -224,11 +236,32 @@ impl Executor<'_> {
         }
     }
 
+    /// Fetches the [`Instruction::Register`] holding the called function reference of a `call_ref`.
+    ///
+    /// # Note
+    ///
+    /// This advances the [`InstructionPtr`] to the next [`Instruction`].
+    fn pull_call_ref_params(&mut self) -> FuncRef {
+        self.ip.add(1);
+        match *self.ip.get() {
+            Instruction::Register { reg } => FuncRef::from(self.get_register(reg)),
+            unexpected => {
+                // Safety: Wasmi translation guarantees that correct instruction parameter follows.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected `Instruction::Register` but found {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
     /// Creates a [`CallFrame`] for calling the [`EngineFunc`].
     fn dispatch_compiled_func<C: CallContext>(
         &mut self,
         results: RegSpan,
-        func: CompiledFuncRef,
+        func: EngineFunc,
+        compiled_func: CompiledFuncRef,
     ) -> Result<CallFrame, Error> {
         // We have to reinstantiate the `self.sp` [`FrameRegisters`] since we just called
         // [`ValueStack::alloc_call_frame`] which might invalidate all live [`FrameRegisters`].
@@ -237,12 +270,13 @@ impl Executor<'_> {
             .calls
             .peek()
             .expect("need to have a caller on the call stack");
-        let (mut uninit_params, offsets) = self.stack.values.alloc_call_frame(func, |this| {
-            // Safety: We use the base offset of a live call frame on the call stack.
-            self.sp = unsafe { this.stack_ptr_at(caller.base_offset()) };
-        })?;
-        let instr_ptr = InstructionPtr::new(func.instrs().as_ptr());
-        let frame = CallFrame::new(instr_ptr, offsets, results);
+        let (mut uninit_params, offsets) =
+            self.stack.values.alloc_call_frame(compiled_func, |this| {
+                // Safety: We use the base offset of a live call frame on the call stack.
+                self.sp = unsafe { this.stack_ptr_at(caller.base_offset()) };
+            })?;
+        let instr_ptr = InstructionPtr::new(compiled_func.instrs().as_ptr());
+        let frame = CallFrame::new(func, instr_ptr, offsets, results);
         if <C as CallContext>::HAS_PARAMS {
             self.copy_call_params(&mut uninit_params);
         }
@@ -313,8 +347,9 @@ impl Executor<'_> {
         func: EngineFunc,
         mut instance: Option<Instance>,
     ) -> Result<(), Error> {
-        let func = self.code_map.get(Some(store.fuel_mut()), func)?;
-        let mut called = self.dispatch_compiled_func::<C>(results, func)?;
+        self.flush_back_edges();
+        let compiled_func = self.code_map.get(Some(store.fuel_mut()), func)?;
+        let mut called = self.dispatch_compiled_func::<C>(results, func, compiled_func)?;
         match <C as CallContext>::KIND {
             CallKind::Nested => {
                 // We need to update the instruction pointer of the caller call frame.
@@ -338,7 +373,10 @@ impl Executor<'_> {
             }
         }
         self.init_call_frame(&called);
-        self.stack.calls.push(called, instance)?;
+        if let Err(error) = self.stack.calls.push(called, instance) {
+            self.rewind_to_call_site();
+            return Err(error.into());
+        }
         Ok(())
     }
 
@@ -510,13 +548,20 @@ impl Executor<'_> {
         let len_results = host_func.len_results();
         let max_inout = usize::from(len_params.max(len_results));
         let instance = *self.stack.calls.instance_expect();
+        if let CallKind::Tail = <C as CallContext>::KIND {
+            // Note: the caller frame is removed by the tail call.
+            self.flush_back_edges();
+        }
         // We have to reinstantiate the `self.sp` [`FrameRegisters`] since we just called
         // [`ValueStack::reserve`] which might invalidate all live [`FrameRegisters`].
-        let caller = match <C as CallContext>::KIND {
-            CallKind::Nested => self.stack.calls.peek().copied(),
-            CallKind::Tail => self.stack.calls.pop().map(|(frame, _instance)| frame),
-        }
-        .expect("need to have a caller on the call stack");
+        //
+        // Note: for tail calls the caller frame is only popped once the host function
+        //       fuel has been charged so that the stack is intact if that traps.
+        let caller = *self
+            .stack
+            .calls
+            .peek()
+            .expect("need to have a caller on the call stack");
         let buffer = self.stack.values.extend_by(max_inout, |this| {
             // Safety: we use the base offset of a live call frame on the call stack.
             self.sp = unsafe { this.stack_ptr_at(caller.base_offset()) };
@@ -525,13 +570,27 @@ impl Executor<'_> {
             let mut uninit_params = FrameParams::new(buffer);
             self.copy_call_params(&mut uninit_params);
         }
+        let values = self.stack.values.as_slice();
+        let params = &values[values.len() - max_inout..][..usize::from(len_params)];
+        if let Err(error) = store.consume_host_func_fuel(host_func.trampoline(), params) {
+            // Note: we drop the parameter and result buffer of the host function
+            //       since it is never called.
+            self.stack.values.drop(max_inout);
+            return Err(error.into());
+        }
+        if let CallKind::Tail = <C as CallContext>::KIND {
+            self.stack.calls.pop();
+        }
         if matches!(<C as CallContext>::KIND, CallKind::Nested) {
             self.update_instr_ptr_at(1);
         }
         self.dispatch_host_func::<T>(store, host_func, &instance)
-            .map_err(|error| match self.stack.calls.is_empty() {
-                true => error,
-                false => ResumableHostError::new(error, *func, results).into(),
+            .map_err(|error| {
+                self.rewind_to_call_site();
+                match self.stack.calls.is_empty() {
+                    true => error,
+                    false => ResumableHostError::new(error, *func, results).into(),
+                }
             })?;
         self.cache.update(&mut store.inner, &instance);
         let results = results.iter(len_results);
@@ -557,7 +616,7 @@ impl Executor<'_> {
         host_func: HostFuncEntity,
         instance: &Instance,
     ) -> Result<(u16, u16), Error> {
-        dispatch_host_func(store, &mut self.stack.values, host_func, Some(instance))
+        dispatch_host_func(store, self.stack, host_func, Some(instance))
     }
 
     /// Executes an [`Instruction::CallIndirect0`].
@@ -674,18 +733,70 @@ impl Executor<'_> {
         table: index::Table,
     ) -> Result<(), Error> {
         let table = self.get_table(table);
-        let funcref = store
-            .inner
-            .resolve_table(&table)
-            .get_untyped(index)
-            .map(FuncRef::from)
-            .ok_or(TrapCode::TableOutOfBounds)?;
-        let func = funcref.func().ok_or(TrapCode::IndirectCallToNull)?;
-        let actual_signature = store.inner.resolve_func(func).ty_dedup();
-        let expected_signature = &self.get_func_type_dedup(func_type);
-        if actual_signature != expected_signature {
-            return Err(Error::from(TrapCode::BadSignature));
-        }
-        self.execute_call_imported_impl::<C, T>(store, results, func)
+        let expected_signature = self.get_func_type_dedup(func_type);
+        // Note: `self.ip` points to the `CallIndirectParams` of the call site at this point.
+        let call_site = self.ip.addr();
+        let func =
+            store
+                .inner
+                .resolve_indirect_call(call_site, &table, index, &expected_signature)?;
+        self.execute_call_imported_impl::<C, T>(store, results, &func)
+    }
+
+    /// Executes an [`Instruction::ReturnCallRef0`].
+    pub fn execute_return_call_ref_0<T>(
+        &mut self,
+        store: &mut Store<T>,
+        func_type: index::FuncType,
+    ) -> Result<(), Error> {
+        let funcref = self.pull_call_ref_params();
+        let results = self.caller_results();
+        self.execute_call_ref_impl::<marker::ReturnCall0, T>(store, results, func_type, funcref)
+    }
+
+    /// Executes an [`Instruction::ReturnCallRef`].
+    pub fn execute_return_call_ref<T>(
+        &mut self,
+        store: &mut Store<T>,
+        func_type: index::FuncType,
+    ) -> Result<(), Error> {
+        let funcref = self.pull_call_ref_params();
+        let results = self.caller_results();
+        self.execute_call_ref_impl::<marker::ReturnCall, T>(store, results, func_type, funcref)
+    }
+
+    /// Executes an [`Instruction::CallRef0`].
+    pub fn execute_call_ref_0<T>(
+        &mut self,
+        store: &mut Store<T>,
+        results: RegSpan,
+        func_type: index::FuncType,
+    ) -> Result<(), Error> {
+        let funcref = self.pull_call_ref_params();
+        self.execute_call_ref_impl::<marker::NestedCall0, T>(store, results, func_type, funcref)
+    }
+
+    /// Executes an [`Instruction::CallRef`].
+    pub fn execute_call_ref<T>(
+        &mut self,
+        store: &mut Store<T>,
+        results: RegSpan,
+        func_type: index::FuncType,
+    ) -> Result<(), Error> {
+        let funcref = self.pull_call_ref_params();
+        self.execute_call_ref_impl::<marker::NestedCall, T>(store, results, func_type, funcref)
+    }
+
+    /// Executes an [`Instruction::CallRef`] and [`Instruction::CallRef0`].
+    fn execute_call_ref_impl<C: CallContext, T>(
+        &mut self,
+        store: &mut Store<T>,
+        results: RegSpan,
+        func_type: index::FuncType,
+        funcref: FuncRef,
+    ) -> Result<(), Error> {
+        let expected_signature = self.get_func_type_dedup(func_type);
+        let func = store.inner.resolve_ref_call(funcref, &expected_signature)?;
+        self.execute_call_imported_impl::<C, T>(store, results, &func)
     }
 }
@@ -0,0 +1,515 @@
+use super::{call::ResumableHostError, Executor, InstructionPtr};
+use crate::{
+    cont::{ContEntity, ContRef},
+    core::{TrapCode, UntypedVal},
+    engine::{
+        executor::{do_nothing, instrs::call::dispatch_host_func, stack::CallFrame},
+        utils::unreachable_unchecked,
+    },
+    func::{FuncEntity, HostFuncEntity},
+    ir::{index, BoundedRegSpan, Instruction, Reg, RegSpan},
+    store::StoreInner,
+    CallHook,
+    Error,
+    Func,
+    FuncRef,
+    Store,
+};
+use alloc::vec::Vec;
+
+/// Tells if execution loop shall continue or break (return) to the execution's caller.
+type ControlFlow = ::core::ops::ControlFlow<(), ()>;
+
+/// The kind of a handler of an [`Instruction::Resume`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum HandlerKind {
+    /// A handler for `suspend` encoded by [`Instruction::ResumeHandler`].
+    Suspend,
+    /// A handler for `switch` encoded by [`Instruction::SwitchHandler`].
+    Switch,
+}
+
+/// Consumes the continuation referred to by `contref`.
+///
+/// # Errors
+///
+/// - If `contref` is `null`.
+/// - If the continuation of `contref` has already been consumed.
+fn take_cont(store: &mut StoreInner, contref: ContRef) -> Result<ContEntity, Error> {
+    if contref.is_null() {
+        return Err(Error::from(TrapCode::NullReference));
+    }
+    store
+        .take_cont(contref)
+        .ok_or_else(|| Error::from(TrapCode::ContinuationAlreadyConsumed))
+}
+
+/// Returns the parameters of the [`Instruction::ResumeParams`] following the [`Instruction::Resume`] at `ip`.
+fn fetch_resume_params(mut ip: InstructionPtr) -> (BoundedRegSpan, u16) {
+    ip.add(1);
+    match *ip.get() {
+        Instruction::ResumeParams { args, len_handlers } => (args, len_handlers),
+        unexpected => {
+            // Safety: Wasmi translation guarantees that `Instruction::ResumeParams` exists.
+            unsafe {
+                unreachable_unchecked!(
+                    "expected `Instruction::ResumeParams` but found: {unexpected:?}"
+                )
+            }
+        }
+    }
+}
+
+/// Returns the result registers of the [`Instruction::Resume`] at `ip`.
+fn fetch_resume_results(ip: InstructionPtr) -> BoundedRegSpan {
+    match *ip.get() {
+        Instruction::Resume { results, .. } => results,
+        unexpected => {
+            // Safety: Wasmi translation guarantees that suspended frames are at `Instruction::Resume`.
+            unsafe {
+                unreachable_unchecked!("expected `Instruction::Resume` but found: {unexpected:?}")
+            }
+        }
+    }
+}
+
+/// Returns the [`InstructionPtr`] to the handler of the [`Instruction::Resume`] at `ip` if any.
+///
+/// The handler must be of the given `kind` and handle `tag`.
+fn find_resume_handler(
+    mut ip: InstructionPtr,
+    kind: HandlerKind,
+    tag: index::Tag,
+) -> Option<InstructionPtr> {
+    let (_, len_handlers) = fetch_resume_params(ip);
+    ip.add(1);
+    for _ in 0..len_handlers {
+        ip.add(1);
+        match *ip.get() {
+            Instruction::ResumeHandler { tag: handled } => {
+                if kind == HandlerKind::Suspend && handled == tag {
+                    return Some(ip);
+                }
+                // Skip the handler target that follows the `Instruction::ResumeHandler`.
+                ip.add(1);
+            }
+            Instruction::SwitchHandler { tag: handled } => {
+                if kind == HandlerKind::Switch && handled == tag {
+                    return Some(ip);
+                }
+            }
+            unexpected => {
+                // Safety: Wasmi translation guarantees that `len_handlers` handlers exist.
+                unsafe {
+                    unreachable_unchecked!("expected resume handler but found: {unexpected:?}")
+                }
+            }
+        }
+    }
+    None
+}
+
+impl Executor<'_> {
+    /// Returns the [`BoundedRegSpan`] of the [`Instruction::RegisterSpan`] parameter.
+    fn fetch_cont_register_span(&self) -> BoundedRegSpan {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(1);
+        match *addr.get() {
+            Instruction::RegisterSpan { span } => span,
+            unexpected => {
+                // Safety: Wasmi translation guarantees that `Instruction::RegisterSpan` exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected `Instruction::RegisterSpan` but found: {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns the parameters of the [`Instruction::SwitchParams`] following an [`Instruction::Switch`] at `ip`.
+    fn fetch_switch_params(mut ip: InstructionPtr) -> (BoundedRegSpan, RegSpan) {
+        ip.add(1);
+        match *ip.get() {
+            Instruction::SwitchParams { args, results } => (args, results),
+            unexpected => {
+                // Safety: Wasmi translation guarantees that `Instruction::SwitchParams` exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected `Instruction::SwitchParams` but found: {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    /// Appends the values of the `registers` to `values`.
+    fn collect_cont_values(&self, values: &mut Vec<UntypedVal>, registers: BoundedRegSpan) {
+        values.extend(registers.iter().map(|register| self.get_register(register)));
+    }
+
+    /// Writes `values` into the `results` registers.
+    fn set_cont_results(
+        &mut self,
+        results: impl IntoIterator<Item = Reg>,
+        values: impl IntoIterator<Item = UntypedVal>,
+    ) {
+        for (result, value) in results.into_iter().zip(values) {
+            self.set_register(result, value);
+        }
+    }
+
+    /// Stores the current [`InstructionPtr`] in the top-most [`CallFrame`].
+    ///
+    /// This is required before switching stack segments since the active
+    /// [`CallFrame`] might get suspended as part of a continuation.
+    fn suspend_call_frame(&mut self) {
+        self.flush_back_edges();
+        self.stack
+            .calls
+            .peek_mut()
+            .expect("the executing call frame is always on the stack")
+            .update_instr_ptr(self.ip);
+    }
+
+    /// Initializes the [`Executor`] state for the top-most [`CallFrame`] of the active stack segment.
+    ///
+    /// Also updates the cached instance to the instance of the top-most [`CallFrame`].
+    fn init_top_call_frame(&mut self, store: &mut StoreInner) {
+        let instance = *self.stack.calls.instance_expect();
+        let frame = self
+            .stack
+            .calls
+            .peek()
+            .expect("the executing call frame is always on the stack");
+        Self::init_call_frame_impl(&mut self.stack.values, &mut self.sp, &mut self.ip, frame);
+        self.cache.update(store, &instance);
+    }
+
+    /// Executes an [`Instruction::ContNew`].
+    pub fn execute_cont_new(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        func: Reg,
+    ) -> Result<(), Error> {
+        let funcref: FuncRef = self.get_register_as(func);
+        let Some(func) = funcref.func() else {
+            return Err(Error::from(TrapCode::NullReference));
+        };
+        let contref = store.alloc_cont(ContEntity::Fresh {
+            func: *func,
+            args: Vec::new(),
+        });
+        self.set_register(result, contref);
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::ContBind`].
+    pub fn execute_cont_bind(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        cont: Reg,
+    ) -> Result<(), Error> {
+        let values = self.fetch_cont_register_span();
+        let contref: ContRef = self.get_register_as(cont);
+        let mut entity = take_cont(store, contref)?;
+        self.collect_cont_values(entity.args_mut(), values);
+        let contref = store.alloc_cont(entity);
+        self.set_register(result, contref);
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes an [`Instruction::Resume`].
+    pub fn execute_resume<T>(&mut self, store: &mut Store<T>, cont: Reg) -> Result<(), Error> {
+        let (args, _) = fetch_resume_params(self.ip);
+        let contref: ContRef = self.get_register_as(cont);
+        let mut entity = take_cont(&mut store.inner, contref)?;
+        let mut values = core::mem::take(entity.args_mut());
+        self.collect_cont_values(&mut values, args);
+        self.suspend_call_frame();
+        self.resume_cont(store, entity, values)
+    }
+
+    /// Executes an [`Instruction::Suspend`].
+    pub fn execute_suspend(
+        &mut self,
+        store: &mut StoreInner,
+        tag: index::Tag,
+    ) -> Result<ControlFlow, Error> {
+        let mut values = Vec::new();
+        self.collect_cont_values(&mut values, self.fetch_cont_register_span());
+        self.suspend_call_frame();
+        let (depth, handler) = self.find_handler(HandlerKind::Suspend, tag)?;
+        let contref = self.suspend_segments(store, depth);
+        self.init_top_call_frame(store);
+        values.push(UntypedVal::from(contref));
+        // The handler target follows its `Instruction::ResumeHandler`.
+        self.ip = handler;
+        self.ip.add(1);
+        match *self.ip.get() {
+            Instruction::BranchTableTarget { results, offset } => {
+                self.set_cont_results(results.iter_sized(values.len()), values);
+                self.execute_branch(offset);
+                Ok(ControlFlow::Continue(()))
+            }
+            Instruction::Return => {
+                let (mut caller_sp, results) = self.return_caller_results();
+                for (result, value) in results.iter_sized(values.len()).zip(values) {
+                    // Safety: The `callee.results()` always refer to a span of valid
+                    //         registers of the `caller` that does not overlap with the
+                    //         registers of the callee since they reside in different
+                    //         call frames. Therefore this access is safe.
+                    unsafe { caller_sp.set(result, value) }
+                }
+                Ok(self.return_impl(store))
+            }
+            unexpected => {
+                // Safety: Wasmi translation guarantees that a handler target exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected resume handler target but found: {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    /// Executes an [`Instruction::Switch`].
+    pub fn execute_switch<T>(
+        &mut self,
+        store: &mut Store<T>,
+        cont: Reg,
+        tag: index::Tag,
+    ) -> Result<(), Error> {
+        let (args, _) = Self::fetch_switch_params(self.ip);
+        let contref: ContRef = self.get_register_as(cont);
+        let mut values = Vec::new();
+        self.collect_cont_values(&mut values, args);
+        self.suspend_call_frame();
+        let (depth, _) = self.find_handler(HandlerKind::Switch, tag)?;
+        let mut target = take_cont(&mut store.inner, contref)?;
+        let suspended = self.suspend_segments(&mut store.inner, depth);
+        self.init_top_call_frame(&mut store.inner);
+        let mut target_values = core::mem::take(target.args_mut());
+        target_values.extend(values);
+        target_values.push(UntypedVal::from(suspended));
+        self.resume_cont(store, target, target_values)
+    }
+
+    /// Finds the innermost handler of `kind` for `tag` on the suspended parent stack segments.
+    ///
+    /// Returns the depth of the parent stack segment as well as the [`InstructionPtr`] to the handler.
+    ///
+    /// # Errors
+    ///
+    /// If no handler exists for `tag`.
+    fn find_handler(
+        &self,
+        kind: HandlerKind,
+        tag: index::Tag,
+    ) -> Result<(usize, InstructionPtr), Error> {
+        let instance = self.stack.calls.instance_expect();
+        for depth in (0..self.stack.len_parents()).rev() {
+            let parent = self
+                .stack
+                .parent(depth)
+                .expect("checked that parent stack segment exists");
+            if parent.calls.instance() != Some(instance) {
+                // Note: tags are local to the instance that defines them.
+                continue;
+            }
+            let frame = parent
+                .calls
+                .peek()
+                .expect("suspended stack segments always have a call frame");
+            if let Some(handler) = find_resume_handler(frame.instr_ptr(), kind, tag) {
+                return Ok((depth, handler));
+            }
+        }
+        Err(Error::from(TrapCode::UnhandledTag))
+    }
+
+    /// Suspends all stack segments above the parent stack segment at `depth` into a new continuation.
+    ///
+    /// The parent stack segment at `depth` is the active stack segment afterwards.
+    fn suspend_segments(&mut self, store: &mut StoreInner, depth: usize) -> ContRef {
+        let mut segments = Vec::with_capacity(self.stack.len_parents() - depth);
+        while self.stack.len_parents() > depth {
+            let segment = self
+                .stack
+                .pop_segment()
+                .expect("checked that parent stack segment exists");
+            segments.push(segment);
+        }
+        segments.reverse();
+        store.alloc_cont(ContEntity::Suspended {
+            segments,
+            args: Vec::new(),
+        })
+    }
+
+    /// Resumes the continuation `entity` with the given `values`.
+    ///
+    /// # Note
+    ///
+    /// The top-most [`CallFrame`] of the active stack segment must be
+    /// at the [`Instruction::Resume`] that resumes the continuation.
+    fn resume_cont<T>(
+        &mut self,
+        store: &mut Store<T>,
+        entity: ContEntity,
+        values: Vec<UntypedVal>,
+    ) -> Result<(), Error> {
+        match entity {
+            ContEntity::Fresh { func, .. } => match store.inner.resolve_func(&func) {
+                FuncEntity::Wasm(wasm_func) => {
+                    let instance = *wasm_func.instance();
+                    let func_body = wasm_func.func_body();
+                    let len_results = fetch_resume_results(self.ip).len();
+                    let compiled_func =
+                        self.code_map.get(Some(store.inner.fuel_mut()), func_body)?;
+                    let mut segment = store.engine().reuse_or_new_stack();
+                    segment.reset();
+                    // The results of the continuation are stored at the bottom of its stack segment.
+                    segment
+                        .values
+                        .extend_by(usize::from(len_results), do_nothing)?;
+                    let (mut uninit_params, offsets) =
+                        segment.values.alloc_call_frame(compiled_func, do_nothing)?;
+                    for value in values {
+                        // Safety: Wasm validation guarantees that the continuation
+                        //         receives exactly the parameters of `func`.
+                        unsafe { uninit_params.init_next(value) };
+                    }
+                    uninit_params.init_zeroes();
+                    segment.calls.push(
+                        CallFrame::new(
+                            func_body,
+                            InstructionPtr::new(compiled_func.instrs().as_ptr()),
+                            offsets,
+                            RegSpan::new(Reg::from(0)),
+                        ),
+                        Some(instance),
+                    )?;
+                    self.stack.push_segment(segment);
+                    self.init_top_call_frame(&mut store.inner);
+                    Ok(())
+                }
+                FuncEntity::Host(host_func) => {
+                    let host_func = *host_func;
+                    self.resume_host_func(store, func, host_func, values)
+                }
+            },
+            ContEntity::Suspended { segments, .. } => {
+                for segment in segments {
+                    self.stack.push_segment(segment);
+                }
+                self.init_top_call_frame(&mut store.inner);
+                let results = match *self.ip.get() {
+                    Instruction::Suspend { results, .. } => results,
+                    Instruction::Switch { .. } => Self::fetch_switch_params(self.ip).1,
+                    unexpected => {
+                        // Safety: suspended continuations are always at `suspend` or `switch`.
+                        unsafe {
+                            unreachable_unchecked!(
+                                "expected `Instruction::Suspend` or `Instruction::Switch` but found: {unexpected:?}"
+                            )
+                        }
+                    }
+                };
+                self.set_cont_results(results.iter_sized(values.len()), values);
+                self.try_next_instr_at(2)
+            }
+        }
+    }
+
+    /// Resumes a continuation of the host function `func` on the active stack segment.
+    ///
+    /// # Note
+    ///
+    /// Host functions cannot suspend which is why they are executed like a
+    /// function call from the [`Instruction::Resume`] that resumes them.
+    fn resume_host_func<T>(
+        &mut self,
+        store: &mut Store<T>,
+        func: Func,
+        host_func: HostFuncEntity,
+        values: Vec<UntypedVal>,
+    ) -> Result<(), Error> {
+        let results = fetch_resume_results(self.ip);
+        let len_params = host_func.len_params();
+        let len_results = host_func.len_results();
+        let max_inout = usize::from(len_params.max(len_results));
+        let instance = *self.stack.calls.instance_expect();
+        let caller = *self
+            .stack
+            .calls
+            .peek()
+            .expect("need to have a caller on the call stack");
+        let buffer = self.stack.values.extend_by(max_inout, |this| {
+            // Safety: we use the base offset of a live call frame on the call stack.
+            self.sp = unsafe { this.stack_ptr_at(caller.base_offset()) };
+        })?;
+        for (uninit, value) in buffer.iter_mut().zip(values) {
+            uninit.write(value);
+        }
+        let values = self.stack.values.as_slice();
+        let params = &values[values.len() - max_inout..][..usize::from(len_params)];
+        store.consume_host_func_fuel(host_func.trampoline(), params)?;
+        self.skip_resume();
+        self.stack
+            .calls
+            .peek_mut()
+            .expect("caller call frame must be on the stack")
+            .update_instr_ptr(self.ip);
+        store.invoke_call_hook(CallHook::CallingHost)?;
+        dispatch_host_func(store, self.stack, host_func, Some(&instance)).map_err(|error| {
+            self.rewind_to_call_site();
+            Error::from(ResumableHostError::new(error, func, results.span()))
+        })?;
+        store.invoke_call_hook(CallHook::ReturningFromHost)?;
+        self.cache.update(&mut store.inner, &instance);
+        let returned = self.stack.values.drop_return(max_inout);
+        for (result, value) in results.iter().zip(returned) {
+            // Safety: The result registers of the `Instruction::Resume` are valid
+            //         registers of the caller which is still live on the stack.
+            unsafe { self.sp.set(result, *value) };
+        }
+        Ok(())
+    }
+
+    /// Advances the [`InstructionPtr`] from an [`Instruction::Resume`] past all of its parameters.
+    fn skip_resume(&mut self) {
+        let (_, len_handlers) = fetch_resume_params(self.ip);
+        self.ip.add(1);
+        for _ in 0..len_handlers {
+            self.ip.add(1);
+            if let Instruction::ResumeHandler { .. } = *self.ip.get() {
+                // Skip the handler target that follows the `Instruction::ResumeHandler`.
+                self.ip.add(1);
+            }
+        }
+        self.ip.add(1);
+    }
+
+    /// Returns from the root [`CallFrame`] of a continuation to the [`Instruction::Resume`] that resumed it.
+    ///
+    /// # Note
+    ///
+    /// The results of the continuation are stored at the bottom of its finished stack segment.
+    #[cold]
+    pub(super) fn return_from_cont(&mut self, store: &mut StoreInner) -> ControlFlow {
+        let finished = self
+            .stack
+            .pop_segment()
+            .expect("continuations always have a parent stack segment");
+        self.init_top_call_frame(store);
+        let results = fetch_resume_results(self.ip);
+        self.set_cont_results(results, finished.values.as_slice().iter().copied());
+        self.skip_resume();
+        store.engine().recycle_stack(finished);
+        ControlFlow::Continue(())
+    }
+}
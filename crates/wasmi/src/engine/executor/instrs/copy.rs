@@ -101,7 +101,7 @@ impl Executor<'_> {
     ) {
         let results = results.iter(len);
         let values = values.iter(len);
-        for (result, value) in results.into_iter().zip(values.into_iter()) {
+        for (result, value) in results.into_iter().zip(values) {
             let value = self.get_register(value);
             self.set_register(result, value);
         }
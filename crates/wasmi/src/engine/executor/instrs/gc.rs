@@ -0,0 +1,641 @@
+use super::{Executor, InstructionPtr};
+use crate::{
+    core::{RefType, TrapCode, TypeId, UntypedVal},
+    engine::utils::unreachable_unchecked,
+    gc::{AnyRef, GcObject, StorageType},
+    ir::{index, Const16, Instruction, Reg},
+    store::StoreInner,
+    Error,
+};
+use alloc::{vec, vec::Vec};
+use core::mem::size_of;
+
+/// The maximum number of bytes of the elements of a single array object.
+///
+/// Allocating larger arrays traps with [`TrapCode::AllocationTooLarge`].
+const MAX_ARRAY_BYTES: usize = 1 << 30;
+
+/// Returns the [`TypeId`] encoded by the [`index::Type`].
+fn type_id(ty: index::Type) -> TypeId {
+    let raw = u32::from(ty);
+    TypeId::from_u32(raw).unwrap_or_else(|| {
+        // Safety: Wasmi translation only encodes valid type identifiers.
+        unsafe { unreachable_unchecked!("encountered invalid type identifier: {raw}") }
+    })
+}
+
+/// Returns a new empty buffer for `len` array elements.
+///
+/// # Errors
+///
+/// If the array would be too large.
+fn array_buffer(len: u32) -> Result<Vec<UntypedVal>, TrapCode> {
+    let len = len as usize;
+    if len.saturating_mul(size_of::<UntypedVal>()) > MAX_ARRAY_BYTES {
+        return Err(TrapCode::AllocationTooLarge);
+    }
+    let mut buffer = Vec::new();
+    buffer
+        .try_reserve_exact(len)
+        .map_err(|_| TrapCode::AllocationTooLarge)?;
+    Ok(buffer)
+}
+
+/// Decodes a little-endian array element of `storage` type from `bytes`.
+fn decode_element(storage: StorageType, bytes: &[u8]) -> UntypedVal {
+    match bytes {
+        [b0] => UntypedVal::from(u32::from(*b0)),
+        [b0, b1] => UntypedVal::from(u32::from(u16::from_le_bytes([*b0, *b1]))),
+        &[b0, b1, b2, b3] => UntypedVal::from(u32::from_le_bytes([b0, b1, b2, b3])),
+        &[b0, b1, b2, b3, b4, b5, b6, b7] => {
+            UntypedVal::from(u64::from_le_bytes([b0, b1, b2, b3, b4, b5, b6, b7]))
+        }
+        _ => unreachable!("invalid element size for storage type {storage:?}"),
+    }
+}
+
+/// Returns the `[start, start+len)` range of `len` items at `start` if within `bound`.
+fn checked_range(start: u32, len: u32, bound: usize) -> Option<core::ops::Range<usize>> {
+    let start = start as usize;
+    let end = start.checked_add(len as usize)?;
+    if end > bound {
+        return None;
+    }
+    Some(start..end)
+}
+
+impl Executor<'_> {
+    /// Collects garbage if the [`GcHeap`] of the `store` crossed its collection threshold.
+    ///
+    /// # Note
+    ///
+    /// The values of all segments of the executed stack and the `fields` of the
+    /// object about to be allocated are roots of the collection.
+    ///
+    /// [`GcHeap`]: crate::gc::GcHeap
+    #[cfg(feature = "gc")]
+    fn collect_garbage_if_due(&self, store: &mut StoreInner, fields: &[UntypedVal]) {
+        if store.gc_heap().is_collection_due() {
+            let roots = self.stack.values().chain(fields.iter().copied());
+            store.collect_garbage_with(roots);
+        }
+    }
+
+    /// Does nothing since garbage is only collected with the `gc` crate feature.
+    #[cfg(not(feature = "gc"))]
+    fn collect_garbage_if_due(&self, _store: &mut StoreInner, _fields: &[UntypedVal]) {}
+
+    /// Returns the [`Reg`] of the [`Instruction::Register`] parameter at `offset`.
+    fn fetch_gc_register(&self, offset: usize) -> Reg {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(offset);
+        match *addr.get() {
+            Instruction::Register { reg } => reg,
+            unexpected => {
+                // Safety: Wasmi translation guarantees that [`Instruction::Register`] exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected `Instruction::Register` but found: {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns the [`Reg`]s of the [`Instruction::Register2`] parameter at `offset`.
+    fn fetch_gc_register_2(&self, offset: usize) -> [Reg; 2] {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(offset);
+        match *addr.get() {
+            Instruction::Register2 { regs } => regs,
+            unexpected => {
+                // Safety: Wasmi translation guarantees that [`Instruction::Register2`] exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected `Instruction::Register2` but found: {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns the value of the [`Instruction::Const32`] parameter at `offset`.
+    fn fetch_gc_const32(&self, offset: usize) -> u32 {
+        let mut addr: InstructionPtr = self.ip;
+        addr.add(offset);
+        match *addr.get() {
+            Instruction::Const32 { value } => u32::from(value),
+            unexpected => {
+                // Safety: Wasmi translation guarantees that [`Instruction::Const32`] exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected `Instruction::Const32` but found: {unexpected:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns the [`RefType`] of the [`Instruction::Const32`] parameter of `ref.test` and `ref.cast`.
+    fn fetch_ref_type(&self) -> RefType {
+        let bits = self.fetch_gc_const32(1);
+        RefType::from_bits(bits).unwrap_or_else(|| {
+            // Safety: Wasmi translation only encodes valid reference types.
+            unsafe { unreachable_unchecked!("encountered invalid reference type: {bits:#X}") }
+        })
+    }
+
+    /// Collects the values of the register list following the current [`Instruction`].
+    ///
+    /// This will make the [`InstructionPtr`] point to the register list finalizer.
+    fn collect_register_list(&mut self, values: &mut Vec<UntypedVal>) {
+        self.ip.add(1);
+        while let Instruction::RegisterList { regs } = *self.ip.get() {
+            values.extend(regs.iter().map(|reg| self.get_register(*reg)));
+            self.ip.add(1);
+        }
+        let regs: &[Reg] = match self.ip.get() {
+            Instruction::Register { reg } => core::array::from_ref(reg),
+            Instruction::Register2 { regs } => regs,
+            Instruction::Register3 { regs } => regs,
+            unexpected => {
+                // Safety: Wasmi translation guarantees that register-list finalizer exists.
+                unsafe {
+                    unreachable_unchecked!(
+                        "expected register-list finalizer but found: {unexpected:?}"
+                    )
+                }
+            }
+        };
+        values.extend(regs.iter().map(|reg| self.get_register(*reg)));
+    }
+
+    /// Returns the non-`null` object referred to by the value of `reference`.
+    ///
+    /// # Errors
+    ///
+    /// If the value of `reference` is `null`.
+    fn resolve_gc_object<'store>(
+        &self,
+        store: &'store StoreInner,
+        reference: Reg,
+    ) -> Result<&'store GcObject, Error> {
+        let handle = self.get_register(reference);
+        if AnyRef::from(handle).is_null() {
+            return Err(Error::from(TrapCode::NullReference));
+        }
+        Ok(store.gc_heap().resolve(handle))
+    }
+
+    /// Returns the non-`null` object referred to by the value of `reference`.
+    ///
+    /// # Errors
+    ///
+    /// If the value of `reference` is `null`.
+    fn resolve_gc_object_mut<'store>(
+        &self,
+        store: &'store mut StoreInner,
+        reference: Reg,
+    ) -> Result<&'store mut GcObject, Error> {
+        let handle = self.get_register(reference);
+        if AnyRef::from(handle).is_null() {
+            return Err(Error::from(TrapCode::NullReference));
+        }
+        Ok(store.gc_heap_mut().resolve_mut(handle))
+    }
+
+    /// Executes an [`Instruction::StructNew`].
+    pub fn execute_struct_new(&mut self, store: &mut StoreInner, result: Reg, ty: index::Type) {
+        let mut fields = Vec::new();
+        self.collect_register_list(&mut fields);
+        self.collect_garbage_if_due(store, &fields);
+        let handle = store
+            .gc_heap_mut()
+            .alloc_struct(type_id(ty), fields.into_boxed_slice());
+        self.set_register(result, handle);
+        self.next_instr();
+    }
+
+    /// Executes an [`Instruction::StructNewDefault`].
+    pub fn execute_struct_new_default(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        ty: index::Type,
+    ) {
+        let len = self.fetch_gc_const32(1) as usize;
+        let fields = vec![UntypedVal::default(); len].into_boxed_slice();
+        self.collect_garbage_if_due(store, &[]);
+        let handle = store.gc_heap_mut().alloc_struct(type_id(ty), fields);
+        self.set_register(result, handle);
+        self.next_instr_at(2);
+    }
+
+    /// Executes an [`Instruction::StructGet`] and its packed variants.
+    pub fn execute_struct_get(
+        &mut self,
+        store: &StoreInner,
+        result: Reg,
+        struct_ref: Reg,
+        field: Const16<u32>,
+        unpack: fn(UntypedVal) -> UntypedVal,
+    ) -> Result<(), Error> {
+        let object = self.resolve_gc_object(store, struct_ref)?;
+        let value = object.fields()[u32::from(field) as usize];
+        self.set_register(result, unpack(value));
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::StructSet`].
+    pub fn execute_struct_set(
+        &mut self,
+        store: &mut StoreInner,
+        struct_ref: Reg,
+        field: Const16<u32>,
+        value: Reg,
+    ) -> Result<(), Error> {
+        let value = self.get_register(value);
+        let object = self.resolve_gc_object_mut(store, struct_ref)?;
+        object.fields_mut()[u32::from(field) as usize] = value;
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::ArrayNew`].
+    pub fn execute_array_new(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        ty: index::Type,
+    ) -> Result<(), Error> {
+        let [len, value] = self.fetch_gc_register_2(1);
+        let len: u32 = self.get_register_as(len);
+        let value = self.get_register(value);
+        let mut elements = array_buffer(len)?;
+        elements.resize(len as usize, value);
+        self.collect_garbage_if_due(store, &[value]);
+        let handle = store
+            .gc_heap_mut()
+            .alloc_array(type_id(ty), elements.into_boxed_slice());
+        self.set_register(result, handle);
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes an [`Instruction::ArrayNewDefault`].
+    pub fn execute_array_new_default(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        ty: index::Type,
+    ) -> Result<(), Error> {
+        let len: u32 = self.get_register_as(self.fetch_gc_register(1));
+        let mut elements = array_buffer(len)?;
+        elements.resize(len as usize, UntypedVal::default());
+        self.collect_garbage_if_due(store, &[]);
+        let handle = store
+            .gc_heap_mut()
+            .alloc_array(type_id(ty), elements.into_boxed_slice());
+        self.set_register(result, handle);
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes an [`Instruction::ArrayNewFixed`].
+    pub fn execute_array_new_fixed(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        ty: index::Type,
+    ) {
+        let mut elements = Vec::new();
+        self.collect_register_list(&mut elements);
+        self.collect_garbage_if_due(store, &elements);
+        let handle = store
+            .gc_heap_mut()
+            .alloc_array(type_id(ty), elements.into_boxed_slice());
+        self.set_register(result, handle);
+        self.next_instr();
+    }
+
+    /// Decodes `len` elements of the array type `ty` from the data segment `data` at `offset`.
+    fn decode_data_elements(
+        &self,
+        store: &mut StoreInner,
+        ty: TypeId,
+        data: index::Data,
+        offset: u32,
+        len: u32,
+    ) -> Result<Vec<UntypedVal>, Error> {
+        let storage = store
+            .engine()
+            .with_types(|types| types.array_type(ty).element().storage());
+        let Some(num_bytes) = storage.num_bytes() else {
+            unreachable!("array.new_data requires numeric array elements but found {storage:?}")
+        };
+        let segment = self.get_data_segment(data);
+        let bytes = store.resolve_data_segment_mut(&segment).bytes();
+        let bytes = (len as usize)
+            .checked_mul(num_bytes)
+            .and_then(|size| bytes.get(offset as usize..)?.get(..size))
+            .ok_or(TrapCode::MemoryOutOfBounds)?;
+        let mut elements = array_buffer(len)?;
+        elements.extend(
+            bytes
+                .chunks_exact(num_bytes)
+                .map(|bytes| decode_element(storage, bytes)),
+        );
+        Ok(elements)
+    }
+
+    /// Returns `len` items of the element segment `elem` at `offset`.
+    fn fetch_elem_elements(
+        &self,
+        store: &StoreInner,
+        elem: index::Elem,
+        offset: u32,
+        len: u32,
+    ) -> Result<Vec<UntypedVal>, Error> {
+        let segment = self.get_element_segment(elem);
+        let items = store.resolve_element_segment(&segment).items();
+        let items = checked_range(offset, len, items.len())
+            .map(|range| &items[range])
+            .ok_or(TrapCode::TableOutOfBounds)?;
+        let mut elements = array_buffer(len)?;
+        elements.extend_from_slice(items);
+        Ok(elements)
+    }
+
+    /// Executes an [`Instruction::ArrayNewData`].
+    pub fn execute_array_new_data(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        ty: index::Type,
+    ) -> Result<(), Error> {
+        let data = self.fetch_data_segment_index(1);
+        let [offset, len] = self.fetch_gc_register_2(2);
+        let offset: u32 = self.get_register_as(offset);
+        let len: u32 = self.get_register_as(len);
+        let ty = type_id(ty);
+        let elements = self.decode_data_elements(store, ty, data, offset, len)?;
+        self.collect_garbage_if_due(store, &[]);
+        let handle = store
+            .gc_heap_mut()
+            .alloc_array(ty, elements.into_boxed_slice());
+        self.set_register(result, handle);
+        self.try_next_instr_at(3)
+    }
+
+    /// Executes an [`Instruction::ArrayNewElem`].
+    pub fn execute_array_new_elem(
+        &mut self,
+        store: &mut StoreInner,
+        result: Reg,
+        ty: index::Type,
+    ) -> Result<(), Error> {
+        let elem = self.fetch_element_segment_index(1);
+        let [offset, len] = self.fetch_gc_register_2(2);
+        let offset: u32 = self.get_register_as(offset);
+        let len: u32 = self.get_register_as(len);
+        let elements = self.fetch_elem_elements(store, elem, offset, len)?;
+        self.collect_garbage_if_due(store, &elements);
+        let handle = store
+            .gc_heap_mut()
+            .alloc_array(type_id(ty), elements.into_boxed_slice());
+        self.set_register(result, handle);
+        self.try_next_instr_at(3)
+    }
+
+    /// Executes an [`Instruction::ArrayGet`] and its packed variants.
+    pub fn execute_array_get(
+        &mut self,
+        store: &StoreInner,
+        result: Reg,
+        array: Reg,
+        index: Reg,
+        unpack: fn(UntypedVal) -> UntypedVal,
+    ) -> Result<(), Error> {
+        let index: u32 = self.get_register_as(index);
+        let object = self.resolve_gc_object(store, array)?;
+        let value = *object
+            .fields()
+            .get(index as usize)
+            .ok_or(TrapCode::ArrayOutOfBounds)?;
+        self.set_register(result, unpack(value));
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::ArraySet`].
+    pub fn execute_array_set(
+        &mut self,
+        store: &mut StoreInner,
+        array: Reg,
+        index: Reg,
+        value: Reg,
+    ) -> Result<(), Error> {
+        let index: u32 = self.get_register_as(index);
+        let value = self.get_register(value);
+        let object = self.resolve_gc_object_mut(store, array)?;
+        let element = object
+            .fields_mut()
+            .get_mut(index as usize)
+            .ok_or(TrapCode::ArrayOutOfBounds)?;
+        *element = value;
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::ArrayLen`].
+    pub fn execute_array_len(
+        &mut self,
+        store: &StoreInner,
+        result: Reg,
+        array: Reg,
+    ) -> Result<(), Error> {
+        let len = self.resolve_gc_object(store, array)?.fields().len() as u32;
+        self.set_register(result, len);
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::ArrayFill`].
+    pub fn execute_array_fill(
+        &mut self,
+        store: &mut StoreInner,
+        array: Reg,
+        offset: Reg,
+        value: Reg,
+    ) -> Result<(), Error> {
+        let len: u32 = self.get_register_as(self.fetch_gc_register(1));
+        let offset: u32 = self.get_register_as(offset);
+        let value = self.get_register(value);
+        let object = self.resolve_gc_object_mut(store, array)?;
+        let elements = object.fields_mut();
+        let range = checked_range(offset, len, elements.len()).ok_or(TrapCode::ArrayOutOfBounds)?;
+        elements[range].fill(value);
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes an [`Instruction::ArrayCopy`].
+    pub fn execute_array_copy(
+        &mut self,
+        store: &mut StoreInner,
+        dst: Reg,
+        dst_offset: Reg,
+        src: Reg,
+    ) -> Result<(), Error> {
+        let [src_offset, len] = self.fetch_gc_register_2(1);
+        let dst_offset: u32 = self.get_register_as(dst_offset);
+        let src_offset: u32 = self.get_register_as(src_offset);
+        let len: u32 = self.get_register_as(len);
+        let src = self.resolve_gc_object(store, src)?.fields();
+        let src_range =
+            checked_range(src_offset, len, src.len()).ok_or(TrapCode::ArrayOutOfBounds)?;
+        let values = src[src_range].to_vec();
+        let dst = self.resolve_gc_object_mut(store, dst)?.fields_mut();
+        let dst_range =
+            checked_range(dst_offset, len, dst.len()).ok_or(TrapCode::ArrayOutOfBounds)?;
+        dst[dst_range].copy_from_slice(&values);
+        self.try_next_instr_at(2)
+    }
+
+    /// Writes `values` into the array referred to by `array` at `offset`.
+    fn init_array(
+        &self,
+        store: &mut StoreInner,
+        array: Reg,
+        offset: u32,
+        values: &[UntypedVal],
+    ) -> Result<(), Error> {
+        let elements = self.resolve_gc_object_mut(store, array)?.fields_mut();
+        let range = checked_range(offset, values.len() as u32, elements.len())
+            .ok_or(TrapCode::ArrayOutOfBounds)?;
+        elements[range].copy_from_slice(values);
+        Ok(())
+    }
+
+    /// Executes an [`Instruction::ArrayInitData`].
+    pub fn execute_array_init_data(
+        &mut self,
+        store: &mut StoreInner,
+        array: Reg,
+        dst: Reg,
+        src: Reg,
+    ) -> Result<(), Error> {
+        let data = self.fetch_data_segment_index(1);
+        let len: u32 = self.get_register_as(self.fetch_gc_register(2));
+        let dst: u32 = self.get_register_as(dst);
+        let src: u32 = self.get_register_as(src);
+        let object = self.resolve_gc_object(store, array)?;
+        let Some(ty) = object.type_id() else {
+            unreachable!("array.init_data requires an array object")
+        };
+        let bounds = object.fields().len();
+        checked_range(dst, len, bounds).ok_or(TrapCode::ArrayOutOfBounds)?;
+        let values = self.decode_data_elements(store, ty, data, src, len)?;
+        self.init_array(store, array, dst, &values)?;
+        self.try_next_instr_at(3)
+    }
+
+    /// Executes an [`Instruction::ArrayInitElem`].
+    pub fn execute_array_init_elem(
+        &mut self,
+        store: &mut StoreInner,
+        array: Reg,
+        dst: Reg,
+        src: Reg,
+    ) -> Result<(), Error> {
+        let elem = self.fetch_element_segment_index(1);
+        let len: u32 = self.get_register_as(self.fetch_gc_register(2));
+        let dst: u32 = self.get_register_as(dst);
+        let src: u32 = self.get_register_as(src);
+        let bounds = self.resolve_gc_object(store, array)?.fields().len();
+        checked_range(dst, len, bounds).ok_or(TrapCode::ArrayOutOfBounds)?;
+        let values = self.fetch_elem_elements(store, elem, src, len)?;
+        self.init_array(store, array, dst, &values)?;
+        self.try_next_instr_at(3)
+    }
+
+    /// Executes an [`Instruction::RefI31`].
+    pub fn execute_ref_i31(&mut self, result: Reg, input: Reg) {
+        let value: i32 = self.get_register_as(input);
+        self.set_register(result, AnyRef::from_i31(value));
+        self.next_instr();
+    }
+
+    /// Executes an [`Instruction::I31GetS`].
+    pub fn execute_i31_get_s(&mut self, result: Reg, input: Reg) -> Result<(), Error> {
+        let anyref: AnyRef = self.get_register_as(input);
+        let value = anyref.i31_get_s().ok_or(TrapCode::NullReference)?;
+        self.set_register(result, value);
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::I31GetU`].
+    pub fn execute_i31_get_u(&mut self, result: Reg, input: Reg) -> Result<(), Error> {
+        let anyref: AnyRef = self.get_register_as(input);
+        let value = anyref.i31_get_u().ok_or(TrapCode::NullReference)?;
+        self.set_register(result, value);
+        self.try_next_instr()
+    }
+
+    /// Executes an [`Instruction::RefTest`].
+    pub fn execute_ref_test(&mut self, store: &StoreInner, result: Reg, input: Reg) {
+        let ref_type = self.fetch_ref_type();
+        let value = self.get_register(input);
+        self.set_register(result, store.ref_matches(value, ref_type));
+        self.next_instr_at(2);
+    }
+
+    /// Executes an [`Instruction::RefCast`].
+    pub fn execute_ref_cast(
+        &mut self,
+        store: &StoreInner,
+        result: Reg,
+        input: Reg,
+    ) -> Result<(), Error> {
+        let ref_type = self.fetch_ref_type();
+        let value = self.get_register(input);
+        if !store.ref_matches(value, ref_type) {
+            return Err(Error::from(TrapCode::CastFailure));
+        }
+        self.set_register(result, value);
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes an [`Instruction::AnyConvertExtern`].
+    pub fn execute_any_convert_extern(&mut self, store: &mut StoreInner, result: Reg, input: Reg) {
+        let value = self.get_register(input);
+        self.collect_garbage_if_due(store, &[]);
+        self.set_register(result, store.any_convert_extern(value));
+        self.next_instr();
+    }
+
+    /// Executes an [`Instruction::ExternConvertAny`].
+    pub fn execute_extern_convert_any(&mut self, store: &mut StoreInner, result: Reg, input: Reg) {
+        let value = self.get_register(input);
+        self.set_register(result, store.extern_convert_any(value));
+        self.next_instr();
+    }
+}
+
+/// Returns the `i32` value of a field or element of type `i8` with sign-extension.
+pub fn unpack_i8_s(value: UntypedVal) -> UntypedVal {
+    UntypedVal::from(i32::from(u32::from(value) as i8))
+}
+
+/// Returns the `i32` value of a field or element of type `i8` with zero-extension.
+pub fn unpack_i8_u(value: UntypedVal) -> UntypedVal {
+    UntypedVal::from(u32::from(u32::from(value) as u8))
+}
+
+/// Returns the `i32` value of a field or element of type `i16` with sign-extension.
+pub fn unpack_i16_s(value: UntypedVal) -> UntypedVal {
+    UntypedVal::from(i32::from(u32::from(value) as i16))
+}
+
+/// Returns the `i32` value of a field or element of type `i16` with zero-extension.
+pub fn unpack_i16_u(value: UntypedVal) -> UntypedVal {
+    UntypedVal::from(u32::from(u32::from(value) as u16))
+}
+
+/// Returns the value of a field or element of unpacked type.
+pub fn unpack_none(value: UntypedVal) -> UntypedVal {
+    value
+}
@@ -11,6 +11,25 @@ use crate::{
 type WasmLoadOp =
     fn(memory: &[u8], address: UntypedVal, offset: u32) -> Result<UntypedVal, TrapCode>;
 
+/// Returns the value of a bounds checked memory access that is known to be in bounds.
+///
+/// # Note
+///
+/// This allows the compiler to optimize away the bounds checks of the memory access.
+///
+/// # Safety
+///
+/// The caller must ensure that the memory access is in bounds.
+#[inline(always)]
+pub(super) unsafe fn assume_in_bounds<T>(result: Result<T, TrapCode>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(trap_code) => unsafe {
+            unreachable_unchecked!("unchecked memory access failed: {trap_code}")
+        },
+    }
+}
+
 impl Executor<'_> {
     /// Returns the `ptr` and `offset` parameters for a `load` [`Instruction`].
     fn fetch_ptr_and_offset(&self) -> (Reg, u32) {
@@ -169,6 +188,98 @@ impl Executor<'_> {
         self.execute_load_extend_mem0(result, address, offset, load_extend)?;
         self.try_next_instr()
     }
+
+    /// Executes a generic `load_at_unchecked` [`Instruction`].
+    fn execute_load_at_unchecked_impl(
+        &mut self,
+        result: Reg,
+        address: u32,
+        load_extend: WasmLoadOp,
+    ) {
+        let memory = self.fetch_default_memory_bytes();
+        // Safety: Wasmi translation guarantees that the loaded bytes are in bounds.
+        let loaded_value =
+            unsafe { assume_in_bounds(load_extend(memory, UntypedVal::from(0u32), address)) };
+        self.set_register(result, loaded_value);
+        self.next_instr()
+    }
+
+    /// Executes a generic `load_offset16_unchecked` [`Instruction`].
+    fn execute_load_offset16_unchecked_impl(
+        &mut self,
+        result: Reg,
+        ptr: Reg,
+        offset: Const16<u32>,
+        load_extend: WasmLoadOp,
+    ) {
+        let memory = self.fetch_default_memory_bytes();
+        let address = self.get_register(ptr);
+        // Safety: Wasmi translation guarantees that the loaded bytes are in bounds.
+        let loaded_value =
+            unsafe { assume_in_bounds(load_extend(memory, address, u32::from(offset))) };
+        self.set_register(result, loaded_value);
+        self.next_instr()
+    }
+}
+
+/// The function signature of Wasm binary operations fused with a load operation.
+type WasmFusedBinOp = fn(lhs: UntypedVal, rhs: UntypedVal) -> UntypedVal;
+
+impl Executor<'_> {
+    /// Executes a generic `load` [`Instruction`] fused with the binary operation `op`.
+    fn execute_fused_load_impl(
+        &mut self,
+        result: Reg,
+        lhs: Reg,
+        load_extend: WasmLoadOp,
+        op: WasmFusedBinOp,
+    ) -> Result<(), Error> {
+        let (ptr, offset) = self.fetch_ptr_and_offset();
+        let memory = self.fetch_default_memory_bytes();
+        let address = self.get_register(ptr);
+        let loaded_value = load_extend(memory, address, offset)?;
+        let lhs = self.get_register(lhs);
+        self.set_register(result, op(lhs, loaded_value));
+        self.try_next_instr_at(2)
+    }
+
+    /// Executes a generic unchecked `load` [`Instruction`] fused with the binary operation `op`.
+    fn execute_fused_load_unchecked_impl(
+        &mut self,
+        result: Reg,
+        lhs: Reg,
+        load_extend: WasmLoadOp,
+        op: WasmFusedBinOp,
+    ) {
+        let (ptr, offset) = self.fetch_ptr_and_offset();
+        let memory = self.fetch_default_memory_bytes();
+        let address = self.get_register(ptr);
+        // Safety: Wasmi translation guarantees that the loaded bytes are in bounds.
+        let loaded_value = unsafe { assume_in_bounds(load_extend(memory, address, offset)) };
+        let lhs = self.get_register(lhs);
+        self.set_register(result, op(lhs, loaded_value));
+        self.next_instr_at(2)
+    }
+
+    /// Executes an [`Instruction::I32AddLoad32`].
+    pub fn execute_i32_add_load32(&mut self, result: Reg, lhs: Reg) -> Result<(), Error> {
+        self.execute_fused_load_impl(result, lhs, UntypedVal::load32, UntypedVal::i32_add)
+    }
+
+    /// Executes an [`Instruction::I32AddLoad32Unchecked`].
+    pub fn execute_i32_add_load32_unchecked(&mut self, result: Reg, lhs: Reg) {
+        self.execute_fused_load_unchecked_impl(result, lhs, UntypedVal::load32, UntypedVal::i32_add)
+    }
+
+    /// Executes an [`Instruction::I64AddLoad64`].
+    pub fn execute_i64_add_load64(&mut self, result: Reg, lhs: Reg) -> Result<(), Error> {
+        self.execute_fused_load_impl(result, lhs, UntypedVal::load64, UntypedVal::i64_add)
+    }
+
+    /// Executes an [`Instruction::I64AddLoad64Unchecked`].
+    pub fn execute_i64_add_load64_unchecked(&mut self, result: Reg, lhs: Reg) {
+        self.execute_fused_load_unchecked_impl(result, lhs, UntypedVal::load64, UntypedVal::i64_add)
+    }
 }
 
 macro_rules! impl_execute_load {
@@ -177,6 +288,8 @@ macro_rules! impl_execute_load {
             (Instruction::$var_load:expr, $fn_load:ident),
             (Instruction::$var_load_at:expr, $fn_load_at:ident),
             (Instruction::$var_load_off16:expr, $fn_load_off16:ident),
+            (Instruction::$var_load_at_unchecked:expr, $fn_load_at_unchecked:ident),
+            (Instruction::$var_load_off16_unchecked:expr, $fn_load_off16_unchecked:ident),
             $impl_fn:expr $(,)?
         )
     ),* $(,)? ) => {
@@ -195,6 +308,16 @@ macro_rules! impl_execute_load {
             pub fn $fn_load_off16(&mut self, result: Reg, ptr: Reg, offset: Const16<u32>) -> Result<(), Error> {
                 self.execute_load_offset16_impl(result, ptr, offset, $impl_fn)
             }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_load_at_unchecked), "`].")]
+            pub fn $fn_load_at_unchecked(&mut self, result: Reg, address: u32) {
+                self.execute_load_at_unchecked_impl(result, address, $impl_fn)
+            }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_load_off16_unchecked), "`].")]
+            pub fn $fn_load_off16_unchecked(&mut self, result: Reg, ptr: Reg, offset: Const16<u32>) {
+                self.execute_load_offset16_unchecked_impl(result, ptr, offset, $impl_fn)
+            }
         )*
     }
 }
@@ -205,12 +328,16 @@ impl Executor<'_> {
             (Instruction::Load32, execute_load32),
             (Instruction::Load32At, execute_load32_at),
             (Instruction::Load32Offset16, execute_load32_offset16),
+            (Instruction::Load32AtUnchecked, execute_load32_at_unchecked),
+            (Instruction::Load32Offset16Unchecked, execute_load32_offset16_unchecked),
             UntypedVal::load32,
         ),
         (
             (Instruction::Load64, execute_load64),
             (Instruction::Load64At, execute_load64_at),
             (Instruction::Load64Offset16, execute_load64_offset16),
+            (Instruction::Load64AtUnchecked, execute_load64_at_unchecked),
+            (Instruction::Load64Offset16Unchecked, execute_load64_offset16_unchecked),
             UntypedVal::load64,
         ),
 
@@ -218,24 +345,32 @@ impl Executor<'_> {
             (Instruction::I32Load8s, execute_i32_load8_s),
             (Instruction::I32Load8sAt, execute_i32_load8_s_at),
             (Instruction::I32Load8sOffset16, execute_i32_load8_s_offset16),
+            (Instruction::I32Load8sAtUnchecked, execute_i32_load8_s_at_unchecked),
+            (Instruction::I32Load8sOffset16Unchecked, execute_i32_load8_s_offset16_unchecked),
             UntypedVal::i32_load8_s,
         ),
         (
             (Instruction::I32Load8u, execute_i32_load8_u),
             (Instruction::I32Load8uAt, execute_i32_load8_u_at),
             (Instruction::I32Load8uOffset16, execute_i32_load8_u_offset16),
+            (Instruction::I32Load8uAtUnchecked, execute_i32_load8_u_at_unchecked),
+            (Instruction::I32Load8uOffset16Unchecked, execute_i32_load8_u_offset16_unchecked),
             UntypedVal::i32_load8_u,
         ),
         (
             (Instruction::I32Load16s, execute_i32_load16_s),
             (Instruction::I32Load16sAt, execute_i32_load16_s_at),
             (Instruction::I32Load16sOffset16, execute_i32_load16_s_offset16),
+            (Instruction::I32Load16sAtUnchecked, execute_i32_load16_s_at_unchecked),
+            (Instruction::I32Load16sOffset16Unchecked, execute_i32_load16_s_offset16_unchecked),
             UntypedVal::i32_load16_s,
         ),
         (
             (Instruction::I32Load16u, execute_i32_load16_u),
             (Instruction::I32Load16uAt, execute_i32_load16_u_at),
             (Instruction::I32Load16uOffset16, execute_i32_load16_u_offset16),
+            (Instruction::I32Load16uAtUnchecked, execute_i32_load16_u_at_unchecked),
+            (Instruction::I32Load16uOffset16Unchecked, execute_i32_load16_u_offset16_unchecked),
             UntypedVal::i32_load16_u,
         ),
 
@@ -243,36 +378,48 @@ impl Executor<'_> {
             (Instruction::I64Load8s, execute_i64_load8_s),
             (Instruction::I64Load8sAt, execute_i64_load8_s_at),
             (Instruction::I64Load8sOffset16, execute_i64_load8_s_offset16),
+            (Instruction::I64Load8sAtUnchecked, execute_i64_load8_s_at_unchecked),
+            (Instruction::I64Load8sOffset16Unchecked, execute_i64_load8_s_offset16_unchecked),
             UntypedVal::i64_load8_s,
         ),
         (
             (Instruction::I64Load8u, execute_i64_load8_u),
             (Instruction::I64Load8uAt, execute_i64_load8_u_at),
             (Instruction::I64Load8uOffset16, execute_i64_load8_u_offset16),
+            (Instruction::I64Load8uAtUnchecked, execute_i64_load8_u_at_unchecked),
+            (Instruction::I64Load8uOffset16Unchecked, execute_i64_load8_u_offset16_unchecked),
             UntypedVal::i64_load8_u,
         ),
         (
             (Instruction::I64Load16s, execute_i64_load16_s),
             (Instruction::I64Load16sAt, execute_i64_load16_s_at),
             (Instruction::I64Load16sOffset16, execute_i64_load16_s_offset16),
+            (Instruction::I64Load16sAtUnchecked, execute_i64_load16_s_at_unchecked),
+            (Instruction::I64Load16sOffset16Unchecked, execute_i64_load16_s_offset16_unchecked),
             UntypedVal::i64_load16_s,
         ),
         (
             (Instruction::I64Load16u, execute_i64_load16_u),
             (Instruction::I64Load16uAt, execute_i64_load16_u_at),
             (Instruction::I64Load16uOffset16, execute_i64_load16_u_offset16),
+            (Instruction::I64Load16uAtUnchecked, execute_i64_load16_u_at_unchecked),
+            (Instruction::I64Load16uOffset16Unchecked, execute_i64_load16_u_offset16_unchecked),
             UntypedVal::i64_load16_u,
         ),
         (
             (Instruction::I64Load32s, execute_i64_load32_s),
             (Instruction::I64Load32sAt, execute_i64_load32_s_at),
             (Instruction::I64Load32sOffset16, execute_i64_load32_s_offset16),
+            (Instruction::I64Load32sAtUnchecked, execute_i64_load32_s_at_unchecked),
+            (Instruction::I64Load32sOffset16Unchecked, execute_i64_load32_s_offset16_unchecked),
             UntypedVal::i64_load32_s,
         ),
         (
             (Instruction::I64Load32u, execute_i64_load32_u),
             (Instruction::I64Load32uAt, execute_i64_load32_u_at),
             (Instruction::I64Load32uOffset16, execute_i64_load32_u_offset16),
+            (Instruction::I64Load32uAtUnchecked, execute_i64_load32_u_at_unchecked),
+            (Instruction::I64Load32uOffset16Unchecked, execute_i64_load32_u_offset16_unchecked),
             UntypedVal::i64_load32_u,
         ),
     }
@@ -33,7 +33,7 @@ impl Executor<'_> {
     }
 
     /// Returns the [`Instruction::DataIndex`] parameter for an [`Instruction`].
-    fn fetch_data_segment_index(&self, offset: usize) -> Data {
+    pub(super) fn fetch_data_segment_index(&self, offset: usize) -> Data {
         let mut addr: InstructionPtr = self.ip;
         addr.add(offset);
         match *addr.get() {
@@ -109,9 +109,7 @@ impl Executor<'_> {
         }
         let memory = self.get_memory(memory);
         let (memory, fuel) = store.resolve_memory_and_fuel_mut(&memory);
-        let return_value = memory
-            .grow(delta, Some(fuel), resource_limiter)
-            .map(u32::from);
+        let return_value = memory.grow(delta, Some(fuel), resource_limiter);
         let return_value = match return_value {
             Ok(return_value) => {
                 // The `memory.grow` operation might have invalidated the cached
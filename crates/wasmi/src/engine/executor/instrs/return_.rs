@@ -15,7 +15,8 @@ impl Executor<'_> {
     ///
     /// Any return values are expected to already have been transferred
     /// from the returning callee to the caller.
-    fn return_impl(&mut self, store: &mut StoreInner) -> ControlFlow {
+    pub(super) fn return_impl(&mut self, store: &mut StoreInner) -> ControlFlow {
+        self.flush_back_edges();
         let (returned, popped_instance) = self
             .stack
             .calls
@@ -36,6 +37,7 @@ impl Executor<'_> {
                 );
                 ControlFlow::Continue(())
             }
+            None if self.stack.len_parents() != 0 => self.return_from_cont(store),
             None => ControlFlow::Break(()),
         }
     }
@@ -48,7 +50,7 @@ impl Executor<'_> {
     /// Returns the [`FrameRegisters`] of the caller and the [`RegSpan`] of the results.
     ///
     /// The returned [`FrameRegisters`] is valid for all [`Reg`] in the returned [`RegSpan`].
-    fn return_caller_results(&mut self) -> (FrameRegisters, RegSpan) {
+    pub(super) fn return_caller_results(&mut self) -> (FrameRegisters, RegSpan) {
         let (callee, caller) = self
             .stack
             .calls
@@ -1,4 +1,4 @@
-use super::{Executor, InstructionPtr};
+use super::{load::assume_in_bounds, Executor, InstructionPtr};
 use crate::{
     core::{TrapCode, UntypedVal},
     engine::utils::unreachable_unchecked,
@@ -256,6 +256,80 @@ impl Executor<'_> {
         )?;
         self.try_next_instr()
     }
+
+    /// Executes a generic Wasm `store[N]` operation for the default memory without bounds checks.
+    fn execute_store_wrap_mem0_unchecked(
+        &mut self,
+        address: UntypedVal,
+        offset: u32,
+        value: UntypedVal,
+        store_wrap: WasmStoreOp,
+    ) {
+        let memory = self.fetch_default_memory_bytes_mut();
+        // Safety: Wasmi translation guarantees that the stored bytes are in bounds.
+        unsafe { assume_in_bounds(store_wrap(memory, address, offset, value)) }
+    }
+
+    fn execute_store_offset16_unchecked(
+        &mut self,
+        ptr: Reg,
+        offset: Const16<u32>,
+        value: Reg,
+        store_op: WasmStoreOp,
+    ) {
+        self.execute_store_wrap_mem0_unchecked(
+            self.get_register(ptr),
+            u32::from(offset),
+            self.get_register(value),
+            store_op,
+        );
+        self.next_instr()
+    }
+
+    fn execute_store_offset16_imm16_unchecked<T, V>(
+        &mut self,
+        ptr: Reg,
+        offset: Const16<u32>,
+        value: V,
+        store_op: WasmStoreOp,
+    ) where
+        T: From<V> + Into<UntypedVal>,
+    {
+        self.execute_store_wrap_mem0_unchecked(
+            self.get_register(ptr),
+            u32::from(offset),
+            T::from(value).into(),
+            store_op,
+        );
+        self.next_instr()
+    }
+
+    fn execute_store_at_unchecked(&mut self, address: u32, value: Reg, store_op: WasmStoreOp) {
+        self.execute_store_wrap_mem0_unchecked(
+            UntypedVal::from(0u32),
+            address,
+            self.get_register(value),
+            store_op,
+        );
+        self.next_instr()
+    }
+
+    fn execute_store_at_imm16_unchecked<T, V>(
+        &mut self,
+        address: u32,
+        value: V,
+        store_op: WasmStoreOp,
+    ) where
+        T: From<V> + Into<UntypedVal>,
+    {
+        self.execute_store_wrap_mem0_unchecked(
+            UntypedVal::from(0u32),
+            address,
+            T::from(value).into(),
+            store_op,
+        );
+        self.next_instr()
+    }
 }
 
 macro_rules! impl_execute_istore {
@@ -265,6 +339,8 @@ macro_rules! impl_execute_istore {
             (Instruction::$var_store_imm:ident, $fn_store_imm:ident),
             (Instruction::$var_store_off16_imm16:ident, $fn_store_off16_imm16:ident),
             (Instruction::$var_store_at_imm16:ident, $fn_store_at_imm16:ident),
+            (Instruction::$var_store_off16_imm16_unchecked:ident, $fn_store_off16_imm16_unchecked:ident),
+            (Instruction::$var_store_at_imm16_unchecked:ident, $fn_store_at_imm16_unchecked:ident),
             $impl_fn:expr $(,)?
         )
     ),* $(,)? ) => {
@@ -293,6 +369,21 @@ macro_rules! impl_execute_istore {
             ) -> Result<(), Error> {
                 self.execute_store_at_imm16::<$to_ty, _>(store, address, value, $impl_fn)
             }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_store_off16_imm16_unchecked), "`].")]
+            pub fn $fn_store_off16_imm16_unchecked(
+                &mut self,
+                ptr: Reg,
+                offset: Const16<u32>,
+                value: $from_ty,
+            ) {
+                self.execute_store_offset16_imm16_unchecked::<$to_ty, _>(ptr, offset, value, $impl_fn)
+            }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_store_at_imm16_unchecked), "`].")]
+            pub fn $fn_store_at_imm16_unchecked(&mut self, address: u32, value: $from_ty) {
+                self.execute_store_at_imm16_unchecked::<$to_ty, _>(address, value, $impl_fn)
+            }
         )*
     };
 }
@@ -303,6 +394,8 @@ impl Executor<'_> {
             (Instruction::I32StoreImm16, execute_i32_store_imm16),
             (Instruction::I32StoreOffset16Imm16, execute_i32_store_offset16_imm16),
             (Instruction::I32StoreAtImm16, execute_i32_store_at_imm16),
+            (Instruction::I32StoreOffset16Imm16Unchecked, execute_i32_store_offset16_imm16_unchecked),
+            (Instruction::I32StoreAtImm16Unchecked, execute_i32_store_at_imm16_unchecked),
             UntypedVal::store32,
         ),
         (
@@ -310,6 +403,8 @@ impl Executor<'_> {
             (Instruction::I64StoreImm16, execute_i64_store_imm16),
             (Instruction::I64StoreOffset16Imm16, execute_i64_store_offset16_imm16),
             (Instruction::I64StoreAtImm16, execute_i64_store_at_imm16),
+            (Instruction::I64StoreOffset16Imm16Unchecked, execute_i64_store_offset16_imm16_unchecked),
+            (Instruction::I64StoreAtImm16Unchecked, execute_i64_store_at_imm16_unchecked),
             UntypedVal::store64,
         ),
     }
@@ -325,6 +420,10 @@ macro_rules! impl_execute_istore_trunc {
             (Instruction::$var_store_off16_imm16:ident, $fn_store_off16_imm16:ident),
             (Instruction::$var_store_at:ident, $fn_store_at:ident),
             (Instruction::$var_store_at_imm16:ident, $fn_store_at_imm16:ident),
+            (Instruction::$var_store_off16_unchecked:ident, $fn_store_off16_unchecked:ident),
+            (Instruction::$var_store_off16_imm16_unchecked:ident, $fn_store_off16_imm16_unchecked:ident),
+            (Instruction::$var_store_at_unchecked:ident, $fn_store_at_unchecked:ident),
+            (Instruction::$var_store_at_imm16_unchecked:ident, $fn_store_at_imm16_unchecked:ident),
             $impl_fn:expr $(,)?
         )
     ),* $(,)? ) => {
@@ -335,6 +434,8 @@ macro_rules! impl_execute_istore_trunc {
                     (Instruction::$var_store_imm, $fn_store_imm),
                     (Instruction::$var_store_off16_imm16, $fn_store_off16_imm16),
                     (Instruction::$var_store_at_imm16, $fn_store_at_imm16),
+                    (Instruction::$var_store_off16_imm16_unchecked, $fn_store_off16_imm16_unchecked),
+                    (Instruction::$var_store_at_imm16_unchecked, $fn_store_at_imm16_unchecked),
                     $impl_fn,
                 )
             }
@@ -358,6 +459,16 @@ macro_rules! impl_execute_istore_trunc {
             pub fn $fn_store_at(&mut self, store: &mut StoreInner, address: u32, value: Reg) -> Result<(), Error> {
                 self.execute_store_at(store, address, value, $impl_fn)
             }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_store_off16_unchecked), "`].")]
+            pub fn $fn_store_off16_unchecked(&mut self, ptr: Reg, offset: Const16<u32>, value: Reg) {
+                self.execute_store_offset16_unchecked(ptr, offset, value, $impl_fn)
+            }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_store_at_unchecked), "`].")]
+            pub fn $fn_store_at_unchecked(&mut self, address: u32, value: Reg) {
+                self.execute_store_at_unchecked(address, value, $impl_fn)
+            }
         )*
     };
 }
@@ -371,6 +482,10 @@ impl Executor<'_> {
             (Instruction::I32Store8Offset16Imm, execute_i32_store8_offset16_imm),
             (Instruction::I32Store8At, execute_i32_store8_at),
             (Instruction::I32Store8AtImm, execute_i32_store8_at_imm),
+            (Instruction::I32Store8Offset16Unchecked, execute_i32_store8_offset16_unchecked),
+            (Instruction::I32Store8Offset16ImmUnchecked, execute_i32_store8_offset16_imm_unchecked),
+            (Instruction::I32Store8AtUnchecked, execute_i32_store8_at_unchecked),
+            (Instruction::I32Store8AtImmUnchecked, execute_i32_store8_at_imm_unchecked),
             UntypedVal::i32_store8,
         ),
         (
@@ -381,6 +496,10 @@ impl Executor<'_> {
             (Instruction::I32Store16Offset16Imm, execute_i32_store16_offset16_imm),
             (Instruction::I32Store16At, execute_i32_store16_at),
             (Instruction::I32Store16AtImm, execute_i32_store16_at_imm),
+            (Instruction::I32Store16Offset16Unchecked, execute_i32_store16_offset16_unchecked),
+            (Instruction::I32Store16Offset16ImmUnchecked, execute_i32_store16_offset16_imm_unchecked),
+            (Instruction::I32Store16AtUnchecked, execute_i32_store16_at_unchecked),
+            (Instruction::I32Store16AtImmUnchecked, execute_i32_store16_at_imm_unchecked),
             UntypedVal::i32_store16,
         ),
         (
@@ -391,6 +510,10 @@ impl Executor<'_> {
             (Instruction::I64Store8Offset16Imm, execute_i64_store8_offset16_imm),
             (Instruction::I64Store8At, execute_i64_store8_at),
             (Instruction::I64Store8AtImm, execute_i64_store8_at_imm),
+            (Instruction::I64Store8Offset16Unchecked, execute_i64_store8_offset16_unchecked),
+            (Instruction::I64Store8Offset16ImmUnchecked, execute_i64_store8_offset16_imm_unchecked),
+            (Instruction::I64Store8AtUnchecked, execute_i64_store8_at_unchecked),
+            (Instruction::I64Store8AtImmUnchecked, execute_i64_store8_at_imm_unchecked),
             UntypedVal::i64_store8,
         ),
         (
@@ -401,6 +524,10 @@ impl Executor<'_> {
             (Instruction::I64Store16Offset16Imm, execute_i64_store16_offset16_imm),
             (Instruction::I64Store16At, execute_i64_store16_at),
             (Instruction::I64Store16AtImm, execute_i64_store16_at_imm),
+            (Instruction::I64Store16Offset16Unchecked, execute_i64_store16_offset16_unchecked),
+            (Instruction::I64Store16Offset16ImmUnchecked, execute_i64_store16_offset16_imm_unchecked),
+            (Instruction::I64Store16AtUnchecked, execute_i64_store16_at_unchecked),
+            (Instruction::I64Store16AtImmUnchecked, execute_i64_store16_at_imm_unchecked),
             UntypedVal::i64_store16,
         ),
         (
@@ -411,6 +538,10 @@ impl Executor<'_> {
             (Instruction::I64Store32Offset16Imm16, execute_i64_store32_offset16_imm16),
             (Instruction::I64Store32At, execute_i64_store32_at),
             (Instruction::I64Store32AtImm16, execute_i64_store32_at_imm16),
+            (Instruction::I64Store32Offset16Unchecked, execute_i64_store32_offset16_unchecked),
+            (Instruction::I64Store32Offset16Imm16Unchecked, execute_i64_store32_offset16_imm16_unchecked),
+            (Instruction::I64Store32AtUnchecked, execute_i64_store32_at_unchecked),
+            (Instruction::I64Store32AtImm16Unchecked, execute_i64_store32_at_imm16_unchecked),
             UntypedVal::i64_store32,
         ),
     }
@@ -422,6 +553,8 @@ macro_rules! impl_execute_store {
             (Instruction::$var_store:ident, $fn_store:ident),
             (Instruction::$var_store_off16:ident, $fn_store_off16:ident),
             (Instruction::$var_store_at:ident, $fn_store_at:ident),
+            (Instruction::$var_store_off16_unchecked:ident, $fn_store_off16_unchecked:ident),
+            (Instruction::$var_store_at_unchecked:ident, $fn_store_at_unchecked:ident),
             $impl_fn:expr $(,)?
         )
     ),* $(,)? ) => {
@@ -445,6 +578,16 @@ macro_rules! impl_execute_store {
             pub fn $fn_store_at(&mut self, store: &mut StoreInner,address: u32, value: Reg) -> Result<(), Error> {
                 self.execute_store_at(store, address, value, $impl_fn)
             }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_store_off16_unchecked), "`].")]
+            pub fn $fn_store_off16_unchecked(&mut self, ptr: Reg, offset: Const16<u32>, value: Reg) {
+                self.execute_store_offset16_unchecked(ptr, offset, value, $impl_fn)
+            }
+
+            #[doc = concat!("Executes an [`Instruction::", stringify!($var_store_at_unchecked), "`].")]
+            pub fn $fn_store_at_unchecked(&mut self, address: u32, value: Reg) {
+                self.execute_store_at_unchecked(address, value, $impl_fn)
+            }
         )*
     }
 }
@@ -455,12 +598,16 @@ impl Executor<'_> {
             (Instruction::Store32, execute_store32),
             (Instruction::Store32Offset16, execute_store32_offset16),
             (Instruction::Store32At, execute_store32_at),
+            (Instruction::Store32Offset16Unchecked, execute_store32_offset16_unchecked),
+            (Instruction::Store32AtUnchecked, execute_store32_at_unchecked),
             UntypedVal::store32,
         ),
         (
             (Instruction::Store64, execute_store64),
             (Instruction::Store64Offset16, execute_store64_offset16),
             (Instruction::Store64At, execute_store64_at),
+            (Instruction::Store64Offset16Unchecked, execute_store64_offset16_unchecked),
+            (Instruction::Store64AtUnchecked, execute_store64_at_unchecked),
             UntypedVal::store64,
         ),
     }
@@ -34,7 +34,7 @@ impl Executor<'_> {
     }
 
     /// Returns the [`Instruction::ElemIndex`] parameter for an [`Instruction`].
-    fn fetch_element_segment_index(&self, offset: usize) -> Elem {
+    pub(super) fn fetch_element_segment_index(&self, offset: usize) -> Elem {
         let mut addr: InstructionPtr = self.ip;
         addr.add(offset);
         match *addr.get() {
@@ -1,5 +1,5 @@
 pub use self::instrs::ResumableHostError;
-pub(crate) use self::stack::Stack;
+pub(crate) use self::{call_cache::CallIndirectCache, stack::Stack};
 use self::{
     instr_ptr::InstructionPtr,
     instrs::{dispatch_host_func, execute_instrs},
@@ -21,8 +21,10 @@ use crate::{
 use crate::engine::StackLimits;
 
 use super::code_map::CodeMap;
+use alloc::sync::Arc;
 
 mod cache;
+mod call_cache;
 mod instr_ptr;
 mod instrs;
 mod stack;
@@ -45,14 +47,14 @@ impl EngineInner {
     where
         Results: CallResults,
     {
-        let mut stack = self.stacks.lock().reuse_or_new();
+        let mut stack = self.reuse_or_new_stack();
         let results = EngineExecutor::new(&self.code_map, &mut stack)
             .execute_root_func(ctx.store, func, params, results)
             .map_err(|error| match error.into_resumable() {
                 Ok(error) => error.into_error(),
                 Err(error) => error,
             });
-        self.stacks.lock().recycle(stack);
+        self.recycle_stack(stack);
         results
     }
 
@@ -74,12 +76,12 @@ impl EngineInner {
         Results: CallResults,
     {
         let store = ctx.store;
-        let mut stack = self.stacks.lock().reuse_or_new();
+        let mut stack = self.reuse_or_new_stack();
         let results = EngineExecutor::new(&self.code_map, &mut stack)
             .execute_root_func(store, func, params, results);
         match results {
             Ok(results) => {
-                self.stacks.lock().recycle(stack);
+                self.recycle_stack(stack);
                 Ok(ResumableCallBase::Finished(results))
             }
             Err(error) => match error.into_resumable() {
@@ -87,6 +89,8 @@ impl EngineInner {
                     let host_func = *error.host_func();
                     let caller_results = *error.caller_results();
                     let host_error = error.into_error();
+                    let gc_token = Arc::new(());
+                    store.inner.suspend_resumable(&gc_token, stack.values());
                     Ok(ResumableCallBase::Resumable(ResumableInvocation::new(
                         store.engine().clone(),
                         *func,
@@ -94,10 +98,11 @@ impl EngineInner {
                         host_error,
                         caller_results,
                         stack,
+                        gc_token,
                     )))
                 }
                 Err(error) => {
-                    self.stacks.lock().recycle(stack);
+                    self.recycle_stack(stack);
                     Err(error)
                 }
             },
@@ -132,7 +137,7 @@ impl EngineInner {
         );
         match results {
             Ok(results) => {
-                self.stacks.lock().recycle(invocation.take_stack());
+                self.recycle_stack(invocation.take_stack());
                 Ok(ResumableCallBase::Finished(results))
             }
             Err(error) => match error.into_resumable() {
@@ -140,10 +145,13 @@ impl EngineInner {
                     let host_func = *error.host_func();
                     let caller_results = *error.caller_results();
                     invocation.update(host_func, error.into_error(), caller_results);
+                    ctx.store
+                        .inner
+                        .suspend_resumable(invocation.gc_token(), invocation.stack.values());
                     Ok(ResumableCallBase::Resumable(invocation))
                 }
                 Err(error) => {
-                    self.stacks.lock().recycle(invocation.take_stack());
+                    self.recycle_stack(invocation.take_stack());
                     Err(error)
                 }
             },
@@ -210,6 +218,7 @@ impl<'engine> EngineExecutor<'engine> {
                 uninit_params.init_zeroes();
                 self.stack.calls.push(
                     CallFrame::new(
+                        engine_func,
                         InstructionPtr::new(compiled_func.instrs().as_ptr()),
                         offsets,
                         RegSpan::new(Reg::from(0)),
@@ -296,7 +305,7 @@ impl<'engine> EngineExecutor<'engine> {
         store: &mut Store<T>,
         host_func: HostFuncEntity,
     ) -> Result<(), Error> {
-        dispatch_host_func(store, &mut self.stack.values, host_func, None)?;
+        dispatch_host_func(store, self.stack, host_func, None)?;
         Ok(())
     }
 
@@ -2,22 +2,14 @@ use super::{err_stack_overflow, BaseValueStackOffset, FrameValueStackOffset};
 use crate::{
     collections::HeadVec,
     core::TrapCode,
-    engine::executor::InstructionPtr,
+    engine::{executor::InstructionPtr, EngineFunc},
     ir::RegSpan,
     Instance,
 };
 use alloc::vec::Vec;
 
 #[cfg(doc)]
-use crate::{
-    engine::executor::stack::ValueStack,
-    engine::EngineFunc,
-    ir::Instruction,
-    ir::Reg,
-    Global,
-    Memory,
-    Table,
-};
+use crate::{engine::executor::stack::ValueStack, ir::Instruction, ir::Reg, Global, Memory, Table};
 
 /// The stack of nested function calls.
 #[derive(Debug, Default)]
@@ -150,6 +142,18 @@ impl CallStack {
         self.frames.last_mut()
     }
 
+    /// Returns an iterator over all [`CallFrame`]s and their [`Instance`] from top to bottom.
+    pub fn frames_with_instances(&self) -> impl Iterator<Item = (&CallFrame, &Instance)> {
+        let mut instances = self.instances.iter().rev().peekable();
+        self.frames.iter().rev().filter_map(move |frame| {
+            let instance = *instances.peek()?;
+            if frame.changed_instance {
+                instances.next();
+            }
+            Some((frame, instance))
+        })
+    }
+
     /// Peeks the two top-most [`CallFrame`] on the [`CallStack`] if any.
     ///
     /// # Note
@@ -200,6 +204,8 @@ pub struct CallFrame {
     offsets: StackOffsets,
     /// Span of registers were the caller expects them in its [`CallFrame`].
     results: RegSpan,
+    /// The called [`EngineFunc`].
+    func: EngineFunc,
     /// Is `true` if this [`CallFrame`] changed the currently used [`Instance`].
     ///
     /// - This flag is an optimization to reduce the amount of accesses on the
@@ -212,11 +218,17 @@ pub struct CallFrame {
 
 impl CallFrame {
     /// Creates a new [`CallFrame`].
-    pub fn new(instr_ptr: InstructionPtr, offsets: StackOffsets, results: RegSpan) -> Self {
+    pub fn new(
+        func: EngineFunc,
+        instr_ptr: InstructionPtr,
+        offsets: StackOffsets,
+        results: RegSpan,
+    ) -> Self {
         Self {
             instr_ptr,
             offsets,
             results,
+            func,
             changed_instance: false,
         }
     }
@@ -244,6 +256,11 @@ impl CallFrame {
         self.instr_ptr
     }
 
+    /// Returns the called [`EngineFunc`] of the [`CallFrame`].
+    pub fn func(&self) -> EngineFunc {
+        self.func
+    }
+
     /// Returns the [`FrameValueStackOffset`] of the [`CallFrame`].
     pub fn frame_offset(&self) -> FrameValueStackOffset {
         self.offsets.frame
@@ -11,7 +11,14 @@ pub use self::{
         ValueStack,
     },
 };
-use crate::{core::TrapCode, Instance, StackLimits};
+use crate::{
+    core::{TrapCode, UntypedVal},
+    engine::EpochGuard,
+    Instance,
+    StackLimits,
+};
+use alloc::vec::Vec;
+use core::mem;
 
 /// Returns a [`TrapCode`] signalling a stack overflow.
 #[cold]
@@ -26,6 +33,18 @@ pub struct Stack {
     pub calls: CallStack,
     /// The value stack.
     pub values: ValueStack,
+    /// The suspended parent stack segments of the active continuation if any.
+    ///
+    /// # Note
+    ///
+    /// - The segments are ordered from outermost to innermost.
+    /// - Each segment is suspended at the `resume` instruction that resumed
+    ///   its child segment as introduced by the Wasm `stack-switching` proposal.
+    parents: Vec<Stack>,
+    /// Keeps the function translations alive that might be executed by the [`Stack`].
+    ///
+    /// This is `None` unless tiered translation is enabled.
+    epoch: Option<EpochGuard>,
 }
 
 impl Stack {
@@ -38,13 +57,19 @@ impl Stack {
             limits.initial_value_stack_height,
             limits.maximum_value_stack_height,
         );
-        Self { calls, values }
+        Self {
+            calls,
+            values,
+            parents: Vec::new(),
+            epoch: None,
+        }
     }
 
     /// Resets the [`Stack`] for clean reuse.
     pub fn reset(&mut self) {
         self.calls.reset();
         self.values.reset();
+        self.parents.clear();
     }
 
     /// Create an empty [`Stack`].
@@ -56,9 +81,55 @@ impl Stack {
         Self {
             values: ValueStack::empty(),
             calls: CallStack::default(),
+            parents: Vec::new(),
+            epoch: None,
         }
     }
 
+    /// Sets the [`EpochGuard`] of the [`Stack`] dropping the previous one if any.
+    pub fn set_epoch(&mut self, epoch: Option<EpochGuard>) {
+        self.epoch = epoch;
+    }
+
+    /// Returns the number of suspended parent stack segments.
+    pub fn len_parents(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns the suspended parent stack segment at `depth` if any.
+    ///
+    /// The outermost parent is at depth `0`.
+    pub fn parent(&self, depth: usize) -> Option<&Stack> {
+        self.parents.get(depth)
+    }
+
+    /// Returns an iterator over the values of all stack segments.
+    pub fn values(&self) -> impl Iterator<Item = UntypedVal> + '_ {
+        self.parents
+            .iter()
+            .flat_map(|parent| parent.values.as_slice())
+            .chain(self.values.as_slice())
+            .copied()
+    }
+
+    /// Makes `segment` the active stack segment and suspends the current one as its parent.
+    pub fn push_segment(&mut self, mut segment: Stack) {
+        debug_assert!(segment.parents.is_empty());
+        mem::swap(&mut self.calls, &mut segment.calls);
+        mem::swap(&mut self.values, &mut segment.values);
+        self.parents.push(segment);
+    }
+
+    /// Makes the innermost parent the active stack segment and returns the former active one.
+    ///
+    /// Returns `None` if there is no parent stack segment.
+    pub fn pop_segment(&mut self) -> Option<Stack> {
+        let mut segment = self.parents.pop()?;
+        mem::swap(&mut self.calls, &mut segment.calls);
+        mem::swap(&mut self.values, &mut segment.values);
+        Some(segment)
+    }
+
     /// Returns the capacity of the [`Stack`].
     pub fn capacity(&self) -> usize {
         self.values.capacity()
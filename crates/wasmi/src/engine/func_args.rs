@@ -2,7 +2,10 @@
 
 use crate::{
     core::{DecodeUntypedSlice, EncodeUntypedSlice, UntypedError, UntypedVal},
+    errors::FuncError,
     value::WithType,
+    Error,
+    FuncType,
     Val,
 };
 use core::cmp;
@@ -131,8 +134,92 @@ impl<'a> FuncParams<'a> {
         Ok(results)
     }
 
+    /// Calls the raw host function `f` with the untyped parameters and results of `self`.
+    ///
+    /// The host function `f` decodes its parameters and encodes its results in place.
+    ///
+    /// # Errors
+    ///
+    /// - If `f` returns an error.
+    /// - If `f` wrote a result that does not match its reference type in `ty`,
+    ///   e.g. `null` for a non-nullable reference type.
+    pub fn call_raw(
+        self,
+        ty: &FuncType,
+        f: impl FnOnce(FuncInOut) -> Result<(), Error>,
+    ) -> Result<FuncFinished, Error> {
+        f(FuncInOut {
+            params_results: &mut *self.params_results,
+            len_params: self.len_params,
+            len_results: self.len_results,
+            ty,
+        })?;
+        // Reference typed results must be checked since Wasm code relies on their types,
+        // e.g. on non-nullable reference types never being `null`.
+        let results_match = self.params_results[..self.len_results]
+            .iter()
+            .zip(ty.results())
+            .filter(|(_, ty)| ty.is_ref())
+            .all(|(result, ty)| result.with_type(*ty).matches_ty_erased(ty));
+        if !results_match {
+            return Err(Error::from(FuncError::MismatchingResultType));
+        }
+        Ok(FuncFinished {})
+    }
+
     /// Consumes `self` to return the [`FuncResults`] out of it.
     fn into_func_results(self) -> FuncResults<'a> {
         FuncResults::new(&mut self.params_results[..self.len_results])
     }
 }
+
+/// The untyped parameters and results of a raw host function invocation.
+///
+/// # Note
+///
+/// - Used by host functions created via [`Func::new_raw`] or [`Linker::func_new_raw`].
+/// - The parameters and results share the same buffer in order to avoid copies.
+///   Therefore writing the results of the host function invocation overwrites its parameters.
+/// - The [`FuncType`] of the host function is available via [`FuncInOut::ty`]
+///   in order to check or interpret the untyped parameters and results.
+///
+/// [`Func::new_raw`]: crate::Func::new_raw
+/// [`Linker::func_new_raw`]: crate::Linker::func_new_raw
+#[derive(Debug)]
+pub struct FuncInOut<'a> {
+    /// Slice holding the untyped parameters before and the untyped results after the invocation.
+    params_results: &'a mut [UntypedVal],
+    /// The number of parameters of the host function.
+    len_params: usize,
+    /// The number of results of the host function.
+    len_results: usize,
+    /// The function type of the host function.
+    ty: &'a FuncType,
+}
+
+impl FuncInOut<'_> {
+    /// Returns the [`FuncType`] of the invoked host function.
+    pub fn ty(&self) -> &FuncType {
+        self.ty
+    }
+
+    /// Returns the untyped parameters of the host function invocation.
+    ///
+    /// # Note
+    ///
+    /// The parameters are overwritten by writes to [`FuncInOut::results_mut`].
+    pub fn params(&self) -> &[UntypedVal] {
+        &self.params_results[..self.len_params]
+    }
+
+    /// Returns the untyped results of the host function invocation.
+    ///
+    /// # Note
+    ///
+    /// - The results are initialized with unspecified values and must all be written
+    ///   with values matching the result types of [`FuncInOut::ty`] by the host function.
+    /// - The results share their buffer with [`FuncInOut::params`].
+    pub fn results_mut(&mut self) -> &mut [UntypedVal] {
+        &mut self.params_results[..self.len_results]
+    }
+}
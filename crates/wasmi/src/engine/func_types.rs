@@ -1,10 +1,17 @@
 use super::{EngineIdx, Guarded};
 use crate::{
     collections::arena::{ArenaIndex, DedupArena, GuardedEntity},
+    core::{HeapType, RefType, TypeId, TypeKind, ValType},
+    gc::{ArrayType, FieldType, StorageType, StructType},
     FuncType,
 };
+use alloc::{boxed::Box, vec::Vec};
 
 /// A raw index to a function signature entity.
+///
+/// # Note
+///
+/// This is the raw representation of the [`TypeId`] of the type.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DedupFuncTypeIdx(u32);
 
@@ -25,6 +32,8 @@ impl ArenaIndex for DedupFuncTypeIdx {
 ///
 /// # Note
 ///
+/// With the Wasm `gc` proposal this may also refer to a struct or array type.
+///
 /// Advantages over a non-deduplicated [`FuncType`] are:
 ///
 /// - Comparison for equality is as fast as an integer value comparison.
@@ -52,6 +61,126 @@ impl DedupFuncType {
     }
 }
 
+/// A raw index to a deduplicated recursion group.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct RecGroupIdx(u32);
+
+impl ArenaIndex for RecGroupIdx {
+    fn into_usize(self) -> usize {
+        self.0 as _
+    }
+
+    fn from_usize(value: usize) -> Self {
+        let value = value.try_into().unwrap_or_else(|error| {
+            panic!("index {value} is out of bounds as rec group index: {error}")
+        });
+        Self(value)
+    }
+}
+
+/// The composite type of a [`SubType`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompositeType {
+    /// A function type.
+    Func(FuncType),
+    /// A struct type.
+    Struct(StructType),
+    /// An array type.
+    Array(ArrayType),
+    /// A continuation type over the function type identified by the [`TypeId`].
+    Cont(TypeId),
+}
+
+impl CompositeType {
+    /// Returns the [`TypeKind`] of the [`CompositeType`].
+    pub fn kind(&self) -> TypeKind {
+        match self {
+            Self::Func(_) => TypeKind::Func,
+            Self::Struct(_) => TypeKind::Struct,
+            Self::Array(_) => TypeKind::Array,
+            Self::Cont(_) => TypeKind::Cont,
+        }
+    }
+
+    /// Returns a copy of `self` with all concrete [`TypeId`]s mapped by `f`.
+    fn map_type_ids(&self, f: &impl Fn(TypeId) -> TypeId) -> Self {
+        let map_field = |field: &FieldType| {
+            let storage = match field.storage() {
+                StorageType::Val(ty) => StorageType::Val(map_val_type(ty, f)),
+                storage => storage,
+            };
+            FieldType::new(storage, field.mutability())
+        };
+        match self {
+            Self::Func(func_type) => Self::Func(FuncType::new(
+                func_type.params().iter().map(|ty| map_val_type(*ty, f)),
+                func_type.results().iter().map(|ty| map_val_type(*ty, f)),
+            )),
+            Self::Struct(struct_type) => {
+                Self::Struct(StructType::new(struct_type.fields().iter().map(map_field)))
+            }
+            Self::Array(array_type) => {
+                Self::Array(ArrayType::new(map_field(&array_type.element())))
+            }
+            Self::Cont(func_type) => Self::Cont(f(*func_type)),
+        }
+    }
+}
+
+/// Returns `ty` with its concrete [`TypeId`] mapped by `f` if any.
+fn map_val_type(ty: ValType, f: &impl Fn(TypeId) -> TypeId) -> ValType {
+    match ty.ref_type() {
+        Some(ref_type) => match ref_type.heap_type() {
+            HeapType::Concrete(type_id) => ValType::from(RefType::new(
+                ref_type.is_nullable(),
+                HeapType::Concrete(f(type_id)),
+            )),
+            _ => ty,
+        },
+        None => ty,
+    }
+}
+
+/// A Wasm type definition with its declared supertype.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SubType {
+    /// Whether the type is final and thus cannot have subtypes.
+    pub is_final: bool,
+    /// The declared direct supertype if any.
+    pub supertype: Option<TypeId>,
+    /// The underlying composite type.
+    pub composite: CompositeType,
+}
+
+impl SubType {
+    /// Creates a final [`SubType`] without supertype for `func_type`.
+    pub fn final_func(func_type: FuncType) -> Self {
+        Self {
+            is_final: true,
+            supertype: None,
+            composite: CompositeType::Func(func_type),
+        }
+    }
+
+    /// Returns a copy of `self` with all concrete [`TypeId`]s mapped by `f`.
+    fn map_type_ids(&self, f: &impl Fn(TypeId) -> TypeId) -> Self {
+        Self {
+            is_final: self.is_final,
+            supertype: self.supertype.map(f),
+            composite: self.composite.map_type_ids(f),
+        }
+    }
+}
+
+/// A type registered in the [`FuncTypeRegistry`].
+#[derive(Debug)]
+struct RegisteredType {
+    /// The type with all of its type references resolved.
+    sub: SubType,
+    /// The chain of all supertypes of the type starting at its root supertype.
+    supertypes: Box<[TypeId]>,
+}
+
 /// A [`FuncType`] registry that efficiently deduplicate stored function types.
 ///
 /// Can also be used to later resolve deduplicated function types into their
@@ -62,6 +191,13 @@ impl DedupFuncType {
 /// deduplicated [`FuncType`] instances is as fast as comparing integer values.
 /// Also with respect to Wasmi bytecode deduplicated [`FuncType`] entities
 /// require a lot less space to be stored.
+///
+/// # Wasm `gc`
+///
+/// Types are registered as whole recursion groups which are canonicalized
+/// iso-recursively: types referring to other types of the same recursion group
+/// do so via [`FuncTypeRegistry::rec_group_type_id`] so that structurally equal
+/// recursion groups are deduplicated to the same registered types.
 #[derive(Debug)]
 pub struct FuncTypeRegistry {
     /// A unique identifier for the associated engine.
@@ -70,24 +206,38 @@ pub struct FuncTypeRegistry {
     ///
     /// This is used to guard against invalid entity indices.
     engine_idx: EngineIdx,
-    /// Deduplicated function types.
-    ///
-    /// # Note
-    ///
-    /// The engine deduplicates function types to make the equality
-    /// comparison very fast. This helps to speed up indirect calls.
-    func_types: DedupArena<DedupFuncTypeIdx, FuncType>,
+    /// Deduplicated recursion groups in their canonical form.
+    rec_groups: DedupArena<RecGroupIdx, Box<[SubType]>>,
+    /// The index of the first registered type of each recursion group.
+    rec_group_starts: Vec<u32>,
+    /// All registered types indexed by [`TypeId::index`].
+    types: Vec<RegisteredType>,
 }
 
 impl FuncTypeRegistry {
+    /// The index offset of [`TypeId`]s referring to types of the same recursion group.
+    const REC_GROUP_OFFSET: u32 = 1 << 26;
+
     /// Creates a new [`FuncTypeRegistry`] using the given [`EngineIdx`].
     pub(crate) fn new(engine_idx: EngineIdx) -> Self {
         Self {
             engine_idx,
-            func_types: DedupArena::default(),
+            rec_groups: DedupArena::default(),
+            rec_group_starts: Vec::new(),
+            types: Vec::new(),
         }
     }
 
+    /// Returns the [`TypeId`] referring to the `index`-th type of the recursion group
+    /// that is currently being registered.
+    ///
+    /// # Note
+    ///
+    /// Used to construct the canonical form of recursion groups for [`FuncTypeRegistry::alloc_rec_group`].
+    pub(crate) fn rec_group_type_id(kind: TypeKind, index: u32) -> TypeId {
+        TypeId::new(kind, Self::REC_GROUP_OFFSET + index)
+    }
+
     /// Unpacks the entity and checks if it is owned by the engine.
     ///
     /// # Panics
@@ -107,22 +257,180 @@ impl FuncTypeRegistry {
 
     /// Allocates a new function type to the engine.
     pub(crate) fn alloc_func_type(&mut self, func_type: FuncType) -> DedupFuncType {
+        let types = self.alloc_rec_group([SubType::final_func(func_type)].into());
+        types[0]
+    }
+
+    /// Allocates the recursion group in its canonical form `group` to the engine.
+    ///
+    /// Returns the deduplicated types of the recursion group in order.
+    ///
+    /// # Panics
+    ///
+    /// If the type registry runs out of type indices.
+    pub(crate) fn alloc_rec_group(&mut self, group: Box<[SubType]>) -> Vec<DedupFuncType> {
+        let len_groups = self.rec_groups.len();
+        let group_idx = self.rec_groups.alloc(group);
+        if group_idx.into_usize() == len_groups {
+            // Case: the recursion group has not been registered before.
+            let group = self.rec_groups[group_idx].clone();
+            let start = u32::try_from(self.types.len())
+                .ok()
+                .filter(|start| {
+                    u64::from(*start) + group.len() as u64 <= u64::from(Self::REC_GROUP_OFFSET)
+                })
+                .unwrap_or_else(|| panic!("out of type indices in func type registry"));
+            self.rec_group_starts.push(start);
+            let resolve =
+                |type_id: TypeId| match type_id.index().checked_sub(Self::REC_GROUP_OFFSET) {
+                    Some(index) => TypeId::new(type_id.kind(), start + index),
+                    None => type_id,
+                };
+            for sub in &group[..] {
+                let sub = sub.map_type_ids(&resolve);
+                let supertypes = match sub.supertype {
+                    Some(supertype) => {
+                        let registered = &self.types[supertype.index() as usize];
+                        let mut supertypes = registered.supertypes.to_vec();
+                        supertypes.push(supertype);
+                        supertypes.into()
+                    }
+                    None => Box::default(),
+                };
+                self.types.push(RegisteredType { sub, supertypes });
+            }
+        }
+        let start = self.rec_group_starts[group_idx.into_usize()];
+        self.rec_groups[group_idx]
+            .iter()
+            .zip(start..)
+            .map(|(sub, index)| self.dedup(TypeId::new(sub.composite.kind(), index)))
+            .collect()
+    }
+
+    /// Returns the [`DedupFuncType`] for the registered `type_id`.
+    fn dedup(&self, type_id: TypeId) -> DedupFuncType {
         DedupFuncType::from_inner(Guarded::new(
             self.engine_idx,
-            self.func_types.alloc(func_type),
+            DedupFuncTypeIdx(type_id.into_u32()),
         ))
     }
 
+    /// Returns the registered type of the deduplicated type.
+    ///
+    /// # Panics
+    ///
+    /// - If the deduplicated type is not owned by the engine.
+    /// - If the deduplicated type cannot be resolved to its entity.
+    fn resolve_registered(&self, func_type: &DedupFuncType) -> &RegisteredType {
+        let type_id = self.type_id(func_type);
+        self.types
+            .get(type_id.index() as usize)
+            .unwrap_or_else(|| panic!("failed to resolve stored type: {type_id:?}"))
+    }
+
     /// Resolves a deduplicated function type into a [`FuncType`] entity.
     ///
     /// # Panics
     ///
     /// - If the deduplicated function type is not owned by the engine.
     /// - If the deduplicated function type cannot be resolved to its entity.
+    /// - If the deduplicated type is not a function type.
     pub(crate) fn resolve_func_type(&self, func_type: &DedupFuncType) -> &FuncType {
+        match &self.resolve_registered(func_type).sub.composite {
+            CompositeType::Func(func_type) => func_type,
+            composite => panic!("expected a function type but found: {composite:?}"),
+        }
+    }
+
+    /// Returns the [`SubType`] identified by `type_id` if any.
+    pub(crate) fn sub_type(&self, type_id: TypeId) -> Option<&SubType> {
+        self.types
+            .get(type_id.index() as usize)
+            .map(|registered| &registered.sub)
+            .filter(|sub| sub.composite.kind() == type_id.kind())
+    }
+
+    /// Returns the [`StructType`] identified by `type_id`.
+    ///
+    /// # Panics
+    ///
+    /// If `type_id` does not identify a registered struct type.
+    pub(crate) fn struct_type(&self, type_id: TypeId) -> &StructType {
+        match self.sub_type(type_id).map(|sub| &sub.composite) {
+            Some(CompositeType::Struct(struct_type)) => struct_type,
+            _ => panic!("expected a registered struct type: {type_id:?}"),
+        }
+    }
+
+    /// Returns the [`ArrayType`] identified by `type_id`.
+    ///
+    /// # Panics
+    ///
+    /// If `type_id` does not identify a registered array type.
+    pub(crate) fn array_type(&self, type_id: TypeId) -> &ArrayType {
+        match self.sub_type(type_id).map(|sub| &sub.composite) {
+            Some(CompositeType::Array(array_type)) => array_type,
+            _ => panic!("expected a registered array type: {type_id:?}"),
+        }
+    }
+
+    /// Returns the [`TypeId`] of the function type of the continuation type identified by `type_id`.
+    ///
+    /// # Panics
+    ///
+    /// If `type_id` does not identify a registered continuation type.
+    pub(crate) fn cont_func_type(&self, type_id: TypeId) -> TypeId {
+        match self.sub_type(type_id).map(|sub| &sub.composite) {
+            Some(CompositeType::Cont(func_type)) => *func_type,
+            _ => panic!("expected a registered continuation type: {type_id:?}"),
+        }
+    }
+
+    /// Returns the engine-wide [`TypeId`] of the deduplicated function type.
+    ///
+    /// # Panics
+    ///
+    /// If the deduplicated function type is not owned by the engine.
+    pub(crate) fn type_id(&self, func_type: &DedupFuncType) -> TypeId {
         let entity_index = self.unwrap_index(func_type.into_inner());
-        self.func_types
-            .get(entity_index)
-            .unwrap_or_else(|| panic!("failed to resolve stored function type: {entity_index:?}"))
+        TypeId::from_u32(entity_index.0)
+            .unwrap_or_else(|| panic!("invalid dedup func type index: {entity_index:?}"))
+    }
+
+    /// Returns the deduplicated function type identified by `type_id` if any.
+    pub(crate) fn dedup_by_id(&self, type_id: TypeId) -> Option<DedupFuncType> {
+        if type_id.kind() != TypeKind::Func {
+            return None;
+        }
+        self.sub_type(type_id)?;
+        Some(self.dedup(type_id))
+    }
+
+    /// Returns `true` if the type identified by `sub` is a subtype of the type identified by `sup`.
+    ///
+    /// # Note
+    ///
+    /// This takes constant time since every registered type stores its full chain of supertypes.
+    pub(crate) fn is_subtype(&self, sub: TypeId, sup: TypeId) -> bool {
+        if sub == sup {
+            return true;
+        }
+        let (Some(sub_type), Some(sup_type)) = (
+            self.types.get(sub.index() as usize),
+            self.types.get(sup.index() as usize),
+        ) else {
+            return false;
+        };
+        let depth = sup_type.supertypes.len();
+        sub_type.supertypes.get(depth) == Some(&sup)
+    }
+
+    /// Returns `true` if the heap type `sub` is a subtype of the heap type `sup`.
+    pub(crate) fn is_heap_subtype(&self, sub: HeapType, sup: HeapType) -> bool {
+        match (sub, sup) {
+            (HeapType::Concrete(sub), HeapType::Concrete(sup)) => self.is_subtype(sub, sup),
+            (sub, sup) => sub.is_subtype_of(&sup),
+        }
     }
 }
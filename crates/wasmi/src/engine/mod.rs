@@ -17,28 +17,30 @@ mod tests;
 
 pub(crate) use self::{
     block_type::BlockType,
+    code_map::{CompiledFuncRef, EpochGuard},
     config::FuelCosts,
-    executor::Stack,
+    executor::{CallIndirectCache, Stack},
     func_args::{FuncFinished, FuncParams, FuncResults},
-    func_types::DedupFuncType,
+    func_types::{CompositeType, DedupFuncType, FuncTypeRegistry, SubType},
     translator::{
         FuncTranslationDriver,
         FuncTranslator,
         FuncTranslatorAllocations,
         LazyFuncTranslator,
+        UntypedProvider,
         ValidatingFuncTranslator,
         WasmTranslator,
     },
 };
 use self::{
-    code_map::{CodeMap, CompiledFuncEntity},
-    func_types::FuncTypeRegistry,
+    code_map::{CodeMap, CompiledFuncEntity, InlinedCall, TierUpSource},
     resumable::ResumableCallBase,
 };
 pub use self::{
     code_map::{EngineFunc, EngineFuncSpan, EngineFuncSpanIter},
-    config::{CompilationMode, Config},
+    config::{CompilationMode, Config, OptimizationLevel},
     executor::ResumableHostError,
+    func_args::FuncInOut,
     limits::{EnforcedLimits, EnforcedLimitsError, StackLimits},
     resumable::{ResumableCall, ResumableInvocation, TypedResumableCall, TypedResumableInvocation},
     traits::{CallParams, CallResults},
@@ -46,6 +48,7 @@ pub use self::{
 };
 use crate::{
     collections::arena::{ArenaIndex, GuardedEntity},
+    core::TypeId,
     module::{FuncIdx, ModuleHeader},
     Error,
     Func,
@@ -53,6 +56,7 @@ use crate::{
     StoreContextMut,
 };
 use alloc::{
+    boxed::Box,
     sync::{Arc, Weak},
     vec::Vec,
 };
@@ -178,6 +182,54 @@ impl Engine {
         self.inner.resolve_func_type(func_type, f)
     }
 
+    /// Registers `func_type` with the [`Engine`] and returns its [`TypeId`].
+    ///
+    /// The returned [`TypeId`] can be used to construct concrete typed function
+    /// references via [`HeapType::Concrete`] that are valid for this [`Engine`].
+    ///
+    /// [`HeapType::Concrete`]: crate::core::HeapType::Concrete
+    pub fn register_func_type(&self, func_type: FuncType) -> TypeId {
+        let dedup = self.alloc_func_type(func_type);
+        self.func_type_id(&dedup)
+    }
+
+    /// Returns the [`FuncType`] identified by `type_id` if it is registered with the [`Engine`].
+    pub fn func_type_by_id(&self, type_id: TypeId) -> Option<FuncType> {
+        let dedup = self.dedup_func_type(type_id)?;
+        Some(self.resolve_func_type(&dedup, FuncType::clone))
+    }
+
+    /// Returns the [`TypeId`] of the deduplicated function type.
+    ///
+    /// # Panics
+    ///
+    /// If the deduplicated function type is not owned by the engine.
+    pub(crate) fn func_type_id(&self, func_type: &DedupFuncType) -> TypeId {
+        self.inner.func_types.read().type_id(func_type)
+    }
+
+    /// Returns the deduplicated function type identified by `type_id` if any.
+    pub(crate) fn dedup_func_type(&self, type_id: TypeId) -> Option<DedupFuncType> {
+        self.inner.func_types.read().dedup_by_id(type_id)
+    }
+
+    /// Allocates the recursion group in its canonical form `group` to the [`Engine`].
+    ///
+    /// Returns the deduplicated types of the recursion group in order.
+    pub(crate) fn alloc_rec_group(&self, group: Box<[SubType]>) -> Vec<DedupFuncType> {
+        self.inner.func_types.write().alloc_rec_group(group)
+    }
+
+    /// Calls `f` with the [`FuncTypeRegistry`] of the [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// Used to query struct and array types as well as subtyping between
+    /// concrete types as introduced by the Wasm `gc` proposal.
+    pub(crate) fn with_types<R>(&self, f: impl FnOnce(&FuncTypeRegistry) -> R) -> R {
+        f(&self.inner.func_types.read())
+    }
+
     /// Allocates `amount` new uninitialized [`EngineFunc`] to the [`CodeMap`].
     ///
     /// Returns a range of [`EngineFunc`]s to allow accessing the allocated [`EngineFunc`].
@@ -185,6 +237,46 @@ impl Engine {
         self.inner.alloc_funcs(amount)
     }
 
+    /// Returns the [`CompiledFuncRef`] of `func`.
+    ///
+    /// Translates `func` first if it has not yet been translated.
+    ///
+    /// # Errors
+    ///
+    /// If translation or Wasm validation of `func` failed.
+    pub(crate) fn get_compiled_func(&self, func: EngineFunc) -> Result<CompiledFuncRef<'_>, Error> {
+        self.inner.code_map.get_unprofiled(None, func)
+    }
+
+    /// Returns the [`CompiledFuncRef`] of `func` if it has already been translated.
+    ///
+    /// Returns `None` if `func` has not yet been translated or is currently being translated.
+    pub(crate) fn get_translated_func(&self, func: EngineFunc) -> Option<CompiledFuncRef<'_>> {
+        self.inner.code_map.get_compiled(func)
+    }
+
+    /// Returns an [`EpochGuard`] that keeps all queried [`CompiledFuncRef`] valid while it is alive.
+    ///
+    /// # Note
+    ///
+    /// With tiered translation a [`CompiledFuncRef`] queried via [`Engine::get_compiled_func`]
+    /// or [`Engine::get_translated_func`] must not be used after its [`EpochGuard`] is dropped.
+    /// Executions hold an [`EpochGuard`] implicitly via their [`Stack`].
+    pub(crate) fn enter_epoch(&self) -> Option<EpochGuard> {
+        self.inner.code_map.enter_epoch()
+    }
+
+    /// Calls `f` with the Wasm binary offsets, [`InlinedCall`]s and number of locals of `func`.
+    ///
+    /// Returns `None` if `func` has not yet been translated or is currently being translated.
+    pub(crate) fn with_inline_info<R>(
+        &self,
+        func: EngineFunc,
+        f: impl FnOnce(&[(u32, u32)], &[InlinedCall], usize) -> R,
+    ) -> Option<R> {
+        self.inner.code_map.with_inline_info(func, f)
+    }
+
     /// Translates the Wasm function using the [`Engine`].
     ///
     /// - Uses the internal [`Config`] to drive the function translation as mandated.
@@ -219,6 +311,42 @@ impl Engine {
             bytes,
             module,
             func_to_validate,
+            None,
+        )
+    }
+
+    /// Translates the Wasm function using the [`Engine`] and the given reusable `allocs`.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Engine::translate_func`] this does not access the reusable allocations
+    /// of the [`Engine`] which are shared by all threads and guarded by a lock.
+    /// This allows concurrent translations on different threads to not contend for them.
+    ///
+    /// # Errors
+    ///
+    /// - If function translation fails.
+    /// - If function validation fails.
+    #[cfg(feature = "std")]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn translate_func_with_allocs(
+        &self,
+        func_index: FuncIdx,
+        engine_func: EngineFunc,
+        offset: usize,
+        bytes: &[u8],
+        module: ModuleHeader,
+        func_to_validate: Option<FuncToValidate<ValidatorResources>>,
+        allocs: &mut ReusableAllocationStack,
+    ) -> Result<(), Error> {
+        self.inner.translate_func(
+            func_index,
+            engine_func,
+            offset,
+            bytes,
+            module,
+            func_to_validate,
+            Some(allocs),
         )
     }
 
@@ -261,12 +389,13 @@ impl Engine {
         &self,
         func_idx: FuncIdx,
         func: EngineFunc,
+        offset: usize,
         bytes: &[u8],
         module: &ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) {
         self.inner
-            .init_lazy_func(func_idx, func, bytes, module, func_to_validate)
+            .init_lazy_func(func_idx, func, offset, bytes, module, func_to_validate)
     }
 
     /// Executes the given [`Func`] with parameters `params`.
@@ -375,6 +504,11 @@ impl Engine {
         self.inner.resume_func(ctx, invocation, params, results)
     }
 
+    /// Returns a reusable [`Stack`] from the [`Engine`] or creates a new one.
+    pub(crate) fn reuse_or_new_stack(&self) -> Stack {
+        self.inner.reuse_or_new_stack()
+    }
+
     /// Recycles the given [`Stack`] for reuse in the [`Engine`].
     pub(crate) fn recycle_stack(&self, stack: Stack) {
         self.inner.recycle_stack(stack)
@@ -551,7 +685,10 @@ impl EngineInner {
 
     /// Translates the Wasm function using the [`Engine`].
     ///
+    /// Uses the reusable `allocs` if any and otherwise the reusable allocations of the [`Engine`].
+    ///
     /// For more information read [`Engine::translate_func`].
+    #[allow(clippy::too_many_arguments)]
     fn translate_func(
         &self,
         func_index: FuncIdx,
@@ -560,34 +697,57 @@ impl EngineInner {
         bytes: &[u8],
         module: ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
+        mut allocs: Option<&mut ReusableAllocationStack>,
     ) -> Result<(), Error> {
         let features = self.config().wasm_features();
-        match (self.config.get_compilation_mode(), func_to_validate) {
+        let compilation_mode = self.config.get_compilation_mode();
+        // Note: lazily compiled functions capture their tier-up source upon compilation.
+        let tier_up = (matches!(compilation_mode, CompilationMode::Eager)
+            && self.config.get_tier_up_threshold() != 0)
+            .then(|| TierUpSource::new(func_index, offset, bytes, module.clone()));
+        match (compilation_mode, func_to_validate) {
             (CompilationMode::Eager, Some(func_to_validate)) => {
-                let (translation_allocs, validation_allocs) = self.get_allocs();
+                let (translation_allocs, validation_allocs) =
+                    self.with_allocs(&mut allocs, |allocs| {
+                        (
+                            allocs.get_translation_allocs(),
+                            allocs.get_validation_allocs(),
+                        )
+                    });
                 let validator = func_to_validate.into_validator(validation_allocs);
                 let translator = FuncTranslator::new(func_index, module, translation_allocs)?;
                 let translator = ValidatingFuncTranslator::new(validator, translator)?;
-                let allocs = FuncTranslationDriver::new(offset, bytes, translator)?
-                    .translate(|func_entity| self.init_func(engine_func, func_entity))?;
-                self.recycle_allocs(allocs.translation, allocs.validation);
+                let recycled = FuncTranslationDriver::new(offset, bytes, translator)?.translate(
+                    |func_entity| self.init_func(engine_func, func_entity.with_tier_up(tier_up)),
+                )?;
+                self.with_allocs(&mut allocs, |allocs| {
+                    allocs.recycle_translation_allocs(recycled.translation);
+                    allocs.recycle_validation_allocs(recycled.validation);
+                });
             }
             (CompilationMode::Eager, None) => {
-                let allocs = self.get_translation_allocs();
-                let translator = FuncTranslator::new(func_index, module, allocs)?;
-                let allocs = FuncTranslationDriver::new(offset, bytes, translator)?
-                    .translate(|func_entity| self.init_func(engine_func, func_entity))?;
-                self.recycle_translation_allocs(allocs);
+                let translation_allocs =
+                    self.with_allocs(&mut allocs, ReusableAllocationStack::get_translation_allocs);
+                let translator = FuncTranslator::new(func_index, module, translation_allocs)?;
+                let recycled = FuncTranslationDriver::new(offset, bytes, translator)?.translate(
+                    |func_entity| self.init_func(engine_func, func_entity.with_tier_up(tier_up)),
+                )?;
+                self.with_allocs(&mut allocs, |allocs| {
+                    allocs.recycle_translation_allocs(recycled)
+                });
             }
             (CompilationMode::LazyTranslation, Some(func_to_validate)) => {
-                let allocs = self.get_validation_allocs();
+                let validation_allocs =
+                    self.with_allocs(&mut allocs, ReusableAllocationStack::get_validation_allocs);
                 let translator =
                     LazyFuncTranslator::new_unchecked(func_index, engine_func, module, features);
-                let validator = func_to_validate.into_validator(allocs);
+                let validator = func_to_validate.into_validator(validation_allocs);
                 let translator = ValidatingFuncTranslator::new(validator, translator)?;
-                let allocs = FuncTranslationDriver::new(offset, bytes, translator)?
+                let recycled = FuncTranslationDriver::new(offset, bytes, translator)?
                     .translate(|func_entity| self.init_func(engine_func, func_entity))?;
-                self.recycle_validation_allocs(allocs.validation);
+                self.with_allocs(&mut allocs, |allocs| {
+                    allocs.recycle_validation_allocs(recycled.validation)
+                });
             }
             (CompilationMode::Lazy | CompilationMode::LazyTranslation, func_to_validate) => {
                 let translator = match func_to_validate {
@@ -605,16 +765,23 @@ impl EngineInner {
         Ok(())
     }
 
+    /// Calls `f` with the reusable `allocs` if any and otherwise with those of the [`Engine`].
+    fn with_allocs<R>(
+        &self,
+        allocs: &mut Option<&mut ReusableAllocationStack>,
+        f: impl FnOnce(&mut ReusableAllocationStack) -> R,
+    ) -> R {
+        match allocs {
+            Some(allocs) => f(allocs),
+            None => f(&mut self.allocs.lock()),
+        }
+    }
+
     /// Returns reusable [`FuncTranslatorAllocations`] from the [`Engine`].
     fn get_translation_allocs(&self) -> FuncTranslatorAllocations {
         self.allocs.lock().get_translation_allocs()
     }
 
-    /// Returns reusable [`FuncValidatorAllocations`] from the [`Engine`].
-    fn get_validation_allocs(&self) -> FuncValidatorAllocations {
-        self.allocs.lock().get_validation_allocs()
-    }
-
     /// Returns reusable [`FuncTranslatorAllocations`] and [`FuncValidatorAllocations`] from the [`Engine`].
     ///
     /// # Note
@@ -634,11 +801,6 @@ impl EngineInner {
         self.allocs.lock().recycle_translation_allocs(allocs)
     }
 
-    /// Recycles the given [`FuncValidatorAllocations`] in the [`Engine`].
-    fn recycle_validation_allocs(&self, allocs: FuncValidatorAllocations) {
-        self.allocs.lock().recycle_validation_allocs(allocs)
-    }
-
     /// Recycles the given [`FuncTranslatorAllocations`] and [`FuncValidatorAllocations`] in the [`Engine`].
     ///
     /// # Note
@@ -686,16 +848,36 @@ impl EngineInner {
         &self,
         func_idx: FuncIdx,
         func: EngineFunc,
+        offset: usize,
         bytes: &[u8],
         module: &ModuleHeader,
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) {
-        self.code_map
-            .init_func_as_uncompiled(func, func_idx, bytes, module, func_to_validate)
+        self.code_map.init_func_as_uncompiled(
+            func,
+            func_idx,
+            offset,
+            bytes,
+            module,
+            func_to_validate,
+        )
+    }
+
+    /// Returns a reusable [`Stack`] or creates a new one.
+    ///
+    /// The returned [`Stack`] holds an [`EpochGuard`] until it is recycled.
+    fn reuse_or_new_stack(&self) -> Stack {
+        let mut stack = self.stacks.lock().reuse_or_new();
+        stack.set_epoch(self.code_map.enter_epoch());
+        stack
     }
 
     /// Recycles the given [`Stack`].
-    fn recycle_stack(&self, stack: Stack) {
+    ///
+    /// This also frees replaced function translations that are no longer executed.
+    fn recycle_stack(&self, mut stack: Stack) {
+        stack.set_epoch(None);
+        self.code_map.reclaim();
         self.stacks.lock().recycle(stack)
     }
 }
@@ -9,6 +9,7 @@ use crate::{
     Val,
     WasmResults,
 };
+use alloc::sync::Arc;
 use core::{fmt, marker::PhantomData, mem::replace, ops::Deref};
 
 /// Returned by [`Engine`] methods for calling a function in a resumable way.
@@ -100,6 +101,12 @@ pub struct ResumableInvocation {
     ///   back to the engine when the [`ResumableInvocation`] goes out
     ///   of scope.
     pub(super) stack: Stack,
+    /// Identifies the objects referenced by the suspended `stack` that are rooted in the [`Store`].
+    ///
+    /// The objects are released by the garbage collector once the token is dropped.
+    ///
+    /// [`Store`]: crate::Store
+    gc_token: Arc<()>,
 }
 
 // # Safety
@@ -126,6 +133,7 @@ impl ResumableInvocation {
         host_error: Error,
         caller_results: RegSpan,
         stack: Stack,
+        gc_token: Arc<()>,
     ) -> Self {
         Self {
             engine,
@@ -134,9 +142,15 @@ impl ResumableInvocation {
             host_error,
             caller_results,
             stack,
+            gc_token,
         }
     }
 
+    /// Returns the token identifying the objects rooted for the suspended stack.
+    pub(super) fn gc_token(&self) -> &Arc<()> {
+        &self.gc_token
+    }
+
     /// Replaces the internal stack with an empty one that has no heap allocations.
     pub(super) fn take_stack(&mut self) -> Stack {
         replace(&mut self.stack, Stack::empty())
@@ -213,21 +227,24 @@ impl ResumableInvocation {
         inputs: &[Val],
         outputs: &mut [Val],
     ) -> Result<ResumableCall, Error> {
-        self.engine
-            .resolve_func_type(self.host_func().ty_dedup(ctx.as_context()), |func_type| {
-                func_type.match_results(inputs, true)
+        let store = &ctx.as_context().store.inner;
+        store
+            .resolve_func_type(self.host_func().ty_dedup(ctx.as_context()))
+            .match_results(inputs, |input, expected| {
+                input.matches_ty_in(store, expected)
             })?;
         self.engine
             .resolve_func_type(self.func.ty_dedup(ctx.as_context()), |func_type| {
-                func_type.match_results(outputs, false)?;
+                func_type.match_results(outputs, |_, _| true)?;
                 func_type.prepare_outputs(outputs);
                 <Result<(), Error>>::Ok(()) // TODO: why do we need types here?
             })?;
-        self.engine
-            .clone()
-            .resume_func(ctx.as_context_mut(), self, inputs, outputs)
-            .map_err(Into::into)
-            .map(ResumableCall::new)
+        let call =
+            self.engine
+                .clone()
+                .resume_func(ctx.as_context_mut(), self, inputs, &mut *outputs)?;
+        ctx.as_context().store.inner.root_host_vals(outputs);
+        Ok(ResumableCall::new(call))
     }
 }
 
@@ -293,9 +310,11 @@ impl<Results> TypedResumableInvocation<Results> {
     where
         Results: WasmResults,
     {
-        self.engine
-            .resolve_func_type(self.host_func().ty_dedup(ctx.as_context()), |func_type| {
-                func_type.match_results(inputs, true)
+        let store = &ctx.as_context().store.inner;
+        store
+            .resolve_func_type(self.host_func().ty_dedup(ctx.as_context()))
+            .match_results(inputs, |input, expected| {
+                input.matches_ty_in(store, expected)
             })?;
         self.engine
             .clone()
@@ -305,7 +324,6 @@ impl<Results> TypedResumableInvocation<Results> {
                 inputs,
                 <CallResultsTuple<Results>>::default(),
             )
-            .map_err(Into::into)
             .map(TypedResumableCall::new)
     }
 }
@@ -0,0 +1,103 @@
+//! This submodule tests that the decoded bytecode cache is bounded and frees evicted functions once no longer executed.
+
+use crate::{Caller, Config, Engine, Error, Linker, Module, Store, TypedFunc, TypedResumableCall};
+
+/// A Wasm module with a function that calls a host function in a loop via another function.
+const WAT: &str = r#"
+    (module
+        (import "host" "tick" (func $tick (param i32)))
+        (func $step (param $i i32)
+            (call $tick (local.get $i))
+        )
+        (func (export "run") (param $n i32) (result i32)
+            (local $i i32)
+            (loop $continue
+                (call $step (local.get $i))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $continue (i32.lt_u (local.get $i) (local.get $n)))
+            )
+            (local.get $i)
+        )
+        (func (export "double") (param $n i32) (result i32)
+            (i32.add (local.get $n) (local.get $n))
+        )
+    )
+"#;
+
+/// Instantiates [`WAT`] with a decoded bytecode cache that fits only a single function.
+///
+/// Returns the `run` and `double` functions.
+///
+/// The `tick` host function returns an error when called with the value stored in the [`Store`].
+fn setup() -> (Store<i32>, TypedFunc<i32, i32>, TypedFunc<i32, i32>) {
+    let mut config = Config::default();
+    config.max_inline_len(100).decoded_cache_size(1);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, -1);
+    let mut linker = <Linker<i32>>::new(&engine);
+    linker
+        .func_wrap("host", "tick", |caller: Caller<i32>, i: i32| {
+            if *caller.data() == i {
+                return Err(Error::new("tick"));
+            }
+            Ok(())
+        })
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+    let double = instance
+        .get_typed_func::<i32, i32>(&store, "double")
+        .unwrap();
+    (store, run, double)
+}
+
+#[test]
+fn evicted_decoded_freed_after_return() {
+    let (mut store, run, double) = setup();
+    // Note: `run` and `$step` evict each other while both are executing.
+    assert_eq!(run.call(&mut store, 20).unwrap(), 20);
+    assert_eq!(store.engine().len_decoded_funcs(), 1);
+    assert_eq!(store.engine().len_evicted_funcs(), 0);
+    assert_eq!(double.call(&mut store, 21).unwrap(), 42);
+    assert_eq!(run.call(&mut store, 20).unwrap(), 20);
+    assert_eq!(store.engine().len_decoded_funcs(), 1);
+    assert_eq!(store.engine().len_evicted_funcs(), 0);
+}
+
+#[test]
+fn evicted_decoded_kept_while_resumable() {
+    let (mut store, run, double) = setup();
+    *store.data_mut() = 10;
+    let TypedResumableCall::Resumable(invocation) = run.call_resumable(&mut store, 20).unwrap()
+    else {
+        panic!("expected a resumable call")
+    };
+    // Note: the suspended `run` and `$step` are still executing their evicted decoded instructions.
+    assert_eq!(double.call(&mut store, 21).unwrap(), 42);
+    assert!(store.engine().len_evicted_funcs() >= 2);
+    *store.data_mut() = -1;
+    let TypedResumableCall::Finished(result) = invocation.resume(&mut store, &[]).unwrap() else {
+        panic!("expected a finished call")
+    };
+    assert_eq!(result, 20);
+    assert_eq!(store.engine().len_evicted_funcs(), 0);
+}
+
+#[test]
+fn evicted_decoded_freed_after_dropped_resumable() {
+    let (mut store, run, double) = setup();
+    *store.data_mut() = 10;
+    let TypedResumableCall::Resumable(invocation) = run.call_resumable(&mut store, 20).unwrap()
+    else {
+        panic!("expected a resumable call")
+    };
+    drop(invocation);
+    assert_eq!(double.call(&mut store, 21).unwrap(), 42);
+    assert_eq!(store.engine().len_evicted_funcs(), 0);
+}
@@ -1,5 +1,8 @@
+#[cfg(feature = "compact-bytecode")]
+mod decoded_cache;
 mod host_calls;
 mod many_inout;
+mod tiered;
 
 use super::{
     code_map::{CompiledFuncRef, EngineFunc},
@@ -57,6 +60,38 @@ impl Engine {
     ) -> Result<Option<UntypedVal>, Error> {
         self.inner.get_func_const(func, index)
     }
+
+    /// Returns the number of function translations replaced by tiered translation that are not yet freed.
+    ///
+    /// # Note
+    ///
+    /// This API is intended for unit testing purposes and shall not be used
+    /// outside of this context.
+    pub(crate) fn len_replaced_funcs(&self) -> usize {
+        self.inner.code_map.len_replaced()
+    }
+
+    /// Returns the number of evicted decoded function bodies that are not yet freed.
+    ///
+    /// # Note
+    ///
+    /// This API is intended for unit testing purposes and shall not be used
+    /// outside of this context.
+    #[cfg(feature = "compact-bytecode")]
+    pub(crate) fn len_evicted_funcs(&self) -> usize {
+        self.inner.code_map.len_evicted()
+    }
+
+    /// Returns the number of functions with cached decoded function bodies.
+    ///
+    /// # Note
+    ///
+    /// This API is intended for unit testing purposes and shall not be used
+    /// outside of this context.
+    #[cfg(feature = "compact-bytecode")]
+    pub(crate) fn len_decoded_funcs(&self) -> usize {
+        self.inner.code_map.len_decoded()
+    }
 }
 
 impl EngineInner {
@@ -0,0 +1,96 @@
+//! This submodule tests that translations replaced by tiered translation are freed once no longer executed.
+
+use crate::{Caller, Config, Engine, Error, Linker, Module, Store, TypedFunc, TypedResumableCall};
+
+/// A Wasm module with a function that calls a host function in a loop.
+///
+/// The host function is called indirectly via `$step` since only calls
+/// to Wasm functions account the loop iterations of `run` to its hotness.
+const WAT: &str = r#"
+    (module
+        (import "host" "tick" (func $tick (param i32)))
+        (func $step (param $i i32)
+            (call $tick (local.get $i))
+        )
+        (func (export "run") (param $n i32) (result i32)
+            (local $i i32)
+            (loop $continue
+                (call $step (local.get $i))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $continue (i32.lt_u (local.get $i) (local.get $n)))
+            )
+            (local.get $i)
+        )
+    )
+"#;
+
+/// Instantiates [`WAT`] with tiered translation and returns the `run` function.
+///
+/// The `tick` host function returns an error when called with the value stored in the [`Store`].
+fn setup() -> (Store<i32>, TypedFunc<i32, i32>) {
+    let mut config = Config::default();
+    config.max_inline_len(100).tier_up_threshold(5);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, -1);
+    let mut linker = <Linker<i32>>::new(&engine);
+    linker
+        .func_wrap("host", "tick", |caller: Caller<i32>, i: i32| {
+            if *caller.data() == i {
+                return Err(Error::new("tick"));
+            }
+            Ok(())
+        })
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+    (store, run)
+}
+
+#[test]
+fn replaced_translation_freed_after_return() {
+    let (mut store, run) = setup();
+    // Note: `run` is re-translated while still executing its initial translation.
+    assert_eq!(run.call(&mut store, 20).unwrap(), 20);
+    assert_eq!(store.engine().len_replaced_funcs(), 0);
+    assert_eq!(run.call(&mut store, 20).unwrap(), 20);
+    assert_eq!(store.engine().len_replaced_funcs(), 0);
+}
+
+#[test]
+fn replaced_translation_kept_while_resumable() {
+    let (mut store, run) = setup();
+    *store.data_mut() = 10;
+    let TypedResumableCall::Resumable(invocation) = run.call_resumable(&mut store, 20).unwrap()
+    else {
+        panic!("expected a resumable call")
+    };
+    // Note: the suspended `run` and `$step` are still executing their initial translations.
+    assert_eq!(store.engine().len_replaced_funcs(), 2);
+    *store.data_mut() = -1;
+    assert_eq!(run.call(&mut store, 20).unwrap(), 20);
+    assert_eq!(store.engine().len_replaced_funcs(), 2);
+    let TypedResumableCall::Finished(result) = invocation.resume(&mut store, &[]).unwrap() else {
+        panic!("expected a finished call")
+    };
+    assert_eq!(result, 20);
+    assert_eq!(store.engine().len_replaced_funcs(), 0);
+}
+
+#[test]
+fn replaced_translation_freed_after_dropped_resumable() {
+    let (mut store, run) = setup();
+    *store.data_mut() = 10;
+    let TypedResumableCall::Resumable(invocation) = run.call_resumable(&mut store, 20).unwrap()
+    else {
+        panic!("expected a resumable call")
+    };
+    assert_eq!(store.engine().len_replaced_funcs(), 2);
+    drop(invocation);
+    assert_eq!(store.engine().len_replaced_funcs(), 0);
+}
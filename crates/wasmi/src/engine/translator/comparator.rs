@@ -90,6 +90,11 @@ pub trait TryIntoCmpBranchInstr: Sized {
         offset: BranchOffset,
         stack: &mut ValueStack,
     ) -> Result<Option<Self>, Error>;
+
+    /// Tries to fuse the compare (`cmp`) [`Instruction`] into a `cmp+branch` [`Instruction`] with 16-bit `offset`.
+    ///
+    /// Returns `None` if `self` cannot be fused.
+    fn try_into_cmp_branch_instr16(&self, offset: BranchOffset16) -> Option<Self>;
 }
 
 impl TryIntoCmpBranchInstr for Instruction {
@@ -98,10 +103,14 @@ impl TryIntoCmpBranchInstr for Instruction {
         offset: BranchOffset,
         stack: &mut ValueStack,
     ) -> Result<Option<Self>, Error> {
-        use Instruction as I;
         let Ok(offset) = BranchOffset16::try_from(offset) else {
             return self.try_into_cmp_branch_fallback_instr(offset, stack);
         };
+        Ok(self.try_into_cmp_branch_instr16(offset))
+    }
+
+    fn try_into_cmp_branch_instr16(&self, offset: BranchOffset16) -> Option<Self> {
+        use Instruction as I;
         #[rustfmt::skip]
         let cmp_branch_instr = match *self {
             // i32
@@ -161,9 +170,9 @@ impl TryIntoCmpBranchInstr for Instruction {
             I::F64Ne { lhs, rhs, .. } => I::branch_f64_ne(lhs, rhs, offset),
             I::F64Lt { lhs, rhs, .. } => I::branch_f64_lt(lhs, rhs, offset),
             I::F64Le { lhs, rhs, .. } => I::branch_f64_le(lhs, rhs, offset),
-            _ => return Ok(None),
+            _ => return None,
         };
-        Ok(Some(cmp_branch_instr))
+        Some(cmp_branch_instr)
     }
 }
 
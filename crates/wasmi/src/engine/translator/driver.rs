@@ -40,6 +40,8 @@ where
         mut self,
         finalize: impl FnOnce(CompiledFuncEntity),
     ) -> Result<T::Allocations, Error> {
+        let offset = self.func_body.get_binary_reader().original_position();
+        self.translator.update_pos(offset);
         if self.translator.setup(self.bytes)? {
             let allocations = self.translator.finish(finalize)?;
             return Ok(allocations);
@@ -57,7 +59,7 @@ where
         finalize: impl FnOnce(CompiledFuncEntity),
     ) -> Result<T::Allocations, Error> {
         self.translator.update_pos(offset);
-        self.translator.finish(finalize).map_err(Into::into)
+        self.translator.finish(finalize)
     }
 
     /// Translates local variables of the Wasm function.
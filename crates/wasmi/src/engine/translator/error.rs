@@ -29,6 +29,10 @@ pub enum TranslationError {
     TooManyFunctionParams,
     /// The function failed to compiled lazily.
     LazyCompilationFailed,
+    /// Encountered an unsupported Wasm operator.
+    UnsupportedOperator(&'static str),
+    /// Encountered an imported or exported Wasm tag.
+    UnsupportedExternalTag,
 }
 
 impl TranslationError {
@@ -103,6 +107,12 @@ impl Display for TranslationError {
                     "lazy function compilation encountered a Wasm validation or translation error"
                 )
             }
+            Self::UnsupportedOperator(op) => {
+                write!(f, "encountered unsupported Wasm operator: {op}")
+            }
+            Self::UnsupportedExternalTag => {
+                write!(f, "imported or exported Wasm tags are not supported")
+            }
         }
     }
 }
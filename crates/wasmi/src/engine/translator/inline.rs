@@ -0,0 +1,488 @@
+use super::{FuelInfo, FuncTranslator, TypedProvider, ValueStack};
+use crate::{
+    core::UntypedVal,
+    engine::{code_map::InlinedCall, EngineFunc},
+    ir::{BoundedRegSpan, Const32, Instruction, Reg, RegSpan, VisitRegs},
+    Error,
+};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+impl FuncTranslator {
+    /// Tries to inline the call to the internal function `callee`.
+    ///
+    /// Returns `Ok(true)` if the call has been inlined in which case the `len_params`
+    /// call parameters on top of the [`ValueStack`] have been replaced by the `len_results`
+    /// call results. Otherwise returns `Ok(false)` and leaves the [`ValueStack`] untouched.
+    ///
+    /// # Note
+    ///
+    /// A call is inlined if `callee`
+    ///
+    /// - has already been translated,
+    /// - consists of no more instructions than allowed by [`Config::max_inline_len`],
+    /// - contains neither control flow nor calls and
+    /// - returns at most a single value.
+    ///
+    /// The registers of `callee` are relocated to fresh dynamic registers of the caller.
+    /// Since `callee` must already be translated recursive calls are never inlined.
+    ///
+    /// If Wasm binary offsets are recorded the inlined instructions keep the Wasm binary
+    /// offsets of `callee` and are registered as [`InlinedCall`] so that Wasm backtraces
+    /// and coredumps still contain a frame for `callee`.
+    ///
+    /// [`Config::max_inline_len`]: crate::Config::max_inline_len
+    pub(super) fn try_inline_call(
+        &mut self,
+        callee: EngineFunc,
+        len_params: usize,
+        len_results: usize,
+    ) -> Result<bool, Error> {
+        if self.max_inline_len == 0 || len_results > 1 {
+            return Ok(false);
+        }
+        let _epoch = self.engine.enter_epoch();
+        let Some(func) = self.engine.get_translated_func(callee) else {
+            return Ok(false);
+        };
+        let instrs = func.instrs();
+        if instrs.len() > self.max_inline_len as usize {
+            return Ok(false);
+        }
+        let Some((ret, body)) = instrs.split_last() else {
+            return Ok(false);
+        };
+        if !is_inlinable_return(ret) || !body.iter().all(is_inlinable) {
+            return Ok(false);
+        }
+        let consts = func.consts();
+        let Some(len_frame) = usize::from(func.len_registers()).checked_sub(consts.len()) else {
+            return Ok(false);
+        };
+        let buffer = &mut self.alloc.buffer;
+        if !find_uninit_reads(
+            instrs,
+            len_params,
+            len_frame,
+            &mut buffer.written,
+            &mut buffer.uninit,
+        ) {
+            return Ok(false);
+        }
+        if self.local_types {
+            // Note: all function locals of `callee` are observable via Wasm coredumps
+            //       and thus must be zero initialized just like upon calling `callee`.
+            let len_locals = self
+                .engine
+                .with_inline_info(callee, |_, _, len_locals| len_locals)
+                .unwrap_or_default();
+            for index in len_params..len_locals.min(len_frame) {
+                let index = index as i16;
+                if !buffer.uninit.contains(&index) {
+                    buffer.uninit.push(index);
+                }
+            }
+        }
+        // Note: the registers of `callee` are allocated above all call parameters
+        //       so that copying the call parameters into them never overlaps.
+        let frame = self.alloc.stack.peek_dynamic_n(len_frame)?;
+        let params = &mut self.alloc.buffer.providers;
+        self.alloc.stack.pop_n(len_params, params);
+        let results = self.alloc.stack.push_dynamic_n(len_results)?;
+        // Note: inlining must not change fuel consumption. The caller has already been
+        //       charged for the call and `callee` contains its own `ConsumeFuel` instruction.
+        self.alloc.instr_encoder.encode_copies(
+            &mut self.alloc.stack,
+            BoundedRegSpan::new(frame, len_params as u16),
+            params,
+            FuelInfo::None,
+        )?;
+        let mut relocator = Relocator::new(frame, consts, &mut self.alloc.stack);
+        for &index in &self.alloc.buffer.uninit {
+            // Note: function locals of `callee` that are read before they are written
+            //       must be zero initialized just like upon calling `callee`.
+            let local = relocator.relocate(Reg::from(index));
+            self.alloc
+                .instr_encoder
+                .push_instr(Instruction::copy_i64imm32(local, <Const32<i64>>::from(0)))?;
+        }
+        let start = self.alloc.instr_encoder.next_instr().into_u32();
+        for instr in body {
+            let mut instr = *instr;
+            instr.visit_regs(&mut relocator);
+            relocator.result()?;
+            match is_param_word(&instr) {
+                true => self.alloc.instr_encoder.append_instr(instr)?,
+                false => self.alloc.instr_encoder.push_instr(instr)?,
+            };
+        }
+        let end = self.alloc.instr_encoder.next_instr().into_u32();
+        let result = results.head();
+        match *ret {
+            Instruction::Return => {}
+            Instruction::ReturnReg { value } => {
+                let value = relocator.relocate(value);
+                relocator.result()?;
+                self.alloc.instr_encoder.encode_copy(
+                    &mut self.alloc.stack,
+                    result,
+                    TypedProvider::Register(value),
+                    FuelInfo::None,
+                )?;
+            }
+            Instruction::ReturnImm32 { value } => {
+                self.alloc
+                    .instr_encoder
+                    .push_instr(Instruction::copy_imm32(result, value))?;
+            }
+            Instruction::ReturnI64Imm32 { value } => {
+                self.alloc
+                    .instr_encoder
+                    .push_instr(Instruction::copy_i64imm32(result, value))?;
+            }
+            Instruction::ReturnF64Imm32 { value } => {
+                self.alloc
+                    .instr_encoder
+                    .push_instr(Instruction::copy_f64imm32(result, value))?;
+            }
+            _ => unreachable!("encountered non-inlinable return instruction: {ret:?}"),
+        }
+        if self.wasm_offsets && start != end {
+            self.record_inlined_call(callee, frame, start..end);
+        }
+        Ok(true)
+    }
+
+    /// Records the Wasm binary offsets of the inlined `callee` whose instructions are at `instrs`.
+    ///
+    /// - The registers of `callee` are relocated to the caller registers starting at `frame`.
+    /// - The instructions following the inlined instructions are associated to the inlined call.
+    fn record_inlined_call(&mut self, callee: EngineFunc, frame: RegSpan, instrs: Range<u32>) {
+        let Range { start, end } = instrs;
+        let Some((call_instr, call_offset)) = self.alloc.wasm_offsets.last().copied() else {
+            return;
+        };
+        if call_instr == start {
+            // Note: the inlined call did not produce any instructions before the inlined ones.
+            self.alloc.wasm_offsets.pop();
+        }
+        let frame = i16::from(frame.head());
+        let len_body = end - start;
+        let wasm_offsets = &mut self.alloc.wasm_offsets;
+        let inlined_calls = &mut self.alloc.inlined_calls;
+        self.engine
+            .with_inline_info(callee, |offsets, calls, _| {
+                inlined_calls.push(InlinedCall {
+                    start,
+                    end,
+                    callee,
+                    call_offset,
+                    frame,
+                });
+                // Note: the calls inlined into `callee` are nested within the inlined call.
+                inlined_calls.extend(calls.iter().map(|call| InlinedCall {
+                    start: start + call.start,
+                    end: start + call.end,
+                    frame: frame + call.frame,
+                    ..*call
+                }));
+                wasm_offsets.extend(
+                    offsets
+                        .iter()
+                        .filter(|(instr, _)| *instr < len_body)
+                        .map(|(instr, offset)| (start + instr, *offset)),
+                );
+            })
+            .unwrap_or_default();
+        self.alloc.wasm_offsets.push((end, call_offset));
+    }
+}
+
+/// Returns `true` if `instr` may end an inlined function.
+fn is_inlinable_return(instr: &Instruction) -> bool {
+    matches!(
+        instr,
+        Instruction::Return
+            | Instruction::ReturnReg { .. }
+            | Instruction::ReturnImm32 { .. }
+            | Instruction::ReturnI64Imm32 { .. }
+            | Instruction::ReturnF64Imm32 { .. }
+    )
+}
+
+/// Returns `true` if `instr` may be part of the body of an inlined function.
+///
+/// # Note
+///
+/// This excludes all control flow and call instructions.
+fn is_inlinable(instr: &Instruction) -> bool {
+    if instr.branch_offset().is_some() {
+        return false;
+    }
+    !matches!(
+        instr,
+        Instruction::Trap { .. }
+            | Instruction::Return
+            | Instruction::ReturnReg { .. }
+            | Instruction::ReturnReg2 { .. }
+            | Instruction::ReturnReg3 { .. }
+            | Instruction::ReturnImm32 { .. }
+            | Instruction::ReturnI64Imm32 { .. }
+            | Instruction::ReturnF64Imm32 { .. }
+            | Instruction::ReturnSpan { .. }
+            | Instruction::ReturnMany { .. }
+            | Instruction::ReturnNez { .. }
+            | Instruction::ReturnNezReg { .. }
+            | Instruction::ReturnNezReg2 { .. }
+            | Instruction::ReturnNezImm32 { .. }
+            | Instruction::ReturnNezI64Imm32 { .. }
+            | Instruction::ReturnNezF64Imm32 { .. }
+            | Instruction::ReturnNezSpan { .. }
+            | Instruction::ReturnNezMany { .. }
+            | Instruction::BranchTable0 { .. }
+            | Instruction::BranchTable1 { .. }
+            | Instruction::BranchTable2 { .. }
+            | Instruction::BranchTable3 { .. }
+            | Instruction::BranchTableSpan { .. }
+            | Instruction::BranchTableMany { .. }
+            | Instruction::BranchTableTarget { .. }
+            | Instruction::BranchTableTargetNonOverlapping { .. }
+            | Instruction::CallInternal0 { .. }
+            | Instruction::CallInternal { .. }
+            | Instruction::CallImported0 { .. }
+            | Instruction::CallImported { .. }
+            | Instruction::CallIndirect0 { .. }
+            | Instruction::CallIndirect0Imm16 { .. }
+            | Instruction::CallIndirect { .. }
+            | Instruction::CallIndirectImm16 { .. }
+            | Instruction::CallIndirectParams { .. }
+            | Instruction::CallIndirectParamsImm16 { .. }
+            | Instruction::CallRef0 { .. }
+            | Instruction::CallRef { .. }
+            | Instruction::ReturnCallInternal0 { .. }
+            | Instruction::ReturnCallInternal { .. }
+            | Instruction::ReturnCallImported0 { .. }
+            | Instruction::ReturnCallImported { .. }
+            | Instruction::ReturnCallIndirect0 { .. }
+            | Instruction::ReturnCallIndirect0Imm16 { .. }
+            | Instruction::ReturnCallIndirect { .. }
+            | Instruction::ReturnCallIndirectImm16 { .. }
+            | Instruction::ReturnCallRef0 { .. }
+            | Instruction::ReturnCallRef { .. }
+    )
+}
+
+/// Returns `true` if `instr` only carries parameters of its preceding [`Instruction`].
+fn is_param_word(instr: &Instruction) -> bool {
+    matches!(
+        instr,
+        Instruction::TableIndex { .. }
+            | Instruction::MemoryIndex { .. }
+            | Instruction::Register { .. }
+            | Instruction::Register2 { .. }
+            | Instruction::Register3 { .. }
+            | Instruction::RegisterList { .. }
+            | Instruction::RegisterSpan { .. }
+            | Instruction::RegisterAndImm32 { .. }
+            | Instruction::Imm16AndImm32 { .. }
+    )
+}
+
+/// Finds all non-parameter registers of the straight-line `instrs` that are read before written.
+///
+/// The indices of the found registers are stored in `uninit`.
+///
+/// Returns `false` if `instrs` cannot be analyzed, e.g. if they use an unbounded [`RegSpan`].
+fn find_uninit_reads(
+    instrs: &[Instruction],
+    len_params: usize,
+    len_frame: usize,
+    written: &mut Vec<bool>,
+    uninit: &mut Vec<i16>,
+) -> bool {
+    written.clear();
+    written.resize(len_frame, false);
+    written[..len_params].fill(true);
+    uninit.clear();
+    let mut scanner = UninitScanner {
+        written,
+        uninit,
+        results: false,
+        invalid: false,
+    };
+    let mut start = 0;
+    while start < instrs.len() {
+        // Note: an instruction reads the registers of its parameter words
+        //       before it writes its own results.
+        let len_words = instrs[start + 1..]
+            .iter()
+            .take_while(|instr| is_param_word(instr))
+            .count();
+        let group = &instrs[start..=start + len_words];
+        for results in [false, true] {
+            scanner.results = results;
+            for instr in group {
+                let mut instr = *instr;
+                instr.visit_regs(&mut scanner);
+            }
+        }
+        start += len_words + 1;
+    }
+    !scanner.invalid
+}
+
+/// Finds the registers of an inlined function that are read before written via [`VisitRegs`].
+struct UninitScanner<'a> {
+    /// Is `true` for every non-constant register that has been written.
+    written: &'a mut [bool],
+    /// The registers that are read before written.
+    uninit: &'a mut Vec<i16>,
+    /// Is `true` if result registers are visited, otherwise input registers are visited.
+    results: bool,
+    /// Is `true` if the visited instructions cannot be analyzed.
+    invalid: bool,
+}
+
+impl UninitScanner<'_> {
+    /// Returns the index of the non-constant `reg` if it is in bounds.
+    fn index(&mut self, reg: Reg) -> Option<usize> {
+        let index = i16::from(reg) as usize;
+        if reg.is_const() || index >= self.written.len() {
+            self.invalid = true;
+            return None;
+        }
+        Some(index)
+    }
+
+    /// Processes a read of `reg`.
+    fn read(&mut self, reg: Reg) {
+        if reg.is_const() {
+            return;
+        }
+        let Some(index) = self.index(reg) else {
+            return;
+        };
+        if !self.written[index] {
+            // Note: marking the register as written avoids duplicate zero initializations.
+            self.written[index] = true;
+            self.uninit.push(index as i16);
+        }
+    }
+
+    /// Processes a write of `reg`.
+    fn write(&mut self, reg: Reg) {
+        if let Some(index) = self.index(reg) {
+            self.written[index] = true;
+        }
+    }
+
+    /// Processes the [`RegSpan`] `regs` with `len` registers using `f`.
+    ///
+    /// # Note
+    ///
+    /// Unbounded [`RegSpan`]s and [`RegSpan`]s of constant values cannot be relocated.
+    fn span(&mut self, regs: RegSpan, len: Option<u16>, f: fn(&mut Self, Reg)) {
+        let Some(len) = len.filter(|_| !regs.head().is_const()) else {
+            self.invalid = true;
+            return;
+        };
+        for reg in regs.iter(len) {
+            f(self, reg)
+        }
+    }
+}
+
+impl VisitRegs for UninitScanner<'_> {
+    fn visit_result_reg(&mut self, reg: &mut Reg) {
+        if self.results {
+            self.write(*reg)
+        }
+    }
+
+    fn visit_result_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        if self.results {
+            self.span(*regs, len, Self::write)
+        }
+    }
+
+    fn visit_input_reg(&mut self, reg: &mut Reg) {
+        if !self.results {
+            self.read(*reg)
+        }
+    }
+
+    fn visit_input_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        if !self.results {
+            self.span(*regs, len, Self::read)
+        }
+    }
+}
+
+/// Relocates the registers of an inlined function into the registers of its caller via [`VisitRegs`].
+struct Relocator<'a> {
+    /// The first caller register used for the non-constant registers of the inlined function.
+    frame: i16,
+    /// The function local constant values of the inlined function in reversed allocation order.
+    consts: &'a [UntypedVal],
+    /// The [`ValueStack`] of the caller used to allocate its function local constant values.
+    stack: &'a mut ValueStack,
+    /// The first error encountered if any.
+    error: Option<Error>,
+}
+
+impl<'a> Relocator<'a> {
+    /// Creates a new [`Relocator`].
+    fn new(frame: RegSpan, consts: &'a [UntypedVal], stack: &'a mut ValueStack) -> Self {
+        Self {
+            frame: i16::from(frame.head()),
+            consts,
+            stack,
+            error: None,
+        }
+    }
+
+    /// Returns the first error encountered while relocating if any.
+    fn result(&mut self) -> Result<(), Error> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the caller register for the register `reg` of the inlined function.
+    fn relocate(&mut self, reg: Reg) -> Reg {
+        let index = i16::from(reg);
+        if !reg.is_const() {
+            return Reg::from(self.frame + index);
+        }
+        // Note: the constant `Reg(-1-k)` refers to the `k`-th allocated constant value
+        //       which is stored at the `k`-th last position of `consts`.
+        let k = -(i32::from(index) + 1) as usize;
+        let value = self.consts[self.consts.len() - 1 - k];
+        match self.stack.alloc_const(value) {
+            Ok(reg) => reg,
+            Err(error) => {
+                self.error.get_or_insert(error);
+                reg
+            }
+        }
+    }
+}
+
+impl VisitRegs for Relocator<'_> {
+    fn visit_result_reg(&mut self, reg: &mut Reg) {
+        *reg = self.relocate(*reg);
+    }
+
+    fn visit_result_regs(&mut self, regs: &mut RegSpan, _len: Option<u16>) {
+        *regs = RegSpan::new(self.relocate(regs.head()));
+    }
+
+    fn visit_input_reg(&mut self, reg: &mut Reg) {
+        *reg = self.relocate(*reg);
+    }
+
+    fn visit_input_regs(&mut self, regs: &mut RegSpan, _len: Option<u16>) {
+        *regs = RegSpan::new(self.relocate(regs.head()));
+    }
+}
@@ -178,10 +178,15 @@ impl InstrSequence {
     /// # Note
     ///
     /// The [`InstrSequence`] will be in an empty state after this operation.
-    pub fn drain(&mut self) -> Drain<Instruction> {
+    pub fn drain(&mut self) -> Drain<'_, Instruction> {
         self.instrs.drain(..)
     }
 
+    /// Returns an exclusive reference to the underlying [`Instruction`] buffer.
+    pub fn instrs_mut(&mut self) -> &mut Vec<Instruction> {
+        &mut self.instrs
+    }
+
     /// Returns a slice to the sequence of [`Instruction`] starting at `start`.
     ///
     /// # Panics
@@ -225,15 +230,29 @@ impl InstrEncoder {
         self.last_instr = None;
     }
 
+    /// Returns the [`Instr`] of the next pushed [`Instruction`].
+    pub fn next_instr(&self) -> Instr {
+        self.instrs.next_instr()
+    }
+
     /// Return an iterator over the sequence of generated [`Instruction`].
     ///
     /// # Note
     ///
     /// The [`InstrEncoder`] will be in an empty state after this operation.
-    pub fn drain_instrs(&mut self) -> Drain<Instruction> {
+    pub fn drain_instrs(&mut self) -> Drain<'_, Instruction> {
         self.instrs.drain()
     }
 
+    /// Returns an exclusive reference to the sequence of generated [`Instruction`].
+    ///
+    /// # Note
+    ///
+    /// This is used to optimize the generated [`Instruction`] sequence after translation.
+    pub fn instrs_mut(&mut self) -> &mut Vec<Instruction> {
+        self.instrs.instrs_mut()
+    }
+
     /// Creates a new unresolved label and returns its [`LabelRef`].
     pub fn new_label(&mut self) -> LabelRef {
         self.labels.new_label()
@@ -433,8 +452,9 @@ impl InstrEncoder {
                     Some(value) => Instruction::copy_f64imm32(result, value),
                     None => copy_imm(stack, result, value)?,
                 },
-                ValType::FuncRef => copy_imm(stack, result, value)?,
-                ValType::ExternRef => copy_imm(stack, result, value)?,
+                ValType::FuncRef | ValType::ExternRef | ValType::Ref(_) => {
+                    copy_imm(stack, result, value)?
+                }
             },
         };
         self.bump_fuel_consumption(fuel_info, FuelCosts::base)?;
@@ -602,7 +622,7 @@ impl InstrEncoder {
                     Some(value) => Instruction::return_f64imm32(value),
                     None => Instruction::return_reg(stack.alloc_const(*value)?),
                 },
-                ValType::FuncRef | ValType::ExternRef => {
+                ValType::FuncRef | ValType::ExternRef | ValType::Ref(_) => {
                     Instruction::return_reg(stack.alloc_const(*value)?)
                 }
             },
@@ -670,7 +690,7 @@ impl InstrEncoder {
                     Some(value) => Instruction::return_nez_f64imm32(condition, value),
                     None => Instruction::return_nez_reg(condition, stack.alloc_const(*value)?),
                 },
-                ValType::FuncRef | ValType::ExternRef => {
+                ValType::FuncRef | ValType::ExternRef | ValType::Ref(_) => {
                     Instruction::return_nez_reg(condition, stack.alloc_const(*value)?)
                 }
             },
@@ -719,6 +739,8 @@ impl InstrEncoder {
     /// - [`Instruction::ReturnCallInternal`]
     /// - [`Instruction::ReturnCallImported`]
     /// - [`Instruction::ReturnCallIndirect`]
+    /// - [`Instruction::CallRef`]
+    /// - [`Instruction::ReturnCallRef`]
     pub fn encode_register_list(
         &mut self,
         stack: &mut ValueStack,
@@ -1108,6 +1130,57 @@ impl InstrEncoder {
         self.push_instr(instr)?;
         Ok(())
     }
+
+    /// Encodes a branch to `label` that is taken if `reference` is `null`.
+    ///
+    /// # Note
+    ///
+    /// Function and external references are `null` if all of their 64 bits are zero.
+    pub fn encode_branch_ref_is_null(
+        &mut self,
+        stack: &mut ValueStack,
+        reference: Reg,
+        label: LabelRef,
+    ) -> Result<(), Error> {
+        self.encode_branch_ref_cmp_null(stack, Comparator::I64Eq, reference, label)
+    }
+
+    /// Encodes a branch to `label` that is taken if `reference` is not `null`.
+    ///
+    /// # Note
+    ///
+    /// Function and external references are `null` if all of their 64 bits are zero.
+    pub fn encode_branch_ref_is_non_null(
+        &mut self,
+        stack: &mut ValueStack,
+        reference: Reg,
+        label: LabelRef,
+    ) -> Result<(), Error> {
+        self.encode_branch_ref_cmp_null(stack, Comparator::I64Ne, reference, label)
+    }
+
+    /// Encodes a branch to `label` comparing `reference` with `null` using `cmp`.
+    fn encode_branch_ref_cmp_null(
+        &mut self,
+        stack: &mut ValueStack,
+        cmp: Comparator,
+        reference: Reg,
+        label: LabelRef,
+    ) -> Result<(), Error> {
+        let offset = self.try_resolve_label(label)?;
+        let instr = match (BranchOffset16::try_from(offset), cmp) {
+            (Ok(offset), Comparator::I64Eq) => {
+                Instruction::branch_i64_eq_imm16(reference, 0, offset)
+            }
+            (Ok(offset), _) => Instruction::branch_i64_ne_imm16(reference, 0, offset),
+            (Err(_), cmp) => {
+                let null = stack.alloc_const(0_i64)?;
+                InstrEncoder::make_branch_cmp_fallback(stack, cmp, reference, null, offset)?
+            }
+        };
+        self.push_instr(instr)?;
+        Ok(())
+    }
 }
 
 /// Extension trait to update the branch offset of an [`Instruction`].
@@ -5,8 +5,10 @@ mod control_frame;
 mod control_stack;
 mod driver;
 mod error;
+mod inline;
 mod instr_encoder;
 mod labels;
+mod optimizer;
 mod provider;
 mod relink_result;
 mod stack;
@@ -28,7 +30,8 @@ use self::{
     },
     control_stack::AcquiredTarget,
     labels::{LabelRef, LabelRegistry},
-    provider::{Provider, ProviderSliceStack, UntypedProvider},
+    optimizer::Optimizer,
+    provider::{Provider, ProviderSliceStack},
     stack::ValueStack,
     utils::{FromProviders as _, WasmFloat, WasmInteger},
 };
@@ -38,12 +41,13 @@ pub use self::{
     driver::FuncTranslationDriver,
     error::TranslationError,
     instr_encoder::{Instr, InstrEncoder},
+    provider::UntypedProvider,
     stack::TypedProvider,
 };
-use super::code_map::CompiledFuncEntity;
+use super::code_map::{CompiledFuncEntity, InlinedCall};
 use crate::{
-    core::{TrapCode, Typed, TypedVal, UntypedVal, ValType},
-    engine::{config::FuelCosts, BlockType, EngineFunc},
+    core::{TrapCode, TypeId, Typed, TypedVal, UntypedVal, ValType},
+    engine::{config::FuelCosts, BlockType, EngineFunc, OptimizationLevel},
     ir::{
         index,
         AnyConst16,
@@ -58,15 +62,24 @@ use crate::{
         ShiftAmount,
         Sign,
     },
-    module::{FuncIdx, FuncTypeIdx, ModuleHeader},
+    module::{
+        utils::{ensure_supported_val_type, TypeResolver},
+        FuncIdx,
+        FuncTypeIdx,
+        ModuleHeader,
+        WasmiValueType,
+        DEFAULT_MEMORY_INDEX,
+    },
+    ArrayType,
     Engine,
     Error,
     ExternRef,
     FuncRef,
     FuncType,
+    StructType,
 };
 use alloc::vec::Vec;
-use core::{fmt, mem};
+use core::{fmt, iter, mem};
 use stack::RegisterSpace;
 use utils::Wrap;
 use wasmparser::{
@@ -117,6 +130,45 @@ pub struct FuncTranslatorAllocations {
     control_stack: ControlStack,
     /// Some reusable buffers for translation purposes.
     buffer: TranslationBuffers,
+    /// Pairs of [`Instr`] and the Wasm binary offset of the Wasm operator they originate from.
+    ///
+    /// # Note
+    ///
+    /// This is only populated if [`Config::wasm_backtrace`] or [`Config::coredump_on_trap`] is enabled.
+    ///
+    /// [`Config::wasm_backtrace`]: crate::Config::wasm_backtrace
+    /// [`Config::coredump_on_trap`]: crate::Config::coredump_on_trap
+    wasm_offsets: Vec<(u32, u32)>,
+    /// The calls of functions that have been inlined into the translated function.
+    ///
+    /// # Note
+    ///
+    /// This is only populated if [`Config::wasm_backtrace`] or [`Config::coredump_on_trap`] is enabled.
+    ///
+    /// [`Config::wasm_backtrace`]: crate::Config::wasm_backtrace
+    /// [`Config::coredump_on_trap`]: crate::Config::coredump_on_trap
+    inlined_calls: Vec<InlinedCall>,
+    /// The types of the function parameters and locals in order.
+    ///
+    /// # Note
+    ///
+    /// This is only populated if [`Config::coredump_on_trap`] is enabled.
+    ///
+    /// [`Config::coredump_on_trap`]: crate::Config::coredump_on_trap
+    local_types: Vec<ValType>,
+    /// Triples of [`Instr`], Wasm binary offset and the end of its operand stack within `operands`.
+    ///
+    /// # Note
+    ///
+    /// - This records the Wasm operand stack before the execution of the Wasm operators in `wasm_offsets`.
+    /// - This is only populated if [`Config::coredump_on_trap`] is enabled and the function is validated.
+    ///
+    /// [`Config::coredump_on_trap`]: crate::Config::coredump_on_trap
+    operand_stacks: Vec<(u32, u32, u32)>,
+    /// The types and providers of the values of all recorded operand stacks from bottom to top.
+    operands: Vec<(ValType, UntypedProvider)>,
+    /// The optimizer applied to the translated instructions.
+    optimizer: Optimizer,
 }
 
 /// Reusable allocations for utility buffers.
@@ -128,6 +180,10 @@ pub struct TranslationBuffers {
     br_table_targets: Vec<u32>,
     /// Buffer to temporarily hold a bunch of preserved [`Reg`] locals.
     preserved: Vec<PreservedLocal>,
+    /// Buffer to temporarily mark the written registers of an inlined function.
+    written: Vec<bool>,
+    /// Buffer to temporarily hold the registers of an inlined function that are read before written.
+    uninit: Vec<i16>,
 }
 
 /// A pair of local [`Reg`] and its preserved [`Reg`].
@@ -150,6 +206,8 @@ impl TranslationBuffers {
         self.providers.clear();
         self.br_table_targets.clear();
         self.preserved.clear();
+        self.written.clear();
+        self.uninit.clear();
     }
 }
 
@@ -160,6 +218,11 @@ impl FuncTranslatorAllocations {
         self.instr_encoder.reset();
         self.control_stack.reset();
         self.buffer.reset();
+        self.wasm_offsets.clear();
+        self.inlined_calls.clear();
+        self.local_types.clear();
+        self.operand_stacks.clear();
+        self.operands.clear();
     }
 }
 
@@ -241,6 +304,14 @@ pub trait WasmTranslator<'parser>: VisitOperator<'parser, Output = Result<(), Er
     /// This information is mainly required for properly locating translation errors.
     fn update_pos(&mut self, pos: usize);
 
+    /// Updates the [`WasmTranslator`] about the Wasm operand stack at the current position.
+    ///
+    /// # Note
+    ///
+    /// - This is called after [`WasmTranslator::update_pos`] if the Wasm function is validated.
+    /// - The operand types are required to capture operand stacks for Wasm coredumps.
+    fn update_operand_types(&mut self, _validator: &FuncValidator) {}
+
     /// Finishes constructing the Wasm function translation.
     ///
     /// # Note
@@ -316,6 +387,8 @@ where
 
     fn update_pos(&mut self, pos: usize) {
         self.pos = pos;
+        self.translator.update_pos(pos);
+        self.translator.update_operand_types(&self.validator);
     }
 
     fn finish(
@@ -366,6 +439,48 @@ macro_rules! impl_visit_operator {
     ( @tail_call $($rest:tt)* ) => {
         impl_visit_operator!(@@supported $($rest)*);
     };
+    ( @function_references $($rest:tt)* ) => {
+        impl_visit_operator!(@@supported $($rest)*);
+    };
+    ( @gc $($rest:tt)* ) => {
+        impl_visit_operator!(@@supported $($rest)*);
+    };
+    ( @wide_arithmetic $($rest:tt)* ) => {
+        impl_visit_operator!(@@supported $($rest)*);
+    };
+    ( @stack_switching Resume { cont_type_index: $ty:ty, resume_table: $table_ty:ty } => $visit:ident $_ann:tt $($rest:tt)* ) => {
+        // We need to special case the `Resume` operand since its
+        // `resume_table` argument is not `Copy`.
+        fn $visit(&mut self, cont_type_index: $ty, resume_table: $table_ty) -> Self::Output {
+            let offset = self.current_pos();
+            let table = resume_table.clone();
+            self.validate_then_translate(
+                move |validator| validator.visitor(offset).$visit(cont_type_index, table),
+                move |translator| translator.$visit(cont_type_index, resume_table),
+            )
+        }
+        impl_visit_operator!($($rest)*);
+    };
+    ( @stack_switching ResumeThrow $($rest:tt)* ) => {
+        // Wasmi does not support Wasm exceptions and thus `resume_throw`.
+        impl_visit_operator!(@@unsupported ResumeThrow $($rest)*);
+    };
+    ( @stack_switching $($rest:tt)* ) => {
+        impl_visit_operator!(@@supported $($rest)*);
+    };
+    ( @exceptions $($rest:tt)* ) => {
+        // The `stack-switching` proposal requires tags from the `exception-handling`
+        // proposal which is why its operators pass validation if it is enabled.
+        impl_visit_operator!(@@unsupported $($rest)*);
+    };
+    ( @@unsupported $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $_ann:tt $($rest:tt)* ) => {
+        fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
+            let offset = self.current_pos();
+            self.validator.visitor(offset).$visit($($($arg),*)?)?;
+            Err(Error::from(TranslationError::UnsupportedOperator(stringify!($op))))
+        }
+        impl_visit_operator!($($rest)*);
+    };
     ( @@supported $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $_ann:tt $($rest:tt)* ) => {
         fn $visit(&mut self $($(,$arg: $argty)*)?) -> Self::Output {
             let offset = self.current_pos();
@@ -378,6 +493,7 @@ macro_rules! impl_visit_operator {
     };
     ( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $ann:tt $($rest:tt)* ) => {
         // Wildcard match arm for all the other (yet) unsupported Wasm proposals.
+        #[allow(unused_variables)]
         fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
             let offset = self.current_pos();
             self.validator.visitor(offset).$visit($($($arg),*)?).map_err(::core::convert::Into::into)
@@ -403,6 +519,8 @@ pub struct LazyFuncTranslator {
     func_idx: FuncIdx,
     /// The identifier of the to be compiled function.
     engine_func: EngineFunc,
+    /// The offset of the Wasm function body within the Wasm module binary.
+    offset: usize,
     /// The Wasm module header information used for translation.
     module: ModuleHeader,
     /// Information about Wasm validation during lazy translation.
@@ -465,6 +583,7 @@ impl LazyFuncTranslator {
         Self {
             func_idx,
             engine_func,
+            offset: 0,
             module,
             validation: Validation::Checked(func_to_validate),
         }
@@ -480,6 +599,7 @@ impl LazyFuncTranslator {
         Self {
             func_idx,
             engine_func,
+            offset: 0,
             module,
             validation: Validation::Unchecked(features),
         }
@@ -502,6 +622,7 @@ impl WasmTranslator<'_> for LazyFuncTranslator {
             .init_lazy_func(
                 self.func_idx,
                 self.engine_func,
+                self.offset,
                 bytes,
                 &self.module,
                 self.validation.take_func_to_validate(),
@@ -529,7 +650,9 @@ impl WasmTranslator<'_> for LazyFuncTranslator {
     }
 
     #[inline]
-    fn update_pos(&mut self, _pos: usize) {}
+    fn update_pos(&mut self, pos: usize) {
+        self.offset = pos;
+    }
 
     #[inline]
     fn finish(
@@ -543,6 +666,7 @@ impl WasmTranslator<'_> for LazyFuncTranslator {
 macro_rules! impl_visit_operator {
     ( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $ann:tt $($rest:tt)* ) => {
         #[inline]
+        #[allow(unused_variables)]
         fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
             Ok(())
         }
@@ -585,6 +709,18 @@ pub struct FuncTranslator {
     ///
     /// `None` if fuel metering is disabled.
     fuel_costs: Option<FuelCosts>,
+    /// Is `true` if Wasm binary offsets are recorded for Wasm backtraces and coredumps.
+    wasm_offsets: bool,
+    /// Is `true` if the types of the function locals are recorded for Wasm coredumps.
+    local_types: bool,
+    /// The Wasm binary offset of the translated Wasm function body if recorded.
+    func_offset: Option<u32>,
+    /// The level of optimizations applied to the translated instructions.
+    optimization_level: OptimizationLevel,
+    /// The maximum number of instructions of internal functions inlined into the translated function.
+    ///
+    /// This is `0` if inlining is disabled.
+    max_inline_len: u32,
     /// The reusable data structures of the [`FuncTranslator`].
     alloc: FuncTranslatorAllocations,
 }
@@ -604,9 +740,17 @@ impl WasmTranslator<'_> for FuncTranslator {
     fn translate_locals(
         &mut self,
         amount: u32,
-        _value_type: wasmparser::ValType,
+        value_type: wasmparser::ValType,
     ) -> Result<(), Error> {
-        self.alloc.stack.register_locals(amount)
+        ensure_supported_val_type(value_type)?;
+        self.alloc.stack.register_locals(amount)?;
+        if self.local_types {
+            let value_type = WasmiValueType::from(value_type).into_inner();
+            self.alloc
+                .local_types
+                .extend(iter::repeat(value_type).take(amount as usize));
+        }
+        Ok(())
     }
 
     fn finish_translate_locals(&mut self) -> Result<(), Error> {
@@ -614,7 +758,58 @@ impl WasmTranslator<'_> for FuncTranslator {
         Ok(())
     }
 
-    fn update_pos(&mut self, _pos: usize) {}
+    fn update_pos(&mut self, pos: usize) {
+        if !self.wasm_offsets {
+            return;
+        }
+        let instr = self.alloc.instr_encoder.next_instr().into_u32();
+        let pos = u32::try_from(pos).unwrap_or(u32::MAX);
+        // Note: the first reported position is the start of the Wasm function body.
+        self.func_offset.get_or_insert(pos);
+        match self.alloc.wasm_offsets.last_mut() {
+            // Note: the previous Wasm operator did not produce any instructions.
+            Some((last, offset)) if *last == instr => *offset = pos,
+            _ => self.alloc.wasm_offsets.push((instr, pos)),
+        }
+    }
+
+    fn update_operand_types(&mut self, validator: &FuncValidator) {
+        if !self.local_types {
+            return;
+        }
+        let instr = self.alloc.instr_encoder.next_instr().into_u32();
+        if let Some((last, _, _)) = self.alloc.operand_stacks.last() {
+            if *last == instr {
+                // Note: the previous Wasm operator did not produce any instructions.
+                self.alloc.operand_stacks.pop();
+                let start = self
+                    .alloc
+                    .operand_stacks
+                    .last()
+                    .map_or(0, |(_, _, end)| *end);
+                self.alloc.operands.truncate(start as usize);
+            }
+        }
+        let height = self.alloc.stack.height();
+        if !self.is_reachable() || validator.operand_stack_height() as usize != height {
+            return;
+        }
+        let Some((_, pos)) = self.alloc.wasm_offsets.last().copied() else {
+            return;
+        };
+        let start = self.alloc.operands.len();
+        for (depth, provider) in self.alloc.stack.providers().rev().enumerate() {
+            let Some(Some(ty)) = validator.get_operand_type(depth) else {
+                self.alloc.operands.truncate(start);
+                return;
+            };
+            let ty = WasmiValueType::from(ty).into_inner();
+            self.alloc.operands.push((ty, provider.into_untyped()));
+        }
+        self.alloc.operands[start..].reverse();
+        let end = u32::try_from(self.alloc.operands.len()).unwrap_or(u32::MAX);
+        self.alloc.operand_stacks.push((instr, pos, end));
+    }
 
     fn finish(
         mut self,
@@ -626,6 +821,31 @@ impl WasmTranslator<'_> for FuncTranslator {
         self.alloc
             .instr_encoder
             .update_branch_offsets(&mut self.alloc.stack)?;
+        // Note: function locals are observable via Wasm coredumps if their types are recorded.
+        //       All registers are observable if the operand stacks are recorded as well.
+        let len_observed_locals = match self.alloc.operand_stacks.is_empty() {
+            true => self.alloc.local_types.len(),
+            false => usize::from(self.alloc.stack.len_registers()),
+        };
+        // Note: imported memories are at least as large as their declared minimum size.
+        let min_memory_bytes = self
+            .module
+            .get_type_of_memory(DEFAULT_MEMORY_INDEX)
+            .and_then(|memory_type| memory_type.pages_to_bytes(memory_type.initial_pages()))
+            .map_or(0, |bytes| bytes as u64);
+        // Note: optimization may allocate new function local constant values
+        //       and thus happens before the number of registers is finalized.
+        self.alloc.optimizer.optimize(
+            self.optimization_level,
+            self.engine.config().get_bounds_check_elimination(),
+            self.alloc.stack.len_registers(),
+            len_observed_locals,
+            min_memory_bytes,
+            &mut self.alloc.stack,
+            self.alloc.instr_encoder.instrs_mut(),
+            &mut self.alloc.wasm_offsets,
+            &mut self.alloc.inlined_calls,
+        );
         let len_registers = self.alloc.stack.len_registers();
         if let Some(fuel_costs) = self.fuel_costs() {
             // Note: Fuel metering is enabled so we need to bump the fuel
@@ -642,9 +862,30 @@ impl WasmTranslator<'_> for FuncTranslator {
                     costs.fuel_for_copies(u64::from(len_registers))
                 })?;
         }
+        for (_, provider) in &mut self.alloc.operands {
+            if let Provider::Register(register) = provider {
+                *register = self.alloc.stack.defrag_register(*register);
+            }
+        }
         let func_consts = self.alloc.stack.func_local_consts();
         let instrs = self.alloc.instr_encoder.drain_instrs();
-        finalize(CompiledFuncEntity::new(len_registers, instrs, func_consts));
+        let func_offset = self.func_offset.unwrap_or_default();
+        let wasm_offsets = self.alloc.wasm_offsets.drain(..);
+        let inlined_calls = self.alloc.inlined_calls.drain(..);
+        let local_types = self.alloc.local_types.drain(..);
+        let operand_stacks = self
+            .alloc
+            .operand_stacks
+            .drain(..)
+            .map(|(_, pos, end)| (pos, end));
+        let operands = self.alloc.operands.drain(..);
+        finalize(
+            CompiledFuncEntity::new(len_registers, instrs, func_consts)
+                .with_wasm_offsets(func_offset, wasm_offsets)
+                .with_inlined_calls(inlined_calls)
+                .with_local_types(local_types)
+                .with_operand_stacks(operand_stacks, operands),
+        );
         Ok(self.into_allocations())
     }
 }
@@ -706,17 +947,47 @@ impl FuncTranslator {
             .get_consume_fuel()
             .then(|| config.fuel_costs())
             .copied();
+        let local_types = config.get_coredump_on_trap();
+        let wasm_offsets = config.get_wasm_backtrace() || local_types;
+        // Note: with tiered translation optimizations and inlining are deferred
+        //       to the re-translation of hot functions.
+        let tiered = config.get_tier_up_threshold() != 0;
+        let optimization_level = match tiered {
+            true => OptimizationLevel::None,
+            false => config.get_optimization_level(),
+        };
+        let max_inline_len = match tiered {
+            true => 0,
+            false => config.get_max_inline_len(),
+        };
         Self {
             func,
             engine,
             module: res,
             reachable: true,
             fuel_costs,
+            wasm_offsets,
+            local_types,
+            func_offset: None,
+            optimization_level,
+            max_inline_len,
             alloc,
         }
         .init()
     }
 
+    /// Makes the [`FuncTranslator`] apply the optimizations and inlining of the [`Config`].
+    ///
+    /// This is used to re-translate hot functions with tiered translation.
+    ///
+    /// [`Config`]: crate::Config
+    pub fn with_tier_up(mut self) -> Self {
+        let config = self.engine.config();
+        self.optimization_level = config.get_optimization_level();
+        self.max_inline_len = config.get_max_inline_len();
+        self
+    }
+
     /// Returns the [`Engine`] for which the function is compiled.
     fn engine(&self) -> &Engine {
         &self.engine
@@ -754,8 +1025,12 @@ impl FuncTranslator {
 
     /// Registers the function parameters in the emulated value stack.
     fn init_func_params(&mut self) -> Result<(), Error> {
-        for _param_type in self.func_type().params() {
+        let func_type = self.func_type();
+        for param_type in func_type.params() {
             self.alloc.stack.register_locals(1)?;
+            if self.local_types {
+                self.alloc.local_types.push(*param_type);
+            }
         }
         Ok(())
     }
@@ -780,6 +1055,58 @@ impl FuncTranslator {
             .resolve_func_type(dedup_func_type, Clone::clone)
     }
 
+    /// Returns a [`TypeResolver`] for the types of the Wasm module that is currently translated.
+    fn type_resolver(&self) -> TypeResolver<'_> {
+        TypeResolver::new(self.engine(), self.module.func_types())
+    }
+
+    /// Returns the engine-wide [`TypeId`] of the type at module `type_index`.
+    fn type_id_at(&self, type_index: u32) -> TypeId {
+        self.type_resolver().type_id(type_index)
+    }
+
+    /// Returns the [`TypeId`] and the [`StructType`] of the struct type at module `type_index`.
+    fn struct_type_at(&self, type_index: u32) -> (TypeId, StructType) {
+        let type_id = self.type_id_at(type_index);
+        let struct_type = self
+            .engine()
+            .with_types(|types| types.struct_type(type_id).clone());
+        (type_id, struct_type)
+    }
+
+    /// Returns the [`TypeId`] and the [`ArrayType`] of the array type at module `type_index`.
+    fn array_type_at(&self, type_index: u32) -> (TypeId, ArrayType) {
+        let type_id = self.type_id_at(type_index);
+        let array_type = self.engine().with_types(|types| *types.array_type(type_id));
+        (type_id, array_type)
+    }
+
+    /// Returns the [`FuncType`] of the continuation type at module `type_index`.
+    fn cont_func_type_at(&self, type_index: u32) -> FuncType {
+        let type_id = self.type_id_at(type_index);
+        self.cont_func_type_of(type_id)
+    }
+
+    /// Returns the [`FuncType`] of the continuation type identified by `type_id`.
+    fn cont_func_type_of(&self, type_id: TypeId) -> FuncType {
+        self.engine().with_types(|types| {
+            let func_type = types
+                .dedup_by_id(types.cont_func_type(type_id))
+                .expect("continuation types always refer to function types");
+            types.resolve_func_type(&func_type).clone()
+        })
+    }
+
+    /// Returns the [`FuncType`] of the tag at module `tag_index`.
+    fn tag_func_type_at(&self, tag_index: u32) -> FuncType {
+        let dedup_func_type = self
+            .module
+            .get_type_of_tag(tag_index)
+            .unwrap_or_else(|| panic!("missing tag at index: {tag_index}"));
+        self.engine()
+            .resolve_func_type(dedup_func_type, Clone::clone)
+    }
+
     /// Resolves the [`FuncType`] of the given [`FuncIdx`].
     fn func_type_of(&self, func_index: FuncIdx) -> FuncType {
         let dedup_func_type = self.module.get_type_of_func(func_index);
@@ -2310,7 +2637,7 @@ impl FuncTranslator {
             ValType::I32 | ValType::F32 => self.translate_select_32(result, condition, lhs, rhs),
             ValType::I64 => self.translate_select_i64(result, condition, lhs, rhs),
             ValType::F64 => self.translate_select_f64(result, condition, lhs, rhs),
-            ValType::FuncRef | ValType::ExternRef => {
+            ValType::FuncRef | ValType::ExternRef | ValType::Ref(_) => {
                 self.translate_select_reftype(result, condition, lhs, rhs)
             }
         }
@@ -2572,6 +2899,106 @@ impl FuncTranslator {
         Ok(instr)
     }
 
+    /// Pops the function reference operand of a `call_ref` or `return_call_ref`.
+    ///
+    /// Returns `None` and translates a trap if the function reference is known to be `null`.
+    fn pop_call_ref_funcref(&mut self) -> Result<Option<Reg>, Error> {
+        match self.alloc.stack.pop() {
+            TypedProvider::Register(funcref) => Ok(Some(funcref)),
+            TypedProvider::Const(_) => {
+                // Case: reference constants are always `null` and thus always trap.
+                self.translate_trap(TrapCode::NullReference)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Translates a conditional branch to `relative_depth` depending on the nullness of `reference`.
+    ///
+    /// The branch is taken if `reference` is `null` if `on_null` is `true`
+    /// and taken if `reference` is not `null` otherwise.
+    ///
+    /// # Note
+    ///
+    /// This is used to translate Wasm `br_on_null` and `br_on_non_null` instructions.
+    /// The values of the branch parameters are expected on top of the value stack.
+    fn translate_br_on_ref(
+        &mut self,
+        relative_depth: u32,
+        reference: Reg,
+        on_null: bool,
+    ) -> Result<(), Error> {
+        let engine = self.engine().clone();
+        let fuel_info = self.fuel_info();
+        let frame = match self.alloc.control_stack.acquire_target(relative_depth) {
+            AcquiredTarget::Return(_frame) => {
+                // Case: the branch returns from the function so we skip
+                //       the return in case the branch is not taken.
+                let len_results = self.func_type().results().len();
+                let skip_label = self.alloc.instr_encoder.new_label();
+                self.encode_branch_ref(reference, !on_null, skip_label)?;
+                let values = &mut self.alloc.buffer.providers;
+                self.alloc.stack.peek_n(len_results, values);
+                self.alloc
+                    .instr_encoder
+                    .encode_return(&mut self.alloc.stack, values, fuel_info)?;
+                self.alloc.instr_encoder.pin_label(skip_label);
+                return Ok(());
+            }
+            AcquiredTarget::Branch(frame) => frame,
+        };
+        frame.bump_branches();
+        let branch_dst = frame.branch_destination();
+        let branch_params = frame.branch_params(&engine);
+        self.alloc.stack.peek_n(
+            usize::from(branch_params.len()),
+            &mut self.alloc.buffer.providers,
+        );
+        if self
+            .alloc
+            .buffer
+            .providers
+            .iter()
+            .copied()
+            .eq(branch_params.iter().map(TypedProvider::Register))
+        {
+            // Case: the providers on the stack are already as
+            //       expected by the branch params and therefore
+            //       no copies are required.
+            return self.encode_branch_ref(reference, on_null, branch_dst);
+        }
+        // Case: we need to copy the branch inputs to where the control frame
+        //       expects them so we skip the copies if the branch is not taken.
+        let skip_label = self.alloc.instr_encoder.new_label();
+        self.encode_branch_ref(reference, !on_null, skip_label)?;
+        self.alloc.instr_encoder.encode_copies(
+            &mut self.alloc.stack,
+            branch_params,
+            &self.alloc.buffer.providers[..],
+            fuel_info,
+        )?;
+        let branch_offset = self.alloc.instr_encoder.try_resolve_label(branch_dst)?;
+        self.push_base_instr(Instruction::branch(branch_offset))?;
+        self.alloc.instr_encoder.pin_label(skip_label);
+        Ok(())
+    }
+
+    /// Encodes a branch to `label` that is taken if `reference` is `null` if `on_null` is `true`
+    /// and taken if `reference` is not `null` otherwise.
+    fn encode_branch_ref(
+        &mut self,
+        reference: Reg,
+        on_null: bool,
+        label: LabelRef,
+    ) -> Result<(), Error> {
+        let stack = &mut self.alloc.stack;
+        let encoder = &mut self.alloc.instr_encoder;
+        match on_null {
+            true => encoder.encode_branch_ref_is_null(stack, reference, label),
+            false => encoder.encode_branch_ref_is_non_null(stack, reference, label),
+        }
+    }
+
     /// Translates a Wasm `br` instruction with its `relative_depth`.
     fn translate_br(&mut self, relative_depth: u32) -> Result<(), Error> {
         let engine = self.engine().clone();
@@ -2753,7 +3180,7 @@ impl FuncTranslator {
                         Instruction::register(register)
                     }
                 },
-                ValType::ExternRef | ValType::FuncRef => {
+                ValType::ExternRef | ValType::FuncRef | ValType::Ref(_) => {
                     let register = self.alloc.stack.provider2reg(&value)?;
                     Instruction::register(register)
                 }
@@ -2883,6 +3310,71 @@ impl FuncTranslator {
         self.reachable = false;
         Ok(())
     }
+
+    /// Pops the top-most `len` values from the stack and returns them as [`BoundedRegSpan`].
+    ///
+    /// # Note
+    ///
+    /// The values are copied to contiguous dynamic registers if they do not form a [`BoundedRegSpan`].
+    fn translate_cont_args(&mut self, len: usize) -> Result<BoundedRegSpan, Error> {
+        let len_values = u16::try_from(len)
+            .map_err(|_| Error::from(TranslationError::AllocatedTooManyRegisters))?;
+        let values = &mut self.alloc.buffer.providers;
+        self.alloc.stack.pop_n(len, values);
+        if len == 0 {
+            return Ok(BoundedRegSpan::new(RegSpan::new(Reg::from(0)), 0));
+        }
+        if let Some(span) = BoundedRegSpan::from_providers(values) {
+            return Ok(span);
+        }
+        let fuel_info = self.fuel_info();
+        let results = BoundedRegSpan::new(self.alloc.stack.push_dynamic_n(len)?, len_values);
+        self.alloc.instr_encoder.encode_copies(
+            &mut self.alloc.stack,
+            results,
+            &self.alloc.buffer.providers[..],
+            fuel_info,
+        )?;
+        self.alloc
+            .stack
+            .pop_n(len, &mut self.alloc.buffer.providers);
+        Ok(results)
+    }
+
+    /// Translates the handlers of a Wasm `resume` instruction.
+    ///
+    /// Each `on $tag $label` handler is encoded as [`Instruction::ResumeHandler`] followed by
+    /// either an [`Instruction::BranchTableTarget`] or an [`Instruction::Return`].
+    /// Each `on $tag switch` handler is encoded as [`Instruction::SwitchHandler`].
+    fn translate_resume_handlers(&mut self, table: &wasmparser::ResumeTable) -> Result<(), Error> {
+        let engine = self.engine().clone();
+        for handle in &table.handlers {
+            let (tag, label) = match *handle {
+                wasmparser::Handle::OnLabel { tag, label } => (tag, label),
+                wasmparser::Handle::OnSwitch { tag } => {
+                    self.alloc
+                        .instr_encoder
+                        .append_instr(Instruction::switch_handler(index::Tag::from(tag)))?;
+                    continue;
+                }
+            };
+            self.alloc
+                .instr_encoder
+                .append_instr(Instruction::resume_handler(index::Tag::from(tag)))?;
+            let target = match self.alloc.control_stack.acquire_target(label) {
+                AcquiredTarget::Return(_) => Instruction::Return,
+                AcquiredTarget::Branch(frame) => {
+                    frame.bump_branches();
+                    let branch_params = frame.branch_params(&engine);
+                    let branch_dst = frame.branch_destination();
+                    let branch_offset = self.alloc.instr_encoder.try_resolve_label(branch_dst)?;
+                    Instruction::branch_table_target(branch_params.span(), branch_offset)
+                }
+            };
+            self.alloc.instr_encoder.append_instr(target)?;
+        }
+        Ok(())
+    }
 }
 
 trait BumpFuelConsumption {
@@ -0,0 +1,1593 @@
+use super::{stack::ValueStack, NegateCmpInstr as _, TryIntoCmpBranchInstr as _};
+use crate::{
+    engine::{code_map::InlinedCall, OptimizationLevel},
+    errors::IrError,
+    ir::{BranchOffset, BranchOffset16, ComparatorAndOffset, Instruction, Reg, RegSpan, VisitRegs},
+};
+use alloc::vec::Vec;
+
+/// The maximum number of unconditional branches followed when threading a single jump.
+///
+/// # Note
+///
+/// This guards against cycles of unconditional branches.
+const MAX_JUMP_THREADING_HOPS: usize = 16;
+
+/// The maximum number of [`Reg`]s with known in-bounds ranges tracked by bounds check elimination.
+const MAX_BOUNDS_PTRS: usize = 16;
+
+/// The maximum number of analysis sweeps of bounds check elimination.
+///
+/// # Note
+///
+/// Every sweep after the first is caused by a loop whose known in-bounds ranges
+/// have not yet reached a fixed point. Bounds checks are kept for functions that
+/// exceed this limit.
+const MAX_BOUNDS_SWEEPS: usize = 8;
+
+/// Optimizes the Wasmi bytecode of a single function after its translation.
+///
+/// The applied passes depend on the [`OptimizationLevel`] except for bounds check elimination.
+/// All passes preserve the observable semantics of the optimized function.
+#[derive(Debug, Default)]
+pub struct Optimizer {
+    /// Is `true` for every [`Instruction`] that is the target of a branch.
+    branch_targets: Vec<bool>,
+    /// Is `true` for every [`Instruction`] that is removed upon the next compaction.
+    removed: Vec<bool>,
+    /// The index of every [`Instruction`] after the next compaction.
+    ///
+    /// # Note
+    ///
+    /// Removed [`Instruction`]s are mapped to the index of the next kept [`Instruction`].
+    new_indices: Vec<u32>,
+    /// The number of reads of every non-constant [`Reg`].
+    reads: Vec<u32>,
+    /// The first [`Reg`] of all unbounded input [`RegSpan`]s if any.
+    ///
+    /// # Note
+    ///
+    /// All [`Reg`]s starting at this [`Reg`] are conservatively considered to be read.
+    unbounded_reads: Option<i16>,
+    /// The pairs of `(result, value)` [`Reg`]s of all currently valid copies.
+    copies: Vec<(Reg, Reg)>,
+    /// The number of function locals whose values are observable, e.g. via Wasm coredumps.
+    len_observed_locals: usize,
+    /// The known in-bounds ranges of the default linear memory upon branching to every [`Instruction`].
+    ///
+    /// Is `None` for every [`Instruction`] that is not the target of an analyzed branch.
+    bounds: Vec<Option<Bounds>>,
+    /// All [`Instruction::BranchCmpFallback`] sorted by their indices.
+    fallbacks: Vec<Fallback>,
+}
+
+/// An [`Instruction::BranchCmpFallback`] whose parameters are stored in a function local constant value.
+#[derive(Debug, Copy, Clone)]
+struct Fallback {
+    /// The index of the [`Instruction::BranchCmpFallback`].
+    index: usize,
+    /// The comparator and the branch offset of the [`Instruction::BranchCmpFallback`].
+    params: ComparatorAndOffset,
+    /// Is `true` if the branch offset changed since the parameters were loaded.
+    changed: bool,
+}
+
+impl Optimizer {
+    /// Optimizes the `instrs` of a function with `len_registers` registers according to `level`.
+    ///
+    /// - Bounds checks are eliminated if `bounds_check_elimination` is `true`.
+    /// - Writes to the first `len_observed_locals` registers are never removed.
+    /// - The default linear memory is known to be at least `min_memory_bytes` large.
+    /// - The Wasm binary offsets in `wasm_offsets` and the instruction ranges of
+    ///   `inlined_calls` are updated to the optimized `instrs`.
+    /// - Changed branch offsets of [`Instruction::BranchCmpFallback`] are stored in
+    ///   newly allocated function local constant values of `stack`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn optimize(
+        &mut self,
+        level: OptimizationLevel,
+        bounds_check_elimination: bool,
+        len_registers: u16,
+        len_observed_locals: usize,
+        min_memory_bytes: u64,
+        stack: &mut ValueStack,
+        instrs: &mut Vec<Instruction>,
+        wasm_offsets: &mut Vec<(u32, u32)>,
+        inlined_calls: &mut [InlinedCall],
+    ) {
+        if matches!(level, OptimizationLevel::None) && !bounds_check_elimination {
+            return;
+        }
+        self.load_fallbacks(stack, instrs);
+        // Note: the branch offsets of all `branch_cmp_fallback` instructions
+        //       might need to be stored in new function local constant values.
+        //       Bounds check elimination never changes branch offsets.
+        let restructures = match level {
+            OptimizationLevel::None => false,
+            _ => stack.has_room_for_consts(self.fallbacks.len()),
+        };
+        self.len_observed_locals = len_observed_locals;
+        self.removed.clear();
+        self.removed.resize(instrs.len(), false);
+        if restructures {
+            self.thread_jumps(instrs);
+            self.merge_fuel(instrs);
+            self.compact(instrs, wasm_offsets, inlined_calls);
+        }
+        if restructures && matches!(level, OptimizationLevel::Full) {
+            self.propagate_copies(instrs);
+            self.remove_dead_copies(len_registers, instrs);
+            self.compact(instrs, wasm_offsets, inlined_calls);
+            self.fuse_cmp_branch(len_registers, instrs);
+            self.compact(instrs, wasm_offsets, inlined_calls);
+        }
+        if bounds_check_elimination {
+            self.eliminate_bounds_checks(min_memory_bytes, instrs);
+        }
+        if restructures && matches!(level, OptimizationLevel::Full) {
+            self.fuse_load_op(len_registers, instrs);
+        }
+        self.store_fallbacks(stack, instrs);
+    }
+
+    /// Loads the parameters of all [`Instruction::BranchCmpFallback`] of `instrs` from `stack`.
+    ///
+    /// # Panics
+    ///
+    /// If the parameters of an [`Instruction::BranchCmpFallback`] are invalid.
+    fn load_fallbacks(&mut self, stack: &ValueStack, instrs: &[Instruction]) {
+        self.fallbacks.clear();
+        for (index, instr) in instrs.iter().enumerate() {
+            let Instruction::BranchCmpFallback { params, .. } = *instr else {
+                continue;
+            };
+            let Some(params) = stack
+                .get_const(params)
+                .and_then(ComparatorAndOffset::from_untyped)
+            else {
+                panic!("encountered invalid `branch_cmp_fallback` parameters at {index}: {instr:?}")
+            };
+            self.fallbacks.push(Fallback {
+                index,
+                params,
+                changed: false,
+            });
+        }
+    }
+
+    /// Stores the changed parameters of all [`Instruction::BranchCmpFallback`] of `instrs` in `stack`.
+    ///
+    /// # Note
+    ///
+    /// The previous function local constant values are kept since they might be shared.
+    fn store_fallbacks(&mut self, stack: &mut ValueStack, instrs: &mut [Instruction]) {
+        for fallback in self.fallbacks.drain(..).filter(|fallback| fallback.changed) {
+            let Instruction::BranchCmpFallback { lhs, rhs, .. } = instrs[fallback.index] else {
+                panic!(
+                    "expected `branch_cmp_fallback` at {} but found: {:?}",
+                    fallback.index, instrs[fallback.index]
+                )
+            };
+            // Note: there is room for all new function local constant values as checked upfront.
+            let params = stack.alloc_const(fallback.params).unwrap_or_else(|error| {
+                panic!("failed to allocate `branch_cmp_fallback` parameters: {error}")
+            });
+            instrs[fallback.index] = Instruction::branch_cmp_fallback(lhs, rhs, params);
+        }
+    }
+
+    /// Returns the index of the target of the branch `instr` at `index` if any.
+    fn branch_target(&self, index: usize, instr: &Instruction) -> Option<usize> {
+        let offset = match instr {
+            Instruction::BranchCmpFallback { .. } => self.fallback(index)?.params.offset.to_i32(),
+            instr => instr.branch_offset()?,
+        };
+        index.checked_add_signed(offset as isize)
+    }
+
+    /// Sets the branch offset of the branch `instr` at `index` to `offset`.
+    ///
+    /// # Errors
+    ///
+    /// If `offset` is out of bounds for the branch offset of `instr`.
+    fn set_branch_offset(
+        &mut self,
+        index: usize,
+        instr: &mut Instruction,
+        offset: i32,
+    ) -> Result<(), IrError> {
+        if !matches!(instr, Instruction::BranchCmpFallback { .. }) {
+            return instr.set_branch_offset(offset);
+        }
+        let Ok(pos) = self
+            .fallbacks
+            .binary_search_by_key(&index, |fallback| fallback.index)
+        else {
+            panic!("missing `branch_cmp_fallback` parameters at {index}: {instr:?}")
+        };
+        let fallback = &mut self.fallbacks[pos];
+        fallback.changed |= fallback.params.offset.to_i32() != offset;
+        fallback.params.offset = BranchOffset::from(offset);
+        Ok(())
+    }
+
+    /// Returns the [`Fallback`] of the [`Instruction::BranchCmpFallback`] at `index` if any.
+    fn fallback(&self, index: usize) -> Option<&Fallback> {
+        self.fallbacks
+            .binary_search_by_key(&index, |fallback| fallback.index)
+            .ok()
+            .map(|pos| &self.fallbacks[pos])
+    }
+
+    /// Marks all [`Instruction`]s in `instrs` that are the target of a branch.
+    fn mark_branch_targets(&mut self, instrs: &[Instruction]) {
+        self.branch_targets.clear();
+        self.branch_targets.resize(instrs.len(), false);
+        for (index, instr) in instrs.iter().enumerate() {
+            let Some(target) = self.branch_target(index, instr) else {
+                continue;
+            };
+            if let Some(is_target) = self.branch_targets.get_mut(target) {
+                *is_target = true;
+            }
+        }
+    }
+
+    /// Counts the reads of all non-constant [`Reg`]s of the kept `instrs`.
+    fn count_reads(&mut self, len_registers: u16, instrs: &[Instruction]) {
+        self.reads.clear();
+        self.reads.resize(usize::from(len_registers), 0);
+        let mut counter = ReadCounter {
+            reads: &mut self.reads,
+            unbounded: None,
+        };
+        for (instr, removed) in instrs.iter().zip(&self.removed) {
+            if *removed {
+                continue;
+            }
+            let mut instr = *instr;
+            instr.visit_regs(&mut counter);
+        }
+        self.unbounded_reads = counter.unbounded;
+    }
+
+    /// Returns the number of reads of the non-constant `reg`.
+    ///
+    /// Returns `None` if the number of reads of `reg` is unknown or if `reg`
+    /// is an observed function local.
+    fn reads_of(&self, reg: Reg) -> Option<u32> {
+        let index = i16::from(reg);
+        if self.unbounded_reads.is_some_and(|head| index >= head) {
+            return None;
+        }
+        let index = usize::try_from(index).ok()?;
+        if index < self.len_observed_locals {
+            return None;
+        }
+        self.reads.get(index).copied()
+    }
+
+    /// Redirects branches to unconditional branches to their final targets.
+    ///
+    /// Unconditional branches to returns are replaced by the return.
+    fn thread_jumps(&mut self, instrs: &mut [Instruction]) {
+        for index in 0..instrs.len() {
+            let Some(first) = self.branch_target(index, &instrs[index]) else {
+                continue;
+            };
+            let mut target = first;
+            for _ in 0..MAX_JUMP_THREADING_HOPS {
+                let Some(next) = instrs
+                    .get(target)
+                    .filter(|instr| matches!(instr, Instruction::Branch { .. }))
+                    .and_then(|instr| self.branch_target(target, instr))
+                else {
+                    break;
+                };
+                if next == target || next >= instrs.len() {
+                    break;
+                }
+                target = next;
+            }
+            if matches!(instrs[index], Instruction::Branch { .. }) && is_return(&instrs[target]) {
+                instrs[index] = instrs[target];
+                continue;
+            }
+            if target == first {
+                continue;
+            }
+            let Ok(offset) = i32::try_from(target as isize - index as isize) else {
+                continue;
+            };
+            let mut threaded = instrs[index];
+            if self.set_branch_offset(index, &mut threaded, offset).is_ok() {
+                instrs[index] = threaded;
+            }
+        }
+    }
+
+    /// Merges consecutive [`Instruction::ConsumeFuel`] without incoming branches in between.
+    fn merge_fuel(&mut self, instrs: &mut [Instruction]) {
+        self.mark_branch_targets(instrs);
+        let mut index = 0;
+        while index < instrs.len() {
+            let Instruction::ConsumeFuel { mut block_fuel } = instrs[index] else {
+                index += 1;
+                continue;
+            };
+            let mut next = index + 1;
+            while next < instrs.len() && !self.branch_targets[next] {
+                let Instruction::ConsumeFuel { block_fuel: fuel } = instrs[next] else {
+                    break;
+                };
+                if block_fuel.bump_by(fuel.to_u64()).is_err() {
+                    break;
+                }
+                self.removed[next] = true;
+                next += 1;
+            }
+            instrs[index] = Instruction::ConsumeFuel { block_fuel };
+            index = next;
+        }
+    }
+
+    /// Replaces reads of copied [`Reg`]s with reads of their originals.
+    ///
+    /// # Note
+    ///
+    /// - Copies are propagated across blocks as long as no branch targets the
+    ///   [`Instruction`] and are invalidated by writes to any of their [`Reg`]s.
+    /// - Copies that became self-copies are removed.
+    fn propagate_copies(&mut self, instrs: &mut [Instruction]) {
+        self.mark_branch_targets(instrs);
+        self.copies.clear();
+        for (index, instr) in instrs.iter_mut().enumerate() {
+            if self.branch_targets[index] {
+                self.copies.clear();
+            }
+            if !self.copies.is_empty() && is_rewritable(instr) {
+                instr.visit_regs(&mut CopyRewriter {
+                    copies: &self.copies,
+                });
+            }
+            if ends_copy_propagation(instr) {
+                self.copies.clear();
+                continue;
+            }
+            instr.visit_regs(&mut CopyInvalidator {
+                copies: &mut self.copies,
+            });
+            if let Instruction::Copy { result, value } = *instr {
+                match result == value {
+                    true => self.removed[index] = true,
+                    false => self.copies.push((result, value)),
+                }
+            }
+        }
+    }
+
+    /// Removes copies whose results are never read.
+    fn remove_dead_copies(&mut self, len_registers: u16, instrs: &[Instruction]) {
+        loop {
+            self.count_reads(len_registers, instrs);
+            let mut removed_any = false;
+            for (index, instr) in instrs.iter().enumerate() {
+                if self.removed[index] {
+                    continue;
+                }
+                let result = match *instr {
+                    | Instruction::Copy { result, .. }
+                    | Instruction::CopyImm32 { result, .. }
+                    | Instruction::CopyI64Imm32 { result, .. }
+                    | Instruction::CopyF64Imm32 { result, .. } => result,
+                    _ => continue,
+                };
+                if self.reads_of(result) == Some(0) {
+                    self.removed[index] = true;
+                    removed_any = true;
+                }
+            }
+            if !removed_any {
+                break;
+            }
+        }
+    }
+
+    /// Fuses compare instructions with their conditional branch users.
+    ///
+    /// # Note
+    ///
+    /// This only applies if the result of the compare instruction is solely read
+    /// by the directly following conditional branch without incoming branches.
+    /// The translator cannot fuse those if the result is stored in a function local.
+    fn fuse_cmp_branch(&mut self, len_registers: u16, instrs: &mut [Instruction]) {
+        self.mark_branch_targets(instrs);
+        self.count_reads(len_registers, instrs);
+        let mut index = 0;
+        while index + 1 < instrs.len() {
+            let next = index + 1;
+            if self.branch_targets[next] {
+                index += 1;
+                continue;
+            }
+            let (condition, offset, negate) = match instrs[next] {
+                Instruction::BranchI32NeImm16 { lhs, rhs, offset } if i32::from(rhs) == 0 => {
+                    (lhs, offset, false)
+                }
+                Instruction::BranchI32EqImm16 { lhs, rhs, offset } if i32::from(rhs) == 0 => {
+                    (lhs, offset, true)
+                }
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            };
+            let Some(fused) = self.try_fuse_cmp_branch(&instrs[index], condition, offset, negate)
+            else {
+                index += 1;
+                continue;
+            };
+            instrs[index] = fused;
+            self.removed[next] = true;
+            index += 2;
+        }
+    }
+
+    /// Tries to fuse the compare `instr` with its following conditional branch.
+    ///
+    /// The conditional branch branches by `offset` if `condition` is not zero or,
+    /// if `negate` is `true`, if `condition` is zero.
+    fn try_fuse_cmp_branch(
+        &self,
+        instr: &Instruction,
+        condition: Reg,
+        offset: BranchOffset16,
+        negate: bool,
+    ) -> Option<Instruction> {
+        let mut cmp = *instr;
+        let mut result = ResultReg::default();
+        cmp.visit_regs(&mut result);
+        if result.reg? != condition || self.reads_of(condition)? != 1 {
+            return None;
+        }
+        if negate {
+            cmp = cmp.negate_cmp_instr(true)?;
+        }
+        // Note: the fused instruction replaces the compare instruction
+        //       which precedes the conditional branch instruction.
+        let offset = i32::from(offset.to_i16()).checked_add(1)?;
+        let offset = BranchOffset16::try_from(BranchOffset::from(offset)).ok()?;
+        cmp.try_into_cmp_branch_instr16(offset)
+    }
+
+    /// Fuses loads of the default linear memory with their binary operation users.
+    ///
+    /// # Note
+    ///
+    /// - This only applies if the result of the load is solely read by the directly
+    ///   following binary operation without incoming branches or overwritten by it.
+    /// - The fused instruction replaces the load and its parameter replaces the binary operation.
+    ///   Therefore no instruction is removed and traps are attributed to the load.
+    fn fuse_load_op(&mut self, len_registers: u16, instrs: &mut [Instruction]) {
+        self.mark_branch_targets(instrs);
+        self.count_reads(len_registers, instrs);
+        let mut index = 0;
+        while index + 1 < instrs.len() {
+            let next = index + 1;
+            if self.branch_targets[next] {
+                index += 1;
+                continue;
+            }
+            let Some([fused, param]) = self.try_fuse_load_op(&instrs[index], &instrs[next]) else {
+                index += 1;
+                continue;
+            };
+            instrs[index] = fused;
+            instrs[next] = param;
+            index += 2;
+        }
+    }
+
+    /// Tries to fuse the `load` instruction with its following binary operation `op`.
+    ///
+    /// Returns the fused instruction and its parameter instruction if possible.
+    fn try_fuse_load_op(&self, load: &Instruction, op: &Instruction) -> Option<[Instruction; 2]> {
+        type MakeFused = fn(Reg, Reg) -> Instruction;
+        let (loaded, ptr, offset, result, lhs, rhs, make_fused): (_, _, _, _, _, _, MakeFused) =
+            match (*load, *op) {
+                (
+                    Instruction::Load32Offset16 {
+                        result: loaded,
+                        ptr,
+                        offset,
+                    },
+                    Instruction::I32Add { result, lhs, rhs },
+                ) => (
+                    loaded,
+                    ptr,
+                    offset,
+                    result,
+                    lhs,
+                    rhs,
+                    Instruction::i32_add_load32,
+                ),
+                (
+                    Instruction::Load32Offset16Unchecked {
+                        result: loaded,
+                        ptr,
+                        offset,
+                    },
+                    Instruction::I32Add { result, lhs, rhs },
+                ) => (
+                    loaded,
+                    ptr,
+                    offset,
+                    result,
+                    lhs,
+                    rhs,
+                    Instruction::i32_add_load32_unchecked,
+                ),
+                (
+                    Instruction::Load64Offset16 {
+                        result: loaded,
+                        ptr,
+                        offset,
+                    },
+                    Instruction::I64Add { result, lhs, rhs },
+                ) => (
+                    loaded,
+                    ptr,
+                    offset,
+                    result,
+                    lhs,
+                    rhs,
+                    Instruction::i64_add_load64,
+                ),
+                (
+                    Instruction::Load64Offset16Unchecked {
+                        result: loaded,
+                        ptr,
+                        offset,
+                    },
+                    Instruction::I64Add { result, lhs, rhs },
+                ) => (
+                    loaded,
+                    ptr,
+                    offset,
+                    result,
+                    lhs,
+                    rhs,
+                    Instruction::i64_add_load64_unchecked,
+                ),
+                _ => return None,
+            };
+        // Note: both supported binary operations are commutative.
+        let lhs = match (lhs == loaded, rhs == loaded) {
+            (true, false) => rhs,
+            (false, true) => lhs,
+            _ => return None,
+        };
+        // Note: the loaded value is dead after the binary operation if it overwrites it.
+        if result != loaded && self.reads_of(loaded)? != 1 {
+            return None;
+        }
+        Some([
+            make_fused(result, lhs),
+            Instruction::register_and_imm32(ptr, u32::from(offset)),
+        ])
+    }
+
+    /// Replaces accesses of the default linear memory known to be in bounds by their unchecked variants.
+    ///
+    /// # Note
+    ///
+    /// - An access through a `ptr` [`Reg`] is known to be in bounds if all paths to it pass
+    ///   a succeeding access through the unchanged `ptr` that covers at least the same bytes.
+    /// - An access of a constant address is known to be in bounds if it is below `min_memory_bytes`
+    ///   or if all paths to it pass a succeeding constant access that covers at least the same bytes.
+    /// - This relies on linear memories never shrinking.
+    /// - Bounds checks are kept if the analysis does not reach a fixed point
+    ///   within [`MAX_BOUNDS_SWEEPS`] sweeps.
+    fn eliminate_bounds_checks(&mut self, min_memory_bytes: u64, instrs: &mut [Instruction]) {
+        if !instrs.iter().any(|instr| into_unchecked(*instr).is_some()) {
+            return;
+        }
+        self.bounds.clear();
+        self.bounds.resize(instrs.len(), None);
+        for _ in 0..MAX_BOUNDS_SWEEPS {
+            if !self.sweep_bounds(min_memory_bytes, instrs, false) {
+                self.sweep_bounds(min_memory_bytes, instrs, true);
+                return;
+            }
+        }
+    }
+
+    /// Propagates the known in-bounds ranges of the default linear memory through `instrs`.
+    ///
+    /// - Replaces accesses known to be in bounds by their unchecked variants if `rewrite` is `true`.
+    /// - Returns `true` if the known in-bounds ranges of a backward branch target changed.
+    fn sweep_bounds(
+        &mut self,
+        min_memory_bytes: u64,
+        instrs: &mut [Instruction],
+        rewrite: bool,
+    ) -> bool {
+        let mut changed = false;
+        // Note: `None` if the current instruction is unreachable via fall through.
+        let mut bounds = Some(Bounds::new(min_memory_bytes));
+        // Note: the known in-bounds ranges upon executing the current branch table
+        //       and the number of its remaining targets.
+        let mut table = None;
+        // Note: the number of remaining handlers of the current `resume` instruction.
+        let mut resume_handlers = 0_u16;
+        for index in 0..instrs.len() {
+            let mut instr = instrs[index];
+            if resume_handlers != 0 {
+                // Note: the handlers of a `resume` branch to their targets upon suspension
+                //       whereas execution falls through the `resume` once it returns.
+                if !matches!(instr, Instruction::ResumeHandler { .. }) {
+                    resume_handlers -= 1;
+                }
+                if let (Some(current), Some(target)) = (&bounds, self.branch_target(index, &instr))
+                {
+                    let mut handler = current.clone();
+                    invalidate_written_regs(&mut handler, &mut instr);
+                    changed |= self.join_bounds_at(index, target, &handler);
+                }
+                continue;
+            }
+            if let Some((table_bounds, remaining)) = &mut table {
+                bounds = Some(Bounds::clone(table_bounds));
+                if !is_param(&instr) {
+                    *remaining -= 1;
+                }
+                if *remaining == 0 {
+                    table = None;
+                }
+            }
+            if let Some(incoming) = &self.bounds[index] {
+                match &mut bounds {
+                    Some(bounds) => {
+                        bounds.join(incoming);
+                    }
+                    None => bounds = Some(incoming.clone()),
+                }
+            }
+            if let Instruction::ResumeParams { len_handlers, .. } = instr {
+                resume_handlers = len_handlers;
+            }
+            let Some(current) = &mut bounds else {
+                continue;
+            };
+            if let Some(access) = memory_access(instrs, index) {
+                if rewrite && current.contains(access) {
+                    if let Some(unchecked) = into_unchecked(instr) {
+                        instrs[index] = unchecked;
+                    }
+                }
+                current.insert(access);
+            }
+            let copied = match instr {
+                Instruction::Copy { result, value } => {
+                    current.end_of(value).map(|end| (result, end))
+                }
+                _ => None,
+            };
+            invalidate_written_regs(current, &mut instr);
+            if let Some((result, end)) = copied {
+                current.insert(MemoryAccess::Ptr { ptr: result, end });
+            }
+            if let Some(target) = self.branch_target(index, &instr) {
+                changed |= self.join_bounds_at(index, target, current);
+            }
+            if let Some(len_targets) = branch_table_len(&instr) {
+                // Note: over-approximating the targets of a branch table is sound
+                //       since it only ever shrinks the known in-bounds ranges.
+                table = bounds
+                    .take()
+                    .filter(|_| len_targets != 0)
+                    .map(|bounds| (bounds, len_targets));
+            } else if ends_control_flow(&instr) {
+                bounds = None;
+            }
+        }
+        changed
+    }
+
+    /// Joins `bounds` into the known in-bounds ranges upon branching from `index` to `target`.
+    ///
+    /// Returns `true` if the known in-bounds ranges of the backward branch `target` changed.
+    fn join_bounds_at(&mut self, index: usize, target: usize, bounds: &Bounds) -> bool {
+        let Some(entry) = self.bounds.get_mut(target) else {
+            return false;
+        };
+        let changed = match entry {
+            Some(entry) => entry.join(bounds),
+            None => {
+                *entry = Some(bounds.clone());
+                true
+            }
+        };
+        changed && target <= index
+    }
+
+    /// Removes all [`Instruction`]s marked as removed from `instrs`.
+    ///
+    /// Adjusts all branch offsets, `wasm_offsets` and `inlined_calls` to the compacted `instrs`.
+    fn compact(
+        &mut self,
+        instrs: &mut Vec<Instruction>,
+        wasm_offsets: &mut Vec<(u32, u32)>,
+        inlined_calls: &mut [InlinedCall],
+    ) {
+        if !self.removed.contains(&true) {
+            return;
+        }
+        self.new_indices.clear();
+        let mut len_kept = 0_u32;
+        for removed in &self.removed {
+            self.new_indices.push(len_kept);
+            len_kept += u32::from(!removed);
+        }
+        self.new_indices.push(len_kept);
+        for (index, instr) in instrs.iter_mut().enumerate() {
+            if self.removed[index] {
+                continue;
+            }
+            let Some(target) = self.branch_target(index, instr) else {
+                continue;
+            };
+            let offset = self.new_indices[target] as i32 - self.new_indices[index] as i32;
+            // Note: removing instructions only ever shrinks branch offsets.
+            self.set_branch_offset(index, instr, offset)
+                .unwrap_or_else(|error| {
+                    panic!("failed to update branch offset of compacted instruction: {error}")
+                });
+        }
+        for fallback in &mut self.fallbacks {
+            fallback.index = self.new_indices[fallback.index] as usize;
+        }
+        let mut index = 0;
+        instrs.retain(|_| {
+            let removed = self.removed[index];
+            index += 1;
+            !removed
+        });
+        let mut len_offsets = 0_usize;
+        for n in 0..wasm_offsets.len() {
+            let (instr, pos) = wasm_offsets[n];
+            let instr = self.new_indices[instr as usize];
+            match len_offsets
+                .checked_sub(1)
+                .map(|last| &mut wasm_offsets[last])
+            {
+                // Note: all instructions of the previous entry have been removed.
+                Some((last, last_pos)) if *last == instr => *last_pos = pos,
+                _ => {
+                    wasm_offsets[len_offsets] = (instr, pos);
+                    len_offsets += 1;
+                }
+            }
+        }
+        wasm_offsets.truncate(len_offsets);
+        for call in inlined_calls {
+            call.start = self.new_indices[call.start as usize];
+            call.end = self.new_indices[call.end as usize];
+        }
+        self.removed.clear();
+        self.removed.resize(instrs.len(), false);
+    }
+}
+
+/// Invalidates the known in-bounds ranges of all [`Reg`]s written by `instr` in `bounds`.
+fn invalidate_written_regs(bounds: &mut Bounds, instr: &mut Instruction) {
+    instr.visit_regs(&mut BoundsInvalidator { bounds });
+    match *instr {
+        Instruction::BranchTableTarget { results, .. }
+        | Instruction::BranchTableTargetNonOverlapping { results, .. } => {
+            // Note: the number of values copied to `results` is unknown here.
+            let head = i16::from(results.head());
+            bounds.invalidate(|reg| i16::from(reg) >= head);
+        }
+        _ => {}
+    }
+}
+
+/// Returns `true` if `instr` returns from the function without using parameter instructions.
+fn is_return(instr: &Instruction) -> bool {
+    matches!(
+        instr,
+        Instruction::Return
+            | Instruction::ReturnReg { .. }
+            | Instruction::ReturnReg2 { .. }
+            | Instruction::ReturnReg3 { .. }
+            | Instruction::ReturnImm32 { .. }
+            | Instruction::ReturnI64Imm32 { .. }
+            | Instruction::ReturnF64Imm32 { .. }
+            | Instruction::ReturnSpan { .. }
+    )
+}
+
+/// Returns `true` if the input [`Reg`]s of `instr` may be rewritten by copy propagation.
+///
+/// # Note
+///
+/// - Parameter instructions are excluded since their semantics depend on their parent.
+/// - Copy instructions of multiple values are excluded since they may rely on their
+///   [`Reg`]s not overlapping.
+fn is_rewritable(instr: &Instruction) -> bool {
+    !matches!(
+        instr,
+        Instruction::Register { .. }
+            | Instruction::Register2 { .. }
+            | Instruction::Register3 { .. }
+            | Instruction::RegisterList { .. }
+            | Instruction::RegisterSpan { .. }
+            | Instruction::RegisterAndImm32 { .. }
+            | Instruction::Imm16AndImm32 { .. }
+            | Instruction::CallIndirectParams { .. }
+            | Instruction::CallIndirectParamsImm16 { .. }
+            | Instruction::BranchTableTarget { .. }
+            | Instruction::BranchTableTargetNonOverlapping { .. }
+            | Instruction::Copy2 { .. }
+            | Instruction::CopySpan { .. }
+            | Instruction::CopySpanNonOverlapping { .. }
+            | Instruction::CopyMany { .. }
+            | Instruction::CopyManyNonOverlapping { .. }
+    )
+}
+
+/// Returns the number of targets of the branch table `instr` if any.
+fn branch_table_len(instr: &Instruction) -> Option<u32> {
+    match *instr {
+        Instruction::BranchTable0 { len_targets, .. }
+        | Instruction::BranchTable1 { len_targets, .. }
+        | Instruction::BranchTable2 { len_targets, .. }
+        | Instruction::BranchTable3 { len_targets, .. }
+        | Instruction::BranchTableSpan { len_targets, .. }
+        | Instruction::BranchTableMany { len_targets, .. } => Some(len_targets),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `instr` is a parameter of a branch table that is not one of its targets.
+fn is_param(instr: &Instruction) -> bool {
+    matches!(
+        instr,
+        Instruction::Register { .. }
+            | Instruction::Register2 { .. }
+            | Instruction::Register3 { .. }
+            | Instruction::RegisterList { .. }
+            | Instruction::RegisterSpan { .. }
+            | Instruction::Const32 { .. }
+            | Instruction::I64Const32 { .. }
+            | Instruction::F64Const32 { .. }
+    )
+}
+
+/// Returns `true` if execution never falls through `instr`.
+fn ends_control_flow(instr: &Instruction) -> bool {
+    matches!(
+        instr,
+        Instruction::Trap { .. }
+            | Instruction::Branch { .. }
+            | Instruction::ReturnCallInternal0 { .. }
+            | Instruction::ReturnCallInternal { .. }
+            | Instruction::ReturnCallImported0 { .. }
+            | Instruction::ReturnCallImported { .. }
+            | Instruction::ReturnCallIndirect0 { .. }
+            | Instruction::ReturnCallIndirect0Imm16 { .. }
+            | Instruction::ReturnCallIndirect { .. }
+            | Instruction::ReturnCallIndirectImm16 { .. }
+            | Instruction::ReturnCallRef0 { .. }
+            | Instruction::ReturnCallRef { .. }
+            | Instruction::ReturnMany { .. }
+            | Instruction::BranchTableTarget { .. }
+            | Instruction::BranchTableTargetNonOverlapping { .. }
+    ) || branch_table_len(instr).is_some()
+        || is_return(instr)
+}
+
+/// Returns `true` if `instr` invalidates all copies for copy propagation.
+///
+/// # Note
+///
+/// Those are calls and instructions after which execution never falls through.
+fn ends_copy_propagation(instr: &Instruction) -> bool {
+    matches!(
+        instr,
+        Instruction::CallInternal0 { .. }
+            | Instruction::CallInternal { .. }
+            | Instruction::CallImported0 { .. }
+            | Instruction::CallImported { .. }
+            | Instruction::CallIndirect0 { .. }
+            | Instruction::CallIndirect0Imm16 { .. }
+            | Instruction::CallIndirect { .. }
+            | Instruction::CallIndirectImm16 { .. }
+            | Instruction::CallRef0 { .. }
+            | Instruction::CallRef { .. }
+    ) || ends_control_flow(instr)
+}
+
+/// The known in-bounds ranges of the default linear memory at some point of a function.
+#[derive(Debug, Clone)]
+struct Bounds {
+    /// All bytes below this address are in bounds.
+    const_end: u64,
+    /// The pairs of `(ptr, end)` for which all bytes from the address stored in `ptr` up to `ptr+end` are in bounds.
+    ptrs: Vec<(Reg, u64)>,
+}
+
+impl Bounds {
+    /// Creates new [`Bounds`] for a default linear memory of at least `min_memory_bytes`.
+    fn new(min_memory_bytes: u64) -> Self {
+        Self {
+            const_end: min_memory_bytes,
+            ptrs: Vec::new(),
+        }
+    }
+
+    /// Returns the end of the known in-bounds range of `ptr` if any.
+    fn end_of(&self, ptr: Reg) -> Option<u64> {
+        self.ptrs
+            .iter()
+            .find(|(reg, _)| *reg == ptr)
+            .map(|(_, end)| *end)
+    }
+
+    /// Returns `true` if all bytes of `access` are known to be in bounds.
+    fn contains(&self, access: MemoryAccess) -> bool {
+        match access {
+            MemoryAccess::Ptr { ptr, end } => self.end_of(ptr).is_some_and(|known| end <= known),
+            MemoryAccess::Const { end } => end <= self.const_end,
+        }
+    }
+
+    /// Records that all bytes of the succeeded `access` are in bounds.
+    fn insert(&mut self, access: MemoryAccess) {
+        match access {
+            MemoryAccess::Ptr { ptr, end } => {
+                if let Some((_, known)) = self.ptrs.iter_mut().find(|(reg, _)| *reg == ptr) {
+                    *known = (*known).max(end);
+                } else if self.ptrs.len() < MAX_BOUNDS_PTRS {
+                    self.ptrs.push((ptr, end));
+                }
+            }
+            MemoryAccess::Const { end } => self.const_end = self.const_end.max(end),
+        }
+    }
+
+    /// Invalidates the known in-bounds ranges of all `ptr` for which `is_written` returns `true`.
+    fn invalidate(&mut self, is_written: impl Fn(Reg) -> bool) {
+        self.ptrs.retain(|(ptr, _)| !is_written(*ptr));
+    }
+
+    /// Joins `other` into `self` keeping only the ranges known to be in bounds by both.
+    ///
+    /// Returns `true` if `self` changed.
+    fn join(&mut self, other: &Self) -> bool {
+        let mut changed = false;
+        if other.const_end < self.const_end {
+            self.const_end = other.const_end;
+            changed = true;
+        }
+        self.ptrs.retain_mut(|(ptr, end)| {
+            let Some(other_end) = other.end_of(*ptr) else {
+                changed = true;
+                return false;
+            };
+            if other_end < *end {
+                *end = other_end;
+                changed = true;
+            }
+            true
+        });
+        changed
+    }
+}
+
+/// An access of the default linear memory by an [`Instruction`].
+#[derive(Debug, Copy, Clone)]
+enum MemoryAccess {
+    /// Accesses the bytes from the address stored in `ptr` up to `ptr+end`.
+    Ptr { ptr: Reg, end: u64 },
+    /// Accesses bytes below the constant address `end`.
+    Const { end: u64 },
+}
+
+/// Returns the access of the default linear memory of the [`Instruction`] at `index` if any.
+///
+/// # Note
+///
+/// Accesses of other linear memories are ignored.
+fn memory_access(instrs: &[Instruction], index: usize) -> Option<MemoryAccess> {
+    let size = memory_access_size(&instrs[index])?;
+    let next = instrs.get(index + 1);
+    let access = match instrs[index] {
+        Instruction::Load32Offset16 { ptr, offset, .. }
+        | Instruction::Load64Offset16 { ptr, offset, .. }
+        | Instruction::I32Load8sOffset16 { ptr, offset, .. }
+        | Instruction::I32Load8uOffset16 { ptr, offset, .. }
+        | Instruction::I32Load16sOffset16 { ptr, offset, .. }
+        | Instruction::I32Load16uOffset16 { ptr, offset, .. }
+        | Instruction::I64Load8sOffset16 { ptr, offset, .. }
+        | Instruction::I64Load8uOffset16 { ptr, offset, .. }
+        | Instruction::I64Load16sOffset16 { ptr, offset, .. }
+        | Instruction::I64Load16uOffset16 { ptr, offset, .. }
+        | Instruction::I64Load32sOffset16 { ptr, offset, .. }
+        | Instruction::I64Load32uOffset16 { ptr, offset, .. }
+        | Instruction::Store32Offset16 { ptr, offset, .. }
+        | Instruction::Store64Offset16 { ptr, offset, .. }
+        | Instruction::I32StoreOffset16Imm16 { ptr, offset, .. }
+        | Instruction::I32Store8Offset16 { ptr, offset, .. }
+        | Instruction::I32Store8Offset16Imm { ptr, offset, .. }
+        | Instruction::I32Store16Offset16 { ptr, offset, .. }
+        | Instruction::I32Store16Offset16Imm { ptr, offset, .. }
+        | Instruction::I64StoreOffset16Imm16 { ptr, offset, .. }
+        | Instruction::I64Store8Offset16 { ptr, offset, .. }
+        | Instruction::I64Store8Offset16Imm { ptr, offset, .. }
+        | Instruction::I64Store16Offset16 { ptr, offset, .. }
+        | Instruction::I64Store16Offset16Imm { ptr, offset, .. }
+        | Instruction::I64Store32Offset16 { ptr, offset, .. }
+        | Instruction::I64Store32Offset16Imm16 { ptr, offset, .. } => MemoryAccess::Ptr {
+            ptr,
+            end: u64::from(u32::from(offset)) + size,
+        },
+        Instruction::Load32At { address, .. }
+        | Instruction::Load64At { address, .. }
+        | Instruction::I32Load8sAt { address, .. }
+        | Instruction::I32Load8uAt { address, .. }
+        | Instruction::I32Load16sAt { address, .. }
+        | Instruction::I32Load16uAt { address, .. }
+        | Instruction::I64Load8sAt { address, .. }
+        | Instruction::I64Load8uAt { address, .. }
+        | Instruction::I64Load16sAt { address, .. }
+        | Instruction::I64Load16uAt { address, .. }
+        | Instruction::I64Load32sAt { address, .. }
+        | Instruction::I64Load32uAt { address, .. }
+        | Instruction::Store32At { address, .. }
+        | Instruction::Store64At { address, .. }
+        | Instruction::I32StoreAtImm16 { address, .. }
+        | Instruction::I32Store8At { address, .. }
+        | Instruction::I32Store8AtImm { address, .. }
+        | Instruction::I32Store16At { address, .. }
+        | Instruction::I32Store16AtImm { address, .. }
+        | Instruction::I64StoreAtImm16 { address, .. }
+        | Instruction::I64Store8At { address, .. }
+        | Instruction::I64Store8AtImm { address, .. }
+        | Instruction::I64Store16At { address, .. }
+        | Instruction::I64Store16AtImm { address, .. }
+        | Instruction::I64Store32At { address, .. }
+        | Instruction::I64Store32AtImm16 { address, .. } => {
+            if let Some(Instruction::MemoryIndex { .. }) = next {
+                return None;
+            }
+            MemoryAccess::Const {
+                end: u64::from(address) + size,
+            }
+        }
+        Instruction::Load32 { memory, .. }
+        | Instruction::Load64 { memory, .. }
+        | Instruction::I32Load8s { memory, .. }
+        | Instruction::I32Load8u { memory, .. }
+        | Instruction::I32Load16s { memory, .. }
+        | Instruction::I32Load16u { memory, .. }
+        | Instruction::I64Load8s { memory, .. }
+        | Instruction::I64Load8u { memory, .. }
+        | Instruction::I64Load16s { memory, .. }
+        | Instruction::I64Load16u { memory, .. }
+        | Instruction::I64Load32s { memory, .. }
+        | Instruction::I64Load32u { memory, .. } => {
+            if !memory.is_default() {
+                return None;
+            }
+            let Some(Instruction::RegisterAndImm32 { reg, imm }) = next else {
+                return None;
+            };
+            MemoryAccess::Ptr {
+                ptr: *reg,
+                end: u64::from(u32::from(*imm)) + size,
+            }
+        }
+        Instruction::Store32 { ptr, memory }
+        | Instruction::Store64 { ptr, memory }
+        | Instruction::I32StoreImm16 { ptr, memory }
+        | Instruction::I32Store8 { ptr, memory }
+        | Instruction::I32Store8Imm { ptr, memory }
+        | Instruction::I32Store16 { ptr, memory }
+        | Instruction::I32Store16Imm { ptr, memory }
+        | Instruction::I64StoreImm16 { ptr, memory }
+        | Instruction::I64Store8 { ptr, memory }
+        | Instruction::I64Store8Imm { ptr, memory }
+        | Instruction::I64Store16 { ptr, memory }
+        | Instruction::I64Store16Imm { ptr, memory }
+        | Instruction::I64Store32 { ptr, memory }
+        | Instruction::I64Store32Imm16 { ptr, memory } => {
+            if !memory.is_default() {
+                return None;
+            }
+            let offset = match next? {
+                Instruction::RegisterAndImm32 { imm, .. } => *imm,
+                Instruction::Imm16AndImm32 { imm32, .. } => *imm32,
+                _ => return None,
+            };
+            MemoryAccess::Ptr {
+                ptr,
+                end: u64::from(u32::from(offset)) + size,
+            }
+        }
+        _ => return None,
+    };
+    Some(access)
+}
+
+/// Returns the number of bytes accessed by the linear memory access `instr` if any.
+fn memory_access_size(instr: &Instruction) -> Option<u64> {
+    let size = match instr {
+        Instruction::I32Load8s { .. }
+        | Instruction::I32Load8sAt { .. }
+        | Instruction::I32Load8sOffset16 { .. }
+        | Instruction::I32Load8u { .. }
+        | Instruction::I32Load8uAt { .. }
+        | Instruction::I32Load8uOffset16 { .. }
+        | Instruction::I64Load8s { .. }
+        | Instruction::I64Load8sAt { .. }
+        | Instruction::I64Load8sOffset16 { .. }
+        | Instruction::I64Load8u { .. }
+        | Instruction::I64Load8uAt { .. }
+        | Instruction::I64Load8uOffset16 { .. }
+        | Instruction::I32Store8 { .. }
+        | Instruction::I32Store8Imm { .. }
+        | Instruction::I32Store8Offset16 { .. }
+        | Instruction::I32Store8Offset16Imm { .. }
+        | Instruction::I32Store8At { .. }
+        | Instruction::I32Store8AtImm { .. }
+        | Instruction::I64Store8 { .. }
+        | Instruction::I64Store8Imm { .. }
+        | Instruction::I64Store8Offset16 { .. }
+        | Instruction::I64Store8Offset16Imm { .. }
+        | Instruction::I64Store8At { .. }
+        | Instruction::I64Store8AtImm { .. } => 1,
+        Instruction::I32Load16s { .. }
+        | Instruction::I32Load16sAt { .. }
+        | Instruction::I32Load16sOffset16 { .. }
+        | Instruction::I32Load16u { .. }
+        | Instruction::I32Load16uAt { .. }
+        | Instruction::I32Load16uOffset16 { .. }
+        | Instruction::I64Load16s { .. }
+        | Instruction::I64Load16sAt { .. }
+        | Instruction::I64Load16sOffset16 { .. }
+        | Instruction::I64Load16u { .. }
+        | Instruction::I64Load16uAt { .. }
+        | Instruction::I64Load16uOffset16 { .. }
+        | Instruction::I32Store16 { .. }
+        | Instruction::I32Store16Imm { .. }
+        | Instruction::I32Store16Offset16 { .. }
+        | Instruction::I32Store16Offset16Imm { .. }
+        | Instruction::I32Store16At { .. }
+        | Instruction::I32Store16AtImm { .. }
+        | Instruction::I64Store16 { .. }
+        | Instruction::I64Store16Imm { .. }
+        | Instruction::I64Store16Offset16 { .. }
+        | Instruction::I64Store16Offset16Imm { .. }
+        | Instruction::I64Store16At { .. }
+        | Instruction::I64Store16AtImm { .. } => 2,
+        Instruction::Load32 { .. }
+        | Instruction::Load32At { .. }
+        | Instruction::Load32Offset16 { .. }
+        | Instruction::I64Load32s { .. }
+        | Instruction::I64Load32sAt { .. }
+        | Instruction::I64Load32sOffset16 { .. }
+        | Instruction::I64Load32u { .. }
+        | Instruction::I64Load32uAt { .. }
+        | Instruction::I64Load32uOffset16 { .. }
+        | Instruction::Store32 { .. }
+        | Instruction::Store32Offset16 { .. }
+        | Instruction::Store32At { .. }
+        | Instruction::I32StoreImm16 { .. }
+        | Instruction::I32StoreOffset16Imm16 { .. }
+        | Instruction::I32StoreAtImm16 { .. }
+        | Instruction::I64Store32 { .. }
+        | Instruction::I64Store32Imm16 { .. }
+        | Instruction::I64Store32Offset16 { .. }
+        | Instruction::I64Store32Offset16Imm16 { .. }
+        | Instruction::I64Store32At { .. }
+        | Instruction::I64Store32AtImm16 { .. } => 4,
+        Instruction::Load64 { .. }
+        | Instruction::Load64At { .. }
+        | Instruction::Load64Offset16 { .. }
+        | Instruction::Store64 { .. }
+        | Instruction::Store64Offset16 { .. }
+        | Instruction::Store64At { .. }
+        | Instruction::I64StoreImm16 { .. }
+        | Instruction::I64StoreOffset16Imm16 { .. }
+        | Instruction::I64StoreAtImm16 { .. } => 8,
+        _ => return None,
+    };
+    Some(size)
+}
+
+/// Returns the variant of the linear memory access `instr` without bounds checks if any.
+fn into_unchecked(instr: Instruction) -> Option<Instruction> {
+    let unchecked = match instr {
+        Instruction::Load32At { result, address } => {
+            Instruction::Load32AtUnchecked { result, address }
+        }
+        Instruction::Load32Offset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::Load32Offset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::Load64At { result, address } => {
+            Instruction::Load64AtUnchecked { result, address }
+        }
+        Instruction::Load64Offset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::Load64Offset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I32Load8sAt { result, address } => {
+            Instruction::I32Load8sAtUnchecked { result, address }
+        }
+        Instruction::I32Load8sOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I32Load8sOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I32Load8uAt { result, address } => {
+            Instruction::I32Load8uAtUnchecked { result, address }
+        }
+        Instruction::I32Load8uOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I32Load8uOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I32Load16sAt { result, address } => {
+            Instruction::I32Load16sAtUnchecked { result, address }
+        }
+        Instruction::I32Load16sOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I32Load16sOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I32Load16uAt { result, address } => {
+            Instruction::I32Load16uAtUnchecked { result, address }
+        }
+        Instruction::I32Load16uOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I32Load16uOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I64Load8sAt { result, address } => {
+            Instruction::I64Load8sAtUnchecked { result, address }
+        }
+        Instruction::I64Load8sOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I64Load8sOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I64Load8uAt { result, address } => {
+            Instruction::I64Load8uAtUnchecked { result, address }
+        }
+        Instruction::I64Load8uOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I64Load8uOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I64Load16sAt { result, address } => {
+            Instruction::I64Load16sAtUnchecked { result, address }
+        }
+        Instruction::I64Load16sOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I64Load16sOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I64Load16uAt { result, address } => {
+            Instruction::I64Load16uAtUnchecked { result, address }
+        }
+        Instruction::I64Load16uOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I64Load16uOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I64Load32sAt { result, address } => {
+            Instruction::I64Load32sAtUnchecked { result, address }
+        }
+        Instruction::I64Load32sOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I64Load32sOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::I64Load32uAt { result, address } => {
+            Instruction::I64Load32uAtUnchecked { result, address }
+        }
+        Instruction::I64Load32uOffset16 {
+            result,
+            ptr,
+            offset,
+        } => Instruction::I64Load32uOffset16Unchecked {
+            result,
+            ptr,
+            offset,
+        },
+        Instruction::Store32Offset16 { ptr, offset, value } => {
+            Instruction::Store32Offset16Unchecked { ptr, offset, value }
+        }
+        Instruction::Store32At { value, address } => {
+            Instruction::Store32AtUnchecked { value, address }
+        }
+        Instruction::Store64Offset16 { ptr, offset, value } => {
+            Instruction::Store64Offset16Unchecked { ptr, offset, value }
+        }
+        Instruction::Store64At { value, address } => {
+            Instruction::Store64AtUnchecked { value, address }
+        }
+        Instruction::I32StoreOffset16Imm16 { ptr, offset, value } => {
+            Instruction::I32StoreOffset16Imm16Unchecked { ptr, offset, value }
+        }
+        Instruction::I32StoreAtImm16 { value, address } => {
+            Instruction::I32StoreAtImm16Unchecked { value, address }
+        }
+        Instruction::I32Store8Offset16 { ptr, offset, value } => {
+            Instruction::I32Store8Offset16Unchecked { ptr, offset, value }
+        }
+        Instruction::I32Store8Offset16Imm { ptr, offset, value } => {
+            Instruction::I32Store8Offset16ImmUnchecked { ptr, offset, value }
+        }
+        Instruction::I32Store8At { value, address } => {
+            Instruction::I32Store8AtUnchecked { value, address }
+        }
+        Instruction::I32Store8AtImm { value, address } => {
+            Instruction::I32Store8AtImmUnchecked { value, address }
+        }
+        Instruction::I32Store16Offset16 { ptr, offset, value } => {
+            Instruction::I32Store16Offset16Unchecked { ptr, offset, value }
+        }
+        Instruction::I32Store16Offset16Imm { ptr, offset, value } => {
+            Instruction::I32Store16Offset16ImmUnchecked { ptr, offset, value }
+        }
+        Instruction::I32Store16At { value, address } => {
+            Instruction::I32Store16AtUnchecked { value, address }
+        }
+        Instruction::I32Store16AtImm { value, address } => {
+            Instruction::I32Store16AtImmUnchecked { value, address }
+        }
+        Instruction::I64StoreOffset16Imm16 { ptr, offset, value } => {
+            Instruction::I64StoreOffset16Imm16Unchecked { ptr, offset, value }
+        }
+        Instruction::I64StoreAtImm16 { value, address } => {
+            Instruction::I64StoreAtImm16Unchecked { value, address }
+        }
+        Instruction::I64Store8Offset16 { ptr, offset, value } => {
+            Instruction::I64Store8Offset16Unchecked { ptr, offset, value }
+        }
+        Instruction::I64Store8Offset16Imm { ptr, offset, value } => {
+            Instruction::I64Store8Offset16ImmUnchecked { ptr, offset, value }
+        }
+        Instruction::I64Store8At { value, address } => {
+            Instruction::I64Store8AtUnchecked { value, address }
+        }
+        Instruction::I64Store8AtImm { value, address } => {
+            Instruction::I64Store8AtImmUnchecked { value, address }
+        }
+        Instruction::I64Store16Offset16 { ptr, offset, value } => {
+            Instruction::I64Store16Offset16Unchecked { ptr, offset, value }
+        }
+        Instruction::I64Store16Offset16Imm { ptr, offset, value } => {
+            Instruction::I64Store16Offset16ImmUnchecked { ptr, offset, value }
+        }
+        Instruction::I64Store16At { value, address } => {
+            Instruction::I64Store16AtUnchecked { value, address }
+        }
+        Instruction::I64Store16AtImm { value, address } => {
+            Instruction::I64Store16AtImmUnchecked { value, address }
+        }
+        Instruction::I64Store32Offset16 { ptr, offset, value } => {
+            Instruction::I64Store32Offset16Unchecked { ptr, offset, value }
+        }
+        Instruction::I64Store32Offset16Imm16 { ptr, offset, value } => {
+            Instruction::I64Store32Offset16Imm16Unchecked { ptr, offset, value }
+        }
+        Instruction::I64Store32At { value, address } => {
+            Instruction::I64Store32AtUnchecked { value, address }
+        }
+        Instruction::I64Store32AtImm16 { value, address } => {
+            Instruction::I64Store32AtImm16Unchecked { value, address }
+        }
+        _ => return None,
+    };
+    Some(unchecked)
+}
+
+/// Counts the reads of non-constant [`Reg`]s via [`VisitRegs`].
+struct ReadCounter<'a> {
+    /// The number of reads per non-constant [`Reg`].
+    reads: &'a mut [u32],
+    /// The first [`Reg`] of all unbounded input [`RegSpan`]s if any.
+    unbounded: Option<i16>,
+}
+
+impl ReadCounter<'_> {
+    /// Counts a single read of `reg`.
+    fn read(&mut self, reg: Reg) {
+        let Ok(index) = usize::try_from(i16::from(reg)) else {
+            // Note: reads of function local constants are not counted.
+            return;
+        };
+        if let Some(reads) = self.reads.get_mut(index) {
+            *reads = reads.saturating_add(1);
+        }
+    }
+}
+
+impl VisitRegs for ReadCounter<'_> {
+    fn visit_result_reg(&mut self, _reg: &mut Reg) {}
+
+    fn visit_result_regs(&mut self, _regs: &mut RegSpan, _len: Option<u16>) {}
+
+    fn visit_input_reg(&mut self, reg: &mut Reg) {
+        self.read(*reg)
+    }
+
+    fn visit_input_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        match len {
+            Some(len) => {
+                for reg in regs.iter(len) {
+                    self.read(reg)
+                }
+            }
+            None => {
+                let head = i16::from(regs.head());
+                self.unbounded = Some(self.unbounded.map_or(head, |min| min.min(head)));
+            }
+        }
+    }
+}
+
+/// Rewrites single input [`Reg`]s to the original [`Reg`] of their copies via [`VisitRegs`].
+struct CopyRewriter<'a> {
+    /// The pairs of `(result, value)` [`Reg`]s of all currently valid copies.
+    copies: &'a [(Reg, Reg)],
+}
+
+impl VisitRegs for CopyRewriter<'_> {
+    fn visit_result_reg(&mut self, _reg: &mut Reg) {}
+
+    fn visit_result_regs(&mut self, _regs: &mut RegSpan, _len: Option<u16>) {}
+
+    fn visit_input_reg(&mut self, reg: &mut Reg) {
+        if let Some((_, value)) = self.copies.iter().find(|(result, _)| result == reg) {
+            *reg = *value;
+        }
+    }
+
+    fn visit_input_regs(&mut self, _regs: &mut RegSpan, _len: Option<u16>) {}
+}
+
+/// Invalidates all copies that involve [`Reg`]s written by an [`Instruction`] via [`VisitRegs`].
+struct CopyInvalidator<'a> {
+    /// The pairs of `(result, value)` [`Reg`]s of all currently valid copies.
+    copies: &'a mut Vec<(Reg, Reg)>,
+}
+
+impl CopyInvalidator<'_> {
+    /// Invalidates all copies involving a [`Reg`] for which `is_written` returns `true`.
+    fn invalidate(&mut self, is_written: impl Fn(Reg) -> bool) {
+        self.copies
+            .retain(|(result, value)| !is_written(*result) && !is_written(*value));
+    }
+}
+
+impl VisitRegs for CopyInvalidator<'_> {
+    fn visit_result_reg(&mut self, reg: &mut Reg) {
+        let reg = *reg;
+        self.invalidate(|written| written == reg)
+    }
+
+    fn visit_result_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        let head = i16::from(regs.head());
+        match len {
+            Some(len) => {
+                let end = i32::from(head) + i32::from(len);
+                self.invalidate(|reg| (i32::from(head)..end).contains(&i32::from(i16::from(reg))))
+            }
+            None => self.invalidate(|reg| i16::from(reg) >= head),
+        }
+    }
+
+    fn visit_input_reg(&mut self, _reg: &mut Reg) {}
+
+    fn visit_input_regs(&mut self, _regs: &mut RegSpan, _len: Option<u16>) {}
+}
+
+/// Invalidates the known in-bounds ranges of [`Reg`]s written by an [`Instruction`] via [`VisitRegs`].
+struct BoundsInvalidator<'a> {
+    /// The known in-bounds ranges of the default linear memory.
+    bounds: &'a mut Bounds,
+}
+
+impl VisitRegs for BoundsInvalidator<'_> {
+    fn visit_result_reg(&mut self, reg: &mut Reg) {
+        let reg = *reg;
+        self.bounds.invalidate(|written| written == reg)
+    }
+
+    fn visit_result_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        let head = i16::from(regs.head());
+        match len {
+            Some(len) => {
+                let end = i32::from(head) + i32::from(len);
+                self.bounds
+                    .invalidate(|reg| (i32::from(head)..end).contains(&i32::from(i16::from(reg))))
+            }
+            None => self.bounds.invalidate(|reg| i16::from(reg) >= head),
+        }
+    }
+
+    fn visit_input_reg(&mut self, _reg: &mut Reg) {}
+
+    fn visit_input_regs(&mut self, _regs: &mut RegSpan, _len: Option<u16>) {}
+}
+
+/// Captures the single result [`Reg`] of an [`Instruction`] via [`VisitRegs`].
+#[derive(Default)]
+struct ResultReg {
+    /// The single result [`Reg`] if any.
+    reg: Option<Reg>,
+}
+
+impl VisitRegs for ResultReg {
+    fn visit_result_reg(&mut self, reg: &mut Reg) {
+        self.reg = Some(*reg);
+    }
+
+    fn visit_result_regs(&mut self, _regs: &mut RegSpan, _len: Option<u16>) {}
+
+    fn visit_input_reg(&mut self, _reg: &mut Reg) {}
+
+    fn visit_input_regs(&mut self, _regs: &mut RegSpan, _len: Option<u16>) {}
+}
@@ -91,7 +91,9 @@ impl RelinkResult for Instruction {
             Self::CallIndirect0 { results, func_type }
             | Self::CallIndirect0Imm16 { results, func_type }
             | Self::CallIndirect { results, func_type }
-            | Self::CallIndirectImm16 { results, func_type } => {
+            | Self::CallIndirectImm16 { results, func_type }
+            | Self::CallRef0 { results, func_type }
+            | Self::CallRef { results, func_type } => {
                 relink_call_indirect(results, *func_type, module, new_result, old_result)
             }
             instr => {
@@ -57,6 +57,11 @@ impl FuncLocalConsts {
         self.next_idx.abs_diff(Self::first_index())
     }
 
+    /// Returns the number of function local constant values that can still be allocated.
+    pub fn len_free(&self) -> usize {
+        usize::from(self.next_idx.abs_diff(Self::last_index()))
+    }
+
     /// Allocates a new constant `value` on the [`FuncLocalConsts`] and returns its identifier.
     ///
     /// # Note
@@ -97,7 +102,7 @@ impl FuncLocalConsts {
     /// # Note
     ///
     /// The function local constant values are yielded in their allocation order.
-    pub fn iter(&self) -> FuncLocalConstsIter {
+    pub fn iter(&self) -> FuncLocalConstsIter<'_> {
         FuncLocalConstsIter::new(self)
     }
 }
@@ -119,6 +119,15 @@ impl ValueStack {
         self.providers.len()
     }
 
+    /// Returns an iterator over all [`Provider`] on the [`ValueStack`] from bottom to top.
+    pub fn providers(&self) -> impl DoubleEndedIterator<Item = TypedProvider> + '_ {
+        self.providers
+            .peek_n(self.height())
+            .iter()
+            .copied()
+            .map(TypedProvider::from)
+    }
+
     /// Returns the number of registers allocated by the [`RegisterAlloc`].
     pub fn len_registers(&self) -> u16 {
         // The addition won't overflow since both operands are in the range of `0..i16::MAX`.
@@ -172,6 +181,16 @@ impl ValueStack {
         }
     }
 
+    /// Returns the function local constant value of `reg` if any.
+    pub fn get_const(&self, reg: Reg) -> Option<UntypedVal> {
+        self.consts.get(reg)
+    }
+
+    /// Returns `true` if `amount` more function local constant values can be allocated.
+    pub fn has_room_for_consts(&self, amount: usize) -> bool {
+        self.consts.len_free() >= amount
+    }
+
     /// Returns the allocated function local constant values in reversed allocation order.
     ///
     /// # Note
@@ -181,7 +200,7 @@ impl ValueStack {
     /// and accessed via negative [`Reg`] index where the 0 index is referring
     /// to the first function local and the -1 index is referring to the first
     /// allocated function local constant value.
-    pub fn func_local_consts(&self) -> FuncLocalConstsIter {
+    pub fn func_local_consts(&self) -> FuncLocalConstsIter<'_> {
         self.consts.iter()
     }
 
@@ -354,8 +373,8 @@ impl ValueStack {
     ///
     /// - This procedure pushes dynamic [`Reg`] onto the [`ValueStack`].
     /// - This is primarily used to allocate branch parameters for control
-    ///    flow frames such as Wasm `block`, `loop` and `if` as well as for
-    ///    instructions that may return multiple values such as `call`.
+    ///   flow frames such as Wasm `block`, `loop` and `if` as well as for
+    ///   instructions that may return multiple values such as `call`.
     ///
     /// # Errors
     ///
@@ -374,7 +393,7 @@ impl ValueStack {
     ///
     /// - This procedure does not push anything onto the [`ValueStack`].
     /// - This is primarily used to allocate branch parameters for control
-    ///    flow frames such as Wasm `block`, `loop` and `if`.
+    ///   flow frames such as Wasm `block`, `loop` and `if`.
     ///
     /// # Errors
     ///
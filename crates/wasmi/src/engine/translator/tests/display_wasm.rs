@@ -1,4 +1,7 @@
-use crate::{core::ValType, Val};
+use crate::{
+    core::{HeapType, ValType},
+    Val,
+};
 use core::{
     fmt,
     fmt::Display,
@@ -85,11 +88,30 @@ impl Display for DisplayValueType {
             ValType::F64 => write!(f, "f64"),
             ValType::FuncRef => write!(f, "funcref"),
             ValType::ExternRef => write!(f, "externref"),
+            ValType::Ref(ref_type) => {
+                let null = if ref_type.is_nullable() { "null " } else { "" };
+                match ref_type.heap_type() {
+                    HeapType::Func => write!(f, "(ref {null}func)"),
+                    HeapType::Extern => write!(f, "(ref {null}extern)"),
+                    HeapType::Any => write!(f, "(ref {null}any)"),
+                    HeapType::Eq => write!(f, "(ref {null}eq)"),
+                    HeapType::I31 => write!(f, "(ref {null}i31)"),
+                    HeapType::Struct => write!(f, "(ref {null}struct)"),
+                    HeapType::Array => write!(f, "(ref {null}array)"),
+                    HeapType::None => write!(f, "(ref {null}none)"),
+                    HeapType::NoFunc => write!(f, "(ref {null}nofunc)"),
+                    HeapType::NoExtern => write!(f, "(ref {null}noextern)"),
+                    HeapType::Cont => write!(f, "(ref {null}cont)"),
+                    HeapType::NoCont => write!(f, "(ref {null}nocont)"),
+                    HeapType::Concrete(id) => write!(f, "(ref {null}{})", id.into_u32()),
+                }
+            }
         }
     }
 }
 
 /// Wasm [`Display`] wrapper for [`Val`].
+#[allow(dead_code)]
 pub struct DisplayValue(Val);
 
 impl From<Val> for DisplayValue {
@@ -117,6 +139,18 @@ impl Display for DisplayValue {
                 }
                 unimplemented!("wasm externref types other than null cannot be displayed")
             }
+            Val::AnyRef(value) => {
+                if value.is_null() {
+                    return write!(f, "null");
+                }
+                unimplemented!("wasm anyref types other than null cannot be displayed")
+            }
+            Val::ContRef(value) => {
+                if value.is_null() {
+                    return write!(f, "null");
+                }
+                unimplemented!("wasm contref types other than null cannot be displayed")
+            }
         }
     }
 }
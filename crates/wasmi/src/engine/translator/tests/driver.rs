@@ -2,10 +2,11 @@ use super::create_module;
 use crate::{
     core::UntypedVal,
     engine::{DedupFuncType, EngineFunc},
-    ir::Instruction,
+    ir::{Instruction, IrFunc},
     Config,
     Engine,
     Module,
+    OptimizationLevel,
 };
 use core::sync::atomic::Ordering;
 use std::{boxed::Box, sync::atomic::AtomicBool, vec::Vec};
@@ -38,6 +39,8 @@ pub struct ExpectedFunc {
     instrs: Vec<Instruction>,
     /// The function local constant values.
     consts: Vec<UntypedVal>,
+    /// The expected number of registers if any.
+    len_registers: Option<u16>,
 }
 
 impl ExpectedFunc {
@@ -54,9 +57,33 @@ impl ExpectedFunc {
         Self {
             instrs,
             consts: Vec::new(),
+            len_registers: None,
         }
     }
 
+    /// Create a new [`ExpectedFunc`] from the Wasmi IR text format.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`ExpectedFunc::new`] this also expects the number of registers.
+    ///
+    /// # Panics
+    ///
+    /// - If `ir` is not valid Wasmi IR text format.
+    /// - If the parsed function is not well-formed.
+    pub fn from_ir(ir: &str) -> Self {
+        let func = ir
+            .parse::<IrFunc>()
+            .unwrap_or_else(|error| panic!("failed to parse expected Wasmi IR: {error}"));
+        if let Err(error) = func.validate() {
+            panic!("invalid expected Wasmi IR: {error}")
+        }
+        let mut expected = Self::new(func.instrs().iter().copied());
+        expected.consts.extend_from_slice(func.consts());
+        expected.len_registers = Some(func.len_registers());
+        expected
+    }
+
     /// Add expected function local constant values to this [`ExpectedFunc`].
     ///
     /// # Note
@@ -99,6 +126,24 @@ impl ExpectedFunc {
     fn assert_func(&self, engine: &Engine, func_type: DedupFuncType, engine_func: EngineFunc) {
         self.assert_instrs(engine, engine_func, func_type);
         self.assert_consts(engine, engine_func);
+        self.assert_len_registers(engine, engine_func);
+    }
+
+    /// Asserts that the number of registers of the [`ExpectedFunc`] has been translated as expected if any.
+    fn assert_len_registers(&self, engine: &Engine, func: EngineFunc) {
+        let Some(expected) = self.len_registers else {
+            return;
+        };
+        let actual = engine
+            .get_compiled_func(func)
+            .unwrap_or_else(|error| {
+                panic!("failed to compiled lazily initialized function: {}", error)
+            })
+            .len_registers();
+        assert_eq!(
+            actual, expected,
+            "number of registers mismatch for {func:?}"
+        );
     }
 
     /// Asserts that the instructions of the [`ExpectedFunc`] have been translated as expected.
@@ -194,6 +239,42 @@ impl TranslationTest {
         Self::new(wasm)
     }
 
+    /// Enables or disables fuel metering for the test case.
+    pub fn consume_fuel(&mut self, enable: bool) -> &mut Self {
+        self.config.consume_fuel(enable);
+        self
+    }
+
+    /// Sets the [`OptimizationLevel`] used for the test case.
+    pub fn optimization_level(&mut self, level: OptimizationLevel) -> &mut Self {
+        self.config.optimization_level(level);
+        self
+    }
+
+    /// Enables or disables bounds check elimination for the test case.
+    pub fn bounds_check_elimination(&mut self, enable: bool) -> &mut Self {
+        self.config.bounds_check_elimination(enable);
+        self
+    }
+
+    /// Sets the maximum number of instructions of inlined functions used for the test case.
+    pub fn max_inline_len(&mut self, max_len: u32) -> &mut Self {
+        self.config.max_inline_len(max_len);
+        self
+    }
+
+    /// Enables or disables capturing of Wasm backtraces for the test case.
+    pub fn wasm_backtrace(&mut self, enable: bool) -> &mut Self {
+        self.config.wasm_backtrace(enable);
+        self
+    }
+
+    /// Sets the hotness after which functions are re-translated used for the test case.
+    pub fn tier_up_threshold(&mut self, threshold: u32) -> &mut Self {
+        self.config.tier_up_threshold(threshold);
+        self
+    }
+
     /// Returns the [`Config`] used for the test case.
     fn config(&self) -> &Config {
         &self.config
@@ -221,6 +302,15 @@ impl TranslationTest {
         self.expect_func(ExpectedFunc::new(instrs))
     }
 
+    /// Add an expected function given in the Wasmi IR text format.
+    ///
+    /// # Note
+    ///
+    /// This is a convenience method for [`ExpectedFunc::from_ir`].
+    pub fn expect_func_ir(&mut self, ir: &str) -> &mut Self {
+        self.expect_func(ExpectedFunc::from_ir(ir))
+    }
+
     /// Add an [`ExpectedFunc`].
     pub fn expect_func(&mut self, func: ExpectedFunc) -> &mut Self {
         self.expected_funcs.push(func);
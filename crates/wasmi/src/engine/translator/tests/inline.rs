@@ -0,0 +1,353 @@
+use super::*;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn getter() {
+    let wasm = r"
+        (module
+            (global $g (mut i32) (i32.const 0))
+            (func $get (result i32)
+                (global.get $g)
+            )
+            (func (param i32) (result i32)
+                (i32.add (call $get) (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(8)
+        .expect_func_ir(
+            "
+            registers: 1
+            consts: 0
+            instrs: 2
+              0: r0 = global_get global=global[0]
+              1: return_reg value=r0
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1 = global_get global=global[0]
+              1: r1 = i32_add lhs=r1, rhs=r0
+              2: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn kept_with_wasm_backtrace() {
+    let wasm = r"
+        (module
+            (global $g (mut i32) (i32.const 0))
+            (func $get (result i32)
+                (global.get $g)
+            )
+            (func (param i32) (result i32)
+                (i32.add (call $get) (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(8)
+        .wasm_backtrace(true)
+        .expect_func_ir(
+            "
+            registers: 1
+            consts: 0
+            instrs: 2
+              0: r0 = global_get global=global[0]
+              1: return_reg value=r0
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1 = global_get global=global[0]
+              1: r1 = i32_add lhs=r1, rhs=r0
+              2: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn params_and_consts() {
+    let wasm = r"
+        (module
+            (func $f (param i32 i32) (result i32)
+                (i32.add
+                    (i32.mul (local.get 0) (i32.const 100000))
+                    (local.get 1)
+                )
+            )
+            (func (param i32) (result i32)
+                (call $f (local.get 0) (i32.const 1))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(8)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 1
+              c0 = 0x00000000000186a0
+            instrs: 3
+              0: r2 = i32_mul lhs=r0, rhs=c0
+              1: r2 = i32_add lhs=r2, rhs=r1
+              2: return_reg value=r2
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 6
+            consts: 2
+              c0 = 0x0000000000000001
+              c1 = 0x00000000000186a0
+            instrs: 5
+              0: r1..r3 = copy2 values=[r0, c0]
+              1: r3 = i32_mul lhs=r1, rhs=c1
+              2: r3 = i32_add lhs=r3, rhs=r2
+              3: r1 = copy value=r3
+              4: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn zero_initializes_locals() {
+    let wasm = r"
+        (module
+            (func $f (param i32) (result i64)
+                (local i64)
+                (i64.add (local.get 1) (i64.extend_i32_u (local.get 0)))
+            )
+            (func (param i32) (result i64)
+                (call $f (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(8)
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 2
+              0: r2 = i64_add lhs=r1, rhs=r0
+              1: return_reg value=r2
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 5
+              0: r1 = copy value=r0
+              1: r2 = copy_i64imm32 value=0
+              2: r3 = i64_add lhs=r2, rhs=r1
+              3: r1 = copy value=r3
+              4: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn keeps_fuel() {
+    let wasm = r"
+        (module
+            (func $f (param i32) (result i32)
+                (i32.add (local.get 0) (i32.const 1))
+            )
+            (func (param i32) (result i32)
+                (call $f (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .consume_fuel(true)
+        .max_inline_len(8)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: consume_fuel block_fuel=3
+              1: r1 = i32_add_imm16 lhs=r0, rhs=1
+              2: return_reg value=r1
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 6
+              0: consume_fuel block_fuel=3
+              1: r1 = copy value=r0
+              2: consume_fuel block_fuel=3
+              3: r2 = i32_add_imm16 lhs=r1, rhs=1
+              4: r1 = copy value=r2
+              5: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn skips_large_callees() {
+    let wasm = r"
+        (module
+            (func $f (param i32) (result i32)
+                (i32.add (local.get 0) (i32.const 1))
+            )
+            (func (param i32) (result i32)
+                (call $f (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(1)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 2
+              0: r1 = i32_add_imm16 lhs=r0, rhs=1
+              1: return_reg value=r1
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1.. = call_internal func=internal_func[0]
+              1: register reg=r0
+              2: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn skips_control_flow() {
+    let wasm = r"
+        (module
+            (func $f (param i32) (result i32)
+                (if (result i32) (local.get 0)
+                    (then (i32.const 1))
+                    (else (i32.const 2))
+                )
+            )
+            (func (param i32) (result i32)
+                (call $f (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(100)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 5
+              0: branch_i32_eq_imm16 lhs=r0, rhs=0, offset=@3
+              1: r1 = copy_imm32 value=0x00000001
+              2: branch offset=@4
+              3: r1 = copy_imm32 value=0x00000002
+              4: return_reg value=r1
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1.. = call_internal func=internal_func[0]
+              1: register reg=r0
+              2: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn skips_untranslated_callees() {
+    let wasm = r"
+        (module
+            (func $caller (param i32) (result i32)
+                (call $f (local.get 0))
+            )
+            (func $f (param i32) (result i32)
+                (i32.add (local.get 0) (i32.const 1))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(100)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1.. = call_internal func=internal_func[1]
+              1: register reg=r0
+              2: return_reg value=r1
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 2
+              0: r1 = i32_add_imm16 lhs=r0, rhs=1
+              1: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn deferred_by_tiered_translation() {
+    let wasm = r"
+        (module
+            (global $g (mut i32) (i32.const 0))
+            (func $get (result i32)
+                (global.get $g)
+            )
+            (func (param i32) (result i32)
+                (i32.add (call $get) (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .max_inline_len(8)
+        .tier_up_threshold(10)
+        .expect_func_ir(
+            "
+            registers: 1
+            consts: 0
+            instrs: 2
+              0: r0 = global_get global=global[0]
+              1: return_reg value=r0
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1.. = call_internal_0 func=internal_func[0]
+              1: r1 = i32_add lhs=r1, rhs=r0
+              2: return_reg value=r1
+            ",
+        )
+        .run()
+}
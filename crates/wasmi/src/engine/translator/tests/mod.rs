@@ -3,7 +3,9 @@
 mod display_wasm;
 pub mod driver;
 mod fuzz;
+mod inline;
 mod op;
+mod optimize;
 pub mod wasm_type;
 
 use self::{
@@ -96,10 +96,11 @@ fn test_load_at_mem0(
     offset: u32,
 ) {
     let result_ty = wasm_op.result_ty();
+    // Note: the memory is initially empty so that no bounds check is eliminated.
     let wasm = format!(
         r#"
         (module
-            (memory 1)
+            (memory 0)
             (func (result {result_ty})
                 i32.const {ptr}
                 {wasm_op} offset={offset}
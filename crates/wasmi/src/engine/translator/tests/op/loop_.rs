@@ -168,6 +168,35 @@ fn repeat_loop_1_copy() {
         .run()
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn repeat_loop_1_copy_ir() {
+    let wasm = r"
+        (module
+            (func (param i32 i32)
+                (local.get 0)
+                (loop (param i32)
+                    (drop)
+                    (local.get 1)
+                    (br 0)
+                )
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 4
+              0: r3 = copy value=r0
+              1: r2 = copy value=r3
+              2: r2 = copy value=r1
+              3: branch offset=@2
+            ",
+        )
+        .run()
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn identity_loop_4_mixed_1() {
@@ -194,6 +223,36 @@ fn identity_loop_4_mixed_1() {
         .run()
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn identity_loop_4_mixed_1_ir() {
+    let wasm = r"
+        (module
+            (func (param i32 i32) (result i32 i32 i32 i32)
+                (i32.const 10)
+                (local.get 0)
+                (local.get 1)
+                (i32.const 20)
+                (loop (param i32 i32 i32 i32) (result i32 i32 i32 i32))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .expect_func_ir(
+            "
+            registers: 10
+            consts: 2
+              c0 = 0x000000000000000a
+              c1 = 0x0000000000000014
+            instrs: 4
+              0: r6..r8 = copy2 values=[r0, r1]
+              1: r2.. = copy_many_non_overlapping values=[c0, r6]
+              2: register2 regs=[r7, c1]
+              3: return_span values=r2..r6
+            ",
+        )
+        .run()
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn identity_loop_4_mixed_2() {
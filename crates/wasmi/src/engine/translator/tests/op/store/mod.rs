@@ -392,10 +392,11 @@ fn test_store_at_for(
         .checked_add(offset)
         .expect("testcase requires valid ptr+offset address");
     let param_ty = wasm_op.param_ty();
+    // Note: the memory is initially empty so that no bounds check is eliminated.
     let wasm = format!(
         r#"
         (module
-            (memory 1)
+            (memory 0)
             (func (param $value {param_ty})
                 i32.const {ptr}
                 local.get $value
@@ -468,10 +469,11 @@ fn test_store_at_imm_for<T>(
         .expect("testcase requires valid ptr+offset address");
     let display_value = DisplayWasm::from(value);
     let param_ty = wasm_op.param_ty();
+    // Note: the memory is initially empty so that no bounds check is eliminated.
     let wasm = format!(
         r#"
         (module
-            (memory 1)
+            (memory 0)
             (func
                 i32.const {ptr}
                 {param_ty}.const {display_value}
@@ -524,10 +526,11 @@ fn test_store_wrap_at_imm_for<Src, Wrapped, Field>(
         .expect("testcase requires valid ptr+offset address");
     let display_value = DisplayWasm::from(value);
     let param_ty = wasm_op.param_ty();
+    // Note: the memory is initially empty so that no bounds check is eliminated.
     let wasm = format!(
         r#"
         (module
-            (memory $mem0 1)
+            (memory $mem0 0)
             (memory $mem1 1)
             (func
                 i32.const {ptr}
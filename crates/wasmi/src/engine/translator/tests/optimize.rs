@@ -0,0 +1,755 @@
+use super::*;
+use crate::{
+    ir::{BranchOffset, BranchOffset16, Comparator, ComparatorAndOffset},
+    OptimizationLevel,
+};
+use std::string::String;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn none_keeps_instrs() {
+    let wasm = r"
+        (module
+            (func (param i32) (result i32)
+                (local i32)
+                (local.set 1 (local.get 0))
+                (i32.add (local.get 1) (local.get 1))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::None)
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 3
+              0: r1 = copy value=r0
+              1: r2 = i32_add lhs=r1, rhs=r1
+              2: return_reg value=r2
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn thread_jumps() {
+    let wasm = r"
+        (module
+            (func (param i32 i32)
+                (loop $l
+                    (block $b
+                        (br_if $b (local.get 0))
+                        (br_if $b (local.get 1))
+                        (return)
+                    )
+                    (br $l)
+                )
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Basic)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 4
+              0: branch_i32_ne_imm16 lhs=r0, rhs=0, offset=@0
+              1: branch_i32_ne_imm16 lhs=r1, rhs=0, offset=@0
+              2: return
+              3: branch offset=@0
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn thread_jumps_to_return() {
+    let wasm = r"
+        (module
+            (func (param i32) (result i32)
+                (block (result i32)
+                    (block
+                        (br_if 0 (local.get 0))
+                        (br 1 (i32.const 10))
+                    )
+                    (i32.const 20)
+                )
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Basic)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 5
+              0: branch_i32_ne_imm16 lhs=r0, rhs=0, offset=@3
+              1: r1 = copy_imm32 value=0x0000000a
+              2: return_reg value=r1
+              3: r1 = copy_imm32 value=0x00000014
+              4: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn merge_fuel() {
+    let wasm = r"
+        (module
+            (func (param i32) (result i32)
+                (loop (result i32)
+                    (i32.add (local.get 0) (i32.const 1))
+                )
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .consume_fuel(true)
+        .optimization_level(OptimizationLevel::Basic)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: consume_fuel block_fuel=4
+              1: r1 = i32_add_imm16 lhs=r0, rhs=1
+              2: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn merge_fuel_keeps_branch_targets() {
+    let wasm = r"
+        (module
+            (func (param i32) (result i32)
+                (loop $l
+                    (local.set 0 (i32.sub (local.get 0) (i32.const 1)))
+                    (br_if $l (local.get 0))
+                )
+                (local.get 0)
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .consume_fuel(true)
+        .optimization_level(OptimizationLevel::Basic)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 5
+              0: consume_fuel block_fuel=2
+              1: consume_fuel block_fuel=2
+              2: r0 = i32_add_imm16 lhs=r0, rhs=-1
+              3: branch_i32_ne_imm16 lhs=r0, rhs=0, offset=@1
+              4: return_reg value=r0
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn propagate_copies() {
+    let wasm = r"
+        (module
+            (func (param i32) (result i32)
+                (local i32)
+                (local.set 1 (local.get 0))
+                (i32.add (local.get 1) (local.get 1))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 2
+              0: r2 = i32_add lhs=r0, rhs=r0
+              1: return_reg value=r2
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn propagate_copies_stops_at_branch_targets() {
+    let wasm = r"
+        (module
+            (func (param i32 i32) (result i32)
+                (local i32)
+                (local.set 2 (local.get 0))
+                (loop $l
+                    (local.set 1 (i32.add (local.get 1) (local.get 2)))
+                    (local.set 2 (local.get 1))
+                    (br_if $l (i32.eqz (local.get 1)))
+                )
+                (local.get 2)
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 5
+              0: r2 = copy value=r0
+              1: r1 = i32_add lhs=r1, rhs=r2
+              2: r2 = copy value=r1
+              3: branch_i32_eq_imm16 lhs=r1, rhs=0, offset=@1
+              4: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn fuse_cmp_branch() {
+    let wasm = r"
+        (module
+            (func (param i32 i32) (result i32)
+                (local i32)
+                (block
+                    (loop
+                        (local.set 2 (i32.lt_s (local.get 0) (local.get 1)))
+                        (br_if 1 (local.get 2))
+                        (local.set 0 (i32.add (local.get 0) (i32.const 1)))
+                        (br 0)
+                    )
+                )
+                (local.get 0)
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 4
+              0: branch_i32_lt_s lhs=r0, rhs=r1, offset=@3
+              1: r0 = i32_add_imm16 lhs=r0, rhs=1
+              2: branch offset=@0
+              3: return_reg value=r0
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn fuse_cmp_branch_negated() {
+    let wasm = r"
+        (module
+            (func (param i32) (result i32)
+                (local i32)
+                (loop
+                    (local.set 1 (i32.lt_u (local.get 0) (i32.const 100)))
+                    (br_if 0 (i32.eqz (local.get 1)))
+                )
+                (local.get 0)
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 2
+              0: branch_i32_le_u_imm16_lhs lhs=100, rhs=r0, offset=@0
+              1: return_reg value=r0
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn fuse_cmp_branch_keeps_observed_result() {
+    let wasm = r"
+        (module
+            (func (param i32 i32) (result i32)
+                (local i32)
+                (block
+                    (local.set 2 (i32.lt_s (local.get 0) (local.get 1)))
+                    (br_if 0 (local.get 2))
+                    (return (i32.const 0))
+                )
+                (local.get 2)
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 4
+              0: r2 = i32_lt_s lhs=r0, rhs=r1
+              1: branch_i32_ne_imm16 lhs=r2, rhs=0, offset=@3
+              2: return_imm32 value=0x00000000
+              3: return_reg value=r2
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn fuse_load_op() {
+    let wasm = r"
+        (module
+            (memory 1)
+            (func (param i32 i32) (result i32)
+                (local.set 1 (i32.add (local.get 1) (i32.load offset=4 (local.get 0))))
+                (local.get 1)
+            )
+            (func (param i32 i64) (result i64)
+                (drop (i64.load offset=8 (local.get 0)))
+                (i64.add (i64.load (local.get 0)) (local.get 1))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 3
+              0: r1 = i32_add_load32 lhs=r1
+              1: register_and_imm32 reg=r0, imm=0x00000004
+              2: return_reg value=r1
+            ",
+        )
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 4
+              0: r2 = load64_offset16 ptr=r0, offset=8
+              1: r2 = i64_add_load64_unchecked lhs=r1
+              2: register_and_imm32 reg=r0, imm=0x00000000
+              3: return_reg value=r2
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn fuse_load_op_keeps_reused_result() {
+    let wasm = r"
+        (module
+            (memory 1)
+            (func (param i32 i32) (result i32)
+                (local i32)
+                (local.set 2 (i32.load (local.get 0)))
+                (i32.mul
+                    (i32.add (local.get 1) (local.get 2))
+                    (local.get 2)
+                )
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 4
+              0: r2 = load32_offset16 ptr=r0, offset=0
+              1: r3 = i32_add lhs=r1, rhs=r2
+              2: r3 = i32_mul lhs=r3, rhs=r2
+              3: return_reg value=r3
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_same_ptr() {
+    let wasm = r"
+        (module
+            (memory 0)
+            (func (param i32) (result i64)
+                (i64.store offset=8 (local.get 0) (i64.const 1))
+                (i64.add
+                    (i64.load32_u offset=4 (local.get 0))
+                    (i64.load offset=16 (local.get 0))
+                )
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 3
+            consts: 0
+            instrs: 5
+              0: i64_store_offset16_imm16 ptr=r0, offset=8, value=1
+              1: r1 = i64_load32_u_offset16_unchecked ptr=r0, offset=4
+              2: r1 = i64_add_load64 lhs=r1
+              3: register_and_imm32 reg=r0, imm=0x00000010
+              4: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_const_address() {
+    let wasm = r"
+        (module
+            (memory 1)
+            (func (result i32)
+                (i32.add
+                    (i32.add (i32.load (i32.const 100)) (i32.load (i32.const 70000)))
+                    (i32.load16_u (i32.const 69000))
+                )
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 6
+              0: r0 = load32_at_unchecked address=100
+              1: r1 = load32_at address=70000
+              2: r0 = i32_add lhs=r0, rhs=r1
+              3: r1 = i32_load16_u_at_unchecked address=69000
+              4: r0 = i32_add lhs=r0, rhs=r1
+              5: return_reg value=r0
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_loop_invariant() {
+    let wasm = r"
+        (module
+            (memory 1)
+            (func (param $p i32) (param $n i32) (result i32)
+                (local $sum i32)
+                (drop (i32.load offset=8 (local.get $p)))
+                (loop $l
+                    (local.set $sum (i32.add (local.get $sum) (i32.load offset=4 (local.get $p))))
+                    (local.set $sum (i32.add (local.get $sum) (i32.load (local.get $n))))
+                    (local.set $sum (i32.add (local.get $sum) (i32.load8_u offset=3 (local.get $n))))
+                    (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br_if $l (local.get $n))
+                )
+                (local.get $sum)
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 10
+              0: r3 = load32_offset16 ptr=r0, offset=8
+              1: r3 = load32_offset16_unchecked ptr=r0, offset=4
+              2: r2 = i32_add lhs=r2, rhs=r3
+              3: r3 = load32_offset16 ptr=r1, offset=0
+              4: r2 = i32_add lhs=r2, rhs=r3
+              5: r3 = i32_load8_u_offset16_unchecked ptr=r1, offset=3
+              6: r2 = i32_add lhs=r2, rhs=r3
+              7: r1 = i32_add_imm16 lhs=r1, rhs=-1
+              8: branch_i32_ne_imm16 lhs=r1, rhs=0, offset=@1
+              9: return_reg value=r2
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_joins_branches() {
+    let wasm = r"
+        (module
+            (memory 1)
+            (func (param i32 i32) (result i32)
+                (if (local.get 1)
+                    (then (drop (i32.load (local.get 0))))
+                    (else (drop (i32.load offset=4 (local.get 0))))
+                )
+                (i32.add (i32.load (local.get 0)) (i32.load offset=8 (local.get 0)))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 4
+            consts: 0
+            instrs: 8
+              0: branch_i32_eq_imm16 lhs=r1, rhs=0, offset=@3
+              1: r2 = load32_offset16 ptr=r0, offset=0
+              2: branch offset=@4
+              3: r2 = load32_offset16 ptr=r0, offset=4
+              4: r2 = load32_offset16_unchecked ptr=r0, offset=0
+              5: r2 = i32_add_load32 lhs=r2
+              6: register_and_imm32 reg=r0, imm=0x00000008
+              7: return_reg value=r2
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_invalidated_by_writes() {
+    let wasm = r"
+        (module
+            (memory 1)
+            (func (param i32) (result i32)
+                (drop (i32.load (local.get 0)))
+                (local.set 0 (i32.add (local.get 0) (i32.const 4)))
+                (i32.load (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 4
+              0: r1 = load32_offset16 ptr=r0, offset=0
+              1: r0 = i32_add_imm16 lhs=r0, rhs=4
+              2: r1 = load32_offset16 ptr=r0, offset=0
+              3: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_without_optimizations() {
+    let wasm = r"
+        (module
+            (memory 0)
+            (func (param i32) (result i32)
+                (drop (i32.load offset=4 (local.get 0)))
+                (i32.load (local.get 0))
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::None)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1 = load32_offset16 ptr=r0, offset=4
+              1: r1 = load32_offset16_unchecked ptr=r0, offset=0
+              2: return_reg value=r1
+            ",
+        )
+        .run();
+    TranslationTest::from_wat(wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .bounds_check_elimination(false)
+        .expect_func_ir(
+            "
+            registers: 2
+            consts: 0
+            instrs: 3
+              0: r1 = load32_offset16 ptr=r0, offset=4
+              1: r1 = load32_offset16 ptr=r0, offset=0
+              2: return_reg value=r1
+            ",
+        )
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_loop_fixed_point() {
+    // Note: the access through `$q` in the loop keeps its bounds check since the
+    //       write to `$q` invalidates its known in-bounds range upon the backward branch.
+    let wasm = r"
+        (module
+            (memory 0)
+            (func (param $p i32) (param $q i32) (param $n i32) (result i32)
+                (local $sum i32)
+                (drop (i32.load offset=4 (local.get $p)))
+                (drop (i32.load offset=4 (local.get $q)))
+                (loop $l
+                    (local.set $sum (i32.add (local.get $sum) (i32.load (local.get $p))))
+                    (local.set $sum (i32.add (local.get $sum) (i32.load (local.get $q))))
+                    (local.set $q (i32.add (local.get $q) (i32.const 4)))
+                    (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br_if $l (local.get $n))
+                )
+                (local.get $sum)
+            )
+        )";
+    TranslationTest::from_wat(wasm)
+        .expect_func_ir(
+            "
+            registers: 5
+            consts: 0
+            instrs: 10
+              0: r4 = load32_offset16 ptr=r0, offset=4
+              1: r4 = load32_offset16 ptr=r1, offset=4
+              2: r4 = load32_offset16_unchecked ptr=r0, offset=0
+              3: r3 = i32_add lhs=r3, rhs=r4
+              4: r4 = load32_offset16 ptr=r1, offset=0
+              5: r3 = i32_add lhs=r3, rhs=r4
+              6: r1 = i32_add_imm16 lhs=r1, rhs=4
+              7: r2 = i32_add_imm16 lhs=r2, rhs=-1
+              8: branch_i32_ne_imm16 lhs=r2, rhs=0, offset=@2
+              9: return_reg value=r3
+            ",
+        )
+        .run()
+}
+
+/// Tests bounds check elimination for a loop that rotates `len_ptrs` pointers.
+///
+/// Every sweep of the analysis invalidates the pointer copied from the pointer
+/// invalidated by the previous sweep. Therefore the dominated access before the
+/// loop is only unchecked if the analysis reaches a fixed point in time.
+fn test_bounds_check_sweeps(len_ptrs: i16, eliminated: bool) {
+    let mut wasm = String::from("(module (memory 0) (func (param");
+    for _ in 0..len_ptrs {
+        wasm.push_str(" i32");
+    }
+    wasm.push_str(") (result i32) (local $n i32)\n");
+    wasm.push_str("(drop (i32.load offset=4 (local.get 0)))\n");
+    for n in 0..len_ptrs {
+        wasm.push_str(&format!("(drop (i32.load (local.get {n})))\n"));
+    }
+    wasm.push_str("(loop $l\n");
+    for n in (1..len_ptrs).rev() {
+        wasm.push_str(&format!("(local.set {n} (local.get {}))\n", n - 1));
+        wasm.push_str("(local.set $n (i32.add (local.get $n) (i32.const 1)))\n");
+    }
+    wasm.push_str("(local.set 0 (i32.add (local.get 0) (i32.const 4)))\n");
+    wasm.push_str(&format!(
+        "(br_if $l (i32.load (local.get {})))\n",
+        len_ptrs - 1
+    ));
+    wasm.push_str(")\n(local.get $n)))");
+    let counter = Reg::from(len_ptrs);
+    let tmp = Reg::from(len_ptrs + 1);
+    let offset = <Const16<u32>>::from;
+    let mut expected_instrs = std::vec![
+        Instruction::load32_offset16(tmp, Reg::from(0), offset(4)),
+        match eliminated {
+            true => Instruction::load32_offset16_unchecked(tmp, Reg::from(0), offset(0)),
+            false => Instruction::load32_offset16(tmp, Reg::from(0), offset(0)),
+        },
+    ];
+    for n in 1..len_ptrs {
+        expected_instrs.push(Instruction::load32_offset16(tmp, Reg::from(n), offset(0)));
+    }
+    for n in (1..len_ptrs).rev() {
+        expected_instrs.extend([
+            Instruction::copy(n, n - 1),
+            Instruction::i32_add_imm16(counter, counter, 1),
+        ]);
+    }
+    expected_instrs.extend([
+        Instruction::i32_add_imm16(Reg::from(0), Reg::from(0), 4),
+        Instruction::load32_offset16(tmp, Reg::from(len_ptrs - 1), offset(0)),
+        Instruction::branch_i32_ne_imm16(tmp, 0, BranchOffset16::from(-2 * len_ptrs)),
+        Instruction::return_reg(counter),
+    ]);
+    TranslationTest::from_wat(&wasm)
+        .expect_func_instrs(expected_instrs)
+        .run()
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_loop_sweeps() {
+    test_bounds_check_sweeps(4, true);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn eliminate_bounds_checks_gives_up() {
+    // Note: the analysis needs more than `MAX_BOUNDS_SWEEPS` sweeps.
+    test_bounds_check_sweeps(10, false);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn branch_cmp_fallback_offsets_updated() {
+    // Required amount of instructions to trigger the `cmp+br` fallback instruction generation.
+    let len_adds = (1 << 15) + 1;
+    let mut wasm = String::from(
+        r"
+        (module
+            (func (param i32)
+                (local i32)
+                (loop $continue
+                    (block $skip
+                        (br_if $skip (local.get 0))
+                        (local.set 1 (local.get 0))
+                        (local.get 0)",
+    );
+    for _ in 0..len_adds {
+        wasm.push_str("(i32.add (i32.const 1))");
+    }
+    wasm.push_str(
+        r"
+                        (local.set 0)
+                    )
+                    (br_if $continue (local.get 0))
+                )
+            )
+        )",
+    );
+    // Note: the dead copy to the local is removed which shrinks the offsets of both branches.
+    let expected_instrs = {
+        let mut instrs = std::vec![
+            Instruction::branch_cmp_fallback(0, -1, -4),
+            Instruction::i32_add_imm16(2, 0, 1),
+        ];
+        instrs.extend((0..(len_adds - 2)).map(|_| Instruction::i32_add_imm16(2, 2, 1)));
+        instrs.extend([
+            Instruction::i32_add_imm16(0, 2, 1),
+            Instruction::branch_cmp_fallback(0, -1, -5),
+            Instruction::r#return(),
+        ]);
+        instrs
+    };
+    let params = |offset: i32| {
+        UntypedVal::from(ComparatorAndOffset::new(
+            Comparator::I32Ne,
+            BranchOffset::from(offset),
+        ))
+    };
+    let offset = len_adds + 1;
+    TranslationTest::from_wat(&wasm)
+        .optimization_level(OptimizationLevel::Full)
+        .expect_func(ExpectedFunc::new(expected_instrs).consts([
+            UntypedVal::from(0_i64), // reg(-1)
+            params(-(offset + 1)),   // reg(-2): replaced
+            params(offset + 1),      // reg(-3): replaced
+            params(offset),          // reg(-4)
+            params(-offset),         // reg(-5)
+        ]))
+        .run()
+}
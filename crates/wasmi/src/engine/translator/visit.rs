@@ -15,18 +15,22 @@ use super::{
     TypedVal,
 };
 use crate::{
-    core::{TrapCode, ValType, F32, F64},
+    core::{HeapType, RefType, TrapCode, TypeKind, UntypedVal, ValType, F32, F64},
     engine::{
         translator::{AcquiredTarget, Provider},
         BlockType,
         FuelCosts,
+        TranslationError,
     },
-    ir::{self, index, index::FuncType, BoundedRegSpan, Const16, Instruction, Reg},
+    gc::AnyRef,
+    ir::{self, index, index::FuncType, BoundedRegSpan, Const16, FixedRegSpan, Instruction, Reg},
     module::{self, FuncIdx, WasmiValueType},
+    ContRef,
     Error,
     ExternRef,
     FuncRef,
     Mutability,
+    StorageType,
 };
 use core::num::{NonZeroU32, NonZeroU64};
 use wasmparser::VisitOperator;
@@ -57,12 +61,39 @@ macro_rules! impl_visit_operator {
     ( @tail_call $($rest:tt)* ) => {
         impl_visit_operator!(@@skipped $($rest)*);
     };
+    ( @function_references $($rest:tt)* ) => {
+        impl_visit_operator!(@@skipped $($rest)*);
+    };
+    ( @gc $($rest:tt)* ) => {
+        impl_visit_operator!(@@skipped $($rest)*);
+    };
+    ( @wide_arithmetic $($rest:tt)* ) => {
+        impl_visit_operator!(@@skipped $($rest)*);
+    };
+    ( @stack_switching ResumeThrow $($rest:tt)* ) => {
+        impl_visit_operator!(@@unsupported ResumeThrow $($rest)*);
+    };
+    ( @stack_switching $($rest:tt)* ) => {
+        impl_visit_operator!(@@skipped $($rest)*);
+    };
+    ( @exceptions $($rest:tt)* ) => {
+        impl_visit_operator!(@@unsupported $($rest)*);
+    };
+    ( @@unsupported $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $_ann:tt $($rest:tt)* ) => {
+        // Wasm operators of the `exception-handling` proposal are not supported by Wasmi.
+        #[allow(unused_variables)]
+        fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
+            Err(Error::from(TranslationError::UnsupportedOperator(stringify!($op))))
+        }
+        impl_visit_operator!($($rest)*);
+    };
     ( @@skipped $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $_ann:tt $($rest:tt)* ) => {
         // We skip Wasm operators that we already implement manually.
         impl_visit_operator!($($rest)*);
     };
     ( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $_ann:tt $($rest:tt)* ) => {
         // Wildcard match arm for all the other (yet) unsupported Wasm proposals.
+        #[allow(unused_variables)]
         fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
             self.unsupported_operator(stringify!($op))
         }
@@ -103,7 +134,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_block(&mut self, block_type: wasmparser::BlockType) -> Self::Output {
-        let block_type = BlockType::new(block_type, &self.module);
+        let block_type = BlockType::new(block_type, &self.module)?;
         if !self.is_reachable() {
             // We keep track of unreachable control flow frames so that we
             // can associated `end` operators to their respective control flow
@@ -140,7 +171,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_loop(&mut self, block_type: wasmparser::BlockType) -> Self::Output {
-        let block_type = BlockType::new(block_type, &self.module);
+        let block_type = BlockType::new(block_type, &self.module)?;
         if !self.is_reachable() {
             // See `visit_block` for rational of tracking unreachable control flow.
             self.alloc
@@ -193,7 +224,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_if(&mut self, block_type: wasmparser::BlockType) -> Self::Output {
-        let block_type = BlockType::new(block_type, &self.module);
+        let block_type = BlockType::new(block_type, &self.module)?;
         if !self.is_reachable() {
             // We keep track of unreachable control flow frames so that we
             // can associated `end` operators to their respective control flow
@@ -483,6 +514,11 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         let func_idx = FuncIdx::from(function_index);
         let func_type = self.func_type_of(func_idx);
         let (params, results) = func_type.params_results();
+        if let Some(engine_func) = self.module.get_engine_func(func_idx) {
+            if self.try_inline_call(engine_func, params.len(), results.len())? {
+                return Ok(());
+            }
+        }
         let provider_params = &mut self.alloc.buffer.providers;
         self.alloc.stack.pop_n(params.len(), provider_params);
         let results = self.alloc.stack.push_dynamic_n(results.len())?;
@@ -613,6 +649,111 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         Ok(())
     }
 
+    fn visit_call_ref(&mut self, type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        self.bump_fuel_consumption(FuelCosts::call)?;
+        let type_index = FuncType::from(type_index);
+        let func_type = self.func_type_at(type_index);
+        let Some(funcref) = self.pop_call_ref_funcref()? else {
+            return Ok(());
+        };
+        let (params, results) = func_type.params_results();
+        let provider_params = &mut self.alloc.buffer.providers;
+        self.alloc.stack.pop_n(params.len(), provider_params);
+        let results = self.alloc.stack.push_dynamic_n(results.len())?;
+        let instr = match params.len() {
+            0 => Instruction::call_ref_0(results, type_index),
+            _ => Instruction::call_ref(results, type_index),
+        };
+        self.alloc.instr_encoder.push_instr(instr)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register(funcref))?;
+        self.alloc
+            .instr_encoder
+            .encode_register_list(&mut self.alloc.stack, provider_params)?;
+        Ok(())
+    }
+
+    fn visit_return_call_ref(&mut self, type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        self.bump_fuel_consumption(FuelCosts::call)?;
+        let type_index = FuncType::from(type_index);
+        let func_type = self.func_type_at(type_index);
+        let Some(funcref) = self.pop_call_ref_funcref()? else {
+            return Ok(());
+        };
+        let params = func_type.params();
+        let provider_params = &mut self.alloc.buffer.providers;
+        self.alloc.stack.pop_n(params.len(), provider_params);
+        let instr = match params.len() {
+            0 => Instruction::return_call_ref_0(type_index),
+            _ => Instruction::return_call_ref(type_index),
+        };
+        self.alloc.instr_encoder.push_instr(instr)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register(funcref))?;
+        self.alloc
+            .instr_encoder
+            .encode_register_list(&mut self.alloc.stack, provider_params)?;
+        self.reachable = false;
+        Ok(())
+    }
+
+    fn visit_ref_as_non_null(&mut self) -> Self::Output {
+        bail_unreachable!(self);
+        let reference = match self.alloc.stack.peek() {
+            Provider::Const(_) => {
+                // Case: reference constants are always `null` and thus always trap.
+                self.alloc.stack.drop();
+                return self.translate_trap(TrapCode::NullReference);
+            }
+            Provider::Register(reference) => reference,
+        };
+        self.bump_fuel_consumption(FuelCosts::base)?;
+        let skip_label = self.alloc.instr_encoder.new_label();
+        self.alloc.instr_encoder.encode_branch_ref_is_non_null(
+            &mut self.alloc.stack,
+            reference,
+            skip_label,
+        )?;
+        self.alloc
+            .instr_encoder
+            .push_instr(Instruction::trap(TrapCode::NullReference))?;
+        self.alloc.instr_encoder.pin_label(skip_label);
+        Ok(())
+    }
+
+    fn visit_br_on_null(&mut self, relative_depth: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let reference = match self.alloc.stack.pop() {
+            Provider::Const(_) => {
+                // Case: reference constants are always `null` so the branch is always taken.
+                return self.translate_br(relative_depth);
+            }
+            Provider::Register(reference) => reference,
+        };
+        self.translate_br_on_ref(relative_depth, reference, true)?;
+        self.alloc.stack.push_register(reference)?;
+        Ok(())
+    }
+
+    fn visit_br_on_non_null(&mut self, relative_depth: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let reference = match self.alloc.stack.peek() {
+            Provider::Const(_) => {
+                // Case: reference constants are always `null` so the branch is never taken.
+                self.alloc.stack.drop();
+                return Ok(());
+            }
+            Provider::Register(reference) => reference,
+        };
+        self.translate_br_on_ref(relative_depth, reference, false)?;
+        self.alloc.stack.drop();
+        Ok(())
+    }
+
     fn visit_drop(&mut self) -> Self::Output {
         bail_unreachable!(self);
         self.alloc.stack.drop();
@@ -624,6 +765,7 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_typed_select(&mut self, ty: wasmparser::ValType) -> Self::Output {
+        module::utils::ensure_supported_val_type(ty)?;
         let type_hint = WasmiValueType::from(ty).into_inner();
         self.translate_select(Some(type_hint))
     }
@@ -1032,12 +1174,15 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_ref_null(&mut self, hty: wasmparser::HeapType) -> Self::Output {
+        module::utils::ensure_supported_heap_type(hty)?;
         bail_unreachable!(self);
-        let type_hint = WasmiValueType::from(hty).into_inner();
-        let null = match type_hint {
-            ValType::FuncRef => TypedVal::from(FuncRef::null()),
-            ValType::ExternRef => TypedVal::from(ExternRef::null()),
-            _ => panic!("must be a Wasm reftype"),
+        let null = match self.type_resolver().heap_type(hty).top() {
+            HeapType::Func => TypedVal::from(FuncRef::null()),
+            HeapType::Extern => TypedVal::from(ExternRef::null()),
+            HeapType::Cont => {
+                TypedVal::new(ValType::from(RefType::CONTREF), ContRef::null().into())
+            }
+            _ => TypedVal::new(ValType::from(RefType::ANYREF), AnyRef::null().into()),
         };
         self.alloc.stack.push_const(null);
         Ok(())
@@ -1052,6 +1197,8 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
             let is_null = match input.ty() {
                 ValType::FuncRef => FuncRef::from(untyped).is_null(),
                 ValType::ExternRef => ExternRef::from(untyped).is_null(),
+                ty if ty.is_any_ref() => AnyRef::from(untyped).is_null(),
+                ty if ty.is_cont_ref() => ContRef::from(untyped).is_null(),
                 invalid => panic!("ref.is_null: encountered invalid input type: {invalid:?}"),
             };
             self.alloc.stack.push_const(i32::from(is_null));
@@ -3374,4 +3521,690 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         self.push_fueled_instr(Instruction::table_size(result, table), FuelCosts::entity)?;
         Ok(())
     }
+
+    fn visit_ref_eq(&mut self) -> Self::Output {
+        bail_unreachable!(self);
+        let (lhs, rhs) = self.alloc.stack.pop2();
+        if let (Provider::Const(lhs), Provider::Const(rhs)) = (lhs, rhs) {
+            self.alloc.stack.push_const(lhs.untyped() == rhs.untyped());
+            return Ok(());
+        }
+        // Note: references are compared by their raw bits which is why we can use `i64.eq`.
+        let lhs = self.alloc.stack.provider2reg(&lhs)?;
+        let rhs = self.alloc.stack.provider2reg(&rhs)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::i64_eq(result, lhs, rhs), FuelCosts::base)?;
+        Ok(())
+    }
+
+    fn visit_struct_new(&mut self, struct_type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let (type_id, struct_type) = self.struct_type_at(struct_type_index);
+        let len_fields = struct_type.fields().len();
+        if len_fields == 0 {
+            return self.visit_struct_new_default(struct_type_index);
+        }
+        let ty = index::Type::from(type_id.into_u32());
+        let fields = &mut self.alloc.buffer.providers;
+        self.alloc.stack.pop_n(len_fields, fields);
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::struct_new(result, ty), FuelCosts::entity)?;
+        self.alloc
+            .instr_encoder
+            .encode_register_list(&mut self.alloc.stack, &self.alloc.buffer.providers)?;
+        Ok(())
+    }
+
+    fn visit_struct_new_default(&mut self, struct_type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let (type_id, struct_type) = self.struct_type_at(struct_type_index);
+        let ty = index::Type::from(type_id.into_u32());
+        let len_fields = struct_type.fields().len() as u32;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(
+            Instruction::struct_new_default(result, ty),
+            FuelCosts::entity,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::const32(len_fields))?;
+        Ok(())
+    }
+
+    fn visit_struct_get(&mut self, struct_type_index: u32, field_index: u32) -> Self::Output {
+        self.translate_struct_get(struct_type_index, field_index, None)
+    }
+
+    fn visit_struct_get_s(&mut self, struct_type_index: u32, field_index: u32) -> Self::Output {
+        self.translate_struct_get(struct_type_index, field_index, Some(true))
+    }
+
+    fn visit_struct_get_u(&mut self, struct_type_index: u32, field_index: u32) -> Self::Output {
+        self.translate_struct_get(struct_type_index, field_index, Some(false))
+    }
+
+    fn visit_struct_set(&mut self, _struct_type_index: u32, field_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let field = struct_field_index(field_index);
+        let (struct_ref, value) = self.alloc.stack.pop2();
+        let struct_ref = self.alloc.stack.provider2reg(&struct_ref)?;
+        let value = self.alloc.stack.provider2reg(&value)?;
+        self.push_fueled_instr(
+            Instruction::struct_set(struct_ref, field, value),
+            FuelCosts::store,
+        )?;
+        Ok(())
+    }
+
+    fn visit_array_new(&mut self, array_type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let ty = index::Type::from(self.type_id_at(array_type_index).into_u32());
+        let (value, len) = self.alloc.stack.pop2();
+        let value = self.alloc.stack.provider2reg(&value)?;
+        let len = self.alloc.stack.provider2reg(&len)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::array_new(result, ty), FuelCosts::entity)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register2_ext(len, value))?;
+        Ok(())
+    }
+
+    fn visit_array_new_default(&mut self, array_type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let ty = index::Type::from(self.type_id_at(array_type_index).into_u32());
+        let len = self.alloc.stack.pop();
+        let len = self.alloc.stack.provider2reg(&len)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(
+            Instruction::array_new_default(result, ty),
+            FuelCosts::entity,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register(len))?;
+        Ok(())
+    }
+
+    fn visit_array_new_fixed(&mut self, array_type_index: u32, array_size: u32) -> Self::Output {
+        bail_unreachable!(self);
+        if array_size == 0 {
+            self.alloc.stack.push_const(0_i32);
+            return self.visit_array_new_default(array_type_index);
+        }
+        let ty = index::Type::from(self.type_id_at(array_type_index).into_u32());
+        let elements = &mut self.alloc.buffer.providers;
+        self.alloc.stack.pop_n(array_size as usize, elements);
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::array_new_fixed(result, ty), FuelCosts::entity)?;
+        self.alloc
+            .instr_encoder
+            .encode_register_list(&mut self.alloc.stack, &self.alloc.buffer.providers)?;
+        Ok(())
+    }
+
+    fn visit_array_new_data(
+        &mut self,
+        array_type_index: u32,
+        array_data_index: u32,
+    ) -> Self::Output {
+        bail_unreachable!(self);
+        let ty = index::Type::from(self.type_id_at(array_type_index).into_u32());
+        let (offset, len) = self.alloc.stack.pop2();
+        let offset = self.alloc.stack.provider2reg(&offset)?;
+        let len = self.alloc.stack.provider2reg(&len)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::array_new_data(result, ty), FuelCosts::entity)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::data_index(array_data_index))?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register2_ext(offset, len))?;
+        Ok(())
+    }
+
+    fn visit_array_new_elem(
+        &mut self,
+        array_type_index: u32,
+        array_elem_index: u32,
+    ) -> Self::Output {
+        bail_unreachable!(self);
+        let ty = index::Type::from(self.type_id_at(array_type_index).into_u32());
+        let (offset, len) = self.alloc.stack.pop2();
+        let offset = self.alloc.stack.provider2reg(&offset)?;
+        let len = self.alloc.stack.provider2reg(&len)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::array_new_elem(result, ty), FuelCosts::entity)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::elem_index(array_elem_index))?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register2_ext(offset, len))?;
+        Ok(())
+    }
+
+    fn visit_array_get(&mut self, array_type_index: u32) -> Self::Output {
+        self.translate_array_get(array_type_index, None)
+    }
+
+    fn visit_array_get_s(&mut self, array_type_index: u32) -> Self::Output {
+        self.translate_array_get(array_type_index, Some(true))
+    }
+
+    fn visit_array_get_u(&mut self, array_type_index: u32) -> Self::Output {
+        self.translate_array_get(array_type_index, Some(false))
+    }
+
+    fn visit_array_set(&mut self, _array_type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let (array, index, value) = self.alloc.stack.pop3();
+        let array = self.alloc.stack.provider2reg(&array)?;
+        let index = self.alloc.stack.provider2reg(&index)?;
+        let value = self.alloc.stack.provider2reg(&value)?;
+        self.push_fueled_instr(
+            Instruction::array_set(array, index, value),
+            FuelCosts::store,
+        )?;
+        Ok(())
+    }
+
+    fn visit_array_len(&mut self) -> Self::Output {
+        bail_unreachable!(self);
+        let array = self.alloc.stack.pop();
+        let array = self.alloc.stack.provider2reg(&array)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::array_len(result, array), FuelCosts::base)?;
+        Ok(())
+    }
+
+    fn visit_array_fill(&mut self, _array_type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let len = self.alloc.stack.pop();
+        let (array, offset, value) = self.alloc.stack.pop3();
+        let array = self.alloc.stack.provider2reg(&array)?;
+        let offset = self.alloc.stack.provider2reg(&offset)?;
+        let value = self.alloc.stack.provider2reg(&value)?;
+        let len = self.alloc.stack.provider2reg(&len)?;
+        self.push_fueled_instr(
+            Instruction::array_fill(array, offset, value),
+            FuelCosts::entity,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register(len))?;
+        Ok(())
+    }
+
+    fn visit_array_copy(
+        &mut self,
+        _array_type_index_dst: u32,
+        _array_type_index_src: u32,
+    ) -> Self::Output {
+        bail_unreachable!(self);
+        let (src_offset, len) = self.alloc.stack.pop2();
+        let (dst, dst_offset, src) = self.alloc.stack.pop3();
+        let dst = self.alloc.stack.provider2reg(&dst)?;
+        let dst_offset = self.alloc.stack.provider2reg(&dst_offset)?;
+        let src = self.alloc.stack.provider2reg(&src)?;
+        let src_offset = self.alloc.stack.provider2reg(&src_offset)?;
+        let len = self.alloc.stack.provider2reg(&len)?;
+        self.push_fueled_instr(
+            Instruction::array_copy(dst, dst_offset, src),
+            FuelCosts::entity,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register2_ext(src_offset, len))?;
+        Ok(())
+    }
+
+    fn visit_array_init_data(
+        &mut self,
+        _array_type_index: u32,
+        array_data_index: u32,
+    ) -> Self::Output {
+        bail_unreachable!(self);
+        let len = self.alloc.stack.pop();
+        let (array, dst, src) = self.alloc.stack.pop3();
+        let array = self.alloc.stack.provider2reg(&array)?;
+        let dst = self.alloc.stack.provider2reg(&dst)?;
+        let src = self.alloc.stack.provider2reg(&src)?;
+        let len = self.alloc.stack.provider2reg(&len)?;
+        self.push_fueled_instr(
+            Instruction::array_init_data(array, dst, src),
+            FuelCosts::entity,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::data_index(array_data_index))?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register(len))?;
+        Ok(())
+    }
+
+    fn visit_array_init_elem(
+        &mut self,
+        _array_type_index: u32,
+        array_elem_index: u32,
+    ) -> Self::Output {
+        bail_unreachable!(self);
+        let len = self.alloc.stack.pop();
+        let (array, dst, src) = self.alloc.stack.pop3();
+        let array = self.alloc.stack.provider2reg(&array)?;
+        let dst = self.alloc.stack.provider2reg(&dst)?;
+        let src = self.alloc.stack.provider2reg(&src)?;
+        let len = self.alloc.stack.provider2reg(&len)?;
+        self.push_fueled_instr(
+            Instruction::array_init_elem(array, dst, src),
+            FuelCosts::entity,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::elem_index(array_elem_index))?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register(len))?;
+        Ok(())
+    }
+
+    fn visit_ref_test_non_null(&mut self, hty: wasmparser::HeapType) -> Self::Output {
+        self.translate_ref_test(RefType::new(false, self.type_resolver().heap_type(hty)))
+    }
+
+    fn visit_ref_test_nullable(&mut self, hty: wasmparser::HeapType) -> Self::Output {
+        self.translate_ref_test(RefType::new(true, self.type_resolver().heap_type(hty)))
+    }
+
+    fn visit_ref_cast_non_null(&mut self, hty: wasmparser::HeapType) -> Self::Output {
+        self.translate_ref_cast(RefType::new(false, self.type_resolver().heap_type(hty)))
+    }
+
+    fn visit_ref_cast_nullable(&mut self, hty: wasmparser::HeapType) -> Self::Output {
+        self.translate_ref_cast(RefType::new(true, self.type_resolver().heap_type(hty)))
+    }
+
+    fn visit_br_on_cast(
+        &mut self,
+        relative_depth: u32,
+        _from_ref_type: wasmparser::RefType,
+        to_ref_type: wasmparser::RefType,
+    ) -> Self::Output {
+        self.translate_br_on_cast(relative_depth, to_ref_type, true)
+    }
+
+    fn visit_br_on_cast_fail(
+        &mut self,
+        relative_depth: u32,
+        _from_ref_type: wasmparser::RefType,
+        to_ref_type: wasmparser::RefType,
+    ) -> Self::Output {
+        self.translate_br_on_cast(relative_depth, to_ref_type, false)
+    }
+
+    fn visit_any_convert_extern(&mut self) -> Self::Output {
+        self.translate_gc_unary(Instruction::any_convert_extern, FuelCosts::entity)
+    }
+
+    fn visit_extern_convert_any(&mut self) -> Self::Output {
+        self.translate_gc_unary(Instruction::extern_convert_any, FuelCosts::entity)
+    }
+
+    fn visit_ref_i31(&mut self) -> Self::Output {
+        bail_unreachable!(self);
+        if let Provider::Const(value) = self.alloc.stack.peek() {
+            self.alloc.stack.drop();
+            let i31 = AnyRef::from_i31(i32::from(value));
+            self.alloc
+                .stack
+                .push_const(TypedVal::new(ValType::from(RefType::I31REF), i31.into()));
+            return Ok(());
+        }
+        self.translate_gc_unary(Instruction::ref_i31, FuelCosts::base)
+    }
+
+    fn visit_i31_get_s(&mut self) -> Self::Output {
+        self.translate_gc_unary(Instruction::i31_get_s, FuelCosts::base)
+    }
+
+    fn visit_i31_get_u(&mut self) -> Self::Output {
+        self.translate_gc_unary(Instruction::i31_get_u, FuelCosts::base)
+    }
+
+    fn visit_i64_add128(&mut self) -> Self::Output {
+        self.translate_i64_binop128(Instruction::i64_add128, UntypedVal::i64_add128)
+    }
+
+    fn visit_i64_sub128(&mut self) -> Self::Output {
+        self.translate_i64_binop128(Instruction::i64_sub128, UntypedVal::i64_sub128)
+    }
+
+    fn visit_i64_mul_wide_s(&mut self) -> Self::Output {
+        self.translate_i64_mul_wide(Instruction::i64_mul_wide_s, UntypedVal::i64_mul_wide_s)
+    }
+
+    fn visit_i64_mul_wide_u(&mut self) -> Self::Output {
+        self.translate_i64_mul_wide(Instruction::i64_mul_wide_u, UntypedVal::i64_mul_wide_u)
+    }
+
+    fn visit_cont_new(&mut self, _cont_type_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let func = self.alloc.stack.pop();
+        let func = self.alloc.stack.provider2reg(&func)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::cont_new(result, func), FuelCosts::entity)?;
+        Ok(())
+    }
+
+    fn visit_cont_bind(&mut self, argument_index: u32, result_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let len_argument_params = self.cont_func_type_at(argument_index).params().len();
+        let len_result_params = self.cont_func_type_at(result_index).params().len();
+        let cont = self.alloc.stack.pop();
+        let cont = self.alloc.stack.provider2reg(&cont)?;
+        let values = self.translate_cont_args(len_argument_params - len_result_params)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::cont_bind(result, cont), FuelCosts::entity)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register_span(values))?;
+        Ok(())
+    }
+
+    fn visit_suspend(&mut self, tag_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let tag_type = self.tag_func_type_at(tag_index);
+        let (params, results) = tag_type.params_results();
+        let values = self.translate_cont_args(params.len())?;
+        let results = self.alloc.stack.push_dynamic_n(results.len())?;
+        self.push_fueled_instr(
+            Instruction::suspend(results, index::Tag::from(tag_index)),
+            FuelCosts::call,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register_span(values))?;
+        Ok(())
+    }
+
+    fn visit_resume(
+        &mut self,
+        cont_type_index: u32,
+        resume_table: wasmparser::ResumeTable,
+    ) -> Self::Output {
+        bail_unreachable!(self);
+        let func_type = self.cont_func_type_at(cont_type_index);
+        let (params, results) = func_type.params_results();
+        let len_handlers = u16::try_from(resume_table.handlers.len())
+            .map_err(|_| Error::from(TranslationError::BranchTableTargetsOutOfBounds))?;
+        let cont = self.alloc.stack.pop();
+        let cont = self.alloc.stack.provider2reg(&cont)?;
+        let values = self.translate_cont_args(params.len())?;
+        let len_results = results.len() as u16;
+        let results = self.alloc.stack.push_dynamic_n(results.len())?;
+        self.push_fueled_instr(
+            Instruction::resume(BoundedRegSpan::new(results, len_results), cont),
+            FuelCosts::call,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::resume_params(values, len_handlers))?;
+        self.translate_resume_handlers(&resume_table)
+    }
+
+    fn visit_switch(&mut self, cont_type_index: u32, tag_index: u32) -> Self::Output {
+        bail_unreachable!(self);
+        let func_type = self.cont_func_type_at(cont_type_index);
+        let (target_cont, params) = func_type
+            .params()
+            .split_last()
+            .expect("`switch` continuation types always have a continuation parameter");
+        let ValType::Ref(target_cont) = target_cont else {
+            panic!("`switch` expects a continuation parameter but found: {target_cont:?}")
+        };
+        let HeapType::Concrete(target_type) = target_cont.heap_type() else {
+            panic!("`switch` expects a concrete continuation type but found: {target_cont:?}")
+        };
+        let len_results = self.cont_func_type_of(target_type).params().len();
+        let cont = self.alloc.stack.pop();
+        let cont = self.alloc.stack.provider2reg(&cont)?;
+        let values = self.translate_cont_args(params.len())?;
+        let results = self.alloc.stack.push_dynamic_n(len_results)?;
+        self.push_fueled_instr(
+            Instruction::switch(cont, index::Tag::from(tag_index)),
+            FuelCosts::call,
+        )?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::switch_params(values, results))?;
+        Ok(())
+    }
+}
+
+/// Returns the [`Const16`] field index of a struct field access.
+///
+/// # Panics
+///
+/// If `field_index` is out of bounds which is prevented by Wasm validation limits.
+fn struct_field_index(field_index: u32) -> Const16<u32> {
+    <Const16<u32>>::try_from(field_index)
+        .unwrap_or_else(|_| panic!("struct field index out of bounds: {field_index}"))
+}
+
+impl FuncTranslator {
+    /// Translates a Wasm `struct.get`, `struct.get_s` or `struct.get_u` instruction.
+    ///
+    /// `signed` is `None` for `struct.get` and otherwise denotes the sign extension of packed fields.
+    fn translate_struct_get(
+        &mut self,
+        struct_type_index: u32,
+        field_index: u32,
+        signed: Option<bool>,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let (_, struct_type) = self.struct_type_at(struct_type_index);
+        let storage = struct_type.fields()[field_index as usize].storage();
+        let field = struct_field_index(field_index);
+        let struct_ref = self.alloc.stack.pop();
+        let struct_ref = self.alloc.stack.provider2reg(&struct_ref)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        let instr = match (storage, signed) {
+            (StorageType::I8, Some(true)) => Instruction::struct_get_i8_s,
+            (StorageType::I8, _) => Instruction::struct_get_i8_u,
+            (StorageType::I16, Some(true)) => Instruction::struct_get_i16_s,
+            (StorageType::I16, _) => Instruction::struct_get_i16_u,
+            (StorageType::Val(_), _) => Instruction::struct_get,
+        };
+        self.push_fueled_instr(instr(result, struct_ref, field), FuelCosts::load)?;
+        Ok(())
+    }
+
+    /// Translates a Wasm `array.get`, `array.get_s` or `array.get_u` instruction.
+    ///
+    /// `signed` is `None` for `array.get` and otherwise denotes the sign extension of packed elements.
+    fn translate_array_get(
+        &mut self,
+        array_type_index: u32,
+        signed: Option<bool>,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let (_, array_type) = self.array_type_at(array_type_index);
+        let (array, index) = self.alloc.stack.pop2();
+        let array = self.alloc.stack.provider2reg(&array)?;
+        let index = self.alloc.stack.provider2reg(&index)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        let instr = match (array_type.element().storage(), signed) {
+            (StorageType::I8, Some(true)) => Instruction::array_get_i8_s,
+            (StorageType::I8, _) => Instruction::array_get_i8_u,
+            (StorageType::I16, Some(true)) => Instruction::array_get_i16_s,
+            (StorageType::I16, _) => Instruction::array_get_i16_u,
+            (StorageType::Val(_), _) => Instruction::array_get,
+        };
+        self.push_fueled_instr(instr(result, array, index), FuelCosts::load)?;
+        Ok(())
+    }
+
+    /// Translates a unary instruction of the Wasm `gc` proposal operating on registers.
+    fn translate_gc_unary(
+        &mut self,
+        make_instr: fn(result: Reg, input: Reg) -> Instruction,
+        fuel: fn(&FuelCosts) -> u64,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let input = self.alloc.stack.pop();
+        let input = self.alloc.stack.provider2reg(&input)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(make_instr(result, input), fuel)?;
+        Ok(())
+    }
+
+    /// Returns an error if references cannot be tested against `ref_type`.
+    ///
+    /// # Note
+    ///
+    /// Continuations do not carry their concrete type at runtime which
+    /// is why they can only be tested against the abstract `cont` type.
+    fn ensure_castable_ref_type(ref_type: RefType, op: &'static str) -> Result<(), Error> {
+        if let HeapType::Concrete(type_id) = ref_type.heap_type() {
+            if type_id.kind() == TypeKind::Cont {
+                return Err(Error::from(TranslationError::UnsupportedOperator(op)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes a `ref.test` of `input` against `ref_type` storing its result into `result`.
+    fn encode_ref_test(&mut self, result: Reg, input: Reg, ref_type: RefType) -> Result<(), Error> {
+        self.push_fueled_instr(Instruction::ref_test(result, input), FuelCosts::base)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::const32(ref_type.to_bits()))?;
+        Ok(())
+    }
+
+    /// Translates a Wasm `ref.test` instruction against `ref_type`.
+    fn translate_ref_test(&mut self, ref_type: RefType) -> Result<(), Error> {
+        bail_unreachable!(self);
+        Self::ensure_castable_ref_type(ref_type, "ref.test")?;
+        let input = self.alloc.stack.pop();
+        let input = self.alloc.stack.provider2reg(&input)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.encode_ref_test(result, input, ref_type)
+    }
+
+    /// Translates a Wasm `ref.cast` instruction to `ref_type`.
+    fn translate_ref_cast(&mut self, ref_type: RefType) -> Result<(), Error> {
+        bail_unreachable!(self);
+        Self::ensure_castable_ref_type(ref_type, "ref.cast")?;
+        let input = self.alloc.stack.pop();
+        let input = self.alloc.stack.provider2reg(&input)?;
+        let result = self.alloc.stack.push_dynamic()?;
+        self.push_fueled_instr(Instruction::ref_cast(result, input), FuelCosts::base)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::const32(ref_type.to_bits()))?;
+        Ok(())
+    }
+
+    /// Translates a Wasm `br_on_cast` or `br_on_cast_fail` instruction.
+    ///
+    /// Branches if the reference on top of the stack matches `to_ref_type` if `on_match` is `true`
+    /// and otherwise if it does not match.
+    fn translate_br_on_cast(
+        &mut self,
+        relative_depth: u32,
+        to_ref_type: wasmparser::RefType,
+        on_match: bool,
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let ref_type = RefType::new(
+            to_ref_type.is_nullable(),
+            self.type_resolver().heap_type(to_ref_type.heap_type()),
+        );
+        Self::ensure_castable_ref_type(ref_type, "br_on_cast")?;
+        let input = self.alloc.stack.peek();
+        let input = match input {
+            TypedProvider::Register(input) => input,
+            TypedProvider::Const(input) => self.alloc.stack.alloc_const(input)?,
+        };
+        let test = self.alloc.stack.push_dynamic()?;
+        self.encode_ref_test(test, input, ref_type)?;
+        self.alloc.stack.drop();
+        // Note: the result of `ref.test` is either `0` or `1` and thus
+        //       we can branch on it the same way as on `null` references.
+        self.translate_br_on_ref(relative_depth, test, !on_match)
+    }
+
+    /// Pushes the two `i64` constant `values` onto the stack as `[lo, hi]` pair.
+    fn push_const_i64_pair(&mut self, values: [UntypedVal; 2]) {
+        let [lo, hi] = values;
+        self.alloc.stack.push_const(i64::from(lo));
+        self.alloc.stack.push_const(i64::from(hi));
+    }
+
+    /// Pushes two dynamic registers onto the stack and returns them as [`FixedRegSpan`].
+    fn push_dynamic_pair(&mut self) -> Result<FixedRegSpan<2>, Error> {
+        let results = self.alloc.stack.push_dynamic_n(2)?;
+        let results = FixedRegSpan::new(results)
+            .unwrap_or_else(|_| panic!("encountered invalid `results` `RegSpan`: {results:?}"));
+        Ok(results)
+    }
+
+    /// Translates a Wasm `i64.add128` or `i64.sub128` instruction.
+    ///
+    /// Both operands and the result are 128-bit integers encoded as `[lo, hi]` pairs of `i64` values.
+    fn translate_i64_binop128(
+        &mut self,
+        make_instr: fn(results: FixedRegSpan<2>, lhs: [Reg; 2]) -> Instruction,
+        consteval: fn(lhs: [UntypedVal; 2], rhs: [UntypedVal; 2]) -> [UntypedVal; 2],
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let (rhs_lo, rhs_hi) = self.alloc.stack.pop2();
+        let (lhs_lo, lhs_hi) = self.alloc.stack.pop2();
+        if let (
+            Provider::Const(lhs_lo),
+            Provider::Const(lhs_hi),
+            Provider::Const(rhs_lo),
+            Provider::Const(rhs_hi),
+        ) = (lhs_lo, lhs_hi, rhs_lo, rhs_hi)
+        {
+            let lhs = [lhs_lo, lhs_hi].map(UntypedVal::from);
+            let rhs = [rhs_lo, rhs_hi].map(UntypedVal::from);
+            self.push_const_i64_pair(consteval(lhs, rhs));
+            return Ok(());
+        }
+        let lhs_lo = self.alloc.stack.provider2reg(&lhs_lo)?;
+        let lhs_hi = self.alloc.stack.provider2reg(&lhs_hi)?;
+        let rhs_lo = self.alloc.stack.provider2reg(&rhs_lo)?;
+        let rhs_hi = self.alloc.stack.provider2reg(&rhs_hi)?;
+        let results = self.push_dynamic_pair()?;
+        self.push_fueled_instr(make_instr(results, [lhs_lo, lhs_hi]), FuelCosts::base)?;
+        self.alloc
+            .instr_encoder
+            .append_instr(Instruction::register2_ext(rhs_lo, rhs_hi))?;
+        Ok(())
+    }
+
+    /// Translates a Wasm `i64.mul_wide_s` or `i64.mul_wide_u` instruction.
+    ///
+    /// The result is a 128-bit integer encoded as `[lo, hi]` pair of `i64` values.
+    fn translate_i64_mul_wide(
+        &mut self,
+        make_instr: fn(results: FixedRegSpan<2>, lhs: Reg, rhs: Reg) -> Instruction,
+        consteval: fn(lhs: UntypedVal, rhs: UntypedVal) -> [UntypedVal; 2],
+    ) -> Result<(), Error> {
+        bail_unreachable!(self);
+        let (lhs, rhs) = self.alloc.stack.pop2();
+        if let (Provider::Const(lhs), Provider::Const(rhs)) = (lhs, rhs) {
+            self.push_const_i64_pair(consteval(lhs.into(), rhs.into()));
+            return Ok(());
+        }
+        let lhs = self.alloc.stack.provider2reg(&lhs)?;
+        let rhs = self.alloc.stack.provider2reg(&rhs)?;
+        let results = self.push_dynamic_pair()?;
+        self.push_fueled_instr(make_instr(results, lhs, rhs), FuelCosts::base)?;
+        Ok(())
+    }
 }
@@ -10,7 +10,9 @@ use super::errors::{
     TableError,
 };
 use crate::{
+    backtrace::WasmBacktrace,
     core::{HostError, TrapCode},
+    coredump::WasmCoreDump,
     engine::{ResumableHostError, TranslationError},
     module::ReadError,
 };
@@ -21,8 +23,31 @@ use wasmparser::BinaryReaderError as WasmError;
 /// The generic Wasmi root error type.
 #[derive(Debug)]
 pub struct Error {
+    /// The underlying kind of the error and additional information.
+    inner: Box<ErrorInner>,
+}
+
+/// The internal representation of an [`Error`].
+#[derive(Debug)]
+struct ErrorInner {
     /// The underlying kind of the error and its specific information.
-    kind: Box<ErrorKind>,
+    kind: ErrorKind,
+    /// The Wasm backtrace captured when the error occurred during Wasm execution.
+    ///
+    /// # Note
+    ///
+    /// This is only ever `Some` if [`Config::wasm_backtrace`] is enabled.
+    ///
+    /// [`Config::wasm_backtrace`]: crate::Config::wasm_backtrace
+    backtrace: Option<WasmBacktrace>,
+    /// The Wasm coredump captured when the error occurred during Wasm execution.
+    ///
+    /// # Note
+    ///
+    /// This is only ever `Some` if [`Config::coredump_on_trap`] is enabled.
+    ///
+    /// [`Config::coredump_on_trap`]: crate::Config::coredump_on_trap
+    coredump: Option<WasmCoreDump>,
 }
 
 #[test]
@@ -35,7 +60,11 @@ impl Error {
     /// Creates a new [`Error`] from the [`ErrorKind`].
     fn from_kind(kind: ErrorKind) -> Self {
         Self {
-            kind: Box::new(kind),
+            inner: Box::new(ErrorInner {
+                kind,
+                backtrace: None,
+                coredump: None,
+            }),
         }
     }
 
@@ -72,7 +101,67 @@ impl Error {
 
     /// Returns the [`ErrorKind`] of the [`Error`].
     pub fn kind(&self) -> &ErrorKind {
-        &self.kind
+        &self.inner.kind
+    }
+
+    /// Returns the [`WasmBacktrace`] captured when the [`Error`] occurred if any.
+    ///
+    /// # Note
+    ///
+    /// Wasm backtraces are only captured for errors that occurred during
+    /// Wasm execution and only if [`Config::wasm_backtrace`] is enabled.
+    ///
+    /// [`Config::wasm_backtrace`]: crate::Config::wasm_backtrace
+    pub fn backtrace(&self) -> Option<&WasmBacktrace> {
+        self.inner.backtrace.as_ref()
+    }
+
+    /// Attaches the Wasm `backtrace` to the [`Error`].
+    ///
+    /// # Note
+    ///
+    /// - If the [`Error`] already has a [`WasmBacktrace`] the frames of
+    ///   `backtrace` are appended since they belong to an outer Wasm execution.
+    /// - Resumable host errors forward the `backtrace` to their underlying host error.
+    #[cold]
+    pub(crate) fn attach_backtrace(&mut self, backtrace: WasmBacktrace) {
+        if let ErrorKind::ResumableHost(error) = &mut self.inner.kind {
+            return error.host_error_mut().attach_backtrace(backtrace);
+        }
+        match &mut self.inner.backtrace {
+            Some(inner) => inner.append(backtrace),
+            None => self.inner.backtrace = Some(backtrace),
+        }
+    }
+
+    /// Returns the [`WasmCoreDump`] captured when the [`Error`] occurred if any.
+    ///
+    /// # Note
+    ///
+    /// Wasm coredumps are only captured for errors that occurred during
+    /// Wasm execution and only if [`Config::coredump_on_trap`] is enabled.
+    ///
+    /// [`Config::coredump_on_trap`]: crate::Config::coredump_on_trap
+    pub fn coredump(&self) -> Option<&WasmCoreDump> {
+        self.inner.coredump.as_ref()
+    }
+
+    /// Attaches the Wasm `coredump` to the [`Error`].
+    ///
+    /// # Note
+    ///
+    /// - If the [`Error`] already has a [`WasmCoreDump`] the frames of
+    ///   `coredump` are appended since they belong to an outer Wasm execution.
+    /// - Resumable host errors forward the `coredump` to their underlying host error.
+    #[cold]
+    pub(crate) fn attach_coredump(&mut self, coredump: WasmCoreDump) {
+        if let ErrorKind::ResumableHost(error) = &mut self.inner.kind {
+            return error.host_error_mut().attach_coredump(coredump);
+        }
+        match &mut self.inner.coredump {
+            Some(inner) => inner.append(coredump),
+            None => self.inner.coredump = Some(coredump),
+        }
     }
 
     /// Returns a reference to [`TrapCode`] if [`Error`] is a [`TrapCode`].
@@ -95,9 +184,10 @@ impl Error {
     where
         T: HostError,
     {
-        self.kind
+        self.inner
+            .kind
             .as_host()
-            .and_then(<(dyn HostError + 'static)>::downcast_ref)
+            .and_then(<dyn HostError + 'static>::downcast_ref)
     }
 
     /// Downcasts the [`Error`] into the `T: HostError` if possible.
@@ -108,9 +198,10 @@ impl Error {
     where
         T: HostError,
     {
-        self.kind
+        self.inner
+            .kind
             .as_host_mut()
-            .and_then(<(dyn HostError + 'static)>::downcast_mut)
+            .and_then(<dyn HostError + 'static>::downcast_mut)
     }
 
     /// Consumes `self` to downcast the [`Error`] into the `T: HostError` if possible.
@@ -121,15 +212,16 @@ impl Error {
     where
         T: HostError,
     {
-        self.kind
+        self.inner
+            .kind
             .into_host()
             .and_then(|error| error.downcast().ok())
             .map(|boxed| *boxed)
     }
 
     pub(crate) fn into_resumable(self) -> Result<ResumableHostError, Error> {
-        if matches!(&*self.kind, ErrorKind::ResumableHost(_)) {
-            let ErrorKind::ResumableHost(error) = *self.kind else {
+        if matches!(&self.inner.kind, ErrorKind::ResumableHost(_)) {
+            let ErrorKind::ResumableHost(error) = self.inner.kind else {
                 unreachable!("asserted that host error is resumable")
             };
             return Ok(error);
@@ -143,7 +235,11 @@ impl std::error::Error for Error {}
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        Display::fmt(&self.kind, f)
+        Display::fmt(&self.inner.kind, f)?;
+        if let Some(backtrace) = self.backtrace().filter(|bt| !bt.is_empty()) {
+            write!(f, "\n\n{backtrace}")?;
+        }
+        Ok(())
     }
 }
 
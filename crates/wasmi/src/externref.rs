@@ -157,12 +157,17 @@ impl ExternRef {
     }
 
     /// Creates a new [`ExternRef`] to the given [`ExternObject`].
-    fn from_object(object: ExternObject) -> Self {
+    pub(crate) fn from_object(object: ExternObject) -> Self {
         Self {
             inner: Some(object),
         }
     }
 
+    /// Returns the [`ExternObject`] referenced by the [`ExternRef`] if it is not `null`.
+    pub(crate) fn as_object(&self) -> Option<&ExternObject> {
+        self.inner.as_ref()
+    }
+
     /// Returns `true` if [`ExternRef`] is `null`.
     pub fn is_null(&self) -> bool {
         self.inner.is_none()
@@ -0,0 +1,114 @@
+use crate::{
+    core::{UntypedVal, ValType},
+    value::WithType,
+    FuncType,
+    Val,
+};
+use alloc::{sync::Arc, vec::Vec};
+use core::fmt;
+
+/// The type of the closure computing the fuel of a host function call from its parameters.
+type HostFuncFuelFn = dyn Fn(&[Val]) -> u64 + Send + Sync + 'static;
+
+/// The amount of fuel charged for calls to a [`Linker`] defined host function.
+///
+/// The fuel is charged before the host function is invoked by Wasm.
+/// If there is not enough fuel left the execution traps with [`TrapCode::OutOfFuel`],
+/// just like it is the case for Wasm instructions.
+///
+/// # Note
+///
+/// This has no effect if fuel metering is disabled for the [`Engine`].
+///
+/// [`Linker`]: crate::Linker
+/// [`Engine`]: crate::Engine
+/// [`TrapCode::OutOfFuel`]: crate::core::TrapCode::OutOfFuel
+#[derive(Clone)]
+pub struct HostFuncFuel {
+    kind: HostFuncFuelKind,
+}
+
+/// The internal representation of a [`HostFuncFuel`].
+#[derive(Clone)]
+enum HostFuncFuelKind {
+    /// The same amount of fuel is charged for every call.
+    Fixed(u64),
+    /// The amount of fuel is computed from the parameters of every call.
+    Dynamic(Arc<HostFuncFuelFn>),
+}
+
+impl fmt::Debug for HostFuncFuel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            HostFuncFuelKind::Fixed(amount) => f.debug_tuple("Fixed").field(amount).finish(),
+            HostFuncFuelKind::Dynamic(_) => f.write_str("Dynamic(..)"),
+        }
+    }
+}
+
+impl From<u64> for HostFuncFuel {
+    fn from(amount: u64) -> Self {
+        Self::fixed(amount)
+    }
+}
+
+impl HostFuncFuel {
+    /// Creates a [`HostFuncFuel`] that charges `amount` of fuel for every call.
+    pub fn fixed(amount: u64) -> Self {
+        Self {
+            kind: HostFuncFuelKind::Fixed(amount),
+        }
+    }
+
+    /// Creates a [`HostFuncFuel`] that charges the fuel computed by `f` for every call.
+    ///
+    /// The closure `f` is provided with the parameters of the host function call.
+    pub fn dynamic(f: impl Fn(&[Val]) -> u64 + Send + Sync + 'static) -> Self {
+        Self {
+            kind: HostFuncFuelKind::Dynamic(Arc::new(f)),
+        }
+    }
+}
+
+/// A [`HostFuncFuel`] bound to the parameter types of its host function.
+#[derive(Debug, Clone)]
+pub struct TrampolineFuel {
+    /// The fuel definition of the host function.
+    fuel: HostFuncFuel,
+    /// The parameter types of the host function.
+    ///
+    /// Required to decode the untyped parameters for [`HostFuncFuel::dynamic`].
+    params: Arc<[ValType]>,
+}
+
+impl TrampolineFuel {
+    /// Creates a new [`TrampolineFuel`] for `fuel` and the host function type `ty`.
+    pub fn new(fuel: HostFuncFuel, ty: &FuncType) -> Self {
+        Self {
+            fuel,
+            params: ty.params().into(),
+        }
+    }
+
+    /// Returns the amount of fuel to charge for a call with the given `params`.
+    ///
+    /// The `buffer` is used to decode `params` for [`HostFuncFuel::dynamic`] and cleared afterwards.
+    pub fn cost(&self, params: &[UntypedVal], buffer: &mut Vec<Val>) -> u64 {
+        match &self.fuel.kind {
+            HostFuncFuelKind::Fixed(amount) => *amount,
+            HostFuncFuelKind::Dynamic(f) => {
+                debug_assert_eq!(params.len(), self.params.len());
+                buffer.clear();
+                buffer.extend(
+                    params
+                        .iter()
+                        .zip(self.params.iter())
+                        .map(|(value, ty)| value.with_type(*ty)),
+                );
+                let cost = f(buffer);
+                buffer.clear();
+                cost
+            }
+        }
+    }
+}
@@ -35,21 +35,13 @@ pub enum FuncTypeInner {
 }
 
 impl FuncTypeInner {
-    /// The inline buffer size on 32-bit platforms.
+    /// The inline buffer size.
     ///
     /// # Note
     ///
-    /// On 32-bit platforms we target a `size_of<FuncTypeInner>()` of 16 bytes.
-    #[cfg(target_pointer_width = "32")]
-    const INLINE_SIZE: usize = 14;
-
-    /// The inline buffer size on 64-bit platforms.
-    ///
-    /// # Note
-    ///
-    /// On 64-bit platforms we target a `size_of<FuncTypeInner>()` of 24 bytes.
-    #[cfg(target_pointer_width = "64")]
-    const INLINE_SIZE: usize = 21;
+    /// We target a `size_of<FuncTypeInner>()` of 36 bytes on 32-bit platforms
+    /// and of 40 bytes on 64-bit platforms given an 8 bytes sized [`ValType`].
+    const INLINE_SIZE: usize = 4;
 
     /// The maximum number of parameter types allowed of a [`FuncType`].
     const MAX_LEN_PARAMS: usize = 1_000;
@@ -204,10 +196,11 @@ impl FuncTypeInner {
 
 #[test]
 fn size_of_func_type() {
+    assert_eq!(core::mem::size_of::<ValType>(), 8);
     #[cfg(target_pointer_width = "32")]
-    assert!(core::mem::size_of::<FuncTypeInner>() <= 16);
+    assert!(core::mem::size_of::<FuncTypeInner>() <= 36);
     #[cfg(target_pointer_width = "64")]
-    assert!(core::mem::size_of::<FuncTypeInner>() <= 24);
+    assert!(core::mem::size_of::<FuncTypeInner>() <= 40);
 }
 
 impl fmt::Debug for FuncType {
@@ -260,22 +253,27 @@ impl FuncType {
 
     /// Returns `Ok` if the number and types of items in `params` matches as expected by the [`FuncType`].
     ///
+    /// # Note
+    ///
+    /// The `matches` closure decides if an item of `params` matches its expected [`ValType`].
+    ///
     /// # Errors
     ///
     /// - If the number of items in `params` does not match the number of parameters of the function type.
     /// - If any type of an item in `params` does not match the expected type of the function type.
-    pub(crate) fn match_params<T>(&self, params: &[T]) -> Result<(), FuncError>
-    where
-        T: Ty,
-    {
+    pub(crate) fn match_params<T>(
+        &self,
+        params: &[T],
+        mut matches: impl FnMut(&T, &ValType) -> bool,
+    ) -> Result<(), FuncError> {
         if self.params().len() != params.len() {
             return Err(FuncError::MismatchingParameterLen);
         }
-        if self
+        if !self
             .params()
             .iter()
-            .copied()
-            .ne(params.iter().map(<T as Ty>::ty))
+            .zip(params)
+            .all(|(expected, param)| matches(param, expected))
         {
             return Err(FuncError::MismatchingParameterType);
         }
@@ -286,25 +284,25 @@ impl FuncType {
     ///
     /// # Note
     ///
-    /// Only checks types if `check_type` is set to `true`.
+    /// The `matches` closure decides if an item of `results` matches its expected [`ValType`].
     ///
     /// # Errors
     ///
     /// - If the number of items in `results` does not match the number of results of the function type.
     /// - If any type of an item in `results` does not match the expected type of the function type.
-    pub(crate) fn match_results<T>(&self, results: &[T], check_type: bool) -> Result<(), FuncError>
-    where
-        T: Ty,
-    {
+    pub(crate) fn match_results<T>(
+        &self,
+        results: &[T],
+        mut matches: impl FnMut(&T, &ValType) -> bool,
+    ) -> Result<(), FuncError> {
         if self.results().len() != results.len() {
             return Err(FuncError::MismatchingResultLen);
         }
-        if check_type
-            && self
-                .results()
-                .iter()
-                .copied()
-                .ne(results.iter().map(<T as Ty>::ty))
+        if !self
+            .results()
+            .iter()
+            .zip(results)
+            .all(|(expected, result)| matches(result, expected))
         {
             return Err(FuncError::MismatchingResultType);
         }
@@ -335,28 +333,6 @@ impl FuncType {
     }
 }
 
-/// Types that have a [`ValType`].
-///
-/// # Note
-///
-/// Primarily used to allow `match_params` and `match_results`
-/// to be called with both [`Val`] and [`ValType`] parameters.
-pub(crate) trait Ty {
-    fn ty(&self) -> ValType;
-}
-
-impl Ty for ValType {
-    fn ty(&self) -> ValType {
-        *self
-    }
-}
-
-impl Ty for Val {
-    fn ty(&self) -> ValType {
-        self.ty()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
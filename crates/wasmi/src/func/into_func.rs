@@ -27,6 +27,50 @@ pub trait IntoFunc<T, Params, Results>: Send + Sync + 'static {
 }
 
 macro_rules! impl_into_func {
+    ( 1 $param:ident ) => {
+        // Single parameters may be any `WasmTyList` so that types
+        // deriving `WasmTyList` can be used as multi-value parameters.
+        impl<T, F, $param, R> IntoFunc<T, ($param,), R> for F
+        where
+            F: Fn($param) -> R,
+            F: Send + Sync + 'static,
+            $param: WasmTyList,
+            R: WasmRet,
+        {
+            type Params = $param;
+            type Results = <R as WasmRet>::Ok;
+
+            fn into_func(self) -> (FuncType, TrampolineEntity<T>) {
+                IntoFunc::into_func(move |_: Caller<'_, T>, param: $param| (self)(param))
+            }
+        }
+
+        impl<T, F, $param, R> IntoFunc<T, (Caller<'_, T>, $param), R> for F
+        where
+            F: Fn(Caller<T>, $param) -> R,
+            F: Send + Sync + 'static,
+            $param: WasmTyList,
+            R: WasmRet,
+        {
+            type Params = $param;
+            type Results = <R as WasmRet>::Ok;
+
+            fn into_func(self) -> (FuncType, TrampolineEntity<T>) {
+                let signature = FuncType::new(
+                    <Self::Params as WasmTyList>::types(),
+                    <Self::Results as WasmTyList>::types(),
+                );
+                let trampoline = TrampolineEntity::new(
+                    move |caller: Caller<T>, params_results: FuncParams| -> Result<FuncFinished, Error> {
+                        let (param, func_results): (Self::Params, FuncResults) = params_results.decode_params();
+                        let results: Self::Results = (self)(caller, param).into_fallible()?;
+                        Ok(func_results.encode_results(results))
+                    },
+                );
+                (signature, trampoline)
+            }
+        }
+    };
     ( $n:literal $( $tuple:ident )* ) => {
         impl<T, F, $($tuple,)* R> IntoFunc<T, ($($tuple,)*), R> for F
         where
@@ -109,11 +153,11 @@ where
     }
 }
 
-impl<T1> WasmRet for Result<T1, Error>
+impl<T> WasmRet for Result<T, Error>
 where
-    T1: WasmTy,
+    T: WasmTyList,
 {
-    type Ok = T1;
+    type Ok = T;
 
     #[inline]
     fn into_fallible(self) -> Result<<Self as WasmRet>::Ok, Error> {
@@ -136,20 +180,6 @@ macro_rules! impl_wasm_return_type {
                 Ok(self)
             }
         }
-
-        impl<$($tuple),*> WasmRet for Result<($($tuple,)*), Error>
-        where
-            $(
-                $tuple: WasmTy
-            ),*
-        {
-            type Ok = ($($tuple,)*);
-
-            #[inline]
-            fn into_fallible(self) -> Result<<Self as WasmRet>::Ok, Error> {
-                self
-            }
-        }
     };
 }
 for_each_tuple!(impl_wasm_return_type);
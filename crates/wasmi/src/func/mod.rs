@@ -1,21 +1,23 @@
 mod caller;
 mod error;
+mod fuel;
 mod func_type;
 mod funcref;
 mod into_func;
 mod typed_func;
 
-pub(crate) use self::typed_func::CallResultsTuple;
 pub use self::{
     caller::Caller,
     error::FuncError,
+    fuel::HostFuncFuel,
     func_type::FuncType,
     funcref::FuncRef,
     into_func::{IntoFunc, WasmRet, WasmTy, WasmTyList},
     typed_func::{TypedFunc, WasmParams, WasmResults},
 };
+pub(crate) use self::{fuel::TrampolineFuel, typed_func::CallResultsTuple};
 use super::{
-    engine::{DedupFuncType, EngineFunc, FuncFinished, FuncParams},
+    engine::{DedupFuncType, EngineFunc, FuncFinished, FuncInOut, FuncParams},
     AsContext,
     AsContextMut,
     Instance,
@@ -231,6 +233,7 @@ impl<T> HostFuncTrampolineEntity<T> {
         let results_iter = ty.results().iter().copied().map(Val::default);
         let len_params = ty.params().len();
         let params_results: Box<[Val]> = params_iter.chain(results_iter).collect();
+        let func_ty = ty.clone();
         let trampoline = <TrampolineEntity<T>>::new(move |caller, args| {
             // We are required to clone the buffer because we are operating within a `Fn`.
             // This way the trampoline closure only has to own a single slice buffer.
@@ -240,11 +243,32 @@ impl<T> HostFuncTrampolineEntity<T> {
             let (params, results) = params_results.split_at_mut(len_params);
             let func_results = args.decode_params_into_slice(params).unwrap();
             func(caller, params, results)?;
+            // Results must be checked since Wasm code relies on their types,
+            // e.g. on non-nullable reference types never being `null`.
+            let results_match = results
+                .iter()
+                .zip(func_ty.results())
+                .all(|(result, ty)| result.matches_ty_erased(ty));
+            if !results_match {
+                return Err(Error::from(FuncError::MismatchingResultType));
+            }
             Ok(func_results.encode_results_from_slice(results).unwrap())
         });
         Self { ty, trampoline }
     }
 
+    /// Creates a new host function trampoline from the given raw closure.
+    pub fn new_raw(
+        ty: FuncType,
+        func: impl Fn(Caller<'_, T>, FuncInOut<'_>) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Self {
+        let func_ty = ty.clone();
+        let trampoline = <TrampolineEntity<T>>::new(move |caller, params_results| {
+            params_results.call_raw(&func_ty, |inout| func(caller, inout))
+        });
+        Self { ty, trampoline }
+    }
+
     /// Creates a new host function trampoline from the given statically typed closure.
     pub fn wrap<Params, Results>(func: impl IntoFunc<T, Params, Results>) -> Self {
         let (ty, trampoline) = func.into_func();
@@ -261,6 +285,11 @@ impl<T> HostFuncTrampolineEntity<T> {
     pub fn trampoline(&self) -> &TrampolineEntity<T> {
         &self.trampoline
     }
+
+    /// Sets the [`HostFuncFuel`] charged for calls to the host function.
+    pub fn set_fuel(&mut self, fuel: HostFuncFuel) {
+        self.trampoline.fuel = Some(TrampolineFuel::new(fuel, &self.ty));
+    }
 }
 
 type TrampolineFn<T> =
@@ -268,6 +297,8 @@ type TrampolineFn<T> =
 
 pub struct TrampolineEntity<T> {
     closure: Arc<TrampolineFn<T>>,
+    /// The fuel charged for calls to the host function if any.
+    fuel: Option<TrampolineFuel>,
 }
 
 impl<T> Debug for TrampolineEntity<T> {
@@ -284,9 +315,15 @@ impl<T> TrampolineEntity<T> {
     {
         Self {
             closure: Arc::new(trampoline),
+            fuel: None,
         }
     }
 
+    /// Returns the [`TrampolineFuel`] charged for calls to the host function if any.
+    pub fn fuel(&self) -> Option<&TrampolineFuel> {
+        self.fuel.as_ref()
+    }
+
     /// Calls the host function trampoline with the given inputs.
     ///
     /// The result is written back into the `outputs` buffer.
@@ -305,6 +342,7 @@ impl<T> Clone for TrampolineEntity<T> {
     fn clone(&self) -> Self {
         Self {
             closure: self.closure.clone(),
+            fuel: self.fuel.clone(),
         }
     }
 }
@@ -330,21 +368,21 @@ impl Func {
     /// This is typically used to create a host-defined function to pass as an import to a Wasm module.
     ///
     /// - `ty`: the signature that the given closure adheres to,
-    ///         used to indicate what the inputs and outputs are.
+    ///   used to indicate what the inputs and outputs are.
     /// - `func`: the native code invoked whenever this Func will be called.
-    ///           The closure is provided a [`Caller`] as its first argument
-    ///           which allows it to query information about the [`Instance`]
-    ///           that is associated to the call.
+    ///   The closure is provided a [`Caller`] as its first argument
+    ///   which allows it to query information about the [`Instance`]
+    ///   that is associated to the call.
     ///
     /// # Note
     ///
     /// - The given [`FuncType`] `ty` must match the parameters and results otherwise
     ///   the resulting host [`Func`] might trap during execution.
     /// - It is the responsibility of the caller of [`Func::new`] to guarantee that
-    ///   the correct amount and types of results are written into the results buffer
-    ///   from the `func` closure. If an incorrect amount of results or types of results
-    ///   is written into the buffer then the remaining computation may fail in unexpected
-    ///   ways. This footgun can be avoided by using the typed [`Func::wrap`] method instead.
+    ///   the correct types of results are written into the results buffer from the
+    ///   `func` closure. Otherwise, e.g. if `null` is written for a non-nullable
+    ///   reference type, calling the [`Func`] returns a [`FuncError::MismatchingResultType`]
+    ///   error. This footgun can be avoided by using the typed [`Func::wrap`] method instead.
     /// - Prefer using [`Func::wrap`] over this method if possible since [`Func`] instances
     ///   created using this constructor have runtime overhead for every invocation that
     ///   can be avoided by using [`Func::wrap`].
@@ -363,6 +401,38 @@ impl Func {
             .alloc_func(host_func.into())
     }
 
+    /// Creates a new raw host [`Func`] with the given arguments.
+    ///
+    /// The `func` closure operates directly on the untyped parameters and results
+    /// of the call via [`FuncInOut`] which avoids all conversions between untyped
+    /// and typed values as well as intermediate buffers.
+    ///
+    /// # Note
+    ///
+    /// - It is the responsibility of the `func` closure to interpret its parameters and
+    ///   to write its results according to `ty` which is available via [`FuncInOut::ty`].
+    ///   Results of mismatching types do not cause undefined behavior but may cause the
+    ///   remaining computation to fail in unexpected ways.
+    /// - Like with [`Func::new`], reference typed results are checked after the call so that,
+    ///   e.g. if `null` is written for a non-nullable reference type, calling the [`Func`]
+    ///   returns a [`FuncError::MismatchingResultType`] error.
+    /// - Prefer this method over [`Func::new`] and [`Func::wrap`] for host functions
+    ///   that are called very frequently, e.g. system calls.
+    pub fn new_raw<T>(
+        mut ctx: impl AsContextMut<Data = T>,
+        ty: FuncType,
+        func: impl Fn(Caller<'_, T>, FuncInOut<'_>) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Self {
+        let host_func = HostFuncTrampolineEntity::new_raw(ty.clone(), func);
+        let trampoline = host_func.trampoline().clone();
+        let func = ctx.as_context_mut().store.alloc_trampoline(trampoline);
+        let host_func = HostFuncEntity::new(ctx.as_context().engine(), &ty, func);
+        ctx.as_context_mut()
+            .store
+            .inner
+            .alloc_func(host_func.into())
+    }
+
     /// Creates a new host function from the given closure.
     pub fn wrap<T, Params, Results>(
         mut ctx: impl AsContextMut<Data = T>,
@@ -399,6 +469,16 @@ impl Func {
     ///
     /// The result is written back into the `outputs` buffer.
     ///
+    /// # Note
+    ///
+    /// Objects referred to by [`AnyRef`] results are kept alive, i.e. leaked, until they
+    /// are released via [`Store::gc_release_host_refs`] unless the call happens within
+    /// a host function call or [`Store::gc_scope`] which releases them once it returns.
+    ///
+    /// [`AnyRef`]: crate::AnyRef
+    /// [`Store::gc_release_host_refs`]: crate::Store::gc_release_host_refs
+    /// [`Store::gc_scope`]: crate::Store::gc_scope
+    ///
     /// # Errors
     ///
     /// - If the function returned a [`Error`].
@@ -420,8 +500,9 @@ impl Func {
             ctx.as_context_mut(),
             self,
             inputs,
-            outputs,
+            &mut *outputs,
         )?;
+        ctx.as_context().store.inner.root_host_vals(outputs);
         Ok(())
     }
 
@@ -435,9 +516,12 @@ impl Func {
     ///
     /// # Note
     ///
-    /// This is a non-standard WebAssembly API and might not be available
-    /// at other WebAssembly engines. Please be aware that depending on this
-    /// feature might mean a lock-in to Wasmi for users.
+    /// - This is a non-standard WebAssembly API and might not be available
+    ///   at other WebAssembly engines. Please be aware that depending on this
+    ///   feature might mean a lock-in to Wasmi for users.
+    /// - Objects referred to by [`AnyRef`] results are kept alive as with [`Func::call`].
+    ///
+    /// [`AnyRef`]: crate::AnyRef
     ///
     /// # Errors
     ///
@@ -456,13 +540,14 @@ impl Func {
     ) -> Result<ResumableCall, Error> {
         self.verify_and_prepare_inputs_outputs(ctx.as_context(), inputs, outputs)?;
         // Note: Cloning an [`Engine`] is intentionally a cheap operation.
-        ctx.as_context()
+        let call = ctx
+            .as_context()
             .store
             .engine()
             .clone()
-            .execute_func_resumable(ctx.as_context_mut(), self, inputs, outputs)
-            .map_err(Into::into)
-            .map(ResumableCall::new)
+            .execute_func_resumable(ctx.as_context_mut(), self, inputs, &mut *outputs)?;
+        ctx.as_context().store.inner.root_host_vals(outputs);
+        Ok(ResumableCall::new(call))
     }
 
     /// Verify that the `inputs` and `outputs` value types match the function signature.
@@ -484,16 +569,14 @@ impl Func {
         inputs: &[Val],
         outputs: &mut [Val],
     ) -> Result<(), FuncError> {
-        let fn_type = self.ty_dedup(ctx.as_context());
-        ctx.as_context()
-            .store
-            .inner
-            .resolve_func_type_with(fn_type, |func_type| {
-                func_type.match_params(inputs)?;
-                func_type.match_results(outputs, false)?;
-                func_type.prepare_outputs(outputs);
-                Ok(())
-            })
+        let store = &ctx.as_context().store.inner;
+        let func_type = store.resolve_func_type(self.ty_dedup(ctx.as_context()));
+        func_type.match_params(inputs, |input, expected| {
+            input.matches_ty_in(store, expected)
+        })?;
+        func_type.match_results(outputs, |_, _| true)?;
+        func_type.prepare_outputs(outputs);
+        Ok(())
     }
 
     /// Creates a new [`TypedFunc`] from this [`Func`].
@@ -71,8 +71,12 @@ where
             <Params as WasmTyList>::types(),
             <Results as WasmTyList>::types(),
         );
-        func_type.match_params(actual_params.as_ref())?;
-        func_type.match_results(actual_results.as_ref(), true)?;
+        func_type.match_params(actual_params.as_ref(), |actual, expected| {
+            actual.is_subtype_of(expected)
+        })?;
+        func_type.match_results(actual_results.as_ref(), |actual, expected| {
+            expected.is_subtype_of(actual)
+        })?;
         Ok(Self {
             signature: PhantomData,
             func,
@@ -0,0 +1,116 @@
+use crate::core::UntypedVal;
+
+/// Represents a nullable reference to an internal object as introduced by the Wasm `gc` proposal.
+///
+/// An [`AnyRef`] is either `null`, an unboxed 31-bit integer (`i31ref`) or refers to
+/// a struct or array object allocated on the garbage collected heap of a [`Store`].
+///
+/// # Note
+///
+/// Objects referred to by [`AnyRef`]s handed out to the host are roots for the
+/// garbage collector until the host function call or [`Store::gc_scope`] they were
+/// handed out in returns or, otherwise, until [`Store::gc_release_host_refs`].
+/// Accessing an object that has been reclaimed results in a panic.
+///
+/// [`Store`]: crate::Store
+/// [`Store::gc_scope`]: crate::Store::gc_scope
+/// [`Store::gc_release_host_refs`]: crate::Store::gc_release_host_refs
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct AnyRef {
+    /// The raw bits of the reference.
+    ///
+    /// - `0` represents `null`.
+    /// - Odd bits represent `i31ref` values.
+    /// - Even non-zero bits represent handles to objects on the heap.
+    bits: u64,
+}
+
+impl From<UntypedVal> for AnyRef {
+    fn from(untyped: UntypedVal) -> Self {
+        Self {
+            bits: u64::from(untyped),
+        }
+    }
+}
+
+impl From<AnyRef> for UntypedVal {
+    fn from(anyref: AnyRef) -> Self {
+        UntypedVal::from(anyref.bits)
+    }
+}
+
+impl AnyRef {
+    /// Creates a new [`AnyRef`] which is `null`.
+    pub fn null() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Returns `true` if [`AnyRef`] is `null`.
+    pub fn is_null(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Creates a new `i31ref` [`AnyRef`] from the low 31 bits of `value`.
+    pub fn from_i31(value: i32) -> Self {
+        Self {
+            bits: u64::from(((value as u32) << 1) | 1),
+        }
+    }
+
+    /// Returns `true` if [`AnyRef`] is an `i31ref`.
+    pub fn is_i31(&self) -> bool {
+        self.bits & 1 == 1
+    }
+
+    /// Returns the sign-extended value of the `i31ref` if [`AnyRef`] is an `i31ref`.
+    pub fn i31_get_s(&self) -> Option<i32> {
+        if !self.is_i31() {
+            return None;
+        }
+        Some((self.bits as u32 as i32) >> 1)
+    }
+
+    /// Returns the zero-extended value of the `i31ref` if [`AnyRef`] is an `i31ref`.
+    pub fn i31_get_u(&self) -> Option<u32> {
+        if !self.is_i31() {
+            return None;
+        }
+        Some((self.bits as u32) >> 1)
+    }
+
+    /// Returns `true` if [`AnyRef`] refers to an object on the heap.
+    pub(crate) fn is_object(&self) -> bool {
+        !self.is_null() && !self.is_i31()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn i31_roundtrip() {
+        for value in [0, 1, -1, 42, -42, (1 << 30) - 1, -(1 << 30)] {
+            let anyref = AnyRef::from_i31(value);
+            assert!(anyref.is_i31());
+            assert!(!anyref.is_null());
+            assert!(!anyref.is_object());
+            assert_eq!(anyref.i31_get_s(), Some(value));
+            assert_eq!(anyref.i31_get_u(), Some((value as u32) & 0x7FFF_FFFF));
+        }
+    }
+
+    #[test]
+    fn i31_wraps() {
+        assert_eq!(AnyRef::from_i31(1 << 30).i31_get_s(), Some(-(1 << 30)));
+        assert_eq!(AnyRef::from_i31(-1).i31_get_u(), Some(0x7FFF_FFFF));
+    }
+
+    #[test]
+    fn null_to_zero() {
+        assert_eq!(UntypedVal::from(AnyRef::null()), UntypedVal::from(0_u64));
+        assert!(AnyRef::from(UntypedVal::from(0_u64)).is_null());
+        assert_eq!(AnyRef::null().i31_get_s(), None);
+    }
+}
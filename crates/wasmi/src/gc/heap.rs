@@ -0,0 +1,371 @@
+use crate::{
+    core::{TypeId, UntypedVal},
+    ExternRef,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::mem::size_of;
+
+/// The minimum number of allocated bytes that trigger a garbage collection.
+#[cfg(feature = "gc")]
+const MIN_COLLECTION_THRESHOLD: usize = 1 << 20;
+
+/// The kind of a [`GcObject`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GcObjectKind {
+    /// A struct object of the given struct type.
+    Struct(TypeId),
+    /// An array object of the given array type.
+    Array(TypeId),
+    /// An [`ExternRef`] converted via `any.convert_extern`.
+    ///
+    /// The single field of the object holds the [`ExternRef`].
+    Extern,
+}
+
+/// An object allocated on the [`GcHeap`].
+#[derive(Debug)]
+pub struct GcObject {
+    /// The kind of the object.
+    kind: GcObjectKind,
+    /// The struct fields, array elements or wrapped [`ExternRef`] of the object.
+    ///
+    /// # Note
+    ///
+    /// Packed `i8` and `i16` fields are stored unpacked and
+    /// are truncated when they are read.
+    fields: Box<[UntypedVal]>,
+}
+
+impl GcObject {
+    /// Returns the [`GcObjectKind`] of the object.
+    pub fn kind(&self) -> GcObjectKind {
+        self.kind
+    }
+
+    /// Returns the [`TypeId`] of the object if it is a struct or array.
+    pub fn type_id(&self) -> Option<TypeId> {
+        match self.kind {
+            GcObjectKind::Struct(type_id) | GcObjectKind::Array(type_id) => Some(type_id),
+            GcObjectKind::Extern => None,
+        }
+    }
+
+    /// Returns the fields of the object.
+    pub fn fields(&self) -> &[UntypedVal] {
+        &self.fields
+    }
+
+    /// Returns the fields of the object.
+    pub fn fields_mut(&mut self) -> &mut [UntypedVal] {
+        &mut self.fields
+    }
+}
+
+/// A slot of the [`GcHeap`].
+#[derive(Debug, Default)]
+struct GcSlot {
+    /// The generation of the slot.
+    ///
+    /// Incremented whenever the object of the slot is reclaimed so that
+    /// stale handles to the slot are detected.
+    generation: u32,
+    /// The object occupying the slot if any.
+    object: Option<GcObject>,
+    /// Whether the object was found to be reachable during a collection.
+    #[cfg(feature = "gc")]
+    marked: bool,
+}
+
+/// The garbage collected heap of a [`Store`] as introduced by the Wasm `gc` proposal.
+///
+/// # Handles
+///
+/// Objects are referred to via 64-bit handles that are stored in Wasm values:
+///
+/// - The lower 32 bits hold the slot index plus one, shifted left by one bit.
+///   This way handles are always even and never zero, which distinguishes them
+///   from `null` and `i31ref` values.
+/// - The upper 32 bits hold the generation of the slot mixed with the tag of the heap.
+///   This way handles of another [`Store`] are most likely detected as invalid.
+///
+/// # Collection
+///
+/// The [`GcHeap`] accounts the bytes of its live objects and requests a garbage
+/// collection once they cross a threshold. After a collection the threshold is
+/// set to twice the bytes of the surviving objects.
+///
+/// [`Store`]: crate::Store
+#[derive(Debug)]
+pub struct GcHeap {
+    /// The slots of the heap.
+    slots: Vec<GcSlot>,
+    /// The indices of unoccupied slots.
+    free: Vec<u32>,
+    /// The tag mixed into the generation bits of all handles of the heap.
+    tag: u32,
+    /// The number of bytes of all live objects.
+    bytes: usize,
+    /// The number of bytes of live objects that trigger the next collection.
+    #[cfg(feature = "gc")]
+    threshold: usize,
+}
+
+impl GcHeap {
+    /// Creates a new empty [`GcHeap`] whose handles are tagged with `tag`.
+    pub fn new(tag: u32) -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            // Note: spreads the tags of consecutive stores over all generation bits.
+            tag: tag.wrapping_mul(0x9E37_79B9),
+            bytes: 0,
+            #[cfg(feature = "gc")]
+            threshold: MIN_COLLECTION_THRESHOLD,
+        }
+    }
+
+    /// Returns the number of live objects on the [`GcHeap`].
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Returns `true` if the [`GcHeap`] has no live objects.
+    ///
+    /// # Note
+    ///
+    /// No valid handles to objects of the [`GcHeap`] exist if this returns `true`.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the live objects crossed the threshold for the next garbage collection.
+    #[cfg(feature = "gc")]
+    pub fn is_collection_due(&self) -> bool {
+        self.bytes >= self.threshold
+    }
+
+    /// Returns the number of bytes accounted for `object`.
+    fn object_bytes(object: &GcObject) -> usize {
+        size_of::<GcSlot>() + size_of::<UntypedVal>() * object.fields.len()
+    }
+
+    /// Allocates a new struct object of type `ty` with the given `fields`.
+    pub fn alloc_struct(&mut self, ty: TypeId, fields: Box<[UntypedVal]>) -> UntypedVal {
+        self.alloc(GcObjectKind::Struct(ty), fields)
+    }
+
+    /// Allocates a new array object of type `ty` with the given `elements`.
+    pub fn alloc_array(&mut self, ty: TypeId, elements: Box<[UntypedVal]>) -> UntypedVal {
+        self.alloc(GcObjectKind::Array(ty), elements)
+    }
+
+    /// Allocates a new object wrapping the non-`null` `externref`.
+    pub fn alloc_extern(&mut self, externref: ExternRef) -> UntypedVal {
+        self.alloc(GcObjectKind::Extern, [UntypedVal::from(externref)].into())
+    }
+
+    /// Allocates a new object and returns its handle.
+    fn alloc(&mut self, kind: GcObjectKind, fields: Box<[UntypedVal]>) -> UntypedVal {
+        let object = GcObject { kind, fields };
+        self.bytes += Self::object_bytes(&object);
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                let index = u32::try_from(self.slots.len())
+                    .ok()
+                    .filter(|index| *index < (u32::MAX >> 1) - 1)
+                    .unwrap_or_else(|| panic!("out of object slots in GC heap"));
+                self.slots.push(GcSlot::default());
+                index
+            }
+        };
+        let slot = &mut self.slots[index as usize];
+        slot.object = Some(object);
+        let generation = slot.generation;
+        self.handle(index, generation)
+    }
+
+    /// Returns the handle for the object at `index` with `generation`.
+    fn handle(&self, index: u32, generation: u32) -> UntypedVal {
+        let low = (index + 1) << 1;
+        let high = generation ^ self.tag;
+        UntypedVal::from((u64::from(high) << 32) | u64::from(low))
+    }
+
+    /// Returns `true` if `handle` refers to a live object of the [`GcHeap`].
+    pub fn contains(&self, handle: UntypedVal) -> bool {
+        self.try_slot_index(handle).is_some()
+    }
+
+    /// Returns the slot index of the object referred to by `handle` if any.
+    fn try_slot_index(&self, handle: UntypedVal) -> Option<usize> {
+        let bits = u64::from(handle);
+        let low = bits as u32;
+        let generation = (bits >> 32) as u32 ^ self.tag;
+        (low >> 1)
+            .checked_sub(1)
+            .filter(|_| low & 1 == 0)
+            .map(|index| index as usize)
+            .filter(|index| {
+                self.slots
+                    .get(*index)
+                    .is_some_and(|slot| slot.generation == generation && slot.object.is_some())
+            })
+    }
+
+    /// Returns the slot index of the object referred to by `handle`.
+    ///
+    /// # Panics
+    ///
+    /// If `handle` does not refer to a live object of the [`GcHeap`].
+    fn slot_index(&self, handle: UntypedVal) -> usize {
+        self.try_slot_index(handle).unwrap_or_else(|| {
+            let bits = u64::from(handle);
+            panic!("encountered invalid GC object handle: {bits:#X}")
+        })
+    }
+
+    /// Returns a shared reference to the object referred to by `handle`.
+    ///
+    /// # Panics
+    ///
+    /// If `handle` does not refer to a live object of the [`GcHeap`].
+    pub fn resolve(&self, handle: UntypedVal) -> &GcObject {
+        let index = self.slot_index(handle);
+        match &self.slots[index].object {
+            Some(object) => object,
+            None => unreachable!("checked that the object is alive"),
+        }
+    }
+
+    /// Returns an exclusive reference to the object referred to by `handle`.
+    ///
+    /// # Panics
+    ///
+    /// If `handle` does not refer to a live object of the [`GcHeap`].
+    pub fn resolve_mut(&mut self, handle: UntypedVal) -> &mut GcObject {
+        let index = self.slot_index(handle);
+        match &mut self.slots[index].object {
+            Some(object) => object,
+            None => unreachable!("checked that the object is alive"),
+        }
+    }
+
+    /// Marks the object referred to by `handle` as reachable.
+    ///
+    /// Returns `true` if the object has not been marked before.
+    ///
+    /// # Panics
+    ///
+    /// If `handle` does not refer to a live object of the [`GcHeap`].
+    #[cfg(feature = "gc")]
+    pub fn mark(&mut self, handle: UntypedVal) -> bool {
+        let index = self.slot_index(handle);
+        let slot = &mut self.slots[index];
+        !core::mem::replace(&mut slot.marked, true)
+    }
+
+    /// Reclaims all objects that have not been marked and resets all marks.
+    ///
+    /// Returns the number of reclaimed objects.
+    ///
+    /// # Note
+    ///
+    /// Sets the threshold for the next collection to twice the bytes of the surviving objects.
+    #[cfg(feature = "gc")]
+    pub fn sweep(&mut self) -> usize {
+        let mut reclaimed = 0;
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if core::mem::take(&mut slot.marked) {
+                continue;
+            }
+            let Some(object) = slot.object.take() else {
+                continue;
+            };
+            self.bytes -= Self::object_bytes(&object);
+            slot.generation = slot.generation.wrapping_add(1);
+            self.free.push(index as u32);
+            reclaimed += 1;
+        }
+        self.threshold = self.bytes.saturating_mul(2).max(MIN_COLLECTION_THRESHOLD);
+        reclaimed
+    }
+}
+
+#[cfg(all(test, feature = "gc"))]
+mod tests {
+    use super::*;
+    use crate::core::TypeKind;
+    use alloc::vec;
+
+    fn struct_type() -> TypeId {
+        TypeId::new(TypeKind::Struct, 0)
+    }
+
+    fn array_type() -> TypeId {
+        TypeId::new(TypeKind::Array, 0)
+    }
+
+    #[test]
+    fn handles_are_even_and_non_null() {
+        let mut heap = GcHeap::new(0);
+        for _ in 0..10 {
+            let handle = u64::from(heap.alloc_struct(struct_type(), [].into()));
+            assert_ne!(handle, 0);
+            assert_eq!(handle & 1, 0);
+        }
+        assert_eq!(heap.len(), 10);
+    }
+
+    #[test]
+    fn sweep_reclaims_unmarked() {
+        let mut heap = GcHeap::new(0);
+        let a = heap.alloc_struct(struct_type(), [UntypedVal::from(1_i32)].into());
+        let b = heap.alloc_struct(struct_type(), [UntypedVal::from(2_i32)].into());
+        assert!(heap.mark(a));
+        assert!(!heap.mark(a));
+        assert_eq!(heap.sweep(), 1);
+        assert_eq!(heap.len(), 1);
+        assert_eq!(heap.resolve(a).fields(), &[UntypedVal::from(1_i32)]);
+        // The slot of `b` is reused with a new generation.
+        let c = heap.alloc_struct(struct_type(), [].into());
+        assert_ne!(u64::from(b), u64::from(c));
+        assert_eq!(u64::from(b) as u32, u64::from(c) as u32);
+        // Unmarked objects are reclaimed by the next sweep.
+        assert_eq!(heap.sweep(), 2);
+        assert_eq!(heap.len(), 0);
+    }
+
+    #[test]
+    fn collection_due_after_threshold() {
+        let mut heap = GcHeap::new(0);
+        let fields = || vec![UntypedVal::default(); 1024].into_boxed_slice();
+        let live = heap.alloc_array(array_type(), fields());
+        while !heap.is_collection_due() {
+            heap.alloc_array(array_type(), fields());
+        }
+        heap.mark(live);
+        heap.sweep();
+        assert_eq!(heap.len(), 1);
+        assert!(!heap.is_collection_due());
+    }
+
+    #[test]
+    fn handles_of_other_heaps_are_invalid() {
+        let mut a = GcHeap::new(0);
+        let mut b = GcHeap::new(1);
+        let handle = a.alloc_struct(struct_type(), [].into());
+        b.alloc_struct(struct_type(), [].into());
+        assert!(a.contains(handle));
+        assert!(!b.contains(handle));
+    }
+
+    #[test]
+    #[should_panic]
+    fn stale_handle_panics() {
+        let mut heap = GcHeap::new(0);
+        let a = heap.alloc_struct(struct_type(), [].into());
+        heap.sweep();
+        heap.resolve(a);
+    }
+}
@@ -0,0 +1,19 @@
+mod anyref;
+mod heap;
+mod roots;
+mod ty;
+
+pub use self::{
+    anyref::AnyRef,
+    ty::{ArrayType, FieldType, StorageType, StructType},
+};
+pub(crate) use self::{
+    heap::{GcHeap, GcObject, GcObjectKind},
+    roots::{GcRoots, HostScope},
+};
+
+/// The host object of an [`ExternRef`] that was created via Wasm `extern.convert_any`.
+///
+/// [`ExternRef`]: crate::ExternRef
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ExternalizedAny(pub AnyRef);
@@ -0,0 +1,140 @@
+use crate::core::UntypedVal;
+use alloc::{
+    boxed::Box,
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+use spin::Mutex;
+
+/// The roots of the garbage collector that are not owned by the entities of a [`Store`].
+///
+/// # Note
+///
+/// All roots are object handles that might have been reclaimed or might not even be handles
+/// and thus must be checked against the [`GcHeap`] before they are traced.
+///
+/// [`Store`]: crate::Store
+/// [`GcHeap`]: super::GcHeap
+#[derive(Debug, Default)]
+pub struct GcRoots {
+    /// Object handles held by the host or by executions suspended in host function calls.
+    host: Mutex<HostRoots>,
+    /// Object handles of the stacks of suspended resumable calls.
+    ///
+    /// Released once the token of the associated resumable call is dropped.
+    resumables: Vec<(Weak<()>, Box<[UntypedVal]>)>,
+}
+
+/// The object handles held by the host.
+///
+/// # Note
+///
+/// - Handles pushed within a host scope are released once the scope is exited.
+/// - Duplicate handles of the innermost scope are removed whenever the number of handles
+///   doubled since so that repeatedly handing out the same objects does not grow the roots.
+#[derive(Debug, Default)]
+struct HostRoots {
+    /// The rooted object handles.
+    handles: Vec<UntypedVal>,
+    /// The index of the first handle of the innermost host scope.
+    scope: usize,
+    /// The number of handles upon which duplicates are removed.
+    compact_at: usize,
+}
+
+/// The scope of a host function call or a host-defined scope entered via [`GcRoots::enter_host_scope`].
+#[derive(Debug)]
+pub struct HostScope {
+    /// The number of handles before the scope was entered.
+    len: usize,
+    /// The index of the first handle of the enclosing scope.
+    outer: usize,
+}
+
+impl HostRoots {
+    /// The minimum number of handles before duplicates are removed.
+    const MIN_COMPACT_AT: usize = 64;
+
+    /// Pushes the object `handles` to the innermost scope.
+    fn extend(&mut self, handles: impl IntoIterator<Item = UntypedVal>) {
+        self.handles.extend(handles);
+        if self.handles.len() >= self.compact_at.max(Self::MIN_COMPACT_AT) {
+            self.compact();
+        }
+    }
+
+    /// Removes duplicate handles of the innermost scope.
+    fn compact(&mut self) {
+        let mut scoped = self.handles.split_off(self.scope);
+        scoped.sort_unstable();
+        scoped.dedup();
+        self.handles.extend(scoped);
+        self.compact_at = self.handles.len() * 2;
+    }
+}
+
+impl GcRoots {
+    /// Roots the object `handles` held by the host.
+    pub fn root_host(&self, handles: impl IntoIterator<Item = UntypedVal>) {
+        self.host.lock().extend(handles);
+    }
+
+    /// Enters the scope of a host function call rooting the object `handles` of its caller.
+    ///
+    /// Returns the scope that must be passed to [`GcRoots::exit_host_scope`] once the call returns.
+    pub fn enter_host_scope(&mut self, handles: impl IntoIterator<Item = UntypedVal>) -> HostScope {
+        let host = self.host.get_mut();
+        let scope = HostScope {
+            len: host.handles.len(),
+            outer: host.scope,
+        };
+        host.scope = scope.len;
+        host.extend(handles);
+        scope
+    }
+
+    /// Exits the `scope` of a host function call releasing all object handles rooted within it.
+    pub fn exit_host_scope(&mut self, scope: HostScope) {
+        let host = self.host.get_mut();
+        host.handles.truncate(scope.len);
+        host.scope = scope.outer;
+    }
+
+    /// Releases all object handles held by the host.
+    #[cfg(feature = "gc")]
+    pub fn release_host(&mut self) {
+        *self.host.get_mut() = HostRoots::default();
+    }
+
+    /// Roots the object `handles` of the stack of the resumable call identified by `token`.
+    ///
+    /// Replaces the handles previously rooted for the same resumable call.
+    pub fn suspend(&mut self, token: &Arc<()>, handles: Box<[UntypedVal]>) {
+        let token = Arc::downgrade(token);
+        match self
+            .resumables
+            .iter_mut()
+            .find(|(other, _)| Weak::ptr_eq(other, &token))
+        {
+            Some((_, rooted)) => *rooted = handles,
+            None => self.resumables.push((token, handles)),
+        }
+    }
+
+    /// Returns an iterator over all roots after releasing those of dropped resumable calls.
+    #[cfg(feature = "gc")]
+    pub fn iter(&mut self) -> impl Iterator<Item = UntypedVal> + '_ {
+        self.resumables
+            .retain(|(token, _)| Weak::strong_count(token) != 0);
+        let resumables = self
+            .resumables
+            .iter()
+            .flat_map(|(_, handles)| handles.iter().copied());
+        self.host
+            .get_mut()
+            .handles
+            .iter()
+            .copied()
+            .chain(resumables)
+    }
+}
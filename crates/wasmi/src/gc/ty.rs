@@ -0,0 +1,119 @@
+use crate::{core::ValType, Mutability};
+use alloc::sync::Arc;
+
+/// The storage type of a struct field or an array element.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StorageType {
+    /// A packed 8-bit integer.
+    I8,
+    /// A packed 16-bit integer.
+    I16,
+    /// An unpacked value of the given [`ValType`].
+    Val(ValType),
+}
+
+impl StorageType {
+    /// Returns `true` if the [`StorageType`] is a packed integer type.
+    pub fn is_packed(&self) -> bool {
+        matches!(self, Self::I8 | Self::I16)
+    }
+
+    /// Returns the [`ValType`] of values read from or written to this [`StorageType`].
+    ///
+    /// Packed integer types are unpacked to [`ValType::I32`].
+    pub fn unpacked(&self) -> ValType {
+        match self {
+            Self::I8 | Self::I16 => ValType::I32,
+            Self::Val(ty) => *ty,
+        }
+    }
+
+    /// Returns the number of bytes of the [`StorageType`] if it is numeric.
+    ///
+    /// Returns `None` for reference types.
+    pub(crate) fn num_bytes(&self) -> Option<usize> {
+        match self {
+            Self::I8 => Some(1),
+            Self::I16 => Some(2),
+            Self::Val(ValType::I32 | ValType::F32) => Some(4),
+            Self::Val(ValType::I64 | ValType::F64) => Some(8),
+            Self::Val(_) => None,
+        }
+    }
+}
+
+/// The type of a struct field or of array elements.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldType {
+    /// The storage type of the field.
+    storage: StorageType,
+    /// The mutability of the field.
+    mutability: Mutability,
+}
+
+impl FieldType {
+    /// Creates a new [`FieldType`].
+    pub fn new(storage: StorageType, mutability: Mutability) -> Self {
+        Self {
+            storage,
+            mutability,
+        }
+    }
+
+    /// Returns the [`StorageType`] of the [`FieldType`].
+    pub fn storage(&self) -> StorageType {
+        self.storage
+    }
+
+    /// Returns the [`Mutability`] of the [`FieldType`].
+    pub fn mutability(&self) -> Mutability {
+        self.mutability
+    }
+}
+
+/// A struct type as introduced by the Wasm `gc` proposal.
+///
+/// # Note
+///
+/// Can be cloned cheaply.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StructType {
+    /// The types of the struct fields.
+    fields: Arc<[FieldType]>,
+}
+
+impl StructType {
+    /// Creates a new [`StructType`] with the given `fields`.
+    pub fn new<I>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = FieldType>,
+    {
+        Self {
+            fields: fields.into_iter().collect(),
+        }
+    }
+
+    /// Returns the types of the struct fields.
+    pub fn fields(&self) -> &[FieldType] {
+        &self.fields
+    }
+}
+
+/// An array type as introduced by the Wasm `gc` proposal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrayType {
+    /// The type of the array elements.
+    element: FieldType,
+}
+
+impl ArrayType {
+    /// Creates a new [`ArrayType`] with elements of type `element`.
+    pub fn new(element: FieldType) -> Self {
+        Self { element }
+    }
+
+    /// Returns the type of the array elements.
+    pub fn element(&self) -> FieldType {
+        self.element
+    }
+}
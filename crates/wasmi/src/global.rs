@@ -5,7 +5,7 @@ use crate::{
     value::WithType,
     Val,
 };
-use core::{fmt, fmt::Display, ptr::NonNull};
+use core::{fmt, fmt::Display, ptr::NonNull, slice};
 
 /// A raw index to a global variable entity.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -78,7 +78,7 @@ impl Display for GlobalError {
 }
 
 /// The mutability of a global variable.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Mutability {
     /// The value of the global variable is a constant.
     Const,
@@ -128,12 +128,24 @@ impl GlobalType {
 
     /// Checks if `self` satisfies the given `GlobalType`.
     ///
+    /// # Note
+    ///
+    /// Immutable global variables may be of a subtype of the `required` content type
+    /// whereas mutable global variables must have exactly the `required` content type.
+    ///
     /// # Errors
     ///
-    /// - If the initial limits of the `required` [`GlobalType`] are greater than `self`.
-    /// - If the maximum limits of the `required` [`GlobalType`] are greater than `self`.
+    /// - If the [`Mutability`] of `self` and `required` differ.
+    /// - If the content type of `self` does not satisfy the `required` content type.
     pub(crate) fn satisfies(&self, required: &GlobalType) -> Result<(), GlobalError> {
-        if self != required {
+        let satisfies = match (self.mutability(), required.mutability()) {
+            (Mutability::Const, Mutability::Const) => {
+                self.content().is_subtype_of(&required.content())
+            }
+            (Mutability::Var, Mutability::Var) => self.content() == required.content(),
+            _ => false,
+        };
+        if !satisfies {
             return Err(GlobalError::UnsatisfyingGlobalType {
                 unsatisfying: *self,
                 required: *required,
@@ -161,6 +173,18 @@ impl GlobalEntity {
         }
     }
 
+    /// Creates a new global entity of type `ty` with the given initial value.
+    ///
+    /// # Note
+    ///
+    /// The caller is responsible to check that `initial_value` matches `ty`.
+    pub fn new_typed(ty: GlobalType, initial_value: Val) -> Self {
+        Self {
+            ty,
+            value: initial_value.into(),
+        }
+    }
+
     /// Returns the [`GlobalType`] of the global variable.
     pub fn ty(&self) -> GlobalType {
         self.ty
@@ -176,7 +200,7 @@ impl GlobalEntity {
         if !self.ty().mutability().is_mut() {
             return Err(GlobalError::ImmutableWrite);
         }
-        if self.ty().content() != new_value.ty() {
+        if !new_value.matches_ty_erased(&self.ty().content()) {
             return Err(GlobalError::TypeMismatch {
                 expected: self.ty().content(),
                 encountered: new_value.ty(),
@@ -232,7 +256,7 @@ impl Global {
     }
 
     /// Returns the underlying stored representation.
-    pub(super) fn as_inner(&self) -> &Stored<GlobalIdx> {
+    pub(crate) fn as_inner(&self) -> &Stored<GlobalIdx> {
         &self.0
     }
 
@@ -244,6 +268,31 @@ impl Global {
             .alloc_global(GlobalEntity::new(initial_value, mutability))
     }
 
+    /// Creates a new global variable of type `ty` to the store.
+    ///
+    /// # Note
+    ///
+    /// In contrast to [`Global::new`] this allows to create global variables
+    /// of typed reference types such as non-nullable function references.
+    ///
+    /// # Errors
+    ///
+    /// If `initial_value` does not match the content type of `ty`.
+    pub fn new_typed(
+        mut ctx: impl AsContextMut,
+        ty: GlobalType,
+        initial_value: Val,
+    ) -> Result<Self, GlobalError> {
+        let inner = &mut ctx.as_context_mut().store.inner;
+        if !initial_value.matches_ty_in(inner, &ty.content()) {
+            return Err(GlobalError::TypeMismatch {
+                expected: ty.content(),
+                encountered: initial_value.ty(),
+            });
+        }
+        Ok(inner.alloc_global(GlobalEntity::new_typed(ty, initial_value)))
+    }
+
     /// Returns the [`GlobalType`] of the global variable.
     pub fn ty(&self, ctx: impl AsContext) -> GlobalType {
         ctx.as_context().store.inner.resolve_global(self).ty()
@@ -260,11 +309,15 @@ impl Global {
     ///
     /// Panics if `ctx` does not own this [`Global`].
     pub fn set(&self, mut ctx: impl AsContextMut, new_value: Val) -> Result<(), GlobalError> {
-        ctx.as_context_mut()
-            .store
-            .inner
-            .resolve_global_mut(self)
-            .set(new_value)
+        let inner = &mut ctx.as_context_mut().store.inner;
+        let content = inner.resolve_global(self).ty().content();
+        if !new_value.matches_ty_in(inner, &content) {
+            return Err(GlobalError::TypeMismatch {
+                expected: content,
+                encountered: new_value.ty(),
+            });
+        }
+        inner.resolve_global_mut(self).set(new_value)
     }
 
     /// Returns the current value of the global variable.
@@ -273,6 +326,9 @@ impl Global {
     ///
     /// Panics if `ctx` does not own this [`Global`].
     pub fn get(&self, ctx: impl AsContext) -> Val {
-        ctx.as_context().store.inner.resolve_global(self).get()
+        let store = &ctx.as_context().store.inner;
+        let value = store.resolve_global(self).get();
+        store.root_host_vals(slice::from_ref(&value));
+        value
     }
 }
@@ -51,6 +51,7 @@ impl ArenaIndex for InstanceIdx {
 #[derive(Debug)]
 pub struct InstanceEntity {
     initialized: bool,
+    module: Option<Module>,
     func_types: Arc<[DedupFuncType]>,
     tables: Box<[Table]>,
     funcs: Box<[Func]>,
@@ -66,6 +67,7 @@ impl InstanceEntity {
     pub fn uninitialized() -> InstanceEntity {
         Self {
             initialized: false,
+            module: None,
             func_types: Arc::new([]),
             tables: [].into(),
             funcs: [].into(),
@@ -102,6 +104,21 @@ impl InstanceEntity {
         self.globals.get(index as usize).copied()
     }
 
+    /// Returns the linear memories of the [`InstanceEntity`] in index order.
+    pub fn memories(&self) -> &[Memory] {
+        &self.memories
+    }
+
+    /// Returns the global variables of the [`InstanceEntity`] in index order.
+    pub fn globals(&self) -> &[Global] {
+        &self.globals
+    }
+
+    /// Returns the [`Module`] from which the [`InstanceEntity`] has been instantiated if any.
+    pub fn module(&self) -> Option<&Module> {
+        self.module.as_ref()
+    }
+
     /// Returns the function at the `index` if any.
     pub fn get_func(&self, index: u32) -> Option<Func> {
         self.funcs.get(index as usize).copied()
@@ -130,7 +147,7 @@ impl InstanceEntity {
     /// Returns an iterator over the exports of the [`Instance`].
     ///
     /// The order of the yielded exports is not specified.
-    pub fn exports(&self) -> ExportsIter {
+    pub fn exports(&self) -> ExportsIter<'_> {
         ExportsIter::new(self.exports.iter())
     }
 }
@@ -86,10 +86,14 @@ extern crate std;
 #[macro_use]
 mod foreach_tuple;
 
+mod backtrace;
+mod cont;
+mod coredump;
 mod engine;
 mod error;
 mod externref;
 mod func;
+mod gc;
 mod global;
 mod instance;
 mod limits;
@@ -104,6 +108,9 @@ mod value;
 #[doc(inline)]
 pub use wasmi_core as core;
 
+#[cfg(feature = "macros")]
+pub use wasmi_macros::{host_module, WasmTyList};
+
 /// Definitions from the `wasmi_collections` crate.
 #[doc(inline)]
 use wasmi_collections as collections;
@@ -129,12 +136,17 @@ pub mod errors {
 }
 
 pub use self::{
+    backtrace::{FrameInfo, FrameSymbol, WasmBacktrace},
+    cont::ContRef,
+    coredump::{CoreDumpFrame, WasmCoreDump},
     engine::{
         CompilationMode,
         Config,
         EnforcedLimits,
         Engine,
         EngineWeak,
+        FuncInOut,
+        OptimizationLevel,
         ResumableCall,
         ResumableInvocation,
         StackLimits,
@@ -148,6 +160,7 @@ pub use self::{
         Func,
         FuncRef,
         FuncType,
+        HostFuncFuel,
         IntoFunc,
         TypedFunc,
         WasmParams,
@@ -156,15 +169,17 @@ pub use self::{
         WasmTy,
         WasmTyList,
     },
+    gc::{AnyRef, ArrayType, FieldType, StorageType, StructType},
     global::{Global, GlobalType, Mutability},
     instance::{Export, ExportsIter, Extern, ExternType, Instance},
     limits::{ResourceLimiter, StoreLimits, StoreLimitsBuilder},
     linker::{state, Linker, LinkerBuilder},
-    memory::{Memory, MemoryType},
+    memory::{Memory, MemoryType, MemoryValue, WasmPtr, WasmSlice, WasmStr},
     module::{
         CustomSection,
         CustomSectionsIter,
         ExportType,
+        ImportName,
         ImportType,
         InstancePre,
         Module,
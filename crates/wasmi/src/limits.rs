@@ -35,7 +35,10 @@ pub trait ResourceLimiter {
     ///   indicates that the linear memory is unbounded.
     ///
     /// The `current` and `desired` amounts are guaranteed to always be
-    /// multiples of the WebAssembly page size, 64KiB.
+    /// multiples of the page size of the linear memory's [`MemoryType`].
+    /// This is 64KiB by default or 1 byte with the Wasm `custom-page-sizes` proposal.
+    ///
+    /// [`MemoryType`]: crate::MemoryType
     ///
     /// ## Return Value
     ///
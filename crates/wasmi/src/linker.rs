@@ -3,7 +3,7 @@ use crate::{
         string_interner::{InternHint, Sym as Symbol},
         StringInterner,
     },
-    func::{FuncEntity, HostFuncEntity, HostFuncTrampolineEntity},
+    func::{FuncEntity, FuncError, HostFuncEntity, HostFuncFuel, HostFuncTrampolineEntity},
     module::{ImportName, ImportType},
     AsContext,
     AsContextMut,
@@ -13,18 +13,23 @@ use crate::{
     Extern,
     ExternType,
     Func,
+    FuncInOut,
     FuncType,
+    Global,
     GlobalType,
     Instance,
     InstancePre,
     IntoFunc,
+    Memory,
     MemoryType,
     Module,
+    Table,
     TableType,
     Val,
 };
 use alloc::{
     collections::{btree_map::Entry, BTreeMap},
+    format,
     sync::Arc,
     vec::Vec,
 };
@@ -93,9 +98,21 @@ pub enum LinkerError {
         /// The mismatching [`GlobalType`] found.
         found: GlobalType,
     },
+    /// Encountered when fuel is attached to a name without [`Linker`] host function definition.
+    MissingHostFuncDefinition {
+        /// The name for which no [`Linker`] host function definition was found.
+        name: ImportName,
+    },
 }
 
 impl LinkerError {
+    /// Creates a new [`LinkerError`] for when no [`Linker`] host function is defined for `module` and `name`.
+    fn missing_host_func_definition(module: &str, name: &str) -> Self {
+        Self::MissingHostFuncDefinition {
+            name: ImportName::new(module, name),
+        }
+    }
+
     /// Creates a new [`LinkerError`] for when an imported definition was not found.
     fn missing_definition(import: &ImportType) -> Self {
         Self::MissingDefinition {
@@ -209,6 +226,12 @@ impl Display for LinkerError {
                     expected {expected:?} but found {found:?}",
                 )
             }
+            Self::MissingHostFuncDefinition { name } => {
+                write!(
+                    f,
+                    "cannot attach fuel to {name}: no linker host function definition found",
+                )
+            }
         }
     }
 }
@@ -282,6 +305,16 @@ impl<T> Definition<T> {
         }
     }
 
+    /// Returns the [`HostFuncTrampolineEntity`] if this [`Definition`] is [`Definition::HostFunc`].
+    ///
+    /// Otherwise returns `None`.
+    fn as_host_func_mut(&mut self) -> Option<&mut HostFuncTrampolineEntity<T>> {
+        match self {
+            Definition::HostFunc(host_func) => Some(host_func),
+            Definition::Extern(_) => None,
+        }
+    }
+
     /// Returns the [`ExternType`] of the [`Definition`].
     pub fn ty(&self, ctx: impl AsContext) -> ExternType {
         match self {
@@ -443,6 +476,31 @@ impl<T> Linker<T> {
         Ok(self)
     }
 
+    /// Creates a new named [`Func::new_raw`]-style host [`Func`] for this [`Linker`].
+    ///
+    /// The `func` closure is handed the untyped parameters of the call via [`FuncInOut::params`]
+    /// and must write all results matching the result types of `ty` via [`FuncInOut::results_mut`].
+    /// Since parameters and results share the same buffer writing results overwrites parameters.
+    ///
+    /// For more information see [`Func::new_raw`].
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    pub fn func_new_raw(
+        &mut self,
+        module: &str,
+        name: &str,
+        ty: FuncType,
+        func: impl Fn(Caller<'_, T>, FuncInOut<'_>) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Result<&mut Self, LinkerError> {
+        self.ensure_undefined(module, name)?;
+        let func = HostFuncTrampolineEntity::new_raw(ty, func);
+        let key = self.inner.new_import_key(module, name);
+        self.inner.insert(key, Definition::HostFunc(func))?;
+        Ok(self)
+    }
+
     /// Creates a new named [`Func::new`]-style host [`Func`] for this [`Linker`].
     ///
     /// For information how to use this API see [`Func::wrap`].
@@ -477,6 +535,47 @@ impl<T> Linker<T> {
         Ok(self)
     }
 
+    /// Attaches `fuel` to the [`Linker`] host function defined under `module` and `name`.
+    ///
+    /// The fuel is charged whenever Wasm calls the host function, before the host
+    /// function is executed. Execution traps with [`TrapCode::OutOfFuel`] if there
+    /// is not enough fuel left. Replaces previously attached fuel for the same function.
+    ///
+    /// # Note
+    ///
+    /// - Only affects instances created after this call.
+    /// - This has no effect if fuel metering is disabled for the [`Engine`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no host function defined in this [`Linker`] under `module` and `name`.
+    /// This includes host functions that have been defined by a [`LinkerBuilder`].
+    ///
+    /// [`TrapCode::OutOfFuel`]: crate::core::TrapCode::OutOfFuel
+    pub fn func_fuel(
+        &mut self,
+        module: &str,
+        name: &str,
+        fuel: impl Into<HostFuncFuel>,
+    ) -> Result<&mut Self, LinkerError> {
+        self.inner.func_fuel(module, name, fuel.into())?;
+        Ok(self)
+    }
+
+    /// Returns `true` if this [`Linker`] has a definition for `name` in `module`.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Linker::get`] this also takes [`Linker`] defined host functions into account.
+    pub fn has_definition(&self, module: &str, name: &str) -> bool {
+        if let Some(shared) = &self.shared {
+            if shared.has_definition(module, name) {
+                return true;
+            }
+        }
+        self.inner.has_definition(module, name)
+    }
+
     /// Looks up a defined [`Extern`] by name in this [`Linker`].
     ///
     /// - Returns `None` if this name was not previously defined in this [`Linker`].
@@ -569,6 +668,135 @@ impl<T> Linker<T> {
         self.inner.alias_module(module, as_module)
     }
 
+    /// Defines all function imports of `module` without definition in this [`Linker`] as trapping host functions.
+    ///
+    /// Calling any of the defined host functions traps with an error naming its import.
+    /// This allows to instantiate Wasm modules with imports that are never called.
+    ///
+    /// Returns the names of all imports that have been defined.
+    ///
+    /// # Note
+    ///
+    /// Imports of tables, memories and globals are left undefined.
+    ///
+    /// # Errors
+    ///
+    /// If any of the host functions cannot be defined in this [`Linker`].
+    pub fn define_unknown_imports_as_traps(
+        &mut self,
+        module: &Module,
+    ) -> Result<Vec<ImportName>, LinkerError> {
+        let mut defined = Vec::new();
+        for import in module.imports() {
+            let ExternType::Func(func_type) = import.ty() else {
+                continue;
+            };
+            if self.has_definition(import.module(), import.name()) {
+                continue;
+            }
+            let import_name = import.import_name().clone();
+            let message = format!("called unknown import `{import_name}` that is defined as trap");
+            self.func_new(
+                import.module(),
+                import.name(),
+                func_type.clone(),
+                move |_, _, _| Err(Error::new(message.clone())),
+            )?;
+            defined.push(import_name);
+        }
+        Ok(defined)
+    }
+
+    /// Defines all imports of `module` without definition in this [`Linker`] with default values.
+    ///
+    /// - Functions return the default values of their result types when called.
+    /// - Tables, memories and globals are created in `store` from their import types
+    ///   with minimum sizes and default values.
+    ///
+    /// Returns the names of all imports that have been defined.
+    ///
+    /// # Note
+    ///
+    /// All imports are validated and created before any of them is defined so that
+    /// this [`Linker`] is left unchanged upon errors.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and `store` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// - If any of the imports cannot be defined in this [`Linker`].
+    /// - If any table, memory or global cannot be created in `store`.
+    /// - If the default value does not match the type of a table, global or function result,
+    ///   e.g. for non-nullable reference types.
+    pub fn define_unknown_imports_as_default_values(
+        &mut self,
+        mut store: impl AsContextMut<Data = T>,
+        module: &Module,
+    ) -> Result<Vec<ImportName>, Error> {
+        assert!(Engine::same(self.engine(), store.as_context().engine()));
+        // Note: function imports are defined as host functions of the `Linker`
+        //       and thus have no definition created in `store`.
+        let mut unknown: Vec<(ImportName, ExternType, Option<Extern>)> = Vec::new();
+        for import in module.imports() {
+            let (module_name, field_name) = (import.module(), import.name());
+            let is_duplicate = unknown
+                .iter()
+                .any(|(name, _, _)| name.module() == module_name && name.name() == field_name);
+            if is_duplicate || self.has_definition(module_name, field_name) {
+                continue;
+            }
+            let definition = match import.ty() {
+                ExternType::Func(func_type) => {
+                    let has_default_results = func_type
+                        .results()
+                        .iter()
+                        .all(|ty| Val::default(*ty).matches_ty_erased(ty));
+                    if !has_default_results {
+                        return Err(Error::from(FuncError::MismatchingResultType));
+                    }
+                    None
+                }
+                ExternType::Table(ty) => {
+                    let init = Val::default(ty.element());
+                    Some(Extern::from(Table::new(&mut store, *ty, init)?))
+                }
+                ExternType::Memory(ty) => Some(Extern::from(Memory::new(&mut store, *ty)?)),
+                ExternType::Global(ty) => {
+                    let init = Val::default(ty.content());
+                    Some(Extern::from(Global::new_typed(&mut store, *ty, init)?))
+                }
+            };
+            unknown.push((
+                import.import_name().clone(),
+                import.ty().clone(),
+                definition,
+            ));
+        }
+        for (import, ty, definition) in &unknown {
+            let (module_name, field_name) = (import.module(), import.name());
+            match (ty, definition) {
+                (_, Some(definition)) => {
+                    self.define(module_name, field_name, *definition)?;
+                }
+                (ExternType::Func(func_type), None) => {
+                    self.func_new(module_name, field_name, func_type.clone(), {
+                        let func_type = func_type.clone();
+                        move |_, _, results| {
+                            for (result, ty) in results.iter_mut().zip(func_type.results()) {
+                                *result = Val::default(*ty);
+                            }
+                            Ok(())
+                        }
+                    })?;
+                }
+                (ty, None) => unreachable!("missing definition for {import} of type {ty:?}"),
+            }
+        }
+        Ok(unknown.into_iter().map(|(import, _, _)| import).collect())
+    }
+
     /// Instantiates the given [`Module`] using the definitions in the [`Linker`].
     ///
     /// # Panics
@@ -666,7 +894,7 @@ impl<T> Linker<T> {
                     .and_then(Extern::into_global)
                     .ok_or_else(invalid_type)?;
                 let found_type = global.ty(context);
-                if &found_type != expected_type {
+                if found_type.satisfies(expected_type).is_err() {
                     return Err(Error::from(LinkerError::global_type_mismatch(
                         import_name,
                         expected_type,
@@ -766,6 +994,28 @@ impl<T> LinkerBuilder<state::Constructing, T> {
         Ok(self)
     }
 
+    /// Creates a new named [`Func::new_raw`]-style host [`Func`] for this [`Linker`].
+    ///
+    /// For more information see [`Linker::func_new_raw`].
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`LinkerBuilder`] has already created a [`Linker`] using [`LinkerBuilder::finish`].
+    pub fn func_new_raw(
+        &mut self,
+        module: &str,
+        name: &str,
+        ty: FuncType,
+        func: impl Fn(Caller<'_, T>, FuncInOut<'_>) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Result<&mut Self, LinkerError> {
+        self.inner_mut().func_new_raw(module, name, ty, func)?;
+        Ok(self)
+    }
+
     /// Creates a new named [`Func::new`]-style host [`Func`] for this [`Linker`].
     ///
     /// For information how to use this API see [`Func::wrap`].
@@ -800,6 +1050,27 @@ impl<T> LinkerBuilder<state::Constructing, T> {
         self.inner_mut().func_wrap(module, name, func)?;
         Ok(self)
     }
+
+    /// Attaches `fuel` to the host function defined under `module` and `name`.
+    ///
+    /// For more information see [`Linker::func_fuel`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no host function defined under `module` and `name`.
+    ///
+    /// # Panics
+    ///
+    /// If the [`LinkerBuilder`] has already created a [`Linker`] using [`LinkerBuilder::finish`].
+    pub fn func_fuel(
+        &mut self,
+        module: &str,
+        name: &str,
+        fuel: impl Into<HostFuncFuel>,
+    ) -> Result<&mut Self, LinkerError> {
+        self.inner_mut().func_fuel(module, name, fuel.into())?;
+        Ok(self)
+    }
 }
 
 /// Internal [`Linker`] implementation.
@@ -939,6 +1210,26 @@ impl<T> LinkerInner<T> {
         Ok(self)
     }
 
+    /// Creates a new named [`Func::new_raw`]-style host [`Func`] for this [`Linker`].
+    ///
+    /// For more information see [`Linker::func_new_raw`].
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    pub fn func_new_raw(
+        &mut self,
+        module: &str,
+        name: &str,
+        ty: FuncType,
+        func: impl Fn(Caller<'_, T>, FuncInOut<'_>) -> Result<(), Error> + Send + Sync + 'static,
+    ) -> Result<&mut Self, LinkerError> {
+        let func = HostFuncTrampolineEntity::new_raw(ty, func);
+        let key = self.new_import_key(module, name);
+        self.insert(key, Definition::HostFunc(func))?;
+        Ok(self)
+    }
+
     /// Creates a new named [`Func::new`]-style host [`Func`] for this [`Linker`].
     ///
     /// For information how to use this API see [`Func::wrap`].
@@ -972,6 +1263,28 @@ impl<T> LinkerInner<T> {
         Ok(self)
     }
 
+    /// Attaches `fuel` to the host function defined under `module` and `name`.
+    ///
+    /// For more information see [`Linker::func_fuel`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no host function defined under `module` and `name`.
+    pub fn func_fuel(
+        &mut self,
+        module: &str,
+        name: &str,
+        fuel: HostFuncFuel,
+    ) -> Result<&mut Self, LinkerError> {
+        let host_func = self
+            .get_import_key(module, name)
+            .and_then(|key| self.definitions.get_mut(&key))
+            .and_then(Definition::as_host_func_mut)
+            .ok_or_else(|| LinkerError::missing_host_func_definition(module, name))?;
+        host_func.set_fuel(fuel);
+        Ok(self)
+    }
+
     /// Looks up a [`Definition`] by name in this [`Linker`].
     ///
     /// Returns `None` if this name was not previously defined in this [`Linker`].
@@ -999,6 +1312,7 @@ mod tests {
 
     use super::*;
     use crate::Store;
+    use std::string::ToString;
 
     struct HostState {
         a: i32,
@@ -1100,6 +1414,155 @@ mod tests {
         assert_eq!(wasm_get_b.call(&mut store, ()).unwrap(), 200);
     }
 
+    #[test]
+    fn linker_func_fuel_works() {
+        fn consumed_fuel(store: &mut Store<()>, f: impl FnOnce(&mut Store<()>)) -> u64 {
+            store.set_fuel(1_000).unwrap();
+            f(store);
+            1_000 - store.get_fuel().unwrap()
+        }
+        let mut config = crate::Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_wrap("host", "fixed", |_caller: Caller<()>| ())
+            .unwrap()
+            .func_wrap("host", "dynamic", |_caller: Caller<()>, _len: i32| ())
+            .unwrap()
+            .func_wrap("host", "free", |_caller: Caller<()>| ())
+            .unwrap()
+            .func_wrap("host", "free_dynamic", |_caller: Caller<()>, _len: i32| ())
+            .unwrap()
+            .func_fuel("host", "fixed", 100)
+            .unwrap()
+            .func_fuel(
+                "host",
+                "dynamic",
+                HostFuncFuel::dynamic(|params| params[0].i32().unwrap() as u64 * 10),
+            )
+            .unwrap();
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (import "host" "fixed" (func $fixed))
+                (import "host" "dynamic" (func $dynamic (param i32)))
+                (import "host" "free" (func $free))
+                (import "host" "free_dynamic" (func $free_dynamic (param i32)))
+                (func (export "call_fixed")
+                    (call $fixed)
+                )
+                (func (export "call_dynamic") (param i32)
+                    (call $dynamic (local.get 0))
+                )
+                (func (export "call_free")
+                    (call $free)
+                )
+                (func (export "call_free_dynamic") (param i32)
+                    (call $free_dynamic (local.get 0))
+                )
+            )"#,
+        )
+        .unwrap();
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let call_fixed = instance
+            .get_typed_func::<(), ()>(&store, "call_fixed")
+            .unwrap();
+        let call_dynamic = instance
+            .get_typed_func::<i32, ()>(&store, "call_dynamic")
+            .unwrap();
+        let call_free = instance
+            .get_typed_func::<(), ()>(&store, "call_free")
+            .unwrap();
+        let call_free_dynamic = instance
+            .get_typed_func::<i32, ()>(&store, "call_free_dynamic")
+            .unwrap();
+        // The uncharged host functions are called by identical Wasm functions
+        // and thus only consume the fuel of the Wasm instructions.
+        let base = consumed_fuel(&mut store, |store| call_free.call(store, ()).unwrap());
+        let base_dynamic = consumed_fuel(&mut store, |store| {
+            call_free_dynamic.call(store, 1).unwrap()
+        });
+        let fixed = consumed_fuel(&mut store, |store| call_fixed.call(store, ()).unwrap());
+        assert_eq!(fixed, base + 100);
+        let small = consumed_fuel(&mut store, |store| call_dynamic.call(store, 1).unwrap());
+        assert_eq!(small, base_dynamic + 10);
+        let large = consumed_fuel(&mut store, |store| call_dynamic.call(store, 11).unwrap());
+        assert_eq!(large, base_dynamic + 110);
+        // Not enough fuel for the host function call: traps before the call.
+        store.set_fuel(50).unwrap();
+        let error = call_fixed.call(&mut store, ()).unwrap_err();
+        assert_eq!(error.as_trap_code(), Some(crate::core::TrapCode::OutOfFuel));
+    }
+
+    #[test]
+    fn linker_func_fuel_return_call_out_of_fuel() {
+        let mut config = crate::Config::default();
+        config.consume_fuel(true).coredump_on_trap(true);
+        let engine = Engine::new(&config);
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_wrap("host", "fixed", |_caller: Caller<()>, _value: i32| ())
+            .unwrap()
+            .func_fuel("host", "fixed", 100)
+            .unwrap();
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (import "host" "fixed" (func $fixed (param i32)))
+                (func $tail (param i32)
+                    (return_call $fixed (local.get 0))
+                )
+                (func (export "run") (param i32)
+                    (call $tail (local.get 0))
+                )
+            )"#,
+        )
+        .unwrap();
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let run = instance.get_typed_func::<i32, ()>(&store, "run").unwrap();
+        store.set_fuel(50).unwrap();
+        let error = run.call(&mut store, 7).unwrap_err();
+        assert_eq!(error.as_trap_code(), Some(crate::core::TrapCode::OutOfFuel));
+        // The frame of `$tail` is kept since the host function has not been tail called.
+        let frames = error.coredump().unwrap().frames();
+        let indices = frames
+            .iter()
+            .map(crate::CoreDumpFrame::func_index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, [1, 2]);
+        assert!(matches!(frames[0].locals(), [Some(Val::I32(7))]));
+    }
+
+    #[test]
+    fn linker_func_fuel_missing_definition() {
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let memory = crate::Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.define("env", "memory", memory).unwrap();
+        assert!(matches!(
+            linker.func_fuel("env", "missing", 1),
+            Err(LinkerError::MissingHostFuncDefinition { .. })
+        ));
+        assert!(matches!(
+            linker.func_fuel("env", "memory", 1),
+            Err(LinkerError::MissingHostFuncDefinition { .. })
+        ));
+    }
+
     #[test]
     fn build_linker() {
         let mut builder = <Linker<()>>::build();
@@ -1200,6 +1663,28 @@ mod tests {
             .unwrap_err();
     }
 
+    #[test]
+    fn linker_has_definition() {
+        use crate::{Engine, Linker, Memory, MemoryType, Store};
+        let engine = Engine::default();
+        let mut builder = <Linker<()>>::build();
+        builder
+            .func_wrap("host", "func.0", |_caller: Caller<()>| ())
+            .unwrap();
+        let mut linker = builder.finish().create(&engine);
+        linker
+            .func_wrap("host", "func.1", |_caller: Caller<()>| ())
+            .unwrap();
+        let mut store = Store::new(&engine, ());
+        let memory = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+        linker.define("env", "memory", memory).unwrap();
+        assert!(linker.has_definition("host", "func.0"));
+        assert!(linker.has_definition("host", "func.1"));
+        assert!(linker.has_definition("env", "memory"));
+        assert!(!linker.has_definition("host", "func.2"));
+        assert!(!linker.has_definition("env", "func.0"));
+    }
+
     #[test]
     fn populate_via_imports() {
         use crate::{Engine, Func, Linker, Memory, MemoryType, Module, Store};
@@ -1230,4 +1715,133 @@ mod tests {
         linker.define("host", "hello", func).unwrap();
         linker.instantiate(&mut store, &module).unwrap();
     }
+
+    fn unknown_imports_module(engine: &Engine) -> Module {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (import "host" "known" (func $known (result i32)))
+                (import "host" "unknown" (func $unknown (param i32) (result i64 f32)))
+                (import "env" "memory" (memory 2 4))
+                (import "env" "table" (table 3 funcref))
+                (import "env" "global" (global $g (mut i32)))
+                (func (export "known") (result i32)
+                    (call $known)
+                )
+                (func (export "unknown") (result i64 f32)
+                    (call $unknown (global.get $g))
+                )
+            )"#,
+        )
+        .unwrap();
+        Module::new(engine, &wasm[..]).unwrap()
+    }
+
+    #[test]
+    fn define_unknown_imports_as_traps_works() {
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let module = unknown_imports_module(&engine);
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.func_wrap("host", "known", || 42_i32).unwrap();
+        let defined = linker.define_unknown_imports_as_traps(&module).unwrap();
+        let defined = defined.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(defined, ["host::unknown"]);
+        // Non-function imports are still missing:
+        assert!(linker.instantiate(&mut store, &module).is_err());
+        linker
+            .define_unknown_imports_as_default_values(&mut store, &module)
+            .unwrap();
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let known = instance.get_typed_func::<(), i32>(&store, "known").unwrap();
+        assert_eq!(known.call(&mut store, ()).unwrap(), 42);
+        let unknown = instance
+            .get_typed_func::<(), (i64, f32)>(&store, "unknown")
+            .unwrap();
+        let error = unknown.call(&mut store, ()).unwrap_err();
+        assert!(error.to_string().contains("host::unknown"));
+    }
+
+    #[test]
+    fn define_unknown_imports_as_default_values_works() {
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let module = unknown_imports_module(&engine);
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.func_wrap("host", "known", || 42_i32).unwrap();
+        let defined = linker
+            .define_unknown_imports_as_default_values(&mut store, &module)
+            .unwrap();
+        let defined = defined.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            defined,
+            ["host::unknown", "env::table", "env::memory", "env::global"]
+        );
+        let memory = linker
+            .get(&store, "env", "memory")
+            .unwrap()
+            .into_memory()
+            .unwrap();
+        assert_eq!(memory.size(&store), 2);
+        let table = linker
+            .get(&store, "env", "table")
+            .unwrap()
+            .into_table()
+            .unwrap();
+        assert_eq!(table.size(&store), 3);
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let unknown = instance
+            .get_typed_func::<(), (i64, f32)>(&store, "unknown")
+            .unwrap();
+        assert_eq!(unknown.call(&mut store, ()).unwrap(), (0, 0.0));
+    }
+
+    #[test]
+    fn define_unknown_imports_as_default_values_rejects_non_nullable_results() {
+        let mut config = crate::Config::default();
+        config.wasm_function_references(true);
+        let engine = Engine::new(&config);
+        let mut store = Store::new(&engine, ());
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (import "env" "memory" (memory 1))
+                (import "host" "known" (func))
+                (import "host" "unknown" (func (result (ref func))))
+            )"#,
+        )
+        .unwrap();
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        let error = linker
+            .define_unknown_imports_as_default_values(&mut store, &module)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            crate::errors::ErrorKind::Func(FuncError::MismatchingResultType)
+        ));
+        // Note: no import is defined if any of them is invalid.
+        assert!(!linker.has_definition("env", "memory"));
+        assert!(!linker.has_definition("host", "known"));
+        assert!(!linker.has_definition("host", "unknown"));
+        // Retrying after defining the invalid import does not fail with duplicate definitions.
+        linker
+            .func_wrap("host", "known", || {})
+            .unwrap()
+            .define_unknown_imports_as_traps(&module)
+            .unwrap();
+        let defined = linker
+            .define_unknown_imports_as_default_values(&mut store, &module)
+            .unwrap();
+        let defined = defined.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(defined, ["env::memory"]);
+    }
 }
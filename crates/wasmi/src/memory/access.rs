@@ -0,0 +1,550 @@
+use super::{Memory, MemoryError};
+use crate::{
+    core::{UntypedVal, ValType, F32, F64},
+    AsContext,
+    AsContextMut,
+    StoreContext,
+    StoreContextMut,
+    WasmTy,
+};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, hash, marker::PhantomData, ops::Range, str};
+
+/// Plain-old-data types with a little-endian encoding in linear memory.
+///
+/// # Note
+///
+/// Implemented for all primitive integer and float types, arrays thereof and [`WasmPtr`].
+pub trait MemoryValue: Copy {
+    /// The number of bytes of the encoding.
+    const SIZE: usize;
+
+    /// Decodes `Self` from its little-endian encoding in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `bytes` is not [`MemoryValue::SIZE`].
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    /// Encodes `self` in little-endian into `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `bytes` is not [`MemoryValue::SIZE`].
+    fn to_le_bytes(self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_memory_value {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl MemoryValue for $ty {
+                const SIZE: usize = ::core::mem::size_of::<$ty>();
+
+                #[inline]
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    let bytes = <[u8; Self::SIZE]>::try_from(bytes)
+                        .unwrap_or_else(|_| panic!("invalid encoding length: {}", bytes.len()));
+                    <$ty>::from_le_bytes(bytes)
+                }
+
+                #[inline]
+                fn to_le_bytes(self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&<$ty>::to_le_bytes(self));
+                }
+            }
+        )*
+    };
+}
+impl_memory_value!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl MemoryValue for F32 {
+    const SIZE: usize = <f32 as MemoryValue>::SIZE;
+
+    #[inline]
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Self::from(<f32 as MemoryValue>::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn to_le_bytes(self, bytes: &mut [u8]) {
+        <f32 as MemoryValue>::to_le_bytes(f32::from(self), bytes)
+    }
+}
+
+impl MemoryValue for F64 {
+    const SIZE: usize = <f64 as MemoryValue>::SIZE;
+
+    #[inline]
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Self::from(<f64 as MemoryValue>::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn to_le_bytes(self, bytes: &mut [u8]) {
+        <f64 as MemoryValue>::to_le_bytes(f64::from(self), bytes)
+    }
+}
+
+impl<T: MemoryValue, const N: usize> MemoryValue for [T; N] {
+    const SIZE: usize = T::SIZE * N;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::SIZE, "invalid encoding length");
+        core::array::from_fn(|i| T::from_le_bytes(&bytes[i * T::SIZE..][..T::SIZE]))
+    }
+
+    fn to_le_bytes(self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), Self::SIZE, "invalid encoding length");
+        for (i, value) in self.into_iter().enumerate() {
+            value.to_le_bytes(&mut bytes[i * T::SIZE..][..T::SIZE]);
+        }
+    }
+}
+
+/// Returns the byte range of `len` values of `size` bytes each starting at `offset`.
+///
+/// # Errors
+///
+/// If the byte range overflows the address space of the host.
+fn byte_range(offset: u32, len: u32, size: usize) -> Result<Range<usize>, MemoryError> {
+    let start = offset as usize;
+    let end = (len as usize)
+        .checked_mul(size)
+        .and_then(|len| start.checked_add(len))
+        .ok_or(MemoryError::OutOfBoundsAccess)?;
+    Ok(start..end)
+}
+
+/// Returns the `range` of the `memory` bytes.
+///
+/// # Errors
+///
+/// If `range` is out of bounds for the `memory` bytes.
+fn get_bytes(memory: &[u8], range: Range<usize>) -> Result<&[u8], MemoryError> {
+    memory.get(range).ok_or(MemoryError::OutOfBoundsAccess)
+}
+
+/// Returns the `range` of the `memory` bytes.
+///
+/// # Errors
+///
+/// If `range` is out of bounds for the `memory` bytes.
+fn get_bytes_mut(memory: &mut [u8], range: Range<usize>) -> Result<&mut [u8], MemoryError> {
+    memory.get_mut(range).ok_or(MemoryError::OutOfBoundsAccess)
+}
+
+/// A typed pointer into the linear memory of a Wasm instance.
+///
+/// # Note
+///
+/// - A [`WasmPtr`] is a 32-bit offset into linear memory and can be used
+///   as parameter or result type of host functions.
+/// - Values are read and written in little-endian via [`MemoryValue`].
+#[repr(transparent)]
+pub struct WasmPtr<T> {
+    /// The offset in bytes into linear memory.
+    offset: u32,
+    /// The type of the values pointed to.
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> fmt::Debug for WasmPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WasmPtr").field(&self.offset).finish()
+    }
+}
+
+impl<T> Clone for WasmPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WasmPtr<T> {}
+
+impl<T> PartialEq for WasmPtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<T> Eq for WasmPtr<T> {}
+
+impl<T> hash::Hash for WasmPtr<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.offset.hash(state)
+    }
+}
+
+impl<T> From<u32> for WasmPtr<T> {
+    fn from(offset: u32) -> Self {
+        Self::new(offset)
+    }
+}
+
+impl<T> From<WasmPtr<T>> for u32 {
+    fn from(ptr: WasmPtr<T>) -> Self {
+        ptr.offset()
+    }
+}
+
+impl<T> From<UntypedVal> for WasmPtr<T> {
+    fn from(value: UntypedVal) -> Self {
+        Self::new(u32::from(value))
+    }
+}
+
+impl<T> From<WasmPtr<T>> for UntypedVal {
+    fn from(ptr: WasmPtr<T>) -> Self {
+        UntypedVal::from(ptr.offset())
+    }
+}
+
+impl<T> WasmTy for WasmPtr<T> {
+    #[inline]
+    fn ty() -> ValType {
+        ValType::I32
+    }
+}
+
+impl<T> MemoryValue for WasmPtr<T> {
+    const SIZE: usize = <u32 as MemoryValue>::SIZE;
+
+    #[inline]
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Self::new(<u32 as MemoryValue>::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn to_le_bytes(self, bytes: &mut [u8]) {
+        <u32 as MemoryValue>::to_le_bytes(self.offset, bytes)
+    }
+}
+
+impl<T> WasmPtr<T> {
+    /// Creates a new [`WasmPtr`] at `offset` bytes into linear memory.
+    pub const fn new(offset: u32) -> Self {
+        Self {
+            offset,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a `null` [`WasmPtr`] at offset zero.
+    pub const fn null() -> Self {
+        Self::new(0)
+    }
+
+    /// Returns `true` if `self` is at offset zero.
+    pub const fn is_null(self) -> bool {
+        self.offset == 0
+    }
+
+    /// Returns the offset of `self` in bytes into linear memory.
+    pub const fn offset(self) -> u32 {
+        self.offset
+    }
+
+    /// Casts `self` into a [`WasmPtr`] to values of type `U` at the same offset.
+    pub const fn cast<U>(self) -> WasmPtr<U> {
+        WasmPtr::new(self.offset)
+    }
+}
+
+impl<T: MemoryValue> WasmPtr<T> {
+    /// Returns the size of `T` in bytes as `u32` if it fits.
+    fn size() -> Option<u32> {
+        u32::try_from(T::SIZE).ok()
+    }
+
+    /// Returns a [`WasmPtr`] that is `count` values of type `T` after `self`.
+    ///
+    /// Returns `None` if the resulting offset overflows.
+    pub fn checked_add(self, count: u32) -> Option<Self> {
+        let delta = Self::size()?.checked_mul(count)?;
+        self.offset.checked_add(delta).map(Self::new)
+    }
+
+    /// Returns a [`WasmPtr`] that is `count` values of type `T` before `self`.
+    ///
+    /// Returns `None` if the resulting offset underflows.
+    pub fn checked_sub(self, count: u32) -> Option<Self> {
+        let delta = Self::size()?.checked_mul(count)?;
+        self.offset.checked_sub(delta).map(Self::new)
+    }
+
+    /// Returns a [`WasmSlice`] of `len` values of type `T` starting at `self`.
+    pub const fn slice(self, len: u32) -> WasmSlice<T> {
+        WasmSlice::new(self, len)
+    }
+
+    /// Reads the value of type `T` at `self` from `memory`.
+    ///
+    /// # Errors
+    ///
+    /// If the value is out of bounds for `memory`.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn read(self, memory: &Memory, ctx: impl AsContext) -> Result<T, MemoryError> {
+        let range = byte_range(self.offset, 1, T::SIZE)?;
+        let bytes = get_bytes(memory.data(&ctx), range)?;
+        Ok(T::from_le_bytes(bytes))
+    }
+
+    /// Writes `value` of type `T` at `self` into `memory`.
+    ///
+    /// # Errors
+    ///
+    /// If the value is out of bounds for `memory`.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn write(
+        self,
+        memory: &Memory,
+        mut ctx: impl AsContextMut,
+        value: T,
+    ) -> Result<(), MemoryError> {
+        let range = byte_range(self.offset, 1, T::SIZE)?;
+        let bytes = get_bytes_mut(memory.data_mut(&mut ctx), range)?;
+        value.to_le_bytes(bytes);
+        Ok(())
+    }
+}
+
+/// A typed slice of values in the linear memory of a Wasm instance.
+///
+/// # Note
+///
+/// Values are read and written in little-endian via [`MemoryValue`].
+pub struct WasmSlice<T> {
+    /// The pointer to the first value.
+    ptr: WasmPtr<T>,
+    /// The number of values.
+    len: u32,
+}
+
+impl<T> fmt::Debug for WasmSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmSlice")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T> Clone for WasmSlice<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WasmSlice<T> {}
+
+impl<T> PartialEq for WasmSlice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr && self.len == other.len
+    }
+}
+
+impl<T> Eq for WasmSlice<T> {}
+
+impl<T> WasmSlice<T> {
+    /// Creates a new [`WasmSlice`] of `len` values starting at `ptr`.
+    pub const fn new(ptr: WasmPtr<T>, len: u32) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Returns the pointer to the first value of `self`.
+    pub const fn ptr(self) -> WasmPtr<T> {
+        self.ptr
+    }
+
+    /// Returns the number of values of `self`.
+    pub const fn len(self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if `self` has no values.
+    pub const fn is_empty(self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: MemoryValue> WasmSlice<T> {
+    /// Returns the byte range of `self` in linear memory.
+    fn byte_range(self) -> Result<Range<usize>, MemoryError> {
+        byte_range(self.ptr.offset(), self.len, T::SIZE)
+    }
+
+    /// Returns a [`WasmPtr`] to the value at `index` if it is in bounds of `self`.
+    pub fn get(self, index: u32) -> Option<WasmPtr<T>> {
+        if index >= self.len {
+            return None;
+        }
+        self.ptr.checked_add(index)
+    }
+
+    /// Returns the little-endian encoded bytes of `self` in `memory`.
+    ///
+    /// # Errors
+    ///
+    /// If `self` is out of bounds for `memory`.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn as_bytes<'a, D: 'a>(
+        self,
+        memory: &Memory,
+        ctx: impl Into<StoreContext<'a, D>>,
+    ) -> Result<&'a [u8], MemoryError> {
+        let range = self.byte_range()?;
+        get_bytes(memory.data(ctx), range)
+    }
+
+    /// Returns the exclusive little-endian encoded bytes of `self` in `memory`.
+    ///
+    /// # Note
+    ///
+    /// The returned bytes borrow the store exclusively so that
+    /// no other accesses to it are possible while they are alive.
+    ///
+    /// # Errors
+    ///
+    /// If `self` is out of bounds for `memory`.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn as_bytes_mut<'a, D: 'a>(
+        self,
+        memory: &Memory,
+        ctx: impl Into<StoreContextMut<'a, D>>,
+    ) -> Result<&'a mut [u8], MemoryError> {
+        let range = self.byte_range()?;
+        get_bytes_mut(memory.data_mut(ctx), range)
+    }
+
+    /// Reads all values of `self` from `memory`.
+    ///
+    /// # Errors
+    ///
+    /// If `self` is out of bounds for `memory`.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn read_to_vec(self, memory: &Memory, ctx: impl AsContext) -> Result<Vec<T>, MemoryError> {
+        let range = self.byte_range()?;
+        let bytes = get_bytes(memory.data(&ctx), range)?;
+        // Note: we cannot use `chunks_exact` since it panics for zero-sized values.
+        let values = (0..self.len as usize)
+            .map(|i| T::from_le_bytes(&bytes[i * T::SIZE..][..T::SIZE]))
+            .collect();
+        Ok(values)
+    }
+
+    /// Writes all `values` into `self` in `memory`.
+    ///
+    /// # Errors
+    ///
+    /// - If the number of `values` does not match the length of `self`.
+    /// - If `self` is out of bounds for `memory`.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn write_slice(
+        self,
+        memory: &Memory,
+        mut ctx: impl AsContextMut,
+        values: &[T],
+    ) -> Result<(), MemoryError> {
+        if values.len() != self.len as usize {
+            return Err(MemoryError::LengthMismatch {
+                expected: self.len,
+                found: values.len(),
+            });
+        }
+        let range = self.byte_range()?;
+        let bytes = get_bytes_mut(memory.data_mut(&mut ctx), range)?;
+        // Note: we cannot use `chunks_exact_mut` since it panics for zero-sized values.
+        for (i, value) in values.iter().enumerate() {
+            value.to_le_bytes(&mut bytes[i * T::SIZE..][..T::SIZE]);
+        }
+        Ok(())
+    }
+}
+
+/// A UTF-8 encoded string in the linear memory of a Wasm instance.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WasmStr {
+    /// The bytes of the string.
+    bytes: WasmSlice<u8>,
+}
+
+impl WasmStr {
+    /// Creates a new [`WasmStr`] of `len` bytes starting at `ptr`.
+    pub const fn new(ptr: WasmPtr<u8>, len: u32) -> Self {
+        Self {
+            bytes: WasmSlice::new(ptr, len),
+        }
+    }
+
+    /// Returns the pointer to the first byte of `self`.
+    pub const fn ptr(self) -> WasmPtr<u8> {
+        self.bytes.ptr()
+    }
+
+    /// Returns the length of `self` in bytes.
+    pub const fn len(self) -> u32 {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if `self` has a length of zero bytes.
+    pub const fn is_empty(self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the bytes of `self` as [`WasmSlice`].
+    pub const fn as_slice(self) -> WasmSlice<u8> {
+        self.bytes
+    }
+
+    /// Returns the string of `self` in `memory`.
+    ///
+    /// # Errors
+    ///
+    /// - If `self` is out of bounds for `memory`.
+    /// - If the bytes of `self` are not valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn as_str<'a, D: 'a>(
+        self,
+        memory: &Memory,
+        ctx: impl Into<StoreContext<'a, D>>,
+    ) -> Result<&'a str, MemoryError> {
+        let bytes = self.bytes.as_bytes(memory, ctx)?;
+        str::from_utf8(bytes).map_err(MemoryError::InvalidUtf8)
+    }
+
+    /// Reads the string of `self` from `memory` into an owned [`String`].
+    ///
+    /// # Errors
+    ///
+    /// - If `self` is out of bounds for `memory`.
+    /// - If the bytes of `self` are not valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own `memory`.
+    pub fn read_string(self, memory: &Memory, ctx: impl AsContext) -> Result<String, MemoryError> {
+        self.as_str(memory, ctx.as_context()).map(String::from)
+    }
+}
@@ -24,6 +24,15 @@ pub enum MemoryError {
     TooManyMemories,
     /// Tried to create memory with invalid static buffer size
     InvalidStaticBufferSize,
+    /// Tried to read a string from linear memory that is not valid UTF-8.
+    InvalidUtf8(core::str::Utf8Error),
+    /// Tried to write a number of values into a linear memory slice of a different length.
+    LengthMismatch {
+        /// The length of the linear memory slice.
+        expected: u32,
+        /// The number of values to write.
+        found: usize,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -53,6 +62,15 @@ impl Display for MemoryError {
             Self::InvalidStaticBufferSize => {
                 write!(f, "tried to use too small static buffer")
             }
+            Self::InvalidUtf8(error) => {
+                write!(f, "invalid UTF-8 in linear memory: {error}")
+            }
+            Self::LengthMismatch { expected, found } => {
+                write!(
+                    f,
+                    "tried to write {found} values into linear memory slice of length {expected}"
+                )
+            }
         }
     }
 }
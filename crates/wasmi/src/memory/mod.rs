@@ -1,3 +1,4 @@
+mod access;
 mod buffer;
 mod data;
 mod error;
@@ -7,6 +8,7 @@ mod tests;
 
 use self::buffer::ByteBuffer;
 pub use self::{
+    access::{MemoryValue, WasmPtr, WasmSlice, WasmStr},
     data::{DataSegment, DataSegmentEntity, DataSegmentIdx},
     error::MemoryError,
 };
@@ -40,6 +42,7 @@ impl ArenaIndex for MemoryIdx {
 pub struct MemoryType {
     initial_pages: Pages,
     maximum_pages: Option<Pages>,
+    page_size_log2: u8,
 }
 
 impl MemoryType {
@@ -50,16 +53,37 @@ impl MemoryType {
     /// If the linear memory type initial or maximum size exceeds the
     /// maximum limits of 2^16 pages.
     pub fn new(initial: u32, maximum: Option<u32>) -> Result<Self, MemoryError> {
-        let initial_pages = Pages::new(initial).ok_or(MemoryError::InvalidMemoryType)?;
-        let maximum_pages = match maximum {
-            Some(maximum) => Pages::new(maximum)
-                .ok_or(MemoryError::InvalidMemoryType)?
-                .into(),
-            None => None,
+        Self::new_with_page_size(initial, maximum, Pages::DEFAULT_PAGE_SIZE_LOG2)
+    }
+
+    /// Creates a new memory type with initial and optional maximum pages of `2^page_size_log2` bytes.
+    ///
+    /// # Note
+    ///
+    /// As of the Wasm `custom-page-sizes` proposal pages are either 1 byte or 64 KiB in size.
+    ///
+    /// # Errors
+    ///
+    /// - If `page_size_log2` is neither `0` nor `16`.
+    /// - If the linear memory type initial or maximum size exceeds the
+    ///   maximum limits of 2^32 bytes.
+    pub fn new_with_page_size(
+        initial: u32,
+        maximum: Option<u32>,
+        page_size_log2: u8,
+    ) -> Result<Self, MemoryError> {
+        if page_size_log2 != 0 && page_size_log2 != Pages::DEFAULT_PAGE_SIZE_LOG2 {
+            return Err(MemoryError::InvalidMemoryType);
+        }
+        let new_pages = |amount| {
+            Pages::new_with_page_size(amount, page_size_log2).ok_or(MemoryError::InvalidMemoryType)
         };
+        let initial_pages = new_pages(initial)?;
+        let maximum_pages = maximum.map(new_pages).transpose()?;
         Ok(Self {
             initial_pages,
             maximum_pages,
+            page_size_log2,
         })
     }
 
@@ -78,6 +102,27 @@ impl MemoryType {
         self.maximum_pages
     }
 
+    /// Returns the size of a single page of the memory type as power of two.
+    pub fn page_size_log2(self) -> u8 {
+        self.page_size_log2
+    }
+
+    /// Returns the size of a single page of the memory type in bytes.
+    pub fn page_size(self) -> u32 {
+        1 << self.page_size_log2
+    }
+
+    /// Returns the maximum pages of the memory type or the absolute maximum for its page size.
+    fn absolute_maximum_pages(self) -> Pages {
+        self.maximum_pages()
+            .unwrap_or_else(|| Pages::max_with_page_size(self.page_size_log2))
+    }
+
+    /// Returns the amount of bytes required for `pages` of the memory type if representable.
+    pub(crate) fn pages_to_bytes(self, pages: Pages) -> Option<usize> {
+        pages.to_bytes_with_page_size(self.page_size_log2)
+    }
+
     /// Checks if `self` is a subtype of `other`.
     ///
     /// # Note
@@ -91,6 +136,7 @@ impl MemoryType {
     ///
     /// - If the `minimum` size of `self` is less than or equal to the `minimum` size of `other`.
     /// - If the `maximum` size of `self` is greater than the `maximum` size of `other`.
+    /// - If the page sizes of `self` and `other` differ.
     pub(crate) fn is_subtype_or_err(&self, other: &MemoryType) -> Result<(), MemoryError> {
         match self.is_subtype_of(other) {
             true => Ok(()),
@@ -110,6 +156,9 @@ impl MemoryType {
     /// [import subtyping]:
     /// https://webassembly.github.io/spec/core/valid/types.html#import-subtyping
     pub(crate) fn is_subtype_of(&self, other: &MemoryType) -> bool {
+        if self.page_size_log2() != other.page_size_log2() {
+            return false;
+        }
         if self.initial_pages() < other.initial_pages() {
             return false;
         }
@@ -136,9 +185,9 @@ impl MemoryEntity {
         limiter: &mut ResourceLimiterRef<'_>,
     ) -> Result<Self, MemoryError> {
         let initial_pages = memory_type.initial_pages();
-        let initial_len = initial_pages.to_bytes();
-        let maximum_pages = memory_type.maximum_pages().unwrap_or_else(Pages::max);
-        let maximum_len = maximum_pages.to_bytes();
+        let initial_len = memory_type.pages_to_bytes(initial_pages);
+        let maximum_pages = memory_type.absolute_maximum_pages();
+        let maximum_len = memory_type.pages_to_bytes(maximum_pages);
 
         if let Some(limiter) = limiter.as_resource_limiter() {
             if !limiter.memory_growing(0, initial_len.unwrap_or(usize::MAX), maximum_len)? {
@@ -172,9 +221,9 @@ impl MemoryEntity {
         buf: &'static mut [u8],
     ) -> Result<Self, MemoryError> {
         let initial_pages = memory_type.initial_pages();
-        let initial_len = initial_pages.to_bytes();
-        let maximum_pages = memory_type.maximum_pages().unwrap_or_else(Pages::max);
-        let maximum_len = maximum_pages.to_bytes();
+        let initial_len = memory_type.pages_to_bytes(initial_pages);
+        let maximum_pages = memory_type.absolute_maximum_pages();
+        let maximum_len = memory_type.pages_to_bytes(maximum_pages);
 
         if let Some(limiter) = limiter.as_resource_limiter() {
             if !limiter.memory_growing(0, initial_len.unwrap_or(usize::MAX), maximum_len)? {
@@ -218,7 +267,7 @@ impl MemoryEntity {
     pub fn dynamic_ty(&self) -> MemoryType {
         let current_pages = self.current_pages().into();
         let maximum_pages = self.ty().maximum_pages().map(Into::into);
-        MemoryType::new(current_pages, maximum_pages)
+        MemoryType::new_with_page_size(current_pages, maximum_pages, self.ty().page_size_log2())
             .unwrap_or_else(|_| panic!("must result in valid memory type due to invariants"))
     }
 
@@ -227,7 +276,7 @@ impl MemoryEntity {
         self.current_pages
     }
 
-    /// Returns the size, in WebAssembly pages, of this Wasm linear memory.
+    /// Returns the size, in pages of its [`MemoryType`], of this Wasm linear memory.
     pub fn size(&self) -> u32 {
         self.current_pages.into()
     }
@@ -259,22 +308,25 @@ impl MemoryEntity {
         if additional == 0 {
             return Ok(self.size());
         }
-        let Some(additional) = Pages::new(additional) else {
+        let ty = self.ty();
+        let page_size_log2 = ty.page_size_log2();
+        let Some(additional) = Pages::new_with_page_size(additional, page_size_log2) else {
             return Err(EntityGrowError::InvalidGrow);
         };
 
         let current_pages = self.current_pages();
-        let maximum_pages = self.ty().maximum_pages().unwrap_or_else(Pages::max);
-        let desired_pages = current_pages.checked_add(additional);
+        let maximum_pages = ty.absolute_maximum_pages();
+        let desired_pages = u32::from(current_pages)
+            .checked_add(u32::from(additional))
+            .and_then(|amount| Pages::new_with_page_size(amount, page_size_log2));
 
         // ResourceLimiter gets first look at the request.
         if let Some(limiter) = limiter.as_resource_limiter() {
-            let current_size = current_pages.to_bytes().unwrap_or(usize::MAX);
-            let desired_size = desired_pages
-                .unwrap_or_else(Pages::max)
-                .to_bytes()
+            let current_size = ty.pages_to_bytes(current_pages).unwrap_or(usize::MAX);
+            let desired_size = ty
+                .pages_to_bytes(desired_pages.unwrap_or(Pages::max_with_page_size(page_size_log2)))
                 .unwrap_or(usize::MAX);
-            let maximum_size = maximum_pages.to_bytes();
+            let maximum_size = ty.pages_to_bytes(maximum_pages);
             match limiter.memory_growing(current_size, desired_size, maximum_size) {
                 Ok(true) => (),
                 Ok(false) => return Err(EntityGrowError::InvalidGrow),
@@ -288,11 +340,11 @@ impl MemoryEntity {
         if new_pages > maximum_pages {
             return notify_limiter(limiter, EntityGrowError::InvalidGrow);
         }
-        let Some(new_size) = new_pages.to_bytes() else {
+        let Some(new_size) = ty.pages_to_bytes(new_pages) else {
             return notify_limiter(limiter, EntityGrowError::InvalidGrow);
         };
         if let Some(fuel) = fuel {
-            let additional_bytes = additional.to_bytes().unwrap_or(usize::MAX) as u64;
+            let additional_bytes = ty.pages_to_bytes(additional).unwrap_or(usize::MAX) as u64;
             if fuel
                 .consume_fuel_if(|costs| costs.fuel_for_bytes(additional_bytes))
                 .is_err()
@@ -327,7 +379,7 @@ impl MemoryEntity {
 
     /// Returns the byte length of this [`Memory`].
     ///
-    /// The returned value will be a multiple of the wasm page size, 64k.
+    /// The returned value will be a multiple of the page size of its [`MemoryType`].
     pub fn data_size(&self) -> usize {
         self.bytes.len
     }
@@ -377,7 +429,7 @@ impl Memory {
     }
 
     /// Returns the underlying stored representation.
-    pub(super) fn as_inner(&self) -> &Stored<MemoryIdx> {
+    pub(crate) fn as_inner(&self) -> &Stored<MemoryIdx> {
         &self.0
     }
 
@@ -445,7 +497,7 @@ impl Memory {
             .dynamic_ty()
     }
 
-    /// Returns the size, in WebAssembly pages, of this Wasm linear memory.
+    /// Returns the size, in pages of its [`MemoryType`], of this Wasm linear memory.
     ///
     /// # Panics
     ///
@@ -520,7 +572,7 @@ impl Memory {
 
     /// Returns the byte length of this [`Memory`].
     ///
-    /// The returned value will be a multiple of the wasm page size, 64k.
+    /// The returned value will be a multiple of the page size of its [`MemoryType`].
     ///
     /// # Panics
     ///
@@ -1,4 +1,5 @@
 use super::*;
+use crate::{Engine, Store};
 
 fn memory_type(minimum: u32, maximum: impl Into<Option<u32>>) -> MemoryType {
     MemoryType::new(minimum, maximum.into()).unwrap()
@@ -14,3 +15,142 @@ fn subtyping_works() {
     assert!(memory_type(0, 1).is_subtype_of(&memory_type(0, None)));
     assert!(!memory_type(0, None).is_subtype_of(&memory_type(0, 1)));
 }
+
+#[test]
+fn subtyping_requires_equal_page_sizes() {
+    let bytes = |minimum, maximum| MemoryType::new_with_page_size(minimum, maximum, 0).unwrap();
+    assert!(bytes(0, Some(1)).is_subtype_of(&bytes(0, Some(1))));
+    assert!(!bytes(0, Some(1)).is_subtype_of(&memory_type(0, 1)));
+    assert!(!memory_type(0, 1).is_subtype_of(&bytes(0, Some(1))));
+}
+
+#[test]
+fn custom_page_sizes_work() {
+    assert!(MemoryType::new_with_page_size(0, None, 8).is_err());
+    assert!(MemoryType::new_with_page_size(u32::MAX, None, 0).is_ok());
+    assert!(MemoryType::new_with_page_size(65537, None, 16).is_err());
+    let ty = MemoryType::new_with_page_size(3, Some(5), 0).unwrap();
+    assert_eq!(ty.page_size(), 1);
+    assert_eq!(memory_type(0, None).page_size(), 65536);
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, ty).unwrap();
+    assert_eq!(memory.data_size(&store), 3);
+    assert_eq!(memory.grow(&mut store, 2).unwrap(), 3);
+    assert_eq!(memory.size(&store), 5);
+    assert_eq!(memory.data_size(&store), 5);
+    assert!(memory.grow(&mut store, 1).is_err());
+}
+
+fn one_page_memory() -> (Store<()>, Memory) {
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, memory_type(1, 1)).unwrap();
+    (store, memory)
+}
+
+#[test]
+fn wasm_ptr_read_write_works() {
+    let (mut store, memory) = one_page_memory();
+    let ptr = WasmPtr::<u32>::new(8);
+    ptr.write(&memory, &mut store, 0xDEAD_BEEF).unwrap();
+    assert_eq!(memory.data(&store)[8..12], [0xEF, 0xBE, 0xAD, 0xDE]);
+    assert_eq!(ptr.read(&memory, &store).unwrap(), 0xDEAD_BEEF);
+    assert_eq!(ptr.cast::<u16>().read(&memory, &store).unwrap(), 0xBEEF);
+    let ptr = WasmPtr::<[f64; 2]>::new(16);
+    ptr.write(&memory, &mut store, [1.5, -2.0]).unwrap();
+    assert_eq!(ptr.read(&memory, &store).unwrap(), [1.5, -2.0]);
+    let ptr = WasmPtr::<WasmPtr<u8>>::new(32);
+    ptr.write(&memory, &mut store, WasmPtr::new(42)).unwrap();
+    assert_eq!(ptr.read(&memory, &store).unwrap(), WasmPtr::new(42));
+}
+
+#[test]
+fn wasm_ptr_out_of_bounds_fails() {
+    let (mut store, memory) = one_page_memory();
+    let ptr = WasmPtr::<u64>::new(65536 - 4);
+    assert!(matches!(
+        ptr.read(&memory, &store),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+    assert!(matches!(
+        ptr.write(&memory, &mut store, 0),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+    assert!(WasmPtr::<u64>::new(65536 - 8).read(&memory, &store).is_ok());
+    assert!(WasmPtr::<u8>::new(u32::MAX).read(&memory, &store).is_err());
+}
+
+#[test]
+fn wasm_ptr_arithmetic_works() {
+    let ptr = WasmPtr::<u32>::new(8);
+    assert_eq!(ptr.checked_add(2), Some(WasmPtr::new(16)));
+    assert_eq!(ptr.checked_sub(2), Some(WasmPtr::null()));
+    assert_eq!(ptr.checked_sub(3), None);
+    assert_eq!(ptr.checked_add(u32::MAX / 4), None);
+    assert!(WasmPtr::<u8>::null().is_null());
+}
+
+#[test]
+fn wasm_slice_works() {
+    let (mut store, memory) = one_page_memory();
+    let slice = WasmPtr::<i16>::new(100).slice(3);
+    slice.write_slice(&memory, &mut store, &[1, -2, 3]).unwrap();
+    assert_eq!(slice.read_to_vec(&memory, &store).unwrap(), [1, -2, 3]);
+    assert_eq!(slice.get(1), Some(WasmPtr::new(102)));
+    assert_eq!(slice.get(3), None);
+    assert!(matches!(
+        slice.write_slice(&memory, &mut store, &[1, 2]),
+        Err(MemoryError::LengthMismatch {
+            expected: 3,
+            found: 2
+        })
+    ));
+    let bytes = slice.as_bytes_mut(&memory, &mut store).unwrap();
+    assert_eq!(bytes.len(), 6);
+    bytes[0] = 5;
+    assert_eq!(slice.as_bytes(&memory, &store).unwrap()[0], 5);
+    assert!(WasmPtr::<u8>::new(65535)
+        .slice(2)
+        .as_bytes(&memory, &store)
+        .is_err());
+    assert!(WasmPtr::<u64>::new(0)
+        .slice(u32::MAX)
+        .read_to_vec(&memory, &store)
+        .is_err());
+}
+
+#[test]
+fn zero_sized_values_work() {
+    let (mut store, memory) = one_page_memory();
+    let ptr = WasmPtr::<[u32; 0]>::new(65536);
+    ptr.write(&memory, &mut store, []).unwrap();
+    assert_eq!(ptr.read(&memory, &store).unwrap(), []);
+    let slice = ptr.slice(3);
+    slice
+        .write_slice(&memory, &mut store, &[[], [], []])
+        .unwrap();
+    assert_eq!(slice.read_to_vec(&memory, &store).unwrap(), [[], [], []]);
+    let ptr = WasmPtr::<[[u8; 0]; 2]>::new(0);
+    ptr.write(&memory, &mut store, [[], []]).unwrap();
+    assert_eq!(ptr.read(&memory, &store).unwrap(), [[], []]);
+}
+
+#[test]
+fn wasm_str_works() {
+    let (mut store, memory) = one_page_memory();
+    memory
+        .write(&mut store, 10, "hello wasmi".as_bytes())
+        .unwrap();
+    let s = WasmStr::new(WasmPtr::new(10), 5);
+    assert_eq!(s.as_str(&memory, &store).unwrap(), "hello");
+    assert_eq!(s.read_string(&memory, &store).unwrap(), "hello");
+    memory.write(&mut store, 10, &[0xFF]).unwrap();
+    assert!(matches!(
+        s.as_str(&memory, &store),
+        Err(MemoryError::InvalidUtf8(_))
+    ));
+    let s = WasmStr::new(WasmPtr::new(65530), 10);
+    assert!(matches!(
+        s.as_str(&memory, &store),
+        Err(MemoryError::OutOfBoundsAccess)
+    ));
+}
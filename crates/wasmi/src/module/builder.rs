@@ -20,10 +20,9 @@ use super::{
 };
 use crate::{
     collections::Map,
-    engine::{DedupFuncType, EngineFuncSpan},
+    engine::{DedupFuncType, EngineFuncSpan, SubType},
     Engine,
     Error,
-    FuncType,
     GlobalType,
     MemoryType,
     TableType,
@@ -46,9 +45,11 @@ pub struct ModuleHeaderBuilder {
     pub imports: ModuleImportsBuilder,
     pub funcs: Vec<DedupFuncType>,
     pub tables: Vec<TableType>,
+    pub tables_init: Vec<Option<ConstExpr>>,
     pub memories: Vec<MemoryType>,
     pub globals: Vec<GlobalType>,
     pub globals_init: Vec<ConstExpr>,
+    pub tags: Vec<DedupFuncType>,
     pub exports: Map<Box<str>, ExternIdx>,
     pub start: Option<FuncIdx>,
     pub engine_funcs: EngineFuncSpan,
@@ -64,9 +65,11 @@ impl ModuleHeaderBuilder {
             imports: ModuleImportsBuilder::default(),
             funcs: Vec::new(),
             tables: Vec::new(),
+            tables_init: Vec::new(),
             memories: Vec::new(),
             globals: Vec::new(),
             globals_init: Vec::new(),
+            tags: Vec::new(),
             exports: Map::new(),
             start: None,
             engine_funcs: EngineFuncSpan::default(),
@@ -83,9 +86,11 @@ impl ModuleHeaderBuilder {
                 imports: self.imports.finish(),
                 funcs: self.funcs.into(),
                 tables: self.tables.into(),
+                tables_init: self.tables_init.into(),
                 memories: self.memories.into(),
                 globals: self.globals.into(),
                 globals_init: self.globals_init.into(),
+                tags: self.tags.into(),
                 exports: self.exports,
                 start: self.start,
                 engine_funcs: self.engine_funcs,
@@ -142,35 +147,34 @@ impl ModuleBuilder {
 }
 
 impl ModuleHeaderBuilder {
-    /// Pushes the given function types to the [`Module`] under construction.
-    ///
-    /// # Errors
-    ///
-    /// If a function type fails to validate.
+    /// Reserves space for `additional` function types of the [`Module`] under construction.
     ///
     /// # Panics
     ///
-    /// If this function has already been called on the same [`ModuleBuilder`].
-    pub fn push_func_types<T>(&mut self, func_types: T) -> Result<(), Error>
-    where
-        T: IntoIterator<Item = Result<FuncType, Error>>,
-        <T as IntoIterator>::IntoIter: ExactSizeIterator,
-    {
+    /// If function types have already been pushed to the same [`ModuleBuilder`].
+    pub fn reserve_func_types(&mut self, additional: usize) {
         assert!(
             self.func_types.is_empty(),
             "tried to initialize module function types twice"
         );
-        let func_types = func_types.into_iter();
         // Note: we use `reserve_exact` instead of `reserve` because this
         //       is the last extension of the vector during the build process
         //       and optimizes conversion to boxed slice.
-        self.func_types.reserve_exact(func_types.len());
-        for func_type in func_types {
-            let func_type = func_type?;
-            let dedup = self.engine.alloc_func_type(func_type);
-            self.func_types.push(dedup)
-        }
-        Ok(())
+        self.func_types.reserve_exact(additional);
+    }
+
+    /// Pushes the types of the given recursion group to the [`Module`] under construction.
+    ///
+    /// # Note
+    ///
+    /// Recursion groups are pushed one by one since later types may refer
+    /// to earlier ones via typed references.
+    /// The `group` must be in its canonical form, see [`TypeResolver::with_rec_group`].
+    ///
+    /// [`TypeResolver::with_rec_group`]: super::utils::TypeResolver::with_rec_group
+    pub fn push_rec_group(&mut self, group: Box<[SubType]>) {
+        let types = self.engine.alloc_rec_group(group);
+        self.func_types.extend(types)
     }
 
     /// Pushes the given imports to the [`Module`] under construction.
@@ -256,7 +260,7 @@ impl ModuleHeaderBuilder {
     /// If this function has already been called on the same [`ModuleBuilder`].
     pub fn push_tables<T>(&mut self, tables: T) -> Result<(), Error>
     where
-        T: IntoIterator<Item = Result<TableType, Error>>,
+        T: IntoIterator<Item = Result<(TableType, Option<ConstExpr>), Error>>,
         <T as IntoIterator>::IntoIter: ExactSizeIterator,
     {
         assert_eq!(
@@ -269,9 +273,11 @@ impl ModuleHeaderBuilder {
         //       is the last extension of the vector during the build process
         //       and optimizes conversion to boxed slice.
         self.tables.reserve_exact(tables.len());
+        self.tables_init.reserve_exact(tables.len());
         for table in tables {
-            let table = table?;
-            self.tables.push(table);
+            let (table_type, table_init) = table?;
+            self.tables.push(table_type);
+            self.tables_init.push(table_init);
         }
         Ok(())
     }
@@ -307,6 +313,34 @@ impl ModuleHeaderBuilder {
         Ok(())
     }
 
+    /// Pushes the given tags to the [`Module`] under construction.
+    ///
+    /// # Errors
+    ///
+    /// If the tags fail to be constructed from the Wasm module.
+    ///
+    /// # Panics
+    ///
+    /// If this function has already been called on the same [`ModuleBuilder`].
+    pub fn push_tags<T>(&mut self, tags: T) -> Result<(), Error>
+    where
+        T: IntoIterator<Item = Result<u32, Error>>,
+        <T as IntoIterator>::IntoIter: ExactSizeIterator,
+    {
+        assert!(
+            self.tags.is_empty(),
+            "tried to initialize module tag declarations twice"
+        );
+        let tags = tags.into_iter();
+        self.tags.reserve_exact(tags.len());
+        for tag in tags {
+            let func_type_idx = tag?;
+            let func_type = self.func_types[func_type_idx as usize];
+            self.tags.push(func_type);
+        }
+        Ok(())
+    }
+
     /// Pushes the given global variables to the [`Module`] under construction.
     ///
     /// # Errors
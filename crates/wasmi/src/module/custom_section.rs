@@ -1,24 +1,54 @@
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use core::{slice, str};
 
+#[cfg(feature = "dwarf")]
+use super::dwarf::{DebugInfo, DebugInfoBuilder};
+
 /// Wasm custom sections.
 #[derive(Default, Debug)]
 pub struct CustomSections {
     inner: CustomSectionsInner,
+    /// The function names of the Wasm `name` custom section sorted by function index.
+    func_names: Box<[(u32, Box<str>)]>,
+    /// The DWARF debug information of the `.debug_*` custom sections if any.
+    #[cfg(feature = "dwarf")]
+    debug_info: Option<DebugInfo>,
 }
 
 impl CustomSections {
     /// Returns an iterator over the [`CustomSection`]s stored in `self`.
     #[inline]
-    pub fn iter(&self) -> CustomSectionsIter {
+    pub fn iter(&self) -> CustomSectionsIter<'_> {
         self.inner.iter()
     }
+
+    /// Returns the name of the function at `func_index` if any.
+    ///
+    /// The name is taken from the Wasm `name` custom section.
+    pub fn func_name(&self, func_index: u32) -> Option<&str> {
+        let index = self
+            .func_names
+            .binary_search_by_key(&func_index, |(index, _)| *index)
+            .ok()?;
+        Some(&self.func_names[index].1)
+    }
+
+    /// Returns the DWARF debug information if any.
+    #[cfg(feature = "dwarf")]
+    pub fn debug_info(&self) -> Option<&DebugInfo> {
+        self.debug_info.as_ref()
+    }
 }
 
 /// A builder for [`CustomSections`].
 #[derive(Default, Debug)]
 pub struct CustomSectionsBuilder {
     inner: CustomSectionsInner,
+    /// The function names of the Wasm `name` custom section.
+    func_names: Vec<(u32, Box<str>)>,
+    /// The DWARF debug information of the `.debug_*` custom sections.
+    #[cfg(feature = "dwarf")]
+    debug_info: DebugInfoBuilder,
 }
 
 impl CustomSectionsBuilder {
@@ -28,10 +58,30 @@ impl CustomSectionsBuilder {
         self.inner.push(name, data);
     }
 
+    /// Pushes the `name` of the function at `func_index` from the Wasm `name` custom section.
+    pub fn push_func_name(&mut self, func_index: u32, name: &str) {
+        self.func_names.push((func_index, name.into()));
+    }
+
+    /// Pushes a `.debug_*` custom section holding DWARF debug information.
+    ///
+    /// The `code_section_offset` is the offset of the Wasm code section contents.
+    #[cfg(feature = "dwarf")]
+    pub fn push_debug_section(&mut self, code_section_offset: usize, name: &str, data: &[u8]) {
+        self.debug_info.push(code_section_offset, name, data);
+    }
+
     /// Finalize construction of the [`CustomSections`].
     #[inline]
-    pub fn finish(self) -> CustomSections {
-        CustomSections { inner: self.inner }
+    pub fn finish(mut self) -> CustomSections {
+        self.func_names.sort_by_key(|(index, _)| *index);
+        self.func_names.dedup_by_key(|(index, _)| *index);
+        CustomSections {
+            inner: self.inner,
+            func_names: self.func_names.into(),
+            #[cfg(feature = "dwarf")]
+            debug_info: self.debug_info.finish(),
+        }
     }
 }
 
@@ -68,7 +118,7 @@ impl CustomSectionsInner {
 
     /// Returns an iterator over the [`CustomSection`]s stored in `self`.
     #[inline]
-    pub fn iter(&self) -> CustomSectionsIter {
+    pub fn iter(&self) -> CustomSectionsIter<'_> {
         CustomSectionsIter {
             items: self.items.iter(),
             names_and_data: &self.names_and_data[..],
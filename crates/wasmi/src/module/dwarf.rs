@@ -0,0 +1,151 @@
+use crate::backtrace::FrameSymbol;
+use addr2line::Context;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::fmt;
+use gimli::{Dwarf, EndianArcSlice, LittleEndian, SectionId};
+use spin::Mutex;
+
+/// The DWARF reader type used to symbolize Wasm binary offsets.
+type Reader = EndianArcSlice<LittleEndian>;
+
+/// A `.debug_*` custom section of a Wasm module and its contents.
+type DebugSection = (Box<str>, Arc<[u8]>);
+
+/// The DWARF debug information of a Wasm module.
+///
+/// # Note
+///
+/// The DWARF debug information is lazily loaded upon its first use
+/// since this is costly and usually only required when a trap occurs.
+pub struct DebugInfo {
+    /// The offset of the Wasm code section contents within the Wasm module binary.
+    ///
+    /// DWARF addresses of Wasm modules are relative to this offset.
+    code_section_offset: usize,
+    /// The `.debug_*` custom sections of the Wasm module.
+    sections: Box<[DebugSection]>,
+    /// The lazily loaded symbolization context.
+    context: Mutex<LazyContext>,
+}
+
+/// A lazily loaded DWARF symbolization context.
+enum LazyContext {
+    /// The context has not yet been loaded.
+    Uninit,
+    /// The context has been loaded successfully.
+    Loaded(Box<Context<Reader>>),
+    /// Loading the context failed due to malformed DWARF debug information.
+    Failed,
+}
+
+impl fmt::Debug for DebugInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DebugInfo")
+            .field("code_section_offset", &self.code_section_offset)
+            .field(
+                "sections",
+                &self
+                    .sections
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl DebugInfo {
+    /// Returns `true` if `name` refers to a Wasm custom section holding DWARF debug information.
+    pub fn is_debug_section(name: &str) -> bool {
+        name.starts_with(".debug_")
+    }
+
+    /// Returns the [`FrameSymbol`]s of the Wasm operator at `module_offset`.
+    ///
+    /// # Note
+    ///
+    /// - Returns multiple [`FrameSymbol`]s if the Wasm operator belongs to inlined
+    ///   functions, ordered from the innermost to the outermost function.
+    /// - Returns no [`FrameSymbol`]s if the DWARF debug information is malformed
+    ///   or does not cover `module_offset`.
+    pub fn symbolize(&self, module_offset: usize) -> Vec<FrameSymbol> {
+        let mut symbols = Vec::new();
+        let Some(address) = module_offset.checked_sub(self.code_section_offset) else {
+            return symbols;
+        };
+        let mut context = self.context.lock();
+        if let LazyContext::Uninit = *context {
+            *context = self.load();
+        }
+        let LazyContext::Loaded(context) = &*context else {
+            return symbols;
+        };
+        let Ok(mut frames) = context.find_frames(address as u64).skip_all_loads() else {
+            return symbols;
+        };
+        while let Ok(Some(frame)) = frames.next() {
+            let name = frame
+                .function
+                .as_ref()
+                .and_then(|name| name.demangle().ok())
+                .map(Box::from);
+            let (file, line, column) = match frame.location {
+                Some(location) => (location.file.map(Box::from), location.line, location.column),
+                None => (None, None, None),
+            };
+            symbols.push(FrameSymbol::new(name, file, line, column));
+        }
+        symbols
+    }
+
+    /// Loads the symbolization context from the DWARF debug sections.
+    #[cold]
+    fn load(&self) -> LazyContext {
+        let dwarf = Dwarf::load(|id: SectionId| -> Result<Reader, gimli::Error> {
+            let data = self
+                .sections
+                .iter()
+                .find(|(name, _)| &**name == id.name())
+                .map(|(_, data)| data.clone())
+                .unwrap_or_else(|| Arc::from([]));
+            Ok(Reader::new(data, LittleEndian))
+        });
+        match dwarf.and_then(Context::from_dwarf) {
+            Ok(context) => LazyContext::Loaded(Box::new(context)),
+            Err(_) => LazyContext::Failed,
+        }
+    }
+}
+
+/// A builder for [`DebugInfo`].
+#[derive(Debug, Default)]
+pub struct DebugInfoBuilder {
+    /// The offset of the Wasm code section contents within the Wasm module binary.
+    code_section_offset: usize,
+    /// The `.debug_*` custom sections of the Wasm module.
+    sections: Vec<DebugSection>,
+}
+
+impl DebugInfoBuilder {
+    /// Pushes a `.debug_*` Wasm custom section to the [`DebugInfoBuilder`].
+    ///
+    /// The `code_section_offset` is the offset of the Wasm code section contents.
+    pub fn push(&mut self, code_section_offset: usize, name: &str, data: &[u8]) {
+        self.code_section_offset = code_section_offset;
+        self.sections.push((name.into(), data.into()));
+    }
+
+    /// Finalizes construction of the [`DebugInfo`].
+    ///
+    /// Returns `None` if no DWARF debug sections have been pushed.
+    pub fn finish(self) -> Option<DebugInfo> {
+        if self.sections.is_empty() {
+            return None;
+        }
+        Some(DebugInfo {
+            code_section_offset: self.code_section_offset,
+            sections: self.sections.into(),
+            context: Mutex::new(LazyContext::Uninit),
+        })
+    }
+}
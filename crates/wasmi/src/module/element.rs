@@ -1,4 +1,4 @@
-use super::{ConstExpr, TableIdx};
+use super::{utils::TypeResolver, ConstExpr, TableIdx};
 use crate::core::ValType;
 use alloc::boxed::Box;
 
@@ -67,8 +67,9 @@ impl From<wasmparser::ElementKind<'_>> for ElementSegmentKind {
     }
 }
 
-impl From<wasmparser::Element<'_>> for ElementSegment {
-    fn from(element: wasmparser::Element<'_>) -> Self {
+impl ElementSegment {
+    /// Creates a new [`ElementSegment`] from the given `wasmparser` element segment.
+    pub fn from_wasmparser(element: wasmparser::Element<'_>, resolver: &TypeResolver) -> Self {
         let kind = ElementSegmentKind::from(element.kind);
         let (items, ty) = match element.items {
             wasmparser::ElementItems::Functions(items) => {
@@ -82,17 +83,13 @@ impl From<wasmparser::Element<'_>> for ElementSegment {
                 (items, ValType::FuncRef)
             }
             wasmparser::ElementItems::Expressions(ref_ty, items) => {
-                let ty = match ref_ty {
-                    ty if ty.is_func_ref() => ValType::FuncRef,
-                    ty if ty.is_extern_ref() => ValType::ExternRef,
-                    _ => panic!("unsupported Wasm reference type"),
-                };
+                let ty = resolver.ref_type(ref_ty);
                 let items = items
                     .into_iter()
                     .map(|item| {
                         item.unwrap_or_else(|error| panic!("failed to parse element item: {error}"))
                     })
-                    .map(ConstExpr::new)
+                    .map(|item| ConstExpr::from_wasmparser(item, resolver))
                     .collect::<Box<[_]>>();
                 (items, ty)
             }
@@ -1,5 +1,11 @@
 use super::GlobalIdx;
-use crate::{collections::map::Iter as MapIter, Error, ExternType, Module};
+use crate::{
+    collections::map::Iter as MapIter,
+    engine::TranslationError,
+    Error,
+    ExternType,
+    Module,
+};
 use alloc::boxed::Box;
 
 /// The index of a function declaration within a [`Module`].
@@ -95,7 +101,7 @@ impl ExternIdx {
             wasmparser::ExternalKind::Memory => Ok(ExternIdx::Memory(MemoryIdx(index))),
             wasmparser::ExternalKind::Global => Ok(ExternIdx::Global(GlobalIdx::from(index))),
             wasmparser::ExternalKind::Tag => {
-                panic!("wasmi does not support the `exception-handling` Wasm proposal")
+                Err(Error::from(TranslationError::UnsupportedExternalTag))
             }
         }
     }
@@ -1,4 +1,4 @@
-use super::ConstExpr;
+use super::{utils::TypeResolver, ConstExpr};
 use crate::GlobalType;
 
 /// The index of a global variable within a [`Module`].
@@ -36,10 +36,11 @@ pub struct Global {
     init_expr: ConstExpr,
 }
 
-impl From<wasmparser::Global<'_>> for Global {
-    fn from(global: wasmparser::Global<'_>) -> Self {
-        let global_type = GlobalType::from_wasmparser(global.ty);
-        let init_expr = ConstExpr::new(global.init_expr);
+impl Global {
+    /// Creates a new [`Global`] from the given `wasmparser` global variable definition.
+    pub fn from_wasmparser(global: wasmparser::Global<'_>, resolver: &TypeResolver) -> Self {
+        let global_type = GlobalType::from_wasmparser(global.ty, resolver);
+        let init_expr = ConstExpr::from_wasmparser(global.init_expr, resolver);
         Self {
             global_type,
             init_expr,
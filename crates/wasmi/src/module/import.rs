@@ -1,4 +1,5 @@
-use crate::{GlobalType, MemoryType, TableType};
+use super::utils::TypeResolver;
+use crate::{engine::TranslationError, Error, GlobalType, MemoryType, TableType};
 use alloc::boxed::Box;
 use core::fmt::{self, Display};
 use wasmparser::TypeRef;
@@ -59,18 +60,24 @@ impl ImportName {
     }
 }
 
-impl From<wasmparser::Import<'_>> for Import {
-    fn from(import: wasmparser::Import) -> Self {
+impl Import {
+    /// Creates a new [`Import`] from the given `wasmparser` import.
+    ///
+    /// # Errors
+    ///
+    /// If the `import` refers to a Wasm tag which is unsupported by Wasmi.
+    pub fn from_wasmparser(
+        import: wasmparser::Import,
+        resolver: &TypeResolver,
+    ) -> Result<Self, Error> {
         let kind = match import.ty {
             TypeRef::Func(ty) => ExternTypeIdx::Func(ty.into()),
-            TypeRef::Table(ty) => ExternTypeIdx::Table(TableType::from_wasmparser(ty)),
+            TypeRef::Table(ty) => ExternTypeIdx::Table(TableType::from_wasmparser(ty, resolver)),
             TypeRef::Memory(ty) => ExternTypeIdx::Memory(MemoryType::from_wasmparser(ty)),
-            TypeRef::Global(ty) => ExternTypeIdx::Global(GlobalType::from_wasmparser(ty)),
-            TypeRef::Tag(tag) => panic!(
-                "wasmi does not support the `exception-handling` Wasm proposal but found: {tag:?}"
-            ),
+            TypeRef::Global(ty) => ExternTypeIdx::Global(GlobalType::from_wasmparser(ty, resolver)),
+            TypeRef::Tag(_) => return Err(Error::from(TranslationError::UnsupportedExternalTag)),
         };
-        Self::new(import.module, import.name, kind)
+        Ok(Self::new(import.module, import.name, kind))
     }
 }
 
@@ -6,15 +6,20 @@
 //!
 //! [`s1vm`]: https://github.com/Neopallium/s1vm
 
-use super::FuncIdx;
+use super::{utils::TypeResolver, FuncIdx};
 use crate::{
-    core::{UntypedVal, F32, F64},
+    core::{TypeId, UntypedVal, F32, F64},
+    gc::AnyRef,
+    store::StoreInner,
+    ContRef,
     ExternRef,
+    Func,
     FuncRef,
+    Global,
     Val,
 };
-use alloc::boxed::Box;
-use core::fmt;
+use alloc::{boxed::Box, vec::Vec};
+use core::{cell::RefCell, fmt};
 use smallvec::SmallVec;
 use wasmparser::AbstractHeapType;
 
@@ -32,6 +37,14 @@ pub trait EvalContext {
     fn get_global(&self, index: u32) -> Option<Val>;
     /// Returns the [`FuncRef`] of the function at `index` if any.
     fn get_func(&self, index: u32) -> Option<FuncRef>;
+    /// Allocates a new struct object of type `ty` with `fields` if possible.
+    fn alloc_struct(&self, ty: TypeId, fields: Box<[UntypedVal]>) -> Option<UntypedVal>;
+    /// Allocates a new array object of type `ty` with `elements` if possible.
+    fn alloc_array(&self, ty: TypeId, elements: Box<[UntypedVal]>) -> Option<UntypedVal>;
+    /// Converts the `externref` into an `anyref` as in Wasm `any.convert_extern` if possible.
+    fn any_convert_extern(&self, externref: UntypedVal) -> Option<UntypedVal>;
+    /// Converts the `anyref` into an `externref` as in Wasm `extern.convert_any` if possible.
+    fn extern_convert_any(&self, anyref: UntypedVal) -> Option<UntypedVal>;
 }
 
 /// An empty evaluation context.
@@ -45,6 +58,22 @@ impl EvalContext for EmptyEvalContext {
     fn get_func(&self, _index: u32) -> Option<FuncRef> {
         None
     }
+
+    fn alloc_struct(&self, _ty: TypeId, _fields: Box<[UntypedVal]>) -> Option<UntypedVal> {
+        None
+    }
+
+    fn alloc_array(&self, _ty: TypeId, _elements: Box<[UntypedVal]>) -> Option<UntypedVal> {
+        None
+    }
+
+    fn any_convert_extern(&self, _externref: UntypedVal) -> Option<UntypedVal> {
+        None
+    }
+
+    fn extern_convert_any(&self, _anyref: UntypedVal) -> Option<UntypedVal> {
+        None
+    }
 }
 
 /// An input parameter to a [`ConstExpr`] operator.
@@ -119,6 +148,10 @@ impl Eval for FuncRefOp {
 /// - `i64.add`
 /// - `i64.sub`
 /// - `i64.mul`
+/// - `ref.i31`
+/// - `struct.new` and `struct.new_default`
+/// - `array.new`, `array.new_default` and `array.new_fixed`
+/// - `any.convert_extern` and `extern.convert_any`
 #[allow(clippy::type_complexity)]
 pub struct ExprOp {
     /// The underlying closure that implements the expression.
@@ -215,6 +248,24 @@ impl ConstExpr {
     /// The constructor assumes that Wasm validation already succeeded
     /// on the input Wasm [`ConstExpr`].
     pub fn new(expr: wasmparser::ConstExpr<'_>) -> Self {
+        Self::new_impl(expr, None)
+    }
+
+    /// Creates a new [`ConstExpr`] from the given Wasm [`ConstExpr`] that may allocate objects.
+    ///
+    /// # Note
+    ///
+    /// The `resolver` resolves the types of the Wasm `gc` proposal allocation operators.
+    pub fn from_wasmparser(expr: wasmparser::ConstExpr<'_>, resolver: &TypeResolver) -> Self {
+        Self::new_impl(expr, Some(resolver))
+    }
+
+    /// Creates a new [`ConstExpr`] from the given Wasm [`ConstExpr`] and optional `resolver`.
+    ///
+    /// # Panics
+    ///
+    /// If `expr` contains operators of the Wasm `gc` proposal but `resolver` is `None`.
+    fn new_impl(expr: wasmparser::ConstExpr<'_>, resolver: Option<&TypeResolver>) -> Self {
         /// A buffer required for translation of Wasm const expressions.
         type TranslationBuffer = SmallVec<[Op; 3]>;
         /// Convenience function to create the various expression operators.
@@ -246,6 +297,31 @@ impl ConstExpr {
             stack.push(op);
         }
 
+        /// Pops the top-most `n` operators from the `stack` in order.
+        fn pop_n(stack: &mut TranslationBuffer, n: usize) -> Vec<Op> {
+            let start = stack
+                .len()
+                .checked_sub(n)
+                .expect("must have enough operators on the stack due to Wasm validation");
+            stack.drain(start..).collect()
+        }
+        /// Convenience function to create unary expression operators.
+        fn unary_op(
+            stack: &mut TranslationBuffer,
+            expr: fn(&dyn EvalContext, UntypedVal) -> Option<UntypedVal>,
+        ) {
+            let input = stack
+                .pop()
+                .expect("must have input operator on the stack due to Wasm validation");
+            stack.push(Op::expr(move |ctx| expr(ctx, input.eval(ctx)?)));
+        }
+        /// Returns the engine-wide [`TypeId`] of the type at module `type_index`.
+        fn type_id(resolver: Option<&TypeResolver>, type_index: u32) -> TypeId {
+            resolver
+                .expect("must have a type resolver for Wasm `gc` operators")
+                .type_id(type_index)
+        }
+
         let mut reader = expr.get_operators_reader();
         let mut stack = TranslationBuffer::new();
         loop {
@@ -272,12 +348,20 @@ impl ConstExpr {
                     let value = match hty {
                         wasmparser::HeapType::Abstract {
                             shared: false,
-                            ty: AbstractHeapType::Func,
+                            ty: AbstractHeapType::Func | AbstractHeapType::NoFunc,
                         } => Val::from(FuncRef::null()),
                         wasmparser::HeapType::Abstract {
                             shared: false,
-                            ty: AbstractHeapType::Extern,
+                            ty: AbstractHeapType::Extern | AbstractHeapType::NoExtern,
                         } => Val::from(ExternRef::null()),
+                        wasmparser::HeapType::Abstract {
+                            shared: false,
+                            ty: AbstractHeapType::Cont | AbstractHeapType::NoCont,
+                        } => Val::from(ContRef::null()),
+                        wasmparser::HeapType::Abstract { shared: false, .. } => {
+                            Val::from(AnyRef::null())
+                        }
+                        wasmparser::HeapType::Concrete(_) => Val::from(FuncRef::null()),
                         invalid => {
                             panic!("encountered invalid heap type for `ref.null`: {invalid:?}")
                         }
@@ -293,6 +377,75 @@ impl ConstExpr {
                 wasmparser::Operator::I64Add => expr_op(&mut stack, UntypedVal::i64_add),
                 wasmparser::Operator::I64Sub => expr_op(&mut stack, UntypedVal::i64_sub),
                 wasmparser::Operator::I64Mul => expr_op(&mut stack, UntypedVal::i64_mul),
+                wasmparser::Operator::RefI31 => unary_op(&mut stack, |_ctx, value| {
+                    Some(AnyRef::from_i31(i32::from(value)).into())
+                }),
+                wasmparser::Operator::AnyConvertExtern => {
+                    unary_op(&mut stack, |ctx, value| ctx.any_convert_extern(value))
+                }
+                wasmparser::Operator::ExternConvertAny => {
+                    unary_op(&mut stack, |ctx, value| ctx.extern_convert_any(value))
+                }
+                wasmparser::Operator::StructNew { struct_type_index } => {
+                    let ty = type_id(resolver, struct_type_index);
+                    let len_fields = resolver
+                        .map(|resolver| resolver.len_struct_fields(struct_type_index))
+                        .unwrap_or_default();
+                    let fields = pop_n(&mut stack, len_fields);
+                    stack.push(Op::expr(move |ctx| {
+                        let fields = fields
+                            .iter()
+                            .map(|field| field.eval(ctx))
+                            .collect::<Option<Box<[_]>>>()?;
+                        ctx.alloc_struct(ty, fields)
+                    }));
+                }
+                wasmparser::Operator::StructNewDefault { struct_type_index } => {
+                    let ty = type_id(resolver, struct_type_index);
+                    let len_fields = resolver
+                        .map(|resolver| resolver.len_struct_fields(struct_type_index))
+                        .unwrap_or_default();
+                    stack.push(Op::expr(move |ctx| {
+                        let fields = (0..len_fields).map(|_| UntypedVal::default()).collect();
+                        ctx.alloc_struct(ty, fields)
+                    }));
+                }
+                wasmparser::Operator::ArrayNew { array_type_index } => {
+                    let ty = type_id(resolver, array_type_index);
+                    let [value, len] = <[Op; 2]>::try_from(pop_n(&mut stack, 2))
+                        .unwrap_or_else(|_| unreachable!("popped exactly 2 operators"));
+                    stack.push(Op::expr(move |ctx| {
+                        let value = value.eval(ctx)?;
+                        let len = u32::from(len.eval(ctx)?);
+                        let elements = (0..len).map(|_| value).collect();
+                        ctx.alloc_array(ty, elements)
+                    }));
+                }
+                wasmparser::Operator::ArrayNewDefault { array_type_index } => {
+                    let ty = type_id(resolver, array_type_index);
+                    let len = stack
+                        .pop()
+                        .expect("must have length operator on the stack due to Wasm validation");
+                    stack.push(Op::expr(move |ctx| {
+                        let len = u32::from(len.eval(ctx)?);
+                        let elements = (0..len).map(|_| UntypedVal::default()).collect();
+                        ctx.alloc_array(ty, elements)
+                    }));
+                }
+                wasmparser::Operator::ArrayNewFixed {
+                    array_type_index,
+                    array_size,
+                } => {
+                    let ty = type_id(resolver, array_type_index);
+                    let elements = pop_n(&mut stack, array_size as usize);
+                    stack.push(Op::expr(move |ctx| {
+                        let elements = elements
+                            .iter()
+                            .map(|element| element.eval(ctx))
+                            .collect::<Option<Box<[_]>>>()?;
+                        ctx.alloc_array(ty, elements)
+                    }));
+                }
                 wasmparser::Operator::End => break,
                 op => panic!("encountered invalid Wasm const expression operator: {op:?}"),
             };
@@ -343,38 +496,73 @@ impl ConstExpr {
 
     /// Evaluates the [`ConstExpr`] given a context for globals and functions.
     ///
-    /// Returns `None` if a non-const expression operand is encountered
-    /// or the provided globals and functions context returns `None`.
+    /// Returns `None` if a non-const expression operand is encountered.
     ///
     /// # Note
     ///
-    /// This is useful for evaluation of [`ConstExpr`] during bytecode execution.
-    pub fn eval_with_context<G, F>(&self, global_get: G, func_get: F) -> Option<UntypedVal>
+    /// - This is useful for evaluation of [`ConstExpr`] during module instantiation.
+    /// - Objects of the Wasm `gc` proposal are allocated in the `store`.
+    pub fn eval_with_context<G, F>(
+        &self,
+        store: &mut StoreInner,
+        global_get: G,
+        func_get: F,
+    ) -> Option<UntypedVal>
     where
-        G: Fn(u32) -> Val,
-        F: Fn(u32) -> FuncRef,
+        G: Fn(u32) -> Global,
+        F: Fn(u32) -> Func,
     {
-        /// Context that wraps closures representing partial evaluation contexts.
-        struct WrappedEvalContext<G, F> {
+        /// Context that wraps the [`StoreInner`] and closures resolving module entities.
+        struct StoreEvalContext<'a, G, F> {
+            /// The store in which globals are resolved and objects are allocated.
+            store: RefCell<&'a mut StoreInner>,
             /// Wrapped context for global variables.
             global_get: G,
             /// Wrapped context for functions.
             func_get: F,
         }
-        impl<G, F> EvalContext for WrappedEvalContext<G, F>
+        impl<G, F> EvalContext for StoreEvalContext<'_, G, F>
         where
-            G: Fn(u32) -> Val,
-            F: Fn(u32) -> FuncRef,
+            G: Fn(u32) -> Global,
+            F: Fn(u32) -> Func,
         {
             fn get_global(&self, index: u32) -> Option<Val> {
-                Some((self.global_get)(index))
+                let global = (self.global_get)(index);
+                Some(self.store.borrow().resolve_global(&global).get())
             }
 
             fn get_func(&self, index: u32) -> Option<FuncRef> {
-                Some((self.func_get)(index))
+                Some(FuncRef::new((self.func_get)(index)))
+            }
+
+            fn alloc_struct(&self, ty: TypeId, fields: Box<[UntypedVal]>) -> Option<UntypedVal> {
+                Some(
+                    self.store
+                        .borrow_mut()
+                        .gc_heap_mut()
+                        .alloc_struct(ty, fields),
+                )
+            }
+
+            fn alloc_array(&self, ty: TypeId, elements: Box<[UntypedVal]>) -> Option<UntypedVal> {
+                Some(
+                    self.store
+                        .borrow_mut()
+                        .gc_heap_mut()
+                        .alloc_array(ty, elements),
+                )
+            }
+
+            fn any_convert_extern(&self, externref: UntypedVal) -> Option<UntypedVal> {
+                Some(self.store.borrow_mut().any_convert_extern(externref))
+            }
+
+            fn extern_convert_any(&self, anyref: UntypedVal) -> Option<UntypedVal> {
+                Some(self.store.borrow_mut().extern_convert_any(anyref))
             }
         }
-        self.eval(&WrappedEvalContext::<G, F> {
+        self.eval(&StoreEvalContext::<G, F> {
+            store: RefCell::new(store),
             global_get,
             func_get,
         })
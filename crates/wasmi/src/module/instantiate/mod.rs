@@ -17,7 +17,6 @@ use crate::{
     Error,
     Extern,
     ExternType,
-    FuncRef,
     Global,
     Instance,
     InstanceEntity,
@@ -131,7 +130,7 @@ impl Module {
                 }
                 (ExternType::Global(required), Extern::Global(global)) => {
                     let imported = global.ty(&store);
-                    required.satisfies(&imported)?;
+                    imported.satisfies(required)?;
                     builder.push_global(global);
                 }
                 (expected_import, actual_extern_val) => {
@@ -182,9 +181,15 @@ impl Module {
             .as_context_mut()
             .store
             .check_new_tables_limit(self.len_tables())?;
-        for table_type in self.internal_tables().copied() {
-            let init = Val::default(table_type.element());
-            let table = Table::new(context.as_context_mut(), table_type, init)?;
+        for (table_type, table_init) in self.internal_tables() {
+            let init = match table_init {
+                Some(init_expr) => {
+                    Self::eval_init_expr(context.as_context_mut(), builder, init_expr)
+                        .with_type(table_type.element())
+                }
+                None => Val::default(table_type.element()),
+            };
+            let table = Table::new(context.as_context_mut(), *table_type, init)?;
             builder.push_table(table);
         }
         Ok(())
@@ -220,26 +225,29 @@ impl Module {
         for (global_type, global_init) in self.internal_globals() {
             let value_type = global_type.content();
             let init_value = Self::eval_init_expr(context.as_context_mut(), builder, global_init);
-            let mutability = global_type.mutability();
-            let global = Global::new(
+            let global = Global::new_typed(
                 context.as_context_mut(),
+                *global_type,
                 init_value.with_type(value_type),
-                mutability,
-            );
+            )
+            .unwrap_or_else(|error| {
+                panic!("encountered invalid global initializer after validation: {error}")
+            });
             builder.push_global(global);
         }
     }
 
     /// Evaluates the given initializer expression using the partially constructed [`Instance`].
     fn eval_init_expr(
-        context: impl AsContext,
+        mut context: impl AsContextMut,
         builder: &InstanceEntityBuilder,
         init_expr: &ConstExpr,
     ) -> UntypedVal {
         init_expr
             .eval_with_context(
-                |global_index| builder.get_global(global_index).get(&context),
-                |func_index| FuncRef::new(builder.get_func(func_index)),
+                &mut context.as_context_mut().store.inner,
+                |global_index| builder.get_global(global_index),
+                |func_index| builder.get_func(func_index),
             )
             .expect("must evaluate to proper value")
     }
@@ -293,7 +301,7 @@ impl Module {
                 ElementSegment::new(context.as_context_mut(), segment, get_func, get_global);
             if let ElementSegmentKind::Active(active) = segment.kind() {
                 let dst_index = u32::from(Self::eval_init_expr(
-                    context.as_context(),
+                    context.as_context_mut(),
                     builder,
                     active.offset(),
                 ));
@@ -338,9 +346,11 @@ impl Module {
                     offset,
                     bytes,
                 } => {
-                    let offset =
-                        u32::from(Self::eval_init_expr(context.as_context(), builder, offset))
-                            as usize;
+                    let offset = u32::from(Self::eval_init_expr(
+                        context.as_context_mut(),
+                        builder,
+                        offset,
+                    )) as usize;
                     let memory = builder.get_memory(memory_index.into_u32());
                     memory.write(context.as_context_mut(), offset, bytes)?;
                     DataSegment::new_active(context.as_context_mut())
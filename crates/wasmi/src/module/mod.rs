@@ -1,6 +1,8 @@
 mod builder;
 mod custom_section;
 mod data;
+#[cfg(feature = "dwarf")]
+mod dwarf;
 mod element;
 mod export;
 mod global;
@@ -34,8 +36,10 @@ pub(crate) use self::{
     utils::WasmiValueType,
 };
 use crate::{
+    backtrace::FrameSymbol,
     collections::Map,
     engine::{DedupFuncType, EngineFunc, EngineFuncSpan, EngineFuncSpanIter, EngineWeak},
+    ir::IrFunc,
     Engine,
     Error,
     ExternType,
@@ -44,8 +48,13 @@ use crate::{
     MemoryType,
     TableType,
 };
-use alloc::{boxed::Box, sync::Arc};
-use core::{iter, slice::Iter as SliceIter};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{iter, mem, slice::Iter as SliceIter};
 use wasmparser::{FuncValidatorAllocations, Parser, ValidPayload, Validator};
 
 /// A parsed and validated WebAssembly module.
@@ -76,9 +85,11 @@ struct ModuleHeaderInner {
     imports: ModuleImports,
     funcs: Box<[DedupFuncType]>,
     tables: Box<[TableType]>,
+    tables_init: Box<[Option<ConstExpr>]>,
     memories: Box<[MemoryType]>,
     globals: Box<[GlobalType]>,
     globals_init: Box<[ConstExpr]>,
+    tags: Box<[DedupFuncType]>,
     exports: Map<Box<str>, ExternIdx>,
     start: Option<FuncIdx>,
     engine_funcs: EngineFuncSpan,
@@ -96,6 +107,11 @@ impl ModuleHeader {
         &self.inner.func_types[func_type_idx.into_u32() as usize]
     }
 
+    /// Returns the deduplicated types of the Wasm module in order.
+    pub fn func_types(&self) -> &[DedupFuncType] {
+        &self.inner.func_types
+    }
+
     /// Returns the [`FuncType`] of the indexed function.
     pub fn get_type_of_func(&self, func_idx: FuncIdx) -> &DedupFuncType {
         &self.inner.funcs[func_idx.into_u32() as usize]
@@ -106,6 +122,16 @@ impl ModuleHeader {
         &self.inner.globals[global_idx.into_u32() as usize]
     }
 
+    /// Returns the [`DedupFuncType`] of the indexed tag if any.
+    pub fn get_type_of_tag(&self, tag_idx: u32) -> Option<&DedupFuncType> {
+        self.inner.tags.get(tag_idx as usize)
+    }
+
+    /// Returns the [`MemoryType`] of the indexed linear memory if any.
+    pub fn get_type_of_memory(&self, memory_idx: u32) -> Option<&MemoryType> {
+        self.inner.memories.get(memory_idx as usize)
+    }
+
     /// Returns the [`EngineFunc`] for the given [`FuncIdx`].
     ///
     /// Returns `None` if [`FuncIdx`] refers to an imported function.
@@ -329,6 +355,41 @@ impl Module {
         Ok(())
     }
 
+    /// Returns the function index of the compiled `func` within the [`Module`] if any.
+    pub(crate) fn get_func_index(&self, func: EngineFunc) -> Option<u32> {
+        self.inner
+            .header
+            .get_func_index(func)
+            .map(FuncIdx::into_u32)
+    }
+
+    /// Returns the name of the function at `func_index` if any.
+    ///
+    /// # Note
+    ///
+    /// Names are only available if the Wasm `name` custom section has been
+    /// parsed which requires [`Config::wasm_backtrace`] to be enabled.
+    ///
+    /// [`Config::wasm_backtrace`]: crate::Config::wasm_backtrace
+    pub fn get_func_name(&self, func_index: u32) -> Option<&str> {
+        self.inner.custom_sections.func_name(func_index)
+    }
+
+    /// Returns the source-level [`FrameSymbol`]s of the Wasm operator at `module_offset`.
+    ///
+    /// # Note
+    ///
+    /// This requires the `dwarf` crate feature and the Wasm module to contain
+    /// DWARF debug information. Otherwise no [`FrameSymbol`]s are returned.
+    #[cfg_attr(not(feature = "dwarf"), allow(unused_variables))]
+    pub(crate) fn get_frame_symbols(&self, module_offset: usize) -> Vec<FrameSymbol> {
+        #[cfg(feature = "dwarf")]
+        if let Some(debug_info) = self.inner.custom_sections.debug_info() {
+            return debug_info.symbolize(module_offset);
+        }
+        Vec::new()
+    }
+
     /// Returns the number of non-imported functions of the [`Module`].
     pub(crate) fn len_funcs(&self) -> usize {
         self.module_header().funcs.len()
@@ -356,7 +417,7 @@ impl Module {
     }
 
     /// Returns an iterator over the imports of the [`Module`].
-    pub fn imports(&self) -> ModuleImportsIter {
+    pub fn imports(&self) -> ModuleImportsIter<'_> {
         let header = self.module_header();
         let len_imported_funcs = header.imports.len_funcs;
         let len_imported_globals = header.imports.len_globals;
@@ -373,7 +434,7 @@ impl Module {
     /// Returns an iterator over the internally defined [`Func`].
     ///
     /// [`Func`]: [`crate::Func`]
-    pub(crate) fn internal_funcs(&self) -> InternalFuncsIter {
+    pub(crate) fn internal_funcs(&self) -> InternalFuncsIter<'_> {
         let header = self.module_header();
         let len_imported = header.imports.len_funcs;
         // We skip the first `len_imported` elements in `funcs`
@@ -388,7 +449,7 @@ impl Module {
     }
 
     /// Returns an iterator over the [`MemoryType`] of internal linear memories.
-    fn internal_memories(&self) -> SliceIter<MemoryType> {
+    fn internal_memories(&self) -> SliceIter<'_, MemoryType> {
         let header = self.module_header();
         let len_imported = header.imports.len_memories;
         // We skip the first `len_imported` elements in `memories`
@@ -399,18 +460,21 @@ impl Module {
     }
 
     /// Returns an iterator over the [`TableType`] of internal tables.
-    fn internal_tables(&self) -> SliceIter<TableType> {
+    fn internal_tables(&self) -> InternalTablesIter<'_> {
         let header = self.module_header();
         let len_imported = header.imports.len_tables;
-        // We skip the first `len_imported` elements in `memories`
+        // We skip the first `len_imported` elements in `tables`
         // since they refer to imported and not internally defined
-        // linear memories.
-        let tables = &header.tables[len_imported..];
-        tables.iter()
+        // tables.
+        let tables = header.tables[len_imported..].iter();
+        let tables_init = header.tables_init.iter();
+        InternalTablesIter {
+            iter: tables.zip(tables_init),
+        }
     }
 
     /// Returns an iterator over the internally defined [`Global`].
-    fn internal_globals(&self) -> InternalGlobalsIter {
+    fn internal_globals(&self) -> InternalGlobalsIter<'_> {
         let header = self.module_header();
         let len_imported = header.imports.len_globals;
         // We skip the first `len_imported` elements in `globals`
@@ -424,7 +488,7 @@ impl Module {
     }
 
     /// Returns an iterator over the exports of the [`Module`].
-    pub fn exports(&self) -> ModuleExportsIter {
+    pub fn exports(&self) -> ModuleExportsIter<'_> {
         ModuleExportsIter::new(self)
     }
 
@@ -479,9 +543,123 @@ impl Module {
     ///
     /// [`Config::ignore_custom_sections`]: crate::Config::ignore_custom_sections
     #[inline]
-    pub fn custom_sections(&self) -> CustomSectionsIter {
+    pub fn custom_sections(&self) -> CustomSectionsIter<'_> {
         self.inner.custom_sections.iter()
     }
+
+    /// Returns the index of the start function of the [`Module`] if any.
+    pub fn start_func(&self) -> Option<u32> {
+        self.module_header().start.map(FuncIdx::into_u32)
+    }
+
+    /// Returns an iterator over the [`FuncType`] of all functions of the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// The functions are yielded in index order and thus imported functions come first.
+    pub fn funcs(&self) -> impl ExactSizeIterator<Item = FuncType> + '_ {
+        let engine = self.engine();
+        self.module_header()
+            .funcs
+            .iter()
+            .map(|func_type| engine.resolve_func_type(func_type, FuncType::clone))
+    }
+
+    /// Returns an iterator over the [`TableType`] of all tables of the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// The tables are yielded in index order and thus imported tables come first.
+    pub fn tables(&self) -> impl ExactSizeIterator<Item = &TableType> {
+        self.module_header().tables.iter()
+    }
+
+    /// Returns an iterator over the [`MemoryType`] of all linear memories of the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// The linear memories are yielded in index order and thus imported linear memories come first.
+    pub fn memories(&self) -> impl ExactSizeIterator<Item = &MemoryType> {
+        self.module_header().memories.iter()
+    }
+
+    /// Returns an iterator over the [`GlobalType`] of all global variables of the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// The global variables are yielded in index order and thus imported global variables come first.
+    pub fn globals(&self) -> impl ExactSizeIterator<Item = &GlobalType> {
+        self.module_header().globals.iter()
+    }
+
+    /// Returns the size in bytes of the Wasmi bytecode of the function at `func_index`.
+    ///
+    /// The size includes the function local constant values of the translated function.
+    ///
+    /// Returns `Ok(None)` if `func_index` refers to an imported function or is out of bounds.
+    ///
+    /// # Note
+    ///
+    /// - This translates the function if it has not yet been translated,
+    ///   for example when using [`CompilationMode::Lazy`].
+    /// - With the `compact-bytecode` crate feature this decodes the function
+    ///   just like its first call does and returns its decoded size.
+    ///
+    /// # Errors
+    ///
+    /// If translation or Wasm validation of the function failed.
+    ///
+    /// [`CompilationMode::Lazy`]: crate::CompilationMode::Lazy
+    pub fn compiled_func_size(&self, func_index: u32) -> Result<Option<usize>, Error> {
+        if func_index as usize >= self.module_header().funcs.len() {
+            return Ok(None);
+        }
+        let header = &self.inner.header;
+        let Some(engine_func) = header.get_engine_func(FuncIdx::from(func_index)) else {
+            return Ok(None);
+        };
+        let _epoch = self.engine().enter_epoch();
+        let compiled = self.engine().get_compiled_func(engine_func)?;
+        let size = mem::size_of_val(compiled.instrs()) + mem::size_of_val(compiled.consts());
+        Ok(Some(size))
+    }
+
+    /// Returns the human readable disassembly of the Wasmi bytecode of the function at `func_index`.
+    ///
+    /// The disassembly lists the number of registers, the function local constant values
+    /// and the instructions of the translated function with their branch targets resolved.
+    ///
+    /// Returns `Ok(None)` if `func_index` refers to an imported function or is out of bounds.
+    ///
+    /// # Note
+    ///
+    /// - The disassembly format is meant for humans and is not stable.
+    /// - This translates the function if it has not yet been translated,
+    ///   for example when using [`CompilationMode::Lazy`].
+    ///
+    /// # Errors
+    ///
+    /// If translation or Wasm validation of the function failed.
+    ///
+    /// [`CompilationMode::Lazy`]: crate::CompilationMode::Lazy
+    pub fn disassemble(&self, func_index: u32) -> Result<Option<String>, Error> {
+        if func_index as usize >= self.module_header().funcs.len() {
+            return Ok(None);
+        }
+        let header = &self.inner.header;
+        let Some(engine_func) = header.get_engine_func(FuncIdx::from(func_index)) else {
+            return Ok(None);
+        };
+        let _epoch = self.engine().enter_epoch();
+        let compiled = self.engine().get_compiled_func(engine_func)?;
+        // Note: function local constant values are stored in reverse allocation order.
+        let ir = IrFunc::new(
+            compiled.len_registers(),
+            compiled.consts().iter().rev().copied(),
+            compiled.instrs().iter().copied(),
+        );
+        Ok(Some(ir.to_string()))
+    }
 }
 
 /// An iterator over the imports of a [`Module`].
@@ -615,6 +793,32 @@ impl ExactSizeIterator for InternalFuncsIter<'_> {
     }
 }
 
+/// An iterator over the internally defined tables of a [`Module`].
+#[derive(Debug)]
+pub struct InternalTablesIter<'a> {
+    iter: iter::Zip<SliceIter<'a, TableType>, SliceIter<'a, Option<ConstExpr>>>,
+}
+
+impl<'a> Iterator for InternalTablesIter<'a> {
+    type Item = (&'a TableType, Option<&'a ConstExpr>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(table_type, table_init)| (table_type, table_init.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl ExactSizeIterator for InternalTablesIter<'_> {
+    fn len(&self) -> usize {
+        ExactSizeIterator::len(&self.iter)
+    }
+}
+
 /// An iterator over the internally defined functions of a [`Module`].
 #[derive(Debug)]
 pub struct InternalGlobalsIter<'a> {
@@ -3,6 +3,8 @@ use super::{
     export::ExternIdx,
     global::Global,
     import::{FuncTypeIdx, Import},
+    utils::{self, TypeResolver},
+    ConstExpr,
     CustomSectionsBuilder,
     ElementSegment,
     FuncIdx,
@@ -10,14 +12,13 @@ use super::{
     ModuleHeader,
 };
 use crate::{
-    engine::{EnforcedLimitsError, EngineFunc},
+    engine::{EnforcedLimitsError, EngineFunc, SubType},
     Engine,
     Error,
-    FuncType,
     MemoryType,
     TableType,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::ops::Range;
 use wasmparser::{
     CustomSectionReader,
@@ -25,24 +26,92 @@ use wasmparser::{
     ElementSectionReader,
     Encoding,
     ExportSectionReader,
+    FuncToValidate,
     FunctionBody,
     FunctionSectionReader,
     GlobalSectionReader,
     ImportSectionReader,
+    KnownCustom,
     MemorySectionReader,
+    Name,
     Parser as WasmParser,
     Payload,
     TableSectionReader,
+    TagSectionReader,
     TypeSectionReader,
     Validator,
+    ValidatorResources,
 };
 
+#[cfg(feature = "dwarf")]
+use super::dwarf::DebugInfo;
+
+#[cfg(feature = "std")]
+use crate::engine::ReusableAllocationStack;
+
 #[cfg(doc)]
 use crate::Module;
 
 mod buffered;
+#[cfg(feature = "std")]
+mod parallel;
 mod streaming;
 
+/// A Wasm function body that is prepared for its translation.
+struct CodeEntry<'a> {
+    /// The index of the function within its Wasm module.
+    func: FuncIdx,
+    /// The index of the function within the [`Engine`].
+    engine_func: EngineFunc,
+    /// The offset of the function body within the Wasm binary.
+    offset: usize,
+    /// The Wasm bytes of the function body.
+    bytes: &'a [u8],
+    /// The function body validator if the Wasm module is validated.
+    func_to_validate: Option<FuncToValidate<ValidatorResources>>,
+}
+
+impl CodeEntry<'_> {
+    /// Translates the [`CodeEntry`] using the `engine`.
+    ///
+    /// # Errors
+    ///
+    /// If the function body fails to validate or translate.
+    fn translate(self, engine: &Engine, header: &ModuleHeader) -> Result<(), Error> {
+        engine.translate_func(
+            self.func,
+            self.engine_func,
+            self.offset,
+            self.bytes,
+            header.clone(),
+            self.func_to_validate,
+        )
+    }
+
+    /// Translates the [`CodeEntry`] using the `engine` and the reusable `allocs`.
+    ///
+    /// # Errors
+    ///
+    /// If the function body fails to validate or translate.
+    #[cfg(feature = "std")]
+    fn translate_with_allocs(
+        self,
+        engine: &Engine,
+        header: &ModuleHeader,
+        allocs: &mut ReusableAllocationStack,
+    ) -> Result<(), Error> {
+        engine.translate_func_with_allocs(
+            self.func,
+            self.engine_func,
+            self.offset,
+            self.bytes,
+            header.clone(),
+            self.func_to_validate,
+            allocs,
+        )
+    }
+}
+
 /// Context used to construct a WebAssembly module from a stream of bytes.
 pub struct ModuleParser {
     /// The engine used for translation.
@@ -55,6 +124,11 @@ pub struct ModuleParser {
     engine_funcs: u32,
     /// Flag, `true` when `stream` is at the end.
     eof: bool,
+    /// The offset of the Wasm code section contents within the Wasm binary.
+    ///
+    /// This is required to resolve DWARF debug information addresses.
+    #[cfg(feature = "dwarf")]
+    code_section_offset: usize,
 }
 
 impl ModuleParser {
@@ -67,6 +141,8 @@ impl ModuleParser {
             parser,
             engine_funcs: 0,
             eof: false,
+            #[cfg(feature = "dwarf")]
+            code_section_offset: 0,
         }
     }
 
@@ -112,24 +188,41 @@ impl ModuleParser {
             validator.type_section(&section)?;
         }
         let limits = self.engine.config().get_enforced_limits();
-        let func_types = section.into_iter().map(|result| {
-            let ty = result?.into_types().next().unwrap();
-            let func_ty = ty.unwrap_func();
-            if let Some(limit) = limits.max_params {
-                if func_ty.params().len() > limit {
-                    return Err(Error::from(EnforcedLimitsError::TooManyParameters {
-                        limit,
-                    }));
+        header.reserve_func_types(section.count() as usize);
+        for result in section {
+            let rec_group = result?;
+            let sub_types = rec_group.types().collect::<Vec<_>>();
+            for sub_type in &sub_types {
+                utils::ensure_supported_sub_type(sub_type)?;
+                let wasmparser::CompositeInnerType::Func(func_ty) = &sub_type.composite_type.inner
+                else {
+                    continue;
+                };
+                if let Some(limit) = limits.max_params {
+                    if func_ty.params().len() > limit {
+                        return Err(Error::from(EnforcedLimitsError::TooManyParameters {
+                            limit,
+                        }));
+                    }
                 }
-            }
-            if let Some(limit) = limits.max_results {
-                if func_ty.results().len() > limit {
-                    return Err(Error::from(EnforcedLimitsError::TooManyResults { limit }));
+                if let Some(limit) = limits.max_results {
+                    if func_ty.results().len() > limit {
+                        return Err(Error::from(EnforcedLimitsError::TooManyResults { limit }));
+                    }
                 }
             }
-            Ok(FuncType::from_wasmparser(func_ty))
-        });
-        header.push_func_types(func_types)?;
+            let kinds = sub_types
+                .iter()
+                .map(|sub_type| utils::type_kind(sub_type))
+                .collect::<Vec<_>>();
+            let resolver =
+                TypeResolver::new(&self.engine, &header.func_types).with_rec_group(&kinds);
+            let group = sub_types
+                .iter()
+                .map(|sub_type| SubType::from_wasmparser(sub_type, &resolver))
+                .collect::<Box<[_]>>();
+            header.push_rec_group(group);
+        }
         Ok(())
     }
 
@@ -151,9 +244,21 @@ impl ModuleParser {
         if let Some(validator) = &mut self.validator {
             validator.import_section(&section)?;
         }
-        let imports = section
-            .into_iter()
-            .map(|import| import.map(Import::from).map_err(Error::from));
+        let func_types = header.func_types.clone();
+        let resolver = TypeResolver::new(&self.engine, &func_types);
+        let imports = section.into_iter().map(|import| {
+            let import = import?;
+            match import.ty {
+                wasmparser::TypeRef::Global(ty) => {
+                    utils::ensure_supported_val_type(ty.content_type)?
+                }
+                wasmparser::TypeRef::Table(ty) => {
+                    utils::ensure_supported_val_type(ty.element_type.into())?
+                }
+                _ => {}
+            }
+            Import::from_wasmparser(import, &resolver)
+        });
         header.push_imports(imports)?;
         Ok(())
     }
@@ -209,12 +314,19 @@ impl ModuleParser {
         if let Some(validator) = &mut self.validator {
             validator.table_section(&section)?;
         }
-        let tables = section.into_iter().map(|table| match table {
-            Ok(table) => {
-                assert!(matches!(table.init, wasmparser::TableInit::RefNull));
-                Ok(TableType::from_wasmparser(table.ty))
-            }
-            Err(err) => Err(err.into()),
+        let func_types = header.func_types.clone();
+        let resolver = TypeResolver::new(&self.engine, &func_types);
+        let tables = section.into_iter().map(|table| {
+            let table = table?;
+            utils::ensure_supported_val_type(table.ty.element_type.into())?;
+            let table_type = TableType::from_wasmparser(table.ty, &resolver);
+            let table_init = match table.init {
+                wasmparser::TableInit::RefNull => None,
+                wasmparser::TableInit::Expr(expr) => {
+                    Some(ConstExpr::from_wasmparser(expr, &resolver))
+                }
+            };
+            Ok((table_type, table_init))
         });
         header.push_tables(tables)?;
         Ok(())
@@ -271,13 +383,41 @@ impl ModuleParser {
         if let Some(validator) = &mut self.validator {
             validator.global_section(&section)?;
         }
-        let globals = section
-            .into_iter()
-            .map(|global| global.map(Global::from).map_err(Error::from));
+        let func_types = header.func_types.clone();
+        let resolver = TypeResolver::new(&self.engine, &func_types);
+        let globals = section.into_iter().map(|global| {
+            let global = global?;
+            utils::ensure_supported_val_type(global.ty.content_type)?;
+            Ok(Global::from_wasmparser(global, &resolver))
+        });
         header.push_globals(globals)?;
         Ok(())
     }
 
+    /// Process module tag declarations.
+    ///
+    /// # Note
+    ///
+    /// This extracts all tag declarations into the [`Module`] under construction.
+    ///
+    /// # Errors
+    ///
+    /// If a tag declaration fails to validate.
+    fn process_tags(
+        &mut self,
+        section: TagSectionReader,
+        header: &mut ModuleHeaderBuilder,
+    ) -> Result<(), Error> {
+        if let Some(validator) = &mut self.validator {
+            validator.tag_section(&section)?;
+        }
+        let tags = section
+            .into_iter()
+            .map(|tag| tag.map(|tag| tag.func_type_idx).map_err(Error::from));
+        header.push_tags(tags)?;
+        Ok(())
+    }
+
     /// Process module export declarations.
     ///
     /// # Note
@@ -356,9 +496,15 @@ impl ModuleParser {
         if let Some(validator) = &mut self.validator {
             validator.element_section(&section)?;
         }
-        let segments = section
-            .into_iter()
-            .map(|segment| segment.map(ElementSegment::from).map_err(Error::from));
+        let func_types = header.func_types.clone();
+        let resolver = TypeResolver::new(&self.engine, &func_types);
+        let segments = section.into_iter().map(|segment| {
+            let segment = segment?;
+            if let wasmparser::ElementItems::Expressions(ty, _) = &segment.items {
+                utils::ensure_supported_val_type((*ty).into())?;
+            }
+            Ok(ElementSegment::from_wasmparser(segment, &resolver))
+        });
         header.push_element_segments(segments)?;
         Ok(())
     }
@@ -455,6 +601,10 @@ impl ModuleParser {
         if let Some(validator) = &mut self.validator {
             validator.code_section_start(count, &range)?;
         }
+        #[cfg(feature = "dwarf")]
+        {
+            self.code_section_offset = range.start;
+        }
         Ok(())
     }
 
@@ -488,16 +638,38 @@ impl ModuleParser {
         bytes: &[u8],
         header: &ModuleHeader,
     ) -> Result<(), Error> {
+        self.prepare_code_entry(func_body, bytes, header)?
+            .translate(&self.engine, header)
+    }
+
+    /// Prepares a single module code section entry for its translation.
+    ///
+    /// # Note
+    ///
+    /// Code section entries must be prepared in the order of their appearance.
+    ///
+    /// # Errors
+    ///
+    /// If the function body fails to validate.
+    fn prepare_code_entry<'a>(
+        &mut self,
+        func_body: FunctionBody,
+        bytes: &'a [u8],
+        header: &ModuleHeader,
+    ) -> Result<CodeEntry<'a>, Error> {
         let (func, engine_func) = self.next_func(header);
-        let module = header.clone();
         let offset = func_body.get_binary_reader().original_position();
         let func_to_validate = match &mut self.validator {
             Some(validator) => Some(validator.code_section_entry(&func_body)?),
             None => None,
         };
-        self.engine
-            .translate_func(func, engine_func, offset, bytes, module, func_to_validate)?;
-        Ok(())
+        Ok(CodeEntry {
+            func,
+            engine_func,
+            offset,
+            bytes,
+            func_to_validate,
+        })
     }
 
     /// Process a single Wasm custom section.
@@ -506,6 +678,17 @@ impl ModuleParser {
         custom_sections: &mut CustomSectionsBuilder,
         reader: CustomSectionReader,
     ) -> Result<(), Error> {
+        if self.engine.config().get_wasm_backtrace() {
+            Self::process_name_section(custom_sections, &reader);
+            #[cfg(feature = "dwarf")]
+            if DebugInfo::is_debug_section(reader.name()) {
+                custom_sections.push_debug_section(
+                    self.code_section_offset,
+                    reader.name(),
+                    reader.data(),
+                );
+            }
+        }
         if self.engine.config().get_ignore_custom_sections() {
             return Ok(());
         }
@@ -513,6 +696,29 @@ impl ModuleParser {
         Ok(())
     }
 
+    /// Extracts the function names of the Wasm `name` custom section if `reader` refers to it.
+    ///
+    /// # Note
+    ///
+    /// Malformed `name` sections are silently ignored since they
+    /// must not cause the parsing of the Wasm module to fail.
+    fn process_name_section(
+        custom_sections: &mut CustomSectionsBuilder,
+        reader: &CustomSectionReader,
+    ) {
+        let KnownCustom::Name(names) = reader.as_known() else {
+            return;
+        };
+        for name in names {
+            let Ok(Name::Function(func_names)) = name else {
+                continue;
+            };
+            for naming in func_names.into_iter().flatten() {
+                custom_sections.push_func_name(naming.index, naming.name);
+            }
+        }
+    }
+
     /// Process an unexpected, unsupported or malformed Wasm module section payload.
     fn process_invalid_payload(&mut self, payload: Payload<'_>) -> Result<(), Error> {
         if let Some(validator) = &mut self.validator {
@@ -8,6 +8,11 @@ use super::{
 use crate::{Error, Module};
 use wasmparser::{Chunk, Payload, Validator};
 
+#[cfg(feature = "std")]
+use super::CodeEntry;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
 impl ModuleParser {
     /// Starts parsing and validating the Wasm bytecode stream.
     ///
@@ -116,6 +121,7 @@ impl ModuleParser {
                 Payload::TableSection(section) => self.process_tables(section, &mut header),
                 Payload::MemorySection(section) => self.process_memories(section, &mut header),
                 Payload::GlobalSection(section) => self.process_globals(section, &mut header),
+                Payload::TagSection(section) => self.process_tags(section, &mut header),
                 Payload::ExportSection(section) => self.process_exports(section, &mut header),
                 Payload::StartSection { func, range } => {
                     self.process_start(func, range, &mut header)
@@ -154,6 +160,11 @@ impl ModuleParser {
         header: ModuleHeader,
         custom_sections: CustomSectionsBuilder,
     ) -> Result<ModuleBuilder, Error> {
+        #[cfg(feature = "std")]
+        if self.engine.config().get_translation_threads() > 1 {
+            self.parse_buffered_code_parallel(buffer, &header)?;
+            return Ok(ModuleBuilder::new(header, custom_sections));
+        }
         loop {
             let (consumed, payload) = self.next_payload(buffer)?;
             match payload {
@@ -172,6 +183,60 @@ impl ModuleParser {
         Ok(ModuleBuilder::new(header, custom_sections))
     }
 
+    /// Parse the Wasm code section entries and translate them in parallel.
+    ///
+    /// # Note
+    ///
+    /// - Code section entries are parsed and prepared sequentially on the calling thread
+    ///   while the much more expensive validation and translation of the function bodies
+    ///   is distributed across [`Config::translation_threads`] threads.
+    /// - The results are identical to sequential translation, including the reported error
+    ///   which is the error of the first function body in the Wasm module that failed.
+    ///
+    /// # Errors
+    ///
+    /// If the Wasm bytecode stream fails to parse, validate or translate.
+    ///
+    /// [`Config::translation_threads`]: crate::Config::translation_threads
+    #[cfg(feature = "std")]
+    fn parse_buffered_code_parallel(
+        &mut self,
+        buffer: &mut &[u8],
+        header: &ModuleHeader,
+    ) -> Result<(), Error> {
+        let mut entries = Vec::new();
+        let parsed = self.parse_buffered_code_entries(buffer, header, &mut entries);
+        self.translate_parallel(entries, header)?;
+        parsed
+    }
+
+    /// Parses and prepares the Wasm code section entries and pushes them to `entries`.
+    ///
+    /// # Note
+    ///
+    /// The `entries` that have been prepared before an error occurred are kept.
+    ///
+    /// # Errors
+    ///
+    /// If the Wasm bytecode stream fails to parse or validate.
+    #[cfg(feature = "std")]
+    fn parse_buffered_code_entries<'a>(
+        &mut self,
+        buffer: &mut &'a [u8],
+        header: &ModuleHeader,
+        entries: &mut Vec<CodeEntry<'a>>,
+    ) -> Result<(), Error> {
+        loop {
+            let (consumed, payload) = self.next_payload(buffer)?;
+            let Payload::CodeSectionEntry(func_body) = payload else {
+                return Ok(());
+            };
+            Self::consume_buffer(consumed, buffer);
+            let bytes = func_body.as_bytes();
+            entries.push(self.prepare_code_entry(func_body, bytes, header)?);
+        }
+    }
+
     /// Parse the Wasm data section and finalize parsing.
     ///
     /// We separate parsing of the Wasm data section since it is the only Wasm
@@ -0,0 +1,63 @@
+use super::{CodeEntry, ModuleHeader, ModuleParser};
+use crate::{engine::ReusableAllocationStack, Error};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use spin::Mutex;
+use std::{thread, vec::Vec};
+
+impl ModuleParser {
+    /// Translates all `entries` in parallel.
+    ///
+    /// Each worker thread uses its own reusable allocations for translation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first entry in `entries` that failed to validate or translate.
+    pub(super) fn translate_parallel(
+        &self,
+        entries: Vec<CodeEntry>,
+        header: &ModuleHeader,
+    ) -> Result<(), Error> {
+        let threads = self.engine.config().get_translation_threads();
+        let engine = &self.engine;
+        // Note: every entry is taken by exactly one worker and thus its lock is never contended.
+        let entries: Vec<Mutex<Option<CodeEntry>>> =
+            entries.into_iter().map(Some).map(Mutex::new).collect();
+        let next = AtomicUsize::new(0);
+        let first_failed = AtomicUsize::new(usize::MAX);
+        let failure: Mutex<Option<(usize, Error)>> = Mutex::new(None);
+        let worker = || {
+            let mut allocs = ReusableAllocationStack::default();
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(entry) = entries.get(index) else {
+                    return;
+                };
+                if first_failed.load(Ordering::Relaxed) < index {
+                    // Note: entries are dequeued in order and thus all remaining entries
+                    //       come after an entry that already failed.
+                    return;
+                }
+                let Some(entry) = entry.lock().take() else {
+                    unreachable!("entry {index} has already been translated")
+                };
+                if let Err(error) = entry.translate_with_allocs(engine, header, &mut allocs) {
+                    first_failed.fetch_min(index, Ordering::Relaxed);
+                    let mut failure = failure.lock();
+                    if failure.as_ref().map_or(true, |(failed, _)| index < *failed) {
+                        *failure = Some((index, error));
+                    }
+                }
+            }
+        };
+        thread::scope(|scope| {
+            for _ in 1..threads {
+                scope.spawn(worker);
+            }
+            worker();
+        });
+        match failure.into_inner() {
+            Some((_, error)) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
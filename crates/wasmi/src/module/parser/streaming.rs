@@ -177,6 +177,7 @@ impl ModuleParser {
                         Payload::GlobalSection(section) => {
                             self.process_globals(section, &mut header)
                         }
+                        Payload::TagSection(section) => self.process_tags(section, &mut header),
                         Payload::ExportSection(section) => {
                             self.process_exports(section, &mut header)
                         }
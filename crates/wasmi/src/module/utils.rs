@@ -1,6 +1,17 @@
 use wasmparser::AbstractHeapType;
 
-use crate::{core::ValType, FuncType, GlobalType, MemoryType, Mutability, TableType};
+use crate::{
+    core::{HeapType, Pages, RefType, TypeId, TypeKind, ValType},
+    engine::{CompositeType, DedupFuncType, FuncTypeRegistry, SubType, TranslationError},
+    gc::{ArrayType, FieldType, StorageType, StructType},
+    Engine,
+    Error,
+    FuncType,
+    GlobalType,
+    MemoryType,
+    Mutability,
+    TableType,
+};
 
 impl TableType {
     /// Creates a new [`TableType`] from the given `wasmparser` primitive.
@@ -9,8 +20,11 @@ impl TableType {
     ///
     /// We do not use the `From` trait here so that this conversion
     /// routine does not become part of the public API of [`TableType`].
-    pub(crate) fn from_wasmparser(table_type: wasmparser::TableType) -> Self {
-        let element = WasmiValueType::from(table_type.element_type).into_inner();
+    pub(crate) fn from_wasmparser(
+        table_type: wasmparser::TableType,
+        resolver: &TypeResolver,
+    ) -> Self {
+        let element = resolver.ref_type(table_type.element_type);
         let minimum: u32 = table_type
             .initial
             .try_into()
@@ -53,7 +67,13 @@ impl MemoryType {
             .map(TryInto::try_into)
             .transpose()
             .expect("wasm32 memories must have a valid u32 maximum size if any");
-        Self::new(initial, maximum)
+        let page_size_log2: u8 = match memory_type.page_size_log2 {
+            Some(page_size_log2) => page_size_log2
+                .try_into()
+                .expect("custom page sizes must be valid after validation"),
+            None => Pages::DEFAULT_PAGE_SIZE_LOG2,
+        };
+        Self::new_with_page_size(initial, maximum, page_size_log2)
             .expect("encountered invalid wasmparser::MemoryType after validation")
     }
 }
@@ -65,8 +85,11 @@ impl GlobalType {
     ///
     /// We do not use the `From` trait here so that this conversion
     /// routine does not become part of the public API of [`GlobalType`].
-    pub(crate) fn from_wasmparser(global_type: wasmparser::GlobalType) -> Self {
-        let value_type = WasmiValueType::from(global_type.content_type).into_inner();
+    pub(crate) fn from_wasmparser(
+        global_type: wasmparser::GlobalType,
+        resolver: &TypeResolver,
+    ) -> Self {
+        let value_type = resolver.val_type(global_type.content_type);
         let mutability = match global_type.mutable {
             true => Mutability::Var,
             false => Mutability::Const,
@@ -82,21 +105,276 @@ impl FuncType {
     ///
     /// We do not use the `From` trait here so that this conversion
     /// routine does not become part of the public API of [`FuncType`].
-    pub(crate) fn from_wasmparser(func_type: &wasmparser::FuncType) -> Self {
-        /// Returns the [`ValType`] from the given [`wasmparser::Type`].
-        ///
-        /// # Panics
-        ///
-        /// If the [`wasmparser::Type`] is not supported by Wasmi.
-        fn extract_value_type(value_type: &wasmparser::ValType) -> ValType {
-            WasmiValueType::from(*value_type).into_inner()
-        }
-        let params = func_type.params().iter().map(extract_value_type);
-        let results = func_type.results().iter().map(extract_value_type);
+    pub(crate) fn from_wasmparser(
+        func_type: &wasmparser::FuncType,
+        resolver: &TypeResolver,
+    ) -> Self {
+        let params = func_type.params().iter().map(|ty| resolver.val_type(*ty));
+        let results = func_type.results().iter().map(|ty| resolver.val_type(*ty));
         Self::new(params, results)
     }
 }
 
+impl FieldType {
+    /// Creates a new [`FieldType`] from the given `wasmparser` primitive.
+    fn from_wasmparser(field_type: &wasmparser::FieldType, resolver: &TypeResolver) -> Self {
+        let storage = match field_type.element_type {
+            wasmparser::StorageType::I8 => StorageType::I8,
+            wasmparser::StorageType::I16 => StorageType::I16,
+            wasmparser::StorageType::Val(ty) => StorageType::Val(resolver.val_type(ty)),
+        };
+        let mutability = match field_type.mutable {
+            true => Mutability::Var,
+            false => Mutability::Const,
+        };
+        Self::new(storage, mutability)
+    }
+}
+
+impl SubType {
+    /// Creates a new [`SubType`] from the given `wasmparser` primitive.
+    ///
+    /// # Note
+    ///
+    /// The `resolver` must be set up for the recursion group of `sub_type`
+    /// via [`TypeResolver::with_rec_group`] so that the result is in canonical form.
+    pub(crate) fn from_wasmparser(sub_type: &wasmparser::SubType, resolver: &TypeResolver) -> Self {
+        let composite = match &sub_type.composite_type.inner {
+            wasmparser::CompositeInnerType::Func(func_type) => {
+                CompositeType::Func(FuncType::from_wasmparser(func_type, resolver))
+            }
+            wasmparser::CompositeInnerType::Struct(struct_type) => {
+                CompositeType::Struct(StructType::new(
+                    struct_type
+                        .fields
+                        .iter()
+                        .map(|field| FieldType::from_wasmparser(field, resolver)),
+                ))
+            }
+            wasmparser::CompositeInnerType::Array(array_type) => CompositeType::Array(
+                ArrayType::new(FieldType::from_wasmparser(&array_type.0, resolver)),
+            ),
+            wasmparser::CompositeInnerType::Cont(cont_type) => {
+                let index = cont_type
+                    .0
+                    .as_module_index()
+                    .unwrap_or_else(|| panic!("encountered non-module type index: {cont_type:?}"));
+                CompositeType::Cont(resolver.type_id(index))
+            }
+        };
+        let supertype = sub_type.supertype_idx.map(|index| {
+            let index = index
+                .as_module_index()
+                .unwrap_or_else(|| panic!("encountered non-module type index: {index:?}"));
+            resolver.type_id(index)
+        });
+        Self {
+            is_final: sub_type.is_final,
+            supertype,
+            composite,
+        }
+    }
+}
+
+/// Returns the [`TypeKind`] of the `wasmparser` sub type.
+pub(crate) fn type_kind(sub_type: &wasmparser::SubType) -> TypeKind {
+    match &sub_type.composite_type.inner {
+        wasmparser::CompositeInnerType::Func(_) => TypeKind::Func,
+        wasmparser::CompositeInnerType::Struct(_) => TypeKind::Struct,
+        wasmparser::CompositeInnerType::Array(_) => TypeKind::Array,
+        wasmparser::CompositeInnerType::Cont(_) => TypeKind::Cont,
+    }
+}
+
+/// Returns an error if `value_type` is an unsupported `exnref` type.
+///
+/// # Note
+///
+/// Wasmi does not support the Wasm `exception-handling` proposal but its types
+/// pass validation whenever the Wasm `stack-switching` proposal is enabled.
+pub(crate) fn ensure_supported_val_type(value_type: wasmparser::ValType) -> Result<(), Error> {
+    match value_type {
+        wasmparser::ValType::Ref(ref_type) => ensure_supported_heap_type(ref_type.heap_type()),
+        _ => Ok(()),
+    }
+}
+
+/// Returns an error if `heap_type` belongs to the unsupported `exn` hierarchy.
+///
+/// For more information read [`ensure_supported_val_type`].
+pub(crate) fn ensure_supported_heap_type(heap_type: wasmparser::HeapType) -> Result<(), Error> {
+    if let wasmparser::HeapType::Abstract {
+        ty: AbstractHeapType::Exn | AbstractHeapType::NoExn,
+        ..
+    } = heap_type
+    {
+        let ref_type = wasmparser::RefType::new(true, heap_type)
+            .expect("abstract heap types always form valid reference types");
+        return Err(Error::from(TranslationError::unsupported_value_type(
+            wasmparser::ValType::Ref(ref_type),
+        )));
+    }
+    Ok(())
+}
+
+/// Returns an error if the `wasmparser` sub type refers to unsupported `exnref` types.
+pub(crate) fn ensure_supported_sub_type(sub_type: &wasmparser::SubType) -> Result<(), Error> {
+    /// Returns the value type of the storage type if any.
+    fn storage_type(field: &wasmparser::FieldType) -> Option<wasmparser::ValType> {
+        match field.element_type {
+            wasmparser::StorageType::Val(value_type) => Some(value_type),
+            _ => None,
+        }
+    }
+    match &sub_type.composite_type.inner {
+        wasmparser::CompositeInnerType::Func(func_type) => func_type
+            .params()
+            .iter()
+            .chain(func_type.results())
+            .try_for_each(|ty| ensure_supported_val_type(*ty)),
+        wasmparser::CompositeInnerType::Struct(struct_type) => struct_type
+            .fields
+            .iter()
+            .filter_map(storage_type)
+            .try_for_each(ensure_supported_val_type),
+        wasmparser::CompositeInnerType::Array(array_type) => {
+            storage_type(&array_type.0).map_or(Ok(()), ensure_supported_val_type)
+        }
+        wasmparser::CompositeInnerType::Cont(_) => Ok(()),
+    }
+}
+
+/// Resolves `wasmparser` types of a Wasm module to Wasmi types.
+///
+/// # Note
+///
+/// Concrete heap types refer to module local type indices
+/// which are resolved to [`Engine`] wide [`TypeId`] identifiers.
+pub struct TypeResolver<'a> {
+    /// The [`Engine`] in which the types are registered.
+    engine: &'a Engine,
+    /// The types of the Wasm module resolved so far.
+    func_types: &'a [DedupFuncType],
+    /// The kinds of the types of the recursion group that is currently resolved if any.
+    ///
+    /// # Note
+    ///
+    /// The recursion group starts at the module type index `func_types.len()`.
+    rec_group: &'a [TypeKind],
+}
+
+impl<'a> TypeResolver<'a> {
+    /// Creates a new [`TypeResolver`] for the resolved `func_types` of a Wasm module.
+    pub fn new(engine: &'a Engine, func_types: &'a [DedupFuncType]) -> Self {
+        Self {
+            engine,
+            func_types,
+            rec_group: &[],
+        }
+    }
+
+    /// Resolves types of the recursion group following the resolved types with the given `kinds`.
+    ///
+    /// References to types of the recursion group are resolved to their canonical form.
+    pub fn with_rec_group(mut self, kinds: &'a [TypeKind]) -> Self {
+        self.rec_group = kinds;
+        self
+    }
+
+    /// Returns the [`TypeId`] of the type at module type `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` refers to a type that has not yet been resolved.
+    pub fn type_id(&self, index: u32) -> TypeId {
+        let resolved = self.func_types.len();
+        match (index as usize).checked_sub(resolved) {
+            None => self.engine.func_type_id(&self.func_types[index as usize]),
+            Some(rel) => {
+                let kind = self
+                    .rec_group
+                    .get(rel)
+                    .unwrap_or_else(|| panic!("encountered unresolved type index: {index}"));
+                FuncTypeRegistry::rec_group_type_id(*kind, rel as u32)
+            }
+        }
+    }
+
+    /// Returns the number of fields of the struct type at module type `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` does not refer to a resolved struct type.
+    pub fn len_struct_fields(&self, index: u32) -> usize {
+        let type_id = self.type_id(index);
+        self.engine
+            .with_types(|types| types.struct_type(type_id).fields().len())
+    }
+
+    /// Returns the [`ValType`] of the `wasmparser` value type.
+    ///
+    /// # Panics
+    ///
+    /// If `value_type` is unsupported or refers to an unresolved type.
+    pub fn val_type(&self, value_type: wasmparser::ValType) -> ValType {
+        match value_type {
+            wasmparser::ValType::Ref(ref_type) => self.ref_type(ref_type),
+            value_type => WasmiValueType::from(value_type).into_inner(),
+        }
+    }
+
+    /// Returns the [`ValType`] of the `wasmparser` reference type.
+    ///
+    /// # Panics
+    ///
+    /// If `ref_type` is unsupported or refers to an unresolved type.
+    pub fn ref_type(&self, ref_type: wasmparser::RefType) -> ValType {
+        let heap_type = self.heap_type(ref_type.heap_type());
+        ValType::from(RefType::new(ref_type.is_nullable(), heap_type))
+    }
+
+    /// Returns the [`HeapType`] of the `wasmparser` heap type.
+    ///
+    /// # Panics
+    ///
+    /// If `heap_type` is unsupported or refers to an unresolved type.
+    pub fn heap_type(&self, heap_type: wasmparser::HeapType) -> HeapType {
+        match heap_type {
+            wasmparser::HeapType::Concrete(index) => {
+                let index = index
+                    .as_module_index()
+                    .unwrap_or_else(|| panic!("encountered non-module type index: {index:?}"));
+                HeapType::Concrete(self.type_id(index))
+            }
+            wasmparser::HeapType::Abstract { shared: false, ty } => abstract_heap_type(ty),
+            unsupported => panic!("encountered unsupported heap type: {unsupported:?}"),
+        }
+    }
+}
+
+/// Returns the [`HeapType`] of the `wasmparser` abstract heap type.
+///
+/// # Panics
+///
+/// If `heap_type` is unsupported by Wasmi.
+fn abstract_heap_type(heap_type: AbstractHeapType) -> HeapType {
+    match heap_type {
+        AbstractHeapType::Func => HeapType::Func,
+        AbstractHeapType::Extern => HeapType::Extern,
+        AbstractHeapType::Any => HeapType::Any,
+        AbstractHeapType::Eq => HeapType::Eq,
+        AbstractHeapType::I31 => HeapType::I31,
+        AbstractHeapType::Struct => HeapType::Struct,
+        AbstractHeapType::Array => HeapType::Array,
+        AbstractHeapType::None => HeapType::None,
+        AbstractHeapType::NoFunc => HeapType::NoFunc,
+        AbstractHeapType::NoExtern => HeapType::NoExtern,
+        AbstractHeapType::Cont => HeapType::Cont,
+        AbstractHeapType::NoCont => HeapType::NoCont,
+        unsupported => panic!("encountered unsupported heap type: {unsupported:?}"),
+    }
+}
+
 /// A Wasmi [`ValType`].
 ///
 /// # Note
@@ -120,28 +398,32 @@ impl From<ValType> for WasmiValueType {
 }
 
 impl From<wasmparser::HeapType> for WasmiValueType {
+    /// Converts the `wasmparser` heap type into a nullable reference type.
+    ///
+    /// # Note
+    ///
+    /// Heap types are erased to the top type of their hierarchy while concrete
+    /// heap types are erased to nullable function references.
+    /// Use a [`TypeResolver`] in order to preserve them.
     fn from(heap_type: wasmparser::HeapType) -> Self {
-        match heap_type {
-            wasmparser::HeapType::Abstract {
-                shared: false,
-                ty: AbstractHeapType::Func,
-            } => Self::from(ValType::FuncRef),
-            wasmparser::HeapType::Abstract {
-                shared: false,
-                ty: AbstractHeapType::Extern,
-            } => Self::from(ValType::ExternRef),
+        let heap_type = match heap_type {
+            wasmparser::HeapType::Concrete(_) => HeapType::Func,
+            wasmparser::HeapType::Abstract { shared: false, ty } => abstract_heap_type(ty).top(),
             unsupported => panic!("encountered unsupported heap type: {unsupported:?}"),
-        }
+        };
+        Self::from(ValType::from(RefType::new(true, heap_type)))
     }
 }
 
 impl From<wasmparser::RefType> for WasmiValueType {
+    /// Converts the `wasmparser` reference type into a nullable reference type.
+    ///
+    /// # Note
+    ///
+    /// Nullability and concrete heap types are erased.
+    /// Use a [`TypeResolver`] in order to preserve them.
     fn from(ref_type: wasmparser::RefType) -> Self {
-        match ref_type {
-            wasmparser::RefType::FUNCREF => Self::from(ValType::FuncRef),
-            wasmparser::RefType::EXTERNREF => Self::from(ValType::ExternRef),
-            unsupported => panic!("encountered unsupported reference type: {unsupported:?}"),
-        }
+        Self::from(ref_type.heap_type())
     }
 }
 
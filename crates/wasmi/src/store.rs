@@ -1,9 +1,11 @@
 use crate::{
     collections::arena::{Arena, ArenaIndex, GuardedEntity},
-    core::TrapCode,
-    engine::{DedupFuncType, FuelCosts},
+    cont::{ContEntity, ContHeap, ContRef},
+    core::{HeapType, RefType, TrapCode, UntypedVal},
+    engine::{CallIndirectCache, DedupFuncType, FuelCosts},
     externref::{ExternObject, ExternObjectEntity, ExternObjectIdx},
-    func::{Trampoline, TrampolineEntity, TrampolineIdx},
+    func::{Trampoline, TrampolineEntity, TrampolineFuel, TrampolineIdx},
+    gc::{AnyRef, ExternalizedAny, GcHeap, GcObjectKind, GcRoots, HostScope},
     memory::{DataSegment, MemoryError},
     module::InstantiationError,
     table::TableError,
@@ -15,9 +17,11 @@ use crate::{
     ElementSegmentIdx,
     Engine,
     Error,
+    ExternRef,
     Func,
     FuncEntity,
     FuncIdx,
+    FuncRef,
     FuncType,
     Global,
     GlobalEntity,
@@ -32,13 +36,17 @@ use crate::{
     Table,
     TableEntity,
     TableIdx,
+    Val,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use core::{
     fmt::{self, Debug},
     sync::atomic::{AtomicU32, Ordering},
 };
 
+#[cfg(feature = "gc")]
+use crate::{core::ValType, gc::StorageType};
+
 /// A unique store index.
 ///
 /// # Note
@@ -76,7 +84,7 @@ pub type Stored<Idx> = GuardedEntity<StoreIdx, Idx>;
 /// A wrapper around an optional `&mut dyn` [`ResourceLimiter`], that exists
 /// both to make types a little easier to read and to provide a `Debug` impl so
 /// that `#[derive(Debug)]` works on structs that contain it.
-pub struct ResourceLimiterRef<'a>(Option<&'a mut (dyn ResourceLimiter)>);
+pub struct ResourceLimiterRef<'a>(Option<&'a mut dyn ResourceLimiter>);
 impl Debug for ResourceLimiterRef<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "ResourceLimiterRef(...)")
@@ -169,6 +177,19 @@ pub struct StoreInner {
     engine: Engine,
     /// The fuel of the [`Store`].
     fuel: Fuel,
+    /// Reusable buffer for the typed parameters of host function calls with dynamic fuel costs.
+    host_fuel_params: Vec<Val>,
+    /// The inline caches of the executed `call_indirect` instructions.
+    ///
+    /// Invalidated whenever a table of the [`Store`] is mutated.
+    call_indirect_cache: CallIndirectCache,
+    /// The garbage collected heap for objects of the Wasm `gc` proposal.
+    gc_heap: GcHeap,
+    /// The continuations of the Wasm `stack-switching` proposal.
+    cont_heap: ContHeap,
+    /// The roots of the garbage collector held by the host, by executions suspended
+    /// in host function calls and by suspended resumable calls.
+    gc_roots: GcRoots,
 }
 
 #[test]
@@ -369,9 +390,10 @@ impl StoreInner {
     /// Creates a new [`StoreInner`] for the given [`Engine`].
     pub fn new(engine: &Engine) -> Self {
         let fuel = Fuel::new(engine.config());
+        let store_idx = StoreIdx::new();
         StoreInner {
             engine: engine.clone(),
-            store_idx: StoreIdx::new(),
+            store_idx,
             funcs: Arena::new(),
             memories: Arena::new(),
             tables: Arena::new(),
@@ -381,6 +403,11 @@ impl StoreInner {
             elems: Arena::new(),
             extern_objects: Arena::new(),
             fuel,
+            host_fuel_params: Vec::new(),
+            call_indirect_cache: CallIndirectCache::default(),
+            gc_heap: GcHeap::new(store_idx.0),
+            cont_heap: ContHeap::default(),
+            gc_roots: GcRoots::default(),
         }
     }
 
@@ -394,6 +421,22 @@ impl StoreInner {
         &mut self.fuel
     }
 
+    /// Consumes the `fuel` charged for a host function call with the untyped `params`.
+    ///
+    /// This does nothing if fuel metering is disabled.
+    ///
+    /// # Errors
+    ///
+    /// - If out of fuel.
+    fn consume_host_func_fuel(
+        &mut self,
+        fuel: &TrampolineFuel,
+        params: &[UntypedVal],
+    ) -> Result<(), TrapCode> {
+        let buffer = &mut self.host_fuel_params;
+        self.fuel.consume_fuel_if(|_| fuel.cost(params, buffer))
+    }
+
     /// Wraps an entity `Idx` (index type) as a [`Stored<Idx>`] type.
     ///
     /// # Note
@@ -605,6 +648,7 @@ impl StoreInner {
     /// - If the [`Table`] cannot be resolved to its entity.
     pub fn resolve_table_mut(&mut self, table: &Table) -> &mut TableEntity {
         let idx = self.unwrap_stored(table.as_inner());
+        self.call_indirect_cache.invalidate();
         Self::resolve_mut(idx, &mut self.tables)
     }
 
@@ -623,6 +667,7 @@ impl StoreInner {
     ) -> (&mut TableEntity, &mut ElementSegmentEntity) {
         let table_idx = self.unwrap_stored(table.as_inner());
         let elem_idx = self.unwrap_stored(elem.as_inner());
+        self.call_indirect_cache.invalidate();
         let table = Self::resolve_mut(table_idx, &mut self.tables);
         let elem = Self::resolve_mut(elem_idx, &mut self.elems);
         (table, elem)
@@ -639,6 +684,7 @@ impl StoreInner {
     /// - If the [`Table`] cannot be resolved to its entity.
     pub fn resolve_table_and_fuel_mut(&mut self, table: &Table) -> (&mut TableEntity, &mut Fuel) {
         let idx = self.unwrap_stored(table.as_inner());
+        self.call_indirect_cache.invalidate();
         let table = Self::resolve_mut(idx, &mut self.tables);
         let fuel = &mut self.fuel;
         (table, fuel)
@@ -657,6 +703,7 @@ impl StoreInner {
     ) -> (&mut TableEntity, &mut TableEntity, &mut Fuel) {
         let fst = self.unwrap_stored(fst.as_inner());
         let snd = self.unwrap_stored(snd.as_inner());
+        self.call_indirect_cache.invalidate();
         let (fst, snd) = self.tables.get_pair_mut(fst, snd).unwrap_or_else(|| {
             panic!("failed to resolve stored pair of entities: {fst:?} and {snd:?}")
         });
@@ -692,11 +739,88 @@ impl StoreInner {
         let mem_idx = self.unwrap_stored(table.as_inner());
         let elem_idx = segment.as_inner();
         let elem = self.resolve(elem_idx, &self.elems);
+        self.call_indirect_cache.invalidate();
         let mem = Self::resolve_mut(mem_idx, &mut self.tables);
         let fuel = &mut self.fuel;
         (mem, elem, fuel)
     }
 
+    /// Returns the [`Func`] at `index` of `table` called by the `call_indirect` at `call_site`.
+    ///
+    /// Uses the inline cache of the `call_site` to skip the table lookup and signature check.
+    ///
+    /// # Errors
+    ///
+    /// - If `index` is out of bounds for `table`.
+    /// - If the function reference at `index` of `table` is `null`.
+    /// - If the signature of the [`Func`] neither is nor is a subtype of `func_type`.
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Table`] does not originate from this [`Store`].
+    /// - If the [`Table`] cannot be resolved to its entity.
+    pub fn resolve_indirect_call(
+        &mut self,
+        call_site: usize,
+        table: &Table,
+        index: u32,
+        func_type: &DedupFuncType,
+    ) -> Result<Func, TrapCode> {
+        let table_idx = self.unwrap_stored(table.as_inner());
+        if let Some(func) = self
+            .call_indirect_cache
+            .get(call_site, table_idx, index, func_type)
+        {
+            return Ok(func);
+        }
+        let funcref = self
+            .resolve_table(table)
+            .get_untyped(index)
+            .map(FuncRef::from)
+            .ok_or(TrapCode::TableOutOfBounds)?;
+        let func = *funcref.func().ok_or(TrapCode::IndirectCallToNull)?;
+        if !self.func_type_matches(self.resolve_func(&func).ty_dedup(), func_type) {
+            return Err(TrapCode::BadSignature);
+        }
+        self.call_indirect_cache
+            .insert(call_site, table_idx, index, *func_type, func);
+        Ok(func)
+    }
+
+    /// Returns the [`Func`] of `funcref` called by a `call_ref` expecting `func_type`.
+    ///
+    /// # Note
+    ///
+    /// Wasm validation guarantees that `funcref` matches `func_type` unless the host
+    /// provided a mismatching function reference which is why the signature is checked.
+    ///
+    /// # Errors
+    ///
+    /// - If `funcref` is `null`.
+    /// - If the signature of the [`Func`] neither is nor is a subtype of `func_type`.
+    pub fn resolve_ref_call(
+        &self,
+        funcref: FuncRef,
+        func_type: &DedupFuncType,
+    ) -> Result<Func, TrapCode> {
+        let func = *funcref.func().ok_or(TrapCode::NullReference)?;
+        if !self.func_type_matches(self.resolve_func(&func).ty_dedup(), func_type) {
+            return Err(TrapCode::BadSignature);
+        }
+        Ok(func)
+    }
+
+    /// Returns `true` if a function of type `actual` may be called as a function of type `expected`.
+    ///
+    /// This is the case if both types are equal or if `actual` is a subtype of `expected`.
+    fn func_type_matches(&self, actual: &DedupFuncType, expected: &DedupFuncType) -> bool {
+        if actual == expected {
+            return true;
+        }
+        self.engine
+            .with_types(|types| types.is_subtype(types.type_id(actual), types.type_id(expected)))
+    }
+
     /// Returns a shared reference to the [`ElementSegmentEntity`] associated to the given [`ElementSegment`].
     ///
     /// # Panics
@@ -839,6 +963,300 @@ impl StoreInner {
         self.resolve(object.as_inner(), &self.extern_objects)
     }
 
+    /// Returns a shared reference to the [`GcHeap`] of the [`StoreInner`].
+    pub fn gc_heap(&self) -> &GcHeap {
+        &self.gc_heap
+    }
+
+    /// Returns an exclusive reference to the [`GcHeap`] of the [`StoreInner`].
+    pub fn gc_heap_mut(&mut self) -> &mut GcHeap {
+        &mut self.gc_heap
+    }
+
+    /// Allocates the continuation `entity` and returns a [`ContRef`] to it.
+    pub fn alloc_cont(&mut self, entity: ContEntity) -> ContRef {
+        self.cont_heap.alloc(entity)
+    }
+
+    /// Consumes the continuation referred to by `contref` and returns it.
+    ///
+    /// Returns `None` if `contref` is `null` or if its continuation has already been consumed.
+    pub fn take_cont(&mut self, contref: ContRef) -> Option<ContEntity> {
+        self.cont_heap.take(contref)
+    }
+
+    /// Returns an iterator over the object handles of the [`GcHeap`] among `values`.
+    fn object_handles<'a>(
+        &'a self,
+        values: impl IntoIterator<Item = UntypedVal> + 'a,
+    ) -> impl Iterator<Item = UntypedVal> + 'a {
+        values
+            .into_iter()
+            .filter(|value| AnyRef::from(*value).is_object() && self.gc_heap.contains(*value))
+    }
+
+    /// Roots the objects referenced by the `values` handed out to the host.
+    ///
+    /// # Note
+    ///
+    /// The objects stay alive until the enclosing host function call or [`Store::gc_scope`]
+    /// returns or until they are released via [`Store::gc_release_host_refs`] outside of
+    /// host function calls.
+    pub fn root_host_vals(&self, values: &[Val]) {
+        if self.gc_heap.is_empty() {
+            return;
+        }
+        let anyrefs = values.iter().filter_map(|value| match value {
+            Val::AnyRef(anyref) => Some(UntypedVal::from(*anyref)),
+            _ => None,
+        });
+        let handles = self.object_handles(anyrefs);
+        self.gc_roots.root_host(handles);
+    }
+
+    /// Enters the scope of a host function call whose caller holds the `values`.
+    ///
+    /// Returns the scope that must be passed to [`StoreInner::exit_host_scope`] once the call returns.
+    ///
+    /// # Note
+    ///
+    /// The objects referenced by `values` are roots of the garbage collector until then.
+    /// This includes the parameters of the host function.
+    pub fn enter_host_scope(&mut self, values: impl IntoIterator<Item = UntypedVal>) -> HostScope {
+        let handles = match self.gc_heap.is_empty() {
+            true => Vec::new(),
+            false => self.object_handles(values).collect(),
+        };
+        self.gc_roots.enter_host_scope(handles)
+    }
+
+    /// Exits the `scope` of a host function call releasing the roots it added.
+    pub fn exit_host_scope(&mut self, scope: HostScope) {
+        self.gc_roots.exit_host_scope(scope);
+    }
+
+    /// Releases the roots of all objects handed out to the host.
+    #[cfg(feature = "gc")]
+    pub fn release_host_roots(&mut self) {
+        self.gc_roots.release_host();
+    }
+
+    /// Roots the objects referenced by the `values` of the stack of a suspended resumable call.
+    ///
+    /// # Note
+    ///
+    /// The objects stay alive until the resumable call is suspended again or until its `token` is dropped.
+    pub fn suspend_resumable(
+        &mut self,
+        token: &Arc<()>,
+        values: impl IntoIterator<Item = UntypedVal>,
+    ) {
+        if self.gc_heap.is_empty() {
+            return;
+        }
+        let handles = self.object_handles(values).collect();
+        self.gc_roots.suspend(token, handles);
+    }
+
+    /// Converts the `externref` into an `anyref` as in Wasm `any.convert_extern`.
+    ///
+    /// # Panics
+    ///
+    /// If `externref` does not originate from this [`Store`].
+    pub fn any_convert_extern(&mut self, externref: UntypedVal) -> UntypedVal {
+        let externref = ExternRef::from(externref);
+        let Some(object) = externref.as_object() else {
+            return UntypedVal::from(AnyRef::null());
+        };
+        if let Some(anyref) = self.externalized_any(object) {
+            return UntypedVal::from(anyref);
+        }
+        self.gc_heap.alloc_extern(externref)
+    }
+
+    /// Converts the `anyref` into an `externref` as in Wasm `extern.convert_any`.
+    pub fn extern_convert_any(&mut self, anyref: UntypedVal) -> UntypedVal {
+        let anyref = AnyRef::from(anyref);
+        if anyref.is_null() {
+            return UntypedVal::from(ExternRef::null());
+        }
+        if anyref.is_object() {
+            let object = self.gc_heap.resolve(anyref.into());
+            if let GcObjectKind::Extern = object.kind() {
+                return object.fields()[0];
+            }
+        }
+        let object = self.alloc_extern_object(ExternObjectEntity::new(ExternalizedAny(anyref)));
+        UntypedVal::from(ExternRef::from_object(object))
+    }
+
+    /// Returns `true` if the reference `value` matches `ref_type` as in Wasm `ref.test`.
+    ///
+    /// # Note
+    ///
+    /// The hierarchy of `value` is determined by `ref_type` which is guaranteed by validation.
+    pub fn ref_matches(&self, value: UntypedVal, ref_type: RefType) -> bool {
+        let heap_type = ref_type.heap_type();
+        let dynamic_type = match heap_type.top() {
+            HeapType::Func => match FuncRef::from(value).func() {
+                Some(func) => {
+                    let func_type = self.resolve_func(func).ty_dedup();
+                    HeapType::Concrete(self.engine.func_type_id(func_type))
+                }
+                None => return ref_type.is_nullable(),
+            },
+            HeapType::Extern => match ExternRef::from(value).is_null() {
+                true => return ref_type.is_nullable(),
+                false => HeapType::Extern,
+            },
+            HeapType::Cont => match ContRef::from(value).is_null() {
+                true => return ref_type.is_nullable(),
+                false => HeapType::Cont,
+            },
+            _ => {
+                let anyref = AnyRef::from(value);
+                if anyref.is_null() {
+                    return ref_type.is_nullable();
+                }
+                if anyref.is_i31() {
+                    HeapType::I31
+                } else {
+                    match self.gc_heap.resolve(value).kind() {
+                        GcObjectKind::Struct(type_id) | GcObjectKind::Array(type_id) => {
+                            HeapType::Concrete(type_id)
+                        }
+                        GcObjectKind::Extern => HeapType::Any,
+                    }
+                }
+            }
+        };
+        if !matches!(heap_type, HeapType::Concrete(_)) {
+            return dynamic_type.is_subtype_of(&heap_type);
+        }
+        self.engine
+            .with_types(|types| types.is_heap_subtype(dynamic_type, heap_type))
+    }
+
+    /// Returns the [`AnyRef`] wrapped by the `object` if it was created via `extern.convert_any`.
+    fn externalized_any(&self, object: &ExternObject) -> Option<AnyRef> {
+        self.resolve_external_object(object)
+            .data()
+            .downcast_ref::<ExternalizedAny>()
+            .map(|externalized| externalized.0)
+    }
+
+    /// Pushes the object referenced by `value` of type `ty` to the `worklist` if any.
+    #[cfg(feature = "gc")]
+    fn trace_value(&self, ty: &ValType, value: UntypedVal, worklist: &mut Vec<AnyRef>) {
+        let anyref = if ty.is_any_ref() {
+            AnyRef::from(value)
+        } else if ty.is_extern_ref() {
+            let Some(anyref) = ExternRef::from(value)
+                .as_object()
+                .and_then(|object| self.externalized_any(object))
+            else {
+                return;
+            };
+            anyref
+        } else {
+            return;
+        };
+        if anyref.is_object() {
+            worklist.push(anyref);
+        }
+    }
+
+    /// Reclaims all objects of the [`GcHeap`] that are unreachable from the roots of the [`StoreInner`].
+    ///
+    /// Returns the number of reclaimed objects.
+    ///
+    /// # Note
+    ///
+    /// Only call this outside of Wasm executions of the [`StoreInner`].
+    /// Use [`StoreInner::collect_garbage_with`] during Wasm executions instead.
+    #[cfg(feature = "gc")]
+    pub fn collect_garbage(&mut self) -> usize {
+        self.collect_garbage_with(core::iter::empty())
+    }
+
+    /// Reclaims all objects of the [`GcHeap`] that are unreachable from the roots of the
+    /// [`StoreInner`] and the additional `roots`.
+    ///
+    /// Returns the number of reclaimed objects.
+    ///
+    /// # Note
+    ///
+    /// - Roots are the values of globals, tables, element segments and continuations,
+    ///   the objects held by the host and by suspended executions as well as objects
+    ///   converted via `extern.convert_any` since their [`ExternRef`]s are never reclaimed.
+    /// - The untyped values of continuations, suspended executions and `roots` are traced
+    ///   conservatively, i.e. every value that is a valid object handle is a root.
+    /// - The values of the active Wasm execution must be part of `roots`.
+    #[cfg(feature = "gc")]
+    pub fn collect_garbage_with(&mut self, roots: impl IntoIterator<Item = UntypedVal>) -> usize {
+        let mut worklist = Vec::new();
+        for (_, global) in self.globals.iter() {
+            self.trace_value(&global.ty().content(), global.get_untyped(), &mut worklist);
+        }
+        for (_, table) in self.tables.iter() {
+            let ty = table.ty().element();
+            for value in table.elements() {
+                self.trace_value(&ty, *value, &mut worklist);
+            }
+        }
+        for (_, segment) in self.elems.iter() {
+            let ty = segment.ty();
+            for value in segment.items() {
+                self.trace_value(&ty, *value, &mut worklist);
+            }
+        }
+        for (_, object) in self.extern_objects.iter() {
+            if let Some(externalized) = object.data().downcast_ref::<ExternalizedAny>() {
+                if externalized.0.is_object() {
+                    worklist.push(externalized.0);
+                }
+            }
+        }
+        let conts = self.cont_heap.iter().flat_map(ContEntity::values);
+        let handles = self.object_handles(conts.chain(roots));
+        worklist.extend(handles.map(AnyRef::from));
+        let mut gc_roots = core::mem::take(&mut self.gc_roots);
+        worklist.extend(self.object_handles(gc_roots.iter()).map(AnyRef::from));
+        self.gc_roots = gc_roots;
+        let engine = self.engine.clone();
+        engine.with_types(|types| {
+            while let Some(anyref) = worklist.pop() {
+                if !self.gc_heap.mark(anyref.into()) {
+                    continue;
+                }
+                let object = self.gc_heap.resolve(anyref.into());
+                let fields = object.fields();
+                match object.kind() {
+                    GcObjectKind::Struct(type_id) => {
+                        let struct_type = types.struct_type(type_id);
+                        for (field, value) in struct_type.fields().iter().zip(fields) {
+                            if let StorageType::Val(ty) = field.storage() {
+                                self.trace_value(&ty, *value, &mut worklist);
+                            }
+                        }
+                    }
+                    GcObjectKind::Array(type_id) => {
+                        if let StorageType::Val(ty) = types.array_type(type_id).element().storage()
+                        {
+                            for value in fields {
+                                self.trace_value(&ty, *value, &mut worklist);
+                            }
+                        }
+                    }
+                    GcObjectKind::Extern => {
+                        self.trace_value(&ValType::ExternRef, fields[0], &mut worklist);
+                    }
+                }
+            }
+        });
+        self.gc_heap.sweep()
+    }
+
     /// Allocates a new Wasm or host [`FuncEntity`] and returns a [`Func`] reference to it.
     pub fn alloc_func(&mut self, func: FuncEntity) -> Func {
         let idx = self.funcs.alloc(func);
@@ -958,7 +1376,7 @@ impl<T> Store<T> {
 
     pub(crate) fn store_inner_and_resource_limiter_ref(
         &mut self,
-    ) -> (&mut StoreInner, ResourceLimiterRef) {
+    ) -> (&mut StoreInner, ResourceLimiterRef<'_>) {
         let resource_limiter = ResourceLimiterRef(match &mut self.limiter {
             Some(q) => Some(q.0(&mut self.data)),
             None => None,
@@ -992,6 +1410,69 @@ impl<T> Store<T> {
         self.inner.fuel.set_fuel(fuel).map_err(Into::into)
     }
 
+    /// Reclaims all unreachable objects of the Wasm `gc` proposal and returns their number.
+    ///
+    /// # Note
+    ///
+    /// - Garbage is also collected automatically whenever Wasm allocates an object after
+    ///   the live objects grew to twice their size since the previous collection.
+    /// - Objects are reachable if they are referenced by a Wasm global, table, element segment,
+    ///   continuation or suspended [`ResumableInvocation`] either directly or transitively
+    ///   via fields of other reachable objects.
+    /// - [`AnyRef`]s handed out to the host are reachable until the host function call or
+    ///   [`Store::gc_scope`] they were handed out in returns or, otherwise, until they are
+    ///   released via [`Store::gc_release_host_refs`].
+    ///   Accessing objects that have been reclaimed results in a panic.
+    ///
+    /// [`ResumableInvocation`]: crate::ResumableInvocation
+    #[cfg(feature = "gc")]
+    pub fn gc(&mut self) -> usize {
+        self.inner.collect_garbage()
+    }
+
+    /// Calls `f` releasing all [`AnyRef`]s handed out to the host within it once it returns.
+    ///
+    /// # Note
+    ///
+    /// - Use this to bound the number of objects kept alive by [`AnyRef`]s handed out by
+    ///   e.g. [`Func::call`], [`Global::get`] or [`Table::get`] to a single scope.
+    /// - Objects only referenced by released [`AnyRef`]s are reclaimed by the next garbage
+    ///   collection. Accessing objects that have been reclaimed results in a panic.
+    /// - [`AnyRef`]s returned by `f` must not be used after `f` returned.
+    ///
+    /// [`Func::call`]: crate::Func::call
+    /// [`Global::get`]: crate::Global::get
+    /// [`Table::get`]: crate::Table::get
+    #[cfg(feature = "gc")]
+    pub fn gc_scope<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let scope = self.inner.enter_host_scope(core::iter::empty());
+        let result = f(self);
+        self.inner.exit_host_scope(scope);
+        result
+    }
+
+    /// Releases all [`AnyRef`]s handed out to the host outside of host function calls.
+    ///
+    /// # Note
+    ///
+    /// Objects only referenced by released [`AnyRef`]s are reclaimed by the next garbage
+    /// collection. Accessing objects that have been reclaimed results in a panic.
+    #[cfg(feature = "gc")]
+    pub fn gc_release_host_refs(&mut self) {
+        self.inner.release_host_roots();
+    }
+
+    /// Returns the number of live objects of the Wasm `gc` proposal.
+    ///
+    /// # Note
+    ///
+    /// Objects that are no longer reachable are counted until they are reclaimed
+    /// by [`Store::gc`] or an automatic garbage collection.
+    #[cfg(feature = "gc")]
+    pub fn gc_objects(&self) -> usize {
+        self.inner.gc_heap().len()
+    }
+
     /// Allocates a new [`TrampolineEntity`] and returns a [`Trampoline`] reference to it.
     pub(super) fn alloc_trampoline(&mut self, func: TrampolineEntity<T>) -> Trampoline {
         let idx = self.trampolines.alloc(func);
@@ -1030,6 +1511,35 @@ impl<T> Store<T> {
             .unwrap_or_else(|| panic!("failed to resolve stored host function: {entity_index:?}"))
     }
 
+    /// Consumes the fuel charged for a call to the host function `func` with the untyped `params`.
+    ///
+    /// This does nothing if `func` charges no fuel or if fuel metering is disabled.
+    ///
+    /// # Errors
+    ///
+    /// - If out of fuel.
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Trampoline`] does not originate from this [`Store`].
+    /// - If the [`Trampoline`] cannot be resolved to its entity.
+    pub(super) fn consume_host_func_fuel(
+        &mut self,
+        func: &Trampoline,
+        params: &[UntypedVal],
+    ) -> Result<(), TrapCode> {
+        let entity_index = self.inner.unwrap_stored(func.as_inner());
+        let Some(fuel) = self
+            .trampolines
+            .get(entity_index)
+            .unwrap_or_else(|| panic!("failed to resolve stored host function: {entity_index:?}"))
+            .fuel()
+        else {
+            return Ok(());
+        };
+        self.inner.consume_host_func_fuel(fuel, params)
+    }
+
     /// Sets a callback function that is executed whenever a WebAssembly
     /// function is called from the host or a host function is called from
     /// WebAssembly, or these functions return.
@@ -1085,13 +1595,13 @@ pub trait AsContext {
     type Data;
 
     /// Returns the store context that this type provides access to.
-    fn as_context(&self) -> StoreContext<Self::Data>;
+    fn as_context(&self) -> StoreContext<'_, Self::Data>;
 }
 
 /// A trait used to get exclusive access to a [`Store`] in Wasmi.
 pub trait AsContextMut: AsContext {
     /// Returns the store context that this type provides access to.
-    fn as_context_mut(&mut self) -> StoreContextMut<Self::Data>;
+    fn as_context_mut(&mut self) -> StoreContextMut<'_, Self::Data>;
 }
 
 /// A temporary handle to a [`&Store<T>`][`Store`].
@@ -2,13 +2,11 @@ use crate::{
     collections::arena::ArenaIndex,
     core::{UntypedVal, ValType},
     module,
-    store::Stored,
+    store::{StoreInner, Stored},
     AsContext,
     AsContextMut,
     Func,
-    FuncRef,
     Global,
-    Val,
 };
 use alloc::boxed::Box;
 
@@ -56,13 +54,9 @@ impl ElementSegment {
         get_func: impl Fn(u32) -> Func,
         get_global: impl Fn(u32) -> Global,
     ) -> Self {
-        let get_func = |index| get_func(index).into();
-        let get_global = |index| get_global(index).get(&ctx);
-        let entity = ElementSegmentEntity::new(elem, get_func, get_global);
-        ctx.as_context_mut()
-            .store
-            .inner
-            .alloc_element_segment(entity)
+        let inner = &mut ctx.as_context_mut().store.inner;
+        let entity = ElementSegmentEntity::new(inner, elem, get_func, get_global);
+        inner.alloc_element_segment(entity)
     }
 
     /// Returns the number of items in the [`ElementSegment`].
@@ -92,9 +86,10 @@ pub struct ElementSegmentEntity {
 
 impl ElementSegmentEntity {
     pub fn new(
+        store: &mut StoreInner,
         elem: &'_ module::ElementSegment,
-        get_func: impl Fn(u32) -> FuncRef,
-        get_global: impl Fn(u32) -> Val,
+        get_func: impl Fn(u32) -> Func,
+        get_global: impl Fn(u32) -> Global,
     ) -> Self {
         let ty = elem.ty();
         match elem.kind() {
@@ -103,7 +98,7 @@ impl ElementSegmentEntity {
                     .items()
                     .iter()
                     .map(|const_expr| {
-                        const_expr.eval_with_context(&get_global, &get_func).unwrap_or_else(|| {
+                        const_expr.eval_with_context(store, &get_global, &get_func).unwrap_or_else(|| {
                             panic!("unexpected failed initialization of constant expression: {const_expr:?}")
                         })
                 }).collect::<Box<[_]>>();
@@ -7,12 +7,12 @@ use crate::{
     collections::arena::ArenaIndex,
     core::{TrapCode, UntypedVal, ValType},
     error::EntityGrowError,
-    store::{Fuel, FuelError, ResourceLimiterRef},
+    store::{Fuel, FuelError, ResourceLimiterRef, StoreInner},
     value::WithType,
     Val,
 };
 use alloc::{vec, vec::Vec};
-use core::cmp::max;
+use core::{cmp::max, slice};
 
 mod element;
 mod error;
@@ -80,16 +80,46 @@ impl TableType {
         self.max
     }
 
-    /// Returns a [`TableError`] if `ty` does not match the [`Table`] element [`ValType`].
+    /// Returns a [`TableError`] if `ty` is not a subtype of the [`Table`] element [`ValType`].
     fn matches_element_type(&self, ty: ValType) -> Result<(), TableError> {
         let expected = self.element();
         let actual = ty;
-        if actual != expected {
+        if !actual.is_subtype_of(&expected) {
             return Err(TableError::ElementTypeMismatch { expected, actual });
         }
         Ok(())
     }
 
+    /// Returns a [`TableError`] if `value` does not match the [`Table`] element [`ValType`].
+    ///
+    /// # Note
+    ///
+    /// This does not check the concrete function types of function references.
+    /// Use [`TableType::matches_element_value_in`] for a complete check.
+    fn matches_element_value(&self, value: &Val) -> Result<(), TableError> {
+        if !value.matches_ty_erased(&self.element()) {
+            return Err(TableError::ElementTypeMismatch {
+                expected: self.element(),
+                actual: value.ty(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a [`TableError`] if `value` does not match the [`Table`] element [`ValType`].
+    ///
+    /// In contrast to [`TableType::matches_element_value`] this also checks the
+    /// concrete function types of function references using the `store`.
+    fn matches_element_value_in(&self, store: &StoreInner, value: &Val) -> Result<(), TableError> {
+        if !value.matches_ty_in(store, &self.element()) {
+            return Err(TableError::ElementTypeMismatch {
+                expected: self.element(),
+                actual: value.ty(),
+            });
+        }
+        Ok(())
+    }
+
     /// Checks if `self` is a subtype of `other`.
     ///
     /// # Note
@@ -123,7 +153,7 @@ impl TableType {
     /// [import subtyping]:
     /// https://webassembly.github.io/spec/core/valid/types.html#import-subtyping
     pub(crate) fn is_subtype_of(&self, other: &Self) -> bool {
-        if self.matches_element_type(other.element()).is_err() {
+        if self.element() != other.element() {
             return false;
         }
         if self.minimum() < other.minimum() {
@@ -155,7 +185,7 @@ impl TableEntity {
         init: Val,
         limiter: &mut ResourceLimiterRef<'_>,
     ) -> Result<Self, TableError> {
-        ty.matches_element_type(init.ty())?;
+        ty.matches_element_value(&init)?;
 
         if let Some(limiter) = limiter.as_resource_limiter() {
             if !limiter.table_growing(0, ty.minimum(), ty.maximum())? {
@@ -214,7 +244,7 @@ impl TableEntity {
         limiter: &mut ResourceLimiterRef<'_>,
     ) -> Result<u32, EntityGrowError> {
         self.ty()
-            .matches_element_type(init.ty())
+            .matches_element_value(&init)
             .map_err(|_| EntityGrowError::InvalidGrow)?;
         self.grow_untyped(delta, init.into(), fuel, limiter)
     }
@@ -305,6 +335,12 @@ impl TableEntity {
         self.elements.get(index as usize).copied()
     }
 
+    /// Returns the untyped elements of the [`Table`].
+    #[cfg(feature = "gc")]
+    pub fn elements(&self) -> &[UntypedVal] {
+        &self.elements
+    }
+
     /// Sets the [`Val`] of this [`Table`] at `index`.
     ///
     /// # Errors
@@ -312,7 +348,7 @@ impl TableEntity {
     /// - If `index` is out of bounds.
     /// - If `value` does not match the [`Table`] element type.
     pub fn set(&mut self, index: u32, value: Val) -> Result<(), TableError> {
-        self.ty().matches_element_type(value.ty())?;
+        self.ty().matches_element_value(&value)?;
         self.set_untyped(index, value.into())
     }
 
@@ -479,7 +515,7 @@ impl TableEntity {
         fuel: Option<&mut Fuel>,
     ) -> Result<(), TrapCode> {
         self.ty()
-            .matches_element_type(val.ty())
+            .matches_element_value(&val)
             .map_err(|_| TrapCode::BadSignature)?;
         self.fill_untyped(dst, val.into(), len, fuel)
     }
@@ -547,6 +583,7 @@ impl Table {
             .as_context_mut()
             .store
             .store_inner_and_resource_limiter_ref();
+        ty.matches_element_value_in(inner, &init)?;
         let entity = TableEntity::new(ty, init, &mut resource_limiter)?;
         let table = inner.alloc_table(entity);
         Ok(table)
@@ -614,6 +651,10 @@ impl Table {
             .as_context_mut()
             .store
             .store_inner_and_resource_limiter_ref();
+        inner
+            .resolve_table(self)
+            .ty()
+            .matches_element_value_in(inner, &init)?;
         let table = inner.resolve_table_mut(self);
         let current = table.size();
         let maximum = table.ty().maximum().unwrap_or(u32::MAX);
@@ -634,7 +675,10 @@ impl Table {
     ///
     /// Panics if `ctx` does not own this [`Table`].
     pub fn get(&self, ctx: impl AsContext, index: u32) -> Option<Val> {
-        ctx.as_context().store.inner.resolve_table(self).get(index)
+        let store = &ctx.as_context().store.inner;
+        let value = store.resolve_table(self).get(index)?;
+        store.root_host_vals(slice::from_ref(&value));
+        Some(value)
     }
 
     /// Sets the [`Val`] of this [`Table`] at `index`.
@@ -653,11 +697,12 @@ impl Table {
         index: u32,
         value: Val,
     ) -> Result<(), TableError> {
-        ctx.as_context_mut()
-            .store
-            .inner
-            .resolve_table_mut(self)
-            .set(index, value)
+        let inner = &mut ctx.as_context_mut().store.inner;
+        inner
+            .resolve_table(self)
+            .ty()
+            .matches_element_value_in(inner, &value)?;
+        inner.resolve_table_mut(self).set(index, value)
     }
 
     /// Returns `true` if `lhs` and `rhs` [`Table`] refer to the same entity.
@@ -737,10 +782,12 @@ impl Table {
         val: Val,
         len: u32,
     ) -> Result<(), TrapCode> {
-        ctx.as_context_mut()
-            .store
-            .inner
-            .resolve_table_mut(self)
-            .fill(dst, val, len, None)
+        let inner = &mut ctx.as_context_mut().store.inner;
+        inner
+            .resolve_table(self)
+            .ty()
+            .matches_element_value_in(inner, &val)
+            .map_err(|_| TrapCode::BadSignature)?;
+        inner.resolve_table_mut(self).fill(dst, val, len, None)
     }
 }
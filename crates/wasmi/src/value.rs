@@ -1,5 +1,9 @@
 use crate::{
-    core::{UntypedVal, ValType, F32, F64},
+    core::{RefType, UntypedVal, ValType, F32, F64},
+    gc::AnyRef,
+    store::StoreInner,
+    AsContext,
+    ContRef,
     ExternRef,
     Func,
     FuncRef,
@@ -25,6 +29,10 @@ impl WithType for UntypedVal {
             ValType::F64 => Val::F64(self.into()),
             ValType::FuncRef => Val::FuncRef(self.into()),
             ValType::ExternRef => Val::ExternRef(self.into()),
+            ty if ty.is_func_ref() => Val::FuncRef(self.into()),
+            ty if ty.is_any_ref() => Val::AnyRef(self.into()),
+            ty if ty.is_cont_ref() => Val::ContRef(self.into()),
+            _ => Val::ExternRef(self.into()),
         }
     }
 }
@@ -38,6 +46,8 @@ impl From<Val> for UntypedVal {
             Val::F64(value) => value.into(),
             Val::FuncRef(value) => value.into(),
             Val::ExternRef(value) => value.into(),
+            Val::AnyRef(value) => value.into(),
+            Val::ContRef(value) => value.into(),
         }
     }
 }
@@ -63,6 +73,10 @@ pub enum Val {
     FuncRef(FuncRef),
     /// A nullable external object reference, a.k.a. [`ExternRef`].
     ExternRef(ExternRef),
+    /// A nullable internal object reference, a.k.a. [`AnyRef`].
+    AnyRef(AnyRef),
+    /// A nullable continuation reference, a.k.a. [`ContRef`].
+    ContRef(ContRef),
 }
 
 impl Val {
@@ -76,6 +90,10 @@ impl Val {
             ValType::F64 => Self::F64(0f64.into()),
             ValType::FuncRef => Self::from(FuncRef::null()),
             ValType::ExternRef => Self::from(ExternRef::null()),
+            ty if ty.is_func_ref() => Self::from(FuncRef::null()),
+            ty if ty.is_any_ref() => Self::from(AnyRef::null()),
+            ty if ty.is_cont_ref() => Self::from(ContRef::null()),
+            _ => Self::from(ExternRef::null()),
         }
     }
 
@@ -89,6 +107,73 @@ impl Val {
             Self::F64(_) => ValType::F64,
             Self::FuncRef(_) => ValType::FuncRef,
             Self::ExternRef(_) => ValType::ExternRef,
+            Self::AnyRef(_) => ValType::from(RefType::ANYREF),
+            Self::ContRef(_) => ValType::from(RefType::CONTREF),
+        }
+    }
+
+    /// Returns `true` if `self` is a valid value of type `ty`.
+    ///
+    /// # Note
+    ///
+    /// In contrast to comparing `ty` with [`Val::ty`] this also respects typed references,
+    /// e.g. a non-`null` [`Val::FuncRef`] matches all reference types of its function type.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own the referenced [`Func`] if any.
+    pub fn matches_ty(&self, ctx: impl AsContext, ty: &ValType) -> bool {
+        self.matches_ty_in(&ctx.as_context().store.inner, ty)
+    }
+
+    /// Returns `true` if `self` is a valid value of type `ty` using the [`StoreInner`].
+    ///
+    /// For more information read [`Val::matches_ty`].
+    pub(crate) fn matches_ty_in(&self, store: &StoreInner, ty: &ValType) -> bool {
+        if !self.matches_ty_erased(ty) {
+            return false;
+        }
+        let Some(ref_type) = ty.ref_type() else {
+            return true;
+        };
+        let value = match self {
+            Self::FuncRef(funcref) => UntypedVal::from(*funcref),
+            Self::ExternRef(externref) => UntypedVal::from(*externref),
+            Self::AnyRef(anyref) => UntypedVal::from(*anyref),
+            Self::ContRef(contref) => UntypedVal::from(*contref),
+            _ => return true,
+        };
+        store.ref_matches(value, ref_type)
+    }
+
+    /// Returns `true` if `self` is a valid value of type `ty` without checking concrete types.
+    ///
+    /// # Note
+    ///
+    /// This still checks the hierarchy and nullability of references.
+    pub(crate) fn matches_ty_erased(&self, ty: &ValType) -> bool {
+        /// Returns `true` if a reference that `is_null` matches `ref_type` with respect to nullability.
+        fn matches_null(ref_type: RefType, is_null: bool) -> bool {
+            let heap_type = ref_type.heap_type();
+            match is_null {
+                true => ref_type.is_nullable(),
+                false => heap_type != heap_type.bottom(),
+            }
+        }
+        match (self, ty.ref_type()) {
+            (Self::FuncRef(funcref), Some(ref_type)) => {
+                ref_type.heap_type().is_func() && matches_null(ref_type, funcref.is_null())
+            }
+            (Self::ExternRef(externref), Some(ref_type)) => {
+                ref_type.heap_type().is_extern() && matches_null(ref_type, externref.is_null())
+            }
+            (Self::AnyRef(anyref), Some(ref_type)) => {
+                ref_type.heap_type().is_any() && matches_null(ref_type, anyref.is_null())
+            }
+            (Self::ContRef(contref), Some(ref_type)) => {
+                ref_type.heap_type().is_cont() && matches_null(ref_type, contref.is_null())
+            }
+            (value, _) => value.ty() == *ty,
         }
     }
 
@@ -139,6 +224,22 @@ impl Val {
             _ => None,
         }
     }
+
+    /// Returns the underlying `anyref` if the type matches otherwise returns `None`.
+    pub fn anyref(&self) -> Option<&AnyRef> {
+        match self {
+            Self::AnyRef(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying `contref` if the type matches otherwise returns `None`.
+    pub fn contref(&self) -> Option<&ContRef> {
+        match self {
+            Self::ContRef(value) => Some(value),
+            _ => None,
+        }
+    }
 }
 
 impl From<i32> for Val {
@@ -189,3 +290,17 @@ impl From<ExternRef> for Val {
         Self::ExternRef(externref)
     }
 }
+
+impl From<AnyRef> for Val {
+    #[inline]
+    fn from(anyref: AnyRef) -> Self {
+        Self::AnyRef(anyref)
+    }
+}
+
+impl From<ContRef> for Val {
+    #[inline]
+    fn from(contref: ContRef) -> Self {
+        Self::ContRef(contref)
+    }
+}
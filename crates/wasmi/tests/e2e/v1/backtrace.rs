@@ -0,0 +1,131 @@
+//! Tests to assert that Wasm backtraces are captured properly upon traps.
+
+use wasmi::{
+    core::TrapCode,
+    CompilationMode,
+    Config,
+    Engine,
+    Error,
+    Linker,
+    Module,
+    Store,
+    WasmBacktrace,
+};
+
+/// The Wasm module used by all tests.
+///
+/// Calls `$outer` -> `$middle` -> `$inner` where `$inner` either
+/// traps or calls the imported host function depending on its input.
+const WASM: &str = r#"
+    (module
+        (import "env" "host" (func $host))
+        (func $inner (param i32)
+            (if (local.get 0)
+                (then (call $host))
+                (else (unreachable))
+            )
+        )
+        (func $middle (param i32)
+            (call $inner (local.get 0))
+        )
+        (func (export "run") (param i32)
+            (call $middle (local.get 0))
+        )
+    )
+"#;
+
+/// Calls the exported `run` function with `input` and returns the [`Error`] and Wasm bytes.
+fn run(mode: CompilationMode, wasm_backtrace: bool, input: i32) -> (Error, Vec<u8>) {
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    config.wasm_backtrace(wasm_backtrace);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WASM).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap("env", "host", || -> Result<(), Error> {
+            Err(Error::new("host error"))
+        })
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<i32, ()>(&store, "run").unwrap();
+    let error = run.call(&mut store, input).unwrap_err();
+    (error, wasm)
+}
+
+/// Asserts that `backtrace` contains the expected frames.
+///
+/// The `top_opcode` is the opcode of the Wasm operator located by the top-most frame.
+fn assert_backtrace(backtrace: &WasmBacktrace, wasm: &[u8], top_opcode: u8) {
+    /// The Wasm `call` opcode.
+    const CALL: u8 = 0x10;
+    let frames = backtrace.frames();
+    let names = frames
+        .iter()
+        .map(|frame| frame.func_name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [Some("inner"), Some("middle"), None]);
+    let indices = frames
+        .iter()
+        .map(|frame| frame.func_index())
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [1, 2, 3]);
+    let opcodes = frames
+        .iter()
+        .map(|frame| wasm[frame.module_offset().unwrap()])
+        .collect::<Vec<_>>();
+    assert_eq!(opcodes, [top_opcode, CALL, CALL]);
+}
+
+const MODES: [CompilationMode; 3] = [
+    CompilationMode::Eager,
+    CompilationMode::LazyTranslation,
+    CompilationMode::Lazy,
+];
+
+#[test]
+fn backtrace_on_trap() {
+    /// The Wasm `unreachable` opcode.
+    const UNREACHABLE: u8 = 0x00;
+    for mode in MODES {
+        let (error, wasm) = run(mode, true, 0);
+        assert_eq!(error.as_trap_code(), Some(TrapCode::UnreachableCodeReached));
+        assert_backtrace(error.backtrace().unwrap(), &wasm, UNREACHABLE);
+    }
+}
+
+#[test]
+fn backtrace_on_host_error() {
+    /// The Wasm `call` opcode.
+    const CALL: u8 = 0x10;
+    for mode in MODES {
+        let (error, wasm) = run(mode, true, 1);
+        assert_eq!(error.to_string().lines().next(), Some("host error"));
+        assert_backtrace(error.backtrace().unwrap(), &wasm, CALL);
+    }
+}
+
+#[test]
+fn backtrace_disabled() {
+    for mode in MODES {
+        let (error, _) = run(mode, false, 0);
+        assert!(error.backtrace().is_none());
+        assert!(!error.to_string().contains("wasm backtrace"));
+    }
+}
+
+#[test]
+fn backtrace_display() {
+    let (error, _) = run(CompilationMode::Eager, true, 0);
+    let display = error.to_string();
+    assert!(display.contains("wasm backtrace:"));
+    assert!(display.contains(" - inner\n"));
+    assert!(display.contains(" - middle\n"));
+    assert!(display.contains(" - <wasm function 3>\n"));
+}
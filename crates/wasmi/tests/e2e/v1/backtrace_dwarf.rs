@@ -0,0 +1,178 @@
+//! Tests to assert that Wasm backtraces are symbolized using DWARF debug information.
+
+use gimli::{
+    write::{Address, AttributeValue, DwarfUnit, EndianVec, LineProgram, LineString, Sections},
+    Encoding,
+    Format,
+    LineEncoding,
+    LittleEndian,
+};
+use wasmi::{Config, Engine, Error, Linker, Module, Store};
+
+/// The Wasm module used by all tests.
+const WASM: &str = r#"
+    (module
+        (func $trap_here
+            (unreachable)
+        )
+        (func (export "run")
+            (call $trap_here)
+        )
+    )
+"#;
+
+/// Instantiates `wasm` and returns the [`Error`] of calling its `run` export.
+fn run(wasm: &[u8]) -> Error {
+    let mut config = Config::default();
+    config.wasm_backtrace(true);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<(), ()>(&store, "run").unwrap();
+    run.call(&mut store, ()).unwrap_err()
+}
+
+/// Returns the offset and size of the Wasm code section contents of `wasm`.
+fn code_section(wasm: &[u8]) -> (usize, usize) {
+    fn read_leb128(wasm: &[u8], pos: &mut usize) -> usize {
+        let mut result = 0;
+        let mut shift = 0;
+        loop {
+            let byte = wasm[*pos];
+            *pos += 1;
+            result |= usize::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return result;
+            }
+            shift += 7;
+        }
+    }
+    let mut pos = 8;
+    loop {
+        let id = wasm[pos];
+        pos += 1;
+        let size = read_leb128(wasm, &mut pos);
+        if id == 10 {
+            return (pos, size);
+        }
+        pos += size;
+    }
+}
+
+/// Appends a Wasm custom section with `name` and `data` to `wasm`.
+fn append_custom_section(wasm: &mut Vec<u8>, name: &str, data: &[u8]) {
+    fn write_leb128(buffer: &mut Vec<u8>, mut value: usize) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                buffer.push(byte);
+                return;
+            }
+            buffer.push(byte | 0x80);
+        }
+    }
+    let mut contents = Vec::new();
+    write_leb128(&mut contents, name.len());
+    contents.extend_from_slice(name.as_bytes());
+    contents.extend_from_slice(data);
+    wasm.push(0x00);
+    write_leb128(wasm, contents.len());
+    wasm.extend_from_slice(&contents);
+}
+
+/// Appends DWARF debug information to `wasm` that maps `trap_address` to `main.c:3:5`.
+///
+/// The whole Wasm code section is covered by a single `trap_here` function.
+fn append_dwarf(wasm: &mut Vec<u8>, code_size: u64, trap_address: u64) {
+    let encoding = Encoding {
+        format: Format::Dwarf32,
+        version: 4,
+        address_size: 4,
+    };
+    let mut dwarf = DwarfUnit::new(encoding);
+    let mut program = LineProgram::new(
+        encoding,
+        LineEncoding::default(),
+        LineString::String(b"/src".to_vec()),
+        LineString::String(b"main.c".to_vec()),
+        None,
+    );
+    let dir = program.default_directory();
+    let file = program.add_file(LineString::String(b"main.c".to_vec()), dir, None);
+    program.begin_sequence(Some(Address::Constant(0)));
+    for (address, line, column) in [(0, 1, 1), (trap_address, 3, 5)] {
+        let row = program.row();
+        row.address_offset = address;
+        row.file = file;
+        row.line = line;
+        row.column = column;
+        program.generate_row();
+    }
+    program.end_sequence(code_size);
+    dwarf.unit.line_program = program;
+    let root = dwarf.unit.root();
+    let cu = dwarf.unit.get_mut(root);
+    cu.set(
+        gimli::DW_AT_name,
+        AttributeValue::String(b"main.c".to_vec()),
+    );
+    cu.set(
+        gimli::DW_AT_low_pc,
+        AttributeValue::Address(Address::Constant(0)),
+    );
+    cu.set(gimli::DW_AT_high_pc, AttributeValue::Udata(code_size));
+    let subprogram = dwarf.unit.add(root, gimli::DW_TAG_subprogram);
+    let subprogram = dwarf.unit.get_mut(subprogram);
+    subprogram.set(
+        gimli::DW_AT_name,
+        AttributeValue::String(b"trap_here".to_vec()),
+    );
+    subprogram.set(
+        gimli::DW_AT_low_pc,
+        AttributeValue::Address(Address::Constant(0)),
+    );
+    subprogram.set(gimli::DW_AT_high_pc, AttributeValue::Udata(code_size));
+    let mut sections = Sections::new(EndianVec::new(LittleEndian));
+    dwarf.write(&mut sections).unwrap();
+    sections
+        .for_each(|id, data| {
+            if !data.slice().is_empty() {
+                append_custom_section(wasm, id.name(), data.slice());
+            }
+            Ok::<(), ()>(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn backtrace_with_dwarf() {
+    let mut wasm = wat::parse_str(WASM).unwrap();
+    // Without DWARF debug information there are no source-level symbols.
+    let error = run(&wasm);
+    let backtrace = error.backtrace().unwrap();
+    assert!(backtrace.frames().iter().all(|f| f.symbols().is_empty()));
+    let trap_offset = backtrace.frames()[0].module_offset().unwrap();
+    let (code_offset, code_size) = code_section(&wasm);
+    append_dwarf(
+        &mut wasm,
+        code_size as u64,
+        (trap_offset - code_offset) as u64,
+    );
+    let error = run(&wasm);
+    let frames = error.backtrace().unwrap().frames();
+    let symbols = frames[0].symbols();
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].name(), Some("trap_here"));
+    assert!(symbols[0].file().unwrap().ends_with("main.c"));
+    assert_eq!(symbols[0].line(), Some(3));
+    assert_eq!(symbols[0].column(), Some(5));
+    let display = error.to_string();
+    assert!(display.contains("trap_here at "));
+    assert!(display.contains("main.c:3:5\n"));
+}
@@ -0,0 +1,164 @@
+//! Tests to check that the inline caches of `call_indirect` are invalidated upon table mutations.
+
+use wasmi::{Engine, FuncRef, Instance, Linker, Module, Store, Table};
+
+/// The Wasm module used by all tests.
+///
+/// The exported `call` function calls the function at the given index of `$table`
+/// through the same `call_indirect` call site.
+const WAT: &str = r#"
+    (module
+        (type $ret_i32 (func (result i32)))
+        (type $ret_i64 (func (result i64)))
+        (table $table (export "table") 2 funcref)
+        (elem (table $table) (i32.const 0) func $one $two)
+        (elem $elem func $one $two)
+        (func $one (result i32) (i32.const 1))
+        (func $two (result i32) (i32.const 2))
+        (func $three (result i32) (i32.const 3))
+        (func $wide (result i64) (i64.const 4))
+        (elem declare func $three $wide)
+        (func (export "call") (param $index i32) (result i32)
+            (call_indirect $table (type $ret_i32) (local.get $index))
+        )
+        (func (export "set_three") (param $index i32)
+            (table.set $table (local.get $index) (ref.func $three))
+        )
+        (func (export "set_wide") (param $index i32)
+            (table.set $table (local.get $index) (ref.func $wide))
+        )
+        (func (export "set_null") (param $index i32)
+            (table.set $table (local.get $index) (ref.null func))
+        )
+        (func (export "copy") (param $dst i32) (param $src i32)
+            (table.copy $table $table (local.get $dst) (local.get $src) (i32.const 1))
+        )
+        (func (export "init") (param $dst i32) (param $src i32)
+            (table.init $table $elem (local.get $dst) (local.get $src) (i32.const 1))
+        )
+        (func (export "fill") (param $dst i32)
+            (table.fill $table (local.get $dst) (ref.func $three) (i32.const 1))
+        )
+        (func (export "grow") (param $delta i32) (result i32)
+            (table.grow $table (ref.func $three) (local.get $delta))
+        )
+    )
+"#;
+
+/// Instantiates [`WAT`] in `store`.
+fn instantiate(store: &mut Store<()>, module: &Module) -> Instance {
+    Linker::new(store.engine())
+        .instantiate(&mut *store, module)
+        .unwrap()
+        .start(&mut *store)
+        .unwrap()
+}
+
+/// Creates a [`Store`] with a single instance of [`WAT`].
+fn setup() -> (Module, Store<()>, Instance) {
+    let engine = Engine::default();
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = instantiate(&mut store, &module);
+    (module, store, instance)
+}
+
+/// Calls the exported `call` function of `instance` with `index`.
+///
+/// Returns the trap message if the call trapped.
+fn call(store: &mut Store<()>, instance: &Instance, index: i32) -> Result<i32, String> {
+    instance
+        .get_typed_func::<i32, i32>(&*store, "call")
+        .unwrap()
+        .call(&mut *store, index)
+        .map_err(|error| error.to_string())
+}
+
+/// Calls the exported function `name` of `instance` with `params`.
+fn run(store: &mut Store<()>, instance: &Instance, name: &str, params: &[i32]) {
+    let func = instance.get_func(&*store, name).unwrap();
+    let params = params.iter().copied().map(Into::into).collect::<Vec<_>>();
+    let mut results = vec![0_i32.into(); func.ty(&*store).results().len()];
+    func.call(&mut *store, &params, &mut results).unwrap();
+}
+
+/// Returns the `table` exported by `instance`.
+fn table(store: &Store<()>, instance: &Instance) -> Table {
+    instance.get_table(store, "table").unwrap()
+}
+
+#[test]
+fn cached_calls_work() {
+    let (_module, mut store, instance) = setup();
+    for _ in 0..3 {
+        assert_eq!(call(&mut store, &instance, 0), Ok(1));
+        assert_eq!(call(&mut store, &instance, 1), Ok(2));
+        assert_eq!(call(&mut store, &instance, 1), Ok(2));
+        assert!(call(&mut store, &instance, 2).is_err());
+    }
+}
+
+#[test]
+fn invalidated_by_table_set() {
+    let (_module, mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, 0), Ok(1));
+    run(&mut store, &instance, "set_three", &[0]);
+    assert_eq!(call(&mut store, &instance, 0), Ok(3));
+    run(&mut store, &instance, "set_wide", &[0]);
+    assert!(call(&mut store, &instance, 0)
+        .unwrap_err()
+        .contains("indirect call type mismatch"));
+    run(&mut store, &instance, "set_null", &[0]);
+    assert!(call(&mut store, &instance, 0)
+        .unwrap_err()
+        .contains("uninitialized element"));
+}
+
+#[test]
+fn invalidated_by_table_copy_and_init() {
+    let (_module, mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, 0), Ok(1));
+    run(&mut store, &instance, "copy", &[0, 1]);
+    assert_eq!(call(&mut store, &instance, 0), Ok(2));
+    run(&mut store, &instance, "init", &[0, 0]);
+    assert_eq!(call(&mut store, &instance, 0), Ok(1));
+    run(&mut store, &instance, "fill", &[0]);
+    assert_eq!(call(&mut store, &instance, 0), Ok(3));
+}
+
+#[test]
+fn invalidated_by_table_grow() {
+    let (_module, mut store, instance) = setup();
+    assert!(call(&mut store, &instance, 2).is_err());
+    run(&mut store, &instance, "grow", &[1]);
+    assert_eq!(call(&mut store, &instance, 2), Ok(3));
+    let table = table(&store, &instance);
+    table.grow(&mut store, 1, FuncRef::null().into()).unwrap();
+    assert!(call(&mut store, &instance, 3).is_err());
+}
+
+#[test]
+fn invalidated_by_host_table_set() {
+    let (_module, mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, 0), Ok(1));
+    let table = table(&store, &instance);
+    let two = table.get(&store, 1).unwrap();
+    table.set(&mut store, 0, two).unwrap();
+    assert_eq!(call(&mut store, &instance, 0), Ok(2));
+    Table::copy(&mut store, &table, 1, &table, 0, 1).unwrap();
+    table.set(&mut store, 0, FuncRef::null().into()).unwrap();
+    assert!(call(&mut store, &instance, 0).is_err());
+    assert_eq!(call(&mut store, &instance, 1), Ok(2));
+}
+
+#[test]
+fn call_sites_shared_by_instances() {
+    let (module, mut store, fst) = setup();
+    let snd = instantiate(&mut store, &module);
+    run(&mut store, &snd, "set_three", &[0]);
+    for _ in 0..3 {
+        assert_eq!(call(&mut store, &fst, 0), Ok(1));
+        assert_eq!(call(&mut store, &snd, 0), Ok(3));
+    }
+}
@@ -0,0 +1,189 @@
+//! Tests to assert that Wasm coredumps are captured and serialized properly upon traps.
+
+use wasmi::{CompilationMode, Config, Engine, Error, Linker, Module, Store, Val};
+use wasmparser::{CoreDumpValue, KnownCustom, Parser, Payload, Validator};
+
+/// The Wasm module used by all tests.
+///
+/// Calls `run` -> `$trap` where `$trap` mutates its local, the linear memory
+/// and the global variable before it traps with values on its operand stack.
+const WASM: &str = r#"
+    (module
+        (memory 1 2)
+        (global $g (mut i32) (i32.const 0))
+        (func $trap (param i32) (result i32)
+            (local i64)
+            (local.set 1 (i64.const 42))
+            (i32.store (i32.const 16) (i32.const 0xABCD))
+            (global.set $g (i32.const 7))
+            (i32.add
+                (i32.mul (local.get 0) (i32.const 2))
+                (i32.div_u (local.get 0) (i32.sub (local.get 0) (local.get 0)))
+            )
+        )
+        (func (export "run") (param i32) (result i32)
+            (i64.const 3)
+            (call $trap (local.get 0))
+            (drop)
+            (i32.wrap_i64)
+        )
+    )
+"#;
+
+/// Calls the exported `run` function with `5` and returns the [`Error`] and Wasm bytes.
+fn run(mode: CompilationMode, coredump_on_trap: bool) -> (Error, Vec<u8>) {
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    config.wasm_backtrace(true);
+    config.coredump_on_trap(coredump_on_trap);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WASM).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+    let error = run.call(&mut store, 5).unwrap_err();
+    (error, wasm)
+}
+
+/// Returns the `i32` or `i64` value of `val` as `i64`.
+fn int(val: &Val) -> i64 {
+    match val {
+        Val::I32(value) => i64::from(*value),
+        Val::I64(value) => *value,
+        _ => panic!("unexpected value: {val:?}"),
+    }
+}
+
+/// Returns the integer values of the captured `values`.
+///
+/// # Panics
+///
+/// If any of the `values` has not been captured.
+fn ints(values: &[Option<Val>]) -> Vec<i64> {
+    values
+        .iter()
+        .map(|value| int(value.as_ref().expect("value must be captured")))
+        .collect()
+}
+
+const MODES: [CompilationMode; 3] = [
+    CompilationMode::Eager,
+    CompilationMode::LazyTranslation,
+    CompilationMode::Lazy,
+];
+
+#[test]
+fn coredump_on_trap() {
+    for mode in MODES {
+        let (error, wasm) = run(mode, true);
+        let coredump = error.coredump().unwrap();
+        let backtrace = error.backtrace().unwrap();
+        let frames = coredump.frames();
+        assert_eq!(frames.len(), 2);
+        let indices = frames.iter().map(|f| f.func_index()).collect::<Vec<_>>();
+        assert_eq!(indices, [0, 1]);
+        let locals = ints(frames[0].locals());
+        assert_eq!(locals, [5, 42]);
+        let locals = ints(frames[1].locals());
+        assert_eq!(locals, [5]);
+        // The operand stacks before the trapping `i32.div_u` and the call to `$trap`.
+        let stack = ints(frames[0].stack());
+        assert_eq!(stack, [10, 5, 0]);
+        assert!(matches!(
+            frames[1].stack(),
+            [Some(Val::I64(3)), Some(Val::I32(5))]
+        ));
+        // The code offsets are relative to the start of the function bodies
+        // which start with the number of their local declarations.
+        for (frame, (bt_frame, len_locals)) in
+            frames.iter().zip(backtrace.frames().iter().zip([1, 0]))
+        {
+            let module_offset = bt_frame.module_offset().unwrap();
+            let body_offset = module_offset - frame.code_offset() as usize;
+            assert_eq!(wasm[body_offset], len_locals);
+        }
+        let memories = coredump.memories().collect::<Vec<_>>();
+        assert_eq!(memories.len(), 1);
+        assert_eq!(memories[0].len(), 0x1_0000);
+        assert_eq!(memories[0][16..20], 0xABCD_u32.to_le_bytes());
+        let globals = coredump.globals().map(int).collect::<Vec<_>>();
+        assert_eq!(globals, [7]);
+    }
+}
+
+#[test]
+fn coredump_disabled() {
+    for mode in MODES {
+        let (error, _) = run(mode, false);
+        assert!(error.coredump().is_none());
+    }
+}
+
+#[test]
+fn coredump_serialize() {
+    let (error, _) = run(CompilationMode::Eager, true);
+    let coredump = error.coredump().unwrap();
+    let bytes = coredump.serialize("test.wasm");
+    Validator::new().validate_all(&bytes).unwrap();
+    let mut found_core = false;
+    let mut found_stack = false;
+    let mut found_memory = false;
+    let mut found_data = false;
+    for payload in Parser::new(0).parse_all(&bytes) {
+        match payload.unwrap() {
+            Payload::CustomSection(reader) => match reader.as_known() {
+                KnownCustom::CoreDump(core) => {
+                    assert_eq!(core.name, "test.wasm");
+                    found_core = true;
+                }
+                KnownCustom::CoreDumpStack(stack) => {
+                    assert_eq!(stack.frames.len(), 2);
+                    for (frame, expected) in stack.frames.iter().zip(coredump.frames()) {
+                        assert_eq!(frame.instanceidx, 0);
+                        assert_eq!(frame.funcidx, expected.func_index());
+                        assert_eq!(frame.codeoffset, expected.code_offset());
+                    }
+                    assert!(matches!(
+                        stack.frames[0].locals[..],
+                        [CoreDumpValue::I32(5), CoreDumpValue::I64(42)]
+                    ));
+                    assert!(matches!(
+                        stack.frames[0].stack[..],
+                        [
+                            CoreDumpValue::I32(10),
+                            CoreDumpValue::I32(5),
+                            CoreDumpValue::I32(0)
+                        ]
+                    ));
+                    assert!(matches!(
+                        stack.frames[1].stack[..],
+                        [CoreDumpValue::I64(3), CoreDumpValue::I32(5)]
+                    ));
+                    found_stack = true;
+                }
+                _ => {}
+            },
+            Payload::MemorySection(reader) => {
+                let memories = reader.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+                assert_eq!(memories.len(), 1);
+                assert!(!memories[0].memory64);
+                assert_eq!(memories[0].initial, 1);
+                assert_eq!(memories[0].maximum, Some(2));
+                found_memory = true;
+            }
+            Payload::DataSection(reader) => {
+                let segments = reader.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+                assert_eq!(segments.len(), 1);
+                assert_eq!(segments[0].data[16..20], 0xABCD_u32.to_le_bytes());
+                found_data = true;
+            }
+            _ => {}
+        }
+    }
+    assert!(found_core && found_stack && found_memory && found_data);
+}
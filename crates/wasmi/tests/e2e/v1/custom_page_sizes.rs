@@ -0,0 +1,157 @@
+//! Tests for the Wasm `custom-page-sizes` proposal.
+
+use wasmi::{
+    core::TrapCode,
+    Config,
+    Engine,
+    Extern,
+    Instance,
+    Linker,
+    Memory,
+    MemoryType,
+    Module,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+};
+
+/// The Wasm module used by most tests.
+const WAT: &str = r#"
+    (module
+        (memory (export "memory") 3 8 (pagesize 1))
+        (func (export "size") (result i32)
+            (memory.size)
+        )
+        (func (export "grow") (param i32) (result i32)
+            (memory.grow (local.get 0))
+        )
+        (func (export "load8") (param i32) (result i32)
+            (i32.load8_u (local.get 0))
+        )
+        (func (export "load_const") (result i32)
+            (i32.load (i32.const 0))
+        )
+        (func (export "store8") (param i32 i32)
+            (i32.store8 (local.get 0) (local.get 1))
+        )
+    )
+"#;
+
+/// Returns a [`Config`] with the Wasm `custom-page-sizes` proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_custom_page_sizes(true);
+    config
+}
+
+/// Creates a [`Store`] with a single instance of [`WAT`] using `limits`.
+fn setup(limits: StoreLimits) -> (Store<StoreLimits>, Instance) {
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, limits);
+    store.limiter(|limits| limits);
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Calls the exported function `name` of `instance` with `params`.
+fn call(
+    store: &mut Store<StoreLimits>,
+    instance: &Instance,
+    name: &str,
+    params: &[i32],
+) -> Result<Option<i32>, wasmi::Error> {
+    let func = instance.get_func(&*store, name).unwrap();
+    let params = params.iter().copied().map(Into::into).collect::<Vec<_>>();
+    let mut results = vec![0_i32.into(); func.ty(&*store).results().len()];
+    func.call(&mut *store, &params, &mut results)?;
+    Ok(results.first().and_then(|result| result.i32()))
+}
+
+#[test]
+fn disabled_by_default() {
+    let engine = Engine::default();
+    let wasm = wat::parse_str(WAT).unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn memory_size_and_grow_use_page_size() {
+    let (mut store, instance) = setup(StoreLimits::default());
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    assert_eq!(memory.ty(&store).page_size(), 1);
+    assert_eq!(memory.data_size(&store), 3);
+    assert_eq!(call(&mut store, &instance, "size", &[]).unwrap(), Some(3));
+    assert_eq!(call(&mut store, &instance, "grow", &[2]).unwrap(), Some(3));
+    assert_eq!(call(&mut store, &instance, "size", &[]).unwrap(), Some(5));
+    assert_eq!(memory.data_size(&store), 5);
+    assert_eq!(call(&mut store, &instance, "grow", &[4]).unwrap(), Some(-1));
+    assert_eq!(memory.grow(&mut store, 3).unwrap(), 5);
+    assert!(memory.grow(&mut store, 1).is_err());
+}
+
+#[test]
+fn accesses_are_bounded_by_bytes() {
+    let (mut store, instance) = setup(StoreLimits::default());
+    call(&mut store, &instance, "store8", &[2, 42]).unwrap();
+    assert_eq!(
+        call(&mut store, &instance, "load8", &[2]).unwrap(),
+        Some(42)
+    );
+    let error = call(&mut store, &instance, "load8", &[3]).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+    // Note: the 4 byte access at a constant address exceeds the minimum size of 3 bytes.
+    let error = call(&mut store, &instance, "load_const", &[]).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::MemoryOutOfBounds));
+    call(&mut store, &instance, "grow", &[1]).unwrap();
+    assert_eq!(
+        call(&mut store, &instance, "load_const", &[]).unwrap(),
+        Some(42 << 16)
+    );
+}
+
+#[test]
+fn resource_limiter_sees_bytes() {
+    let limits = StoreLimitsBuilder::new().memory_size(6).build();
+    let (mut store, instance) = setup(limits);
+    assert_eq!(call(&mut store, &instance, "grow", &[3]).unwrap(), Some(3));
+    assert_eq!(call(&mut store, &instance, "grow", &[1]).unwrap(), Some(-1));
+    assert_eq!(call(&mut store, &instance, "size", &[]).unwrap(), Some(6));
+}
+
+#[test]
+fn static_memory_with_custom_page_size() {
+    let engine = Engine::new(&config());
+    let mut store = Store::new(&engine, ());
+    let ty = MemoryType::new_with_page_size(3, Some(8), 0).unwrap();
+    let memory = Memory::new_static(&mut store, ty, Box::leak(Box::new([0_u8; 8]))).unwrap();
+    assert_eq!(memory.data_size(&store), 3);
+    let too_small = Box::leak(Box::new([0_u8; 2]));
+    assert!(Memory::new_static(&mut store, ty, too_small).is_err());
+}
+
+#[test]
+fn imports_require_matching_page_sizes() {
+    let engine = Engine::new(&config());
+    let wasm =
+        wat::parse_str(r#"(module (import "env" "memory" (memory 1 (pagesize 1))))"#).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instantiate = |store: &mut Store<()>, memory: Memory| {
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .define("env", "memory", Extern::Memory(memory))
+            .unwrap();
+        linker.instantiate(store, &module).map(|_| ())
+    };
+    let wasm_pages = Memory::new(&mut store, MemoryType::new(1, None).unwrap()).unwrap();
+    assert!(instantiate(&mut store, wasm_pages).is_err());
+    let byte_pages = MemoryType::new_with_page_size(1, None, 0).unwrap();
+    let byte_pages = Memory::new(&mut store, byte_pages).unwrap();
+    assert!(instantiate(&mut store, byte_pages).is_ok());
+}
@@ -8,10 +8,12 @@ use wasmi::{
     Engine,
     Func,
     FuncType,
+    Linker,
+    Module,
     Store,
     Val,
 };
-use wasmi_core::{ValType, F32, F64};
+use wasmi_core::{UntypedVal, ValType, F32, F64};
 
 fn test_setup() -> Store<()> {
     let engine = Engine::default();
@@ -520,3 +522,104 @@ fn static_type_check_works() {
         ErrorKind::Func(FuncError::MismatchingResultType)
     );
 }
+
+// Returns a Wasm store and a binary addition [`Func`] operating on raw parameters and results.
+fn setup_add2_raw() -> (Store<()>, Func) {
+    let mut store = test_setup();
+    let add2_raw = Func::new_raw(
+        &mut store,
+        FuncType::new([ValType::I32, ValType::I32], [ValType::I32]),
+        |_caller, mut inout| {
+            assert_eq!(inout.ty().params(), [ValType::I32, ValType::I32]);
+            assert_eq!(inout.ty().results(), [ValType::I32]);
+            let [lhs, rhs] = inout.params() else {
+                panic!("unexpected number of parameters: {:?}", inout.params())
+            };
+            let result = i32::from(*lhs).wrapping_add(i32::from(*rhs));
+            inout.results_mut()[0] = UntypedVal::from(result);
+            Ok(())
+        },
+    );
+    (store, add2_raw)
+}
+
+#[test]
+fn raw_add2_works() {
+    let (mut store, add2_raw) = setup_add2_raw();
+    let add2_typed = add2_raw.typed::<(i32, i32), i32>(&mut store).unwrap();
+    for a in 0..10 {
+        for b in 0..10 {
+            let params = [Val::I32(a), Val::I32(b)];
+            let expected = a + b;
+            let mut result = Val::I32(0);
+            add2_raw
+                .call(&mut store, &params, slice::from_mut(&mut result))
+                .unwrap();
+            assert_eq!(result.i32(), Some(expected));
+            assert_eq!(add2_typed.call(&mut store, (a, b)).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn raw_duplicate_works() {
+    let mut store = test_setup();
+    let duplicate = Func::new_raw(
+        &mut store,
+        FuncType::new([ValType::I64], [ValType::I64, ValType::I64]),
+        |_caller, mut inout| {
+            // Parameters and results share the same buffer so we need to read before writing.
+            let value = inout.params()[0];
+            inout.results_mut().fill(value);
+            Ok(())
+        },
+    );
+    let duplicate = duplicate.typed::<i64, (i64, i64)>(&store).unwrap();
+    for input in [0, 1, -1, i64::MIN, i64::MAX] {
+        assert_eq!(duplicate.call(&mut store, input).unwrap(), (input, input));
+    }
+}
+
+#[test]
+fn raw_linker_func_works() {
+    let mut store = test_setup();
+    let mut linker = <Linker<()>>::new(store.engine());
+    linker
+        .func_new_raw(
+            "env",
+            "sub",
+            FuncType::new([ValType::I64, ValType::I64], [ValType::I64]),
+            |_caller, mut inout| {
+                let lhs = i64::from(inout.params()[0]);
+                let rhs = i64::from(inout.params()[1]);
+                inout.results_mut()[0] = UntypedVal::from(lhs.wrapping_sub(rhs));
+                Ok(())
+            },
+        )
+        .unwrap();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "sub" (func $sub (param i64 i64) (result i64)))
+            (func (export "run") (param i64 i64) (result i64)
+                (i64.add
+                    (call $sub (local.get 0) (local.get 1))
+                    (i64.const 1)
+                )
+            )
+        )
+        "#,
+    )
+    .unwrap();
+    let module = Module::new(store.engine(), &wasm[..]).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance
+        .get_typed_func::<(i64, i64), i64>(&store, "run")
+        .unwrap();
+    assert_eq!(run.call(&mut store, (10, 3)).unwrap(), 8);
+    assert_eq!(run.call(&mut store, (i64::MIN, 1)).unwrap(), i64::MIN);
+}
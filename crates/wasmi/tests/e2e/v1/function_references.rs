@@ -0,0 +1,344 @@
+//! Tests for the Wasm `function-references` proposal.
+
+use wasmi::{
+    core::{HeapType, RefType, TrapCode, UntypedVal, ValType},
+    errors::{ErrorKind, FuncError},
+    Config,
+    Engine,
+    Extern,
+    Func,
+    FuncRef,
+    FuncType,
+    Global,
+    GlobalType,
+    Instance,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Val,
+};
+
+/// The Wasm module used by most tests.
+const WAT: &str = r#"
+    (module
+        (type $binop (func (param i32 i32) (result i32)))
+        (table $table 2 (ref $binop) (ref.func $add))
+        (elem declare func $sub)
+        (func $add (type $binop) (i32.add (local.get 0) (local.get 1)))
+        (func $sub (type $binop) (i32.sub (local.get 0) (local.get 1)))
+        (func $select (param $sub i32) (result (ref $binop))
+            (if (result (ref $binop)) (local.get $sub)
+                (then (ref.func $sub))
+                (else (table.get $table (i32.const 0)))
+            )
+        )
+        (func (export "call_ref") (param $sub i32) (param i32 i32) (result i32)
+            (call_ref $binop
+                (local.get 1)
+                (local.get 2)
+                (call $select (local.get $sub))
+            )
+        )
+        (func $tail (param $sub i32) (param i32 i32) (result i32)
+            (return_call_ref $binop
+                (local.get 1)
+                (local.get 2)
+                (call $select (local.get $sub))
+            )
+        )
+        (func (export "return_call_ref") (param $sub i32) (param i32 i32) (result i32)
+            (call $tail (local.get 0) (local.get 1) (local.get 2))
+        )
+        (func $maybe (param $null i32) (result (ref null $binop))
+            (if (result (ref null $binop)) (local.get $null)
+                (then (ref.null $binop))
+                (else (ref.func $add))
+            )
+        )
+        (func (export "call_ref_null") (result i32)
+            (call_ref $binop (i32.const 1) (i32.const 2) (ref.null $binop))
+        )
+        (func (export "as_non_null") (param $null i32) (result i32)
+            (call_ref $binop
+                (i32.const 1)
+                (i32.const 2)
+                (ref.as_non_null (call $maybe (local.get $null)))
+            )
+        )
+        (func (export "br_on_null") (param $null i32) (result i32)
+            (block $is_null
+                (return
+                    (call_ref $binop
+                        (i32.const 10)
+                        (i32.const 20)
+                        (br_on_null $is_null (call $maybe (local.get $null)))
+                    )
+                )
+            )
+            (i32.const -1)
+        )
+        (func (export "br_on_non_null") (param $null i32) (result i32)
+            (call_ref $binop
+                (i32.const 5)
+                (i32.const 6)
+                (block $non_null (result (ref $binop))
+                    (br_on_non_null $non_null (call $maybe (local.get $null)))
+                    (return (i32.const -1))
+                )
+            )
+        )
+        (func (export "table_size") (result i32)
+            (table.size $table)
+        )
+    )
+"#;
+
+/// Returns a [`Config`] with the Wasm `function-references` proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_function_references(true);
+    config
+}
+
+/// Creates a [`Store`] with a single instance of [`WAT`].
+fn setup() -> (Store<()>, Instance) {
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Calls the exported function `name` of `instance` with `params`.
+fn call(
+    store: &mut Store<()>,
+    instance: &Instance,
+    name: &str,
+    params: &[i32],
+) -> Result<i32, wasmi::Error> {
+    let func = instance.get_func(&*store, name).unwrap();
+    let params = params.iter().copied().map(Val::from).collect::<Vec<_>>();
+    let mut results = [Val::I32(0)];
+    func.call(&mut *store, &params, &mut results)?;
+    Ok(results[0].i32().unwrap())
+}
+
+#[test]
+fn disabled_by_default() {
+    let engine = Engine::default();
+    let wasm = wat::parse_str(WAT).unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn call_ref_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "call_ref", &[0, 7, 3]).unwrap(),
+        10
+    );
+    assert_eq!(
+        call(&mut store, &instance, "call_ref", &[1, 7, 3]).unwrap(),
+        4
+    );
+}
+
+#[test]
+fn return_call_ref_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "return_call_ref", &[0, 7, 3]).unwrap(),
+        10
+    );
+    assert_eq!(
+        call(&mut store, &instance, "return_call_ref", &[1, 7, 3]).unwrap(),
+        4
+    );
+}
+
+#[test]
+fn call_ref_null_traps() {
+    let (mut store, instance) = setup();
+    let error = call(&mut store, &instance, "call_ref_null", &[]).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::NullReference));
+}
+
+#[test]
+fn ref_as_non_null_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "as_non_null", &[0]).unwrap(), 3);
+    let error = call(&mut store, &instance, "as_non_null", &[1]).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::NullReference));
+}
+
+#[test]
+fn br_on_null_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "br_on_null", &[0]).unwrap(), 30);
+    assert_eq!(call(&mut store, &instance, "br_on_null", &[1]).unwrap(), -1);
+}
+
+#[test]
+fn br_on_non_null_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "br_on_non_null", &[0]).unwrap(),
+        11
+    );
+    assert_eq!(
+        call(&mut store, &instance, "br_on_non_null", &[1]).unwrap(),
+        -1
+    );
+}
+
+#[test]
+fn non_nullable_table_with_initializer() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "table_size", &[]).unwrap(), 2);
+}
+
+#[test]
+fn register_func_type() {
+    let engine = Engine::new(&config());
+    let binop = FuncType::new([ValType::I32, ValType::I32], [ValType::I32]);
+    let id = engine.register_func_type(binop.clone());
+    assert_eq!(engine.func_type_by_id(id), Some(binop));
+}
+
+#[test]
+fn typed_global_imports() {
+    let wat = r#"
+        (module
+            (type $binop (func (param i32 i32) (result i32)))
+            (import "env" "f" (global $f (ref $binop)))
+            (func (export "call") (param i32 i32) (result i32)
+                (call_ref $binop (local.get 0) (local.get 1) (global.get $f))
+            )
+        )
+    "#;
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let mul = Func::wrap(&mut store, |lhs: i32, rhs: i32| lhs * rhs);
+    let neg = Func::wrap(&mut store, |value: i32| -value);
+    let binop = FuncType::new([ValType::I32, ValType::I32], [ValType::I32]);
+    let typed = ValType::from(RefType::new(
+        false,
+        HeapType::Concrete(engine.register_func_type(binop)),
+    ));
+    assert!(Val::from(FuncRef::new(mul)).matches_ty(&store, &typed));
+    assert!(!Val::from(FuncRef::new(neg)).matches_ty(&store, &typed));
+    assert!(!Val::from(FuncRef::null()).matches_ty(&store, &typed));
+    // Globals of typed function references reject mismatching values.
+    let global_type = GlobalType::new(typed, Mutability::Const);
+    assert!(Global::new_typed(&mut store, global_type, Val::from(FuncRef::new(neg))).is_err());
+    assert!(Global::new_typed(&mut store, global_type, Val::from(FuncRef::null())).is_err());
+    // An untyped `funcref` global does not satisfy the typed import.
+    let untyped = Global::new(&mut store, Val::from(FuncRef::new(mul)), Mutability::Const);
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "f", Extern::Global(untyped)).unwrap();
+    assert!(linker.instantiate(&mut store, &module).is_err());
+    // A typed global holding a matching function satisfies the typed import.
+    let global = Global::new_typed(&mut store, global_type, Val::from(FuncRef::new(mul))).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "f", Extern::Global(global)).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    assert_eq!(call(&mut store, &instance, "call", &[6, 7]).unwrap(), 42);
+}
+
+#[test]
+fn host_func_non_nullable_results() {
+    let wat = r#"
+        (module
+            (import "env" "get" (func $get (param i32) (result (ref func))))
+            (func (export "is_null") (param i32) (result i32)
+                (ref.is_null (call $get (local.get 0)))
+            )
+        )
+    "#;
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let target = Func::wrap(&mut store, || ());
+    let non_null_func = ValType::from(RefType::new(false, HeapType::Func));
+    let get = Func::new(
+        &mut store,
+        FuncType::new([ValType::I32], [non_null_func]),
+        move |_caller, params, results| {
+            results[0] = match params[0].i32().unwrap() {
+                0 => Val::from(FuncRef::new(target)),
+                _ => Val::from(FuncRef::null()),
+            };
+            Ok(())
+        },
+    );
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "get", get).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    assert_eq!(call(&mut store, &instance, "is_null", &[0]).unwrap(), 0);
+    // Host functions must not return `null` for non-nullable reference types.
+    let error = call(&mut store, &instance, "is_null", &[1]).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::MismatchingResultType)
+    ));
+}
+
+#[test]
+fn raw_host_func_non_nullable_results() {
+    let wat = r#"
+        (module
+            (import "env" "get" (func $get (param i32) (result (ref func))))
+            (func (export "is_null") (param i32) (result i32)
+                (ref.is_null (call $get (local.get 0)))
+            )
+        )
+    "#;
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let target = Func::wrap(&mut store, || ());
+    let non_null_func = ValType::from(RefType::new(false, HeapType::Func));
+    let get = Func::new_raw(
+        &mut store,
+        FuncType::new([ValType::I32], [non_null_func]),
+        move |_caller, mut inout| {
+            inout.results_mut()[0] = match i32::from(inout.params()[0]) {
+                0 => UntypedVal::from(FuncRef::new(target)),
+                _ => UntypedVal::from(FuncRef::null()),
+            };
+            Ok(())
+        },
+    );
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "get", get).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    assert_eq!(call(&mut store, &instance, "is_null", &[0]).unwrap(), 0);
+    // Raw host functions must not return `null` for non-nullable reference types either.
+    let error = call(&mut store, &instance, "is_null", &[1]).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::MismatchingResultType)
+    ));
+}
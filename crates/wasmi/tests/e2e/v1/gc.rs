@@ -0,0 +1,713 @@
+//! Tests for the Wasm `gc` proposal.
+
+use wasmi::{
+    core::TrapCode,
+    Caller,
+    Config,
+    Engine,
+    Error,
+    Instance,
+    Linker,
+    Module,
+    ResumableCall,
+    Store,
+    Val,
+};
+
+/// The Wasm module used by most tests.
+const WAT: &str = r#"
+    (module
+        (type $base (sub (struct (field i32))))
+        (type $point (sub $base (struct (field i32) (field (mut i32)))))
+        (type $alias (sub (struct (field i32))))
+        (type $packed (struct (field (mut i8)) (field (mut i16))))
+        (type $bytes (array (mut i8)))
+        (type $ints (array (mut i32)))
+        (type $refs (array (ref null $base)))
+        (data $data "\01\02\ff")
+        (elem $elem (ref null $base) (item (struct.new $base (i32.const 7))) (item (ref.null $base)))
+        (global $origin (ref $point) (struct.new $point (i32.const 1) (i32.const 2)))
+        (func $point (param i32 i32) (result (ref $point))
+            (struct.new $point (local.get 0) (local.get 1))
+        )
+        (func (export "struct_get") (param i32 i32) (result i32)
+            (struct.get $point 1 (call $point (local.get 0) (local.get 1)))
+        )
+        (func (export "struct_set") (param i32) (result i32)
+            (local $p (ref $point))
+            (local.set $p (call $point (i32.const 0) (i32.const 0)))
+            (struct.set $point 1 (local.get $p) (local.get 0))
+            (struct.get $point 1 (local.get $p))
+        )
+        (func (export "struct_new_default") (result i32)
+            (struct.get $point 1 (struct.new_default $point))
+        )
+        (func (export "struct_packed") (param i32) (result i32 i32 i32 i32)
+            (local $p (ref $packed))
+            (local.set $p (struct.new $packed (local.get 0) (local.get 0)))
+            (struct.get_s $packed 0 (local.get $p))
+            (struct.get_u $packed 0 (local.get $p))
+            (struct.get_s $packed 1 (local.get $p))
+            (struct.get_u $packed 1 (local.get $p))
+        )
+        (func (export "struct_get_null") (result i32)
+            (struct.get $point 0 (ref.null $point))
+        )
+        (func (export "global_get") (result i32)
+            (i32.add
+                (struct.get $point 0 (global.get $origin))
+                (struct.get $point 1 (global.get $origin))
+            )
+        )
+        (func (export "array_new") (param i32 i32) (result i32)
+            (array.len (array.new $ints (local.get 0) (local.get 1)))
+        )
+        (func (export "array_get") (param i32) (result i32)
+            (array.get $ints
+                (array.new_fixed $ints 3 (i32.const 10) (i32.const 20) (i32.const 30))
+                (local.get 0)
+            )
+        )
+        (func (export "array_set_fill_copy") (result i32)
+            (local $a (ref $ints))
+            (local $b (ref $ints))
+            (local.set $a (array.new_default $ints (i32.const 4)))
+            (local.set $b (array.new $ints (i32.const 5) (i32.const 4)))
+            (array.fill $ints (local.get $a) (i32.const 1) (i32.const 3) (i32.const 2))
+            (array.set $ints (local.get $b) (i32.const 0) (i32.const 100))
+            (array.copy $ints $ints (local.get $a) (i32.const 3) (local.get $b) (i32.const 0) (i32.const 1))
+            ;; a = [0, 3, 3, 100]
+            (i32.add
+                (i32.add (array.get $ints (local.get $a) (i32.const 0)) (array.get $ints (local.get $a) (i32.const 1)))
+                (i32.add (array.get $ints (local.get $a) (i32.const 2)) (array.get $ints (local.get $a) (i32.const 3)))
+            )
+        )
+        (func (export "array_new_data") (param i32) (result i32 i32)
+            (local $a (ref $bytes))
+            (local.set $a (array.new_data $bytes $data (i32.const 0) (i32.const 3)))
+            (array.get_s $bytes (local.get $a) (local.get 0))
+            (array.get_u $bytes (local.get $a) (local.get 0))
+        )
+        (func (export "array_new_data_oob") (result i32)
+            (array.len (array.new_data $bytes $data (i32.const 2) (i32.const 2)))
+        )
+        (func (export "array_init_data") (result i32)
+            (local $a (ref $bytes))
+            (local.set $a (array.new_default $bytes (i32.const 4)))
+            (array.init_data $bytes $data (local.get $a) (i32.const 1) (i32.const 0) (i32.const 2))
+            (i32.add
+                (array.get_u $bytes (local.get $a) (i32.const 1))
+                (array.get_u $bytes (local.get $a) (i32.const 2))
+            )
+        )
+        (func (export "array_new_elem") (result i32 i32)
+            (local $a (ref $refs))
+            (local.set $a (array.new_elem $refs $elem (i32.const 0) (i32.const 2)))
+            (struct.get $base 0 (array.get $refs (local.get $a) (i32.const 0)))
+            (ref.is_null (array.get $refs (local.get $a) (i32.const 1)))
+        )
+        (func (export "array_oob") (param i32) (result i32)
+            (array.get $ints (array.new_default $ints (i32.const 2)) (local.get 0))
+        )
+        (func (export "i31") (param i32) (result i32 i32)
+            (i31.get_s (ref.i31 (local.get 0)))
+            (i31.get_u (ref.i31 (local.get 0)))
+        )
+        (func (export "i31_null") (result i32)
+            (i31.get_s (ref.null i31))
+        )
+        (func $value (param $kind i32) (result anyref)
+            (block $case2
+                (block $case1
+                    (block $case0
+                        (br_table $case0 $case1 $case2 (local.get $kind))
+                    )
+                    (return (struct.new $base (i32.const 1)))
+                )
+                (return (call $point (i32.const 2) (i32.const 3)))
+            )
+            (ref.i31 (i32.const 4))
+        )
+        (func (export "ref_test") (param $kind i32) (result i32 i32 i32 i32 i32)
+            (ref.test (ref $base) (call $value (local.get $kind)))
+            (ref.test (ref $point) (call $value (local.get $kind)))
+            (ref.test (ref struct) (call $value (local.get $kind)))
+            (ref.test (ref i31) (call $value (local.get $kind)))
+            (ref.test (ref eq) (call $value (local.get $kind)))
+        )
+        (func (export "ref_test_null") (result i32 i32)
+            (ref.test (ref null $point) (ref.null any))
+            (ref.test (ref $point) (ref.null any))
+        )
+        (func (export "ref_cast") (param $kind i32) (result i32)
+            (struct.get $point 1 (ref.cast (ref $point) (call $value (local.get $kind))))
+        )
+        (func (export "br_on_cast") (param $kind i32) (result i32)
+            (block $is_base (result (ref $base))
+                (br_on_cast $is_base anyref (ref $base) (call $value (local.get $kind)))
+                (return (i32.const -1))
+            )
+            (struct.get $base 0)
+        )
+        (func (export "br_on_cast_fail") (param $kind i32) (result i32)
+            (block $not_i31 (result anyref)
+                (br_on_cast_fail $not_i31 anyref (ref i31) (call $value (local.get $kind)))
+                (return (i31.get_s))
+            )
+            (drop)
+            (i32.const -1)
+        )
+        (func (export "ref_eq") (param i32) (result i32 i32)
+            (local $a eqref)
+            (local.set $a (call $point (i32.const 0) (i32.const 0)))
+            (ref.eq (local.get $a) (local.get $a))
+            (ref.eq (local.get $a) (call $point (i32.const 0) (i32.const 0)))
+        )
+        (func (export "rec_canonical") (result i32)
+            (ref.test (ref $alias) (struct.new $base (i32.const 0)))
+        )
+        (func (export "convert") (param i32) (result i32)
+            (i31.get_s
+                (ref.cast (ref i31)
+                    (any.convert_extern (extern.convert_any (ref.i31 (local.get 0))))
+                )
+            )
+        )
+        (func (export "alloc") (param $n i32)
+            (loop $continue
+                (if (local.get $n)
+                    (then
+                        (drop (struct.new $base (local.get $n)))
+                        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                        (br $continue)
+                    )
+                )
+            )
+        )
+        (func (export "make") (param i32) (result anyref)
+            (struct.new $base (local.get 0))
+        )
+        (func (export "read") (param anyref) (result i32)
+            (struct.get $base 0 (ref.cast (ref $base) (local.get 0)))
+        )
+    )
+"#;
+
+/// Returns a [`Config`] with the Wasm `gc` proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_function_references(true);
+    config.wasm_gc(true);
+    config
+}
+
+/// Creates a [`Store`] with a single instance of `wat`.
+fn instantiate(engine: &Engine, wat: &str) -> (Store<()>, Instance) {
+    let wasm = wat::parse_str(wat).unwrap();
+    let module = Module::new(engine, &wasm[..]).unwrap();
+    let mut store = Store::new(engine, ());
+    let instance = Linker::new(engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Creates a [`Store`] with a single instance of [`WAT`].
+fn setup() -> (Store<()>, Instance) {
+    instantiate(&Engine::new(&config()), WAT)
+}
+
+/// Calls the exported function `name` of `instance` with `params` returning all `i32` results.
+fn call(
+    store: &mut Store<()>,
+    instance: &Instance,
+    name: &str,
+    params: &[i32],
+) -> Result<Vec<i32>, wasmi::Error> {
+    let func = instance.get_func(&*store, name).unwrap();
+    let params = params.iter().copied().map(Val::from).collect::<Vec<_>>();
+    let len_results = func.ty(&*store).results().len();
+    let mut results = vec![Val::I32(0); len_results];
+    func.call(&mut *store, &params, &mut results)?;
+    Ok(results.iter().map(|result| result.i32().unwrap()).collect())
+}
+
+/// Calls the exported function `name` of `instance` and asserts that it traps with `code`.
+fn assert_trap(
+    store: &mut Store<()>,
+    instance: &Instance,
+    name: &str,
+    params: &[i32],
+    code: TrapCode,
+) {
+    let error = call(store, instance, name, params).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(code));
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut config = Config::default();
+    config.wasm_function_references(true);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn struct_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "struct_get", &[1, 2]).unwrap(),
+        [2]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "struct_set", &[42]).unwrap(),
+        [42]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "struct_new_default", &[]).unwrap(),
+        [0]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "struct_packed", &[-1]).unwrap(),
+        [-1, 0xFF, -1, 0xFFFF]
+    );
+    assert_eq!(call(&mut store, &instance, "global_get", &[]).unwrap(), [3]);
+    assert_trap(
+        &mut store,
+        &instance,
+        "struct_get_null",
+        &[],
+        TrapCode::NullReference,
+    );
+}
+
+#[test]
+fn array_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "array_new", &[0, 5]).unwrap(),
+        [5]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "array_get", &[2]).unwrap(),
+        [30]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "array_set_fill_copy", &[]).unwrap(),
+        [106]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "array_new_data", &[0]).unwrap(),
+        [1, 1]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "array_new_data", &[2]).unwrap(),
+        [-1, 0xFF]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "array_init_data", &[]).unwrap(),
+        [3]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "array_new_elem", &[]).unwrap(),
+        [7, 1]
+    );
+    assert_trap(
+        &mut store,
+        &instance,
+        "array_oob",
+        &[2],
+        TrapCode::ArrayOutOfBounds,
+    );
+    assert_trap(
+        &mut store,
+        &instance,
+        "array_new_data_oob",
+        &[],
+        TrapCode::MemoryOutOfBounds,
+    );
+}
+
+#[test]
+fn i31_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "i31", &[5]).unwrap(), [5, 5]);
+    assert_eq!(
+        call(&mut store, &instance, "i31", &[-1]).unwrap(),
+        [-1, 0x7FFF_FFFF]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "i31", &[0x4000_0000]).unwrap(),
+        [-0x4000_0000, 0x4000_0000]
+    );
+    assert_trap(
+        &mut store,
+        &instance,
+        "i31_null",
+        &[],
+        TrapCode::NullReference,
+    );
+}
+
+#[test]
+fn ref_test_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "ref_test", &[0]).unwrap(),
+        [1, 0, 1, 0, 1]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "ref_test", &[1]).unwrap(),
+        [1, 1, 1, 0, 1]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "ref_test", &[2]).unwrap(),
+        [0, 0, 0, 1, 1]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "ref_test_null", &[]).unwrap(),
+        [1, 0]
+    );
+}
+
+#[test]
+fn ref_cast_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "ref_cast", &[1]).unwrap(), [3]);
+    assert_trap(
+        &mut store,
+        &instance,
+        "ref_cast",
+        &[0],
+        TrapCode::CastFailure,
+    );
+    assert_trap(
+        &mut store,
+        &instance,
+        "ref_cast",
+        &[2],
+        TrapCode::CastFailure,
+    );
+}
+
+#[test]
+fn br_on_cast_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "br_on_cast", &[0]).unwrap(),
+        [1]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "br_on_cast", &[1]).unwrap(),
+        [2]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "br_on_cast", &[2]).unwrap(),
+        [-1]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "br_on_cast_fail", &[0]).unwrap(),
+        [-1]
+    );
+    assert_eq!(
+        call(&mut store, &instance, "br_on_cast_fail", &[2]).unwrap(),
+        [4]
+    );
+}
+
+#[test]
+fn ref_eq_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "ref_eq", &[0]).unwrap(), [1, 0]);
+}
+
+#[test]
+fn conversions_work() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "convert", &[-7]).unwrap(), [-7]);
+}
+
+#[test]
+fn rec_groups_are_canonicalized() {
+    let (mut store, instance) = setup();
+    assert_eq!(
+        call(&mut store, &instance, "rec_canonical", &[]).unwrap(),
+        [1]
+    );
+    // Identical recursion groups of different modules are the same types.
+    let engine = Engine::new(&config());
+    let wat = r#"
+        (module
+            (rec
+                (type $node (struct (field i32) (field (ref null $list))))
+                (type $list (array (ref null $node)))
+            )
+            (func (export "make") (result (ref $node))
+                (struct.new $node (i32.const 42) (ref.null $list))
+            )
+        )
+    "#;
+    let import = r#"
+        (module
+            (rec
+                (type $node (struct (field i32) (field (ref null $list))))
+                (type $list (array (ref null $node)))
+            )
+            (import "env" "make" (func $make (result (ref $node))))
+            (func (export "get") (result i32)
+                (struct.get $node 0 (call $make))
+            )
+        )
+    "#;
+    let (mut store, instance) = instantiate(&engine, wat);
+    let make = instance.get_func(&store, "make").unwrap();
+    let wasm = wat::parse_str(import).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "make", make).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    assert_eq!(call(&mut store, &instance, "get", &[]).unwrap(), [42]);
+}
+
+#[test]
+fn calls_through_supertypes_work() {
+    let engine = Engine::new(&config());
+    let wat = r#"
+        (module
+            (type $super (sub (func (result i32))))
+            (type $sub (sub final $super (func (result i32))))
+            (type $other (func (result i32)))
+            (table 2 funcref)
+            (elem (i32.const 0) func $sub $other)
+            (func $sub (type $sub) (i32.const 42))
+            (func $other (type $other) (i32.const 7))
+            (func (export "call_indirect") (param i32) (result i32)
+                (call_indirect (type $super) (local.get 0))
+            )
+            (func (export "return_call_indirect") (param i32) (result i32)
+                (return_call_indirect (type $super) (local.get 0))
+            )
+            (func (export "call_ref") (result i32)
+                (call_ref $super (ref.func $sub))
+            )
+            (func (export "return_call_ref") (result i32)
+                (return_call_ref $super (ref.func $sub))
+            )
+        )
+    "#;
+    let (mut store, instance) = instantiate(&engine, wat);
+    for name in ["call_indirect", "return_call_indirect"] {
+        // Note: the second call hits the inline cache of the call site.
+        for _ in 0..2 {
+            assert_eq!(call(&mut store, &instance, name, &[0]).unwrap(), [42]);
+            assert_trap(&mut store, &instance, name, &[1], TrapCode::BadSignature);
+        }
+    }
+    for name in ["call_ref", "return_call_ref"] {
+        assert_eq!(call(&mut store, &instance, name, &[]).unwrap(), [42]);
+    }
+}
+
+#[test]
+fn host_roundtrip_works() {
+    let (mut store, instance) = setup();
+    let make = instance.get_func(&store, "make").unwrap();
+    let read = instance.get_func(&store, "read").unwrap();
+    let mut anyref = [Val::I32(0)];
+    make.call(&mut store, &[Val::I32(11)], &mut anyref).unwrap();
+    assert!(matches!(anyref[0], Val::AnyRef(_)));
+    let mut result = [Val::I32(0)];
+    read.call(&mut store, &anyref, &mut result).unwrap();
+    assert_eq!(result[0].i32(), Some(11));
+}
+
+#[test]
+fn gc_reclaims_unreachable_objects() {
+    let (mut store, instance) = setup();
+    let live = store.gc_objects();
+    call(&mut store, &instance, "alloc", &[100]).unwrap();
+    assert_eq!(store.gc_objects(), live + 100);
+    assert!(store.gc() >= 100);
+    assert!(store.gc_objects() <= live);
+    // Objects reachable from globals and element segments survive collection.
+    assert_eq!(call(&mut store, &instance, "global_get", &[]).unwrap(), [3]);
+    assert_eq!(
+        call(&mut store, &instance, "array_new_elem", &[]).unwrap(),
+        [7, 1]
+    );
+}
+
+/// The Wasm module used by tests of automatic garbage collection.
+const COLLECT_WAT: &str = r#"
+    (module
+        (type $node (struct (field i32) (field (ref null $node))))
+        (type $ints (array (mut i32)))
+        (import "host" "garbage" (func $host_garbage (param i32)))
+        (import "host" "suspend" (func $suspend))
+        (func $garbage (export "garbage") (param $n i32)
+            (loop $continue
+                (drop (array.new_default $ints (i32.const 16)))
+                (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                (br_if $continue (local.get $n))
+            )
+        )
+        (func (export "alloc_loop") (param $n i32) (result i32)
+            (local $list (ref null $node))
+            (local $sum i32)
+            (loop $continue
+                (drop (array.new_default $ints (i32.const 16)))
+                (if (i32.eqz (i32.rem_u (local.get $n) (i32.const 1000)))
+                    (then
+                        (local.set $list (struct.new $node (local.get $n) (local.get $list)))
+                    )
+                )
+                (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                (br_if $continue (local.get $n))
+            )
+            (block $done
+                (loop $walk
+                    (br_if $done (ref.is_null (local.get $list)))
+                    (local.set $sum
+                        (i32.add (local.get $sum) (struct.get $node 0 (local.get $list)))
+                    )
+                    (local.set $list (struct.get $node 1 (local.get $list)))
+                    (br $walk)
+                )
+            )
+            (local.get $sum)
+        )
+        (func (export "make") (param i32) (result anyref)
+            (struct.new $node (local.get 0) (ref.null $node))
+        )
+        (func (export "read") (param anyref) (result i32)
+            (struct.get $node 0 (ref.cast (ref $node) (local.get 0)))
+        )
+        (func (export "across_host_call") (param i32) (result i32)
+            (local $node (ref $node))
+            (local.set $node (struct.new $node (local.get 0) (ref.null $node)))
+            (call $host_garbage (i32.const 100000))
+            (struct.get $node 0 (local.get $node))
+        )
+        (func (export "across_suspension") (param i32) (result i32)
+            (local $node (ref $node))
+            (local.set $node (struct.new $node (local.get 0) (ref.null $node)))
+            (call $suspend)
+            (struct.get $node 0 (local.get $node))
+        )
+    )
+"#;
+
+/// Creates a [`Store`] with a single instance of [`COLLECT_WAT`].
+///
+/// - `host.garbage` calls back into the exported `garbage` function.
+/// - `host.suspend` always returns an error.
+fn setup_collect() -> (Store<()>, Instance) {
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(COLLECT_WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap("host", "garbage", |mut caller: Caller<()>, n: i32| {
+            let garbage = caller.get_export("garbage").unwrap().into_func().unwrap();
+            garbage.call(&mut caller, &[Val::I32(n)], &mut [])
+        })
+        .unwrap()
+        .func_wrap("host", "suspend", || -> Result<(), Error> {
+            Err(Error::new("suspend"))
+        })
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn alloc_loop_collects_garbage() {
+    let (mut store, instance) = setup_collect();
+    let result = call(&mut store, &instance, "alloc_loop", &[200_000]).unwrap();
+    // Note: the nodes kept alive in a local survive all automatic collections.
+    assert_eq!(result, [(1..=200).sum::<i32>() * 1000]);
+    assert!(store.gc_objects() < 20_000);
+}
+
+#[test]
+fn collection_keeps_objects_of_host_calling_wasm() {
+    let (mut store, instance) = setup_collect();
+    let result = call(&mut store, &instance, "across_host_call", &[42]).unwrap();
+    assert_eq!(result, [42]);
+    assert!(store.gc_objects() < 20_000);
+}
+
+#[test]
+fn collection_keeps_objects_held_by_host() {
+    let (mut store, instance) = setup_collect();
+    let make = instance.get_func(&store, "make").unwrap();
+    let read = instance.get_func(&store, "read").unwrap();
+    let mut anyref = [Val::I32(0)];
+    make.call(&mut store, &[Val::I32(11)], &mut anyref).unwrap();
+    call(&mut store, &instance, "garbage", &[100_000]).unwrap();
+    let mut result = [Val::I32(0)];
+    read.call(&mut store, &anyref, &mut result).unwrap();
+    assert_eq!(result[0].i32(), Some(11));
+    // Released host references are reclaimed by the next collection.
+    store.gc();
+    let live = store.gc_objects();
+    store.gc_release_host_refs();
+    store.gc();
+    assert_eq!(store.gc_objects(), live - 1);
+}
+
+#[test]
+fn collection_releases_objects_held_by_host_scope() {
+    let (mut store, instance) = setup_collect();
+    let make = instance.get_func(&store, "make").unwrap();
+    let read = instance.get_func(&store, "read").unwrap();
+    store.gc();
+    let live = store.gc_objects();
+    let result = store.gc_scope(|store| {
+        let mut anyref = [Val::I32(0)];
+        make.call(&mut *store, &[Val::I32(11)], &mut anyref)
+            .unwrap();
+        store.gc();
+        let mut result = [Val::I32(0)];
+        read.call(&mut *store, &anyref, &mut result).unwrap();
+        result[0].i32()
+    });
+    assert_eq!(result, Some(11));
+    // Host references handed out within the scope are released once it returns.
+    store.gc();
+    assert_eq!(store.gc_objects(), live);
+}
+
+#[test]
+fn collection_keeps_objects_of_resumable_calls() {
+    let (mut store, instance) = setup_collect();
+    let func = instance.get_func(&store, "across_suspension").unwrap();
+    let mut result = [Val::I32(0)];
+    let ResumableCall::Resumable(invocation) = func
+        .call_resumable(&mut store, &[Val::I32(7)], &mut result)
+        .unwrap()
+    else {
+        panic!("expected a resumable call")
+    };
+    call(&mut store, &instance, "garbage", &[100_000]).unwrap();
+    store.gc();
+    let ResumableCall::Finished = invocation.resume(&mut store, &[], &mut result).unwrap() else {
+        panic!("expected a finished call")
+    };
+    assert_eq!(result[0].i32(), Some(7));
+    // The object of a finished resumable call is no longer a root.
+    store.gc();
+    assert_eq!(store.gc_objects(), 0);
+}
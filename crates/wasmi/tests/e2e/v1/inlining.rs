@@ -0,0 +1,219 @@
+//! Tests to check that inlining of small functions preserves the Wasm semantics.
+
+use wasmi::{Config, Engine, Error, Instance, Linker, Module, Store, Val};
+
+/// The Wasm module used to check the semantics of inlined functions.
+const WAT: &str = r#"
+    (module
+        (memory 1)
+        (global $counter (mut i32) (i32.const 0))
+        (func $get_counter (result i32)
+            (global.get $counter)
+        )
+        (func $bump (param $delta i32)
+            (global.set $counter (i32.add (global.get $counter) (local.get $delta)))
+        )
+        (func $load (param $ptr i32) (result i32)
+            (i32.load offset=4 (local.get $ptr))
+        )
+        (func $store (param $ptr i32) (param $value i32)
+            (i32.store offset=4 (local.get $ptr) (local.get $value))
+        )
+        (func $mul_add (param $a i32) (param $b i32) (param $c i32) (result i32)
+            (i32.add (i32.mul (local.get $a) (local.get $b)) (local.get $c))
+        )
+        (func $with_local (param $x i64) (result i64)
+            (local $acc i64)
+            (local.set $acc (i64.add (local.get $acc) (local.get $x)))
+            (i64.mul (local.get $acc) (i64.const 100000))
+        )
+        (func $div (param $a i32) (param $b i32) (result i32)
+            (i32.div_u (local.get $a) (local.get $b))
+        )
+        (func $fact (param $n i32) (result i32)
+            (if (result i32) (i32.eqz (local.get $n))
+                (then (i32.const 1))
+                (else (i32.mul (local.get $n) (call $fact (i32.sub (local.get $n) (i32.const 1)))))
+            )
+        )
+        (func (export "accessors") (param $n i32) (result i32)
+            (call $bump (local.get $n))
+            (call $bump (call $get_counter))
+            (call $store (local.get $n) (call $mul_add (local.get $n) (i32.const 3) (call $get_counter)))
+            (call $load (local.get $n))
+        )
+        (func (export "locals") (param $n i32) (result i64)
+            (i64.add
+                (call $with_local (i64.extend_i32_s (local.get $n)))
+                (call $with_local (i64.const 7))
+            )
+        )
+        (func (export "div") (param $n i32) (result i32)
+            (call $div (i32.const 1000) (local.get $n))
+        )
+        (func (export "fact") (param $n i32) (result i32)
+            (call $fact (local.get $n))
+        )
+        (func (export "loop") (param $n i32) (result i32)
+            (local $i i32)
+            (loop $continue
+                (call $bump (local.get $i))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $continue (i32.lt_u (local.get $i) (local.get $n)))
+            )
+            (call $get_counter)
+        )
+    )
+"#;
+
+/// Instantiates [`WAT`] with the given maximum number of instructions of inlined functions.
+///
+/// Fuel metering is enabled.
+fn setup(config: &mut Config, max_inline_len: u32) -> (Store<()>, Instance) {
+    config.max_inline_len(max_inline_len).consume_fuel(true);
+    let engine = Engine::new(config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Calls the exported function `name` with `input` with the given maximum inlining length.
+///
+/// Returns the result of the call and the consumed fuel.
+fn call<R>(
+    config: &mut Config,
+    max_inline_len: u32,
+    name: &str,
+    input: i32,
+) -> (Result<R, String>, u64)
+where
+    R: wasmi::WasmResults,
+{
+    let (mut store, instance) = setup(config, max_inline_len);
+    let fuel = 1_000_000;
+    store.set_fuel(fuel).unwrap();
+    let func = instance.get_typed_func::<i32, R>(&store, name).unwrap();
+    let result = func
+        .call(&mut store, input)
+        .map_err(|error: Error| error.to_string());
+    let consumed = fuel - store.get_fuel().unwrap();
+    (result, consumed)
+}
+
+/// Asserts that the exported function `name` behaves the same with and without inlining.
+fn assert_same<R>(name: &str, inputs: impl IntoIterator<Item = i32>)
+where
+    R: wasmi::WasmResults + PartialEq + std::fmt::Debug,
+{
+    for input in inputs {
+        let expected = call::<R>(&mut Config::default(), 0, name, input);
+        for max_inline_len in [1, 4, 16, 100] {
+            assert_eq!(
+                call::<R>(&mut Config::default(), max_inline_len, name, input),
+                expected,
+                "{name}({input}) differs for max_inline_len = {max_inline_len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn accessors() {
+    assert_same::<i32>("accessors", [0, 1, 7, 1000]);
+}
+
+#[test]
+fn zero_initialized_locals() {
+    assert_same::<i64>("locals", [-1, 0, 1, 42]);
+}
+
+#[test]
+fn traps() {
+    assert_same::<i32>("div", [0, 1, 3]);
+}
+
+#[test]
+fn recursion() {
+    assert_same::<i32>("fact", [0, 1, 5, 10]);
+}
+
+#[test]
+fn loops() {
+    assert_same::<i32>("loop", [1, 10, 100]);
+}
+
+#[test]
+fn backtraces() {
+    let mut config = Config::default();
+    config.wasm_backtrace(true);
+    let (error, _) = call::<i32>(&mut config, 100, "div", 0);
+    let error = error.unwrap_err();
+    assert!(
+        error.contains(" - div\n"),
+        "expected the inlined function in the backtrace: {error}"
+    );
+}
+
+/// Calls the exported `div` function with `0` so that the inlined `$div` traps.
+///
+/// Returns the [`Error`] and the Wasm bytes.
+fn trap_inlined_div(config: &mut Config) -> (Error, Vec<u8>) {
+    config.max_inline_len(100);
+    let engine = Engine::new(config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<i32, i32>(&store, "div").unwrap();
+    (func.call(&mut store, 0).unwrap_err(), wasm)
+}
+
+#[test]
+fn backtrace_frames() {
+    /// The Wasm `i32.div_u` and `call` opcodes.
+    const I32_DIV_U: u8 = 0x6E;
+    const CALL: u8 = 0x10;
+    let (error, wasm) = trap_inlined_div(Config::default().wasm_backtrace(true));
+    let frames = error.backtrace().unwrap().frames();
+    let indices = frames
+        .iter()
+        .map(|frame| frame.func_index())
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [6, 10]);
+    let opcodes = frames
+        .iter()
+        .map(|frame| wasm[frame.module_offset().unwrap()])
+        .collect::<Vec<_>>();
+    assert_eq!(opcodes, [I32_DIV_U, CALL]);
+}
+
+#[test]
+fn coredump_frames() {
+    let (error, _) = trap_inlined_div(Config::default().coredump_on_trap(true));
+    let frames = error.coredump().unwrap().frames();
+    let indices = frames
+        .iter()
+        .map(|frame| frame.func_index())
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [6, 10]);
+    let ints = |values: &[Option<Val>]| {
+        values
+            .iter()
+            .map(|value| value.as_ref()?.i32())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ints(frames[0].locals()), [Some(1000), Some(0)]);
+    assert_eq!(ints(frames[0].stack()), [Some(1000), Some(0)]);
+    assert_eq!(ints(frames[1].locals()), [Some(0)]);
+    assert_eq!(ints(frames[1].stack()), [Some(1000), Some(0)]);
+}
@@ -0,0 +1,68 @@
+//! Tests for typed host access to linear memory via `WasmPtr`, `WasmSlice` and `WasmStr`.
+
+use wasmi::{Caller, Engine, Extern, Linker, Memory, Module, Store, WasmPtr, WasmStr};
+
+fn caller_memory(caller: &Caller<Vec<String>>) -> Memory {
+    caller
+        .get_export("memory")
+        .and_then(Extern::into_memory)
+        .unwrap()
+}
+
+#[test]
+fn host_reads_and_writes_guest_memory() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, Vec::new());
+    let mut linker = <Linker<Vec<String>>>::new(&engine);
+    linker
+        .func_wrap(
+            "env",
+            "log",
+            |mut caller: Caller<Vec<String>>, ptr: WasmPtr<u8>, len: u32| {
+                let memory = caller_memory(&caller);
+                let message = WasmStr::new(ptr, len)
+                    .read_string(&memory, &caller)
+                    .unwrap();
+                caller.data_mut().push(message);
+            },
+        )
+        .unwrap();
+    linker
+        .func_wrap(
+            "env",
+            "sum",
+            |mut caller: Caller<Vec<String>>, ptr: WasmPtr<u32>, len: u32, out: WasmPtr<u64>| {
+                let memory = caller_memory(&caller);
+                let values = ptr.slice(len).read_to_vec(&memory, &caller).unwrap();
+                let sum = values.into_iter().map(u64::from).sum::<u64>();
+                out.write(&memory, &mut caller, sum).unwrap();
+            },
+        )
+        .unwrap();
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (import "env" "log" (func $log (param i32 i32)))
+            (import "env" "sum" (func $sum (param i32 i32 i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 0) "hello")
+            (data (i32.const 16) "\01\00\00\00\02\00\00\00\03\00\00\00")
+            (func (export "run") (result i64)
+                (call $log (i32.const 0) (i32.const 5))
+                (call $sum (i32.const 16) (i32.const 3) (i32.const 32))
+                (i64.load (i32.const 32))
+            )
+        )
+        "#,
+    )
+    .unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_typed_func::<(), i64>(&store, "run").unwrap();
+    assert_eq!(run.call(&mut store, ()).unwrap(), 6);
+    assert_eq!(store.data(), &["hello".to_string()]);
+}
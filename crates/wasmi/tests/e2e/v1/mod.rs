@@ -1,9 +1,26 @@
+mod backtrace;
+#[cfg(feature = "dwarf")]
+mod backtrace_dwarf;
 mod call_hook;
+mod call_indirect_cache;
+mod coredump;
+mod custom_page_sizes;
 mod fuel_consumption;
 mod fuel_metering;
 mod func;
+mod function_references;
+#[cfg(feature = "gc")]
+mod gc;
 mod host_call_compilation;
 mod host_call_instantiation;
 mod host_calls_wasm;
+mod inlining;
+mod memory_access;
+mod module_info;
+mod optimization;
+mod parallel_translation;
 mod resource_limiter;
 mod resumable_call;
+mod stack_switching;
+mod tiered_translation;
+mod wide_arithmetic;
@@ -0,0 +1,178 @@
+//! Tests to assert that [`Module`] provides information about its internals.
+
+use wasmi::{CompilationMode, Config, Engine, MemoryType, Module, Mutability};
+use wasmi_core::ValType;
+use wasmi_ir::IrFunc;
+
+/// The Wasm module used by all tests.
+const WASM: &str = r#"
+    (module
+        (import "env" "log" (func $log (param i32)))
+        (import "env" "memory" (memory 1))
+        (import "env" "global" (global i64))
+        (table 2 10 funcref)
+        (memory 3 4)
+        (global $counter (mut i32) (i32.const 0))
+        (func $init
+            (global.set $counter (i32.const 1))
+        )
+        (func $add (export "add") (param i32 i32) (result i32)
+            (call $log (local.get 0))
+            (i32.add (local.get 0) (local.get 1))
+        )
+        (start $init)
+    )
+"#;
+
+/// Returns the minimum and maximum pages of the [`MemoryType`].
+fn limits(ty: &MemoryType) -> (u32, Option<u32>) {
+    (
+        u32::from(ty.initial_pages()),
+        ty.maximum_pages().map(u32::from),
+    )
+}
+
+/// Compiles the Wasm module using the given [`CompilationMode`].
+fn compile(mode: CompilationMode) -> Module {
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    config.wasm_backtrace(true);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WASM).unwrap();
+    Module::new(&engine, &wasm[..]).unwrap()
+}
+
+#[test]
+fn module_info() {
+    let module = compile(CompilationMode::Eager);
+    assert_eq!(module.start_func(), Some(1));
+    let funcs = module.funcs().collect::<Vec<_>>();
+    assert_eq!(funcs.len(), 3);
+    assert_eq!(funcs[0].params(), [ValType::I32]);
+    assert_eq!(funcs[1].params(), []);
+    assert_eq!(funcs[2].params(), [ValType::I32, ValType::I32]);
+    assert_eq!(funcs[2].results(), [ValType::I32]);
+    let tables = module.tables().collect::<Vec<_>>();
+    assert_eq!(tables.len(), 1);
+    assert_eq!((tables[0].minimum(), tables[0].maximum()), (2, Some(10)));
+    let memories = module.memories().collect::<Vec<_>>();
+    assert_eq!(memories.len(), 2);
+    assert_eq!(limits(memories[0]), (1, None));
+    assert_eq!(limits(memories[1]), (3, Some(4)));
+    let globals = module.globals().collect::<Vec<_>>();
+    assert_eq!(globals.len(), 2);
+    assert_eq!(globals[0].content(), ValType::I64);
+    assert_eq!(globals[0].mutability(), Mutability::Const);
+    assert_eq!(globals[1].content(), ValType::I32);
+    assert_eq!(globals[1].mutability(), Mutability::Var);
+    assert_eq!(module.get_func_name(0), Some("log"));
+    assert_eq!(module.get_func_name(2), Some("add"));
+}
+
+#[test]
+fn compiled_func_size() {
+    for mode in [
+        CompilationMode::Eager,
+        CompilationMode::LazyTranslation,
+        CompilationMode::Lazy,
+    ] {
+        let module = compile(mode);
+        // Imported and out of bounds functions have no Wasmi bytecode.
+        assert_eq!(module.compiled_func_size(0).unwrap(), None);
+        assert_eq!(module.compiled_func_size(3).unwrap(), None);
+        assert_eq!(module.disassemble(0).unwrap(), None);
+        for func_index in [1, 2] {
+            let size = module.compiled_func_size(func_index).unwrap().unwrap();
+            assert!(size > 0);
+            assert!(module.disassemble(func_index).unwrap().is_some());
+        }
+    }
+}
+
+#[test]
+fn disassemble() {
+    let wasm = r#"
+        (module
+            (func (param i32) (result i32)
+                (block
+                    (br_if 0 (i32.eqz (local.get 0)))
+                    (return (i32.add (local.get 0) (i32.const 100000)))
+                )
+                (i32.const 0)
+            )
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wat::parse_str(wasm).unwrap()[..]).unwrap();
+    assert_eq!(
+        module.disassemble(0).unwrap().unwrap(),
+        "\
+        registers: 3\n\
+        consts: 1\n\
+        \x20 c0 = 0x00000000000186a0\n\
+        instrs: 4\n\
+        \x20 0: branch_i32_eq_imm16 lhs=r0, rhs=0, offset=@3\n\
+        \x20 1: r1 = i32_add lhs=r0, rhs=c0\n\
+        \x20 2: return_reg value=r1\n\
+        \x20 3: return_imm32 value=0x00000000\n\
+        ",
+    );
+    assert_eq!(module.disassemble(1).unwrap(), None);
+}
+
+#[test]
+fn disassembly_round_trips() {
+    let wasm = r#"
+        (module
+            (memory 1)
+            (table 2 funcref)
+            (global $g (mut i64) (i64.const 0))
+            (func $fib (param i32) (result i32)
+                (if (result i32) (i32.lt_u (local.get 0) (i32.const 2))
+                    (then (local.get 0))
+                    (else
+                        (i32.add
+                            (call $fib (i32.sub (local.get 0) (i32.const 1)))
+                            (call $fib (i32.sub (local.get 0) (i32.const 2)))
+                        )
+                    )
+                )
+            )
+            (func $sum (param i32) (result i64) (local i64)
+                (loop $continue
+                    (local.set 1
+                        (i64.add
+                            (local.get 1)
+                            (i64.load offset=8 (i32.shl (local.get 0) (i32.const 3)))
+                        )
+                    )
+                    (br_if $continue (local.tee 0 (i32.sub (local.get 0) (i32.const 1))))
+                )
+                (global.set $g (local.get 1))
+                (f64.store (i32.const 0) (f64.copysign (f64.const 1.5) (f64.const -0.0)))
+                (local.get 1)
+            )
+            (func $switch (param i32 i32 i32) (result i32 i32)
+                (block $a
+                    (block $b
+                        (br_table $a $b $a (local.get 0))
+                    )
+                    (return (local.get 2) (local.get 1))
+                )
+                (call_indirect (param i32) (result i32) (local.get 1) (local.get 0))
+                (select (local.get 2) (i32.const 100000) (local.get 1))
+            )
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wat::parse_str(wasm).unwrap()[..]).unwrap();
+    for func_index in 0..3 {
+        let text = module.disassemble(func_index).unwrap().unwrap();
+        let func = text
+            .parse::<IrFunc>()
+            .unwrap_or_else(|error| panic!("failed to parse disassembly: {error}\n{text}"));
+        func.validate()
+            .unwrap_or_else(|error| panic!("invalid disassembly: {error}\n{text}"));
+        assert_eq!(func.to_string(), text);
+    }
+}
@@ -0,0 +1,273 @@
+//! Tests to check that optimizations of the Wasmi bytecode preserve the Wasm semantics.
+
+use wasmi::{Config, Engine, Error, Instance, Linker, Module, OptimizationLevel, Store};
+
+/// All [`OptimizationLevel`]s that are tested.
+const LEVELS: [OptimizationLevel; 3] = [
+    OptimizationLevel::None,
+    OptimizationLevel::Basic,
+    OptimizationLevel::Full,
+];
+
+/// The Wasm module used to check the semantics of all [`OptimizationLevel`]s.
+const WAT: &str = r#"
+    (module
+        (memory 1)
+        (func (export "sum") (param $n i32) (result i32)
+            (local $i i32) (local $acc i32) (local $done i32)
+            (block $exit
+                (loop $continue
+                    (local.set $done (i32.ge_s (local.get $i) (local.get $n)))
+                    (br_if $exit (local.get $done))
+                    (local.set $acc (i32.add (local.get $acc) (local.get $i)))
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br $continue)
+                )
+            )
+            (local.get $acc)
+        )
+        (func $fib (export "fib") (param $n i32) (result i64)
+            (if (result i64) (i32.lt_u (local.get $n) (i32.const 2))
+                (then (i64.extend_i32_u (local.get $n)))
+                (else
+                    (i64.add
+                        (call $fib (i32.sub (local.get $n) (i32.const 1)))
+                        (call $fib (i32.sub (local.get $n) (i32.const 2)))
+                    )
+                )
+            )
+        )
+        (func (export "swap") (param $a i32) (result i32)
+            (local $b i32) (local $t i32)
+            (local.set $b (i32.mul (local.get $a) (i32.const 3)))
+            (local.set $t (local.get $a))
+            (local.set $a (local.get $b))
+            (local.set $b (local.get $t))
+            (i32.sub (local.get $a) (local.get $b))
+        )
+        (func (export "classify") (param $x i32) (result i32)
+            (block $c
+                (block $b
+                    (block $a
+                        (br_table $a $b $c (local.get $x))
+                    )
+                    (br $c)
+                )
+                (return (i32.const 20))
+            )
+            (i32.const 30)
+        )
+        (func (export "memsum") (param $n i32) (result i32)
+            (local $i i32) (local $acc i32) (local $tmp i32)
+            (loop $fill
+                (i32.store (i32.shl (local.get $i) (i32.const 2)) (local.get $i))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (local.set $tmp (i32.lt_u (local.get $i) (local.get $n)))
+                (br_if $fill (local.get $tmp))
+            )
+            (local.set $i (i32.const 0))
+            (loop $sum
+                (local.set $tmp (i32.load (i32.shl (local.get $i) (i32.const 2))))
+                (local.set $acc (i32.add (local.get $acc) (local.get $tmp)))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $sum (i32.eqz (i32.ge_u (local.get $i) (local.get $n))))
+            )
+            (local.get $acc)
+        )
+        (data (i32.const 65520) "\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10")
+        (func (export "bounds") (param $p i32) (result i32)
+            (local $acc i32) (local $i i32)
+            (local.set $acc (i32.load offset=8 (local.get $p)))
+            (loop $l
+                (local.set $acc (i32.add (local.get $acc) (i32.load offset=4 (local.get $p))))
+                (local.set $acc (i32.add (local.get $acc) (i32.load16_u offset=10 (local.get $p))))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $l (i32.lt_u (local.get $i) (i32.const 3)))
+            )
+            (i32.store8 offset=11 (local.get $p) (local.get $acc))
+            (i32.add (local.get $acc) (i32.load offset=12 (local.get $p)))
+        )
+        (func (export "bounds64") (param $p i32) (result i64)
+            (local $acc i64)
+            (local.set $acc (i64.add (local.get $acc) (i64.load offset=8 (local.get $p))))
+            (i64.add (local.get $acc) (i64.load (local.get $p)))
+        )
+        (func (export "bounds_grow") (param $delta i32) (result i32)
+            (drop (memory.grow (local.get $delta)))
+            (i32.add
+                (i32.load (i32.const 65532))
+                (i32.add (i32.load (i32.const 65540)) (i32.load (i32.const 65536)))
+            )
+        )
+        (func (export "trap") (param $x i32) (result i32)
+            (local $y i32)
+            (local.set $y (local.get $x))
+            (i32.div_u (i32.const 100) (local.get $y))
+        )
+    )
+"#;
+
+/// Instantiates [`WAT`] with the given [`OptimizationLevel`] and bounds check elimination.
+///
+/// Fuel metering and Wasm backtraces are enabled.
+fn setup(level: OptimizationLevel, bounds_check_elimination: bool) -> (Store<()>, Instance) {
+    let mut config = Config::default();
+    config
+        .optimization_level(level)
+        .bounds_check_elimination(bounds_check_elimination)
+        .consume_fuel(true)
+        .wasm_backtrace(true);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Calls the exported function `name` with `input` with the given [`OptimizationLevel`]
+/// and bounds check elimination.
+///
+/// Returns the result of the call and the consumed fuel.
+fn call<R>(
+    level: OptimizationLevel,
+    bounds_check_elimination: bool,
+    name: &str,
+    input: i32,
+) -> (Result<R, String>, u64)
+where
+    R: wasmi::WasmResults,
+{
+    let (mut store, instance) = setup(level, bounds_check_elimination);
+    let fuel = 1_000_000;
+    store.set_fuel(fuel).unwrap();
+    let func = instance.get_typed_func::<i32, R>(&store, name).unwrap();
+    let result = func
+        .call(&mut store, input)
+        .map_err(|error: Error| error.to_string());
+    let consumed = fuel - store.get_fuel().unwrap();
+    (result, consumed)
+}
+
+/// Asserts that the exported function `name` behaves the same for all [`OptimizationLevel`]s
+/// with and without bounds check elimination.
+fn assert_same<R>(name: &str, inputs: impl IntoIterator<Item = i32>)
+where
+    R: wasmi::WasmResults + PartialEq + std::fmt::Debug,
+{
+    for input in inputs {
+        let expected = call::<R>(OptimizationLevel::None, false, name, input);
+        for level in LEVELS {
+            for bounds_check_elimination in [false, true] {
+                assert_eq!(
+                    call::<R>(level, bounds_check_elimination, name, input),
+                    expected,
+                    "{name}({input}) differs for {level:?} with bounds check elimination: {bounds_check_elimination}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn loops_with_locals() {
+    assert_same::<i32>("sum", [0, 1, 2, 10, 100]);
+    assert_same::<i32>("memsum", [1, 2, 10, 100]);
+}
+
+#[test]
+fn calls() {
+    assert_same::<i64>("fib", [0, 1, 2, 10, 20]);
+}
+
+#[test]
+fn copies() {
+    assert_same::<i32>("swap", [-5, 0, 1, 42]);
+}
+
+#[test]
+fn br_table() {
+    assert_same::<i32>("classify", [-1, 0, 1, 2, 3, 100]);
+}
+
+#[test]
+fn traps() {
+    assert_same::<i32>("trap", [0, 1, 7]);
+}
+
+#[test]
+fn bounds_checks() {
+    assert_same::<i32>(
+        "bounds",
+        [0, 100, 65512, 65520, 65524, 65526, 65528, 65536, -1],
+    );
+    assert_same::<i64>("bounds64", [0, 65512, 65520, 65524, 65528, -1]);
+    assert_same::<i32>("bounds_grow", [0, 1]);
+}
+
+#[test]
+fn out_of_fuel() {
+    for level in LEVELS {
+        let (mut store, instance) = setup(level, true);
+        store.set_fuel(100).unwrap();
+        let sum = instance.get_typed_func::<i32, i32>(&store, "sum").unwrap();
+        let error = sum.call(&mut store, 1_000).unwrap_err();
+        assert!(
+            matches!(error.as_trap_code(), Some(wasmi::core::TrapCode::OutOfFuel)),
+            "expected out of fuel trap for {level:?} but found: {error}"
+        );
+    }
+}
+
+#[test]
+fn branch_cmp_fallback() {
+    // Note: the branch offsets over this many instructions require `branch_cmp_fallback` instructions.
+    let len_adds = (1 << 15) + 1;
+    let adds = "(i32.add (i32.const 1))".repeat(len_adds);
+    let wat = format!(
+        r#"
+        (module
+            (func (export "run") (param $n i32) (result i32)
+                (local $acc i32) (local $tmp i32)
+                (loop $continue
+                    (block $skip
+                        (br_if $skip (i32.eqz (local.get $n)))
+                        (local.set $tmp (local.get $n))
+                        (local.get $acc)
+                        {adds}
+                        (local.set $acc)
+                    )
+                    (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                    (br_if $continue (i32.gt_s (local.get $n) (i32.const 0)))
+                )
+                (local.get $acc)
+            )
+        )
+        "#
+    );
+    let wasm = wat::parse_str(wat).unwrap();
+    for level in LEVELS {
+        let mut config = Config::default();
+        config.optimization_level(level);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = Linker::new(&engine)
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+        for n in [0, 1, 3] {
+            assert_eq!(
+                run.call(&mut store, n).unwrap(),
+                n * len_adds as i32,
+                "run({n}) failed for {level:?}"
+            );
+        }
+    }
+}
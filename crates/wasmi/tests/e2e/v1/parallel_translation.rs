@@ -0,0 +1,111 @@
+//! Tests to check that parallel translation yields the same results as sequential translation.
+
+use std::{fmt::Write as _, string::String};
+use wasmi::{Config, Engine, Linker, Module, Store};
+
+/// The number of generated functions.
+const LEN_FUNCS: u32 = 200;
+
+/// Returns a Wasm module with [`LEN_FUNCS`] functions of varying shapes.
+///
+/// The functions at the `invalid` indices fail to validate.
+fn generate_wat(invalid: &[u32]) -> String {
+    let mut wat = String::from("(module (memory 1) (global $g (mut i32) (i32.const 0))\n");
+    for n in 0..LEN_FUNCS {
+        if invalid.contains(&n) {
+            writeln!(wat, "(func $f{n} (result i32) (i64.const {n}))").unwrap();
+            continue;
+        }
+        let body = match n % 4 {
+            0 => format!("(i32.add (local.get 0) (i32.const {n}))"),
+            1 => format!(
+                "(local $i i32) (local $acc i32)
+                (block $exit
+                    (loop $continue
+                        (br_if $exit (i32.ge_u (local.get $i) (local.get 0)))
+                        (local.set $acc (i32.add (local.get $acc) (i32.const {n})))
+                        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                        (br $continue)
+                    )
+                )
+                (local.get $acc)"
+            ),
+            2 => format!(
+                "(i32.store (i32.const {n}) (local.get 0))
+                (global.set $g (i32.load (i32.const {n})))
+                (call $f{prev} (global.get $g))",
+                prev = n - 1,
+            ),
+            _ => format!(
+                "(if (result i32) (local.get 0)
+                    (then (call $f{prev} (i32.sub (local.get 0) (i32.const 1))))
+                    (else (i32.const {n}))
+                )",
+                prev = n - 3,
+            ),
+        };
+        writeln!(
+            wat,
+            "(func $f{n} (export \"f{n}\") (param i32) (result i32) {body})"
+        )
+        .unwrap();
+    }
+    wat.push(')');
+    wat
+}
+
+/// Compiles `wat` using `threads` translation threads.
+fn compile(wat: &str, threads: usize) -> Result<Module, String> {
+    let mut config = Config::default();
+    config.translation_threads(threads);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(wat).unwrap();
+    Module::new(&engine, &wasm[..]).map_err(|error| error.to_string())
+}
+
+#[test]
+fn same_bytecode() {
+    let wat = generate_wat(&[]);
+    let expected = compile(&wat, 1).unwrap();
+    for threads in [2, 4, 16] {
+        let module = compile(&wat, threads).unwrap();
+        for n in 0..LEN_FUNCS {
+            assert_eq!(
+                module.disassemble(n).unwrap(),
+                expected.disassemble(n).unwrap(),
+                "function {n} differs with {threads} threads"
+            );
+        }
+    }
+}
+
+#[test]
+fn same_execution() {
+    let wat = generate_wat(&[]);
+    let module = compile(&wat, 4).unwrap();
+    let mut store = Store::new(module.engine(), ());
+    let instance = Linker::new(module.engine())
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let f = instance.get_typed_func::<i32, i32>(&store, "f1").unwrap();
+    assert_eq!(f.call(&mut store, 10).unwrap(), 10);
+    let f = instance.get_typed_func::<i32, i32>(&store, "f7").unwrap();
+    assert_eq!(f.call(&mut store, 1).unwrap(), 4);
+}
+
+#[test]
+fn same_error() {
+    for invalid in [&[0][..], &[LEN_FUNCS - 1], &[50, 120], &[120, 121, 199]] {
+        let wat = generate_wat(invalid);
+        let expected = compile(&wat, 1).unwrap_err();
+        for threads in [2, 4, 16] {
+            assert_eq!(
+                compile(&wat, threads).unwrap_err(),
+                expected,
+                "error differs with {threads} threads for invalid functions {invalid:?}"
+            );
+        }
+    }
+}
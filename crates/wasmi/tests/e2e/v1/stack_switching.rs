@@ -0,0 +1,569 @@
+//! Tests for the Wasm `stack-switching` proposal.
+
+use wasmi::{
+    core::TrapCode,
+    Caller,
+    Config,
+    ContRef,
+    Engine,
+    Error,
+    Instance,
+    Linker,
+    Module,
+    OptimizationLevel,
+    Store,
+    TypedResumableCall,
+    Val,
+};
+
+/// The Wasm module used by most tests.
+const WAT: &str = r#"
+    (module
+        (import "env" "host" (func $host (param i32) (result i32)))
+
+        (type $ft (func))
+        (type $ct (cont $ft))
+        (type $ft_gen (func (param i32)))
+        (type $ct_gen (cont $ft_gen))
+        (type $ft_i32 (func (param i32) (result i32)))
+        (type $ct_i32 (cont $ft_i32))
+        (type $ft_2 (func (param i32 i32) (result i32)))
+        (type $ct_2 (cont $ft_2))
+        (type $ft_res (func (result i32)))
+        (type $ct_res (cont $ft_res))
+        (type $ft_sw (func (param (ref $ct_res)) (result i32)))
+        (type $ct_sw (cont $ft_sw))
+
+        (tag $yield (param i32))
+        (tag $ask (param i32) (result i32))
+        (tag $sw (result i32))
+
+        (elem declare func $gen $double_twice $sub $host $ping $pong $call_host $spin)
+
+        ;; Yields the values from `n` down to `1`.
+        (func $gen (param $n i32)
+            (loop $continue
+                (suspend $yield (local.get $n))
+                (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                (br_if $continue (local.get $n))
+            )
+        )
+
+        ;; Sums up all values yielded by `$gen` starting at `n`.
+        (func (export "sum") (param $n i32) (result i32)
+            (local $sum i32)
+            (local $k (ref null $ct))
+            (local.set $k
+                (cont.bind $ct_gen $ct
+                    (local.get $n)
+                    (cont.new $ct_gen (ref.func $gen))
+                )
+            )
+            (loop $next
+                (block $on_yield (result i32 (ref $ct))
+                    (resume $ct (on $yield $on_yield) (local.get $k))
+                    (return (local.get $sum))
+                )
+                (local.set $k)
+                (local.set $sum (i32.add (local.get $sum)))
+                (br $next)
+            )
+            (unreachable)
+        )
+
+        ;; Asks the handler twice to double a value.
+        (func $double_twice (param $x i32) (result i32)
+            (suspend $ask (suspend $ask (local.get $x)))
+        )
+
+        ;; Handles `$ask` by doubling its value.
+        (func (export "ask") (param $x i32) (result i32)
+            (local $k (ref null $ct_i32))
+            (local $v i32)
+            (local.set $v (local.get $x))
+            (local.set $k (cont.new $ct_i32 (ref.func $double_twice)))
+            (loop $next
+                (block $on_ask (result i32 (ref $ct_i32))
+                    (return (resume $ct_i32 (on $ask $on_ask) (local.get $v) (local.get $k)))
+                )
+                (local.set $k)
+                (local.set $v (i32.mul (i32.const 2)))
+                (br $next)
+            )
+            (unreachable)
+        )
+
+        (func $sub (param i32 i32) (result i32)
+            (i32.sub (local.get 0) (local.get 1))
+        )
+
+        ;; Binds the first parameter of `$sub` and resumes it without handlers.
+        (func (export "bind") (param i32 i32) (result i32)
+            (resume $ct_i32
+                (local.get 1)
+                (cont.bind $ct_2 $ct_i32 (local.get 0) (cont.new $ct_2 (ref.func $sub)))
+            )
+        )
+
+        ;; Resumes the host function `$host` as continuation.
+        (func (export "resume_host") (param i32) (result i32)
+            (resume $ct_i32 (local.get 0) (cont.new $ct_i32 (ref.func $host)))
+        )
+
+        ;; Calls the host function `$host` from within a continuation.
+        (func $call_host (param i32) (result i32)
+            (i32.add (call $host (local.get 0)) (i32.const 1))
+        )
+
+        (func (export "call_host") (param i32) (result i32)
+            (resume $ct_i32 (local.get 0) (cont.new $ct_i32 (ref.func $call_host)))
+        )
+
+        ;; Switches to `$pong` and returns `1` once it is resumed.
+        (func $ping (result i32)
+            (switch $ct_sw $sw (cont.new $ct_sw (ref.func $pong)))
+            (i32.const 1)
+        )
+
+        ;; Resumes `$ping` and adds `100` to its result.
+        (func $pong (param $k (ref $ct_res)) (result i32)
+            (i32.add (i32.const 100) (resume $ct_res (local.get $k)))
+        )
+
+        (func (export "switch") (result i32)
+            (resume $ct_res (on $sw switch) (cont.new $ct_res (ref.func $ping)))
+        )
+
+        (func (export "resume_null") (result i32)
+            (resume $ct_res (ref.null $ct_res))
+        )
+
+        (func (export "resume_twice") (result i32)
+            (local $k (ref null $ct_i32))
+            (local.set $k (cont.new $ct_i32 (ref.func $sub_one)))
+            (drop (resume $ct_i32 (i32.const 1) (local.get $k)))
+            (resume $ct_i32 (i32.const 2) (local.get $k))
+        )
+
+        (func $sub_one (param i32) (result i32)
+            (i32.sub (local.get 0) (i32.const 1))
+        )
+        (elem declare func $sub_one)
+
+        (func (export "unhandled") (param i32)
+            (resume $ct (cont.bind $ct_gen $ct (local.get 0) (cont.new $ct_gen (ref.func $gen))))
+        )
+
+        (func $spin (param $n i32) (result i32)
+            (loop $continue
+                (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                (br_if $continue (local.get $n))
+            )
+            (local.get $n)
+        )
+
+        ;; Yields `n` from a nested call frame via `$gen`.
+        (func $nested_gen (param $n i32)
+            (call $gen (local.get $n))
+        )
+
+        ;; Resumes `$nested_gen` with a handler for `$ask` only.
+        (func $inner (param $n i32)
+            (block $on_ask (result i32 (ref $ct_gen))
+                (resume $ct_gen (on $ask $on_ask)
+                    (local.get $n)
+                    (cont.new $ct_gen (ref.func $nested_gen))
+                )
+                (return)
+            )
+            (unreachable)
+        )
+
+        ;; Sums all values yielded through the intermediate `$inner` continuation.
+        (func (export "nested") (param $n i32) (result i32)
+            (local $sum i32)
+            (local $k (ref null $ct))
+            (local.set $k
+                (cont.bind $ct_gen $ct (local.get $n) (cont.new $ct_gen (ref.func $inner)))
+            )
+            (loop $next
+                (block $on_yield (result i32 (ref $ct))
+                    (resume $ct (on $yield $on_yield) (local.get $k))
+                    (return (local.get $sum))
+                )
+                (local.set $k)
+                (local.set $sum (i32.add (local.get $sum)))
+                (br $next)
+            )
+            (unreachable)
+        )
+
+        ;; Returns the first value yielded by `$gen` via a handler targeting the function body.
+        (func (export "first") (param $n i32) (result i32 (ref null $ct))
+            (resume $ct_gen (on $yield 0)
+                (local.get $n)
+                (cont.new $ct_gen (ref.func $gen))
+            )
+            (i32.const -1)
+            (ref.null $ct)
+        )
+
+        (elem declare func $nested_gen $inner)
+
+        (func (export "spin") (param i32) (result i32)
+            (resume $ct_i32 (local.get 0) (cont.new $ct_i32 (ref.func $spin)))
+        )
+    )
+"#;
+
+/// Returns a [`Config`] with the Wasm `stack-switching` proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_function_references(true);
+    config.wasm_exceptions(true);
+    config.wasm_stack_switching(true);
+    config
+}
+
+/// Creates a [`Store`] with a single instance of [`WAT`] using `config`.
+///
+/// The imported host function returns an error for even inputs and doubles odd inputs.
+fn setup_with(config: &Config) -> (Store<()>, Instance) {
+    let engine = Engine::new(config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let mut linker = <Linker<()>>::new(&engine);
+    linker
+        .func_wrap(
+            "env",
+            "host",
+            |_caller: Caller<'_, ()>, input: i32| -> Result<i32, Error> {
+                if input % 2 == 0 {
+                    return Err(Error::i32_exit(input));
+                }
+                Ok(input * 2)
+            },
+        )
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Creates a [`Store`] with a single instance of [`WAT`].
+fn setup() -> (Store<()>, Instance) {
+    setup_with(&config())
+}
+
+/// Calls the exported function `name` of `instance` with `param`.
+fn call(store: &mut Store<()>, instance: &Instance, name: &str, param: i32) -> Result<i32, Error> {
+    instance
+        .get_typed_func::<i32, i32>(&*store, name)
+        .unwrap()
+        .call(store, param)
+}
+
+/// Calls the exported function `name` of `instance` without parameters.
+fn call_0(store: &mut Store<()>, instance: &Instance, name: &str) -> Result<i32, Error> {
+    instance
+        .get_typed_func::<(), i32>(&*store, name)
+        .unwrap()
+        .call(store, ())
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut config = Config::default();
+    config.wasm_function_references(true);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn suspend_and_resume_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "sum", 1).unwrap(), 1);
+    assert_eq!(call(&mut store, &instance, "sum", 10).unwrap(), 55);
+    assert_eq!(call(&mut store, &instance, "sum", 100).unwrap(), 5050);
+}
+
+#[test]
+fn suspend_with_results_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "ask", 1).unwrap(), 4);
+    assert_eq!(call(&mut store, &instance, "ask", 5).unwrap(), 20);
+}
+
+#[test]
+fn cont_bind_works() {
+    let (mut store, instance) = setup();
+    let func = instance
+        .get_typed_func::<(i32, i32), i32>(&store, "bind")
+        .unwrap();
+    assert_eq!(func.call(&mut store, (10, 3)).unwrap(), 7);
+    assert_eq!(func.call(&mut store, (3, 10)).unwrap(), -7);
+}
+
+#[test]
+fn switch_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call_0(&mut store, &instance, "switch").unwrap(), 101);
+}
+
+#[test]
+fn nested_handlers_work() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "nested", 4).unwrap(), 10);
+    assert_eq!(call(&mut store, &instance, "nested", 10).unwrap(), 55);
+}
+
+#[test]
+fn handler_to_function_body_works() {
+    let (mut store, instance) = setup();
+    let func = instance.get_func(&store, "first").unwrap();
+    let mut results = [Val::I32(0), Val::ContRef(ContRef::null())];
+    func.call(&mut store, &[Val::I32(7)], &mut results).unwrap();
+    assert_eq!(results[0].i32(), Some(7));
+    assert!(!results[1].contref().unwrap().is_null());
+}
+
+#[test]
+fn resume_host_func_works() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "resume_host", 3).unwrap(), 6);
+    let func = instance
+        .get_typed_func::<i32, i32>(&store, "resume_host")
+        .unwrap();
+    let invocation = match func.call_resumable(&mut store, 4).unwrap() {
+        TypedResumableCall::Resumable(invocation) => invocation,
+        TypedResumableCall::Finished(_) => panic!("expected a resumable call"),
+    };
+    assert_eq!(invocation.host_error().i32_exit_status(), Some(4));
+    match invocation.resume(&mut store, &[10_i32.into()]).unwrap() {
+        TypedResumableCall::Finished(result) => assert_eq!(result, 10),
+        TypedResumableCall::Resumable(_) => panic!("expected the resumable call to finish"),
+    }
+}
+
+#[test]
+fn resume_null_traps() {
+    let (mut store, instance) = setup();
+    let error = call_0(&mut store, &instance, "resume_null").unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::NullReference));
+}
+
+#[test]
+fn resume_twice_traps() {
+    let (mut store, instance) = setup();
+    let error = call_0(&mut store, &instance, "resume_twice").unwrap_err();
+    assert_eq!(
+        error.as_trap_code(),
+        Some(TrapCode::ContinuationAlreadyConsumed)
+    );
+}
+
+#[test]
+fn unhandled_tag_traps() {
+    let (mut store, instance) = setup();
+    let func = instance
+        .get_typed_func::<i32, ()>(&store, "unhandled")
+        .unwrap();
+    let error = func.call(&mut store, 1).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::UnhandledTag));
+    // The store remains usable after trapping within a continuation.
+    assert_eq!(call(&mut store, &instance, "sum", 3).unwrap(), 6);
+}
+
+#[test]
+fn resumable_call_within_continuation() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, &instance, "call_host", 3).unwrap(), 7);
+    let func = instance
+        .get_typed_func::<i32, i32>(&store, "call_host")
+        .unwrap();
+    let invocation = match func.call_resumable(&mut store, 4).unwrap() {
+        TypedResumableCall::Resumable(invocation) => invocation,
+        TypedResumableCall::Finished(_) => panic!("expected a resumable call"),
+    };
+    assert_eq!(invocation.host_error().i32_exit_status(), Some(4));
+    match invocation.resume(&mut store, &[10_i32.into()]).unwrap() {
+        TypedResumableCall::Finished(result) => assert_eq!(result, 11),
+        TypedResumableCall::Resumable(_) => panic!("expected the resumable call to finish"),
+    }
+}
+
+#[test]
+fn fuel_metering_within_continuation() {
+    let mut config = config();
+    config.consume_fuel(true);
+    let (mut store, instance) = setup_with(&config);
+    store.set_fuel(1_000).unwrap();
+    assert_eq!(call(&mut store, &instance, "spin", 10).unwrap(), 0);
+    let consumed = 1_000 - store.get_fuel().unwrap();
+    store.set_fuel(1_000).unwrap();
+    assert_eq!(call(&mut store, &instance, "spin", 20).unwrap(), 0);
+    assert!(1_000 - store.get_fuel().unwrap() > consumed);
+    store.set_fuel(10).unwrap();
+    let error = call(&mut store, &instance, "spin", 1_000).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::OutOfFuel));
+}
+
+#[test]
+fn resume_keeps_bounds_checks_after_resume() {
+    // Regression test: bounds check elimination must not treat the handler
+    //                  parameters of `resume` as the end of its control flow.
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (type $ft (func))
+            (type $ct (cont $ft))
+            (tag $t)
+            (memory 1)
+            (func $noop)
+            (elem declare func $noop)
+            (func (export "load") (param $p i32) (param $c i32) (result i32)
+                (block $b
+                    (drop (i32.load (local.get $p)))
+                    (br_if $b (local.get $c))
+                    (block $h (result (ref $ct))
+                        (resume $ct (on $t $h) (cont.new $ct (ref.func $noop)))
+                        (local.set $p (i32.const 0x7fff0000))
+                        (br $b)
+                    )
+                    (drop)
+                )
+                (i32.load (local.get $p))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    for level in [
+        OptimizationLevel::None,
+        OptimizationLevel::Basic,
+        OptimizationLevel::Full,
+    ] {
+        let mut config = config();
+        config.optimization_level(level);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = Linker::new(&engine)
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let load = instance
+            .get_typed_func::<(i32, i32), i32>(&store, "load")
+            .unwrap();
+        assert_eq!(load.call(&mut store, (0, 1)).unwrap(), 0);
+        let error = load.call(&mut store, (0, 0)).unwrap_err();
+        assert_eq!(
+            error.as_trap_code(),
+            Some(TrapCode::MemoryOutOfBounds),
+            "{level:?}"
+        );
+    }
+}
+
+#[test]
+fn imported_tags_are_unsupported() {
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(r#"(module (import "env" "tag" (tag)))"#).unwrap();
+    let error = Module::new(&engine, &wasm[..]).unwrap_err();
+    assert!(error.to_string().contains("tags are not supported"));
+    let wasm = wat::parse_str(r#"(module (tag (export "tag")))"#).unwrap();
+    let error = Module::new(&engine, &wasm[..]).unwrap_err();
+    assert!(error.to_string().contains("tags are not supported"));
+}
+
+#[test]
+fn tags_require_exceptions() {
+    let mut config = config();
+    config.wasm_exceptions(false);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn disabling_stack_switching_keeps_exceptions() {
+    let mut config = config();
+    config.wasm_stack_switching(false);
+    let engine = Engine::new(&config);
+    let wasm = wat::parse_str(r#"(module (tag $t (param i32)))"#).unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_ok());
+}
+
+#[test]
+fn exceptions_are_unsupported() {
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (tag $e)
+            (func (throw $e))
+        )
+    "#,
+    )
+    .unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+#[cfg(feature = "gc")]
+fn ref_test_cont_works() {
+    let engine = Engine::new(config().wasm_gc(true));
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (type $ft (func))
+            (type $ct (cont $ft))
+            (func $f)
+            (elem declare func $f)
+            (func (export "test") (param $null i32) (result i32)
+                (ref.test (ref cont)
+                    (if (result (ref null $ct)) (local.get $null)
+                        (then (ref.null $ct))
+                        (else (cont.new $ct (ref.func $f)))
+                    )
+                )
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    assert_eq!(call(&mut store, &instance, "test", 0).unwrap(), 1);
+    assert_eq!(call(&mut store, &instance, "test", 1).unwrap(), 0);
+}
+
+#[test]
+#[cfg(feature = "gc")]
+fn ref_test_concrete_cont_is_unsupported() {
+    let engine = Engine::new(config().wasm_gc(true));
+    let wasm = wat::parse_str(
+        r#"
+        (module
+            (type $ft (func))
+            (type $ct (cont $ft))
+            (func (param (ref null cont)) (result i32)
+                (ref.test (ref $ct) (local.get 0))
+            )
+        )
+    "#,
+    )
+    .unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
@@ -0,0 +1,189 @@
+//! Tests to check that tiered translation re-translates hot functions and preserves the Wasm semantics.
+
+use wasmi::{
+    CompilationMode,
+    Config,
+    Engine,
+    Error,
+    Instance,
+    Linker,
+    Module,
+    OptimizationLevel,
+    Store,
+};
+
+/// The Wasm module used by all tests.
+const WAT: &str = r#"
+    (module
+        (global $counter (mut i32) (i32.const 0))
+        (func $get_counter (result i32)
+            (global.get $counter)
+        )
+        (func $bump (param $delta i32)
+            (global.set $counter (i32.add (global.get $counter) (local.get $delta)))
+        )
+        (func $div (param $a i32) (param $b i32) (result i32)
+            (i32.div_u (local.get $a) (local.get $b))
+        )
+        (func (export "bump") (param $n i32) (result i32)
+            (call $bump (local.get $n))
+            (call $get_counter)
+        )
+        (func (export "loop") (param $n i32) (result i32)
+            (local $i i32)
+            (loop $continue
+                (call $bump (local.get $i))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $continue (i32.lt_u (local.get $i) (local.get $n)))
+            )
+            (call $get_counter)
+        )
+        (func (export "loop_then_div") (param $n i32) (result i32)
+            (local $i i32)
+            (loop $continue
+                (call $bump (i32.const 1))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $continue (i32.lt_u (local.get $i) (local.get $n)))
+            )
+            (call $div (i32.const 1000) (i32.sub (local.get $n) (i32.const 10)))
+        )
+    )
+"#;
+
+/// The function index of the exported `bump` function.
+const BUMP: u32 = 3;
+
+/// The function index of the exported `loop` function.
+const LOOP: u32 = 4;
+
+/// Instantiates [`WAT`] with fuel metering enabled and inlining of small functions.
+fn setup(config: &mut Config) -> (Module, Store<()>, Instance) {
+    config.max_inline_len(8).consume_fuel(true);
+    let engine = Engine::new(config);
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    store.set_fuel(1_000_000).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (module, store, instance)
+}
+
+/// Returns `true` if the function at `func_index` of `module` contains internal calls.
+fn has_calls(module: &Module, func_index: u32) -> bool {
+    module
+        .disassemble(func_index)
+        .unwrap()
+        .unwrap()
+        .contains("call_internal")
+}
+
+/// Calls the exported function `name` with all `inputs` in order.
+///
+/// Returns the results of the calls and the total consumed fuel.
+fn call_all(
+    config: &mut Config,
+    name: &str,
+    inputs: impl IntoIterator<Item = i32>,
+) -> (Vec<Result<i32, String>>, u64) {
+    let (_module, mut store, instance) = setup(config);
+    let func = instance.get_typed_func::<i32, i32>(&store, name).unwrap();
+    let results = inputs
+        .into_iter()
+        .map(|input| {
+            func.call(&mut store, input)
+                .map_err(|error: Error| error.to_string())
+        })
+        .collect();
+    let consumed = 1_000_000 - store.get_fuel().unwrap();
+    (results, consumed)
+}
+
+#[test]
+fn tier_up_after_threshold_calls() {
+    for mode in [CompilationMode::Eager, CompilationMode::Lazy] {
+        let mut config = Config::default();
+        config.compilation_mode(mode).tier_up_threshold(3);
+        let (module, mut store, instance) = setup(&mut config);
+        let bump = instance.get_typed_func::<i32, i32>(&store, "bump").unwrap();
+        // Note: `Lazy` compiles the function upon its first call which is not accounted for.
+        let calls_before_tier_up = match mode {
+            CompilationMode::Lazy => 3,
+            _ => 2,
+        };
+        for _ in 0..calls_before_tier_up {
+            bump.call(&mut store, 1).unwrap();
+            assert!(has_calls(&module, BUMP), "{mode:?}");
+        }
+        bump.call(&mut store, 1).unwrap();
+        assert!(!has_calls(&module, BUMP), "{mode:?}");
+        assert_eq!(bump.call(&mut store, 1).unwrap(), calls_before_tier_up + 2);
+    }
+}
+
+#[test]
+fn loops_count_towards_hotness() {
+    let mut config = Config::default();
+    config.tier_up_threshold(10);
+    let (module, mut store, instance) = setup(&mut config);
+    let run = instance.get_typed_func::<i32, i32>(&store, "loop").unwrap();
+    assert!(has_calls(&module, LOOP));
+    // Note: `loop` is re-translated while still executing its initial translation.
+    assert_eq!(run.call(&mut store, 20).unwrap(), 190);
+    assert!(!has_calls(&module, LOOP));
+    assert_eq!(run.call(&mut store, 20).unwrap(), 380);
+}
+
+#[test]
+fn same_results_and_fuel() {
+    let inputs = [1, 5, 0, 20, 3, 11, 10, 7];
+    for name in ["bump", "loop", "loop_then_div"] {
+        let expected = call_all(&mut Config::default(), name, inputs);
+        for threshold in [1, 2, 10, 100] {
+            let mut config = Config::default();
+            config.tier_up_threshold(threshold);
+            assert_eq!(
+                call_all(&mut config, name, inputs),
+                expected,
+                "{name} differs for tier_up_threshold = {threshold}",
+            );
+        }
+    }
+}
+
+#[test]
+fn same_results_with_optimizations() {
+    let inputs = [1, 5, 0, 20, 3, 11, 10, 7];
+    for name in ["bump", "loop", "loop_then_div"] {
+        let (expected, _) = call_all(&mut Config::default(), name, inputs);
+        let mut config = Config::default();
+        config
+            .optimization_level(OptimizationLevel::Full)
+            .tier_up_threshold(2);
+        let (results, _) = call_all(&mut config, name, inputs);
+        assert_eq!(results, expected, "{name} differs");
+    }
+}
+
+#[test]
+fn backtraces_of_replaced_translations() {
+    let mut config = Config::default();
+    config.wasm_backtrace(true).tier_up_threshold(2);
+    let (_module, mut store, instance) = setup(&mut config);
+    let run = instance
+        .get_typed_func::<i32, i32>(&store, "loop_then_div")
+        .unwrap();
+    // Note: `loop_then_div` traps in its initial translation after being re-translated.
+    let error = run.call(&mut store, 10).unwrap_err();
+    let backtrace = error.backtrace().unwrap();
+    let offsets = backtrace
+        .frames()
+        .iter()
+        .map(|frame| frame.module_offset())
+        .collect::<Vec<_>>();
+    assert_eq!(offsets.len(), 2);
+    assert!(offsets.iter().all(Option::is_some), "{backtrace:?}");
+}
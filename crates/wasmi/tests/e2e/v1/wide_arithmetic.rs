@@ -0,0 +1,94 @@
+//! Tests for the Wasm `wide-arithmetic` proposal.
+
+use wasmi::{Config, Engine, Linker, Module, Store, TypedFunc};
+
+/// Multiplies the 128-bit `lhs` with the 64-bit `rhs` and adds `acc` using wide arithmetic.
+///
+/// All 128-bit integers are encoded as `[lo, hi]` pairs of `i64` values.
+const WAT: &str = r#"
+    (module
+        (func (export "mul_add") (param $lhs_lo i64) (param $lhs_hi i64) (param $rhs i64) (param $acc_lo i64) (param $acc_hi i64) (result i64 i64)
+            (local $lo i64)
+            (local $hi i64)
+            (i64.mul_wide_u (local.get $lhs_lo) (local.get $rhs))
+            (local.set $hi)
+            (local.set $lo)
+            (i64.add128
+                (local.get $lo)
+                (i64.add (local.get $hi) (i64.mul (local.get $lhs_hi) (local.get $rhs)))
+                (local.get $acc_lo)
+                (local.get $acc_hi)
+            )
+        )
+        (func (export "neg128") (param i64 i64) (result i64 i64)
+            (i64.sub128 (i64.const 0) (i64.const 0) (local.get 0) (local.get 1))
+        )
+    )
+"#;
+
+/// A 128-bit integer encoded as `[lo, hi]` pair of `i64` values.
+type Pair = (i64, i64);
+
+/// Returns a [`Config`] with the Wasm `wide-arithmetic` proposal enabled.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.wasm_wide_arithmetic(true);
+    config
+}
+
+/// Splits `value` into its `[lo, hi]` halves.
+fn split(value: u128) -> Pair {
+    (value as i64, (value >> 64) as i64)
+}
+
+/// Combines the `[lo, hi]` halves into a `u128` value.
+fn join((lo, hi): Pair) -> u128 {
+    (u128::from(hi as u64) << 64) | u128::from(lo as u64)
+}
+
+#[test]
+fn disabled_by_default() {
+    let engine = Engine::default();
+    let wasm = wat::parse_str(WAT).unwrap();
+    assert!(Module::new(&engine, &wasm[..]).is_err());
+}
+
+#[test]
+fn matches_u128_arithmetic() {
+    let engine = Engine::new(&config());
+    let wasm = wat::parse_str(WAT).unwrap();
+    let module = Module::new(&engine, &wasm[..]).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let mul_add: TypedFunc<(i64, i64, i64, i64, i64), Pair> =
+        instance.get_typed_func(&store, "mul_add").unwrap();
+    let neg128: TypedFunc<Pair, Pair> = instance.get_typed_func(&store, "neg128").unwrap();
+    let values = [
+        0,
+        1,
+        u128::from(u64::MAX),
+        u128::from(u64::MAX) + 1,
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+        u128::MAX,
+    ];
+    let factors = [0, 1, 2, u64::MAX, 0xdead_beef_cafe_babe];
+    for lhs in values {
+        for rhs in factors {
+            for acc in values {
+                let expected = lhs.wrapping_mul(u128::from(rhs)).wrapping_add(acc);
+                let (lhs_lo, lhs_hi) = split(lhs);
+                let (acc_lo, acc_hi) = split(acc);
+                let result = mul_add
+                    .call(&mut store, (lhs_lo, lhs_hi, rhs as i64, acc_lo, acc_hi))
+                    .unwrap();
+                assert_eq!(join(result), expected);
+            }
+        }
+        let result = neg128.call(&mut store, split(lhs)).unwrap();
+        assert_eq!(join(result), lhs.wrapping_neg());
+    }
+}
@@ -0,0 +1,121 @@
+;; Tests for the Wasm `wide-arithmetic` proposal.
+
+(module
+  (func (export "i64.add128") (param i64 i64 i64 i64) (result i64 i64)
+    (i64.add128 (local.get 0) (local.get 1) (local.get 2) (local.get 3))
+  )
+  (func (export "i64.sub128") (param i64 i64 i64 i64) (result i64 i64)
+    (i64.sub128 (local.get 0) (local.get 1) (local.get 2) (local.get 3))
+  )
+  (func (export "i64.mul_wide_s") (param i64 i64) (result i64 i64)
+    (i64.mul_wide_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64.mul_wide_u") (param i64 i64) (result i64 i64)
+    (i64.mul_wide_u (local.get 0) (local.get 1))
+  )
+
+  ;; Operands with constant values.
+  (func (export "i64.add128_const_rhs") (param i64 i64) (result i64 i64)
+    (i64.add128 (local.get 0) (local.get 1) (i64.const 1) (i64.const 0))
+  )
+  (func (export "i64.sub128_const_lhs") (param i64 i64) (result i64 i64)
+    (i64.sub128 (i64.const 0) (i64.const 0) (local.get 0) (local.get 1))
+  )
+  (func (export "i64.mul_wide_s_const") (param i64) (result i64 i64)
+    (i64.mul_wide_s (local.get 0) (i64.const -2))
+  )
+  (func (export "i64.mul_wide_u_const") (param i64) (result i64 i64)
+    (i64.mul_wide_u (i64.const -1) (local.get 0))
+  )
+
+  ;; Constant folded operations.
+  (func (export "i64.add128_folded") (result i64 i64)
+    (i64.add128 (i64.const -1) (i64.const 0) (i64.const 1) (i64.const 0))
+  )
+  (func (export "i64.sub128_folded") (result i64 i64)
+    (i64.sub128 (i64.const 0) (i64.const 0) (i64.const 1) (i64.const 0))
+  )
+  (func (export "i64.mul_wide_s_folded") (result i64 i64)
+    (i64.mul_wide_s (i64.const -1) (i64.const 1))
+  )
+  (func (export "i64.mul_wide_u_folded") (result i64 i64)
+    (i64.mul_wide_u (i64.const -1) (i64.const -1))
+  )
+
+  ;; Results of wide operations used as operands of other wide operations.
+  (func (export "i64.mul_add128") (param i64 i64 i64 i64) (result i64 i64)
+    (i64.add128
+      (i64.mul_wide_u (local.get 0) (local.get 1))
+      (i64.mul_wide_u (local.get 2) (local.get 3))
+    )
+  )
+  (func (export "i64.sub128_swap") (param i64 i64 i64 i64) (result i64 i64)
+    (i64.sub128 (local.get 0) (local.get 1) (local.get 2) (local.get 3))
+    (local.set 1)
+    (local.set 0)
+    (local.get 0)
+    (local.get 1)
+  )
+)
+
+(assert_return (invoke "i64.add128" (i64.const 0) (i64.const 0) (i64.const 0) (i64.const 0)) (i64.const 0) (i64.const 0))
+(assert_return (invoke "i64.add128" (i64.const 1) (i64.const 0) (i64.const 1) (i64.const 0)) (i64.const 2) (i64.const 0))
+(assert_return (invoke "i64.add128" (i64.const -1) (i64.const 0) (i64.const 1) (i64.const 0)) (i64.const 0) (i64.const 1))
+(assert_return (invoke "i64.add128" (i64.const -1) (i64.const -1) (i64.const 1) (i64.const 0)) (i64.const 0) (i64.const 0))
+(assert_return (invoke "i64.add128" (i64.const 0) (i64.const -9223372036854775808) (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0) (i64.const 0))
+(assert_return (invoke "i64.add128" (i64.const 9223372036854775807) (i64.const 9223372036854775807) (i64.const 1) (i64.const 0)) (i64.const -9223372036854775808) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.add128" (i64.const -1) (i64.const -1) (i64.const -1) (i64.const -1)) (i64.const -2) (i64.const -1))
+
+(assert_return (invoke "i64.sub128" (i64.const 0) (i64.const 0) (i64.const 1) (i64.const 0)) (i64.const -1) (i64.const -1))
+(assert_return (invoke "i64.sub128" (i64.const 0) (i64.const 1) (i64.const 1) (i64.const 0)) (i64.const -1) (i64.const 0))
+(assert_return (invoke "i64.sub128" (i64.const 5) (i64.const 7) (i64.const 3) (i64.const 2)) (i64.const 2) (i64.const 5))
+(assert_return (invoke "i64.sub128" (i64.const 0) (i64.const -9223372036854775808) (i64.const 0) (i64.const 1)) (i64.const 0) (i64.const 9223372036854775807))
+(assert_return (invoke "i64.sub128" (i64.const 0) (i64.const 0) (i64.const -1) (i64.const -1)) (i64.const 1) (i64.const 0))
+
+(assert_return (invoke "i64.mul_wide_s" (i64.const -1) (i64.const -1)) (i64.const 1) (i64.const 0))
+(assert_return (invoke "i64.mul_wide_s" (i64.const -1) (i64.const 1)) (i64.const -1) (i64.const -1))
+(assert_return (invoke "i64.mul_wide_s" (i64.const 9223372036854775807) (i64.const 2)) (i64.const -2) (i64.const 0))
+(assert_return (invoke "i64.mul_wide_s" (i64.const -9223372036854775808) (i64.const -9223372036854775808)) (i64.const 0) (i64.const 4611686018427387904))
+(assert_return (invoke "i64.mul_wide_s" (i64.const -9223372036854775808) (i64.const -1)) (i64.const -9223372036854775808) (i64.const 0))
+(assert_return (invoke "i64.mul_wide_s" (i64.const 0) (i64.const -9223372036854775808)) (i64.const 0) (i64.const 0))
+(assert_return (invoke "i64.mul_wide_s" (i64.const 3) (i64.const -5)) (i64.const -15) (i64.const -1))
+
+(assert_return (invoke "i64.mul_wide_u" (i64.const -1) (i64.const -1)) (i64.const 1) (i64.const -2))
+(assert_return (invoke "i64.mul_wide_u" (i64.const -1) (i64.const 2)) (i64.const -2) (i64.const 1))
+(assert_return (invoke "i64.mul_wide_u" (i64.const 4294967296) (i64.const 4294967296)) (i64.const 0) (i64.const 1))
+(assert_return (invoke "i64.mul_wide_u" (i64.const -9223372036854775808) (i64.const 2)) (i64.const 0) (i64.const 1))
+(assert_return (invoke "i64.mul_wide_u" (i64.const 0) (i64.const -1)) (i64.const 0) (i64.const 0))
+(assert_return (invoke "i64.mul_wide_u" (i64.const 3) (i64.const 5)) (i64.const 15) (i64.const 0))
+
+(assert_return (invoke "i64.add128_const_rhs" (i64.const -1) (i64.const 0)) (i64.const 0) (i64.const 1))
+(assert_return (invoke "i64.add128_const_rhs" (i64.const -1) (i64.const -1)) (i64.const 0) (i64.const 0))
+(assert_return (invoke "i64.sub128_const_lhs" (i64.const 1) (i64.const 0)) (i64.const -1) (i64.const -1))
+(assert_return (invoke "i64.sub128_const_lhs" (i64.const 0) (i64.const 1)) (i64.const 0) (i64.const -1))
+(assert_return (invoke "i64.mul_wide_s_const" (i64.const -9223372036854775808)) (i64.const 0) (i64.const 1))
+(assert_return (invoke "i64.mul_wide_s_const" (i64.const 3)) (i64.const -6) (i64.const -1))
+(assert_return (invoke "i64.mul_wide_u_const" (i64.const -1)) (i64.const 1) (i64.const -2))
+(assert_return (invoke "i64.mul_wide_u_const" (i64.const 2)) (i64.const -2) (i64.const 1))
+
+(assert_return (invoke "i64.add128_folded" ) (i64.const 0) (i64.const 1))
+(assert_return (invoke "i64.sub128_folded" ) (i64.const -1) (i64.const -1))
+(assert_return (invoke "i64.mul_wide_s_folded" ) (i64.const -1) (i64.const -1))
+(assert_return (invoke "i64.mul_wide_u_folded" ) (i64.const 1) (i64.const -2))
+
+(assert_return (invoke "i64.mul_add128" (i64.const -1) (i64.const -1) (i64.const -1) (i64.const -1)) (i64.const 2) (i64.const -4))
+(assert_return (invoke "i64.mul_add128" (i64.const 4294967296) (i64.const 4294967296) (i64.const 3) (i64.const 5)) (i64.const 15) (i64.const 1))
+(assert_return (invoke "i64.mul_add128" (i64.const -1) (i64.const 2) (i64.const 1) (i64.const 1)) (i64.const -1) (i64.const 1))
+(assert_return (invoke "i64.sub128_swap" (i64.const 5) (i64.const 7) (i64.const 3) (i64.const 2)) (i64.const 2) (i64.const 5))
+(assert_return (invoke "i64.sub128_swap" (i64.const 0) (i64.const 0) (i64.const 1) (i64.const 0)) (i64.const -1) (i64.const -1))
+
+(assert_invalid
+  (module (func (result i64 i64) (i64.add128 (i64.const 0) (i64.const 0) (i64.const 0))))
+  "type mismatch"
+)
+(assert_invalid
+  (module (func (result i64) (i64.mul_wide_s (i64.const 0) (i64.const 0))))
+  "type mismatch"
+)
+(assert_invalid
+  (module (func (result i64 i64) (i64.mul_wide_u (i32.const 0) (i64.const 0))))
+  "type mismatch"
+)
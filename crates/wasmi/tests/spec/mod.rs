@@ -288,6 +288,12 @@ mod blobs {
 
         let folder = "testsuite";
     }
+
+    include_wasm_blobs! {
+        let folder = "local";
+
+        fn wasm_wide_arithmetic("wide-arithmetic");
+    }
 }
 
 mod multi_memory {
@@ -310,6 +316,28 @@ mod multi_memory {
     }
 }
 
+mod wide_arithmetic {
+    use super::*;
+
+    fn test_config() -> RunnerConfig {
+        let mut config = Config::default();
+        config.wasm_wide_arithmetic(true);
+        let parsing_mode = ParsingMode::Buffered;
+        RunnerConfig {
+            config,
+            parsing_mode,
+        }
+    }
+
+    define_tests! {
+        let folder = "local";
+        let config = test_config();
+        let runner = process_wast;
+
+        fn wasm_wide_arithmetic("wide-arithmetic");
+    }
+}
+
 mod fueled {
     use super::*;
 